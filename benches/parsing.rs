@@ -0,0 +1,94 @@
+//! Hot paths of loading and searching a spec, on synthetic specs of 1k,
+//! 10k and 100k fields. Run with `cargo bench`, or one group with e.g.
+//! `cargo bench -- build_field_index`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use openapi_explorer::synthetic::synthetic_spec;
+use openapi_explorer::{indexer, parser, App};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+/// Deserializing the document, reference resolution included (file
+/// reading left out).
+fn parse_openapi(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_openapi");
+    group.sample_size(10);
+    for fields in SIZES {
+        let content = synthetic_spec(fields);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(fields),
+            &content,
+            |b, content| b.iter(|| parser::parse_openapi_content(black_box(content)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn resolve_references(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resolve_references");
+    group.sample_size(10);
+    for fields in SIZES {
+        let document: serde_json::Value = serde_json::from_str(&synthetic_spec(fields)).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(fields),
+            &document,
+            |b, document| {
+                b.iter_batched(
+                    || document.clone(),
+                    |mut document| parser::inline_component_refs(&mut document),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Indexing on the global rayon pool, and on a single thread for the
+/// speedup of the parallel walk.
+fn build_field_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_field_index");
+    group.sample_size(10);
+    let single = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    for fields in SIZES {
+        let spec = parser::parse_openapi_content(&synthetic_spec(fields)).unwrap();
+        group.bench_with_input(BenchmarkId::new("parallel", fields), &spec, |b, spec| {
+            b.iter(|| indexer::build_field_index(black_box(spec)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("single_thread", fields),
+            &spec,
+            |b, spec| b.iter(|| single.install(|| indexer::build_field_index(black_box(spec)))),
+        );
+    }
+    group.finish();
+}
+
+/// Filtering every list with a query, as a keystroke in the search bar
+/// does once debounced.
+fn update_filters(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update_filters");
+    for fields in SIZES {
+        let spec = parser::parse_openapi_content(&synthetic_spec(fields)).unwrap();
+        let index = indexer::build_field_index(&spec);
+        let mut app = App::new(spec, index, None);
+        app.search_query = "fld1".to_string();
+        group.bench_function(BenchmarkId::from_parameter(fields), |b| {
+            b.iter(|| app.update_filters())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    parse_openapi,
+    resolve_references,
+    build_field_index,
+    update_filters
+);
+criterion_main!(benches);
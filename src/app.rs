@@ -1,11 +1,40 @@
-use crate::indexer::FieldIndex;
-use crate::parser::OpenApiSpec;
-use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
-
-// Heuristic for pre-allocating vectors during fuzzy search
-// Assumes approximately 25% of items will match a typical search query
-const FUZZY_SEARCH_MATCH_RATE: usize = 4; // 1/4 = 25%
+use crate::clusters::FieldCluster;
+use crate::codegen::Language;
+use crate::command::PaletteCommand;
+use crate::compare::SchemaComparison;
+use crate::config::ExportDirs;
+use crate::content_types::{analyze_content_types, ContentTypeReport};
+use crate::coverage::CoverageReport;
+use crate::criticality::CriticalityRules;
+use crate::duplicates::DuplicatePair;
+use crate::file_browser::FileBrowser;
+use crate::history::History;
+use crate::impact::{ImpactReport, ImpactTarget};
+use crate::indexer::{FieldFlag, FieldFlags, FieldIndex};
+use crate::lint::{Diagnostic, LintConfig, LintFinding, LintRule};
+use crate::multi_spec::{CrossSpecIndex, SpecEntry, SpecSummary};
+use crate::notifications::{Level, Notifications};
+use crate::pagination::PaginationReport;
+use crate::parse_error::ParseError;
+use crate::parser::{is_event_target, ExternalDocs, OpenApiSpec, Schema, Server};
+use crate::path_tree::PathRow;
+use crate::progress::Progress;
+use crate::responses::{build_response_matrix, ResponseMatrix};
+use crate::runner::{ExecutedResponse, RequestDraft};
+use crate::schema_tree::{NodeKind, TreeRow};
+use crate::search::{SearchEngine, SearchResults};
+use crate::sensitive::{SensitiveDetector, SensitiveExposure};
+use crate::sorting::SortMode;
+use crate::stats::{Bucket, StatsSection, StatsSnapshot, StatsTrend};
+use crate::validate::{PayloadKind, PayloadValidation};
+use crate::view_model::{CrossSpecStats, FieldColumn, StatsAnalysis};
+use crate::workflows::WorkflowLink;
+use crate::workspace::{SavedFilter, Workspace};
+use crate::writeback::DescriptionTarget;
+use clap::ValueEnum;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum View {
@@ -14,6 +43,76 @@ pub enum View {
     Endpoints,
     Graph,
     Stats,
+    Headers,
+    Responses,
+}
+
+impl View {
+    /// Name used by `:view` and saved views.
+    pub fn name(&self) -> &'static str {
+        match self {
+            View::Fields => "fields",
+            View::Schemas => "schemas",
+            View::Endpoints => "endpoints",
+            View::Graph => "graph",
+            View::Stats => "stats",
+            View::Headers => "headers",
+            View::Responses => "responses",
+        }
+    }
+}
+
+/// Text panel or popup scrolled with Up/Down/PageUp/PageDown while it has
+/// focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollTarget {
+    Help,
+    Notifications,
+    SpecInfo,
+    Workflows,
+    EndpointDetailsPopup,
+    /// Center panel of the Endpoints view
+    EndpointDetails,
+    /// Center panel of the Headers view
+    HeaderDetails,
+    /// Center panel of the Graph view
+    SchemaDependencies,
+}
+
+/// Scroll position of a text panel, with the bounds of its last render.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextScroll {
+    pub offset: u16,
+    /// Rows that do not fit, i.e. the offset showing the last row
+    pub max_offset: u16,
+    /// Rows visible at once, scrolled by PageUp/PageDown
+    pub page: u16,
+    /// What the panel showed; showing something else starts at the top
+    pub subject: String,
+}
+
+/// Scroll positions of the text panels, recorded while rendering.
+pub type TextScrolls = RefCell<HashMap<ScrollTarget, TextScroll>>;
+
+/// Records the bounds of `target` as rendered and returns its scroll
+/// position clamped to them. Called by the renderers.
+pub fn fit_text_scroll(
+    scrolls: &TextScrolls,
+    target: ScrollTarget,
+    subject: &str,
+    content_rows: u16,
+    visible_rows: u16,
+) -> TextScroll {
+    let mut scrolls = scrolls.borrow_mut();
+    let scroll = scrolls.entry(target).or_default();
+    if scroll.subject != subject {
+        scroll.subject = subject.to_string();
+        scroll.offset = 0;
+    }
+    scroll.page = visible_rows;
+    scroll.max_offset = content_rows.saturating_sub(visible_rows);
+    scroll.offset = scroll.offset.min(scroll.max_offset);
+    scroll.clone()
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,7 +122,17 @@ pub enum Panel {
     Right,
 }
 
-#[derive(Debug, Clone)]
+/// A view and its selections, as kept in the navigation history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub view: View,
+    pub field: Option<String>,
+    pub schema: Option<String>,
+    pub endpoint: Option<String>,
+    pub header: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct FieldInfo {
     pub name: String,
     pub field_type: String,
@@ -31,6 +140,275 @@ pub struct FieldInfo {
     pub schemas: Vec<String>,
     pub endpoints: Vec<String>,
     pub is_critical: bool,
+    /// Sensitive data category (password, email, ...) if the name matches
+    pub sensitive: Option<String>,
+    /// nullable / readOnly / writeOnly, per schema declaring any
+    pub flags: Vec<(String, FieldFlags)>,
+    /// Validation keywords (maxLength, pattern, ...), per schema declaring
+    /// any
+    pub constraints: Vec<(String, Vec<String>)>,
+    /// `default` and `example` as compact JSON, per schema declaring
+    /// either
+    pub values: Vec<FieldValues>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValues {
+    pub schema: String,
+    pub default: Option<String>,
+    pub example: Option<String>,
+}
+
+/// State of the try-it-out form opened from the endpoint details popup.
+#[derive(Debug)]
+pub struct TryItOut {
+    pub draft: RequestDraft,
+    /// Focused input: 0 is the base URL, then parameters, then the body
+    pub focus: usize,
+    pub response: Option<Result<ExecutedResponse, String>>,
+    pub should_send: bool,
+}
+
+impl TryItOut {
+    pub fn new(draft: RequestDraft) -> Self {
+        Self {
+            draft,
+            focus: 0,
+            response: None,
+            should_send: false,
+        }
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.draft.content_type.is_some()
+    }
+
+    pub fn input_count(&self) -> usize {
+        1 + self.draft.params.len() + usize::from(self.has_body())
+    }
+
+    pub fn body_focused(&self) -> bool {
+        self.has_body() && self.focus == self.input_count() - 1
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.input_count();
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus = (self.focus + self.input_count() - 1) % self.input_count();
+    }
+
+    pub fn focused_value_mut(&mut self) -> &mut String {
+        if self.focus == 0 {
+            &mut self.draft.base_url
+        } else if self.body_focused() {
+            &mut self.draft.body
+        } else {
+            &mut self.draft.params[self.focus - 1].value
+        }
+    }
+}
+
+/// State of the server picker popup: one set of variable values per server.
+#[derive(Debug)]
+pub struct ServerPicker {
+    pub cursor: usize,
+    /// Focused variable of the server under the cursor
+    pub variable_focus: usize,
+    pub values: Vec<BTreeMap<String, String>>,
+}
+
+impl ServerPicker {
+    pub fn new(servers: &[Server], cursor: usize) -> Self {
+        Self {
+            cursor: cursor.min(servers.len().saturating_sub(1)),
+            variable_focus: 0,
+            values: servers
+                .iter()
+                .map(crate::servers::default_variables)
+                .collect(),
+        }
+    }
+
+    pub fn move_cursor(&mut self, down: bool) {
+        let count = self.values.len();
+        if count == 0 {
+            return;
+        }
+        self.cursor = if down {
+            (self.cursor + 1) % count
+        } else {
+            (self.cursor + count - 1) % count
+        };
+        self.variable_focus = 0;
+    }
+
+    pub fn focus_next_variable(&mut self) {
+        let count = self.values.get(self.cursor).map_or(0, |v| v.len());
+        if count > 0 {
+            self.variable_focus = (self.variable_focus + 1) % count;
+        }
+    }
+
+    /// Name of the focused variable of the server under the cursor.
+    pub fn focused_variable(&self) -> Option<&String> {
+        self.values
+            .get(self.cursor)?
+            .keys()
+            .nth(self.variable_focus)
+    }
+
+    /// Steps an enumerated variable through its allowed values.
+    pub fn cycle_value(&mut self, servers: &[Server], forward: bool) {
+        let Some(name) = self.focused_variable().cloned() else {
+            return;
+        };
+        let Some(options) = servers
+            .get(self.cursor)
+            .and_then(|server| server.variables.as_ref())
+            .and_then(|variables| variables.get(&name))
+            .and_then(|variable| variable.enum_.as_ref())
+            .filter(|options| !options.is_empty())
+        else {
+            return;
+        };
+        if let Some(value) = self.values[self.cursor].get_mut(&name) {
+            let position = options.iter().position(|o| o == value).unwrap_or(0);
+            let next = if forward {
+                (position + 1) % options.len()
+            } else {
+                (position + options.len() - 1) % options.len()
+            };
+            *value = options[next].clone();
+        }
+    }
+
+    /// Free-text value of the focused variable; `None` for enumerated ones.
+    pub fn editable_value(&mut self, servers: &[Server]) -> Option<&mut String> {
+        let name = self.focused_variable()?.clone();
+        let is_enum = servers
+            .get(self.cursor)?
+            .variables
+            .as_ref()?
+            .get(&name)?
+            .enum_
+            .is_some();
+        if is_enum {
+            return None;
+        }
+        self.values.get_mut(self.cursor)?.get_mut(&name)
+    }
+}
+
+/// Most recent commands kept for Up/Down recall in the palette
+const COMMAND_HISTORY_LIMIT: usize = 50;
+const NAVIGATION_HISTORY_LIMIT: usize = 100;
+
+/// Methods cycled through by the Endpoints view method filter
+pub const METHOD_FILTERS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+/// State of the `:` command palette.
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    pub input: String,
+    /// Position in the command history while recalling with Up/Down
+    pub history_cursor: Option<usize>,
+    pub completions: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Description being edited, before it is written back to the spec file.
+#[derive(Debug)]
+pub struct DescriptionEditor {
+    pub target: DescriptionTarget,
+    pub input: String,
+    /// Character index into `input`
+    pub cursor: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadKind {
+    /// Re-read the active spec
+    Reload,
+    /// Add the spec and switch to it
+    Open,
+    /// Re-read a spec of a watched directory, or add a new one, without
+    /// switching to it
+    Refresh,
+}
+
+/// Spec load taken from the app, run off the render path.
+#[derive(Debug, Clone)]
+pub struct LoadRequest {
+    pub kind: LoadKind,
+    pub path: std::path::PathBuf,
+    pub cache: Option<crate::cache::SpecCache>,
+    pub max_depth: usize,
+    pub lenient: bool,
+    /// Downloaded to `path` before it is read
+    pub url: Option<String>,
+}
+
+impl LoadRequest {
+    /// Reads, parses and indexes the spec, reporting each stage. Reloading
+    /// a spec downloaded from a URL first asks the server whether it
+    /// changed, and stops there when it did not; opening a URL downloads
+    /// it first.
+    pub async fn run(self, progress: Progress<'_>) -> SpecLoad {
+        let not_modified = match (self.kind, &self.url) {
+            (LoadKind::Reload, _) => crate::remote::revalidate(&self.path).await,
+            (_, Some(url)) => {
+                let cache_dir = crate::cache::SpecCache::default_dir();
+                crate::remote::download(&reqwest::Client::new(), url, &cache_dir)
+                    .await
+                    .map(|_| false)
+            }
+            (LoadKind::Open | LoadKind::Refresh, None) => Ok(false),
+        };
+        let result = match not_modified {
+            Ok(true) => Ok(None),
+            Ok(false) => crate::cache::load_indexed_with_progress(
+                &self.path,
+                self.cache.as_ref(),
+                self.max_depth,
+                self.lenient,
+                progress,
+            )
+            .await
+            .map(Some),
+            Err(error) => Err(error),
+        };
+        SpecLoad {
+            kind: self.kind,
+            path: self.path,
+            result,
+        }
+    }
+}
+
+/// Outcome of a [`LoadRequest`], applied with [`App::apply_spec_load`].
+#[derive(Debug)]
+pub struct SpecLoad {
+    pub kind: LoadKind,
+    pub path: std::path::PathBuf,
+    /// None when the remote spec was not modified since it was downloaded
+    pub result: anyhow::Result<Option<(OpenApiSpec, FieldIndex)>>,
+}
+
+/// Reload or `:open` that failed on an invalid document, shown with its
+/// location until retried or dismissed.
+#[derive(Debug)]
+pub struct FailedLoad {
+    pub kind: LoadKind,
+    pub path: std::path::PathBuf,
+    pub error: anyhow::Error,
+}
+
+impl FailedLoad {
+    pub fn parse_error(&self) -> Option<&ParseError> {
+        self.error.downcast_ref()
+    }
 }
 
 #[derive(Debug)]
@@ -43,26 +421,177 @@ pub struct App {
     pub selected_schema: Option<String>,
     pub selected_endpoint: Option<String>,
     pub search_query: String,
-    pub filtered_fields: Vec<String>,
-    pub filtered_schemas: Vec<String>,
-    pub filtered_endpoints: Vec<String>,
+    // Cursor in the search bar while it has keyboard focus (`/`)
+    pub search_cursor: Option<usize>,
+    // Names shared with the search candidates
+    pub filtered_fields: Vec<Arc<str>>,
+    pub filtered_schemas: Vec<Arc<str>>,
+    pub filtered_endpoints: Vec<Arc<str>>,
+    pub filtered_headers: Vec<Arc<str>>,
+    pub selected_header: Option<String>,
     pub should_quit: bool,
     pub show_help: bool,
+    // Scroll positions of text panels and popups, bounded while rendering
+    pub text_scroll: TextScrolls,
     pub show_endpoint_details: bool,
     pub selected_endpoint_for_details: Option<String>,
     // Selection indices for navigation
     pub field_list_state: usize,
     pub schema_list_state: usize,
     pub endpoint_list_state: usize,
+    pub header_list_state: usize,
+    // Cursor in the detail list linking to another view: the selected
+    // field's schemas (Fields view, center) or the selected schema's
+    // endpoints (Schemas view, right)
+    pub detail_list_state: usize,
+    // Schemas view center panel: tree rows expanded (by path) and the
+    // cursor among the visible rows
+    pub schema_tree_expanded: HashSet<String>,
+    pub schema_tree_cursor: usize,
     // File path for reloading
     pub file_path: Option<std::path::PathBuf>,
     pub should_reload: bool,
-    pub reload_error: Option<String>,
+    pub failed_load: Option<FailedLoad>,
     // Loading state
     pub is_loading: bool,
     pub loading_message: String,
-    // Validation warnings
-    pub validation_warnings: Vec<String>,
+    // Validation warnings, and those tied to an operation or path
+    pub validation_warnings: Vec<Diagnostic>,
+    pub lint_config: LintConfig,
+    pub lint_findings: Vec<LintFinding>,
+    // Cursor in the lint findings picker
+    pub lint_picker: Option<usize>,
+    // Feedback from actions (export, reload, ...), the newest shown as a
+    // toast in the status bar
+    pub notifications: Notifications,
+    pub show_notifications: bool,
+    pub show_spec_info: bool,
+    pub show_workflows: bool,
+    // Change since the last recorded stats snapshot (--stats-snapshot-dir)
+    pub stats_trend: Option<StatsTrend>,
+    // Documentation coverage target of the Stats view gauge, in percent
+    pub min_doc_coverage: Option<f64>,
+    // Stats view chart selected with Up/Down, and its items once opened
+    pub stats_section: StatsSection,
+    // Row of the Tags chart, while it is the selected section
+    pub stats_tag_cursor: usize,
+    pub stats_drilldown: Option<(StatsSection, Vec<Bucket>)>,
+    // Whole-spec analyses of the Stats view and popups, rebuilt with the
+    // validation warnings, and the totals of every loaded spec
+    pub stats_analysis: StatsAnalysis,
+    pub cross_spec_stats: CrossSpecStats,
+    // Response links of the active spec, for the workflows popup and the
+    // endpoint details
+    pub workflow_links: Vec<WorkflowLink>,
+    // Responses view row highlighted with Up/Down
+    pub response_row_state: usize,
+    // Impact analysis popup
+    pub impact_report: Option<ImpactReport>,
+    // Sensitive field detection
+    pub sensitive_detector: SensitiveDetector,
+    pub sensitive_report: Option<Vec<SensitiveExposure>>,
+    // Coverage analysis popup
+    pub coverage_report: Option<CoverageReport>,
+    // Duplicate schema popup
+    pub duplicate_report: Option<Vec<DuplicatePair>>,
+    // Pagination conventions popup
+    pub pagination_report: Option<PaginationReport>,
+    // Content type inventory popup
+    pub content_type_report: Option<ContentTypeReport>,
+    // Payload validation popup
+    pub payload_validation: Option<PayloadValidation>,
+    /// Sites a proposed field rename would touch, shown as a popup
+    pub rename_plan: Option<crate::rename::RenamePlan>,
+    pub description_editor: Option<DescriptionEditor>,
+    // Schema comparison: first schema picked, then the side-by-side popup
+    pub schema_compare_base: Option<String>,
+    pub schema_comparison: Option<SchemaComparison>,
+    // Ordering of the field, schema and endpoint lists
+    pub sort_mode: SortMode,
+    // Endpoints list shows operationIds before paths
+    pub operation_ids_first: bool,
+    // Endpoints list grouped by path segments, with the prefixes folded;
+    // `endpoint_list_state` then indexes the visible rows
+    pub endpoint_tree: bool,
+    pub collapsed_prefixes: HashSet<String>,
+    // Endpoint fields panel lists each field once with where it is
+    // declared, instead of the tree of origins
+    pub endpoint_fields_by_field: bool,
+    // Try-it-out request runner
+    pub base_url: String,
+    pub try_it_out: Option<TryItOut>,
+    // Deprecation timeline popup
+    pub show_timeline: bool,
+    // Server picker popup and the server backing `base_url`
+    pub server_picker: Option<ServerPicker>,
+    pub active_server: Option<usize>,
+    // Bookmarks, notes, filters, suppressions and owners shared with the team
+    pub workspace: Workspace,
+    pub workspace_file: std::path::PathBuf,
+    pub saved_filter_cursor: usize,
+    // Saved filters and views picker, with its cursor
+    pub saved_view_picker: Option<usize>,
+    // Every loaded spec (the active one is parked in `openapi_spec`)
+    pub specs: Vec<SpecEntry>,
+    pub active_spec: usize,
+    pub cross_index: CrossSpecIndex,
+    pub spec_switcher: Option<usize>,
+    pub cross_spec_field: Option<String>,
+    // Debounced background filtering for the search bar
+    pub search: SearchEngine,
+    // Parsed-spec cache used on reload (None with --no-cache)
+    pub cache: Option<crate::cache::SpecCache>,
+    // Keep the valid parts of partially invalid specs on reload and :open
+    pub lenient: bool,
+    // `:` command palette, its history, and the state its commands set
+    pub command_palette: Option<CommandPalette>,
+    pub command_history: Vec<String>,
+    // Previously visited views and selections, for Backspace / Ctrl+O
+    pub navigation: History<Location>,
+    pub field_type_filter: Option<String>,
+    // Fields view limited to fields with (or without) these attributes
+    pub field_flag_filters: Vec<(FieldFlag, bool)>,
+    // Fields table sort column and direction (by name keeps the search
+    // order), and the columns scrolled past on the right of the name
+    pub field_sort: FieldColumn,
+    pub field_sort_descending: bool,
+    pub field_column_offset: usize,
+    // Items marked with Space for bulk actions, and text waiting to be
+    // copied to the clipboard by the UI loop
+    pub marked_fields: BTreeSet<String>,
+    pub marked_endpoints: BTreeSet<String>,
+    pub pending_clipboard: Option<String>,
+    // Endpoints view shows only paths with an operation of this method
+    pub method_filter: Option<&'static str>,
+    // Endpoints view limited to the operations of a tag
+    pub tag_filter: Option<String>,
+    pub pending_open: Option<std::path::PathBuf>,
+    // URL entered in the file browser, downloaded before it is opened
+    pub pending_open_url: Option<String>,
+    // Watched spec directories, their files changed since the last refresh
+    // (reloaded once `refresh_due` passes) and the files that fail to load
+    pub spec_dirs: Vec<std::path::PathBuf>,
+    pub pending_refreshes: BTreeSet<std::path::PathBuf>,
+    pub refresh_due: Option<std::time::Instant>,
+    pub spec_dir_failures: BTreeMap<std::path::PathBuf, String>,
+    // File browser popup for opening another spec
+    pub file_browser: Option<FileBrowser>,
+    // Colors used by every view and popup
+    pub theme: crate::ui::theme::Theme,
+    // Redraw interval while idle, which also expires toasts
+    pub tick_rate: std::time::Duration,
+    // Output locations of the export keys
+    pub export_dirs: ExportDirs,
+    // Which fields are shown as critical
+    pub criticality: CriticalityRules,
+}
+
+/// Requests go to the first declared server until another is picked
+fn initial_base_url(openapi_spec: &OpenApiSpec) -> (String, Option<usize>) {
+    match crate::servers::default_base_url(openapi_spec) {
+        Some(url) => (url, Some(0)),
+        None => (crate::runner::DEFAULT_BASE_URL.to_string(), None),
+    }
 }
 
 impl App {
@@ -71,6 +600,13 @@ impl App {
         field_index: FieldIndex,
         file_path: Option<std::path::PathBuf>,
     ) -> Self {
+        let (base_url, active_server) = initial_base_url(&openapi_spec);
+        let specs = vec![SpecEntry {
+            name: crate::multi_spec::spec_name(&openapi_spec, file_path.as_deref()),
+            file_path: file_path.clone(),
+            parked: None,
+        }];
+
         let mut app = Self {
             openapi_spec,
             field_index,
@@ -80,89 +616,206 @@ impl App {
             selected_schema: None,
             selected_endpoint: None,
             search_query: String::new(),
+            search_cursor: None,
             filtered_fields: Vec::new(),
             filtered_schemas: Vec::new(),
             filtered_endpoints: Vec::new(),
+            filtered_headers: Vec::new(),
+            selected_header: None,
             should_quit: false,
             show_help: false,
+            text_scroll: RefCell::default(),
             show_endpoint_details: false,
             selected_endpoint_for_details: None,
             field_list_state: 0,
             schema_list_state: 0,
             endpoint_list_state: 0,
+            header_list_state: 0,
+            detail_list_state: 0,
+            schema_tree_expanded: HashSet::new(),
+            schema_tree_cursor: 0,
             file_path,
             should_reload: false,
-            reload_error: None,
+            failed_load: None,
             is_loading: false,
             loading_message: String::new(),
             validation_warnings: Vec::new(),
+            lint_config: LintConfig::default(),
+            lint_findings: Vec::new(),
+            lint_picker: None,
+            notifications: Notifications::default(),
+            show_notifications: false,
+            show_spec_info: false,
+            show_workflows: false,
+            stats_trend: None,
+            min_doc_coverage: None,
+            stats_section: StatsSection::default(),
+            stats_tag_cursor: 0,
+            response_row_state: 0,
+            stats_drilldown: None,
+            stats_analysis: StatsAnalysis::default(),
+            cross_spec_stats: CrossSpecStats::default(),
+            workflow_links: Vec::new(),
+            impact_report: None,
+            sensitive_detector: SensitiveDetector::default(),
+            sensitive_report: None,
+            coverage_report: None,
+            duplicate_report: None,
+            pagination_report: None,
+            content_type_report: None,
+            payload_validation: None,
+            rename_plan: None,
+            description_editor: None,
+            schema_compare_base: None,
+            schema_comparison: None,
+            sort_mode: SortMode::default(),
+            operation_ids_first: false,
+            endpoint_tree: false,
+            collapsed_prefixes: HashSet::new(),
+            endpoint_fields_by_field: false,
+            base_url,
+            try_it_out: None,
+            show_timeline: false,
+            server_picker: None,
+            active_server,
+            workspace: Workspace::default(),
+            workspace_file: crate::workspace::DEFAULT_WORKSPACE_FILE.into(),
+            saved_filter_cursor: 0,
+            saved_view_picker: None,
+            specs,
+            active_spec: 0,
+            cross_index: CrossSpecIndex::default(),
+            spec_switcher: None,
+            cross_spec_field: None,
+            search: SearchEngine::default(),
+            cache: None,
+            lenient: false,
+            command_palette: None,
+            command_history: Vec::new(),
+            navigation: History::new(NAVIGATION_HISTORY_LIMIT),
+            field_type_filter: None,
+            field_flag_filters: Vec::new(),
+            field_sort: FieldColumn::default(),
+            field_sort_descending: false,
+            field_column_offset: 0,
+            marked_fields: BTreeSet::new(),
+            marked_endpoints: BTreeSet::new(),
+            pending_clipboard: None,
+            method_filter: None,
+            tag_filter: None,
+            pending_open: None,
+            pending_open_url: None,
+            spec_dirs: Vec::new(),
+            pending_refreshes: BTreeSet::new(),
+            refresh_due: None,
+            spec_dir_failures: BTreeMap::new(),
+            file_browser: None,
+            theme: crate::ui::theme::Theme::default(),
+            tick_rate: crate::ui::TICK_RATE,
+            export_dirs: ExportDirs::default(),
+            criticality: CriticalityRules::default(),
         };
 
+        app.rebuild_cross_index();
+        app.search.rebuild(&app.field_index, &app.openapi_spec);
         app.update_filters();
         app.validate_spec();
         app
     }
 
+    /// Filters all lists synchronously with the current query.
     pub fn update_filters(&mut self) {
-        // Pre-allocate vectors with estimated capacity for better performance
-        let estimated_size = if self.search_query.is_empty() {
-            self.field_index.fields.len()
-        } else {
-            // Use heuristic: assume ~25% of items match a typical search query
-            self.field_index.fields.len() / FUZZY_SEARCH_MATCH_RATE
-        };
+        self.search.cancel();
+        if let Some(results) = crate::search::filter_candidates(
+            &self.search.candidates,
+            &self.search_query,
+            self.sort_mode,
+            &|| false,
+        ) {
+            self.apply_search_results(results);
+        }
+    }
 
+    /// Called on each keystroke in the search bar: clearing the query is
+    /// applied at once, anything else is debounced and filtered off the UI
+    /// thread.
+    pub fn on_search_changed(&mut self) {
         if self.search_query.is_empty() {
-            // Fast path: no filtering needed
-            self.filtered_fields = self.field_index.fields.keys().cloned().collect();
-            self.filtered_schemas = self.field_index.schemas.keys().cloned().collect();
-            self.filtered_endpoints = self.openapi_spec.paths.keys().cloned().collect();
-
-            // Sort alphabetically
-            self.filtered_fields.sort_unstable();
-            self.filtered_schemas.sort_unstable();
-            self.filtered_endpoints.sort_unstable();
+            self.update_filters();
         } else {
-            // Fuzzy search implementation with pre-allocated vectors
-            let matcher = SkimMatcherV2::default();
-            let query = &self.search_query;
-
-            // Filter and score fields with capacity hint
-            let mut field_matches: Vec<(String, i64)> = Vec::with_capacity(estimated_size);
-            field_matches.extend(self.field_index.fields.keys().filter_map(|field| {
-                matcher
-                    .fuzzy_match(field, query)
-                    .map(|score| (field.clone(), score))
-            }));
-            field_matches.sort_unstable_by(|a, b| b.1.cmp(&a.1)); // Sort by score descending
-            self.filtered_fields = field_matches.into_iter().map(|(field, _)| field).collect();
-
-            // Filter and score schemas
-            let mut schema_matches: Vec<(String, i64)> = Vec::with_capacity(estimated_size);
-            schema_matches.extend(self.field_index.schemas.keys().filter_map(|schema| {
-                matcher
-                    .fuzzy_match(schema, query)
-                    .map(|score| (schema.clone(), score))
-            }));
-            schema_matches.sort_unstable_by(|a, b| b.1.cmp(&a.1));
-            self.filtered_schemas = schema_matches
-                .into_iter()
-                .map(|(schema, _)| schema)
-                .collect();
+            self.search.schedule();
+        }
+    }
 
-            // Filter and score endpoints
-            let mut endpoint_matches: Vec<(String, i64)> = Vec::with_capacity(estimated_size);
-            endpoint_matches.extend(self.openapi_spec.paths.keys().filter_map(|endpoint| {
-                matcher
-                    .fuzzy_match(endpoint, query)
-                    .map(|score| (endpoint.clone(), score))
-            }));
-            endpoint_matches.sort_unstable_by(|a, b| b.1.cmp(&a.1));
-            self.filtered_endpoints = endpoint_matches
-                .into_iter()
-                .map(|(endpoint, _)| endpoint)
-                .collect();
+    /// Gives the search bar keyboard focus, the cursor after the query.
+    pub fn start_search_input(&mut self) {
+        self.search_cursor = Some(self.search_query.chars().count());
+    }
+
+    pub fn stop_search_input(&mut self) {
+        self.search_cursor = None;
+    }
+
+    /// Applies a `line_editor` edit to the focused search bar, searching
+    /// again when the query changed.
+    pub fn edit_search(&mut self, edit: impl FnOnce(&mut String, &mut usize)) {
+        let Some(cursor) = &mut self.search_cursor else {
+            return;
+        };
+        let previous = self.search_query.clone();
+        edit(&mut self.search_query, cursor);
+        if self.search_query != previous {
+            self.on_search_changed();
+        }
+    }
+
+    /// Starts a due search and swaps in results that have arrived.
+    pub fn poll_search(&mut self) {
+        self.search.start_if_due(&self.search_query, self.sort_mode);
+        if let Some(results) = self.search.try_recv() {
+            self.apply_search_results(results);
+        }
+    }
+
+    fn apply_search_results(&mut self, results: SearchResults) {
+        self.filtered_fields = results.fields;
+        if let Some(field_type) = &self.field_type_filter {
+            let fields = &self.field_index.fields;
+            self.filtered_fields.retain(|name| {
+                fields
+                    .get(&**name)
+                    .is_some_and(|data| data.field_type.eq_ignore_ascii_case(field_type))
+            });
+        }
+        if !self.field_flag_filters.is_empty() {
+            let fields = &self.field_index.fields;
+            let flag_filters = &self.field_flag_filters;
+            self.filtered_fields.retain(|name| {
+                fields.get(&**name).is_some_and(|data| {
+                    flag_filters
+                        .iter()
+                        .all(|(flag, value)| data.has_flag(*flag) == *value)
+                })
+            });
+        }
+        self.sort_filtered_fields();
+        self.filtered_schemas = results.schemas;
+        self.filtered_endpoints = results.endpoints;
+        if self.method_filter.is_some() || self.tag_filter.is_some() {
+            let spec = &self.openapi_spec;
+            let method_filter = self.method_filter;
+            let tag_filter = self.tag_filter.as_deref();
+            self.filtered_endpoints.retain(|target| {
+                spec.path_item(target).is_some_and(|item| {
+                    item.operations.iter().any(|(m, operation)| {
+                        matches_method(method_filter, m) && matches_tag(tag_filter, operation)
+                    })
+                })
+            });
         }
+        // Webhooks and callbacks form their own section after the paths
+        self.filtered_endpoints
+            .sort_by_key(|target| is_event_target(target));
 
         // Reset selection indices to stay within bounds
         // Reset to 0 when lists are empty to prevent index out of bounds
@@ -178,25 +831,62 @@ impl App {
             self.schema_list_state = 0;
         }
 
-        if !self.filtered_endpoints.is_empty() {
-            self.endpoint_list_state = self
-                .endpoint_list_state
-                .min(self.filtered_endpoints.len() - 1);
-        } else {
-            self.endpoint_list_state = 0;
-        }
+        self.endpoint_list_state = self
+            .endpoint_list_state
+            .min(self.endpoint_list_len().saturating_sub(1));
+
+        self.filtered_headers = results.headers;
+        self.header_list_state = self
+            .header_list_state
+            .min(self.filtered_headers.len().saturating_sub(1));
+        self.response_row_state = self
+            .response_row_state
+            .min(self.response_matrix().rows.len().saturating_sub(1));
     }
 
     pub fn get_field_info(&self, field_name: &str) -> Option<FieldInfo> {
         self.field_index.fields.get(field_name).map(|field_data| {
             let endpoints = self.field_index.get_endpoints_for_field(field_name);
+            // The field's own schema in each component declaring it
+            let properties: Vec<(&str, &Schema)> = field_data
+                .schemas
+                .iter()
+                .filter_map(|schema| {
+                    let component = self.field_index.schemas.get(&**schema)?;
+                    Some((&**schema, component.property_at(field_name)?))
+                })
+                .collect();
             FieldInfo {
                 name: field_name.to_string(),
                 field_type: field_data.field_type.clone(),
                 description: field_data.description.clone(),
-                schemas: field_data.schemas.clone(),
+                schemas: field_data.schemas.iter().map(|s| s.to_string()).collect(),
                 endpoints,
-                is_critical: self.field_index.is_critical_field(field_name),
+                is_critical: self.criticality.is_critical(&self.field_index, field_name),
+                sensitive: self.sensitive_label(field_name).map(str::to_string),
+                flags: field_data
+                    .flags
+                    .iter()
+                    .map(|(schema, flags)| (schema.to_string(), *flags))
+                    .collect(),
+                constraints: properties
+                    .iter()
+                    .filter_map(|(schema, property)| {
+                        let constraints = property.constraints();
+                        (!constraints.is_empty()).then(|| (schema.to_string(), constraints))
+                    })
+                    .collect(),
+                values: properties
+                    .iter()
+                    .filter(|(_, property)| {
+                        property.default.is_some() || property.example.is_some()
+                    })
+                    .map(|(schema, property)| FieldValues {
+                        schema: schema.to_string(),
+                        default: property.default.as_ref().map(ToString::to_string),
+                        example: property.example.as_ref().map(ToString::to_string),
+                    })
+                    .collect(),
             }
         })
     }
@@ -210,32 +900,119 @@ impl App {
     }
 
     pub fn set_view(&mut self, view: View) {
+        let from = self.location();
         self.current_view = view;
         self.selected_field = None;
         self.selected_schema = None;
         self.selected_endpoint = None;
+        self.selected_header = None;
+        self.detail_list_state = 0;
+        self.reset_schema_tree();
+        self.record_visit(from);
+    }
+
+    pub fn location(&self) -> Location {
+        Location {
+            view: self.current_view.clone(),
+            field: self.selected_field.clone(),
+            schema: self.selected_schema.clone(),
+            endpoint: self.selected_endpoint.clone(),
+            header: self.selected_header.clone(),
+        }
+    }
+
+    /// Adds the location just left to the navigation history, unless
+    /// nothing changed.
+    fn record_visit(&mut self, from: Location) {
+        if from != self.location() {
+            self.navigation.visit(from);
+        }
+    }
+
+    /// Returns to the previous view and selection.
+    pub fn go_back(&mut self) {
+        match self.navigation.back(self.location()) {
+            Some(location) => self.restore_location(location),
+            None => self.notify(Level::Info, "No earlier location"),
+        }
+    }
+
+    /// Undoes the last `go_back`.
+    pub fn go_forward(&mut self) {
+        match self.navigation.forward(self.location()) {
+            Some(location) => self.restore_location(location),
+            None => self.notify(Level::Info, "No later location"),
+        }
+    }
+
+    /// Shows a location again, moving the list cursors to its selections
+    /// when they are still listed.
+    fn restore_location(&mut self, location: Location) {
+        let position = |items: &[Arc<str>], item: &Option<String>| {
+            item.as_ref()
+                .and_then(|item| items.iter().position(|i| **i == **item))
+        };
+        if let Some(i) = position(&self.filtered_fields, &location.field) {
+            self.field_list_state = i;
+        }
+        if let Some(i) = position(&self.filtered_schemas, &location.schema) {
+            self.schema_list_state = i;
+        }
+        if let Some(i) = location
+            .endpoint
+            .as_deref()
+            .and_then(|endpoint| self.endpoint_position(endpoint))
+        {
+            self.endpoint_list_state = i;
+        }
+        if let Some(i) = position(&self.filtered_headers, &location.header) {
+            self.header_list_state = i;
+        }
+        self.detail_list_state = 0;
+        self.current_view = location.view;
+        self.selected_field = location.field;
+        self.selected_schema = location.schema;
+        self.selected_endpoint = location.endpoint;
+        self.selected_header = location.header;
+        self.reset_schema_tree();
     }
 
     pub fn navigate_up(&mut self) {
+        if self.current_view == View::Stats {
+            if self.stats_section == StatsSection::Tags && self.stats_tag_cursor > 0 {
+                self.stats_tag_cursor -= 1;
+            } else {
+                self.stats_section = self.stats_section.previous();
+                self.stats_tag_cursor = 0;
+            }
+            return;
+        }
+        if self.current_view == View::Responses {
+            self.response_row_state = self.response_row_state.saturating_sub(1);
+            return;
+        }
         match self.current_panel {
             Panel::Left => match self.current_view {
-                View::Fields => {
-                    if self.field_list_state > 0 {
-                        self.field_list_state -= 1;
-                    }
+                View::Fields if self.field_list_state > 0 => {
+                    self.field_list_state -= 1;
                 }
-                View::Schemas => {
-                    if self.schema_list_state > 0 {
-                        self.schema_list_state -= 1;
-                    }
+                View::Schemas | View::Graph if self.schema_list_state > 0 => {
+                    self.schema_list_state -= 1;
                 }
-                View::Endpoints => {
-                    if self.endpoint_list_state > 0 {
-                        self.endpoint_list_state -= 1;
-                    }
+                View::Endpoints if self.endpoint_list_state > 0 => {
+                    self.endpoint_list_state -= 1;
+                }
+                View::Headers if self.header_list_state > 0 => {
+                    self.header_list_state -= 1;
                 }
                 _ => {}
             },
+            Panel::Center if self.in_schema_tree() => {
+                self.schema_tree_cursor = self.schema_tree_cursor.saturating_sub(1);
+            }
+            Panel::Center | Panel::Right if self.has_detail_list() => {
+                self.detail_list_state = self.detail_list_state.saturating_sub(1);
+            }
             Panel::Right => {
                 // Navigation in right panel (endpoints list)
                 // Only navigate if a field is selected (consistent with navigate_down)
@@ -251,25 +1028,53 @@ impl App {
     }
 
     pub fn navigate_down(&mut self) {
+        if self.current_view == View::Stats {
+            let tags = self.stats_analysis.tags.len();
+            if self.stats_section == StatsSection::Tags && self.stats_tag_cursor + 1 < tags {
+                self.stats_tag_cursor += 1;
+            } else {
+                self.stats_section = self.stats_section.next();
+                self.stats_tag_cursor = 0;
+            }
+            return;
+        }
+        if self.current_view == View::Responses {
+            let rows = self.response_matrix().rows.len();
+            self.response_row_state = (self.response_row_state + 1).min(rows.saturating_sub(1));
+            return;
+        }
         match self.current_panel {
             Panel::Left => match self.current_view {
-                View::Fields => {
-                    if self.field_list_state < self.filtered_fields.len().saturating_sub(1) {
-                        self.field_list_state += 1;
-                    }
+                View::Fields
+                    if self.field_list_state < self.filtered_fields.len().saturating_sub(1) =>
+                {
+                    self.field_list_state += 1;
                 }
-                View::Schemas => {
-                    if self.schema_list_state < self.filtered_schemas.len().saturating_sub(1) {
-                        self.schema_list_state += 1;
-                    }
+                View::Schemas | View::Graph
+                    if self.schema_list_state < self.filtered_schemas.len().saturating_sub(1) =>
+                {
+                    self.schema_list_state += 1;
                 }
-                View::Endpoints => {
-                    if self.endpoint_list_state < self.filtered_endpoints.len().saturating_sub(1) {
-                        self.endpoint_list_state += 1;
-                    }
+                View::Endpoints
+                    if self.endpoint_list_state < self.endpoint_list_len().saturating_sub(1) =>
+                {
+                    self.endpoint_list_state += 1;
+                }
+                View::Headers
+                    if self.header_list_state < self.filtered_headers.len().saturating_sub(1) =>
+                {
+                    self.header_list_state += 1;
                 }
                 _ => {}
             },
+            Panel::Center if self.in_schema_tree() => {
+                let last = self.schema_tree().len().saturating_sub(1);
+                self.schema_tree_cursor = (self.schema_tree_cursor + 1).min(last);
+            }
+            Panel::Center | Panel::Right if self.has_detail_list() => {
+                let last = self.detail_items().len().saturating_sub(1);
+                self.detail_list_state = (self.detail_list_state + 1).min(last);
+            }
             Panel::Right => {
                 // Navigation in right panel (endpoints list)
                 if let Some(selected_field) = &self.selected_field {
@@ -284,31 +1089,74 @@ impl App {
     }
 
     pub fn select_current_item(&mut self) {
+        let from = self.location();
+        self.select_item();
+        self.record_visit(from);
+    }
+
+    fn select_item(&mut self) {
+        if self.current_view == View::Stats {
+            if self.stats_section == StatsSection::Tags {
+                let tag = self.stats_analysis.tags.get(self.stats_tag_cursor);
+                if let Some(tag) = tag.map(|stats| stats.tag.clone()) {
+                    self.filter_by_tag(Some(tag));
+                    self.set_view(View::Endpoints);
+                }
+            } else {
+                self.open_stats_drilldown();
+            }
+            return;
+        }
+        if self.current_view == View::Responses {
+            if let Some(row) = self.response_matrix().rows.get(self.response_row_state) {
+                self.selected_endpoint_for_details = Some(row.endpoint.clone());
+                self.show_endpoint_details = true;
+            }
+            return;
+        }
         match self.current_panel {
             Panel::Left => {
                 match self.current_view {
                     View::Fields => {
                         // Use get() for safe bounds-checked access
                         if let Some(field) = self.filtered_fields.get(self.field_list_state) {
-                            self.selected_field = Some(field.clone());
+                            self.selected_field = Some(field.to_string());
                             self.endpoint_list_state = 0; // Reset endpoint selection
+                            self.detail_list_state = 0;
                         }
                     }
-                    View::Schemas => {
+                    View::Schemas | View::Graph => {
                         if let Some(schema) = self.filtered_schemas.get(self.schema_list_state) {
-                            self.selected_schema = Some(schema.clone());
+                            self.selected_schema = Some(schema.to_string());
+                            self.detail_list_state = 0;
+                            self.reset_schema_tree();
                         }
-                    }
-                    View::Endpoints => {
-                        if let Some(endpoint) =
-                            self.filtered_endpoints.get(self.endpoint_list_state)
+                        if self.schema_compare_base.is_some() && self.current_view == View::Schemas
                         {
-                            self.selected_endpoint = Some(endpoint.clone());
+                            self.compare_schema();
+                        }
+                    }
+                    View::Endpoints => match self.endpoint_at_cursor() {
+                        Some(endpoint) => self.selected_endpoint = Some(endpoint.to_string()),
+                        None => self.toggle_path_node(),
+                    },
+                    View::Headers => {
+                        if let Some(header) = self.filtered_headers.get(self.header_list_state) {
+                            self.selected_header = Some(header.to_string());
                         }
                     }
                     _ => {}
                 }
             }
+            Panel::Center if self.in_schema_tree() => self.toggle_tree_node(),
+            Panel::Center | Panel::Right if self.has_detail_list() => {
+                if let Some(item) = self.detail_items().get(self.detail_list_state).cloned() {
+                    match self.current_view {
+                        View::Fields => self.jump_to_schema(&item),
+                        _ => self.jump_to_endpoint(&item),
+                    }
+                }
+            }
             Panel::Right => {
                 // Show endpoint details popup when selecting in Right panel
                 if let Some(selected_field) = &self.selected_field {
@@ -323,131 +1171,1944 @@ impl App {
         }
     }
 
-    pub fn request_reload(&mut self) {
-        self.should_reload = true;
-        self.is_loading = true;
-        self.loading_message = "Reloading OpenAPI specification...".to_string();
+    /// Operations under the currently filtered paths, as `"METHOD /path"` keys,
+    /// limited to the method filter.
+    pub fn filtered_endpoint_keys(&self) -> Vec<String> {
+        self.filtered_endpoints
+            .iter()
+            .flat_map(|path| self.operation_keys(path))
+            .filter(|key| {
+                key.split_once(' ').is_some_and(|(method, target)| {
+                    matches_method(self.method_filter, method)
+                        && self.openapi_spec.path_item(target).is_some_and(|item| {
+                            item.operations
+                                .get(&method.to_lowercase())
+                                .is_some_and(|operation| {
+                                    matches_tag(self.tag_filter.as_deref(), operation)
+                                })
+                        })
+                })
+            })
+            .collect()
     }
 
-    pub async fn reload(&mut self) -> Result<(), String> {
-        if let Some(file_path) = &self.file_path {
-            self.is_loading = true;
-            self.loading_message = format!(
-                "Parsing {}...",
-                file_path
-                    .file_name()
-                    .map(|n| n.to_string_lossy())
-                    .unwrap_or_else(|| "file".into())
-            );
+    /// Limits the Endpoints view to the operations of `tag`, or lifts the
+    /// limit.
+    pub fn filter_by_tag(&mut self, tag: Option<String>) {
+        self.notify(
+            Level::Info,
+            match &tag {
+                Some(tag) => format!("Showing endpoints tagged {}", tag),
+                None => "Showing all tags".to_string(),
+            },
+        );
+        self.tag_filter = tag;
+        self.update_filters();
+    }
 
-            match crate::parser::parse_openapi(file_path).await {
-                Ok(spec) => {
-                    self.loading_message = "Building field index...".to_string();
-                    let new_index = crate::indexer::build_field_index(&spec);
-                    self.openapi_spec = spec;
-                    self.field_index = new_index;
-                    self.update_filters();
-                    self.validate_spec(); // Validate after reload
-                    self.reload_error = None;
-                    self.is_loading = false;
-                    self.loading_message.clear();
-                    Ok(())
-                }
-                Err(e) => {
-                    let error_msg = format!("Failed to reload: {}", e);
-                    self.reload_error = Some(error_msg.clone());
-                    self.is_loading = false;
-                    self.loading_message.clear();
-                    Err(error_msg)
-                }
+    /// Cycles the Endpoints method filter: all, then each of
+    /// `METHOD_FILTERS`, then all again.
+    pub fn cycle_method_filter(&mut self) {
+        let next = match self.method_filter {
+            None => 0,
+            Some(method) => METHOD_FILTERS
+                .iter()
+                .position(|m| *m == method)
+                .map_or(0, |i| i + 1),
+        };
+        self.method_filter = METHOD_FILTERS.get(next).copied();
+        self.update_filters();
+        self.notify(
+            Level::Info,
+            match self.method_filter {
+                Some(method) => format!("Showing {} endpoints", method),
+                None => "Showing all methods".to_string(),
+            },
+        );
+    }
+
+    /// Operations of a path, webhook or callback target, sorted by method.
+    pub fn operation_keys(&self, target: &str) -> Vec<String> {
+        let Some(path_item) = self.openapi_spec.path_item(target) else {
+            return Vec::new();
+        };
+        let mut methods: Vec<&String> = path_item.operations.keys().collect();
+        methods.sort_unstable();
+        methods
+            .into_iter()
+            .map(|method| format!("{} {}", method.to_uppercase(), target))
+            .collect()
+    }
+
+    pub fn export_parameter_matrix(&mut self, path: &std::path::Path) {
+        let endpoints = self.filtered_endpoint_keys();
+        match crate::export::write_parameter_matrix_csv(&self.openapi_spec, &endpoints, path) {
+            Ok(rows) => {
+                self.notify(
+                    Level::Success,
+                    format!("Exported {} endpoint(s) to {}", rows, path.display()),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Export failed: {}", e));
             }
-        } else {
-            let error_msg = "No file path available for reload".to_string();
-            self.reload_error = Some(error_msg.clone());
-            self.is_loading = false;
-            self.loading_message.clear();
-            Err(error_msg)
         }
     }
 
-    pub fn validate_spec(&mut self) {
-        self.validation_warnings.clear();
-
-        // Check for empty or missing components
-        if let Some(components) = &self.openapi_spec.components {
-            match &components.schemas {
-                Some(schemas) if !schemas.is_empty() => {}
-                _ => {
-                    self.validation_warnings
-                        .push("No schemas defined in components".to_string());
-                }
+    /// Status-code matrix of the filtered endpoints, as shown in the
+    /// Responses view.
+    pub fn response_matrix(&self) -> ResponseMatrix {
+        build_response_matrix(&self.field_index, &self.filtered_endpoint_keys())
+    }
+
+    /// Writes the error responses missing from the filtered endpoints.
+    pub fn export_error_skeleton(&mut self, path: &std::path::Path) {
+        let skeleton = crate::error_skeleton::build_error_skeleton(
+            &self.openapi_spec,
+            &self.field_index,
+            &self.filtered_endpoint_keys(),
+        );
+        if skeleton.endpoints.is_empty() {
+            self.notify(Level::Info, "No error responses missing");
+            return;
+        }
+        let format = crate::error_skeleton::SkeletonFormat::for_path(path);
+        match std::fs::write(path, skeleton.render(format)) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Wrote {} error response(s) for {} endpoint(s) to {}",
+                        skeleton.response_count(),
+                        skeleton.endpoints.len(),
+                        path.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Error skeleton export failed: {}", e));
             }
-        } else {
-            self.validation_warnings
-                .push("No components section found in OpenAPI spec".to_string());
         }
+    }
+
+    /// Lists the items behind the selected Stats chart.
+    pub fn open_stats_drilldown(&mut self) {
+        let buckets = self.stats_analysis.buckets(self.stats_section);
+        self.stats_drilldown = Some((self.stats_section, buckets));
+    }
 
-        // Check for paths
-        if self.openapi_spec.paths.is_empty() {
-            self.validation_warnings
-                .push("No paths/endpoints defined in spec".to_string());
+    /// Writes the Stats dashboard, with the snapshot trend when one was
+    /// recorded at startup.
+    pub fn export_stats(&mut self, path: &std::path::Path) {
+        let snapshot =
+            StatsSnapshot::capture(&self.openapi_spec, &self.field_index, chrono::Utc::now());
+        match crate::stats::write_stats(path, &snapshot, self.stats_trend.as_ref()) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!("Exported stats to {}", path.display()),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Stats export failed: {}", e));
+            }
         }
+    }
 
-        // Check for fields without types
-        for (field_name, field_data) in &self.field_index.fields {
-            if field_data.field_type == "unknown" {
-                self.validation_warnings
-                    .push(format!("Field '{}' has unknown type", field_name));
+    /// Writes the endpoint / schema / field graph, as an interactive HTML
+    /// page or a Graphviz file depending on the extension.
+    pub fn export_graph(&mut self, path: &std::path::Path) {
+        let graph = crate::graph_export::build_api_graph(&self.field_index);
+        let title = format!(
+            "{} {}",
+            self.openapi_spec.info.title, self.openapi_spec.info.version
+        );
+        match crate::graph_export::write_graph(path, &graph, &title) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Exported graph ({} nodes, {} links) to {}",
+                        graph.nodes.len(),
+                        graph.links.len(),
+                        path.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Graph export failed: {}", e));
             }
         }
+    }
 
-        // Check for endpoints without operations
-        for (path, path_item) in &self.openapi_spec.paths {
-            if path_item.operations.is_empty() {
-                self.validation_warnings
-                    .push(format!("Path '{}' has no operations defined", path));
+    pub fn export_unused_components(&mut self, path: &std::path::Path) {
+        let report = crate::coverage::analyze_coverage(&self.openapi_spec, &self.field_index);
+        let unused = crate::coverage::unused_component_list(&self.openapi_spec, &report);
+        match crate::coverage::write_unused_components(path, &unused) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Exported {} unused component(s) to {}",
+                        unused.len(),
+                        path.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(
+                    Level::Error,
+                    format!("Unused components export failed: {}", e),
+                );
             }
         }
+    }
 
-        // Check for missing descriptions
-        let mut missing_descriptions = 0;
-        for (_, operation) in self
-            .openapi_spec
-            .paths
-            .values()
-            .flat_map(|pi| pi.operations.iter())
-        {
-            if operation.description.is_none() && operation.summary.is_none() {
-                missing_descriptions += 1;
+    /// Fields always declared together, shown in the Graph view as
+    /// value-object candidates.
+    pub fn field_clusters(&self) -> Vec<FieldCluster> {
+        crate::clusters::field_clusters(&self.field_index)
+    }
+
+    pub fn export_field_clusters(&mut self, path: &std::path::Path) {
+        let clusters = self.field_clusters();
+        match crate::clusters::write_clusters(path, &clusters) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Exported {} field cluster(s) to {}",
+                        clusters.len(),
+                        path.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Field clusters export failed: {}", e));
             }
         }
-        if missing_descriptions > 0 {
-            self.validation_warnings.push(format!(
-                "{} endpoint(s) missing description/summary",
-                missing_descriptions
-            ));
+    }
+
+    /// Schema selected (or highlighted) in the Schemas view.
+    pub fn current_schema(&self) -> Option<String> {
+        self.selected_schema.clone().or_else(|| {
+            self.filtered_schemas
+                .get(self.schema_list_state)
+                .map(|s| s.to_string())
+        })
+    }
+
+    /// Picks the highlighted schema as the first side of a comparison, or
+    /// compares it with the schema picked before.
+    pub fn compare_schema(&mut self) {
+        let Some(highlighted) = self
+            .filtered_schemas
+            .get(self.schema_list_state)
+            .map(|s| s.to_string())
+        else {
+            return;
+        };
+        let Some(base) = self.schema_compare_base.clone() else {
+            self.notify(
+                Level::Info,
+                format!(
+                    "Comparing {}: highlight another schema and press 'c' or Enter",
+                    highlighted
+                ),
+            );
+            self.schema_compare_base = Some(highlighted);
+            return;
+        };
+        if base == highlighted {
+            self.notify(Level::Info, format!("Pick a schema other than {}", base));
+            return;
         }
+        self.schema_compare_base = None;
+        self.schema_comparison =
+            crate::compare::compare_schemas(&self.openapi_spec, &base, &highlighted);
+    }
 
-        // Check for schemas not used in any endpoint
-        let mut unused_schemas = 0;
-        for schema_name in self.field_index.schemas.keys() {
-            let is_used = self.field_index.fields.values().any(|field_data| {
-                field_data.schemas.contains(schema_name) && !field_data.endpoints.is_empty()
-            });
-            if !is_used {
-                unused_schemas += 1;
+    /// Writes models for `names`, plus the schemas they reference when the
+    /// language needs them.
+    pub fn export_models(&mut self, language: Language, names: &[String], dir: &std::path::Path) {
+        let names = language.schemas_to_export(&self.openapi_spec, names);
+        if names.is_empty() {
+            self.notify(Level::Info, "No component schema to export");
+            return;
+        }
+        let files = language.generate(&self.openapi_spec, &names);
+        match crate::codegen::write_files(dir, &files) {
+            Ok(_) => {
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Exported {} {} type(s) to {}",
+                        names.len(),
+                        language.label(),
+                        dir.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(
+                    Level::Error,
+                    format!("{} export failed: {}", language.label(), e),
+                );
             }
         }
-        if unused_schemas > 0 {
-            self.validation_warnings.push(format!(
-                "{} schema(s) not used in any endpoint",
-                unused_schemas
-            ));
+    }
+
+    /// Runs impact analysis on the selected (or highlighted) field or schema.
+    pub fn open_impact_analysis(&mut self) {
+        if self.analyze_marked_impact() {
+            return;
         }
+        let target = match self.current_view {
+            View::Fields => self
+                .selected_field
+                .clone()
+                .or_else(|| {
+                    self.filtered_fields
+                        .get(self.field_list_state)
+                        .map(|s| s.to_string())
+                })
+                .map(ImpactTarget::Field),
+            View::Schemas | View::Graph => self.current_schema().map(ImpactTarget::Schema),
+            _ => None,
+        };
 
-        log::debug!(
-            "Spec validation complete: {} warning(s) found",
-            self.validation_warnings.len()
-        );
+        if let Some(target) = target {
+            self.impact_report = Some(crate::impact::analyze_impact(
+                &self.openapi_spec,
+                &self.field_index,
+                &target,
+            ));
+        }
     }
+
+    /// Lists what renaming the selected (or highlighted) field would touch
+    /// in the source document, and writes the JSON Patch plan to
+    /// `patch_path` when given.
+    pub fn plan_field_rename(&mut self, new_name: &str, patch_path: Option<&std::path::Path>) {
+        let field = match self.current_view {
+            View::Fields => self.selected_field.clone().or_else(|| {
+                self.filtered_fields
+                    .get(self.field_list_state)
+                    .map(|s| s.to_string())
+            }),
+            _ => None,
+        };
+        let Some(field) = field else {
+            self.notify(Level::Info, "Select a field in the Fields view to rename");
+            return;
+        };
+        let Some(spec_path) = self.file_path.clone() else {
+            self.notify(Level::Info, "Renames need a spec loaded from a file");
+            return;
+        };
+        let plan = std::fs::read_to_string(&spec_path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(serde_json::from_str(&content)?))
+            .and_then(|document| crate::rename::plan_rename(&document, &field, new_name));
+        let plan = match plan {
+            Ok(plan) => plan,
+            Err(e) => {
+                self.notify(
+                    Level::Error,
+                    format!("Rename of '{}' failed: {:#}", field, e),
+                );
+                return;
+            }
+        };
+        if let Some(patch_path) = patch_path {
+            let written = serde_json::to_string_pretty(&plan.patch())
+                .map_err(anyhow::Error::from)
+                .and_then(|patch| Ok(std::fs::write(patch_path, patch)?));
+            match written {
+                Ok(()) => {
+                    self.notify(
+                        Level::Success,
+                        format!(
+                            "Wrote {} patch operation(s) to {}",
+                            plan.sites.len(),
+                            patch_path.display()
+                        ),
+                    );
+                }
+                Err(e) => {
+                    self.notify(Level::Error, format!("Patch export failed: {}", e));
+                }
+            }
+        }
+        self.rename_plan = Some(plan);
+    }
+
+    /// Edits the description of the operation in the details popup, else
+    /// of the selected (or highlighted) field, starting from the current
+    /// text.
+    pub fn open_description_editor(&mut self) {
+        let target = if self.show_endpoint_details {
+            self.selected_endpoint_for_details
+                .clone()
+                .map(DescriptionTarget::Operation)
+        } else if self.current_view == View::Fields {
+            self.selected_field
+                .clone()
+                .or_else(|| {
+                    self.filtered_fields
+                        .get(self.field_list_state)
+                        .map(|s| s.to_string())
+                })
+                .map(DescriptionTarget::Field)
+        } else {
+            None
+        };
+        let Some(target) = target else {
+            self.notify(
+                Level::Info,
+                "Select a field or open an endpoint's details to edit a description",
+            );
+            return;
+        };
+        let input = match &target {
+            DescriptionTarget::Field(name) => self
+                .field_index
+                .fields
+                .get(name)
+                .and_then(|data| data.description.clone()),
+            DescriptionTarget::Operation(endpoint) => {
+                endpoint.split_once(' ').and_then(|(method, target)| {
+                    self.openapi_spec
+                        .path_item(target)?
+                        .operations
+                        .get(&method.to_lowercase())?
+                        .description
+                        .clone()
+                })
+            }
+        }
+        .unwrap_or_default();
+        self.description_editor = Some(DescriptionEditor {
+            target,
+            cursor: input.chars().count(),
+            input,
+        });
+    }
+
+    pub fn edit_description(&mut self, edit: impl FnOnce(&mut String, &mut usize)) {
+        if let Some(editor) = &mut self.description_editor {
+            edit(&mut editor.input, &mut editor.cursor);
+        }
+    }
+
+    /// Writes the edited description into the spec file, touching only
+    /// that value, then reloads the spec.
+    pub fn save_description(&mut self) {
+        let Some(editor) = self.description_editor.take() else {
+            return;
+        };
+        let Some(spec_path) = self.file_path.clone() else {
+            self.notify(Level::Info, "Descriptions can only be saved to a spec file");
+            return;
+        };
+        let saved = editor
+            .target
+            .pointer(&self.field_index)
+            .and_then(|pointer| {
+                let source = std::fs::read_to_string(&spec_path)?;
+                let updated = crate::writeback::set_description(&source, &pointer, &editor.input)?;
+                std::fs::write(&spec_path, updated)?;
+                Ok(())
+            });
+        match saved {
+            Ok(()) => {
+                self.request_reload();
+                self.notify(
+                    Level::Success,
+                    format!(
+                        "Saved the description of {} to {}",
+                        editor.target.label(),
+                        spec_path.display()
+                    ),
+                );
+            }
+            Err(e) => {
+                self.notify(
+                    Level::Error,
+                    format!(
+                        "Saving the description of {} failed: {:#}",
+                        editor.target.label(),
+                        e
+                    ),
+                );
+            }
+        }
+    }
+
+    pub fn toggle_sort_mode(&mut self) {
+        self.sort_mode = self.sort_mode.toggle();
+        self.update_filters();
+        self.notify(Level::Info, format!("Sorting: {}", self.sort_mode.label()));
+    }
+
+    /// Orders the filtered fields by the Fields table sort column, ties
+    /// keeping the search order.
+    fn sort_filtered_fields(&mut self) {
+        if self.field_sort == FieldColumn::Name {
+            if self.field_sort_descending {
+                self.filtered_fields.reverse();
+            }
+            return;
+        }
+        let mut rows: Vec<_> = std::mem::take(&mut self.filtered_fields)
+            .into_iter()
+            .map(|name| (self.field_columns(&name), name))
+            .collect();
+        rows.sort_by(|a, b| {
+            let ordering = a.0.compare(&b.0, self.field_sort);
+            if self.field_sort_descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        self.filtered_fields = rows.into_iter().map(|(_, name)| name).collect();
+    }
+
+    /// Sorts the Fields table by the next column (`step` 1) or the previous
+    /// one (-1), keeping the cursor on the same field.
+    pub fn cycle_field_sort(&mut self, step: isize) {
+        self.field_sort = self.field_sort.cycle(step);
+        self.resort_fields();
+    }
+
+    pub fn reverse_field_sort(&mut self) {
+        self.field_sort_descending = !self.field_sort_descending;
+        self.resort_fields();
+    }
+
+    fn resort_fields(&mut self) {
+        let current = self.filtered_fields.get(self.field_list_state).cloned();
+        self.update_filters();
+        if let Some(position) =
+            current.and_then(|current| self.filtered_fields.iter().position(|f| *f == current))
+        {
+            self.field_list_state = position;
+        }
+        self.notify(
+            Level::Info,
+            format!(
+                "Fields sorted by {} {}",
+                self.field_sort.title(),
+                if self.field_sort_descending {
+                    "↓"
+                } else {
+                    "↑"
+                }
+            ),
+        );
+    }
+
+    /// Scrolls the Fields table columns after the name by `step`.
+    pub fn scroll_field_columns(&mut self, step: isize) {
+        let max = FieldColumn::ALL.len() - 2;
+        self.field_column_offset = self
+            .field_column_offset
+            .saturating_add_signed(step)
+            .min(max);
+    }
+
+    pub fn toggle_endpoint_listing(&mut self) {
+        self.operation_ids_first = !self.operation_ids_first;
+        self.notify(
+            Level::Info,
+            if self.operation_ids_first {
+                "Endpoints: operationId first"
+            } else {
+                "Endpoints: path first"
+            }
+            .to_string(),
+        );
+    }
+
+    pub fn toggle_endpoint_fields_by_field(&mut self) {
+        self.endpoint_fields_by_field = !self.endpoint_fields_by_field;
+        self.notify(
+            Level::Info,
+            if self.endpoint_fields_by_field {
+                "Endpoint fields: one line per field"
+            } else {
+                "Endpoint fields: grouped by origin"
+            },
+        );
+    }
+
+    /// Switches the Endpoints list between the sorted paths and the tree of
+    /// path prefixes, keeping the cursor on the same path.
+    pub fn toggle_endpoint_tree(&mut self) {
+        let current = self.endpoint_at_cursor();
+        self.endpoint_tree = !self.endpoint_tree;
+        self.endpoint_list_state = current
+            .and_then(|endpoint| self.endpoint_position(&endpoint))
+            .unwrap_or(0);
+        self.notify(
+            Level::Info,
+            if self.endpoint_tree {
+                "Endpoints: grouped by path prefix"
+            } else {
+                "Endpoints: flat list"
+            }
+            .to_string(),
+        );
+    }
+
+    /// Rows of the Endpoints tree: paths grouped by prefix, then webhooks
+    /// and callbacks as they are listed.
+    pub fn endpoint_rows(&self) -> Vec<PathRow> {
+        let (events, paths): (Vec<Arc<str>>, Vec<Arc<str>>) = self
+            .filtered_endpoints
+            .iter()
+            .cloned()
+            .partition(|endpoint| is_event_target(endpoint));
+        let mut rows = crate::path_tree::path_rows(&paths, &self.collapsed_prefixes);
+        rows.extend(events.into_iter().map(|event| PathRow {
+            prefix: event.to_string(),
+            depth: 0,
+            label: event.to_string(),
+            target: Some(event),
+            descendants: 0,
+            collapsed: false,
+        }));
+        rows
+    }
+
+    fn endpoint_list_len(&self) -> usize {
+        if self.endpoint_tree {
+            self.endpoint_rows().len()
+        } else {
+            self.filtered_endpoints.len()
+        }
+    }
+
+    /// Path under the Endpoints list cursor, `None` on a tree row grouping
+    /// paths without being one.
+    pub fn endpoint_at_cursor(&self) -> Option<Arc<str>> {
+        if self.endpoint_tree {
+            self.endpoint_rows()
+                .into_iter()
+                .nth(self.endpoint_list_state)
+                .and_then(|row| row.target)
+        } else {
+            self.filtered_endpoints
+                .get(self.endpoint_list_state)
+                .cloned()
+        }
+    }
+
+    fn endpoint_position(&self, target: &str) -> Option<usize> {
+        if self.endpoint_tree {
+            self.endpoint_rows()
+                .iter()
+                .position(|row| row.target.as_deref() == Some(target))
+        } else {
+            self.filtered_endpoints.iter().position(|e| **e == *target)
+        }
+    }
+
+    fn in_endpoint_tree(&self) -> bool {
+        self.endpoint_tree
+            && self.current_view == View::Endpoints
+            && self.current_panel == Panel::Left
+    }
+
+    /// Right arrow in the Endpoints tree: expands the prefix under the
+    /// cursor, or moves to its first path.
+    pub fn expand_path_node(&mut self) {
+        if !self.in_endpoint_tree() {
+            return;
+        }
+        if let Some(row) = self.endpoint_rows().get(self.endpoint_list_state) {
+            if row.collapsed {
+                self.collapsed_prefixes.remove(&row.prefix);
+            } else if row.has_children() {
+                self.endpoint_list_state += 1;
+            }
+        }
+    }
+
+    /// Left arrow in the Endpoints tree: collapses the prefix under the
+    /// cursor, or moves to the prefix grouping it.
+    pub fn collapse_path_node(&mut self) {
+        if !self.in_endpoint_tree() {
+            return;
+        }
+        let rows = self.endpoint_rows();
+        let Some(row) = rows.get(self.endpoint_list_state) else {
+            return;
+        };
+        if row.has_children() && !row.collapsed {
+            self.collapsed_prefixes.insert(row.prefix.clone());
+        } else if let Some(position) = rows[..self.endpoint_list_state]
+            .iter()
+            .rposition(|parent| parent.depth < row.depth)
+        {
+            self.endpoint_list_state = position;
+        }
+    }
+
+    fn toggle_path_node(&mut self) {
+        if let Some(row) = self.endpoint_rows().get(self.endpoint_list_state) {
+            if row.collapsed {
+                self.collapsed_prefixes.remove(&row.prefix);
+            } else if row.has_children() {
+                self.collapsed_prefixes.insert(row.prefix.clone());
+            }
+        }
+    }
+
+    /// operationIds of a path, webhook or callback target, sorted by method.
+    pub fn operation_ids(&self, target: &str) -> Vec<&str> {
+        let Some(path_item) = self.openapi_spec.path_item(target) else {
+            return Vec::new();
+        };
+        let mut operations: Vec<(&String, &crate::parser::Operation)> =
+            path_item.operations.iter().collect();
+        operations.sort_by(|a, b| a.0.cmp(b.0));
+        operations
+            .into_iter()
+            .filter_map(|(_, operation)| operation.operation_id.as_deref())
+            .collect()
+    }
+
+    /// Selects the endpoint declaring `operation_id` in the Endpoints view
+    /// and opens its details.
+    pub fn select_operation(&mut self, operation_id: &str) {
+        let Some(endpoint) = self
+            .field_index
+            .endpoint_for_operation(operation_id)
+            .cloned()
+        else {
+            self.notify(
+                Level::Error,
+                format!("Unknown operationId '{}'", operation_id),
+            );
+            return;
+        };
+        self.jump_to_endpoint(&endpoint);
+        self.selected_endpoint_for_details = Some(endpoint);
+        self.show_endpoint_details = true;
+    }
+
+    /// Whether the focused panel is a detail list linking to another view.
+    /// Shows `message` as a toast in the status bar, kept in the
+    /// notification history.
+    pub fn notify(&mut self, level: Level, message: impl Into<String>) {
+        self.notifications.push(level, message);
+    }
+
+    /// Documentation of the selected item: the operation in the details
+    /// popup or Endpoints view (or its tag's), the schema in the Schemas
+    /// view or the tag on the Stats Tags chart, else the spec's.
+    pub fn documentation_link(&self) -> Option<&ExternalDocs> {
+        let spec = &self.openapi_spec;
+        let endpoint = if self.show_endpoint_details {
+            self.selected_endpoint_for_details.as_deref()
+        } else if self.current_view == View::Endpoints {
+            self.selected_endpoint.as_deref()
+        } else {
+            None
+        };
+        let item_docs = match (endpoint, &self.current_view) {
+            (Some(endpoint), _) => spec.operation_docs(endpoint),
+            (None, View::Schemas) => self
+                .current_schema()
+                .and_then(|name| self.field_index.schemas.get(&name))
+                .and_then(|schema| schema.external_docs.as_ref()),
+            (None, View::Stats) if self.stats_section == StatsSection::Tags => self
+                .stats_analysis
+                .tags
+                .get(self.stats_tag_cursor)
+                .and_then(|stats| spec.tag_docs(&stats.tag)),
+            _ => None,
+        };
+        item_docs.or(spec.external_docs.as_ref())
+    }
+
+    /// Opens [`App::documentation_link`] in the default browser.
+    pub fn open_documentation(&mut self) {
+        let Some(url) = self.documentation_link().map(|docs| docs.url.clone()) else {
+            self.notify(Level::Info, "No external documentation for this item");
+            return;
+        };
+        match open::that_detached(&url) {
+            Ok(()) => self.notify(Level::Success, format!("Opened {}", url)),
+            Err(e) => self.notify(Level::Error, format!("Cannot open {}: {}", url, e)),
+        }
+    }
+
+    pub fn toggle_spec_info(&mut self) {
+        self.show_spec_info = !self.show_spec_info;
+        self.text_scroll
+            .borrow_mut()
+            .remove(&ScrollTarget::SpecInfo);
+    }
+
+    pub fn toggle_workflows(&mut self) {
+        self.show_workflows = !self.show_workflows;
+        self.text_scroll
+            .borrow_mut()
+            .remove(&ScrollTarget::Workflows);
+    }
+
+    /// Text panel or popup Up/Down scroll: the help or endpoint details
+    /// popup when open, else the focused center panel when it is text.
+    pub fn scroll_target(&self) -> Option<ScrollTarget> {
+        if self.show_help {
+            return Some(ScrollTarget::Help);
+        }
+        if self.show_notifications {
+            return Some(ScrollTarget::Notifications);
+        }
+        if self.show_spec_info {
+            return Some(ScrollTarget::SpecInfo);
+        }
+        if self.show_workflows {
+            return Some(ScrollTarget::Workflows);
+        }
+        if self.show_endpoint_details && self.selected_endpoint_for_details.is_some() {
+            return Some(ScrollTarget::EndpointDetailsPopup);
+        }
+        if self.current_panel != Panel::Center {
+            return None;
+        }
+        match self.current_view {
+            View::Endpoints => Some(ScrollTarget::EndpointDetails),
+            View::Headers => Some(ScrollTarget::HeaderDetails),
+            View::Graph => Some(ScrollTarget::SchemaDependencies),
+            _ => None,
+        }
+    }
+
+    /// Scrolls `target` by `rows` (negative scrolls up), within the bounds
+    /// of its last render.
+    pub fn scroll_text(&mut self, target: ScrollTarget, rows: i32) {
+        let mut scrolls = self.text_scroll.borrow_mut();
+        let scroll = scrolls.entry(target).or_default();
+        scroll.offset = (scroll.offset as i32 + rows).clamp(0, scroll.max_offset as i32) as u16;
+    }
+
+    /// Scrolls `target` by a page of its visible rows.
+    pub fn scroll_text_page(&mut self, target: ScrollTarget, down: bool) {
+        let page = self
+            .text_scroll
+            .borrow()
+            .get(&target)
+            .map_or(1, |scroll| scroll.page.max(1)) as i32;
+        self.scroll_text(target, if down { page } else { -page });
+    }
+
+    pub fn has_detail_list(&self) -> bool {
+        matches!(
+            (&self.current_view, &self.current_panel),
+            (View::Fields, Panel::Center) | (View::Schemas, Panel::Right)
+        )
+    }
+
+    /// Items of the focused detail list: schema names or endpoint keys.
+    pub fn detail_items(&self) -> Vec<String> {
+        match (&self.current_view, &self.current_panel) {
+            (View::Fields, Panel::Center) => self
+                .selected_field
+                .as_ref()
+                .and_then(|field| self.get_field_info(field))
+                .map(|info| info.schemas)
+                .unwrap_or_default(),
+            (View::Schemas, Panel::Right) => self
+                .selected_schema
+                .as_ref()
+                .map(|schema| self.related_endpoints(schema))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Endpoints using a field of the schema, sorted.
+    pub fn related_endpoints(&self, schema_name: &str) -> Vec<String> {
+        let endpoints: std::collections::BTreeSet<String> = self
+            .field_index
+            .get_schema_fields(schema_name)
+            .iter()
+            .flat_map(|field| self.field_index.get_endpoints_for_field(field))
+            .collect();
+        endpoints.into_iter().collect()
+    }
+
+    /// Selects a schema in the Schemas view, clearing the search when it
+    /// hides the schema.
+    pub fn jump_to_schema(&mut self, name: &str) {
+        self.set_view(View::Schemas);
+        if !self.filtered_schemas.iter().any(|s| **s == *name) {
+            self.search_query.clear();
+            self.update_filters();
+        }
+        if let Some(position) = self.filtered_schemas.iter().position(|s| **s == *name) {
+            self.schema_list_state = position;
+        }
+        self.selected_schema = Some(name.to_string());
+        self.reset_schema_tree();
+    }
+
+    /// Rows of the selected schema's tree currently visible.
+    pub fn schema_tree(&self) -> Vec<TreeRow> {
+        self.selected_schema
+            .as_ref()
+            .and_then(|name| {
+                let schema = self.field_index.schemas.get(name)?;
+                Some(crate::schema_tree::tree_rows(
+                    name,
+                    schema,
+                    &self.field_index.schemas,
+                    &self.schema_tree_expanded,
+                ))
+            })
+            .unwrap_or_default()
+    }
+
+    fn in_schema_tree(&self) -> bool {
+        self.current_view == View::Schemas && self.current_panel == Panel::Center
+    }
+
+    /// Collapses the tree of a newly selected schema, except the sections
+    /// of the schemas it extends so inherited fields show at once.
+    fn reset_schema_tree(&mut self) {
+        self.schema_tree_expanded.clear();
+        self.schema_tree_cursor = 0;
+        let parents: Vec<String> = self
+            .schema_tree()
+            .into_iter()
+            .filter(|row| row.kind == NodeKind::Branch("extends") && row.expandable)
+            .map(|row| row.path)
+            .collect();
+        self.schema_tree_expanded.extend(parents);
+    }
+
+    /// Opens the component schema referenced by the tree row under the
+    /// cursor: a parent schema, a variant or a property's type.
+    pub fn open_tree_reference(&mut self) {
+        if !self.in_schema_tree() {
+            return;
+        }
+        let reference = self
+            .schema_tree()
+            .get(self.schema_tree_cursor)
+            .and_then(|row| row.reference.clone());
+        match reference {
+            Some(name) if self.field_index.schemas.contains_key(&name) => {
+                self.jump_to_schema(&name);
+                self.current_panel = Panel::Center;
+            }
+            _ => self.notify(Level::Info, "No schema referenced here"),
+        }
+    }
+
+    /// Right arrow in the schema tree: expands the node under the cursor,
+    /// or steps into it when it is already expanded.
+    pub fn expand_tree_node(&mut self) {
+        if !self.in_schema_tree() {
+            return;
+        }
+        if let Some(row) = self.schema_tree().get(self.schema_tree_cursor) {
+            if row.expanded {
+                self.schema_tree_cursor += 1;
+            } else if row.expandable {
+                self.schema_tree_expanded.insert(row.path.clone());
+            }
+        }
+    }
+
+    /// Left arrow in the schema tree: collapses the node under the cursor,
+    /// or moves to its parent.
+    pub fn collapse_tree_node(&mut self) {
+        if !self.in_schema_tree() {
+            return;
+        }
+        let rows = self.schema_tree();
+        let Some(row) = rows.get(self.schema_tree_cursor) else {
+            return;
+        };
+        if row.expanded {
+            self.schema_tree_expanded.remove(&row.path);
+        } else if let Some(parent) = crate::schema_tree::parent_path(&row.path) {
+            if let Some(position) = rows.iter().position(|r| r.path == parent) {
+                self.schema_tree_cursor = position;
+            }
+        }
+    }
+
+    fn toggle_tree_node(&mut self) {
+        if let Some(row) = self.schema_tree().get(self.schema_tree_cursor) {
+            if row.expanded {
+                self.schema_tree_expanded.remove(&row.path);
+            } else if row.expandable {
+                self.schema_tree_expanded.insert(row.path.clone());
+            }
+        }
+    }
+
+    /// Selects the path of an endpoint key (`"METHOD /path"`) or of a bare
+    /// path in the Endpoints view, clearing the search and method filter
+    /// when they hide it.
+    pub fn jump_to_endpoint(&mut self, endpoint: &str) {
+        let target = endpoint
+            .split_once(' ')
+            .map_or(endpoint, |(_, target)| target)
+            .to_string();
+        self.set_view(View::Endpoints);
+        if !self.filtered_endpoints.iter().any(|e| **e == *target) {
+            self.search_query.clear();
+            self.method_filter = None;
+            self.tag_filter = None;
+            self.update_filters();
+        }
+        self.collapsed_prefixes
+            .retain(|prefix| !crate::path_tree::contains(prefix, &target) || *prefix == target);
+        if let Some(position) = self.endpoint_position(&target) {
+            self.endpoint_list_state = position;
+        }
+        self.selected_endpoint = Some(target);
+    }
+
+    pub fn sensitive_label(&self, field_name: &str) -> Option<&str> {
+        self.sensitive_detector.classify(field_name)
+    }
+
+    pub fn open_sensitive_report(&mut self) {
+        let mut exposures = crate::sensitive::find_response_exposures(
+            &self.openapi_spec,
+            &self.field_index,
+            &self.sensitive_detector,
+        );
+        // Drop findings the team has suppressed in the workspace
+        exposures.retain(|exposure| !self.workspace.is_suppressed(&exposure.endpoint));
+        for exposure in &mut exposures {
+            exposure
+                .fields
+                .retain(|field| !self.workspace.suppressions.contains(&field.name));
+        }
+        exposures.retain(|exposure| !exposure.fields.is_empty());
+        self.sensitive_report = Some(exposures);
+    }
+
+    pub fn open_coverage_report(&mut self) {
+        self.coverage_report = Some(crate::coverage::analyze_coverage(
+            &self.openapi_spec,
+            &self.field_index,
+        ));
+    }
+
+    pub fn open_duplicate_report(&mut self) {
+        self.duplicate_report = Some(crate::duplicates::find_duplicate_schemas(
+            &self.openapi_spec,
+        ));
+    }
+
+    pub fn open_pagination_report(&mut self) {
+        self.pagination_report = Some(crate::pagination::analyze_pagination(&self.openapi_spec));
+    }
+
+    pub fn open_content_type_report(&mut self) {
+        self.content_type_report = Some(analyze_content_types(&self.field_index));
+    }
+
+    /// Checks a JSON payload file against the endpoint in the details popup.
+    pub fn validate_payload_file(&mut self, path: &std::path::Path, kind: &PayloadKind) {
+        let Some(endpoint) = self.selected_endpoint_for_details.clone() else {
+            self.notify(
+                Level::Info,
+                "Open an endpoint's details to validate a payload against it",
+            );
+            return;
+        };
+        let validation = std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(serde_json::from_str(&content)?))
+            .and_then(|payload| {
+                crate::validate::validate_payload(&self.openapi_spec, &endpoint, kind, &payload)
+            });
+        match validation {
+            Ok(validation) => {
+                self.payload_validation = Some(validation);
+            }
+            Err(e) => {
+                self.notify(
+                    Level::Error,
+                    format!("Validation of {} failed: {:#}", path.display(), e),
+                );
+            }
+        }
+    }
+
+    /// Endpoint in the details popup, else the path highlighted in the
+    /// Endpoints view.
+    fn workspace_target(&self) -> Option<String> {
+        if self.show_endpoint_details {
+            return self.selected_endpoint_for_details.clone();
+        }
+        if self.current_view == View::Endpoints {
+            return self.endpoint_at_cursor().map(|e| e.to_string());
+        }
+        None
+    }
+
+    pub fn toggle_bookmark(&mut self) {
+        // The details popup bookmarks its own endpoint
+        if !self.show_endpoint_details && self.bookmark_marked() {
+            return;
+        }
+        if let Some(target) = self.workspace_target() {
+            let message = if self.workspace.toggle_bookmark(&target) {
+                format!("Bookmarked {}", target)
+            } else {
+                format!("Removed bookmark {}", target)
+            };
+            self.notify(Level::Info, message);
+        }
+    }
+
+    pub fn is_bookmarked(&self, item: &str) -> bool {
+        self.workspace.bookmarks.contains(item)
+    }
+
+    pub fn save_current_filter(&mut self) {
+        if self.search_query.is_empty() {
+            return;
+        }
+        self.workspace.save_filter(&self.search_query);
+        self.notify(
+            Level::Success,
+            format!("Saved filter '{}'", self.search_query),
+        );
+    }
+
+    /// Cycles through saved filters, applying each as the search query.
+    pub fn apply_next_saved_filter(&mut self) {
+        if self.workspace.saved_filters.is_empty() {
+            self.notify(Level::Info, "No saved filters");
+            return;
+        }
+        let index = self.saved_filter_cursor % self.workspace.saved_filters.len();
+        self.apply_saved_filter(index);
+        self.saved_filter_cursor = index + 1;
+    }
+
+    /// Applies a saved filter's query; a saved view also switches to its
+    /// view, sort order and method filter.
+    pub fn apply_saved_filter(&mut self, index: usize) {
+        let Some(filter) = self.workspace.saved_filters.get(index).cloned() else {
+            return;
+        };
+        self.search_query = filter.query;
+        if let Some(view) = filter.view {
+            if let Ok(view) = crate::command::parse_view(&view) {
+                self.set_view(view);
+            }
+            if let Some(sort_mode) = filter
+                .sort
+                .and_then(|sort| SortMode::from_str(&sort, true).ok())
+            {
+                self.sort_mode = sort_mode;
+            }
+            self.method_filter = filter.method.and_then(|method| {
+                METHOD_FILTERS
+                    .iter()
+                    .find(|m| m.eq_ignore_ascii_case(&method))
+                    .copied()
+            });
+            self.notify(Level::Info, format!("View: {}", filter.name));
+        } else {
+            self.notify(Level::Info, format!("Filter: {}", filter.name));
+        }
+        self.update_filters();
+    }
+
+    /// Saves the search query, view, sort order and method filter as a
+    /// named view and writes the workspace file.
+    pub fn save_named_view(&mut self, name: &str) {
+        self.workspace.save_view(SavedFilter {
+            name: name.to_string(),
+            query: self.search_query.clone(),
+            view: Some(self.current_view.name().to_string()),
+            sort: Some(self.sort_mode.label().to_string()),
+            method: self.method_filter.map(str::to_string),
+        });
+        match self.workspace.save(&self.workspace_file) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!("Saved view '{}' to {}", name, self.workspace_file.display()),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Workspace export failed: {}", e));
+            }
+        }
+    }
+
+    pub fn open_lint_picker(&mut self) {
+        if self.lint_findings.is_empty() {
+            self.notify(Level::Success, "No lint findings");
+            return;
+        }
+        self.lint_picker = Some(0);
+    }
+
+    /// Shows the operation or path of a lint finding in the Endpoints view,
+    /// or its schema in the Schemas view. Meta-schema violations elsewhere
+    /// in the document (`/info`...) have nothing to show.
+    pub fn jump_to_finding(&mut self, index: usize) {
+        if let Some(finding) = self.lint_findings.get(index) {
+            let location = match finding.rule {
+                LintRule::MetaSchema => {
+                    match crate::meta_schema::pointer_target(&finding.location) {
+                        Some(target) => target,
+                        None => return,
+                    }
+                }
+                _ => finding.location.clone(),
+            };
+            if self.field_index.schemas.contains_key(&location) {
+                self.jump_to_schema(&location);
+            } else {
+                self.jump_to_endpoint(&location);
+            }
+        }
+    }
+
+    pub fn open_saved_view_picker(&mut self) {
+        if self.workspace.saved_filters.is_empty() {
+            self.notify(Level::Info, "No saved filters or views");
+            return;
+        }
+        self.saved_view_picker = Some(0);
+    }
+
+    pub fn export_workspace(&mut self) {
+        let path = &self.workspace_file;
+        match self.workspace.save(path) {
+            Ok(()) => {
+                self.notify(
+                    Level::Success,
+                    format!("Workspace saved to {}", path.display()),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Workspace export failed: {}", e));
+            }
+        }
+    }
+
+    pub fn import_workspace(&mut self) {
+        let path = &self.workspace_file;
+        match Workspace::load(path) {
+            Ok(workspace) => {
+                self.workspace.merge(workspace);
+                self.notify(
+                    Level::Success,
+                    format!("Workspace imported from {}", path.display()),
+                );
+            }
+            Err(e) => {
+                self.notify(Level::Error, format!("Workspace import failed: {:#}", e));
+            }
+        }
+    }
+
+    /// Opens the request form for the endpoint shown in the details popup.
+    pub fn open_try_it_out(&mut self) {
+        let Some(endpoint) = &self.selected_endpoint_for_details else {
+            return;
+        };
+        let Some((method, path)) = endpoint.split_once(' ') else {
+            return;
+        };
+        if let Some(operation) = crate::export::find_operation(&self.openapi_spec, endpoint) {
+            self.try_it_out = Some(TryItOut::new(RequestDraft::from_operation(
+                method,
+                path,
+                operation,
+                &self.field_index.schemas,
+                &self.base_url,
+            )));
+        }
+    }
+
+    /// Draft of a try-it-out request waiting to be sent.
+    pub fn take_try_it_out_request(&mut self) -> Option<RequestDraft> {
+        let try_it_out = self.try_it_out.as_mut().filter(|t| t.should_send)?;
+        try_it_out.should_send = false;
+        // Keep the edited base URL for the next request
+        self.base_url = try_it_out.draft.base_url.clone();
+        Some(try_it_out.draft.clone())
+    }
+
+    /// Shows a response, unless its form was closed while it was in flight.
+    pub fn apply_try_it_out_response(&mut self, response: Result<ExecutedResponse, String>) {
+        if let Some(try_it_out) = &mut self.try_it_out {
+            try_it_out.response = Some(response);
+        }
+    }
+
+    pub fn open_server_picker(&mut self) {
+        match self.openapi_spec.servers.as_deref() {
+            Some(servers) if !servers.is_empty() => {
+                self.server_picker =
+                    Some(ServerPicker::new(servers, self.active_server.unwrap_or(0)));
+            }
+            _ => {
+                self.notify(Level::Info, "No servers declared in the spec");
+            }
+        }
+    }
+
+    /// Makes the server under the picker cursor the target of requests.
+    pub fn apply_server_selection(&mut self) {
+        let Some(picker) = self.server_picker.take() else {
+            return;
+        };
+        if let Some(server) = self
+            .openapi_spec
+            .servers
+            .as_ref()
+            .and_then(|servers| servers.get(picker.cursor))
+        {
+            self.base_url =
+                crate::servers::resolve_server_url(server, &picker.values[picker.cursor]);
+            self.active_server = Some(picker.cursor);
+            self.notify(Level::Info, format!("Base URL: {}", self.base_url));
+        }
+    }
+
+    /// Adds another spec to the session without activating it.
+    pub fn add_spec(
+        &mut self,
+        openapi_spec: OpenApiSpec,
+        field_index: FieldIndex,
+        file_path: Option<std::path::PathBuf>,
+    ) {
+        let mut name = crate::multi_spec::spec_name(&openapi_spec, file_path.as_deref());
+        if self.specs.iter().any(|entry| entry.name == name) {
+            name = format!("{} ({})", name, self.specs.len() + 1);
+        }
+        self.specs.push(SpecEntry {
+            name,
+            file_path,
+            parked: Some((openapi_spec, field_index)),
+        });
+        self.rebuild_cross_index();
+    }
+
+    pub fn rebuild_cross_index(&mut self) {
+        let active_index = &self.field_index;
+        self.cross_index =
+            CrossSpecIndex::build(self.specs.iter().map(|entry| match &entry.parked {
+                Some((_, index)) => (entry.name.as_str(), index),
+                None => (entry.name.as_str(), active_index),
+            }));
+        self.cross_spec_stats = CrossSpecStats::build(self.spec_summaries(), &self.cross_index);
+    }
+
+    /// Counts of every loaded spec, in session order.
+    pub fn spec_summaries(&self) -> Vec<SpecSummary> {
+        self.specs
+            .iter()
+            .map(|entry| match &entry.parked {
+                Some((spec, index)) => SpecSummary::of(&entry.name, spec, index),
+                None => SpecSummary::of(&entry.name, &self.openapi_spec, &self.field_index),
+            })
+            .collect()
+    }
+
+    pub fn active_spec_name(&self) -> &str {
+        &self.specs[self.active_spec].name
+    }
+
+    pub fn open_spec_switcher(&mut self) {
+        if self.specs.len() < 2 {
+            self.notify(Level::Info, "Only one spec loaded");
+            return;
+        }
+        self.spec_switcher = Some(self.active_spec);
+    }
+
+    /// Parks the active spec and activates `target`, resetting selections.
+    pub fn switch_spec(&mut self, target: usize) {
+        if target == self.active_spec {
+            return;
+        }
+        let Some((spec, index)) = self
+            .specs
+            .get_mut(target)
+            .and_then(|entry| entry.parked.take())
+        else {
+            return;
+        };
+
+        let previous_spec = std::mem::replace(&mut self.openapi_spec, spec);
+        let previous_index = std::mem::replace(&mut self.field_index, index);
+        self.specs[self.active_spec].parked = Some((previous_spec, previous_index));
+        self.active_spec = target;
+        self.file_path = self.specs[target].file_path.clone();
+
+        self.selected_field = None;
+        self.selected_schema = None;
+        self.selected_endpoint = None;
+        self.selected_header = None;
+        self.navigation.clear();
+        self.selected_endpoint_for_details = None;
+        self.show_endpoint_details = false;
+        self.impact_report = None;
+        self.sensitive_report = None;
+        self.coverage_report = None;
+        self.duplicate_report = None;
+        self.pagination_report = None;
+        self.content_type_report = None;
+        self.lint_picker = None;
+        self.stats_drilldown = None;
+        self.payload_validation = None;
+        self.rename_plan = None;
+        self.description_editor = None;
+        self.schema_compare_base = None;
+        self.schema_comparison = None;
+        self.cross_spec_field = None;
+        self.field_list_state = 0;
+        self.schema_list_state = 0;
+        self.endpoint_list_state = 0;
+        self.header_list_state = 0;
+        self.response_row_state = 0;
+        (self.base_url, self.active_server) = initial_base_url(&self.openapi_spec);
+
+        self.search.rebuild(&self.field_index, &self.openapi_spec);
+        self.update_filters();
+        self.validate_spec();
+        self.notify(Level::Info, format!("Spec: {}", self.specs[target].name));
+    }
+
+    /// Shows where the selected (or highlighted) field is declared across
+    /// all loaded specs.
+    pub fn open_cross_spec_view(&mut self) {
+        self.cross_spec_field = self.selected_field.clone().or_else(|| {
+            self.filtered_fields
+                .get(self.field_list_state)
+                .map(|s| s.to_string())
+        });
+    }
+
+    pub fn open_command_palette(&mut self) {
+        self.command_palette = Some(CommandPalette::default());
+    }
+
+    /// Extends the palette input to the longest common completion.
+    pub fn complete_command(&mut self) {
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+        palette.completions = crate::command::complete(&palette.input);
+        if let Some(prefix) = crate::command::common_prefix(&palette.completions) {
+            if prefix.len() > palette.input.len() {
+                palette.input = prefix;
+            }
+        }
+    }
+
+    /// Steps through previous commands, newest first.
+    pub fn recall_command(&mut self, older: bool) {
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+        if self.command_history.is_empty() {
+            return;
+        }
+        let last = self.command_history.len() - 1;
+        palette.history_cursor = match (palette.history_cursor, older) {
+            (None, true) => Some(last),
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i < last => Some(i + 1),
+            (Some(_), false) => None,
+        };
+        palette.input = palette
+            .history_cursor
+            .map(|i| self.command_history[i].clone())
+            .unwrap_or_default();
+        palette.completions.clear();
+    }
+
+    /// Parses and runs the palette input. Invalid commands keep the palette
+    /// open with the error shown.
+    pub fn submit_command(&mut self) {
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+        let input = palette.input.trim().to_string();
+        match crate::command::parse_command(&input) {
+            Ok(command) => {
+                if self.command_history.last() != Some(&input) {
+                    self.command_history.push(input);
+                    if self.command_history.len() > COMMAND_HISTORY_LIMIT {
+                        self.command_history.remove(0);
+                    }
+                }
+                self.command_palette = None;
+                self.run_command(command);
+            }
+            Err(e) => {
+                palette.error = Some(e.to_string());
+                palette.completions.clear();
+            }
+        }
+    }
+
+    pub fn run_command(&mut self, command: PaletteCommand) {
+        match command {
+            PaletteCommand::View(view) => self.set_view(view),
+            PaletteCommand::Filter {
+                query,
+                field_type,
+                flags,
+            } => {
+                let mut qualifiers: Vec<String> = flags
+                    .iter()
+                    .map(|(flag, value)| format!("{}:{}", flag.name(), value))
+                    .collect();
+                if let Some(t) = &field_type {
+                    qualifiers.insert(0, format!("type {}", t));
+                }
+                self.notify(
+                    Level::Info,
+                    if qualifiers.is_empty() && query.is_empty() {
+                        "Filter cleared".to_string()
+                    } else if qualifiers.is_empty() {
+                        format!("Filter: {}", query)
+                    } else {
+                        format!("Filter: {} ({})", query, qualifiers.join(", "))
+                    },
+                );
+                self.search_query = query;
+                self.field_type_filter = field_type;
+                self.field_flag_filters = flags;
+                self.update_filters();
+            }
+            PaletteCommand::Tag(tag) => {
+                self.filter_by_tag(tag);
+                self.set_view(View::Endpoints);
+            }
+            PaletteCommand::Sort(sort_mode) => {
+                if self.sort_mode != sort_mode {
+                    self.toggle_sort_mode();
+                }
+            }
+            PaletteCommand::ExportCsv(path) => self.export_parameter_matrix(&path),
+            PaletteCommand::ExportStats(path) => self.export_stats(&path),
+            PaletteCommand::ExportErrors(path) => self.export_error_skeleton(&path),
+            PaletteCommand::ExportUnused(path) => self.export_unused_components(&path),
+            PaletteCommand::ExportClusters(path) => self.export_field_clusters(&path),
+            PaletteCommand::ExportGraph(path) => self.export_graph(&path),
+            PaletteCommand::ExportMarked(path) => self.export_marked(&path),
+            PaletteCommand::ExportModels(language, dir) => {
+                let names = crate::codegen::all_schema_names(&self.openapi_spec);
+                self.export_models(language, &names, &dir);
+            }
+            PaletteCommand::Open(path) => self.request_open(path),
+            PaletteCommand::Operation(operation_id) => self.select_operation(&operation_id),
+            PaletteCommand::ValidatePayload(path, kind) => self.validate_payload_file(&path, &kind),
+            PaletteCommand::Reload => self.request_reload(),
+            PaletteCommand::Rename(new_name, patch_path) => {
+                self.plan_field_rename(&new_name, patch_path.as_deref())
+            }
+            PaletteCommand::SaveView(name) => self.save_named_view(&name),
+            PaletteCommand::Info => self.toggle_spec_info(),
+            PaletteCommand::Help => self.show_help = true,
+            PaletteCommand::Quit => self.should_quit = true,
+        }
+    }
+
+    /// Opens the file browser in the directory of the active spec.
+    pub fn open_file_browser(&mut self) {
+        let dir = self
+            .file_path
+            .as_deref()
+            .and_then(|path| path.parent())
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map(std::path::Path::to_path_buf)
+            .unwrap_or_else(|| ".".into());
+        match FileBrowser::open(dir) {
+            Ok(browser) => self.file_browser = Some(browser),
+            Err(e) => self.notify(Level::Error, format!("Cannot list directory: {}", e)),
+        }
+    }
+
+    /// Opens the URL typed in the browser's prompt, else enters the
+    /// directory under the cursor or opens the spec file under it.
+    pub fn select_browser_entry(&mut self) {
+        let Some(browser) = &mut self.file_browser else {
+            return;
+        };
+        if let Some(url) = browser.url.take() {
+            let url = url.trim().to_string();
+            if url.starts_with("http://") || url.starts_with("https://") {
+                self.file_browser = None;
+                self.request_open_url(url);
+            } else {
+                self.notify(Level::Error, "Enter an http:// or https:// URL");
+                if let Some(browser) = &mut self.file_browser {
+                    browser.url = Some(url);
+                }
+            }
+            return;
+        }
+        let Some(entry) = browser.selected().cloned() else {
+            return;
+        };
+        if entry.is_dir {
+            if let Err(e) = browser.enter(&entry.path) {
+                self.notify(
+                    Level::Error,
+                    format!("Cannot list {}: {}", entry.path.display(), e),
+                );
+            }
+        } else {
+            self.file_browser = None;
+            self.request_open(entry.path);
+        }
+    }
+
+    /// Schedules loading `path` (picked up by the UI loop, like reloads).
+    pub fn request_open(&mut self, path: std::path::PathBuf) {
+        self.is_loading = true;
+        self.loading_message = format!("Opening {}...", path.display());
+        self.pending_open = Some(path);
+    }
+
+    /// Schedules downloading and loading the spec at `url`.
+    pub fn request_open_url(&mut self, url: String) {
+        self.is_loading = true;
+        self.loading_message = format!("Downloading {}...", url);
+        self.pending_open_url = Some(url);
+    }
+
+    /// Queues the reload of changed files of a watched directory, once no
+    /// change has come for [`crate::spec_dir::REFRESH_DEBOUNCE`].
+    pub fn queue_spec_refresh(&mut self, paths: Vec<std::path::PathBuf>) {
+        self.pending_refreshes.extend(paths);
+        self.refresh_due = Some(std::time::Instant::now() + crate::spec_dir::REFRESH_DEBOUNCE);
+    }
+
+    /// Takes the pending reload, `:open` or URL, then the pending directory
+    /// refreshes, to be run with [`LoadRequest::run`]. Opening a spec that
+    /// is already loaded re-reads the active one and switches to any other
+    /// right away.
+    pub fn take_load_request(&mut self) -> Option<LoadRequest> {
+        let (kind, path) = if self.should_reload {
+            self.should_reload = false;
+            let Some(path) = self.file_path.clone() else {
+                self.notify(Level::Error, "No file path available for reload");
+                self.is_loading = false;
+                self.loading_message.clear();
+                return None;
+            };
+            (LoadKind::Reload, path)
+        } else if let Some(path) = self.pending_open.take() {
+            return self.open_request(path, None);
+        } else if let Some(url) = self.pending_open_url.take() {
+            let path = crate::remote::stored_path(&crate::cache::SpecCache::default_dir(), &url);
+            return self.open_request(path, Some(url));
+        } else {
+            return self.take_refresh_request();
+        };
+        Some(self.load_request(kind, path))
+    }
+
+    /// Request opening `path`, downloaded from `url` when given, unless it
+    /// is already loaded.
+    fn open_request(
+        &mut self,
+        path: std::path::PathBuf,
+        url: Option<String>,
+    ) -> Option<LoadRequest> {
+        let canonical = path.canonicalize().ok();
+        if let Some(loaded) = self.specs.iter().position(|entry| {
+            canonical.is_some()
+                && entry.file_path.as_ref().and_then(|p| p.canonicalize().ok()) == canonical
+        }) {
+            if loaded != self.active_spec {
+                self.is_loading = false;
+                self.loading_message.clear();
+                self.switch_spec(loaded);
+                return None;
+            }
+            return Some(self.load_request(LoadKind::Reload, path));
+        }
+        Some(LoadRequest {
+            url,
+            ..self.load_request(LoadKind::Open, path)
+        })
+    }
+
+    fn load_request(&self, kind: LoadKind, path: std::path::PathBuf) -> LoadRequest {
+        LoadRequest {
+            kind,
+            path,
+            cache: self.cache.clone(),
+            max_depth: self.field_index.max_depth,
+            lenient: self.lenient,
+            url: None,
+        }
+    }
+
+    /// Next changed file of a watched directory; deleted files are dropped
+    /// from the session on the way.
+    fn take_refresh_request(&mut self) -> Option<LoadRequest> {
+        if self
+            .refresh_due
+            .is_some_and(|due| std::time::Instant::now() < due)
+        {
+            return None;
+        }
+        self.refresh_due = None;
+        while let Some(path) = self.pending_refreshes.pop_first() {
+            if path.exists() {
+                self.is_loading = true;
+                self.loading_message = format!("Refreshing {}...", path.display());
+                return Some(self.load_request(LoadKind::Refresh, path));
+            }
+            self.remove_spec_file(&path);
+        }
+        None
+    }
+
+    /// Replaces the spec loaded from `path` (parked or active) with a fresh
+    /// load, or adds it when the file is new.
+    fn refresh_spec(&mut self, path: std::path::PathBuf, spec: OpenApiSpec, index: FieldIndex) {
+        self.spec_dir_failures.remove(&path);
+        let Some(position) = self
+            .specs
+            .iter()
+            .position(|entry| entry.file_path.as_ref() == Some(&path))
+        else {
+            self.add_spec(spec, index, Some(path));
+            let name = self.specs[self.specs.len() - 1].name.clone();
+            self.notify(Level::Info, format!("Added {}", name));
+            return;
+        };
+        let name = crate::multi_spec::spec_name(&spec, Some(&path));
+        if position == self.active_spec {
+            self.openapi_spec = spec;
+            self.field_index = index;
+            self.search.rebuild(&self.field_index, &self.openapi_spec);
+            self.update_filters();
+            self.validate_spec();
+        } else {
+            self.specs[position].parked = Some((spec, index));
+        }
+        self.specs[position].name = name;
+        self.rebuild_cross_index();
+        let message = format!("Refreshed {}", self.specs[position].name);
+        self.notify(Level::Info, message);
+    }
+
+    /// Drops the spec of a deleted file, switching away first when it is
+    /// the active one. The last spec stays loaded.
+    pub fn remove_spec_file(&mut self, path: &std::path::Path) {
+        self.spec_dir_failures.remove(path);
+        let Some(position) = self
+            .specs
+            .iter()
+            .position(|entry| entry.file_path.as_deref() == Some(path))
+        else {
+            return;
+        };
+        if self.specs.len() == 1 {
+            self.notify(
+                Level::Info,
+                format!("{} was deleted; keeping its last version", path.display()),
+            );
+            return;
+        }
+        if position == self.active_spec {
+            self.switch_spec(if position == 0 { 1 } else { position - 1 });
+        }
+        let removed = self.specs.remove(position);
+        if position < self.active_spec {
+            self.active_spec -= 1;
+        }
+        if let Some(cursor) = &mut self.spec_switcher {
+            *cursor = (*cursor).min(self.specs.len() - 1);
+        }
+        self.rebuild_cross_index();
+        self.notify(Level::Info, format!("Removed {}", removed.name));
+    }
+
+    /// Swaps in a finished load: the active spec for a reload, a new spec
+    /// for `:open`. Bookmarks and notes are kept: they live in the
+    /// workspace, which is shared by every loaded spec.
+    pub fn apply_spec_load(&mut self, load: SpecLoad) {
+        self.is_loading = false;
+        self.loading_message.clear();
+        if load.kind != LoadKind::Refresh {
+            self.failed_load = None;
+        }
+        match (load.kind, load.result) {
+            (_, Ok(None)) => {
+                let message = format!("{} not modified", self.active_spec_name());
+                self.notify(Level::Info, message);
+            }
+            (LoadKind::Reload, Ok(Some((spec, index)))) => {
+                self.openapi_spec = spec;
+                self.field_index = index;
+                self.search.rebuild(&self.field_index, &self.openapi_spec);
+                self.update_filters();
+                self.validate_spec(); // Validate after reload
+                self.specs[self.active_spec].name =
+                    crate::multi_spec::spec_name(&self.openapi_spec, self.file_path.as_deref());
+                self.rebuild_cross_index();
+                let message = match self.lint_findings.len() {
+                    0 => format!("Reloaded {}", self.active_spec_name()),
+                    findings => format!(
+                        "Reloaded {} ({} lint finding(s), L to list)",
+                        self.active_spec_name(),
+                        findings
+                    ),
+                };
+                self.notify(Level::Success, message);
+            }
+            (LoadKind::Open, Ok(Some((spec, index)))) => {
+                self.add_spec(spec, index, Some(load.path));
+                self.switch_spec(self.specs.len() - 1);
+            }
+            (LoadKind::Refresh, Ok(Some((spec, index)))) => {
+                self.refresh_spec(load.path, spec, index)
+            }
+            // Often a file saved mid-edit: listed until the next save fixes it
+            (LoadKind::Refresh, Err(error)) => {
+                self.notify(
+                    Level::Error,
+                    format!("Cannot refresh {}: {}", load.path.display(), error),
+                );
+                self.spec_dir_failures
+                    .insert(load.path, format!("{:#}", error));
+            }
+            (kind, Err(error)) => {
+                self.notify(
+                    Level::Error,
+                    match kind {
+                        LoadKind::Reload => format!("Failed to reload: {}", error),
+                        LoadKind::Open | LoadKind::Refresh => {
+                            format!("Failed to open {}: {}", load.path.display(), error)
+                        }
+                    },
+                );
+                if error.is::<ParseError>() {
+                    self.failed_load = Some(FailedLoad {
+                        kind,
+                        path: load.path,
+                        error,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Runs the failed load again, e.g. once the document is fixed.
+    pub fn retry_failed_load(&mut self) {
+        let Some(failed) = self.failed_load.take() else {
+            return;
+        };
+        match failed.kind {
+            LoadKind::Reload => self.request_reload(),
+            LoadKind::Open => self.request_open(failed.path),
+            LoadKind::Refresh => self.queue_spec_refresh(vec![failed.path]),
+        }
+    }
+
+    /// Runs pending loads in place, for callers without an event loop.
+    pub async fn run_pending_loads(&mut self) {
+        while let Some(request) = self.take_load_request() {
+            let load = request.run(&crate::progress::no_progress).await;
+            self.apply_spec_load(load);
+        }
+    }
+
+    pub fn request_reload(&mut self) {
+        if self.file_path.is_none() {
+            self.notify(
+                Level::Info,
+                "Nothing to reload: the spec was not read from a file (piped on stdin?)",
+            );
+            return;
+        }
+        self.should_reload = true;
+        self.is_loading = true;
+        self.loading_message = "Reloading OpenAPI specification...".to_string();
+    }
+
+    /// Meta-schema violations of the active spec's file; specs without one
+    /// (piped on stdin) are not checked.
+    fn meta_schema_violations(&mut self) -> Vec<LintFinding> {
+        let Some(path) = &self.file_path else {
+            return Vec::new();
+        };
+        match crate::meta_schema::validate_file(path) {
+            Ok(violations) => violations,
+            Err(e) => {
+                self.notify(Level::Error, format!("Strict validation skipped: {:#}", e));
+                Vec::new()
+            }
+        }
+    }
+
+    /// Rebuilds what derives from the whole active spec: validation
+    /// warnings, lint findings, the Stats analyses and the response links.
+    pub fn validate_spec(&mut self) {
+        self.validation_warnings =
+            crate::lint::parse_warning_diagnostics(&self.openapi_spec, &self.lint_config);
+        self.validation_warnings.extend(crate::lint::lint_spec(
+            &self.openapi_spec,
+            &self.field_index,
+            &self.lint_config,
+        ));
+        self.lint_findings = crate::lint::lint_findings(&self.openapi_spec, &self.lint_config);
+        if self.lint_config.strict {
+            let violations = self.meta_schema_violations();
+            for violation in violations {
+                let diagnostic = crate::lint::finding_diagnostic(
+                    &self.openapi_spec,
+                    &self.lint_config,
+                    &violation,
+                );
+                if !diagnostic.suppressed {
+                    self.lint_findings.push(violation);
+                }
+                self.validation_warnings.push(diagnostic);
+            }
+        }
+
+        self.stats_analysis =
+            StatsAnalysis::build(&self.openapi_spec, &self.field_index, &self.criticality);
+        self.workflow_links =
+            crate::workflows::collect_links(&self.openapi_spec, &self.field_index);
+
+        log::debug!(
+            "Spec validation complete: {} warning(s) found",
+            self.validation_warnings.len()
+        );
+    }
+}
+
+/// Whether `method` (any case) passes the method filter.
+fn matches_method(filter: Option<&str>, method: &str) -> bool {
+    filter.is_none_or(|filter| filter.eq_ignore_ascii_case(method))
+}
+
+fn matches_tag(filter: Option<&str>, operation: &crate::parser::Operation) -> bool {
+    filter.is_none_or(|tag| crate::stats::operation_has_tag(operation, tag))
 }
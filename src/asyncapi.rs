@@ -0,0 +1,333 @@
+//! AsyncAPI 2.x documents translated to OpenAPI on load, so message
+//! payload fields are indexed with the REST ones. Messages the application
+//! sends (`subscribe` operations) become webhooks named after their channel,
+//! and messages it receives (`publish`) `POST /<channel>` operations; each
+//! message payload is the request body, under its content type.
+
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Content type of messages declaring none, without `defaultContentType`
+const DEFAULT_CONTENT_TYPE: &str = "application/json";
+
+/// Local `$ref`s followed at most this deep, which also stops cycles
+const MAX_REF_DEPTH: usize = 8;
+
+/// The `asyncapi` version of an AsyncAPI document, None for other
+/// documents.
+pub fn version(document: &Value) -> Option<&str> {
+    document.get("asyncapi").and_then(Value::as_str)
+}
+
+/// OpenAPI 3.1 equivalent of an AsyncAPI 2.x `document`: info, tags,
+/// servers and component schemas are kept, channels become operations.
+/// Security schemes and the other components have no equivalent and are
+/// dropped, message components being inlined where used.
+pub fn to_openapi(document: &Value) -> Value {
+    let default_content_type = document
+        .get("defaultContentType")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_CONTENT_TYPE);
+    let mut paths = Map::new();
+    let mut webhooks = Map::new();
+    for (channel, item) in document
+        .get("channels")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+    {
+        let parameters = channel_parameters(document, item);
+        for (action, targets) in [("subscribe", &mut webhooks), ("publish", &mut paths)] {
+            let Some(operation) = item.get(action).map(|op| resolve(document, op)) else {
+                continue;
+            };
+            let operation = translate_operation(
+                document,
+                operation,
+                parameters.clone(),
+                default_content_type,
+            );
+            let target = if action == "publish" {
+                format!("/{}", channel.trim_start_matches('/'))
+            } else {
+                channel.clone()
+            };
+            targets.insert(target, json!({ "post": operation }));
+        }
+    }
+
+    let mut openapi = Map::new();
+    openapi.insert("openapi".to_string(), json!("3.1.0"));
+    openapi.insert(
+        "info".to_string(),
+        document.get("info").cloned().unwrap_or_else(|| json!({})),
+    );
+    if let Some(servers) = document.get("servers").and_then(Value::as_object) {
+        openapi.insert("servers".to_string(), translate_servers(servers));
+    }
+    if let Some(tags) = document.get("tags") {
+        openapi.insert("tags".to_string(), tags.clone());
+    }
+    openapi.insert("paths".to_string(), Value::Object(paths));
+    openapi.insert("webhooks".to_string(), Value::Object(webhooks));
+    if let Some(schemas) = document.pointer("/components/schemas") {
+        openapi.insert("components".to_string(), json!({ "schemas": schemas }));
+    }
+    Value::Object(openapi)
+}
+
+/// `value`, or what its local `$ref` points to.
+fn resolve<'a>(document: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    for _ in 0..MAX_REF_DEPTH {
+        let Some(target) = value
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| document.pointer(pointer))
+        else {
+            break;
+        };
+        value = target;
+    }
+    value
+}
+
+/// Channel parameters (`orders/{orderId}`) as OpenAPI path parameters.
+fn channel_parameters(document: &Value, channel: &Value) -> Vec<Value> {
+    channel
+        .get("parameters")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(name, parameter)| {
+            let parameter = resolve(document, parameter);
+            let mut translated = json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": parameter.get("schema").cloned().unwrap_or_else(|| json!({"type": "string"})),
+            });
+            if let Some(description) = parameter.get("description") {
+                translated["description"] = description.clone();
+            }
+            translated
+        })
+        .collect()
+}
+
+fn translate_operation(
+    document: &Value,
+    operation: &Value,
+    mut parameters: Vec<Value>,
+    default_content_type: &str,
+) -> Value {
+    let mut translated = Map::new();
+    for key in ["operationId", "summary", "description", "externalDocs"] {
+        if let Some(value) = operation.get(key) {
+            translated.insert(key.to_string(), value.clone());
+        }
+    }
+    // AsyncAPI tags are objects, OpenAPI operations only name them
+    let tags: Vec<Value> = operation
+        .get("tags")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|tag| tag.get("name").cloned())
+        .collect();
+    if !tags.is_empty() {
+        translated.insert("tags".to_string(), Value::Array(tags));
+    }
+
+    let messages: Vec<&Value> = match operation.get("message").map(|m| resolve(document, m)) {
+        Some(message) => match message.get("oneOf").and_then(Value::as_array) {
+            Some(variants) => variants.iter().map(|m| resolve(document, m)).collect(),
+            None => vec![message],
+        },
+        None => Vec::new(),
+    };
+    let mut payloads: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    for message in &messages {
+        let content_type = message
+            .get("contentType")
+            .and_then(Value::as_str)
+            .unwrap_or(default_content_type);
+        if let Some(payload) = message.get("payload") {
+            payloads
+                .entry(content_type)
+                .or_default()
+                .push(payload_schema(document, payload));
+        }
+        for header in message_headers(document, message) {
+            let known = parameters.iter().any(|p| p["name"] == header["name"]);
+            if !known {
+                parameters.push(header);
+            }
+        }
+    }
+    // Messages sharing a content type are alternatives
+    let content: Map<String, Value> = payloads
+        .into_iter()
+        .map(|(content_type, mut schemas)| {
+            let schema = match schemas.len() {
+                1 => schemas.remove(0),
+                _ => json!({ "oneOf": schemas }),
+            };
+            (content_type.to_string(), json!({ "schema": schema }))
+        })
+        .collect();
+
+    if !parameters.is_empty() {
+        translated.insert("parameters".to_string(), Value::Array(parameters));
+    }
+    if !content.is_empty() {
+        translated.insert("requestBody".to_string(), json!({ "content": content }));
+    }
+    translated.insert("responses".to_string(), json!({}));
+    Value::Object(translated)
+}
+
+/// A payload referencing a component schema keeps its `$ref`, alongside a
+/// copy of the schema: the reference tracks schema usage, while the copied
+/// properties trace the payload fields to the channel.
+fn payload_schema(document: &Value, payload: &Value) -> Value {
+    let target = resolve(document, payload);
+    match (payload.get("$ref"), target.as_object()) {
+        (Some(reference), Some(schema)) if !std::ptr::eq(target, payload) => {
+            let mut schema = schema.clone();
+            schema.insert("$ref".to_string(), reference.clone());
+            Value::Object(schema)
+        }
+        _ => payload.clone(),
+    }
+}
+
+/// Properties of a message's `headers` schema as header parameters.
+fn message_headers(document: &Value, message: &Value) -> Vec<Value> {
+    let Some(headers) = message.get("headers").map(|h| resolve(document, h)) else {
+        return Vec::new();
+    };
+    let required = headers.get("required").and_then(Value::as_array);
+    headers
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(name, schema)| {
+            json!({
+                "name": name,
+                "in": "header",
+                "required": required.is_some_and(|required| required.iter().any(|r| r == name)),
+                "schema": schema,
+            })
+        })
+        .collect()
+}
+
+/// Named AsyncAPI servers as an OpenAPI list; broker URLs without a scheme
+/// get their protocol as one (`kafka://broker:9092`).
+fn translate_servers(servers: &Map<String, Value>) -> Value {
+    let servers = servers
+        .iter()
+        .filter_map(|(name, server)| {
+            let url = server.get("url")?.as_str()?;
+            let url = match server.get("protocol").and_then(Value::as_str) {
+                Some(protocol) if !url.contains("://") => format!("{}://{}", protocol, url),
+                _ => url.to_string(),
+            };
+            let description = match server.get("description").and_then(Value::as_str) {
+                Some(description) => format!("{}: {}", name, description),
+                None => name.clone(),
+            };
+            Some(json!({"url": url, "description": description}))
+        })
+        .collect();
+    Value::Array(servers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    const ORDERS: &str = r##"{
+        "asyncapi": "2.6.0",
+        "info": {"title": "Order events", "version": "1.0"},
+        "defaultContentType": "application/json",
+        "servers": {"production": {"url": "broker.example.com:9092", "protocol": "kafka"}},
+        "channels": {
+            "orders/{orderId}/created": {
+                "parameters": {"orderId": {"$ref": "#/components/parameters/OrderId"}},
+                "subscribe": {
+                    "operationId": "onOrderCreated",
+                    "tags": [{"name": "orders"}],
+                    "message": {"$ref": "#/components/messages/OrderCreated"}
+                }
+            },
+            "payments": {
+                "publish": {
+                    "message": {"oneOf": [
+                        {"payload": {"type": "object", "properties": {"order_id": {"type": "string"}, "amount": {"type": "number"}}}},
+                        {"contentType": "application/avro", "payload": {"type": "object", "properties": {"refund_id": {"type": "string"}}}}
+                    ]}
+                }
+            }
+        },
+        "components": {
+            "messages": {"OrderCreated": {
+                "headers": {"type": "object", "required": ["trace_id"], "properties": {"trace_id": {"type": "string"}}},
+                "payload": {"$ref": "#/components/schemas/Order"}
+            }},
+            "parameters": {"OrderId": {"description": "Order identifier", "schema": {"type": "string"}}},
+            "schemas": {"Order": {"type": "object", "properties": {
+                "order_id": {"type": "string"},
+                "total": {"type": "number"}
+            }}}
+        }
+    }"##;
+
+    #[test]
+    fn test_channels_become_operations() {
+        let document: Value = serde_json::from_str(ORDERS).unwrap();
+        assert_eq!(version(&document), Some("2.6.0"));
+        let openapi = to_openapi(&document);
+
+        assert_eq!(openapi["openapi"], "3.1.0");
+        assert_eq!(
+            openapi["servers"][0]["url"],
+            "kafka://broker.example.com:9092"
+        );
+
+        let created = &openapi["webhooks"]["orders/{orderId}/created"]["post"];
+        assert_eq!(created["operationId"], "onOrderCreated");
+        assert_eq!(created["tags"], json!(["orders"]));
+        let parameters: Vec<(&str, &str)> = created["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p["name"].as_str().unwrap(), p["in"].as_str().unwrap()))
+            .collect();
+        assert_eq!(parameters, [("orderId", "path"), ("trace_id", "header")]);
+        let payload = &created["requestBody"]["content"]["application/json"]["schema"];
+        assert_eq!(payload["$ref"], "#/components/schemas/Order");
+        assert!(payload["properties"]["order_id"].is_object());
+
+        let payments = &openapi["paths"]["/payments"]["post"]["requestBody"]["content"];
+        assert!(payments["application/json"]["schema"]["properties"]["amount"].is_object());
+        assert!(payments["application/avro"]["schema"]["properties"]["refund_id"].is_object());
+    }
+
+    #[test]
+    fn test_payload_fields_are_traced_to_channels() {
+        let spec = parse_openapi_content(ORDERS).unwrap();
+        let index = build_field_index(&spec);
+        let mut endpoints = index.get_endpoints_for_field("order_id");
+        endpoints.sort();
+        assert_eq!(
+            endpoints,
+            ["POST /payments", "POST webhook:orders/{orderId}/created"]
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! Bundling a spec spread over several files into one self-contained
+//! document, and the reverse: splitting a spec into one file of paths per
+//! tag next to a root document keeping the components.
+
+use crate::parser::{pointer_segment, unescape_pointer, HTTP_METHODS};
+use anyhow::{anyhow, Context, Result};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Root document written by [`split_document`]
+pub const ROOT_FILE: &str = "openapi.json";
+
+/// Component sections a bundled reference can be stored in
+const COMPONENT_SECTIONS: &[&str] = &[
+    "schemas",
+    "parameters",
+    "requestBodies",
+    "responses",
+    "headers",
+    "examples",
+    "links",
+    "callbacks",
+    "securitySchemes",
+];
+
+/// What a value found under `key` is, from what its parent is: the
+/// component section a `$ref` there would point into, or `pathItem`,
+/// `paths`... for the other places that matter.
+fn child_kind(parent: &str, key: &str) -> &'static str {
+    match (parent, key) {
+        // Everything below a schema is a schema, whatever the property names
+        ("schemas", _) => "schemas",
+        ("examples", _) => "examples",
+        ("root", "paths" | "webhooks") => "paths",
+        ("root", "components") => "components",
+        ("paths", _) => "pathItem",
+        ("components", section) => COMPONENT_SECTIONS
+            .iter()
+            .find(|known| **known == section)
+            .copied()
+            .unwrap_or("other"),
+        (_, "schema" | "items" | "properties" | "additionalProperties" | "not") => "schemas",
+        (_, "allOf" | "oneOf" | "anyOf") => "schemas",
+        (_, "parameters") => "parameters",
+        (_, "requestBody") => "requestBodies",
+        (_, "responses") => "responses",
+        (_, "headers") => "headers",
+        (_, "examples") => "examples",
+        (_, "links") => "links",
+        (_, "callbacks") => "callbacks",
+        (_, "content") => "content",
+        // Entries of a map of responses, headers...
+        ("responses", _) => "responses",
+        ("headers", _) => "headers",
+        ("links", _) => "links",
+        ("callbacks", _) => "callbacks",
+        ("parameters", _) => "parameters",
+        ("requestBodies", _) => "requestBodies",
+        _ => "other",
+    }
+}
+
+struct Bundler {
+    root: PathBuf,
+    /// Parsed files, by canonical path
+    documents: HashMap<PathBuf, Value>,
+    /// Local reference given to each external target (file and pointer)
+    targets: HashMap<(PathBuf, String), String>,
+    /// Bundled components, by section then name
+    components: BTreeMap<String, Map<String, Value>>,
+}
+
+impl Bundler {
+    fn document(&mut self, path: &Path) -> Result<&Value> {
+        if !self.documents.contains_key(path) {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Cannot read {}", path.display()))?;
+            let document: Value = serde_json::from_str(&content)
+                .with_context(|| format!("{} is not a JSON document", path.display()))?;
+            self.documents.insert(path.to_path_buf(), document);
+        }
+        Ok(&self.documents[path])
+    }
+
+    /// Whether `section/name` is taken, in the root document or by an
+    /// already bundled component.
+    fn is_taken(&self, section: &str, name: &str) -> bool {
+        self.components
+            .get(section)
+            .is_some_and(|entries| entries.contains_key(name))
+            || self.documents[&self.root]
+                .get("components")
+                .and_then(|components| components.get(section)?.get(name))
+                .is_some()
+    }
+
+    /// Replaces the references to other files below `value`, read from
+    /// `file`, by references to bundled components.
+    fn rewrite(&mut self, value: &mut Value, file: &Path, kind: &str) -> Result<()> {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    let reference = reference.clone();
+                    if kind == "pathItem" && !reference.starts_with('#') {
+                        // Path items are no component in OpenAPI 3.0: inline them
+                        let (target, pointer) = self.locate(&reference, file)?;
+                        let mut item = self.resolve(&target, &pointer)?;
+                        self.rewrite(&mut item, &target, kind)?;
+                        *value = item;
+                        return Ok(());
+                    }
+                    if let Some(local) = self.local_reference(&reference, file, kind)? {
+                        map.insert("$ref".to_string(), Value::String(local));
+                    }
+                }
+                for (key, child) in map.iter_mut().filter(|(key, _)| *key != "$ref") {
+                    self.rewrite(child, file, child_kind(kind, key))?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.rewrite(item, file, kind)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// File and JSON pointer a reference found in `file` points to.
+    fn locate(&self, reference: &str, file: &Path) -> Result<(PathBuf, String)> {
+        let (target, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        let target = if target.is_empty() {
+            file.to_path_buf()
+        } else {
+            let path = file.parent().unwrap_or(Path::new(".")).join(target);
+            path.canonicalize().with_context(|| {
+                format!("Cannot resolve '{}' from {}", reference, file.display())
+            })?
+        };
+        Ok((target, pointer.to_string()))
+    }
+
+    fn resolve(&mut self, file: &Path, pointer: &str) -> Result<Value> {
+        self.document(file)?
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| anyhow!("{}#{} does not exist", file.display(), pointer))
+    }
+
+    /// Reference to use instead of `reference`, bundling its target; None
+    /// for references the root document can already follow.
+    fn local_reference(
+        &mut self,
+        reference: &str,
+        file: &Path,
+        kind: &str,
+    ) -> Result<Option<String>> {
+        if reference.starts_with("http://") || reference.starts_with("https://") {
+            log::warn!(
+                "{}: remote reference '{}' left as is",
+                file.display(),
+                reference
+            );
+            return Ok(None);
+        }
+        if file == self.root && reference.starts_with('#') {
+            return Ok(None);
+        }
+        let (target, pointer) = self.locate(reference, file)?;
+        if target == self.root {
+            return Ok(Some(format!("#{}", pointer)));
+        }
+        let key = (target.clone(), pointer.clone());
+        if let Some(local) = self.targets.get(&key) {
+            return Ok(Some(local.clone()));
+        }
+
+        // `#/components/<section>/<name>` keeps its section and name
+        let segments: Vec<String> = pointer.split('/').skip(1).map(unescape_pointer).collect();
+        let (section, name) = match segments.as_slice() {
+            [components, section, name] if components == "components" => {
+                (section.clone(), name.clone())
+            }
+            _ => {
+                let section = if COMPONENT_SECTIONS.contains(&kind) {
+                    kind
+                } else {
+                    "schemas"
+                };
+                let name = match segments.last() {
+                    Some(last) if !last.is_empty() => last.clone(),
+                    _ => target
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "Bundled".to_string()),
+                };
+                (section.to_string(), name)
+            }
+        };
+        let mut unique = name.clone();
+        let mut suffix = 2;
+        while self.is_taken(&section, &unique) {
+            unique = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        let local = format!(
+            "#/components/{}/{}",
+            pointer_segment(&section),
+            pointer_segment(&unique)
+        );
+        // Registered first, so reference cycles end here
+        self.targets.insert(key, local.clone());
+        self.components
+            .entry(section.clone())
+            .or_default()
+            .insert(unique.clone(), Value::Null);
+
+        let mut component = self.resolve(&target, &pointer)?;
+        self.rewrite(&mut component, &target, &section)?;
+        self.components
+            .entry(section)
+            .or_default()
+            .insert(unique, component);
+        Ok(Some(local))
+    }
+}
+
+/// The spec at `path` with the targets of its references to other files
+/// added to `components` (path items are inlined), so it can be read on its
+/// own. Each target is bundled once, under its own component name when it
+/// is one, else the last segment of its pointer or the file name.
+pub fn bundle_file(path: &Path) -> Result<Value> {
+    let root = path
+        .canonicalize()
+        .with_context(|| format!("Cannot read {}", path.display()))?;
+    let mut bundler = Bundler {
+        root: root.clone(),
+        documents: HashMap::new(),
+        targets: HashMap::new(),
+        components: BTreeMap::new(),
+    };
+    let mut document = bundler.document(&root)?.clone();
+    bundler.rewrite(&mut document, &root, "root")?;
+
+    if !bundler.components.is_empty() {
+        let Some(root_map) = document.as_object_mut() else {
+            return Err(anyhow!("{} is not an OpenAPI document", path.display()));
+        };
+        let components = root_map
+            .entry("components")
+            .or_insert_with(|| Value::Object(Map::new()));
+        for (section, entries) in bundler.components {
+            let section = components
+                .as_object_mut()
+                .ok_or_else(|| anyhow!("components is not an object"))?
+                .entry(section)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Some(section) = section.as_object_mut() {
+                section.extend(entries);
+            }
+        }
+    }
+    Ok(document)
+}
+
+/// File name of a tag's paths.
+fn tag_file(tag: Option<&str>) -> String {
+    let slug: String = tag
+        .unwrap_or("untagged")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let slug = slug.trim_matches('-');
+    match slug {
+        "" => "untagged.json".to_string(),
+        // Would overwrite the root document
+        "openapi" => "openapi-paths.json".to_string(),
+        slug => format!("{}.json", slug),
+    }
+}
+
+/// Points the local references below `value` at the root document.
+fn rebase_references(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) if reference.starts_with('#') => {
+                        *reference = format!("{}{}", ROOT_FILE, reference);
+                    }
+                    (_, child) => rebase_references(child),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(rebase_references),
+        _ => {}
+    }
+}
+
+/// Files of a split spec, by name: [`ROOT_FILE`] with everything but the
+/// paths, which are replaced by references to one file per tag (the first
+/// tag of a path's first operation). Webhooks stay in the root document.
+pub fn split_document(document: &Value) -> BTreeMap<String, Value> {
+    let mut root = document.clone();
+    let mut files: BTreeMap<String, Value> = BTreeMap::new();
+    let paths = root.get_mut("paths").and_then(Value::as_object_mut);
+    for (path, item) in paths.into_iter().flatten() {
+        let tag = HTTP_METHODS
+            .iter()
+            .filter_map(|method| item.get(method))
+            .find_map(|operation| operation.get("tags")?.get(0)?.as_str());
+        let file = tag_file(tag);
+        let mut moved = std::mem::replace(
+            item,
+            serde_json::json!({
+                "$ref": format!("{}#/paths/{}", file, pointer_segment(path))
+            }),
+        );
+        rebase_references(&mut moved);
+        let entry = files
+            .entry(file)
+            .or_insert_with(|| serde_json::json!({"paths": {}}));
+        entry["paths"][path.as_str()] = moved;
+    }
+    files.insert(ROOT_FILE.to_string(), root);
+    files
+}
+
+/// Writes the files of [`split_document`] into `dir`, creating it.
+pub fn write_split(dir: &Path, files: &BTreeMap<String, Value>) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+    for (name, document) in files {
+        let path = dir.join(name);
+        std::fs::write(
+            &path,
+            format!("{}\n", serde_json::to_string_pretty(document)?),
+        )
+        .with_context(|| format!("Cannot write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write(dir: &Path, name: &str, value: Value) {
+        std::fs::write(dir.join(name), value.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_bundles_external_references() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "openapi.json",
+            json!({
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"$ref": "paths/users.json"},
+                    "/orders": {"get": {
+                        "parameters": [{"$ref": "common.json#/components/parameters/Page"}],
+                        "responses": {"200": {"description": "ok", "content": {"application/json": {
+                            "schema": {"$ref": "models/order.json"}}}}}
+                    }}
+                },
+                "components": {"schemas": {"Order": {"type": "string"}}}
+            }),
+        );
+        std::fs::create_dir(dir.path().join("paths")).unwrap();
+        write(
+            dir.path(),
+            "paths/users.json",
+            json!({"get": {"responses": {
+                "200": {"$ref": "../common.json#/components/responses/UserList"}
+            }}}),
+        );
+        write(
+            dir.path(),
+            "common.json",
+            json!({"components": {
+                "parameters": {"Page": {"name": "page", "in": "query", "schema": {"type": "integer"}}},
+                "responses": {"UserList": {"description": "users", "content": {"application/json": {
+                    "schema": {"type": "array", "items": {"$ref": "#/components/schemas/User"}}}}}},
+                "schemas": {"User": {"type": "object", "properties": {
+                    "manager": {"$ref": "#/components/schemas/User"}}}}
+            }}),
+        );
+        std::fs::create_dir(dir.path().join("models")).unwrap();
+        write(dir.path(), "models/order.json", json!({"type": "object"}));
+
+        let bundled = bundle_file(&dir.path().join("openapi.json")).unwrap();
+        // Path items are inlined, components keep their names
+        assert_eq!(
+            bundled["paths"]["/users"]["get"]["responses"]["200"],
+            json!({"$ref": "#/components/responses/UserList"})
+        );
+        assert_eq!(
+            bundled["components"]["responses"]["UserList"]["content"]["application/json"]["schema"]
+                ["items"],
+            json!({"$ref": "#/components/schemas/User"})
+        );
+        assert_eq!(
+            bundled["components"]["schemas"]["User"]["properties"]["manager"],
+            json!({"$ref": "#/components/schemas/User"})
+        );
+        assert_eq!(
+            bundled["paths"]["/orders"]["get"]["parameters"][0],
+            json!({"$ref": "#/components/parameters/Page"})
+        );
+        // Whole-file targets are named after the file, without clobbering
+        // the root document's own components
+        assert_eq!(
+            bundled["paths"]["/orders"]["get"]["responses"]["200"]["content"]["application/json"]
+                ["schema"],
+            json!({"$ref": "#/components/schemas/order"})
+        );
+        assert_eq!(
+            bundled["components"]["schemas"]["Order"],
+            json!({"type": "string"})
+        );
+        assert!(crate::parser::parse_openapi_content(&bundled.to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_split_then_bundle_round_trips() {
+        let document = json!({
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/users": {"get": {"tags": ["Users"], "responses": {"200": {"description": "ok",
+                    "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}}},
+                "/users/{id}": {"delete": {"tags": ["Users", "Admin"], "responses": {"204": {"description": "gone"}}}},
+                "/health": {"get": {"responses": {"200": {"description": "ok"}}}}
+            },
+            "components": {"schemas": {"User": {"type": "object"}}}
+        });
+        let files = split_document(&document);
+        let names: Vec<&str> = files.keys().map(String::as_str).collect();
+        assert_eq!(names, ["openapi.json", "untagged.json", "users.json"]);
+        assert_eq!(
+            files["openapi.json"]["paths"]["/users/{id}"],
+            json!({"$ref": "users.json#/paths/~1users~1{id}"})
+        );
+        assert_eq!(
+            files["users.json"]["paths"]["/users"]["get"]["responses"]["200"]["content"]
+                ["application/json"]["schema"],
+            json!({"$ref": "openapi.json#/components/schemas/User"})
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        write_split(dir.path(), &files).unwrap();
+        let bundled = bundle_file(&dir.path().join(ROOT_FILE)).unwrap();
+        assert_eq!(bundled, document);
+    }
+}
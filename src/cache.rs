@@ -0,0 +1,201 @@
+use crate::indexer::{build_field_index_with_progress, FieldIndex};
+use crate::parser::{
+    is_stdin, parse_openapi_document_with_progress, read_spec_content, OpenApiSpec,
+};
+use crate::progress::{no_progress, LoadStage, Progress};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the cached structures change shape
+const CACHE_FORMAT: u32 = 21;
+
+/// Parsed spec and index of one source file. Stored as JSON rather than a
+/// binary format because schemas carry arbitrary JSON values (`example`,
+/// `default`, `x-` extensions) that need a self-describing format.
+#[derive(Deserialize)]
+struct CacheEntry {
+    format: u32,
+    tool_version: String,
+    content_hash: String,
+    spec: OpenApiSpec,
+    index: FieldIndex,
+}
+
+/// On-disk cache of parsed specs, one entry per source file. An entry is
+/// only used while the source content hash matches.
+#[derive(Debug, Clone)]
+pub struct SpecCache {
+    dir: PathBuf,
+}
+
+impl SpecCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `$XDG_CACHE_HOME/openapi-explorer`, else `~/.cache/openapi-explorer`,
+    /// else the system temp directory.
+    pub fn default_dir() -> PathBuf {
+        std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .unwrap_or_else(std::env::temp_dir)
+            .join("openapi-explorer")
+    }
+
+    fn entry_path(&self, source: &Path) -> PathBuf {
+        let source = source
+            .canonicalize()
+            .unwrap_or_else(|_| source.to_path_buf());
+        self.dir.join(format!(
+            "{}.json",
+            hash_hex(source.to_string_lossy().as_bytes())
+        ))
+    }
+
+    pub fn load(&self, source: &Path, content: &str) -> Option<(OpenApiSpec, FieldIndex)> {
+        let data = std::fs::read(self.entry_path(source)).ok()?;
+        let mut entry: CacheEntry = serde_json::from_slice(&data).ok()?;
+        let valid = entry.format == CACHE_FORMAT
+            && entry.tool_version == env!("CARGO_PKG_VERSION")
+            && entry.content_hash == hash_hex(content.as_bytes());
+        if !valid {
+            return None;
+        }
+        entry.index.share_names();
+        Some((entry.spec, entry.index))
+    }
+
+    /// Writes (or replaces) the entry of `source`.
+    pub fn store(
+        &self,
+        source: &Path,
+        content: &str,
+        spec: &OpenApiSpec,
+        index: &FieldIndex,
+    ) -> Result<()> {
+        // Borrowing twin of `CacheEntry`, to avoid cloning the spec
+        #[derive(Serialize)]
+        struct CacheEntryRef<'a> {
+            format: u32,
+            tool_version: &'a str,
+            content_hash: String,
+            spec: &'a OpenApiSpec,
+            index: &'a FieldIndex,
+        }
+
+        std::fs::create_dir_all(&self.dir)?;
+        let entry = CacheEntryRef {
+            format: CACHE_FORMAT,
+            tool_version: env!("CARGO_PKG_VERSION"),
+            content_hash: hash_hex(content.as_bytes()),
+            spec,
+            index,
+        };
+        std::fs::write(self.entry_path(source), serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+}
+
+pub(crate) fn hash_hex(bytes: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Parses and indexes `path` with nested fields down to `max_depth`, going
+/// through `cache` when given. Entries indexed at another depth are rebuilt.
+/// A `lenient` parse keeps what deserializes of a partially invalid spec;
+/// such specs are not cached, so their warnings are reported on each load.
+pub async fn load_indexed(
+    path: &Path,
+    cache: Option<&SpecCache>,
+    max_depth: usize,
+    lenient: bool,
+) -> Result<(OpenApiSpec, FieldIndex)> {
+    load_indexed_with_progress(path, cache, max_depth, lenient, &no_progress).await
+}
+
+/// [`load_indexed`], reporting each stage. A cache hit skips parsing and
+/// indexing.
+pub async fn load_indexed_with_progress(
+    path: &Path,
+    cache: Option<&SpecCache>,
+    max_depth: usize,
+    lenient: bool,
+    progress: Progress<'_>,
+) -> Result<(OpenApiSpec, FieldIndex)> {
+    progress(LoadStage::Reading);
+    let content = read_spec_content(path).await?;
+    // Piped specs have no source file to key the entry on
+    let cache = cache.filter(|_| !is_stdin(path));
+
+    if let Some(cached) = cache
+        .and_then(|cache| cache.load(path, &content))
+        .filter(|(_, index)| index.max_depth == max_depth)
+    {
+        log::debug!("Loaded {} from cache", path.display());
+        return Ok(cached);
+    }
+
+    let spec = parse_openapi_document_with_progress(path, &content, lenient, progress)?;
+    let index = build_field_index_with_progress(&spec, max_depth, progress);
+    if let Some(cache) = cache.filter(|_| spec.parse_warnings.is_empty()) {
+        if let Err(e) = cache.store(path, &content, &spec, &index) {
+            log::warn!("Could not cache {}: {}", path.display(), e);
+        }
+    }
+    Ok((spec, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::DEFAULT_FIELD_DEPTH;
+
+    const SPEC: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "T", "version": "1"},
+        "paths": {"/users": {"get": {"x-owner": "team-a", "responses": {"200": {
+            "description": "ok",
+            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}
+        }}}}},
+        "components": {"schemas": {"User": {"type": "object", "properties": {
+            "id": {"type": "integer", "example": 1}
+        }}}}
+    }"##;
+
+    #[tokio::test]
+    async fn test_cache_hit_and_invalidation() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("api.json");
+        let cache = SpecCache::new(dir.path().join("cache"));
+
+        std::fs::write(&source, SPEC).unwrap();
+        let (_, index) = load_indexed(&source, Some(&cache), DEFAULT_FIELD_DEPTH, false)
+            .await
+            .unwrap();
+        let (spec, cached_index) = cache.load(&source, SPEC).expect("entry stored");
+        assert_eq!(cached_index.fields.len(), index.fields.len());
+        assert_eq!(
+            spec.paths["/users"].operations["get"].extension("x-owner"),
+            Some(&serde_json::json!("team-a"))
+        );
+
+        // Changed content misses and replaces the entry
+        let changed = SPEC.replace("\"id\"", "\"uuid\"");
+        assert!(cache.load(&source, &changed).is_none());
+        std::fs::write(&source, &changed).unwrap();
+        let (_, index) = load_indexed(&source, Some(&cache), DEFAULT_FIELD_DEPTH, false)
+            .await
+            .unwrap();
+        assert!(index.fields.contains_key("uuid"));
+        assert!(cache.load(&source, &changed).is_some());
+
+        // Another nested field depth re-indexes instead of using the entry
+        let (_, index) = load_indexed(&source, Some(&cache), 1, false).await.unwrap();
+        assert_eq!(index.max_depth, 1);
+    }
+}
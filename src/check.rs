@@ -0,0 +1,146 @@
+use crate::diff::{diff_specs, Change, SpecDiff};
+use crate::parser::OpenApiSpec;
+use serde::Serialize;
+
+/// Changes that make `check` fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum FailOn {
+    /// Removed endpoints or fields, type changes, newly required inputs
+    #[default]
+    Breaking,
+    /// Any difference from the baseline
+    Any,
+    /// Report only
+    Never,
+}
+
+impl FailOn {
+    pub fn is_triggered(&self, diff: &SpecDiff) -> bool {
+        match self {
+            FailOn::Breaking => diff.has_breaking_changes(),
+            FailOn::Any => !diff.is_empty(),
+            FailOn::Never => false,
+        }
+    }
+}
+
+/// Outcome of comparing a spec against a baseline, serialized as the JSON
+/// report of `openapi-explorer check`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckReport {
+    pub baseline: String,
+    pub current: String,
+    pub fail_on: FailOn,
+    pub failed: bool,
+    pub breaking: usize,
+    pub non_breaking: usize,
+    /// Breaking changes first
+    pub changes: Vec<Change>,
+}
+
+impl CheckReport {
+    /// Human-readable summary for CI logs.
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "API check: {} → {}\n{} breaking change(s), {} other change(s)\n",
+            self.baseline, self.current, self.breaking, self.non_breaking
+        );
+        for (heading, breaking, marker) in [("Breaking", true, "✗"), ("Other", false, "•")] {
+            let changes: Vec<&Change> = self
+                .changes
+                .iter()
+                .filter(|change| change.breaking == breaking)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n{}:\n", heading));
+            for change in changes {
+                out.push_str(&format!(
+                    "  {} {}: {}\n",
+                    marker, change.location, change.message
+                ));
+            }
+        }
+        out.push_str(&if self.failed {
+            format!(
+                "\nFAILED (--fail-on {})\n",
+                match self.fail_on {
+                    FailOn::Breaking => "breaking",
+                    FailOn::Any => "any",
+                    FailOn::Never => "never",
+                }
+            )
+        } else {
+            "\nOK\n".to_string()
+        });
+        out
+    }
+}
+
+/// Diffs `current` against `baseline`; the names label the report.
+pub fn check_specs(
+    baseline: &OpenApiSpec,
+    current: &OpenApiSpec,
+    names: (&str, &str),
+    fail_on: FailOn,
+) -> CheckReport {
+    let diff = diff_specs(baseline, current);
+    CheckReport {
+        baseline: names.0.to_string(),
+        current: names.1.to_string(),
+        fail_on,
+        failed: fail_on.is_triggered(&diff),
+        breaking: diff.breaking_changes().count(),
+        non_breaking: diff.non_breaking_changes().count(),
+        changes: diff.changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn spec(paths: &str) -> OpenApiSpec {
+        parse_openapi_content(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "T", "version": "1"}}, "paths": {}}}"#,
+            paths
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_fails_on_breaking_changes() {
+        let baseline = spec(r#"{"/a": {"get": {"responses": {}}}}"#);
+        let current = spec(r#"{"/b": {"get": {"responses": {}}}}"#);
+
+        let report = check_specs(&baseline, &current, ("v1", "v2"), FailOn::Breaking);
+        assert!(report.failed);
+        assert_eq!((report.breaking, report.non_breaking), (1, 1));
+        let text = report.to_text();
+        assert!(text.contains("  ✗ GET /a: endpoint removed"));
+        assert!(text.contains("  • GET /b: endpoint added"));
+        assert!(text.ends_with("FAILED (--fail-on breaking)\n"));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["fail_on"], "breaking");
+        assert_eq!(json["changes"][0]["kind"], "endpoint_removed");
+
+        assert!(!check_specs(&baseline, &current, ("v1", "v2"), FailOn::Never).failed);
+    }
+
+    #[test]
+    fn test_additions_only_fail_on_any() {
+        let baseline = spec(r#"{"/a": {"get": {"responses": {}}}}"#);
+        let current =
+            spec(r#"{"/a": {"get": {"responses": {}}}, "/b": {"get": {"responses": {}}}}"#);
+
+        assert!(!check_specs(&baseline, &current, ("v1", "v2"), FailOn::Breaking).failed);
+        assert!(check_specs(&baseline, &current, ("v1", "v2"), FailOn::Any).failed);
+        assert!(check_specs(&baseline, &baseline, ("v1", "v1"), FailOn::Any)
+            .to_text()
+            .ends_with("\nOK\n"));
+    }
+}
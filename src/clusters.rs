@@ -0,0 +1,163 @@
+//! Fields that always appear together (`street`, `city`, `zip` declared
+//! side by side in several schemas), candidates for a shared value object.
+
+use crate::indexer::FieldIndex;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Schemas a group of fields must share before it is reported: a single
+/// schema is already the value object.
+pub const MIN_CLUSTER_SCHEMAS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldCluster {
+    /// Sorted field names
+    pub fields: Vec<String>,
+    /// Schemas declaring every field of the cluster, and no field of it
+    /// without the others
+    pub schemas: Vec<String>,
+    /// Endpoints using every field
+    pub endpoints: Vec<String>,
+}
+
+impl FieldCluster {
+    pub fn label(&self) -> String {
+        format!("{{{}}}", self.fields.join(", "))
+    }
+}
+
+/// Top-level fields grouped by the exact set of schemas declaring them,
+/// keeping the groups of two fields or more found in at least
+/// [`MIN_CLUSTER_SCHEMAS`] schemas, largest first.
+pub fn field_clusters(index: &FieldIndex) -> Vec<FieldCluster> {
+    let mut groups: BTreeMap<BTreeSet<&Arc<str>>, Vec<&str>> = BTreeMap::new();
+    for (name, data) in &index.fields {
+        // Nested paths repeat the fields of the schemas they point to
+        if name.contains('.') || name.contains("[]") {
+            continue;
+        }
+        let schemas: BTreeSet<&Arc<str>> = data.schemas.iter().collect();
+        if schemas.len() >= MIN_CLUSTER_SCHEMAS {
+            groups.entry(schemas).or_default().push(name);
+        }
+    }
+
+    let mut clusters: Vec<FieldCluster> = groups
+        .into_iter()
+        .filter(|(_, fields)| fields.len() >= 2)
+        .map(|(schemas, mut fields)| {
+            fields.sort_unstable();
+            let mut endpoints: Option<BTreeSet<&Arc<str>>> = None;
+            for field in &fields {
+                let used: BTreeSet<&Arc<str>> = index.fields[*field].endpoints.iter().collect();
+                endpoints = Some(match endpoints {
+                    Some(shared) => shared.intersection(&used).copied().collect(),
+                    None => used,
+                });
+            }
+            FieldCluster {
+                fields: fields.into_iter().map(str::to_string).collect(),
+                schemas: schemas.into_iter().map(|s| s.to_string()).collect(),
+                endpoints: endpoints
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|e| e.to_string())
+                    .collect(),
+            }
+        })
+        .collect();
+    clusters.sort_by(|a, b| {
+        b.fields
+            .len()
+            .cmp(&a.fields.len())
+            .then(b.schemas.len().cmp(&a.schemas.len()))
+            .then(a.fields.cmp(&b.fields))
+    });
+    clusters
+}
+
+/// Writes the clusters as Markdown for `.md` files and JSON otherwise.
+pub fn write_clusters(path: &Path, clusters: &[FieldCluster]) -> Result<()> {
+    let is_markdown = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "md" | "markdown"));
+    let content = if is_markdown {
+        let mut out = format!("# Field clusters ({})\n\n", clusters.len());
+        for cluster in clusters {
+            out.push_str(&format!(
+                "- `{}` in {} schemas ({}), {} endpoint(s)\n",
+                cluster.label(),
+                cluster.schemas.len(),
+                cluster.schemas.join(", "),
+                cluster.endpoints.len()
+            ));
+        }
+        out
+    } else {
+        serde_json::to_string_pretty(clusters)?
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_clusters_fields_always_declared_together() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"get": {"responses": {"200": {"description": "ok", "content": {
+                        "application/json": {"schema": {"type": "object", "properties": {
+                            "street": {"type": "string"}, "zip": {"type": "string"}
+                        }}}
+                    }}}}}
+                },
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "id": {"type": "integer"},
+                        "street": {"type": "string"}, "city": {"type": "string"}, "zip": {"type": "string"}
+                    }},
+                    "Warehouse": {"type": "object", "properties": {
+                        "code": {"type": "string"},
+                        "street": {"type": "string"}, "city": {"type": "string"}, "zip": {"type": "string"}
+                    }},
+                    "Store": {"type": "object", "properties": {
+                        "id": {"type": "integer"}, "city": {"type": "string"}
+                    }},
+                    "Money": {"type": "object", "properties": {
+                        "amount": {"type": "number"}, "currency": {"type": "string"}
+                    }}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        let clusters = field_clusters(&index);
+
+        // `city` also appears in Store, `amount`/`currency` in a single schema
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].fields, vec!["street", "zip"]);
+        assert_eq!(clusters[0].schemas, vec!["User", "Warehouse"]);
+        assert_eq!(clusters[0].endpoints, vec!["GET /users"]);
+        assert_eq!(clusters[0].label(), "{street, zip}");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clusters.md");
+        write_clusters(&path, &clusters).unwrap();
+        let markdown = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            markdown.contains("- `{street, zip}` in 2 schemas (User, Warehouse), 1 endpoint(s)")
+        );
+    }
+}
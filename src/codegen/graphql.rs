@@ -0,0 +1,256 @@
+use super::{
+    component_schemas, object_fields, string_enum, type_name, union_members, GeneratedFile,
+};
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::HashMap;
+
+const INDENT: &str = "  ";
+
+/// Custom scalar for maps, untyped values and shapes SDL cannot express
+const JSON_SCALAR: &str = "JSON";
+
+/// A single `schema.graphql` declaring one type per schema: object types
+/// (`allOf` parts flattened), enums, unions of `oneOf`/`anyOf` references
+/// and scalars for the rest. Required, non-nullable properties are non-null.
+pub fn generate(spec: &OpenApiSpec, names: &[String]) -> Vec<GeneratedFile> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    let mut sdl = Sdl {
+        schemas,
+        definitions: Vec::new(),
+        uses_json: false,
+    };
+    for name in names {
+        if let Some(schema) = schemas.get(name) {
+            sdl.define(&type_name(name), schema);
+        }
+    }
+    if sdl.definitions.is_empty() {
+        return Vec::new();
+    }
+
+    let mut content = format!(
+        "# Generated by openapi-explorer from {} {}\n\n",
+        spec.info.title, spec.info.version
+    );
+    if sdl.uses_json {
+        content.push_str(&format!("scalar {}\n\n", JSON_SCALAR));
+    }
+    content.push_str(&sdl.definitions.join("\n"));
+    vec![GeneratedFile {
+        name: "schema.graphql".to_string(),
+        content,
+    }]
+}
+
+struct Sdl<'a> {
+    schemas: &'a HashMap<String, Schema>,
+    /// Rendered type definitions, nested ones before the type using them
+    definitions: Vec<String>,
+    uses_json: bool,
+}
+
+impl<'a> Sdl<'a> {
+    /// Declares `name` for `schema`.
+    fn define(&mut self, name: &str, schema: &'a Schema) {
+        let mut out = description(schema.description.as_deref(), "");
+        if let Some(values) = string_enum(schema) {
+            out.push_str(&format!("enum {} {{\n", name));
+            for value in values {
+                out.push_str(&format!("{}{}\n", INDENT, identifier(&value)));
+            }
+            out.push_str("}\n");
+        } else if let Some(members) = union_members(schema) {
+            out.push_str(&format!("union {} = {}\n", name, members.join(" | ")));
+        } else if let Some((properties, required)) = object_fields(schema, self.schemas) {
+            let mut body = String::new();
+            for (field, property) in properties {
+                let nested = format!("{}{}", name, type_name(field));
+                body.push_str(&description(property.description.as_deref(), INDENT));
+                body.push_str(&format!(
+                    "{}{}: {}\n",
+                    INDENT,
+                    identifier(field),
+                    self.field_type(&nested, property, required.contains(field))
+                ));
+            }
+            out.push_str(&format!("type {} {{\n{}}}\n", name, body));
+        } else {
+            out.push_str(&format!("scalar {}\n", name));
+        }
+        self.definitions.push(out);
+    }
+
+    /// Type of a field; inline enums, objects and unions are declared as
+    /// `nested`.
+    fn field_type(&mut self, nested: &str, schema: &'a Schema, required: bool) -> String {
+        let base = match (&schema.reference, schema.schema_type.as_deref()) {
+            (Some(reference), _) => extract_schema_name_from_ref(reference)
+                .map(type_name)
+                .unwrap_or_else(|| self.json()),
+            (None, _) if string_enum(schema).is_some() || union_members(schema).is_some() => {
+                self.define(nested, schema);
+                nested.to_string()
+            }
+            (None, Some("array")) => {
+                let item = match schema.items.as_deref() {
+                    Some(items) => self.field_type(nested, items, true),
+                    None => self.json(),
+                };
+                format!("[{}]", item)
+            }
+            (None, Some("string")) => "String".to_string(),
+            (None, Some("integer")) => "Int".to_string(),
+            (None, Some("number")) => "Float".to_string(),
+            (None, Some("boolean")) => "Boolean".to_string(),
+            (None, _) if schema.properties.is_some() || schema.all_of.is_some() => {
+                self.define(nested, schema);
+                nested.to_string()
+            }
+            _ => self.json(),
+        };
+        if required && !schema.nullable.unwrap_or(false) {
+            format!("{}!", base)
+        } else {
+            base
+        }
+    }
+
+    fn json(&mut self) -> String {
+        self.uses_json = true;
+        JSON_SCALAR.to_string()
+    }
+}
+
+/// GraphQL name for a property or enum value: characters outside
+/// `[_0-9A-Za-z]` become `_`, and a leading digit gets a `_` prefix.
+fn identifier(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    match name.chars().next() {
+        None => "_".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+    }
+}
+
+fn description(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text.map(str::trim).filter(|t| !t.is_empty()) else {
+        return String::new();
+    };
+    let text = text.replace("\"\"\"", "\\\"\"\"");
+    if !text.contains('\n') {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, text);
+    }
+    let mut out = format!("{}\"\"\"\n", indent);
+    for line in text.lines() {
+        out.push_str(&format!("{}{}\n", indent, line).replace(&format!("{}\n", indent), "\n"));
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    const SHOP: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Shop", "version": "1.0"},
+        "paths": {},
+        "components": {"schemas": {
+            "Order": {
+                "type": "object",
+                "description": "A customer order",
+                "required": ["id", "lines", "note"],
+                "properties": {
+                    "id": {"type": "integer"},
+                    "status": {"type": "string", "enum": ["pending", "paid"]},
+                    "lines": {"type": "array", "items": {"$ref": "#/components/schemas/order_line"}},
+                    "note": {"type": "string", "nullable": true, "description": "Free text"},
+                    "x-trace": {"type": "string"},
+                    "meta": {"type": "object", "additionalProperties": {"type": "string"}},
+                    "payment": {"$ref": "#/components/schemas/Payment"}
+                }
+            },
+            "order_line": {"type": "object", "required": ["sku"], "properties": {
+                "sku": {"type": "string"},
+                "price": {"type": "object", "properties": {"amount": {"type": "number"}}}
+            }},
+            "Payment": {"oneOf": [{"$ref": "#/components/schemas/Card"}, {"$ref": "#/components/schemas/Transfer"}]},
+            "Card": {"allOf": [{"$ref": "#/components/schemas/Base"}, {"type": "object", "required": ["kind"], "properties": {"last4": {"type": "string"}}}]},
+            "Base": {"type": "object", "properties": {"kind": {"type": "string"}}},
+            "Transfer": {"type": "object", "properties": {"iban": {"type": "string"}}},
+            "Currency": {"type": "string", "enum": ["EUR", "USD"]},
+            "Sku": {"type": "string"}
+        }}
+    }"##;
+
+    #[test]
+    fn test_sdl_types() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let names = crate::codegen::all_schema_names(&spec);
+        let files = generate(&spec, &names);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "schema.graphql");
+        assert_eq!(
+            files[0].content,
+            r#"# Generated by openapi-explorer from Shop 1.0
+
+scalar JSON
+
+type Base {
+  kind: String
+}
+
+type Card {
+  kind: String!
+  last4: String
+}
+
+enum Currency {
+  EUR
+  USD
+}
+
+enum OrderStatus {
+  pending
+  paid
+}
+
+"""A customer order"""
+type Order {
+  id: Int!
+  lines: [OrderLine!]!
+  meta: JSON
+  """Free text"""
+  note: String
+  payment: Payment
+  status: OrderStatus
+  x_trace: String
+}
+
+union Payment = Card | Transfer
+
+scalar Sku
+
+type Transfer {
+  iban: String
+}
+
+type OrderLinePrice {
+  amount: Float
+}
+
+type OrderLine {
+  price: OrderLinePrice
+  sku: String!
+}
+"#
+        );
+    }
+}
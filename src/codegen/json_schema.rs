@@ -0,0 +1,307 @@
+use super::{component_schemas, type_name, GeneratedFile};
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeSet, HashMap};
+
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// One standalone `<Type>.schema.json` document per schema.
+pub fn generate(spec: &OpenApiSpec, names: &[String]) -> Vec<GeneratedFile> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    names
+        .iter()
+        .filter_map(|name| {
+            let document = to_json_schema(name, schemas)?;
+            Some(GeneratedFile {
+                name: format!("{}.schema.json", type_name(name)),
+                content: serde_json::to_string_pretty(&document).ok()? + "\n",
+            })
+        })
+        .collect()
+}
+
+/// The component schema `name` as a JSON Schema document: references are
+/// inlined, `allOf` members merged and `nullable` turned into a `null`
+/// type. References back into a schema being inlined point to `#` (the
+/// document itself) or to an entry of `$defs`.
+pub fn to_json_schema(name: &str, schemas: &HashMap<String, Schema>) -> Option<Value> {
+    let schema = schemas.get(name)?;
+    let Value::Object(mut document) = resolve(schema, Some(name), schemas) else {
+        return None;
+    };
+    document.insert("$schema".to_string(), json!(DIALECT));
+    document
+        .entry("title")
+        .or_insert_with(|| json!(name.to_string()));
+    Some(Value::Object(document))
+}
+
+/// Any schema, such as an operation's request body, converted like
+/// `to_json_schema` but without the dialect and title.
+pub fn resolve_schema(schema: &Schema, schemas: &HashMap<String, Schema>) -> Value {
+    resolve(schema, None, schemas)
+}
+
+fn resolve(schema: &Schema, root: Option<&str>, schemas: &HashMap<String, Schema>) -> Value {
+    let mut resolver = Resolver {
+        schemas,
+        root,
+        stack: root.map(str::to_string).into_iter().collect(),
+        recursive: BTreeSet::new(),
+    };
+    let mut document = resolver.convert(schema);
+
+    let mut defs = Map::new();
+    while let Some(def) = resolver
+        .recursive
+        .iter()
+        .find(|def| !defs.contains_key(*def))
+        .cloned()
+    {
+        resolver.stack = vec![def.clone()];
+        let converted = resolver.convert(&schemas[&def]);
+        defs.insert(def, Value::Object(converted));
+    }
+    if !defs.is_empty() {
+        document.insert("$defs".to_string(), Value::Object(defs));
+    }
+    Value::Object(document)
+}
+
+struct Resolver<'a> {
+    schemas: &'a HashMap<String, Schema>,
+    root: Option<&'a str>,
+    /// Component schemas being inlined, innermost last
+    stack: Vec<String>,
+    /// Schemas referenced from inside themselves, emitted under `$defs`
+    recursive: BTreeSet<String>,
+}
+
+impl Resolver<'_> {
+    fn convert(&mut self, schema: &Schema) -> Map<String, Value> {
+        let mut out = Map::new();
+
+        if let Some(reference) = &schema.reference {
+            match extract_schema_name_from_ref(reference)
+                .filter(|name| self.schemas.contains_key(*name))
+            {
+                Some(name) if self.stack.iter().any(|expanding| expanding == name) => {
+                    let target = if Some(name) == self.root {
+                        "#".to_string()
+                    } else {
+                        self.recursive.insert(name.to_string());
+                        format!("#/$defs/{}", name)
+                    };
+                    out.insert("$ref".to_string(), json!(target));
+                }
+                Some(name) => {
+                    self.stack.push(name.to_string());
+                    out = self.convert(&self.schemas[name]);
+                    self.stack.pop();
+                }
+                None => {
+                    out.insert("$ref".to_string(), json!(reference));
+                }
+            }
+        }
+
+        for part in schema.all_of.iter().flatten() {
+            let converted = self.convert(part);
+            merge(&mut out, converted);
+        }
+
+        if let Some(schema_type) = &schema.schema_type {
+            let value = if schema.nullable.unwrap_or(false) {
+                json!([schema_type, "null"])
+            } else {
+                json!(schema_type)
+            };
+            out.insert("type".to_string(), value);
+        }
+        for (keyword, value) in [
+            ("format", schema.format.as_ref().map(|v| json!(v))),
+            ("description", schema.description.as_ref().map(|v| json!(v))),
+            ("default", schema.default.clone()),
+            ("examples", schema.example.as_ref().map(|v| json!([v]))),
+            ("readOnly", schema.read_only.map(|v| json!(v))),
+            ("writeOnly", schema.write_only.map(|v| json!(v))),
+        ] {
+            if let Some(value) = value {
+                out.insert(keyword.to_string(), value);
+            }
+        }
+        if let Some(values) = &schema.enum_ {
+            let mut values = values.clone();
+            if schema.nullable.unwrap_or(false) && !values.contains(&Value::Null) {
+                values.push(Value::Null);
+            }
+            out.insert("enum".to_string(), Value::Array(values));
+        }
+
+        if let Some(properties) = &schema.properties {
+            let mut converted: Map<String, Value> = properties
+                .iter()
+                .map(|(name, property)| (name.clone(), Value::Object(self.convert(property))))
+                .collect();
+            if let Some(Value::Object(inherited)) = out.remove("properties") {
+                for (name, property) in inherited {
+                    converted.entry(name).or_insert(property);
+                }
+            }
+            out.insert("properties".to_string(), Value::Object(converted));
+        }
+        if let Some(required) = &schema.required {
+            merge_required(&mut out, required.iter().map(|name| json!(name)).collect());
+        }
+        if let Some(items) = &schema.items {
+            out.insert("items".to_string(), Value::Object(self.convert(items)));
+        }
+        if let Some(additional) = &schema.additional_properties {
+            out.insert(
+                "additionalProperties".to_string(),
+                Value::Object(self.convert(additional)),
+            );
+        }
+        if let Some(not) = &schema.not {
+            out.insert("not".to_string(), Value::Object(self.convert(not)));
+        }
+        for (keyword, variants) in [("oneOf", &schema.one_of), ("anyOf", &schema.any_of)] {
+            if let Some(variants) = variants {
+                let converted = variants
+                    .iter()
+                    .map(|variant| Value::Object(self.convert(variant)))
+                    .collect();
+                out.insert(keyword.to_string(), Value::Array(converted));
+            }
+        }
+        out
+    }
+}
+
+/// Merges an `allOf` member into the schema built so far: properties and
+/// required names are combined, other keywords kept from the first member
+/// defining them.
+fn merge(out: &mut Map<String, Value>, member: Map<String, Value>) {
+    for (keyword, value) in member {
+        match (keyword.as_str(), value) {
+            ("properties", Value::Object(properties)) => {
+                let target = out
+                    .entry("properties")
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(target) = target {
+                    for (name, property) in properties {
+                        target.entry(name).or_insert(property);
+                    }
+                }
+            }
+            ("required", Value::Array(required)) => merge_required(out, required),
+            (_, value) => {
+                out.entry(keyword).or_insert(value);
+            }
+        }
+    }
+}
+
+fn merge_required(out: &mut Map<String, Value>, names: Vec<Value>) {
+    let target = out
+        .entry("required")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(target) = target {
+        for name in names {
+            if !target.contains(&name) {
+                target.push(name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn schemas(content: &str) -> HashMap<String, Schema> {
+        parse_openapi_content(content)
+            .unwrap()
+            .components
+            .unwrap()
+            .schemas
+            .unwrap()
+    }
+
+    #[test]
+    fn test_refs_inlined_and_all_of_flattened() {
+        let schemas = schemas(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Base": {"type": "object", "required": ["id"], "properties": {"id": {"type": "integer"}}},
+                    "Customer": {"type": "object", "properties": {"email": {"type": "string", "format": "email"}}},
+                    "Order": {
+                        "description": "A customer order",
+                        "allOf": [
+                            {"$ref": "#/components/schemas/Base"},
+                            {"type": "object", "required": ["buyer"], "properties": {
+                                "buyer": {"$ref": "#/components/schemas/Customer"},
+                                "note": {"type": "string", "nullable": true, "example": "leave at door"},
+                                "status": {"type": "string", "enum": ["open", "paid"]}
+                            }}
+                        ]
+                    }
+                }}
+            }"##,
+        );
+
+        assert_eq!(
+            to_json_schema("Order", &schemas).unwrap(),
+            json!({
+                "$schema": DIALECT,
+                "title": "Order",
+                "description": "A customer order",
+                "type": "object",
+                "required": ["id", "buyer"],
+                "properties": {
+                    "id": {"type": "integer"},
+                    "buyer": {"type": "object", "properties": {"email": {"type": "string", "format": "email"}}},
+                    "note": {"type": ["string", "null"], "examples": ["leave at door"]},
+                    "status": {"type": "string", "enum": ["open", "paid"]}
+                }
+            })
+        );
+        assert!(to_json_schema("Missing", &schemas).is_none());
+    }
+
+    #[test]
+    fn test_recursive_refs_use_defs() {
+        let schemas = schemas(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Tree", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Tree": {"type": "object", "properties": {"root": {"$ref": "#/components/schemas/Node"}}},
+                    "Node": {"type": "object", "properties": {
+                        "children": {"type": "array", "items": {"$ref": "#/components/schemas/Node"}},
+                        "tree": {"$ref": "#/components/schemas/Tree"}
+                    }}
+                }}
+            }"##,
+        );
+
+        let document = to_json_schema("Tree", &schemas).unwrap();
+        let node = &document["properties"]["root"];
+        assert_eq!(node["properties"]["tree"], json!({"$ref": "#"}));
+        assert_eq!(
+            node["properties"]["children"]["items"],
+            json!({"$ref": "#/$defs/Node"})
+        );
+        assert_eq!(
+            document["$defs"]["Node"]["properties"]["children"]["items"],
+            json!({"$ref": "#/$defs/Node"})
+        );
+    }
+}
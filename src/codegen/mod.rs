@@ -0,0 +1,195 @@
+//! Model code generated from the component schemas of a spec.
+
+pub mod graphql;
+pub mod json_schema;
+pub mod protobuf;
+pub mod rust;
+pub mod typescript;
+
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use crate::schema_graph::build_schema_graph;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// `allOf` chains followed at most this deep, which also stops cycles
+const MAX_ALL_OF_DEPTH: usize = 8;
+
+/// Target language of the model exporters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    TypeScript,
+    Rust,
+    JsonSchema,
+    GraphQl,
+    Protobuf(protobuf::FieldNumbering),
+}
+
+impl Language {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::TypeScript => "TypeScript",
+            Language::Rust => "Rust",
+            Language::JsonSchema => "JSON Schema",
+            Language::GraphQl => "GraphQL",
+            Language::Protobuf(_) => "Protobuf",
+        }
+    }
+
+    pub fn generate(&self, spec: &OpenApiSpec, names: &[String]) -> Vec<GeneratedFile> {
+        match self {
+            Language::TypeScript => typescript::generate(spec, names),
+            Language::Rust => rust::generate(spec, names),
+            Language::JsonSchema => json_schema::generate(spec, names),
+            Language::GraphQl => graphql::generate(spec, names),
+            Language::Protobuf(numbering) => protobuf::generate(spec, names, *numbering),
+        }
+    }
+
+    /// Schemas to generate for `names`: JSON Schema documents inline what
+    /// they reference, the other languages need the referenced types too.
+    pub fn schemas_to_export(&self, spec: &OpenApiSpec, names: &[String]) -> Vec<String> {
+        match self {
+            Language::JsonSchema => {
+                let known = all_schema_names(spec);
+                names
+                    .iter()
+                    .filter(|name| known.contains(name))
+                    .cloned()
+                    .collect()
+            }
+            _ => with_dependencies(spec, names),
+        }
+    }
+}
+
+/// A generated source file, relative to the output directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub name: String,
+    pub content: String,
+}
+
+pub(crate) fn component_schemas(spec: &OpenApiSpec) -> Option<&HashMap<String, Schema>> {
+    spec.components.as_ref().and_then(|c| c.schemas.as_ref())
+}
+
+/// `names` plus every component schema they reference, directly or through
+/// other schemas, sorted. Unknown names are dropped.
+pub fn with_dependencies(spec: &OpenApiSpec, names: &[String]) -> Vec<String> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    let graph = build_schema_graph(spec);
+    let closure: BTreeSet<String> = names
+        .iter()
+        .filter(|name| schemas.contains_key(*name))
+        .flat_map(|name| graph.transitive_dependencies(name))
+        .collect();
+    closure.into_iter().collect()
+}
+
+/// Every component schema name, sorted.
+pub fn all_schema_names(spec: &OpenApiSpec) -> Vec<String> {
+    let mut names: Vec<String> = component_schemas(spec)
+        .map(|schemas| schemas.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort_unstable();
+    names
+}
+
+/// PascalCase identifier for a schema name: `order_item` and `order-item`
+/// both become `OrderItem`.
+pub fn type_name(schema_name: &str) -> String {
+    let name: String = schema_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    match name.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+    }
+}
+
+/// Properties of an object schema and its `allOf` parts, sorted by name,
+/// with the required ones; None when it has none.
+pub(crate) fn object_fields<'a>(
+    schema: &'a Schema,
+    schemas: &'a HashMap<String, Schema>,
+) -> Option<(BTreeMap<&'a str, &'a Schema>, BTreeSet<&'a str>)> {
+    let mut properties = BTreeMap::new();
+    let mut required = BTreeSet::new();
+    let mut pending = vec![(schema, 0)];
+    while let Some((part, depth)) = pending.pop() {
+        let part = match part.reference.as_deref() {
+            Some(reference) => {
+                match extract_schema_name_from_ref(reference).and_then(|name| schemas.get(name)) {
+                    Some(target) => target,
+                    None => continue,
+                }
+            }
+            None => part,
+        };
+        for (name, property) in part.properties.iter().flatten() {
+            properties.entry(name.as_str()).or_insert(property);
+        }
+        required.extend(part.required.iter().flatten().map(String::as_str));
+        if depth < MAX_ALL_OF_DEPTH {
+            pending.extend(part.all_of.iter().flatten().map(|p| (p, depth + 1)));
+        }
+    }
+    (!properties.is_empty()).then_some((properties, required))
+}
+
+/// Values of an enum of strings.
+pub(crate) fn string_enum(schema: &Schema) -> Option<Vec<String>> {
+    let values: Vec<String> = schema
+        .enum_
+        .iter()
+        .flatten()
+        .filter_map(|value| value.as_str().map(str::to_string))
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+/// Type names of `oneOf`/`anyOf` variants, when they all reference a
+/// component schema.
+pub(crate) fn union_members(schema: &Schema) -> Option<Vec<String>> {
+    let variants = schema.one_of.as_ref().or(schema.any_of.as_ref())?;
+    let members: Option<Vec<String>> = variants
+        .iter()
+        .map(|variant| extract_schema_name_from_ref(variant.reference.as_deref()?).map(type_name))
+        .collect();
+    members.filter(|members| !members.is_empty())
+}
+
+/// Writes the files into `dir`, creating it if needed, and returns how many
+/// were written.
+pub fn write_files(dir: &Path, files: &[GeneratedFile]) -> Result<usize> {
+    std::fs::create_dir_all(dir)?;
+    for file in files {
+        std::fs::write(dir.join(&file.name), &file.content)?;
+    }
+    Ok(files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(type_name("Order"), "Order");
+        assert_eq!(type_name("order_item"), "OrderItem");
+        assert_eq!(type_name("v2.user-profile"), "V2UserProfile");
+        assert_eq!(type_name("404-error"), "_404Error");
+    }
+}
@@ -0,0 +1,462 @@
+use super::{
+    component_schemas, object_fields, string_enum, type_name, union_members, GeneratedFile,
+};
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::{BTreeSet, HashMap};
+
+const INDENT: &str = "  ";
+
+/// Largest field number protobuf accepts
+const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+
+/// Field numbers reserved by the protobuf implementation
+const RESERVED_NUMBERS: std::ops::RangeInclusive<u32> = 19000..=19999;
+
+const TIMESTAMP: &str = "google.protobuf.Timestamp";
+const VALUE: &str = "google.protobuf.Value";
+const STRUCT: &str = "google.protobuf.Struct";
+const LIST_VALUE: &str = "google.protobuf.ListValue";
+
+/// How the fields of a message are numbered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldNumbering {
+    /// 1, 2, 3... in property name order: compact, but adding a property
+    /// renumbers the ones after it
+    #[default]
+    Alphabetical,
+    /// From a hash of the property name: a field keeps its number across
+    /// spec versions, at the cost of longer tags on the wire
+    Hashed,
+}
+
+impl FieldNumbering {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "alphabetical" => Some(FieldNumbering::Alphabetical),
+            "hashed" => Some(FieldNumbering::Hashed),
+            _ => None,
+        }
+    }
+
+    /// Numbers of `names`, which are sorted and distinct.
+    fn numbers(self, names: &[&str]) -> Vec<u32> {
+        match self {
+            FieldNumbering::Alphabetical => (1..=names.len() as u32).collect(),
+            FieldNumbering::Hashed => {
+                let mut taken = BTreeSet::new();
+                names
+                    .iter()
+                    .map(|name| {
+                        // FNV-1a, stable across platforms and releases
+                        let hash = name.bytes().fold(0x811c_9dc5u32, |hash, byte| {
+                            (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+                        });
+                        let mut number = hash % MAX_FIELD_NUMBER + 1;
+                        while RESERVED_NUMBERS.contains(&number) || !taken.insert(number) {
+                            number = number % MAX_FIELD_NUMBER + 1;
+                        }
+                        number
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A single `models.proto` (proto3) with one message per schema, `allOf`
+/// parts flattened, or an enum for enums of strings. Nested objects and
+/// enums become nested declarations, `oneOf`/`anyOf` references a `oneof`.
+/// Fields are snake_case with a `json_name` when the property name
+/// differs, and optional properties of scalar types are `optional`.
+pub fn generate(
+    spec: &OpenApiSpec,
+    names: &[String],
+    numbering: FieldNumbering,
+) -> Vec<GeneratedFile> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    let mut proto = Proto {
+        schemas,
+        numbering,
+        imports: BTreeSet::new(),
+    };
+    let declarations: Vec<String> = names
+        .iter()
+        .filter_map(|name| Some((name, schemas.get(name)?)))
+        .map(|(name, schema)| proto.declaration(&type_name(name), schema, ""))
+        .collect();
+    if declarations.is_empty() {
+        return Vec::new();
+    }
+
+    let mut content = format!(
+        "// Generated by openapi-explorer from {} {}\n\nsyntax = \"proto3\";\n\npackage {};\n\n",
+        spec.info.title,
+        spec.info.version,
+        package_name(&spec.info.title)
+    );
+    for import in &proto.imports {
+        content.push_str(&format!("import \"{}\";\n", import));
+    }
+    if !proto.imports.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&declarations.join("\n"));
+    vec![GeneratedFile {
+        name: "models.proto".to_string(),
+        content,
+    }]
+}
+
+struct Proto<'a> {
+    schemas: &'a HashMap<String, Schema>,
+    numbering: FieldNumbering,
+    imports: BTreeSet<&'static str>,
+}
+
+impl<'a> Proto<'a> {
+    /// Message or enum `name` for `schema`, indented by `indent`.
+    fn declaration(&mut self, name: &str, schema: &'a Schema, indent: &str) -> String {
+        let mut out = comment(schema.description.as_deref(), indent);
+        let inner = format!("{}{}", indent, INDENT);
+        if let Some(values) = string_enum(schema) {
+            let prefix = screaming_snake(name);
+            out.push_str(&format!("{}enum {} {{\n", indent, name));
+            out.push_str(&format!("{}{}_UNSPECIFIED = 0;\n", inner, prefix));
+            for (number, value) in values.iter().enumerate() {
+                out.push_str(&format!(
+                    "{}{}_{} = {};\n",
+                    inner,
+                    prefix,
+                    screaming_snake(value),
+                    number + 1
+                ));
+            }
+            out.push_str(&format!("{}}}\n", indent));
+            return out;
+        }
+
+        let mut nested = String::new();
+        let mut fields = String::new();
+        if let Some(members) = union_members(schema) {
+            fields.push_str(&format!("{}oneof value {{\n", inner));
+            for (number, member) in members.iter().enumerate() {
+                fields.push_str(&format!(
+                    "{}{}{} {} = {};\n",
+                    inner,
+                    INDENT,
+                    member,
+                    snake_case(member),
+                    number + 1
+                ));
+            }
+            fields.push_str(&format!("{}}}\n", inner));
+        } else if let Some((properties, required)) = object_fields(schema, self.schemas) {
+            let names: Vec<&str> = properties.keys().copied().collect();
+            let numbers = self.numbering.numbers(&names);
+            for ((property_name, property), number) in properties.into_iter().zip(numbers) {
+                let field = snake_case(property_name);
+                let optional = !required.contains(property_name) || property.nullable == Some(true);
+                let field_type = self.field_type(
+                    &type_name(property_name),
+                    property,
+                    optional,
+                    &inner,
+                    &mut nested,
+                );
+                fields.push_str(&comment(property.description.as_deref(), &inner));
+                fields.push_str(&format!("{}{} {} = {}", inner, field_type, field, number));
+                if field != *property_name {
+                    fields.push_str(&format!(
+                        " [json_name = {}]",
+                        serde_json::Value::from(property_name)
+                    ));
+                }
+                fields.push_str(";\n");
+            }
+        } else {
+            // Messages hold fields only: wrap anything else
+            let field_type = self.field_type("Value", schema, false, &inner, &mut nested);
+            fields.push_str(&format!("{}{} value = 1;\n", inner, field_type));
+        }
+        out.push_str(&format!(
+            "{}message {} {{\n{}{}{}}}\n",
+            indent, name, nested, fields, indent
+        ));
+        out
+    }
+
+    /// Type of a field with its `repeated`/`optional` label; inline enums
+    /// and objects are declared in `nested` as `nested_name`.
+    fn field_type(
+        &mut self,
+        nested_name: &str,
+        schema: &'a Schema,
+        optional: bool,
+        indent: &str,
+        nested: &mut String,
+    ) -> String {
+        if schema.reference.is_none() && schema.schema_type.as_deref() == Some("array") {
+            return match schema.items.as_deref() {
+                // Lists of lists have no protobuf equivalent
+                Some(items) if items.schema_type.as_deref() != Some("array") => {
+                    let (item, _) = self.value_type(nested_name, items, indent, nested);
+                    format!("repeated {}", item)
+                }
+                _ => self.well_known(LIST_VALUE, "google/protobuf/struct.proto"),
+            };
+        }
+        if schema.properties.is_none() && schema.reference.is_none() {
+            if let Some(values) = schema.additional_properties.as_deref() {
+                let (value, _) = match values.schema_type.as_deref() {
+                    Some("array") => (
+                        self.well_known(LIST_VALUE, "google/protobuf/struct.proto"),
+                        true,
+                    ),
+                    _ if values.additional_properties.is_some() && values.properties.is_none() => (
+                        self.well_known(STRUCT, "google/protobuf/struct.proto"),
+                        true,
+                    ),
+                    _ => self.value_type(nested_name, values, indent, nested),
+                };
+                return format!("map<string, {}>", value);
+            }
+        }
+        let (value, is_message) = self.value_type(nested_name, schema, indent, nested);
+        if optional && !is_message {
+            format!("optional {}", value)
+        } else {
+            value
+        }
+    }
+
+    /// Type of a single value, and whether it is a message (which has
+    /// presence without `optional`).
+    fn value_type(
+        &mut self,
+        nested_name: &str,
+        schema: &'a Schema,
+        indent: &str,
+        nested: &mut String,
+    ) -> (String, bool) {
+        if let Some(reference) = &schema.reference {
+            return match extract_schema_name_from_ref(reference).and_then(|name| {
+                Some((
+                    type_name(name),
+                    string_enum(self.schemas.get(name)?).is_none(),
+                ))
+            }) {
+                Some(known) => known,
+                None => (self.well_known(VALUE, "google/protobuf/struct.proto"), true),
+            };
+        }
+        if string_enum(schema).is_some() {
+            nested.push_str(&self.declaration(nested_name, schema, indent));
+            return (nested_name.to_string(), false);
+        }
+        let scalar = match (schema.schema_type.as_deref(), schema.format.as_deref()) {
+            (Some("string"), Some("date-time")) => {
+                return (
+                    self.well_known(TIMESTAMP, "google/protobuf/timestamp.proto"),
+                    true,
+                )
+            }
+            (Some("string"), Some("byte" | "binary")) => "bytes",
+            (Some("string"), _) => "string",
+            (Some("integer"), Some("int32")) => "int32",
+            (Some("integer"), _) => "int64",
+            (Some("number"), Some("float")) => "float",
+            (Some("number"), _) => "double",
+            (Some("boolean"), _) => "bool",
+            _ if schema.properties.is_some()
+                || schema.all_of.is_some()
+                || union_members(schema).is_some() =>
+            {
+                nested.push_str(&self.declaration(nested_name, schema, indent));
+                return (nested_name.to_string(), true);
+            }
+            _ => return (self.well_known(VALUE, "google/protobuf/struct.proto"), true),
+        };
+        (scalar.to_string(), false)
+    }
+
+    fn well_known(&mut self, name: &str, import: &'static str) -> String {
+        self.imports.insert(import);
+        name.to_string()
+    }
+}
+
+/// Words of an identifier: split on non-alphanumerics and lower to upper
+/// case changes, so `createdAt`, `created-at` and `CREATED_AT` agree.
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            previous = None;
+            continue;
+        }
+        let boundary = match previous {
+            None => true,
+            Some(p) => c.is_ascii_uppercase() && (p.is_ascii_lowercase() || p.is_ascii_digit()),
+        };
+        if boundary {
+            words.push(String::new());
+        }
+        if let Some(word) = words.last_mut() {
+            word.push(c);
+        }
+        previous = Some(c);
+    }
+    words
+}
+
+fn snake_case(name: &str) -> String {
+    let name = words(name).join("_").to_ascii_lowercase();
+    match name.chars().next() {
+        None => "field".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+    }
+}
+
+fn screaming_snake(name: &str) -> String {
+    let name = words(name).join("_").to_ascii_uppercase();
+    if name.is_empty() {
+        "EMPTY".to_string()
+    } else {
+        name
+    }
+}
+
+/// Package named after the API title, e.g. `pet_store` for "Pet Store".
+fn package_name(title: &str) -> String {
+    let name = snake_case(title);
+    if name == "field" {
+        "api".to_string()
+    } else {
+        name
+    }
+}
+
+fn comment(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text.map(str::trim).filter(|t| !t.is_empty()) else {
+        return String::new();
+    };
+    text.lines()
+        .map(|line| format!("{}// {}", indent, line).trim_end().to_string() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    const SHOP: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Pet Shop", "version": "1.0"},
+        "paths": {},
+        "components": {"schemas": {
+            "Order": {
+                "type": "object",
+                "description": "A customer order",
+                "required": ["id", "lines"],
+                "properties": {
+                    "id": {"type": "integer", "format": "int32"},
+                    "status": {"type": "string", "enum": ["pending", "paid"]},
+                    "lines": {"type": "array", "items": {"$ref": "#/components/schemas/OrderLine"}},
+                    "createdAt": {"type": "string", "format": "date-time", "description": "Creation time"},
+                    "meta": {"type": "object", "additionalProperties": {"type": "string"}},
+                    "payment": {"$ref": "#/components/schemas/Payment"},
+                    "currency": {"$ref": "#/components/schemas/Currency"}
+                }
+            },
+            "OrderLine": {"type": "object", "properties": {
+                "price": {"type": "object", "properties": {"amount": {"type": "number"}}}
+            }},
+            "Payment": {"oneOf": [{"$ref": "#/components/schemas/Card"}, {"$ref": "#/components/schemas/BankTransfer"}]},
+            "Card": {"type": "object", "properties": {"last4": {"type": "string"}}},
+            "BankTransfer": {"type": "object", "properties": {"iban": {"type": "string"}}},
+            "Currency": {"type": "string", "enum": ["EUR", "USD"]},
+            "Sku": {"type": "string"}
+        }}
+    }"##;
+
+    #[test]
+    fn test_proto_messages() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let names: Vec<String> = ["Order", "OrderLine", "Payment", "Currency", "Sku"]
+            .map(str::to_string)
+            .to_vec();
+        let files = generate(&spec, &names, FieldNumbering::Alphabetical);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "models.proto");
+        assert_eq!(
+            files[0].content,
+            r#"// Generated by openapi-explorer from Pet Shop 1.0
+
+syntax = "proto3";
+
+package pet_shop;
+
+import "google/protobuf/timestamp.proto";
+
+// A customer order
+message Order {
+  enum Status {
+    STATUS_UNSPECIFIED = 0;
+    STATUS_PENDING = 1;
+    STATUS_PAID = 2;
+  }
+  // Creation time
+  google.protobuf.Timestamp created_at = 1 [json_name = "createdAt"];
+  optional Currency currency = 2;
+  int32 id = 3;
+  repeated OrderLine lines = 4;
+  map<string, string> meta = 5;
+  Payment payment = 6;
+  optional Status status = 7;
+}
+
+message OrderLine {
+  message Price {
+    optional double amount = 1;
+  }
+  Price price = 1;
+}
+
+message Payment {
+  oneof value {
+    Card card = 1;
+    BankTransfer bank_transfer = 2;
+  }
+}
+
+enum Currency {
+  CURRENCY_UNSPECIFIED = 0;
+  CURRENCY_EUR = 1;
+  CURRENCY_USD = 2;
+}
+
+message Sku {
+  string value = 1;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_hashed_numbers_are_stable() {
+        let numbering = FieldNumbering::Hashed;
+        let before = numbering.numbers(&["email", "name"]);
+        let after = numbering.numbers(&["age", "email", "name"]);
+        assert_eq!(before, after[1..]);
+        assert!(after
+            .iter()
+            .all(|n| (1..=MAX_FIELD_NUMBER).contains(n) && !RESERVED_NUMBERS.contains(n)));
+        assert_eq!(FieldNumbering::parse("hashed"), Some(numbering));
+        assert_eq!(FieldNumbering::parse("random"), None);
+    }
+}
@@ -0,0 +1,464 @@
+use super::{component_schemas, type_name, GeneratedFile};
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::HashSet;
+
+/// Output file of the Rust exporter
+pub const MODELS_FILE: &str = "models.rs";
+
+const DERIVES: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]";
+const UNIT_ENUM_DERIVES: &str =
+    "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]";
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+/// Keywords that cannot be raw identifiers
+const RESERVED: &[&str] = &["crate", "self", "Self", "super"];
+
+/// A single `models.rs` with one item per schema, followed by the types
+/// generated for its inline enums and objects.
+pub fn generate(spec: &OpenApiSpec, names: &[String]) -> Vec<GeneratedFile> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    let mut generator = Generator {
+        items: Vec::new(),
+        taken: schemas.keys().map(|name| type_name(name)).collect(),
+    };
+    for name in names {
+        if let Some(schema) = schemas.get(name) {
+            generator.item(&type_name(name), schema);
+        }
+    }
+    if generator.items.is_empty() {
+        return Vec::new();
+    }
+
+    let body = generator.items.join("\n");
+    let mut content = format!(
+        "//! Generated by openapi-explorer from {} {}\n\nuse serde::{{Deserialize, Serialize}};\n",
+        spec.info.title, spec.info.version
+    );
+    if body.contains("HashMap<") {
+        content.push_str("use std::collections::HashMap;\n");
+    }
+    content.push('\n');
+    content.push_str(&body);
+    vec![GeneratedFile {
+        name: MODELS_FILE.to_string(),
+        content,
+    }]
+}
+
+/// Items for one schema, as they appear in `models.rs`.
+pub fn render_schema(name: &str, schema: &Schema) -> String {
+    let mut generator = Generator {
+        items: Vec::new(),
+        taken: HashSet::from([type_name(name)]),
+    };
+    generator.item(&type_name(name), schema);
+    generator.items.join("\n")
+}
+
+struct Generator {
+    items: Vec<String>,
+    /// Type names in use, so generated inline types do not collide
+    taken: HashSet<String>,
+}
+
+impl Generator {
+    fn item(&mut self, ident: &str, schema: &Schema) {
+        // Reserve the slot so inline types generated below follow their parent
+        let slot = self.items.len();
+        self.items.push(String::new());
+        let mut out = doc_comment(schema.description.as_deref(), "");
+
+        if let Some(values) = string_enum(schema) {
+            out.push_str(&unit_enum(ident, &values));
+        } else if let Some(variants) = schema.one_of.as_ref().or(schema.any_of.as_ref()) {
+            if let Some(discriminator) = &schema.discriminator {
+                if !out.is_empty() {
+                    out.push_str("///\n");
+                }
+                out.push_str(&format!(
+                    "/// Discriminated by `{}`.\n",
+                    discriminator.property_name
+                ));
+            }
+            out.push_str(&format!(
+                "{}\n#[serde(untagged)]\npub enum {} {{\n",
+                DERIVES, ident
+            ));
+            for (i, variant) in variants.iter().enumerate() {
+                let variant_ident = match variant.reference.as_deref() {
+                    Some(reference) => extract_schema_name_from_ref(reference)
+                        .map(type_name)
+                        .unwrap_or_else(|| format!("Variant{}", i + 1)),
+                    None => format!("Variant{}", i + 1),
+                };
+                let ty = self.rust_type(variant, &format!("{}{}", ident, variant_ident), None);
+                out.push_str(&format!("    {}({}),\n", variant_ident, ty));
+            }
+            out.push_str("}\n");
+        } else if schema.properties.is_some() || schema.all_of.is_some() {
+            out.push_str(&self.record(ident, schema));
+        } else {
+            let ty = self.rust_type(schema, &format!("{}Value", ident), None);
+            out.push_str(&format!(
+                "pub type {} = {};\n",
+                ident,
+                wrap_nullable(schema, ty)
+            ));
+        }
+        self.items[slot] = out;
+    }
+
+    /// Struct with the schema's properties; `allOf` references are
+    /// flattened in and inline `allOf` members merged.
+    fn record(&mut self, ident: &str, schema: &Schema) -> String {
+        let mut fields = String::new();
+        let mut taken_fields = HashSet::new();
+
+        let mut parts: Vec<&Schema> = schema.all_of.iter().flatten().collect();
+        parts.push(schema);
+        for part in parts {
+            if let Some(target) = part
+                .reference
+                .as_deref()
+                .and_then(extract_schema_name_from_ref)
+            {
+                let target = type_name(target);
+                let field = unique(&field_ident(&target).0, &mut taken_fields);
+                fields.push_str(&format!(
+                    "    #[serde(flatten)]\n    pub {}: {},\n",
+                    field,
+                    if target == ident {
+                        format!("Box<{}>", target)
+                    } else {
+                        target
+                    }
+                ));
+                continue;
+            }
+
+            let mut properties: Vec<(&String, &Schema)> =
+                part.properties.iter().flatten().collect();
+            properties.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, property) in properties {
+                let (field, renamed) = field_ident(name);
+                let field = unique(&field, &mut taken_fields);
+                let context = format!("{}{}", ident, type_name(name));
+                let ty = self.rust_type(property, &context, Some(ident));
+                let optional = !part.is_field_required(name) || property.nullable.unwrap_or(false);
+
+                let mut attributes = Vec::new();
+                if renamed || field.trim_start_matches("r#") != name {
+                    attributes.push(format!(
+                        "rename = {}",
+                        serde_json::Value::from(name.as_str())
+                    ));
+                }
+                if optional {
+                    attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                }
+
+                fields.push_str(&doc_comment(property.description.as_deref(), "    "));
+                if !attributes.is_empty() {
+                    fields.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+                }
+                fields.push_str(&format!(
+                    "    pub {}: {},\n",
+                    field,
+                    if optional {
+                        format!("Option<{}>", ty)
+                    } else {
+                        ty
+                    }
+                ));
+            }
+        }
+
+        format!("{}\npub struct {} {{\n{}}}\n", DERIVES, ident, fields)
+    }
+
+    /// Rust type of a schema used inside another item. `owner` is the struct
+    /// holding the value directly (not through a `Vec` or map), which must
+    /// box references back to itself.
+    fn rust_type(&mut self, schema: &Schema, context: &str, owner: Option<&str>) -> String {
+        if let Some(reference) = &schema.reference {
+            let Some(target) = extract_schema_name_from_ref(reference).map(type_name) else {
+                return "serde_json::Value".to_string();
+            };
+            return match owner {
+                Some(owner) if owner == target => format!("Box<{}>", target),
+                _ => target,
+            };
+        }
+        if let [single] = schema.all_of.as_deref().unwrap_or_default() {
+            if single.reference.is_some() && schema.properties.is_none() {
+                return self.rust_type(single, context, owner);
+            }
+        }
+        if string_enum(schema).is_some()
+            || schema.all_of.is_some()
+            || schema.one_of.is_some()
+            || schema.any_of.is_some()
+            || schema.properties.is_some()
+        {
+            let ident = self.claim(context);
+            self.item(&ident, schema);
+            return ident;
+        }
+
+        match schema.schema_type.as_deref() {
+            Some("string") => "String".to_string(),
+            Some("integer") => match schema.format.as_deref() {
+                Some("int32") => "i32".to_string(),
+                _ => "i64".to_string(),
+            },
+            Some("number") => match schema.format.as_deref() {
+                Some("float") => "f32".to_string(),
+                _ => "f64".to_string(),
+            },
+            Some("boolean") => "bool".to_string(),
+            Some("array") => match schema.items.as_deref() {
+                Some(items) => {
+                    let item = self.rust_type(items, &format!("{}Item", context), None);
+                    format!("Vec<{}>", wrap_nullable(items, item))
+                }
+                None => "Vec<serde_json::Value>".to_string(),
+            },
+            _ => match schema.additional_properties.as_deref() {
+                Some(values) if values.schema_type.is_some() || values.reference.is_some() => {
+                    let value = self.rust_type(values, &format!("{}Value", context), None);
+                    format!("HashMap<String, {}>", wrap_nullable(values, value))
+                }
+                _ => "serde_json::Value".to_string(),
+            },
+        }
+    }
+
+    fn claim(&mut self, wanted: &str) -> String {
+        let mut ident = wanted.to_string();
+        let mut n = 2;
+        while !self.taken.insert(ident.clone()) {
+            ident = format!("{}{}", wanted, n);
+            n += 1;
+        }
+        ident
+    }
+}
+
+fn string_enum(schema: &Schema) -> Option<Vec<&str>> {
+    let values = schema.enum_.as_ref().filter(|values| !values.is_empty())?;
+    values.iter().map(|value| value.as_str()).collect()
+}
+
+fn unit_enum(ident: &str, values: &[&str]) -> String {
+    let mut out = format!("{}\npub enum {} {{\n", UNIT_ENUM_DERIVES, ident);
+    let mut taken = HashSet::new();
+    for value in values {
+        let variant = unique(&type_name(value), &mut taken);
+        if variant != *value {
+            out.push_str(&format!(
+                "    #[serde(rename = {})]\n",
+                serde_json::Value::from(*value)
+            ));
+        }
+        out.push_str(&format!("    {},\n", variant));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn wrap_nullable(schema: &Schema, ty: String) -> String {
+    if schema.nullable.unwrap_or(false) {
+        format!("Option<{}>", ty)
+    } else {
+        ty
+    }
+}
+
+fn unique(ident: &str, taken: &mut HashSet<String>) -> String {
+    let mut candidate = ident.to_string();
+    let mut n = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}_{}", ident, n);
+        n += 1;
+    }
+    candidate
+}
+
+/// snake_case field identifier for a property name, and whether it had to
+/// be escaped (raw identifier or trailing underscore).
+fn field_ident(name: &str) -> (String, bool) {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_alphanumeric() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = c.is_ascii_uppercase()
+                && prev.is_some_and(|p| {
+                    p.is_ascii_lowercase()
+                        || p.is_ascii_digit()
+                        || (p.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+                });
+            if boundary && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else if !snake.is_empty() && !snake.ends_with('_') {
+            snake.push('_');
+        }
+    }
+    let mut snake = snake.trim_end_matches('_').to_string();
+    if snake.is_empty() {
+        snake = "field".to_string();
+    }
+    if snake.starts_with(|c: char| c.is_ascii_digit()) {
+        snake.insert(0, '_');
+    }
+
+    if RESERVED.contains(&snake.as_str()) {
+        (format!("{}_", snake), true)
+    } else if KEYWORDS.contains(&snake.as_str()) {
+        (format!("r#{}", snake), false)
+    } else {
+        (snake, false)
+    }
+}
+
+fn doc_comment(description: Option<&str>, indent: &str) -> String {
+    description
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|description| {
+            description
+                .lines()
+                .map(|line| {
+                    if line.trim().is_empty() {
+                        format!("{}///\n", indent)
+                    } else {
+                        format!("{}/// {}\n", indent, line.trim_end())
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    const SHOP: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Shop", "version": "1.0"},
+        "paths": {},
+        "components": {"schemas": {
+            "Order": {
+                "type": "object",
+                "description": "A customer order",
+                "required": ["id", "lines", "type"],
+                "properties": {
+                    "id": {"type": "integer", "format": "int32"},
+                    "type": {"type": "string"},
+                    "status": {"type": "string", "enum": ["pending", "in-transit"]},
+                    "lines": {"type": "array", "items": {"$ref": "#/components/schemas/order_line"}},
+                    "totalAmount": {"type": "number", "description": "Including taxes"},
+                    "parent": {"$ref": "#/components/schemas/Order"},
+                    "meta": {"type": "object", "additionalProperties": {"type": "string"}}
+                }
+            },
+            "order_line": {"type": "object", "properties": {"sku": {"type": "string", "nullable": true}}},
+            "Payment": {
+                "oneOf": [{"$ref": "#/components/schemas/Card"}, {"type": "string"}],
+                "discriminator": {"propertyName": "kind"}
+            },
+            "Card": {"allOf": [{"$ref": "#/components/schemas/Base"}, {"type": "object", "required": ["last4"], "properties": {"last4": {"type": "string"}}}]},
+            "Base": {"type": "object", "properties": {"kind": {"type": "string"}}},
+            "Sku": {"type": "string"}
+        }}
+    }"##;
+
+    #[test]
+    fn test_struct_generation() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let schemas = component_schemas(&spec).unwrap();
+
+        assert_eq!(
+            render_schema("Order", &schemas["Order"]),
+            r#"/// A customer order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    pub id: i32,
+    pub lines: Vec<OrderLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Order>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    /// Including taxes
+    #[serde(rename = "totalAmount", skip_serializing_if = "Option::is_none")]
+    pub total_amount: Option<f64>,
+    pub r#type: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "in-transit")]
+    InTransit,
+}
+"#
+        );
+        assert_eq!(
+            render_schema("Card", &schemas["Card"]),
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub struct Card {\n    #[serde(flatten)]\n    pub base: Base,\n    pub last4: String,\n}\n"
+        );
+        assert_eq!(
+            render_schema("Payment", &schemas["Payment"]),
+            "/// Discriminated by `kind`.\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n#[serde(untagged)]\npub enum Payment {\n    Card(Card),\n    Variant2(String),\n}\n"
+        );
+        assert_eq!(
+            render_schema("Sku", &schemas["Sku"]),
+            "pub type Sku = String;\n"
+        );
+    }
+
+    #[test]
+    fn test_field_idents() {
+        assert_eq!(field_ident("firstName"), ("first_name".to_string(), false));
+        assert_eq!(
+            field_ident("HTTPStatus"),
+            ("http_status".to_string(), false)
+        );
+        assert_eq!(field_ident("x-trace-id"), ("x_trace_id".to_string(), false));
+        assert_eq!(field_ident("3ds"), ("_3ds".to_string(), false));
+        assert_eq!(field_ident("self"), ("self_".to_string(), true));
+        assert_eq!(field_ident("match"), ("r#match".to_string(), false));
+    }
+
+    #[test]
+    fn test_generate_models_file() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let names = crate::codegen::with_dependencies(&spec, &["Order".to_string()]);
+        let files = generate(&spec, &names);
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, MODELS_FILE);
+        let content = &files[0].content;
+        assert!(content.starts_with(
+            "//! Generated by openapi-explorer from Shop 1.0\n\nuse serde::{Deserialize, Serialize};\nuse std::collections::HashMap;\n\n"
+        ));
+        assert!(content.contains("pub struct OrderLine {\n    #[serde(skip_serializing_if = \"Option::is_none\")]\n    pub sku: Option<String>,\n}"));
+    }
+}
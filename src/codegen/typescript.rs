@@ -0,0 +1,314 @@
+use super::{component_schemas, type_name, GeneratedFile};
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use crate::schema_graph::referenced_schemas;
+
+const INDENT: &str = "  ";
+
+/// One `<Type>.ts` module per schema, importing the types it references,
+/// plus an `index.ts` re-exporting them all.
+pub fn generate(spec: &OpenApiSpec, names: &[String]) -> Vec<GeneratedFile> {
+    let Some(schemas) = component_schemas(spec) else {
+        return Vec::new();
+    };
+    let header = format!(
+        "// Generated by openapi-explorer from {} {}\n",
+        spec.info.title, spec.info.version
+    );
+
+    let mut files: Vec<GeneratedFile> = names
+        .iter()
+        .filter_map(|name| Some((name, schemas.get(name)?)))
+        .map(|(name, schema)| {
+            let own_type = type_name(name);
+            let mut content = header.clone();
+            let mut imports: Vec<String> = referenced_schemas(schema)
+                .iter()
+                .map(|referenced| type_name(referenced))
+                .filter(|referenced| *referenced != own_type)
+                .collect();
+            imports.sort_unstable();
+            imports.dedup();
+            for import in &imports {
+                content.push_str(&format!(
+                    "import type {{ {} }} from \"./{}\";\n",
+                    import, import
+                ));
+            }
+            content.push('\n');
+            content.push_str(&render_declaration(name, schema));
+            GeneratedFile {
+                name: format!("{}.ts", own_type),
+                content,
+            }
+        })
+        .collect();
+
+    if !files.is_empty() {
+        let mut index = header;
+        for file in &files {
+            index.push_str(&format!(
+                "export * from \"./{}\";\n",
+                file.name.trim_end_matches(".ts")
+            ));
+        }
+        files.push(GeneratedFile {
+            name: "index.ts".to_string(),
+            content: index,
+        });
+    }
+    files
+}
+
+/// `export interface` for plain objects, `export type` for everything else.
+pub fn render_declaration(name: &str, schema: &Schema) -> String {
+    let mut out = doc_comment(schema.description.as_deref(), "");
+    let is_plain_object = schema.properties.is_some()
+        && schema.reference.is_none()
+        && schema.enum_.is_none()
+        && schema.all_of.is_none()
+        && schema.one_of.is_none()
+        && schema.any_of.is_none()
+        && !schema.nullable.unwrap_or(false);
+
+    if is_plain_object {
+        out.push_str(&format!(
+            "export interface {} {}\n",
+            type_name(name),
+            object_body(schema, "")
+        ));
+    } else {
+        out.push_str(&format!(
+            "export type {} = {};\n",
+            type_name(name),
+            ts_type(schema, "")
+        ));
+    }
+    out
+}
+
+/// TypeScript type of a schema; `indent` is the indentation of the line the
+/// type starts on, used by inline object literals.
+pub fn ts_type(schema: &Schema, indent: &str) -> String {
+    let base = base_type(schema, indent);
+    if schema.nullable.unwrap_or(false) && base != "null" && base != "unknown" {
+        format!("{} | null", base)
+    } else {
+        base
+    }
+}
+
+fn base_type(schema: &Schema, indent: &str) -> String {
+    if let Some(reference) = &schema.reference {
+        return extract_schema_name_from_ref(reference)
+            .map(type_name)
+            .unwrap_or_else(|| "unknown".to_string());
+    }
+    if let Some(values) = schema.enum_.as_ref().filter(|values| !values.is_empty()) {
+        return values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    if let Some(parts) = &schema.all_of {
+        return combine(parts, " & ", indent);
+    }
+    if let Some(variants) = schema.one_of.as_ref().or(schema.any_of.as_ref()) {
+        return combine(variants, " | ", indent);
+    }
+
+    match schema.schema_type.as_deref() {
+        Some("string") => "string".to_string(),
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("array") => {
+            let item = schema
+                .items
+                .as_deref()
+                .map(|items| ts_type(items, indent))
+                .unwrap_or_else(|| "unknown".to_string());
+            if item.contains(' ') && !item.starts_with('{') {
+                format!("Array<{}>", item)
+            } else {
+                format!("{}[]", item)
+            }
+        }
+        _ if schema.properties.is_some() => object_body(schema, indent),
+        _ => match &schema.additional_properties {
+            Some(values) => format!("Record<string, {}>", ts_type(values, indent)),
+            None if schema.schema_type.as_deref() == Some("object") => {
+                "Record<string, unknown>".to_string()
+            }
+            None => "unknown".to_string(),
+        },
+    }
+}
+
+fn combine(schemas: &[Schema], separator: &str, indent: &str) -> String {
+    let parts: Vec<String> = schemas
+        .iter()
+        .map(|schema| {
+            let part = ts_type(schema, indent);
+            if part.contains(" | ") && separator == " & " {
+                format!("({})", part)
+            } else {
+                part
+            }
+        })
+        .collect();
+    match parts.len() {
+        0 => "unknown".to_string(),
+        _ => parts.join(separator),
+    }
+}
+
+/// `{ ... }` with one member per property, sorted by name; properties not
+/// listed in `required` are optional.
+fn object_body(schema: &Schema, indent: &str) -> String {
+    let member_indent = format!("{}{}", indent, INDENT);
+    let mut properties: Vec<(&String, &Schema)> = schema.properties.iter().flatten().collect();
+    properties.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut out = "{\n".to_string();
+    for (name, property) in properties {
+        out.push_str(&doc_comment(
+            property.description.as_deref(),
+            &member_indent,
+        ));
+        out.push_str(&format!(
+            "{}{}{}{}: {};\n",
+            member_indent,
+            if property.read_only.unwrap_or(false) {
+                "readonly "
+            } else {
+                ""
+            },
+            property_key(name),
+            if schema.is_field_required(name) {
+                ""
+            } else {
+                "?"
+            },
+            ts_type(property, &member_indent)
+        ));
+    }
+    if let Some(values) = &schema.additional_properties {
+        out.push_str(&format!(
+            "{}[key: string]: {};\n",
+            member_indent,
+            ts_type(values, &member_indent)
+        ));
+    }
+    out.push_str(indent);
+    out.push('}');
+    out
+}
+
+fn property_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        serde_json::Value::from(name).to_string()
+    }
+}
+
+fn doc_comment(description: Option<&str>, indent: &str) -> String {
+    let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    let description = description.replace("*/", "*\\/");
+    if !description.contains('\n') {
+        return format!("{}/** {} */\n", indent, description);
+    }
+    let mut out = format!("{}/**\n", indent);
+    for line in description.lines() {
+        out.push_str(&format!("{} * {}\n", indent, line).replace(" * \n", " *\n"));
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    const SHOP: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Shop", "version": "1.0"},
+        "paths": {},
+        "components": {"schemas": {
+            "Order": {
+                "type": "object",
+                "description": "A customer order",
+                "required": ["id", "lines"],
+                "properties": {
+                    "id": {"type": "integer", "readOnly": true},
+                    "status": {"type": "string", "enum": ["pending", "paid"]},
+                    "lines": {"type": "array", "items": {"$ref": "#/components/schemas/order_line"}},
+                    "note": {"type": "string", "nullable": true, "description": "Free text"},
+                    "x-trace": {"type": "string"},
+                    "meta": {"type": "object", "additionalProperties": {"type": "string"}}
+                }
+            },
+            "order_line": {"type": "object", "properties": {"sku": {"type": "string"}}},
+            "Payment": {"oneOf": [{"$ref": "#/components/schemas/Card"}, {"$ref": "#/components/schemas/Transfer"}]},
+            "Card": {"allOf": [{"$ref": "#/components/schemas/Base"}, {"type": "object", "properties": {"last4": {"type": "string"}}}]},
+            "Base": {"type": "object", "properties": {"kind": {"type": "string"}}},
+            "Transfer": {"type": "object", "properties": {"iban": {"type": "string"}}}
+        }}
+    }"##;
+
+    #[test]
+    fn test_interface_generation() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let schemas = component_schemas(&spec).unwrap();
+
+        assert_eq!(
+            render_declaration("Order", &schemas["Order"]),
+            r#"/** A customer order */
+export interface Order {
+  readonly id: number;
+  lines: OrderLine[];
+  meta?: Record<string, string>;
+  /** Free text */
+  note?: string | null;
+  status?: "pending" | "paid";
+  "x-trace"?: string;
+}
+"#
+        );
+        assert_eq!(
+            render_declaration("Payment", &schemas["Payment"]),
+            "export type Payment = Card | Transfer;\n"
+        );
+        assert_eq!(
+            render_declaration("Card", &schemas["Card"]),
+            "export type Card = Base & {\n  last4?: string;\n};\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_files_with_imports() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let names = crate::codegen::with_dependencies(&spec, &["Order".to_string()]);
+        assert_eq!(names, vec!["Order", "order_line"]);
+
+        let files = generate(&spec, &names);
+        let file_names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(file_names, vec!["Order.ts", "OrderLine.ts", "index.ts"]);
+        assert!(files[0]
+            .content
+            .starts_with("// Generated by openapi-explorer from Shop 1.0\nimport type { OrderLine } from \"./OrderLine\";\n\n"));
+        assert_eq!(
+            files[2].content,
+            "// Generated by openapi-explorer from Shop 1.0\nexport * from \"./Order\";\nexport * from \"./OrderLine\";\n"
+        );
+    }
+}
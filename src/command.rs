@@ -0,0 +1,461 @@
+use crate::app::View;
+use crate::codegen::protobuf::FieldNumbering;
+use crate::codegen::Language;
+use crate::indexer::FieldFlag;
+use crate::sorting::SortMode;
+use crate::validate::PayloadKind;
+use anyhow::{anyhow, Result};
+use std::path::PathBuf;
+
+/// Command names and their argument completions, in help order
+pub const COMMANDS: &[(&str, &[&str])] = &[
+    (
+        "view",
+        &[
+            "fields",
+            "schemas",
+            "endpoints",
+            "graph",
+            "stats",
+            "headers",
+            "responses",
+        ],
+    ),
+    (
+        "filter",
+        &["type:", "readonly:true", "writeonly:true", "nullable:true"],
+    ),
+    ("tag", &[]),
+    ("sort", &["natural", "lexical"]),
+    (
+        "export",
+        &[
+            "csv",
+            "stats",
+            "errors",
+            "unused",
+            "clusters",
+            "graph",
+            "marked",
+            "ts",
+            "rust",
+            "jsonschema",
+            "graphql",
+            "proto",
+        ],
+    ),
+    ("open", &[]),
+    ("op", &[]),
+    ("validate", &[]),
+    ("rename", &[]),
+    ("save", &[]),
+    ("reload", &[]),
+    ("info", &[]),
+    ("help", &[]),
+    ("quit", &[]),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteCommand {
+    View(View),
+    /// Fuzzy query plus optional `type:<field type>` and
+    /// `readonly|writeonly|nullable:true|false` qualifiers; all empty
+    /// clears the filter.
+    Filter {
+        query: String,
+        field_type: Option<String>,
+        flags: Vec<(FieldFlag, bool)>,
+    },
+    Sort(SortMode),
+    /// Endpoints listed under a tag; none clears the tag filter
+    Tag(Option<String>),
+    ExportCsv(PathBuf),
+    /// Stats dashboard, as Markdown for `.md` files and JSON otherwise
+    ExportStats(PathBuf),
+    /// Missing error responses of the filtered endpoints, as a YAML
+    /// fragment for `.yaml` files and a JSON Patch otherwise
+    ExportErrors(PathBuf),
+    /// Unused components with a preview of each, as Markdown for `.md`
+    /// files and JSON otherwise
+    ExportUnused(PathBuf),
+    /// Fields always declared together, as Markdown for `.md` files and
+    /// JSON otherwise
+    ExportClusters(PathBuf),
+    /// Endpoint / schema / field graph, as an interactive page for `.html`
+    /// files and Graphviz for `.dot` ones
+    ExportGraph(PathBuf),
+    /// Marked fields or endpoints (else the one under the cursor) as CSV
+    ExportMarked(PathBuf),
+    /// Models of every component schema, into a directory
+    ExportModels(Language, PathBuf),
+    Open(PathBuf),
+    /// Jump to the endpoint of an operationId
+    Operation(String),
+    /// JSON payload file checked against the endpoint in the details popup
+    ValidatePayload(PathBuf, PayloadKind),
+    /// New name for the selected field, with an optional file receiving
+    /// the JSON Patch plan
+    Rename(String, Option<PathBuf>),
+    /// Query, view, sort order and method filter saved under a name
+    SaveView(String),
+    Reload,
+    /// Spec metadata popup
+    Info,
+    Help,
+    Quit,
+}
+
+/// Parses a palette line (without the leading `:`).
+pub fn parse_command(input: &str) -> Result<PaletteCommand> {
+    let input = input.trim().trim_start_matches(':');
+    let (name, args) = input
+        .split_once(char::is_whitespace)
+        .map(|(name, args)| (name, args.trim()))
+        .unwrap_or((input, ""));
+
+    match name {
+        "view" | "v" => parse_view(args).map(PaletteCommand::View),
+        "filter" | "f" => {
+            let mut query = Vec::new();
+            let mut field_type = None;
+            let mut flags = Vec::new();
+            for word in args.split_whitespace() {
+                let (qualifier, value) = word.split_once(':').unwrap_or((word, ""));
+                if qualifier == "type" && !value.is_empty() {
+                    field_type = Some(value.to_string());
+                } else if let Some(flag) = FieldFlag::parse(qualifier) {
+                    let value = value
+                        .parse::<bool>()
+                        .map_err(|_| anyhow!("Usage: filter {}:true|false", flag.name()))?;
+                    flags.push((flag, value));
+                } else {
+                    query.push(word);
+                }
+            }
+            Ok(PaletteCommand::Filter {
+                query: query.join(" "),
+                field_type,
+                flags,
+            })
+        }
+        "tag" | "t" => Ok(PaletteCommand::Tag(
+            Some(args.to_string()).filter(|tag| !tag.is_empty()),
+        )),
+        "sort" => match args {
+            "natural" => Ok(PaletteCommand::Sort(SortMode::Natural)),
+            "lexical" => Ok(PaletteCommand::Sort(SortMode::Lexical)),
+            _ => Err(anyhow!("Usage: sort natural|lexical")),
+        },
+        "export" | "e" => match args.split_once(char::is_whitespace) {
+            Some(("csv", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportCsv(expand_home(path.trim())))
+            }
+            Some(("stats", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportStats(expand_home(path.trim())))
+            }
+            Some(("errors", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportErrors(expand_home(path.trim())))
+            }
+            Some(("unused", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportUnused(expand_home(path.trim())))
+            }
+            Some(("clusters", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportClusters(expand_home(path.trim())))
+            }
+            Some(("graph", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportGraph(expand_home(path.trim())))
+            }
+            Some(("marked", path)) if !path.trim().is_empty() => {
+                Ok(PaletteCommand::ExportMarked(expand_home(path.trim())))
+            }
+            Some(("ts", dir)) if !dir.trim().is_empty() => Ok(PaletteCommand::ExportModels(
+                Language::TypeScript,
+                expand_home(dir.trim()),
+            )),
+            Some(("rust", dir)) if !dir.trim().is_empty() => Ok(PaletteCommand::ExportModels(
+                Language::Rust,
+                expand_home(dir.trim()),
+            )),
+            Some(("jsonschema", dir)) if !dir.trim().is_empty() => Ok(
+                PaletteCommand::ExportModels(Language::JsonSchema, expand_home(dir.trim())),
+            ),
+            Some(("graphql", dir)) if !dir.trim().is_empty() => Ok(
+                PaletteCommand::ExportModels(Language::GraphQl, expand_home(dir.trim())),
+            ),
+            Some(("proto", rest)) if !rest.trim().is_empty() => {
+                let rest = rest.trim();
+                let (dir, numbering) = match rest.rsplit_once(char::is_whitespace) {
+                    Some((dir, strategy)) => match FieldNumbering::parse(strategy) {
+                        Some(numbering) => (dir.trim(), numbering),
+                        None => (rest, FieldNumbering::default()),
+                    },
+                    None => (rest, FieldNumbering::default()),
+                };
+                Ok(PaletteCommand::ExportModels(
+                    Language::Protobuf(numbering),
+                    expand_home(dir),
+                ))
+            }
+            _ => Err(anyhow!(
+                "Usage: export csv|stats|errors|unused|clusters|graph|marked <file>, export ts|rust|jsonschema|graphql <dir>, export proto <dir> [alphabetical|hashed]"
+            )),
+        },
+        "open" | "o" if !args.is_empty() => Ok(PaletteCommand::Open(expand_home(args))),
+        "open" | "o" => Err(anyhow!("Usage: open <spec file>")),
+        "op" if !args.is_empty() => Ok(PaletteCommand::Operation(args.to_string())),
+        "op" => Err(anyhow!("Usage: op <operationId>")),
+        "validate" if !args.is_empty() => Ok(parse_validate(args)),
+        "validate" => Err(anyhow!("Usage: validate <payload.json> [status|response]")),
+        "rename" if !args.is_empty() => Ok(match args.split_once(char::is_whitespace) {
+            Some((name, path)) => {
+                PaletteCommand::Rename(name.to_string(), Some(expand_home(path.trim())))
+            }
+            None => PaletteCommand::Rename(args.to_string(), None),
+        }),
+        "rename" => Err(anyhow!("Usage: rename <new name> [patch.json]")),
+        "save" if !args.is_empty() => Ok(PaletteCommand::SaveView(args.to_string())),
+        "save" => Err(anyhow!("Usage: save <view name>")),
+        "reload" | "r" => Ok(PaletteCommand::Reload),
+        "info" | "i" => Ok(PaletteCommand::Info),
+        "help" | "h" => Ok(PaletteCommand::Help),
+        "quit" | "q" => Ok(PaletteCommand::Quit),
+        "" => Err(anyhow!("Empty command")),
+        other => Err(anyhow!("Unknown command '{}'", other)),
+    }
+}
+
+/// `<file>` checks a request body; a trailing status code, `default` or
+/// `response` (the first 2xx) checks a response instead.
+fn parse_validate(args: &str) -> PaletteCommand {
+    if let Some((file, last)) = args.rsplit_once(char::is_whitespace) {
+        let is_status = last.len() == 3 && last.chars().all(|c| c.is_ascii_digit() || c == 'X');
+        if is_status || last == "default" {
+            return PaletteCommand::ValidatePayload(
+                expand_home(file.trim()),
+                PayloadKind::Response(Some(last.to_string())),
+            );
+        }
+        if last == "response" {
+            return PaletteCommand::ValidatePayload(
+                expand_home(file.trim()),
+                PayloadKind::Response(None),
+            );
+        }
+    }
+    PaletteCommand::ValidatePayload(expand_home(args), PayloadKind::Request)
+}
+
+pub fn parse_view(name: &str) -> Result<View> {
+    match name {
+        "fields" | "1" => Ok(View::Fields),
+        "schemas" | "2" => Ok(View::Schemas),
+        "endpoints" | "3" => Ok(View::Endpoints),
+        "graph" | "4" => Ok(View::Graph),
+        "stats" | "5" => Ok(View::Stats),
+        "headers" | "6" => Ok(View::Headers),
+        "responses" | "7" => Ok(View::Responses),
+        _ => Err(anyhow!(
+            "Usage: view fields|schemas|endpoints|graph|stats|headers|responses"
+        )),
+    }
+}
+
+/// `path` with a leading `~/` replaced by the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Completions of the word being typed: command names for the first word,
+/// known arguments for the second.
+pub fn complete(input: &str) -> Vec<String> {
+    let input = input.trim_start();
+    match input.split_once(' ') {
+        None => COMMANDS
+            .iter()
+            .filter(|(name, _)| name.starts_with(input))
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        Some((name, arg)) => COMMANDS
+            .iter()
+            .find(|(command, _)| *command == name)
+            .map(|(_, args)| {
+                args.iter()
+                    .filter(|candidate| candidate.starts_with(arg.trim_start()))
+                    .map(|candidate| format!("{} {}", name, candidate))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Longest common prefix of the completions, used to extend the input on
+/// Tab.
+pub fn common_prefix(completions: &[String]) -> Option<String> {
+    let first = completions.first()?;
+    let len = completions.iter().skip(1).fold(first.len(), |len, other| {
+        first
+            .char_indices()
+            .zip(other.chars())
+            .take_while(|((i, a), b)| *i < len && a == b)
+            .count()
+    });
+    let prefix: String = first.chars().take(len).collect();
+    Some(if completions.len() == 1 && !prefix.ends_with(':') {
+        format!("{} ", prefix)
+    } else {
+        prefix
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse_command(":view schemas").unwrap(),
+            PaletteCommand::View(View::Schemas)
+        );
+        assert_eq!(
+            parse_command("filter type:string user").unwrap(),
+            PaletteCommand::Filter {
+                query: "user".to_string(),
+                field_type: Some("string".to_string()),
+                flags: Vec::new(),
+            }
+        );
+        assert_eq!(
+            parse_command("filter readonly:true id WriteOnly:false").unwrap(),
+            PaletteCommand::Filter {
+                query: "id".to_string(),
+                field_type: None,
+                flags: vec![(FieldFlag::ReadOnly, true), (FieldFlag::WriteOnly, false)],
+            }
+        );
+        assert!(parse_command("filter nullable:maybe").is_err());
+        assert_eq!(parse_command("info").unwrap(), PaletteCommand::Info);
+        assert_eq!(
+            parse_command("tag admin").unwrap(),
+            PaletteCommand::Tag(Some("admin".to_string()))
+        );
+        assert_eq!(parse_command("tag").unwrap(), PaletteCommand::Tag(None));
+        assert_eq!(
+            parse_command("export csv out.csv").unwrap(),
+            PaletteCommand::ExportCsv(PathBuf::from("out.csv"))
+        );
+        assert_eq!(
+            parse_command("export stats stats.md").unwrap(),
+            PaletteCommand::ExportStats(PathBuf::from("stats.md"))
+        );
+        assert_eq!(
+            parse_command("export errors fixes.yaml").unwrap(),
+            PaletteCommand::ExportErrors(PathBuf::from("fixes.yaml"))
+        );
+        assert_eq!(
+            parse_command("export unused unused.md").unwrap(),
+            PaletteCommand::ExportUnused(PathBuf::from("unused.md"))
+        );
+        assert_eq!(
+            parse_command("export clusters clusters.json").unwrap(),
+            PaletteCommand::ExportClusters(PathBuf::from("clusters.json"))
+        );
+        assert_eq!(
+            parse_command("export graph api.html").unwrap(),
+            PaletteCommand::ExportGraph(PathBuf::from("api.html"))
+        );
+        assert_eq!(
+            parse_command("export marked fields.csv").unwrap(),
+            PaletteCommand::ExportMarked(PathBuf::from("fields.csv"))
+        );
+        assert_eq!(
+            parse_command("export ts web/src/api").unwrap(),
+            PaletteCommand::ExportModels(Language::TypeScript, PathBuf::from("web/src/api"))
+        );
+        assert_eq!(
+            parse_command("export rust src/models").unwrap(),
+            PaletteCommand::ExportModels(Language::Rust, PathBuf::from("src/models"))
+        );
+        assert_eq!(
+            parse_command("export jsonschema schemas").unwrap(),
+            PaletteCommand::ExportModels(Language::JsonSchema, PathBuf::from("schemas"))
+        );
+        assert_eq!(
+            parse_command("export graphql gateway").unwrap(),
+            PaletteCommand::ExportModels(Language::GraphQl, PathBuf::from("gateway"))
+        );
+        assert_eq!(
+            parse_command("export proto grpc/models").unwrap(),
+            PaletteCommand::ExportModels(
+                Language::Protobuf(FieldNumbering::Alphabetical),
+                PathBuf::from("grpc/models")
+            )
+        );
+        assert_eq!(
+            parse_command("export proto grpc hashed").unwrap(),
+            PaletteCommand::ExportModels(
+                Language::Protobuf(FieldNumbering::Hashed),
+                PathBuf::from("grpc")
+            )
+        );
+        assert_eq!(
+            parse_command("open other-spec.yaml").unwrap(),
+            PaletteCommand::Open(PathBuf::from("other-spec.yaml"))
+        );
+        assert_eq!(
+            parse_command("op createInvoice").unwrap(),
+            PaletteCommand::Operation("createInvoice".to_string())
+        );
+        assert_eq!(
+            parse_command("validate body.json").unwrap(),
+            PaletteCommand::ValidatePayload(PathBuf::from("body.json"), PayloadKind::Request)
+        );
+        assert_eq!(
+            parse_command("validate out.json 404").unwrap(),
+            PaletteCommand::ValidatePayload(
+                PathBuf::from("out.json"),
+                PayloadKind::Response(Some("404".to_string()))
+            )
+        );
+        assert_eq!(
+            parse_command("validate out.json response").unwrap(),
+            PaletteCommand::ValidatePayload(PathBuf::from("out.json"), PayloadKind::Response(None))
+        );
+        assert_eq!(
+            parse_command("rename email_address plan.json").unwrap(),
+            PaletteCommand::Rename(
+                "email_address".to_string(),
+                Some(PathBuf::from("plan.json"))
+            )
+        );
+        assert!(parse_command("rename").is_err());
+        assert_eq!(
+            parse_command("save billing writes").unwrap(),
+            PaletteCommand::SaveView("billing writes".to_string())
+        );
+        assert!(parse_command("save").is_err());
+        assert!(parse_command("op").is_err());
+        assert!(parse_command("export pdf x").is_err());
+        assert!(parse_command("view nowhere").is_err());
+        assert!(parse_command("launch").is_err());
+    }
+
+    #[test]
+    fn test_completion() {
+        assert_eq!(complete("s"), vec!["sort", "save"]);
+        assert_eq!(complete("view s"), vec!["view schemas", "view stats"]);
+        assert_eq!(
+            common_prefix(&complete("view s")).as_deref(),
+            Some("view s")
+        );
+        assert_eq!(common_prefix(&complete("exp")).as_deref(), Some("export "));
+        assert_eq!(
+            common_prefix(&complete("filter t")).as_deref(),
+            Some("filter type:")
+        );
+        assert!(complete("open x").is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// How one of the two schemas declares a field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSide {
+    pub type_label: String,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldComparison {
+    pub name: String,
+    pub left: Option<FieldSide>,
+    pub right: Option<FieldSide>,
+}
+
+impl FieldComparison {
+    fn differs<T: PartialEq>(&self, attribute: impl Fn(&FieldSide) -> T) -> bool {
+        match (&self.left, &self.right) {
+            (Some(left), Some(right)) => attribute(left) != attribute(right),
+            _ => false,
+        }
+    }
+
+    pub fn type_differs(&self) -> bool {
+        self.differs(|side| side.type_label.clone())
+    }
+
+    pub fn required_differs(&self) -> bool {
+        self.differs(|side| side.required)
+    }
+
+    pub fn description_differs(&self) -> bool {
+        self.differs(|side| side.description.clone())
+    }
+
+    /// Declared identically on both sides.
+    pub fn is_match(&self) -> bool {
+        self.left.is_some()
+            && self.right.is_some()
+            && !self.type_differs()
+            && !self.required_differs()
+            && !self.description_differs()
+    }
+}
+
+/// Field-by-field comparison of two component schemas, fields sorted by
+/// name. Properties inherited through `allOf` count as the schema's own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaComparison {
+    pub left: String,
+    pub right: String,
+    pub fields: Vec<FieldComparison>,
+}
+
+impl SchemaComparison {
+    /// Fields declared by both schemas.
+    pub fn shared_count(&self) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| field.left.is_some() && field.right.is_some())
+            .count()
+    }
+
+    /// Fields missing on one side or declared differently.
+    pub fn mismatch_count(&self) -> usize {
+        self.fields.iter().filter(|field| !field.is_match()).count()
+    }
+}
+
+pub fn compare_schemas(spec: &OpenApiSpec, left: &str, right: &str) -> Option<SchemaComparison> {
+    let schemas = spec.components.as_ref()?.schemas.as_ref()?;
+    let left_fields = field_sides(schemas.get(left)?, schemas);
+    let mut right_fields = field_sides(schemas.get(right)?, schemas);
+
+    let mut fields: Vec<FieldComparison> = left_fields
+        .into_iter()
+        .map(|(name, side)| FieldComparison {
+            right: right_fields.remove(&name),
+            left: Some(side),
+            name,
+        })
+        .collect();
+    fields.extend(
+        right_fields
+            .into_iter()
+            .map(|(name, side)| FieldComparison {
+                name,
+                left: None,
+                right: Some(side),
+            }),
+    );
+    fields.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Some(SchemaComparison {
+        left: left.to_string(),
+        right: right.to_string(),
+        fields,
+    })
+}
+
+pub(crate) fn field_sides(
+    schema: &Schema,
+    schemas: &HashMap<String, Schema>,
+) -> BTreeMap<String, FieldSide> {
+    let mut properties = BTreeMap::new();
+    let mut required = BTreeSet::new();
+    collect_properties(
+        schema,
+        schemas,
+        &mut properties,
+        &mut required,
+        &mut HashSet::new(),
+    );
+    properties
+        .into_iter()
+        .map(|(name, property)| {
+            let side = FieldSide {
+                type_label: type_label(property),
+                required: required.contains(&name),
+                description: property.description.clone(),
+            };
+            (name, side)
+        })
+        .collect()
+}
+
+/// Own properties first, then those of referenced and `allOf` schemas not
+/// already declared.
+fn collect_properties<'a>(
+    schema: &'a Schema,
+    schemas: &'a HashMap<String, Schema>,
+    properties: &mut BTreeMap<String, &'a Schema>,
+    required: &mut BTreeSet<String>,
+    visited: &mut HashSet<&'a str>,
+) {
+    for (name, property) in schema.properties.iter().flatten() {
+        properties.entry(name.clone()).or_insert(property);
+    }
+    required.extend(schema.required.iter().flatten().cloned());
+
+    if let Some(name) = schema
+        .reference
+        .as_deref()
+        .and_then(extract_schema_name_from_ref)
+    {
+        if let Some(target) = schemas.get(name).filter(|_| visited.insert(name)) {
+            collect_properties(target, schemas, properties, required, visited);
+        }
+    }
+    for part in schema.all_of.iter().flatten() {
+        collect_properties(part, schemas, properties, required, visited);
+    }
+}
+
+/// Referenced schema name or type, with the format, `[]` for arrays and
+/// `?` when nullable: `Address`, `string(date-time)`, `LineItem[]`.
+fn type_label(schema: &Schema) -> String {
+    let label = match (&schema.reference, &schema.items) {
+        (Some(reference), _) => extract_schema_name_from_ref(reference)
+            .unwrap_or(reference)
+            .to_string(),
+        (None, Some(items)) => format!("{}[]", type_label(items)),
+        (None, None) => {
+            let base = schema.schema_type.as_deref().unwrap_or("any");
+            match &schema.format {
+                Some(format) => format!("{}({})", base, format),
+                None => base.to_string(),
+            }
+        }
+    };
+    if schema.nullable.unwrap_or(false) {
+        format!("{}?", label)
+    } else {
+        label
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_compare_near_duplicate_dtos() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Users", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Audited": {"type": "object", "properties": {"createdAt": {"type": "string", "format": "date-time"}}},
+                    "UserDto": {"allOf": [
+                        {"$ref": "#/components/schemas/Audited"},
+                        {"type": "object", "required": ["id", "email"], "properties": {
+                            "id": {"type": "integer"},
+                            "email": {"type": "string", "description": "Login"},
+                            "tags": {"type": "array", "items": {"type": "string"}}
+                        }}
+                    ]},
+                    "UserResponse": {"type": "object", "required": ["id"], "properties": {
+                        "id": {"type": "string"},
+                        "email": {"type": "string", "description": "Login"},
+                        "createdAt": {"type": "string", "format": "date-time"},
+                        "nickname": {"type": "string", "nullable": true}
+                    }}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let comparison = compare_schemas(&spec, "UserDto", "UserResponse").unwrap();
+        let names: Vec<&str> = comparison.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["createdAt", "email", "id", "nickname", "tags"]);
+        assert_eq!(comparison.shared_count(), 3);
+        assert_eq!(comparison.mismatch_count(), 4);
+
+        let field = |name: &str| comparison.fields.iter().find(|f| f.name == name).unwrap();
+        assert!(field("createdAt").is_match());
+        assert!(field("email").required_differs());
+        assert!(!field("email").description_differs());
+        assert!(field("id").type_differs());
+        assert_eq!(
+            field("nickname").right.as_ref().unwrap().type_label,
+            "string?"
+        );
+        assert_eq!(field("tags").left.as_ref().unwrap().type_label, "string[]");
+        assert!(field("tags").right.is_none());
+
+        assert!(compare_schemas(&spec, "UserDto", "Missing").is_none());
+    }
+}
@@ -0,0 +1,83 @@
+//! Compressed spec files and downloads (`openapi.json.gz`, `.zst`), told
+//! apart by their magic bytes rather than their extension so renamed
+//! artifacts and `Content-Encoding` responses decode alike.
+
+use anyhow::{anyhow, Context, Result};
+use std::io::Read;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// `Accept-Encoding` of spec downloads: the encodings [`decode_spec`]
+/// handles
+pub const ACCEPT_ENCODING: &str = "gzip, zstd";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(GZIP_MAGIC) {
+            Compression::Gzip
+        } else if bytes.starts_with(ZSTD_MAGIC) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+}
+
+/// Text of a spec, decompressed first when gzip or zstd compressed.
+pub fn decode_spec(bytes: Vec<u8>) -> Result<String> {
+    let bytes = match Compression::detect(&bytes) {
+        Compression::None => bytes,
+        Compression::Gzip => {
+            let mut decoded = Vec::new();
+            flate2::read::MultiGzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut decoded)
+                .context("Invalid gzip data")?;
+            decoded
+        }
+        Compression::Zstd => {
+            let mut decoded = Vec::new();
+            ruzstd::decoding::StreamingDecoder::new(bytes.as_slice())
+                .map_err(|e| anyhow!("Invalid zstd data: {}", e))?
+                .read_to_end(&mut decoded)
+                .context("Invalid zstd data")?;
+            decoded
+        }
+    };
+    String::from_utf8(bytes).map_err(|_| anyhow!("The spec is not valid UTF-8 text"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const SPEC: &str =
+        r#"{"openapi": "3.0.0", "info": {"title": "T", "version": "1"}, "paths": {}}"#;
+
+    #[test]
+    fn test_decodes_by_magic_bytes() {
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gzip.write_all(SPEC.as_bytes()).unwrap();
+        let gzip = gzip.finish().unwrap();
+        let zstd = ruzstd::encoding::compress_to_vec(
+            SPEC.as_bytes(),
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+
+        assert_eq!(Compression::detect(&gzip), Compression::Gzip);
+        assert_eq!(Compression::detect(&zstd), Compression::Zstd);
+        assert_eq!(Compression::detect(SPEC.as_bytes()), Compression::None);
+        for bytes in [gzip, zstd, SPEC.as_bytes().to_vec()] {
+            assert_eq!(decode_spec(bytes).unwrap(), SPEC);
+        }
+        assert!(decode_spec(GZIP_MAGIC.to_vec()).is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! User config file, `~/.config/openapi-explorer/config.toml`: defaults
+//! for startup options. Command line flags override it.
+
+use crate::app::View;
+use crate::command::{expand_home, parse_view};
+use crate::criticality::CriticalityRules;
+use crate::discovery::DiscoveryConfig;
+use crate::events::{GRAPHQL_DIR, JSON_SCHEMA_DIR, PROTOBUF_DIR, RUST_DIR, TYPESCRIPT_DIR};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// `$XDG_CONFIG_HOME/openapi-explorer`, else `~/.config/openapi-explorer`.
+pub fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("openapi-explorer"))
+}
+
+/// Where the exports of the TUI keys are written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportDirs {
+    /// Parameter matrix, stats and graph files
+    pub files: PathBuf,
+    pub typescript: PathBuf,
+    pub rust: PathBuf,
+    pub json_schema: PathBuf,
+    pub graphql: PathBuf,
+    pub protobuf: PathBuf,
+}
+
+impl Default for ExportDirs {
+    fn default() -> Self {
+        Self {
+            files: PathBuf::from("."),
+            typescript: PathBuf::from(TYPESCRIPT_DIR),
+            rust: PathBuf::from(RUST_DIR),
+            json_schema: PathBuf::from(JSON_SCHEMA_DIR),
+            graphql: PathBuf::from(GRAPHQL_DIR),
+            protobuf: PathBuf::from(PROTOBUF_DIR),
+        }
+    }
+}
+
+/// Config file as written, e.g.
+///
+/// ```toml
+/// spec = "~/api/openapi.json"   # or an http(s) URL
+/// theme = "light"
+/// tick-rate-ms = 250
+/// default-view = "endpoints"
+/// sensitive-patterns = ["iban=(?i)iban"]
+/// min-doc-coverage = 80         # percent, for --min-doc-coverage
+///
+/// [criticality]
+/// methods = ["POST", "PUT", "PATCH", "DELETE"]
+/// fields = ["(?i)^(id|tenant_id)$"]
+///
+/// [export]
+/// dir = "exports"
+/// typescript = "web/src/api"
+///
+/// [lint]
+/// ignore = ["unused_schema", "missing_operation_id:GET /health"]
+///
+/// [discovery]   # probed by --discover
+/// services = ["http://orders:8080", "http://billing:8080"]
+/// paths = ["/openapi.json", "/v3/api-docs"]
+/// timeout-ms = 2000
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct ConfigFile {
+    spec: Option<String>,
+    theme: Option<String>,
+    tick_rate_ms: Option<u64>,
+    default_view: Option<String>,
+    sensitive_patterns: Vec<String>,
+    min_doc_coverage: Option<f64>,
+    criticality: CriticalityFile,
+    export: ExportFile,
+    lint: LintFile,
+    discovery: DiscoveryFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CriticalityFile {
+    methods: Option<Vec<String>>,
+    fields: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct ExportFile {
+    dir: Option<String>,
+    typescript: Option<String>,
+    rust: Option<String>,
+    json_schema: Option<String>,
+    graphql: Option<String>,
+    protobuf: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct LintFile {
+    ignore: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct DiscoveryFile {
+    services: Vec<String>,
+    paths: Option<Vec<String>>,
+    timeout_ms: Option<u64>,
+}
+
+/// Startup defaults, each overridden by its command line flag.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Spec path or http(s) URL opened without `--file`
+    pub spec: Option<String>,
+    pub theme: Option<String>,
+    pub tick_rate: Option<Duration>,
+    pub default_view: Option<View>,
+    pub sensitive_patterns: Vec<String>,
+    /// Documentation coverage percentage `--min-doc-coverage` requires
+    /// without a value, and the Stats view gauge aims at
+    pub min_doc_coverage: Option<f64>,
+    pub criticality: CriticalityRules,
+    pub export: ExportDirs,
+    /// Lint rules turned off, as `rule` or `rule:location`
+    pub lint_ignore: Vec<String>,
+    pub discovery: DiscoveryConfig,
+}
+
+impl Config {
+    pub fn default_path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("config.toml"))
+    }
+
+    /// Reads `path` (which must exist), else the default config file when
+    /// it exists, else the defaults.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match Self::default_path().filter(|path| path.exists()) {
+                Some(path) => path,
+                None => return Ok(Self::default()),
+            },
+        };
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Cannot read config {}", path.display()))?;
+        Self::from_toml(&content).with_context(|| format!("Invalid config {}", path.display()))
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let file: ConfigFile = toml::from_str(content)?;
+        let defaults = ExportDirs::default();
+        let discovery = DiscoveryConfig::default();
+        let dir = |value: Option<String>, default: PathBuf| {
+            value.map_or(default, |value| expand_home(&value))
+        };
+        Ok(Self {
+            spec: file.spec,
+            theme: file.theme,
+            tick_rate: file.tick_rate_ms.map(|ms| Duration::from_millis(ms.max(1))),
+            default_view: file.default_view.as_deref().map(parse_view).transpose()?,
+            sensitive_patterns: file.sensitive_patterns,
+            min_doc_coverage: file
+                .min_doc_coverage
+                .map(crate::stats::check_min_coverage)
+                .transpose()?,
+            criticality: CriticalityRules::new(
+                file.criticality.methods.as_deref(),
+                &file.criticality.fields,
+            )?,
+            export: ExportDirs {
+                files: dir(file.export.dir, defaults.files),
+                typescript: dir(file.export.typescript, defaults.typescript),
+                rust: dir(file.export.rust, defaults.rust),
+                json_schema: dir(file.export.json_schema, defaults.json_schema),
+                graphql: dir(file.export.graphql, defaults.graphql),
+                protobuf: dir(file.export.protobuf, defaults.protobuf),
+            },
+            lint_ignore: file.lint.ignore,
+            discovery: DiscoveryConfig {
+                services: file.discovery.services,
+                paths: file.discovery.paths.unwrap_or(discovery.paths),
+                timeout: file
+                    .discovery
+                    .timeout_ms
+                    .map_or(discovery.timeout, Duration::from_millis),
+            },
+        })
+    }
+
+    /// Local path of the default spec. A URL is downloaded to a file under
+    /// `cache_dir`, which reloads revalidate against the URL.
+    pub async fn spec_path(&self, cache_dir: &Path) -> Result<Option<PathBuf>> {
+        let Some(spec) = &self.spec else {
+            return Ok(None);
+        };
+        if !(spec.starts_with("http://") || spec.starts_with("https://")) {
+            return Ok(Some(expand_home(spec)));
+        }
+        let client = reqwest::Client::new();
+        Ok(Some(
+            crate::remote::download(&client, spec, cache_dir).await?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_toml() {
+        let config = Config::from_toml(
+            r#"
+            spec = "api.json"
+            theme = "light"
+            tick-rate-ms = 100
+            default-view = "endpoints"
+            sensitive-patterns = ["iban=(?i)iban"]
+            min-doc-coverage = 80
+
+            [criticality]
+            methods = ["delete"]
+            fields = ["^id$"]
+
+            [export]
+            dir = "exports"
+            json-schema = "schemas"
+
+            [lint]
+            ignore = ["unused_schema", "missing_operation_id:GET /health"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.spec.as_deref(), Some("api.json"));
+        assert_eq!(config.theme.as_deref(), Some("light"));
+        assert_eq!(config.tick_rate, Some(Duration::from_millis(100)));
+        assert_eq!(config.default_view, Some(View::Endpoints));
+        assert_eq!(config.sensitive_patterns, ["iban=(?i)iban"]);
+        assert_eq!(config.min_doc_coverage, Some(80.0));
+        assert_eq!(
+            config.criticality.methods,
+            [crate::indexer::HttpMethod::Delete]
+        );
+        assert_eq!(config.export.files, PathBuf::from("exports"));
+        assert_eq!(config.export.json_schema, PathBuf::from("schemas"));
+        assert_eq!(config.export.rust, PathBuf::from(RUST_DIR));
+        assert_eq!(
+            config.lint_ignore,
+            ["unused_schema", "missing_operation_id:GET /health"]
+        );
+        assert_eq!(config.discovery, DiscoveryConfig::default());
+    }
+
+    #[test]
+    fn test_config_discovery() {
+        let config = Config::from_toml(
+            r#"
+            [discovery]
+            services = ["http://orders:8080"]
+            timeout-ms = 2000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.discovery.services, ["http://orders:8080"]);
+        assert_eq!(config.discovery.paths, crate::discovery::DEFAULT_PATHS);
+        assert_eq!(config.discovery.timeout, Duration::from_secs(2));
+        assert!(Config::from_toml(
+            "[discovery]
+urls = []"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_config_defaults_and_errors() {
+        let config = Config::from_toml("").unwrap();
+        assert!(config.spec.is_none());
+        assert_eq!(config.export, ExportDirs::default());
+        assert_eq!(
+            config.criticality.methods,
+            crate::criticality::DEFAULT_CRITICAL_METHODS
+        );
+
+        assert!(Config::from_toml("colour = \"red\"").is_err());
+        assert!(Config::from_toml("default-view = \"nowhere\"").is_err());
+        assert!(Config::from_toml("min-doc-coverage = 101").is_err());
+        assert!(Config::from_toml("[criticality]\nmethods = [\"FETCH\"]").is_err());
+        assert!(Config::load(Some(Path::new("/nonexistent/config.toml"))).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::indexer::{ContentTypeData, FieldIndex};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Endpoints sending or returning one media type, each list sorted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContentTypeUsage {
+    pub requests: Vec<String>,
+    pub responses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContentTypeReport {
+    pub by_type: BTreeMap<String, ContentTypeUsage>,
+    /// Endpoints using more than one family of media types (JSON, form,
+    /// CSV, ...), sorted
+    pub mixed: Vec<String>,
+    /// Endpoints with a request or response body but no JSON option for
+    /// it, sorted
+    pub without_json: Vec<String>,
+}
+
+/// `application/json` and `+json` types such as `application/problem+json`,
+/// parameters ignored.
+pub fn is_json(media_type: &str) -> bool {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+/// Family a media type is negotiated in: `json` for every JSON type, the
+/// essence (`multipart/form-data`, `text/csv`) otherwise.
+fn family(media_type: &str) -> String {
+    if is_json(media_type) {
+        return "json".to_string();
+    }
+    media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Which endpoints use which request and response media types, from the
+/// field index.
+pub fn analyze_content_types(index: &FieldIndex) -> ContentTypeReport {
+    let mut endpoints: Vec<(&String, &ContentTypeData)> = index.content_types.iter().collect();
+    endpoints.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut report = ContentTypeReport::default();
+    for (endpoint, data) in endpoints {
+        for media_type in &data.requests {
+            report
+                .by_type
+                .entry(media_type.clone())
+                .or_default()
+                .requests
+                .push(endpoint.clone());
+        }
+        for media_type in &data.responses {
+            report
+                .by_type
+                .entry(media_type.clone())
+                .or_default()
+                .responses
+                .push(endpoint.clone());
+        }
+
+        let families: BTreeSet<String> = data
+            .requests
+            .iter()
+            .chain(&data.responses)
+            .map(|media_type| family(media_type))
+            .collect();
+        if families.len() > 1 {
+            report.mixed.push(endpoint.clone());
+        }
+        let lacks_json = |media_types: &[String]| {
+            !media_types.is_empty() && !media_types.iter().any(|m| is_json(m))
+        };
+        if lacks_json(&data.requests) || lacks_json(&data.responses) {
+            report.without_json.push(endpoint.clone());
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_flags_mixed_and_non_json_endpoints() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Files", "version": "1"},
+                "paths": {
+                    "/users": {"post": {
+                        "requestBody": {"content": {"application/json": {}}},
+                        "responses": {
+                            "201": {"description": "ok", "content": {"application/json; charset=utf-8": {}}},
+                            "400": {"description": "bad", "content": {"application/problem+json": {}}}
+                        }
+                    }},
+                    "/uploads": {"post": {
+                        "requestBody": {"content": {"multipart/form-data": {}}},
+                        "responses": {"201": {"description": "ok", "content": {"application/json": {}}}}
+                    }},
+                    "/reports": {"get": {
+                        "responses": {"200": {"description": "ok", "content": {"text/csv": {}}}}
+                    }},
+                    "/health": {"get": {"responses": {"204": {"description": "empty"}}}}
+                }
+            }"#,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        assert_eq!(
+            index.content_types["POST /users"].responses,
+            vec![
+                "application/json; charset=utf-8",
+                "application/problem+json"
+            ]
+        );
+
+        let report = analyze_content_types(&index);
+        assert_eq!(
+            report.by_type.keys().collect::<Vec<_>>(),
+            vec![
+                "application/json",
+                "application/json; charset=utf-8",
+                "application/problem+json",
+                "multipart/form-data",
+                "text/csv"
+            ]
+        );
+        assert_eq!(
+            report.by_type["application/json"],
+            ContentTypeUsage {
+                requests: vec!["POST /users".to_string()],
+                responses: vec!["POST /uploads".to_string()],
+            }
+        );
+        assert_eq!(report.mixed, vec!["POST /uploads"]);
+        assert_eq!(report.without_json, vec!["GET /reports", "POST /uploads"]);
+    }
+}
@@ -0,0 +1,572 @@
+use crate::indexer::{resolve_schema_ref, FieldIndex};
+use crate::parser::{OpenApiSpec, Operation, PathItem, Schema};
+use crate::schema_graph::referenced_schemas;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+/// Statuses that are not expected to carry a body
+const BODYLESS_STATUSES: &[&str] = &["204", "205", "304"];
+
+/// A declared response without any content.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EmptyResponse {
+    pub endpoint: String,
+    pub status: String,
+}
+
+/// A component nothing outside `components` uses, directly or through
+/// other components.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct UnusedComponent {
+    /// `parameters`, `responses`, `requestBodies`, `headers`,
+    /// `securitySchemes` or `schemas`
+    pub section: String,
+    pub name: String,
+    /// One-line summary of the definition, e.g. `integer in query`
+    pub preview: String,
+}
+
+/// Parts of the spec that no operation exercises, each list sorted so the
+/// report reads as a to-do list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoverageReport {
+    /// Component schemas no operation references, directly or through
+    /// other schemas
+    pub unreferenced_schemas: Vec<String>,
+    /// Indexed fields that no request or response carries
+    pub orphaned_fields: Vec<String>,
+    /// Responses declared without content, except 204/205/304 and HEAD
+    pub empty_responses: Vec<EmptyResponse>,
+    /// Operations with neither a request nor a response schema
+    pub schemaless_endpoints: Vec<String>,
+    /// Required component schema properties (`Schema.field`) without an
+    /// example, which SDK docs end up showing blank
+    pub required_without_examples: Vec<String>,
+    /// Unused components other than schemas, by section then name
+    pub unused_components: Vec<UnusedComponent>,
+}
+
+impl CoverageReport {
+    pub fn issue_count(&self) -> usize {
+        self.unreferenced_schemas.len()
+            + self.orphaned_fields.len()
+            + self.empty_responses.len()
+            + self.schemaless_endpoints.len()
+            + self.required_without_examples.len()
+            + self.unused_components.len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.issue_count() == 0
+    }
+}
+
+/// Cross-checks component schemas, indexed fields and operations, over
+/// paths, webhooks and callbacks.
+pub fn analyze_coverage(openapi_spec: &OpenApiSpec, index: &FieldIndex) -> CoverageReport {
+    let mut operations: Vec<(String, &PathItem)> = openapi_spec
+        .paths
+        .iter()
+        .map(|(path, item)| (path.clone(), item))
+        .collect();
+    operations.extend(openapi_spec.event_targets());
+
+    let mut used_schemas = HashSet::new();
+    let mut empty_responses = BTreeSet::new();
+    let mut schemaless_endpoints = BTreeSet::new();
+
+    for (target, path_item) in &operations {
+        for (method, operation) in &path_item.operations {
+            let endpoint = format!("{} {}", method.to_uppercase(), target);
+
+            let schemas = operation_schemas(operation);
+            if !has_payload_schema(operation) {
+                schemaless_endpoints.insert(endpoint.clone());
+            }
+            for schema in schemas {
+                for name in referenced_schemas(schema) {
+                    used_schemas.extend(index.schema_graph.transitive_dependencies(&name));
+                }
+            }
+
+            if method.eq_ignore_ascii_case("head") {
+                continue;
+            }
+            for (status, response) in &operation.responses {
+                let has_content = response
+                    .content
+                    .as_ref()
+                    .is_some_and(|content| !content.is_empty());
+                if !has_content && !BODYLESS_STATUSES.contains(&status.as_str()) {
+                    empty_responses.insert(EmptyResponse {
+                        endpoint: endpoint.clone(),
+                        status: status.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    let component_schemas: Vec<&String> = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|c| c.schemas.as_ref())
+        .map(|schemas| schemas.keys().collect())
+        .unwrap_or_default();
+    let mut unreferenced_schemas: Vec<String> = component_schemas
+        .into_iter()
+        .filter(|name| !used_schemas.contains(*name))
+        .cloned()
+        .collect();
+    unreferenced_schemas.sort_unstable();
+
+    // Payload `$ref`s are not expanded into the endpoint lists, so a field
+    // also counts as carried when one of its schemas is referenced
+    let mut orphaned_fields: Vec<String> = index
+        .fields
+        .iter()
+        .filter(|(_, data)| {
+            data.endpoints.is_empty()
+                && !data
+                    .schemas
+                    .iter()
+                    .any(|name| used_schemas.contains(&**name))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    orphaned_fields.sort_unstable();
+
+    CoverageReport {
+        unreferenced_schemas,
+        orphaned_fields,
+        empty_responses: empty_responses.into_iter().collect(),
+        schemaless_endpoints: schemaless_endpoints.into_iter().collect(),
+        required_without_examples: required_without_examples(openapi_spec),
+        unused_components: unused_components(openapi_spec),
+    }
+}
+
+/// Description of a component, first line and shortened, after `summary`.
+fn preview(summary: String, description: Option<&str>) -> String {
+    const MAX_CHARS: usize = 60;
+    let Some(line) = description.and_then(|text| text.lines().find(|l| !l.trim().is_empty()))
+    else {
+        return summary;
+    };
+    let mut line: String = line.trim().chars().take(MAX_CHARS).collect();
+    if line.chars().count() == MAX_CHARS {
+        line.push('…');
+    }
+    if summary.is_empty() {
+        line
+    } else {
+        format!("{} — {}", summary, line)
+    }
+}
+
+fn schema_summary(schema: Option<&Schema>) -> String {
+    let Some(schema) = schema else {
+        return String::new();
+    };
+    let fields = schema.get_field_names().len();
+    match schema.schema_type.as_deref() {
+        Some(schema_type) if fields > 0 => format!("{}, {} field(s)", schema_type, fields),
+        Some(schema_type) => schema_type.to_string(),
+        None if fields > 0 => format!("{} field(s)", fields),
+        None => String::new(),
+    }
+}
+
+fn media_types<T>(content: Option<&HashMap<String, T>>) -> String {
+    let mut types: Vec<&str> = content
+        .into_iter()
+        .flat_map(|content| content.keys())
+        .map(String::as_str)
+        .collect();
+    types.sort_unstable();
+    types.join(", ")
+}
+
+/// Parameters, responses, request bodies, headers and security schemes
+/// of `components` that parsing found no use of.
+fn unused_components(openapi_spec: &OpenApiSpec) -> Vec<UnusedComponent> {
+    let Some(components) = &openapi_spec.components else {
+        return Vec::new();
+    };
+    let mut unused = Vec::new();
+    let mut add = |section: &str, name: &String, preview: String| {
+        if !openapi_spec
+            .referenced_components
+            .contains(&format!("{}/{}", section, name))
+        {
+            unused.push(UnusedComponent {
+                section: section.to_string(),
+                name: name.clone(),
+                preview,
+            });
+        }
+    };
+    for (name, parameter) in components.parameters.iter().flatten() {
+        let schema_type = parameter
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.schema_type.as_deref())
+            .unwrap_or("any");
+        let summary = format!("{} {} in {}", parameter.name, schema_type, parameter.in_);
+        add(
+            "parameters",
+            name,
+            preview(summary, parameter.description.as_deref()),
+        );
+    }
+    for (name, response) in components.responses.iter().flatten() {
+        add(
+            "responses",
+            name,
+            preview(
+                media_types(response.content.as_ref()),
+                Some(&response.description),
+            ),
+        );
+    }
+    for (name, body) in components.request_bodies.iter().flatten() {
+        add(
+            "requestBodies",
+            name,
+            preview(
+                media_types(Some(&body.content)),
+                body.description.as_deref(),
+            ),
+        );
+    }
+    for (name, header) in components.headers.iter().flatten() {
+        add(
+            "headers",
+            name,
+            preview(
+                schema_summary(header.schema.as_ref()),
+                header.description.as_deref(),
+            ),
+        );
+    }
+    for (name, scheme) in components.security_schemes.iter().flatten() {
+        add(
+            "securitySchemes",
+            name,
+            preview(scheme.describe(), scheme.description.as_deref()),
+        );
+    }
+    unused.sort();
+    unused
+}
+
+/// Every unused component, schemas first, as exported.
+pub fn unused_component_list(
+    openapi_spec: &OpenApiSpec,
+    report: &CoverageReport,
+) -> Vec<UnusedComponent> {
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    report
+        .unreferenced_schemas
+        .iter()
+        .map(|name| {
+            let schema = schemas.and_then(|schemas| schemas.get(name));
+            UnusedComponent {
+                section: "schemas".to_string(),
+                name: name.clone(),
+                preview: preview(
+                    schema_summary(schema),
+                    schema.and_then(|schema| schema.description.as_deref()),
+                ),
+            }
+        })
+        .chain(report.unused_components.iter().cloned())
+        .collect()
+}
+
+/// Writes `components` as a Markdown list for `.md` files, JSON otherwise.
+pub fn write_unused_components(path: &Path, components: &[UnusedComponent]) -> Result<()> {
+    let is_markdown = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "md" | "markdown"));
+    let content = if is_markdown {
+        let mut out = format!(
+            "# Unused components ({})
+
+",
+            components.len()
+        );
+        for component in components {
+            out.push_str(&format!(
+                "- `#/components/{}/{}`",
+                component.section, component.name
+            ));
+            if !component.preview.is_empty() {
+                out.push_str(&format!(": {}", component.preview));
+            }
+            out.push('\n');
+        }
+        out
+    } else {
+        serde_json::to_string_pretty(components)?
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Required properties of the component schemas with no example: none on
+/// the property, on the schema it references, or in the parent's example
+/// object. `allOf` parts count as the schema's own.
+fn required_without_examples(openapi_spec: &OpenApiSpec) -> Vec<String> {
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    let mut missing = BTreeSet::new();
+    for (name, schema) in schemas.into_iter().flatten() {
+        let parts: Vec<&Schema> = std::iter::once(schema)
+            .chain(
+                schema
+                    .all_of
+                    .iter()
+                    .flatten()
+                    .map(|part| resolve_schema_ref(part, schemas)),
+            )
+            .collect();
+        for field in parts.iter().flat_map(|part| part.required.iter().flatten()) {
+            let in_parent_example = parts.iter().any(|part| {
+                part.example
+                    .as_ref()
+                    .and_then(|example| example.get(field))
+                    .is_some()
+            });
+            let has_example = in_parent_example
+                || parts
+                    .iter()
+                    .filter_map(|part| part.properties.as_ref()?.get(field))
+                    .any(|property| {
+                        property.example.is_some()
+                            || resolve_schema_ref(property, schemas).example.is_some()
+                    });
+            if !has_example {
+                missing.insert(format!("{}.{}", name, field));
+            }
+        }
+    }
+    missing.into_iter().collect()
+}
+
+/// Every schema of an operation: parameters, request body, response bodies
+/// and response headers.
+fn operation_schemas(operation: &Operation) -> Vec<&Schema> {
+    let parameters = operation
+        .parameters
+        .iter()
+        .flatten()
+        .filter_map(|param| param.schema.as_ref());
+    let headers = operation
+        .responses
+        .values()
+        .filter_map(|response| response.headers.as_ref())
+        .flat_map(|headers| headers.values())
+        .filter_map(|header| header.schema.as_ref());
+    parameters
+        .chain(headers)
+        .chain(payload_schemas(operation))
+        .collect()
+}
+
+fn payload_schemas(operation: &Operation) -> impl Iterator<Item = &Schema> {
+    let requests = operation
+        .request_body
+        .iter()
+        .flat_map(|body| body.content.values());
+    let responses = operation
+        .responses
+        .values()
+        .filter_map(|response| response.content.as_ref())
+        .flat_map(|content| content.values());
+    requests
+        .chain(responses)
+        .filter_map(|media| media.schema.as_ref())
+}
+
+fn has_payload_schema(operation: &Operation) -> bool {
+    payload_schemas(operation).next().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_coverage_report() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/orders": {
+                        "post": {
+                            "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+                            "responses": {"201": {"description": "created"}, "204": {"description": "none"}}
+                        },
+                        "head": {"responses": {"200": {"description": "ok"}}}
+                    },
+                    "/health": {"get": {"parameters": [{"name": "verbose", "in": "query", "schema": {"$ref": "#/components/schemas/Flag"}}],
+                        "responses": {"200": {"description": "ok"}}}}
+                },
+                "webhooks": {
+                    "shipped": {"post": {"requestBody": {"content": {"application/json": {}}},
+                        "responses": {"200": {"description": "ok", "content": {"text/plain": {}}}}}}
+                },
+                "components": {"schemas": {
+                    "Order": {"type": "object", "properties": {"id": {"type": "integer"}, "buyer": {"$ref": "#/components/schemas/Customer"}}},
+                    "Customer": {"type": "object", "properties": {"email": {"type": "string"}}},
+                    "Flag": {"type": "boolean"},
+                    "Legacy": {"type": "object", "properties": {"fax": {"type": "string"}, "id": {"type": "integer"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let report = analyze_coverage(&spec, &build_field_index(&spec));
+
+        assert_eq!(report.unreferenced_schemas, vec!["Legacy"]);
+        // `id` is also declared by Order, which POST /orders sends
+        assert_eq!(report.orphaned_fields, vec!["fax"]);
+        assert_eq!(
+            report.empty_responses,
+            vec![
+                EmptyResponse {
+                    endpoint: "GET /health".to_string(),
+                    status: "200".to_string(),
+                },
+                EmptyResponse {
+                    endpoint: "POST /orders".to_string(),
+                    status: "201".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            report.schemaless_endpoints,
+            vec!["GET /health", "HEAD /orders", "POST webhook:shipped"]
+        );
+        assert!(report.required_without_examples.is_empty());
+        assert_eq!(report.issue_count(), 7);
+    }
+
+    #[test]
+    fn test_required_fields_without_examples() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Money": {"type": "string", "example": "12.50"},
+                    "Order": {"type": "object", "required": ["id", "total", "buyer", "note"],
+                        "example": {"buyer": "ada"},
+                        "properties": {
+                            "id": {"type": "integer", "example": 7},
+                            "total": {"$ref": "#/components/schemas/Money"},
+                            "buyer": {"type": "string"},
+                            "note": {"type": "string"},
+                            "optional": {"type": "string"}}},
+                    "Refund": {"allOf": [{"$ref": "#/components/schemas/Order"},
+                        {"required": ["reason"], "properties": {"reason": {"type": "string"}}}]}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let report = analyze_coverage(&spec, &build_field_index(&spec));
+        assert_eq!(
+            report.required_without_examples,
+            vec!["Order.note", "Refund.note", "Refund.reason"]
+        );
+    }
+
+    #[test]
+    fn test_unused_components_of_every_section() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "security": [{"bearer": []}],
+                "paths": {"/orders": {"get": {
+                    "parameters": [{"$ref": "#/components/parameters/Limit"}],
+                    "responses": {"404": {"$ref": "#/components/responses/NotFound"}}}}},
+                "components": {
+                    "parameters": {
+                        "Limit": {"$ref": "#/components/parameters/PageLimit"},
+                        "PageLimit": {"name": "limit", "in": "query", "schema": {"type": "integer"}},
+                        "Offset": {"name": "offset", "in": "query", "description": "Items to skip",
+                            "schema": {"type": "integer"}}
+                    },
+                    "responses": {
+                        "NotFound": {"description": "Not found",
+                            "headers": {"X-Request-Id": {"$ref": "#/components/headers/RequestId"}}},
+                        "Gone": {"description": "Gone for good", "content": {"application/json": {}}}
+                    },
+                    "requestBodies": {"Draft": {"content": {"application/json": {}}}},
+                    "headers": {
+                        "RequestId": {"schema": {"type": "string"}},
+                        "RateLimit": {"description": "Calls left", "schema": {"type": "integer"}}
+                    },
+                    "securitySchemes": {
+                        "bearer": {"type": "http", "scheme": "bearer", "bearerFormat": "JWT"},
+                        "key": {"type": "apiKey", "in": "header", "name": "X-Api-Key"}
+                    },
+                    "schemas": {"Legacy": {"type": "object", "description": "Old order",
+                        "properties": {"fax": {"type": "string"}}}}
+                }
+            }"##,
+        )
+        .unwrap();
+        let report = analyze_coverage(&spec, &build_field_index(&spec));
+
+        let unused: Vec<(&str, &str, &str)> = report
+            .unused_components
+            .iter()
+            .map(|c| (c.section.as_str(), c.name.as_str(), c.preview.as_str()))
+            .collect();
+        assert_eq!(
+            unused,
+            [
+                ("headers", "RateLimit", "integer — Calls left"),
+                (
+                    "parameters",
+                    "Offset",
+                    "offset integer in query — Items to skip"
+                ),
+                ("requestBodies", "Draft", "application/json"),
+                ("responses", "Gone", "application/json — Gone for good"),
+                ("securitySchemes", "key", "apiKey in header X-Api-Key"),
+            ]
+        );
+
+        let list = unused_component_list(&spec, &report);
+        assert_eq!(list[0].section, "schemas");
+        assert_eq!(list[0].preview, "object, 1 field(s) — Old order");
+        assert_eq!(list.len(), 6);
+
+        let dir = tempfile::tempdir().unwrap();
+        let markdown = dir.path().join("unused.md");
+        write_unused_components(&markdown, &list).unwrap();
+        let text = std::fs::read_to_string(&markdown).unwrap();
+        assert!(text.starts_with("# Unused components (6)"));
+        assert!(text.contains("- `#/components/headers/RateLimit`: integer — Calls left\n"));
+        let json = dir.path().join("unused.json");
+        write_unused_components(&json, &list).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!(value[5]["name"], "key");
+    }
+}
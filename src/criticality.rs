@@ -0,0 +1,118 @@
+//! Which fields count as critical: by default those used by a write
+//! operation, configurable in the config file.
+
+use crate::indexer::{FieldIndex, HttpMethod};
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+
+/// Methods making a field critical by default
+pub const DEFAULT_CRITICAL_METHODS: &[HttpMethod] = &[
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Patch,
+    HttpMethod::Delete,
+];
+
+/// A field is critical when an operation of one of `methods` uses it, or
+/// when its name matches one of `patterns`.
+#[derive(Debug, Clone)]
+pub struct CriticalityRules {
+    pub methods: Vec<HttpMethod>,
+    pub patterns: Vec<Regex>,
+}
+
+impl Default for CriticalityRules {
+    fn default() -> Self {
+        Self {
+            methods: DEFAULT_CRITICAL_METHODS.to_vec(),
+            patterns: Vec::new(),
+        }
+    }
+}
+
+impl CriticalityRules {
+    /// Rules from method names (the default write methods when `None`)
+    /// and field name regexes.
+    pub fn new(methods: Option<&[String]>, patterns: &[String]) -> Result<Self> {
+        let methods = match methods {
+            Some(methods) => methods
+                .iter()
+                .map(|name| {
+                    HttpMethod::parse(&name.to_lowercase()).ok_or_else(|| {
+                        anyhow!("Unknown HTTP method '{}' in criticality rules", name)
+                    })
+                })
+                .collect::<Result<_>>()?,
+            None => DEFAULT_CRITICAL_METHODS.to_vec(),
+        };
+        let patterns = patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern)
+                    .with_context(|| format!("Invalid critical field pattern '{}'", pattern))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { methods, patterns })
+    }
+
+    pub fn is_critical(&self, index: &FieldIndex, field_name: &str) -> bool {
+        if self
+            .patterns
+            .iter()
+            .any(|pattern| pattern.is_match(field_name))
+        {
+            return true;
+        }
+        index.fields.get(field_name).is_some_and(|data| {
+            data.methods
+                .iter()
+                .any(|method| self.methods.contains(method))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_rules_by_method_and_pattern() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/users": {"get": {"parameters": [{"name": "nickname", "in": "query", "schema": {"type": "string"}}],
+                        "responses": {}}},
+                    "/orders": {"post": {"requestBody": {"content": {"application/json": {"schema": {
+                        "type": "object", "properties": {"total": {"type": "number"}}}}}}, "responses": {}}}
+                },
+                "components": {"schemas": {"Order": {"type": "object", "properties": {
+                    "nickname": {"type": "string"}, "total": {"type": "number"}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let default = CriticalityRules::default();
+        assert!(default.is_critical(&index, "total"));
+        assert!(!default.is_critical(&index, "nickname"));
+        assert_eq!(
+            default.is_critical(&index, "total"),
+            index.is_critical_field("total")
+        );
+
+        let rules =
+            CriticalityRules::new(Some(&["GET".to_string()]), &["(?i)^balance$".to_string()])
+                .unwrap();
+        assert!(rules.is_critical(&index, "nickname"));
+        assert!(!rules.is_critical(&index, "total"));
+        assert!(rules.is_critical(&index, "Balance"));
+
+        assert!(CriticalityRules::new(Some(&["FETCH".to_string()]), &[]).is_err());
+        assert!(CriticalityRules::new(None, &["(".to_string()]).is_err());
+    }
+}
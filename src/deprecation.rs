@@ -0,0 +1,237 @@
+use crate::parser::{OpenApiSpec, Operation};
+use chrono::{DateTime, NaiveDate};
+use serde_json::Value;
+
+/// Extensions holding the date an operation was deprecated
+const SINCE_EXTENSIONS: &[&str] = &["x-deprecated-since", "x-deprecation-date"];
+
+/// Extensions holding the planned removal date
+const SUNSET_EXTENSIONS: &[&str] = &["x-sunset", "x-sunset-date", "x-removal-date"];
+
+/// Removals closer than this are highlighted
+pub const IMMINENT_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalStatus {
+    /// Deprecated without a sunset date
+    Unscheduled,
+    Upcoming {
+        days_left: i64,
+    },
+    Imminent {
+        days_left: i64,
+    },
+    Overdue {
+        days_over: i64,
+    },
+}
+
+impl RemovalStatus {
+    pub fn describe(&self) -> String {
+        match self {
+            RemovalStatus::Unscheduled => "no sunset date".to_string(),
+            RemovalStatus::Upcoming { days_left } | RemovalStatus::Imminent { days_left } => {
+                format!("{} day(s) left", days_left)
+            }
+            RemovalStatus::Overdue { days_over } => format!("overdue by {} day(s)", days_over),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deprecation {
+    pub endpoint: String,
+    /// `deprecated: true` is set on the operation
+    pub flagged: bool,
+    pub since: Option<NaiveDate>,
+    pub sunset: Option<NaiveDate>,
+}
+
+impl Deprecation {
+    pub fn status(&self, today: NaiveDate) -> RemovalStatus {
+        match self.sunset {
+            None => RemovalStatus::Unscheduled,
+            Some(sunset) => {
+                let days_left = (sunset - today).num_days();
+                if days_left < 0 {
+                    RemovalStatus::Overdue {
+                        days_over: -days_left,
+                    }
+                } else if days_left <= IMMINENT_DAYS {
+                    RemovalStatus::Imminent { days_left }
+                } else {
+                    RemovalStatus::Upcoming { days_left }
+                }
+            }
+        }
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        matches!(self.status(today), RemovalStatus::Overdue { .. })
+    }
+}
+
+/// Deprecation data of a single operation, if it is deprecated or announces
+/// a sunset.
+pub fn operation_deprecation(endpoint: &str, operation: &Operation) -> Option<Deprecation> {
+    let since = SINCE_EXTENSIONS
+        .iter()
+        .find_map(|name| operation.extension(name).and_then(parse_date))
+        .or_else(|| response_header_date(operation, "deprecation"));
+    let sunset = SUNSET_EXTENSIONS
+        .iter()
+        .find_map(|name| operation.extension(name).and_then(parse_date))
+        .or_else(|| response_header_date(operation, "sunset"));
+
+    if !operation.is_deprecated() && since.is_none() && sunset.is_none() {
+        return None;
+    }
+
+    Some(Deprecation {
+        endpoint: endpoint.to_string(),
+        flagged: operation.is_deprecated(),
+        since,
+        sunset,
+    })
+}
+
+/// Every deprecated operation, scheduled removals first (soonest first), then
+/// unscheduled ones by endpoint.
+pub fn collect_deprecations(openapi_spec: &OpenApiSpec) -> Vec<Deprecation> {
+    let mut deprecations: Vec<Deprecation> = openapi_spec
+        .paths
+        .iter()
+        .flat_map(|(path, path_item)| {
+            path_item.operations.iter().filter_map(move |(method, op)| {
+                operation_deprecation(&format!("{} {}", method.to_uppercase(), path), op)
+            })
+        })
+        .collect();
+
+    deprecations.sort_by(|a, b| match (a.sunset, b.sunset) {
+        (Some(x), Some(y)) => x.cmp(&y).then(a.endpoint.cmp(&b.endpoint)),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.endpoint.cmp(&b.endpoint),
+    });
+    deprecations
+}
+
+/// Date documented for a `Sunset`/`Deprecation` response header (example,
+/// schema example or default).
+fn response_header_date(operation: &Operation, header: &str) -> Option<NaiveDate> {
+    operation
+        .responses
+        .values()
+        .filter_map(|response| response.headers.as_ref())
+        .flat_map(|headers| headers.iter())
+        .filter(|(name, _)| name.eq_ignore_ascii_case(header))
+        .find_map(|(_, h)| {
+            let schema = h.schema.as_ref();
+            [
+                h.example.as_ref(),
+                schema.and_then(|s| s.example.as_ref()),
+                schema.and_then(|s| s.default.as_ref()),
+            ]
+            .into_iter()
+            .flatten()
+            .find_map(parse_date)
+        })
+}
+
+/// Accepts `YYYY-MM-DD`, RFC 3339, HTTP-dates (RFC 7231) and the
+/// `@<unix seconds>` form of the Deprecation header.
+pub fn parse_date(value: &Value) -> Option<NaiveDate> {
+    let text = value.as_str()?.trim();
+
+    if let Some(seconds) = text.strip_prefix('@') {
+        return DateTime::from_timestamp(seconds.parse().ok()?, 0).map(|dt| dt.date_naive());
+    }
+
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(text)
+                .ok()
+                .map(|dt| dt.date_naive())
+        })
+        .or_else(|| {
+            DateTime::parse_from_rfc2822(text)
+                .ok()
+                .map(|dt| dt.date_naive())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+    use serde_json::json;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        assert_eq!(parse_date(&json!("2026-03-01")), Some(date("2026-03-01")));
+        assert_eq!(
+            parse_date(&json!("2026-03-01T12:00:00Z")),
+            Some(date("2026-03-01"))
+        );
+        assert_eq!(
+            parse_date(&json!("Sun, 01 Mar 2026 00:00:00 GMT")),
+            Some(date("2026-03-01"))
+        );
+        assert_eq!(parse_date(&json!("@1772323200")), Some(date("2026-03-01")));
+        assert_eq!(parse_date(&json!("soon")), None);
+        assert_eq!(parse_date(&json!(true)), None);
+    }
+
+    #[test]
+    fn test_collect_deprecations() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/v1/users": {"get": {
+                        "deprecated": true,
+                        "x-deprecated-since": "2025-01-01",
+                        "x-sunset": "2026-01-01",
+                        "responses": {}
+                    }},
+                    "/v1/orders": {"get": {
+                        "deprecated": true,
+                        "responses": {"200": {"description": "ok", "headers": {
+                            "Sunset": {"schema": {"type": "string", "example": "Wed, 01 Jul 2026 00:00:00 GMT"}}
+                        }}}
+                    }},
+                    "/v1/legacy": {"get": {"deprecated": true, "responses": {}}},
+                    "/v2/users": {"get": {"responses": {}}}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let deprecations = collect_deprecations(&spec);
+        let endpoints: Vec<&str> = deprecations.iter().map(|d| d.endpoint.as_str()).collect();
+        assert_eq!(
+            endpoints,
+            vec!["GET /v1/users", "GET /v1/orders", "GET /v1/legacy"]
+        );
+        assert_eq!(deprecations[0].since, Some(date("2025-01-01")));
+        assert_eq!(deprecations[1].sunset, Some(date("2026-07-01")));
+
+        let today = date("2026-06-15");
+        assert_eq!(
+            deprecations[0].status(today),
+            RemovalStatus::Overdue { days_over: 165 }
+        );
+        assert_eq!(
+            deprecations[1].status(today),
+            RemovalStatus::Imminent { days_left: 16 }
+        );
+        assert_eq!(deprecations[2].status(today), RemovalStatus::Unscheduled);
+    }
+}
@@ -0,0 +1,709 @@
+use crate::direction::{schema_directions, SchemaDirection};
+use crate::parser::{MediaType, OpenApiSpec, Operation, Schema};
+use crate::schema_graph::build_schema_graph;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    EndpointAdded,
+    EndpointRemoved,
+    SchemaAdded,
+    SchemaRemoved,
+    FieldAdded,
+    FieldRemoved,
+    FieldTypeChanged,
+    FieldBecameRequired,
+    ParameterAdded,
+    ParameterRemoved,
+    ParameterBecameRequired,
+    RequestBodyAdded,
+    RequestBodyBecameRequired,
+    ResponseAdded,
+    ResponseRemoved,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Where the change happened, e.g. `GET /users` or `User.email`
+    pub location: String,
+    pub message: String,
+    pub breaking: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpecDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    pub fn non_breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| !change.breaking)
+    }
+
+    /// Renders the diff as a Markdown changelog section.
+    pub fn to_markdown(&self, title: &str) -> String {
+        let mut out = format!("# {}\n\n", title);
+
+        if self.is_empty() {
+            out.push_str("No changes.\n");
+            return out;
+        }
+
+        let breaking: Vec<&Change> = self.breaking_changes().collect();
+        if !breaking.is_empty() {
+            out.push_str(&format!("## Breaking changes ({})\n\n", breaking.len()));
+            for change in breaking {
+                out.push_str(&format!("- `{}`: {}\n", change.location, change.message));
+            }
+            out.push('\n');
+        }
+
+        let other: Vec<&Change> = self.non_breaking_changes().collect();
+        if !other.is_empty() {
+            out.push_str(&format!("## Other changes ({})\n\n", other.len()));
+            for change in other {
+                out.push_str(&format!("- `{}`: {}\n", change.location, change.message));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn push(&mut self, kind: ChangeKind, location: String, message: String, breaking: bool) {
+        self.changes.push(Change {
+            kind,
+            location,
+            message,
+            breaking,
+        });
+    }
+}
+
+/// Compares two specifications and classifies every change as breaking or
+/// not from a client's point of view. Schemas are compared down to their
+/// nested properties and array items; newly required fields only break
+/// clients that send them, so they count in request bodies and in
+/// component schemas reached from requests, not in responses.
+pub fn diff_specs(old: &OpenApiSpec, new: &OpenApiSpec) -> SpecDiff {
+    let mut diff = SpecDiff::default();
+
+    // Endpoints
+    let old_ops = operations(old);
+    let new_ops = operations(new);
+    for (endpoint, old_op) in &old_ops {
+        match new_ops.get(endpoint) {
+            Some(new_op) => diff_operation(endpoint, old_op, new_op, &mut diff),
+            None => diff.push(
+                ChangeKind::EndpointRemoved,
+                endpoint.clone(),
+                "endpoint removed".to_string(),
+                true,
+            ),
+        }
+    }
+    for endpoint in new_ops.keys().filter(|e| !old_ops.contains_key(*e)) {
+        diff.push(
+            ChangeKind::EndpointAdded,
+            endpoint.clone(),
+            "endpoint added".to_string(),
+            false,
+        );
+    }
+
+    // Component schemas, sent by clients when a request reaches them in
+    // either version
+    let mut sent = sent_schemas(old);
+    sent.extend(sent_schemas(new));
+    let empty = HashMap::new();
+    let old_schemas = component_schemas(old).unwrap_or(&empty);
+    let new_schemas = component_schemas(new).unwrap_or(&empty);
+    for (name, old_schema) in old_schemas {
+        match new_schemas.get(name) {
+            Some(new_schema) => {
+                diff_schema(name, old_schema, new_schema, sent.contains(name), &mut diff)
+            }
+            None => diff.push(
+                ChangeKind::SchemaRemoved,
+                name.clone(),
+                "schema removed".to_string(),
+                true,
+            ),
+        }
+    }
+    for name in new_schemas.keys().filter(|n| !old_schemas.contains_key(*n)) {
+        diff.push(
+            ChangeKind::SchemaAdded,
+            name.clone(),
+            "schema added".to_string(),
+            false,
+        );
+    }
+
+    diff.changes.sort_by(|a, b| {
+        b.breaking
+            .cmp(&a.breaking)
+            .then(a.location.cmp(&b.location))
+            .then(a.message.cmp(&b.message))
+    });
+    diff
+}
+
+fn operations(spec: &OpenApiSpec) -> HashMap<String, &Operation> {
+    spec.paths
+        .iter()
+        .flat_map(|(path, item)| {
+            item.operations
+                .iter()
+                .map(move |(method, op)| (format!("{} {}", method.to_uppercase(), path), op))
+        })
+        .collect()
+}
+
+fn component_schemas(spec: &OpenApiSpec) -> Option<&HashMap<String, Schema>> {
+    spec.components.as_ref().and_then(|c| c.schemas.as_ref())
+}
+
+/// Component schemas reached from request parameters or bodies.
+fn sent_schemas(spec: &OpenApiSpec) -> BTreeSet<String> {
+    schema_directions(spec, &build_schema_graph(spec))
+        .into_iter()
+        .filter(|(_, direction)| {
+            matches!(direction, SchemaDirection::Request | SchemaDirection::Both)
+        })
+        .map(|(name, _)| name)
+        .collect()
+}
+
+fn diff_operation(endpoint: &str, old: &Operation, new: &Operation, diff: &mut SpecDiff) {
+    // Parameters, keyed by (location, name)
+    let params = |op: &Operation| -> HashMap<(String, String), bool> {
+        op.parameters
+            .iter()
+            .flatten()
+            .map(|p| {
+                (
+                    (p.in_.clone(), p.name.clone()),
+                    p.required.unwrap_or(p.in_ == "path"),
+                )
+            })
+            .collect()
+    };
+    let old_params = params(old);
+    let new_params = params(new);
+
+    for ((location, name), was_required) in &old_params {
+        match new_params.get(&(location.clone(), name.clone())) {
+            None => diff.push(
+                ChangeKind::ParameterRemoved,
+                endpoint.to_string(),
+                format!("{} parameter '{}' removed", location, name),
+                true,
+            ),
+            Some(true) if !was_required => diff.push(
+                ChangeKind::ParameterBecameRequired,
+                endpoint.to_string(),
+                format!("{} parameter '{}' is now required", location, name),
+                true,
+            ),
+            _ => {}
+        }
+    }
+    for ((location, name), required) in &new_params {
+        if !old_params.contains_key(&(location.clone(), name.clone())) {
+            diff.push(
+                ChangeKind::ParameterAdded,
+                endpoint.to_string(),
+                format!(
+                    "{} {} parameter '{}' added",
+                    if *required { "required" } else { "optional" },
+                    location,
+                    name
+                ),
+                *required,
+            );
+        }
+    }
+
+    // Request bodies
+    match (&old.request_body, &new.request_body) {
+        (None, Some(body)) => {
+            let required = body.required.unwrap_or(false);
+            diff.push(
+                ChangeKind::RequestBodyAdded,
+                endpoint.to_string(),
+                format!(
+                    "{} request body added",
+                    if required { "required" } else { "optional" }
+                ),
+                required,
+            );
+        }
+        (Some(old_body), Some(new_body)) => {
+            if new_body.required.unwrap_or(false) && !old_body.required.unwrap_or(false) {
+                diff.push(
+                    ChangeKind::RequestBodyBecameRequired,
+                    endpoint.to_string(),
+                    "request body is now required".to_string(),
+                    true,
+                );
+            }
+            diff_content(
+                &format!("{} request", endpoint),
+                &old_body.content,
+                &new_body.content,
+                true,
+                diff,
+            );
+        }
+        _ => {}
+    }
+
+    // Response status codes
+    let old_statuses: BTreeSet<&String> = old.responses.keys().collect();
+    let new_statuses: BTreeSet<&String> = new.responses.keys().collect();
+    for status in old_statuses.difference(&new_statuses) {
+        diff.push(
+            ChangeKind::ResponseRemoved,
+            endpoint.to_string(),
+            format!("response {} removed", status),
+            status.starts_with('2'),
+        );
+    }
+    for status in new_statuses.difference(&old_statuses) {
+        diff.push(
+            ChangeKind::ResponseAdded,
+            endpoint.to_string(),
+            format!("response {} added", status),
+            false,
+        );
+    }
+    for status in old_statuses.intersection(&new_statuses) {
+        if let (Some(old_content), Some(new_content)) = (
+            &old.responses[*status].content,
+            &new.responses[*status].content,
+        ) {
+            diff_content(
+                &format!("{} {} response", endpoint, status),
+                old_content,
+                new_content,
+                false,
+                diff,
+            );
+        }
+    }
+}
+
+/// Compares the schemas of the media types of a request or response body
+/// found in both versions.
+fn diff_content(
+    location: &str,
+    old: &HashMap<String, MediaType>,
+    new: &HashMap<String, MediaType>,
+    sent: bool,
+    diff: &mut SpecDiff,
+) {
+    for (content_type, old_media) in old {
+        if let (Some(old_schema), Some(new_schema)) = (
+            &old_media.schema,
+            new.get(content_type).and_then(|m| m.schema.as_ref()),
+        ) {
+            diff_schema(location, old_schema, new_schema, sent, diff);
+        }
+    }
+}
+
+/// Compares two versions of the same schema, recursing into inline
+/// properties and array items (`User.address.city`, `User.tags[]`);
+/// references to component schemas are left to the component's own diff.
+/// Newly required fields are breaking when clients send the schema.
+fn diff_schema(location: &str, old: &Schema, new: &Schema, sent: bool, diff: &mut SpecDiff) {
+    if old.reference.is_some() && old.reference == new.reference {
+        return;
+    }
+    if old.reference != new.reference {
+        diff.push(
+            ChangeKind::FieldTypeChanged,
+            location.to_string(),
+            format!(
+                "reference changed from {} to {}",
+                old.reference.as_deref().unwrap_or("inline schema"),
+                new.reference.as_deref().unwrap_or("inline schema")
+            ),
+            true,
+        );
+        return;
+    }
+
+    if let (Some(old_items), Some(new_items)) = (&old.items, &new.items) {
+        let items_location = format!("{}[]", location);
+        let (old_type, new_type) = (describe_type(old_items), describe_type(new_items));
+        if old_type == new_type {
+            diff_schema(&items_location, old_items, new_items, sent, diff);
+        } else {
+            diff.push(
+                ChangeKind::FieldTypeChanged,
+                items_location,
+                format!("item type changed from {} to {}", old_type, new_type),
+                true,
+            );
+        }
+    }
+
+    let empty = HashMap::new();
+    let old_props = old.properties.as_ref().unwrap_or(&empty);
+    let new_props = new.properties.as_ref().unwrap_or(&empty);
+
+    let added: Vec<&String> = new_props
+        .keys()
+        .filter(|f| !old_props.contains_key(*f))
+        .collect();
+    let removed_count = old_props
+        .keys()
+        .filter(|f| !new_props.contains_key(*f))
+        .count();
+    // A single field swapped for a single field of the same type reads as a
+    // rename
+    let rename_target = match (removed_count, added.as_slice()) {
+        (1, [only]) => Some(*only),
+        _ => None,
+    };
+
+    for (field, old_field) in old_props {
+        let field_location = format!("{}.{}", location, field);
+        match new_props.get(field) {
+            None => diff.push(
+                ChangeKind::FieldRemoved,
+                field_location,
+                match rename_target
+                    .filter(|target| describe_type(&new_props[*target]) == describe_type(old_field))
+                {
+                    Some(target) => format!("field removed, possibly renamed to '{}'", target),
+                    None => "field removed".to_string(),
+                },
+                true,
+            ),
+            Some(new_field) => {
+                let old_type = describe_type(old_field);
+                let new_type = describe_type(new_field);
+                if new.is_field_required(field) && !old.is_field_required(field) {
+                    diff.push(
+                        ChangeKind::FieldBecameRequired,
+                        field_location.clone(),
+                        "field is now required".to_string(),
+                        sent,
+                    );
+                }
+                if old_type == new_type {
+                    diff_schema(&field_location, old_field, new_field, sent, diff);
+                } else {
+                    diff.push(
+                        ChangeKind::FieldTypeChanged,
+                        field_location,
+                        format!("type changed from {} to {}", old_type, new_type),
+                        true,
+                    );
+                }
+            }
+        }
+    }
+
+    for field in added {
+        let required = new.is_field_required(field);
+        diff.push(
+            ChangeKind::FieldAdded,
+            format!("{}.{}", location, field),
+            if required {
+                "required field added".to_string()
+            } else {
+                "optional field added".to_string()
+            },
+            required && sent,
+        );
+    }
+}
+
+fn describe_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    match (&schema.schema_type, &schema.items) {
+        (Some(t), Some(items)) if t == "array" => format!("array<{}>", describe_type(items)),
+        (Some(t), _) => match &schema.format {
+            Some(format) => format!("{}({})", t, format),
+            None => t.clone(),
+        },
+        (None, _) => "any".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn spec(paths: &str, schemas: &str) -> OpenApiSpec {
+        parse_openapi_content(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "T", "version": "1"}},
+                "paths": {}, "components": {{"schemas": {}}}}}"#,
+            paths, schemas
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_identical_specs_have_no_changes() {
+        let a = spec(
+            r#"{"/a": {"get": {"responses": {}}}}"#,
+            r#"{"A": {"properties": {"x": {"type": "string"}}}}"#,
+        );
+        let diff = diff_specs(&a, &a);
+        assert!(diff.is_empty());
+        assert!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_removed_endpoint_is_breaking() {
+        let old = spec(
+            r#"{"/a": {"get": {"responses": {}}, "delete": {"responses": {}}}}"#,
+            "{}",
+        );
+        let new = spec(
+            r#"{"/a": {"get": {"responses": {}}}, "/b": {"get": {"responses": {}}}}"#,
+            "{}",
+        );
+
+        let diff = diff_specs(&old, &new);
+        assert_eq!(diff.changes.len(), 2);
+        assert_eq!(diff.changes[0].kind, ChangeKind::EndpointRemoved);
+        assert_eq!(diff.changes[0].location, "DELETE /a");
+        assert!(diff.changes[0].breaking);
+        assert_eq!(diff.changes[1].kind, ChangeKind::EndpointAdded);
+        assert!(!diff.changes[1].breaking);
+    }
+
+    /// `POST /users` sending a `User`
+    const POST_USER: &str = r##"{"/users": {"post": {"responses": {},
+        "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}
+    }}}"##;
+
+    #[test]
+    fn test_field_changes() {
+        let old = spec(
+            POST_USER,
+            r#"{"User": {"properties": {
+                "id": {"type": "integer"},
+                "name": {"type": "string"},
+                "email": {"type": "string"}
+            }}}"#,
+        );
+        let new = spec(
+            POST_USER,
+            r#"{"User": {"required": ["email"], "properties": {
+                "id": {"type": "string"},
+                "email": {"type": "string"},
+                "nickname": {"type": "string"}
+            }}}"#,
+        );
+
+        let diff = diff_specs(&old, &new);
+        let kinds: Vec<(ChangeKind, &str)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.kind, c.location.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ChangeKind::FieldBecameRequired, "User.email"),
+                (ChangeKind::FieldTypeChanged, "User.id"),
+                (ChangeKind::FieldRemoved, "User.name"),
+                (ChangeKind::FieldAdded, "User.nickname"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_required_fields_only_break_requests() {
+        let schemas = |required: &str| {
+            format!(
+                r#"{{"User": {{"required": {0}, "properties": {{"email": {{"type": "string"}}}}}},
+                    "Order": {{"required": {0}, "properties": {{"email": {{"type": "string"}}}}}}}}"#,
+                required
+            )
+        };
+        let paths = r##"{
+            "/users": {"post": {"responses": {},
+                "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}},
+            "/orders": {"get": {"responses": {"200": {"description": "ok",
+                "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}}}}}
+        }"##;
+        let old = spec(paths, &schemas("[]"));
+        let new = spec(paths, &schemas(r#"["email"]"#));
+
+        let diff = diff_specs(&old, &new);
+        let changes: Vec<(&str, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.location.as_str(), c.breaking))
+            .collect();
+        assert_eq!(changes, [("User.email", true), ("Order.email", false)]);
+    }
+
+    #[test]
+    fn test_inline_and_nested_schemas_are_compared() {
+        let old = spec(
+            r#"{"/users": {"post": {
+                "requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {
+                    "address": {"type": "object", "properties": {"city": {"type": "string"}}}
+                }}}}},
+                "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {
+                    "type": "array", "items": {"type": "object", "properties": {
+                        "id": {"type": "integer"},
+                        "tags": {"type": "array", "items": {"type": "string"}}
+                    }}
+                }}}}}
+            }}}"#,
+            "{}",
+        );
+        let new = spec(
+            r#"{"/users": {"post": {
+                "requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {
+                    "address": {"type": "object", "required": ["zip"], "properties": {
+                        "city": {"type": "string"},
+                        "zip": {"type": "string"}
+                    }}
+                }}}}},
+                "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {
+                    "type": "array", "items": {"type": "object", "properties": {
+                        "tags": {"type": "array", "items": {"type": "integer"}},
+                        "extra": {"type": "object", "required": ["note"], "properties": {"note": {"type": "string"}}}
+                    }}
+                }}}}}
+            }}}"#,
+            "{}",
+        );
+
+        let diff = diff_specs(&old, &new);
+        let changes: Vec<(&str, &str, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.location.as_str(), c.message.as_str(), c.breaking))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("POST /users 200 response[].id", "field removed", true),
+                (
+                    "POST /users 200 response[].tags",
+                    "type changed from array<string> to array<integer>",
+                    true
+                ),
+                (
+                    "POST /users request.address.zip",
+                    "required field added",
+                    true
+                ),
+                (
+                    "POST /users 200 response[].extra",
+                    "optional field added",
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_required_request_body_added_is_breaking() {
+        let body = |required: bool| {
+            format!(
+                r#"{{"/a": {{"post": {{"responses": {{}}, "requestBody": {{"required": {}, "content": {{}}}}}}}}}}"#,
+                required
+            )
+        };
+        let none = spec(r#"{"/a": {"post": {"responses": {}}}}"#, "{}");
+
+        let diff = diff_specs(&none, &spec(&body(true), "{}"));
+        assert_eq!(diff.changes[0].kind, ChangeKind::RequestBodyAdded);
+        assert_eq!(diff.changes[0].message, "required request body added");
+        assert!(diff.has_breaking_changes());
+        assert!(!diff_specs(&none, &spec(&body(false), "{}")).has_breaking_changes());
+
+        let diff = diff_specs(&spec(&body(false), "{}"), &spec(&body(true), "{}"));
+        assert_eq!(diff.changes[0].kind, ChangeKind::RequestBodyBecameRequired);
+        assert!(diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_renamed_field_is_reported() {
+        let old = spec(
+            "{}",
+            r#"{"User": {"properties": {"id": {"type": "integer"}, "mail": {"type": "string"}}}}"#,
+        );
+        let new = spec(
+            "{}",
+            r#"{"User": {"properties": {"id": {"type": "integer"}, "email": {"type": "string"}}}}"#,
+        );
+
+        let diff = diff_specs(&old, &new);
+        assert_eq!(diff.changes[0].kind, ChangeKind::FieldRemoved);
+        assert_eq!(
+            diff.changes[0].message,
+            "field removed, possibly renamed to 'email'"
+        );
+        assert!(diff.changes[0].breaking);
+
+        // A type change rules out the rename
+        let new = spec(
+            "{}",
+            r#"{"User": {"properties": {"id": {"type": "integer"}, "email": {"type": "boolean"}}}}"#,
+        );
+        assert_eq!(diff_specs(&old, &new).changes[0].message, "field removed");
+    }
+
+    #[test]
+    fn test_new_required_parameter_is_breaking() {
+        let old = spec(r#"{"/a": {"get": {"responses": {}}}}"#, "{}");
+        let new = spec(
+            r#"{"/a": {"get": {"responses": {}, "parameters": [
+                {"name": "q", "in": "query", "required": true},
+                {"name": "page", "in": "query"}
+            ]}}}"#,
+            "{}",
+        );
+
+        let diff = diff_specs(&old, &new);
+        assert_eq!(diff.breaking_changes().count(), 1);
+        assert_eq!(diff.non_breaking_changes().count(), 1);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let old = spec(r#"{"/a": {"get": {"responses": {}}}}"#, "{}");
+        let new = spec("{}", "{}");
+
+        let markdown = diff_specs(&old, &new).to_markdown("API changes");
+        assert!(markdown.starts_with("# API changes"));
+        assert!(markdown.contains("## Breaking changes (1)"));
+        assert!(markdown.contains("- `GET /a`: endpoint removed"));
+    }
+}
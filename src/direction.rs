@@ -0,0 +1,163 @@
+//! Whether component schemas are sent by clients, returned to them or
+//! both, from the parameters, request bodies and responses reaching them
+//! directly or through other schemas.
+
+use crate::parser::{OpenApiSpec, Operation};
+use crate::schema_graph::{referenced_schemas, SchemaGraph};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchemaDirection {
+    /// Only sent to the API: parameters and request bodies
+    Request,
+    /// Only returned by the API: responses, and webhook or callback
+    /// payloads
+    Response,
+    Both,
+    /// Reached by no operation
+    Unused,
+}
+
+impl SchemaDirection {
+    pub fn icon(self) -> &'static str {
+        match self {
+            SchemaDirection::Request => "↑",
+            SchemaDirection::Response => "↓",
+            SchemaDirection::Both => "↕",
+            SchemaDirection::Unused => "·",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SchemaDirection::Request => "request only",
+            SchemaDirection::Response => "response only",
+            SchemaDirection::Both => "request and response",
+            SchemaDirection::Unused => "unused",
+        }
+    }
+}
+
+/// Schemas an operation receives (parameters, request body) and sends
+/// (responses).
+fn operation_schemas(operation: &Operation) -> (Vec<String>, Vec<String>) {
+    let mut received = Vec::new();
+    for parameter in operation.parameters.iter().flatten() {
+        received.extend(parameter.schema.iter().flat_map(referenced_schemas));
+    }
+    if let Some(body) = &operation.request_body {
+        for media_type in body.content.values() {
+            received.extend(media_type.schema.iter().flat_map(referenced_schemas));
+        }
+    }
+    let mut sent = Vec::new();
+    for response in operation.responses.values() {
+        for media_type in response.content.iter().flat_map(|content| content.values()) {
+            sent.extend(media_type.schema.iter().flat_map(referenced_schemas));
+        }
+    }
+    (received, sent)
+}
+
+/// Direction of every component schema. Webhooks and callbacks are
+/// requests the API makes, so their payloads count as responses and their
+/// responses as requests.
+pub fn schema_directions(
+    openapi_spec: &OpenApiSpec,
+    graph: &SchemaGraph,
+) -> HashMap<String, SchemaDirection> {
+    let mut requests = BTreeSet::new();
+    let mut responses = BTreeSet::new();
+    for operation in openapi_spec
+        .paths
+        .values()
+        .flat_map(|item| item.operations.values())
+    {
+        let (received, sent) = operation_schemas(operation);
+        requests.extend(received);
+        responses.extend(sent);
+    }
+    for (_, item) in openapi_spec.event_targets() {
+        for operation in item.operations.values() {
+            let (received, sent) = operation_schemas(operation);
+            responses.extend(received);
+            requests.extend(sent);
+        }
+    }
+
+    let reached = |roots: &BTreeSet<String>| -> BTreeSet<String> {
+        roots
+            .iter()
+            .flat_map(|root| graph.transitive_dependencies(root))
+            .collect()
+    };
+    let requests = reached(&requests);
+    let responses = reached(&responses);
+
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    schemas
+        .into_iter()
+        .flat_map(|schemas| schemas.keys())
+        .map(|name| {
+            let direction = match (requests.contains(name), responses.contains(name)) {
+                (true, true) => SchemaDirection::Both,
+                (true, false) => SchemaDirection::Request,
+                (false, true) => SchemaDirection::Response,
+                (false, false) => SchemaDirection::Unused,
+            };
+            (name.clone(), direction)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+    use crate::schema_graph::build_schema_graph;
+
+    #[test]
+    fn test_directions_follow_references() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {"/users": {
+                    "post": {
+                        "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/NewUser"}}}},
+                        "responses": {"201": {"description": "created", "content": {
+                            "application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+                    },
+                    "get": {
+                        "parameters": [{"name": "sort", "in": "query", "schema": {"$ref": "#/components/schemas/Sort"}}],
+                        "responses": {"200": {"description": "ok"}}
+                    }
+                }},
+                "webhooks": {"userDeleted": {"post": {
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Event"}}}},
+                    "responses": {"200": {"description": "ok"}}
+                }}},
+                "components": {"schemas": {
+                    "Address": {"type": "object", "properties": {"city": {"type": "string"}}},
+                    "NewUser": {"type": "object", "properties": {"address": {"$ref": "#/components/schemas/Address"}}},
+                    "User": {"type": "object", "properties": {"address": {"$ref": "#/components/schemas/Address"}}},
+                    "Sort": {"type": "string"},
+                    "Event": {"type": "object"},
+                    "Legacy": {"type": "object"}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let directions = schema_directions(&spec, &build_schema_graph(&spec));
+        assert_eq!(directions["NewUser"], SchemaDirection::Request);
+        assert_eq!(directions["Sort"], SchemaDirection::Request);
+        assert_eq!(directions["User"], SchemaDirection::Response);
+        assert_eq!(directions["Event"], SchemaDirection::Response);
+        assert_eq!(directions["Address"], SchemaDirection::Both);
+        assert_eq!(directions["Legacy"], SchemaDirection::Unused);
+    }
+}
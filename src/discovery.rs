@@ -0,0 +1,179 @@
+//! Discovery of the specs served by a list of services: each base URL is
+//! probed at the well-known spec paths, and the first document found is
+//! stored locally to be explored with the others.
+
+use crate::remote::{self, Fetched, Validators};
+use anyhow::{anyhow, Result};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Paths probed in order when the config lists none
+pub const DEFAULT_PATHS: &[&str] = &["/openapi.json", "/swagger.json", "/v3/api-docs"];
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `[discovery]` of the config file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveryConfig {
+    /// Base URLs of the services
+    pub services: Vec<String>,
+    pub paths: Vec<String>,
+    /// Per request
+    pub timeout: Duration,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            services: Vec::new(),
+            paths: DEFAULT_PATHS.iter().map(|path| path.to_string()).collect(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// Outcome of probing one service.
+#[derive(Debug)]
+pub struct ServiceProbe {
+    pub base_url: String,
+    /// URL the spec was found at and where it is stored, else why no path
+    /// served one (a rejected document rather than a failed request)
+    pub result: Result<(String, PathBuf), String>,
+}
+
+/// Summary of a discovery, one line per service.
+pub fn to_text(probes: &[ServiceProbe]) -> String {
+    let found = probes.iter().filter(|probe| probe.result.is_ok()).count();
+    let mut out = format!(
+        "Found {} spec(s) across {} service(s)\n",
+        found,
+        probes.len()
+    );
+    for probe in probes {
+        match &probe.result {
+            Ok((url, _)) => out.push_str(&format!("  ✓ {}\n", url)),
+            Err(error) => out.push_str(&format!("  ✗ {}: {}\n", probe.base_url, error)),
+        }
+    }
+    out
+}
+
+/// Probes every service concurrently, storing the specs found in
+/// `store_dir`. Probes are returned in the order of the services.
+pub async fn discover(config: &DiscoveryConfig, store_dir: &Path) -> Result<Vec<ServiceProbe>> {
+    let client = reqwest::Client::builder().timeout(config.timeout).build()?;
+    let mut tasks = tokio::task::JoinSet::new();
+    for (position, base_url) in config.services.iter().enumerate() {
+        let (client, base_url, paths) = (client.clone(), base_url.clone(), config.paths.clone());
+        let store_dir = store_dir.to_path_buf();
+        tasks.spawn(async move {
+            let result = probe(&client, &base_url, &paths, &store_dir)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            (position, ServiceProbe { base_url, result })
+        });
+    }
+    let mut probes = Vec::with_capacity(config.services.len());
+    while let Some(joined) = tasks.join_next().await {
+        probes.push(joined?);
+    }
+    probes.sort_by_key(|(position, _)| *position);
+    Ok(probes.into_iter().map(|(_, probe)| probe).collect())
+}
+
+/// The first of `paths` under `base_url` serving an OpenAPI 3 (or
+/// AsyncAPI) document, stored in `store_dir`.
+async fn probe(
+    client: &reqwest::Client,
+    base_url: &str,
+    paths: &[String],
+    store_dir: &Path,
+) -> Result<(String, PathBuf)> {
+    let mut failed = None;
+    let mut rejected = None;
+    for path in paths {
+        let url = format!("{}{}", base_url.trim_end_matches('/'), path);
+        let (content, validators) = match remote::fetch(client, &url, &Validators::default()).await
+        {
+            Ok(Fetched::Modified {
+                content,
+                validators,
+            }) => (content, validators),
+            Ok(Fetched::NotModified) => continue,
+            Err(e) => {
+                failed = Some(e);
+                continue;
+            }
+        };
+        match check_document(&content) {
+            Ok(()) => {
+                let stored = remote::store(store_dir, &url, &content, &validators)?;
+                return Ok((url, stored));
+            }
+            Err(e) => rejected = Some(anyhow!("{}: {}", url, e)),
+        }
+    }
+    Err(rejected
+        .or(failed)
+        .unwrap_or_else(|| anyhow!("no path to probe")))
+}
+
+/// Rejects responses that are not specs the explorer reads (HTML pages,
+/// Swagger 2.0 documents...).
+fn check_document(content: &str) -> Result<()> {
+    let document: serde_json::Value =
+        serde_json::from_str(content).map_err(|_| anyhow!("not a JSON document"))?;
+    if document.get("openapi").is_some() || document.get("asyncapi").is_some() {
+        Ok(())
+    } else if document.get("swagger").is_some() {
+        Err(anyhow!("Swagger 2.0 document, only OpenAPI 3 is supported"))
+    } else {
+        Err(anyhow!("JSON document without an `openapi` version"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{}", address)
+    }
+
+    #[tokio::test]
+    async fn test_probes_well_known_paths() {
+        let spring = serve(Router::new().route(
+            "/v3/api-docs",
+            get(|| async {
+                r#"{"openapi": "3.0.1", "info": {"title": "Orders", "version": "1"}, "paths": {}}"#
+            }),
+        ))
+        .await;
+        let legacy = serve(Router::new().route(
+            "/swagger.json",
+            get(|| async {
+                r#"{"swagger": "2.0", "info": {"title": "Legacy", "version": "1"}, "paths": {}}"#
+            }),
+        ))
+        .await;
+        let dir = tempfile::tempdir().unwrap();
+        let config = DiscoveryConfig {
+            services: vec![format!("{}/", spring), legacy.clone()],
+            ..DiscoveryConfig::default()
+        };
+
+        let probes = discover(&config, dir.path()).await.unwrap();
+        let (url, stored) = probes[0].result.as_ref().unwrap();
+        assert_eq!(url, &format!("{}/v3/api-docs", spring));
+        assert!(std::fs::read_to_string(stored).unwrap().contains("Orders"));
+        // Reported for the document it serves rather than the missing paths
+        assert_eq!(probes[1].base_url, legacy);
+        let error = probes[1].result.as_ref().unwrap_err();
+        assert!(error.contains("/swagger.json: Swagger 2.0"), "{}", error);
+        assert!(to_text(&probes).starts_with("Found 1 spec(s) across 2 service(s)"));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::compare::field_sides;
+use crate::parser::OpenApiSpec;
+use std::collections::BTreeMap;
+
+/// Share of same-typed fields from which two schemas are reported
+pub const NEAR_DUPLICATE_THRESHOLD: f64 = 0.9;
+
+/// Two component schemas with (nearly) the same structure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicatePair {
+    pub left: String,
+    pub right: String,
+    /// Fields with the same name and type, over all fields of both schemas
+    pub similarity: f64,
+    pub shared_fields: usize,
+    pub total_fields: usize,
+    /// Same fields, types and required flags
+    pub exact: bool,
+}
+
+/// Structural fingerprint of a schema: field name to type label and
+/// required flag, `allOf` parts included. Descriptions are ignored.
+pub type Fingerprint = BTreeMap<String, (String, bool)>;
+
+/// Pairs of object schemas sharing at least `NEAR_DUPLICATE_THRESHOLD` of
+/// their fields with the same types, most similar first.
+pub fn find_duplicate_schemas(spec: &OpenApiSpec) -> Vec<DuplicatePair> {
+    let Some(schemas) = spec.components.as_ref().and_then(|c| c.schemas.as_ref()) else {
+        return Vec::new();
+    };
+    let mut fingerprints: Vec<(&String, Fingerprint)> = schemas
+        .iter()
+        .map(|(name, schema)| {
+            let fingerprint = field_sides(schema, schemas)
+                .into_iter()
+                .map(|(field, side)| (field, (side.type_label, side.required)))
+                .collect();
+            (name, fingerprint)
+        })
+        .filter(|(_, fingerprint): &(_, Fingerprint)| !fingerprint.is_empty())
+        .collect();
+    fingerprints.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut pairs = Vec::new();
+    for (i, (left, left_fields)) in fingerprints.iter().enumerate() {
+        for (right, right_fields) in &fingerprints[i + 1..] {
+            let (smaller, larger) = if left_fields.len() <= right_fields.len() {
+                (left_fields.len(), right_fields.len())
+            } else {
+                (right_fields.len(), left_fields.len())
+            };
+            // Fields missing from the smaller schema alone rule the pair out
+            if (smaller as f64) < larger as f64 * NEAR_DUPLICATE_THRESHOLD {
+                continue;
+            }
+            let shared_fields = left_fields
+                .iter()
+                .filter(|(field, (type_label, _))| {
+                    right_fields
+                        .get(*field)
+                        .is_some_and(|(other, _)| other == type_label)
+                })
+                .count();
+            let total_fields = left_fields.len() + right_fields.len() - shared_fields;
+            let similarity = shared_fields as f64 / total_fields as f64;
+            if similarity >= NEAR_DUPLICATE_THRESHOLD {
+                pairs.push(DuplicatePair {
+                    left: left.to_string(),
+                    right: right.to_string(),
+                    similarity,
+                    shared_fields,
+                    total_fields,
+                    exact: left_fields == right_fields,
+                });
+            }
+        }
+    }
+    pairs.sort_by(|a, b| {
+        b.similarity
+            .total_cmp(&a.similarity)
+            .then(b.exact.cmp(&a.exact))
+            .then(a.left.cmp(&b.left))
+            .then(a.right.cmp(&b.right))
+    });
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_exact_and_near_duplicates() {
+        let fields = |extra: &str| {
+            let mut properties: Vec<String> = (1..=9)
+                .map(|i| format!(r#""f{}": {{"type": "string"}}"#, i))
+                .collect();
+            if !extra.is_empty() {
+                properties.push(extra.to_string());
+            }
+            format!(
+                r#"{{"type": "object", "properties": {{{}}}}}"#,
+                properties.join(", ")
+            )
+        };
+        let spec = parse_openapi_content(&format!(
+            r#"{{
+                "openapi": "3.0.0",
+                "info": {{"title": "Dupes", "version": "1"}},
+                "paths": {{}},
+                "components": {{"schemas": {{
+                    "UserDto": {},
+                    "UserResponse": {},
+                    "UserSummary": {},
+                    "Retyped": {},
+                    "Other": {{"type": "object", "properties": {{"id": {{"type": "integer"}}}}}},
+                    "Status": {{"type": "string", "enum": ["a"]}}
+                }}}}
+            }}"#,
+            fields(r#""id": {"type": "integer"}"#),
+            fields(r#""id": {"type": "integer"}"#),
+            fields(""),
+            fields(r#""id": {"type": "string"}"#),
+        ))
+        .unwrap();
+
+        let pairs = find_duplicate_schemas(&spec);
+        let found: Vec<(&str, &str, bool)> = pairs
+            .iter()
+            .map(|p| (p.left.as_str(), p.right.as_str(), p.exact))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("UserDto", "UserResponse", true),
+                ("Retyped", "UserSummary", false),
+                ("UserDto", "UserSummary", false),
+                ("UserResponse", "UserSummary", false),
+            ]
+        );
+        assert_eq!(pairs[0].similarity, 1.0);
+        assert_eq!((pairs[1].shared_fields, pairs[1].total_fields), (9, 10));
+    }
+}
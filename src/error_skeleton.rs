@@ -0,0 +1,353 @@
+//! Spec snippets adding the error responses an operation is missing,
+//! each referencing the spec's error schema, so response gaps can be
+//! fixed by merging a fragment or applying a JSON Patch.
+
+use crate::error_taxonomy::{analyze_error_taxonomy, ErrorShape, PROBLEM_JSON};
+use crate::indexer::{FieldIndex, ResponseData};
+use crate::parser::OpenApiSpec;
+use crate::responses::{response_gaps, ResponseGap};
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+/// Error schema referenced when the spec has no canonical one yet
+pub const DEFAULT_ERROR_SCHEMA: &str = "Error";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkeletonFormat {
+    /// Fragment to merge into a JSON document
+    Json,
+    /// Fragment to merge into a YAML document
+    Yaml,
+    /// JSON Patch (RFC 6902) `add` operations
+    Patch,
+}
+
+impl SkeletonFormat {
+    /// YAML for `.yaml`/`.yml` files, a JSON Patch otherwise.
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => SkeletonFormat::Yaml,
+            _ => SkeletonFormat::Patch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorSkeleton {
+    pub error_schema: String,
+    pub content_type: &'static str,
+    /// Definition to add when the spec does not declare the error schema
+    pub schema_definition: Option<Value>,
+    /// Where the definition is added: the schema itself, or the first of
+    /// `components` and `components/schemas` missing from the document
+    pub schema_pointer: String,
+    /// `"METHOD /path"` and the error statuses it is missing
+    pub endpoints: Vec<(String, Vec<String>)>,
+}
+
+/// Error statuses an operation should add: 400 for writes without a 4xx,
+/// 404 for paths with parameters, 401 for secured operations, and 500
+/// when no 5xx or `default` response is documented.
+pub fn missing_error_statuses(endpoint: &str, data: &ResponseData) -> Vec<String> {
+    let mut statuses: Vec<String> = response_gaps(endpoint, data)
+        .into_iter()
+        .filter_map(|gap| match gap {
+            ResponseGap::NoSuccess => None,
+            ResponseGap::NoClientError => Some("400"),
+            ResponseGap::NoUnauthorized => Some("401"),
+            ResponseGap::NoNotFound => Some("404"),
+        })
+        .map(str::to_string)
+        .collect();
+    let has_server_error = data
+        .statuses
+        .iter()
+        .any(|s| s.starts_with('5') || s.eq_ignore_ascii_case("default"));
+    if !has_server_error {
+        statuses.push("500".to_string());
+    }
+    statuses.sort();
+    statuses
+}
+
+/// Skeleton for the given endpoints, skipping those without gaps. The
+/// error schema is the spec's canonical one when it is a component
+/// schema, and Problem+JSON is used when most error responses already do.
+pub fn build_error_skeleton(
+    openapi_spec: &OpenApiSpec,
+    index: &FieldIndex,
+    endpoints: &[String],
+) -> ErrorSkeleton {
+    let taxonomy = analyze_error_taxonomy(openapi_spec);
+    let error_schema = match &taxonomy.canonical {
+        Some(ErrorShape::Named(name)) => name.clone(),
+        _ => DEFAULT_ERROR_SCHEMA.to_string(),
+    };
+    let content_type = if taxonomy.problem_json_count() * 2 > taxonomy.responses.len() {
+        PROBLEM_JSON
+    } else {
+        "application/json"
+    };
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    let declared = schemas.is_some_and(|schemas| schemas.contains_key(&error_schema));
+    let schema_pointer = match (&openapi_spec.components, schemas) {
+        (None, _) => "/components".to_string(),
+        (Some(_), None) => "/components/schemas".to_string(),
+        (Some(_), Some(_)) => format!("/components/schemas/{}", escape(&error_schema)),
+    };
+
+    ErrorSkeleton {
+        schema_definition: (!declared).then(|| {
+            json!({
+                "type": "object",
+                "required": ["code", "message"],
+                "properties": {
+                    "code": {"type": "string"},
+                    "message": {"type": "string"}
+                }
+            })
+        }),
+        endpoints: endpoints
+            .iter()
+            .filter_map(|endpoint| {
+                let statuses = missing_error_statuses(endpoint, index.responses.get(endpoint)?);
+                (!statuses.is_empty()).then(|| (endpoint.clone(), statuses))
+            })
+            .collect(),
+        schema_pointer,
+        error_schema,
+        content_type,
+    }
+}
+
+fn status_description(status: &str) -> &'static str {
+    match status {
+        "400" => "Invalid request",
+        "401" => "Missing or invalid credentials",
+        "404" => "Resource not found",
+        _ => "Unexpected server error",
+    }
+}
+
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+impl ErrorSkeleton {
+    pub fn response_count(&self) -> usize {
+        self.endpoints
+            .iter()
+            .map(|(_, statuses)| statuses.len())
+            .sum()
+    }
+
+    fn response(&self, status: &str) -> Value {
+        json!({
+            "description": status_description(status),
+            "content": {
+                self.content_type: {
+                    "schema": {"$ref": format!("#/components/schemas/{}", self.error_schema)}
+                }
+            }
+        })
+    }
+
+    fn operations(&self) -> impl Iterator<Item = (&str, String, &Vec<String>)> {
+        self.endpoints.iter().filter_map(|(endpoint, statuses)| {
+            let (method, path) = endpoint.split_once(' ')?;
+            Some((path, method.to_lowercase(), statuses))
+        })
+    }
+
+    /// Document fragment holding only the additions, to merge into the
+    /// spec.
+    pub fn fragment(&self) -> Value {
+        let mut paths = Map::new();
+        for (path, method, statuses) in self.operations() {
+            let responses: Map<String, Value> = statuses
+                .iter()
+                .map(|status| (status.clone(), self.response(status)))
+                .collect();
+            let item = paths.entry(path.to_string()).or_insert_with(|| json!({}));
+            item[method.as_str()] = json!({ "responses": responses });
+        }
+        let mut fragment = json!({ "paths": paths });
+        if let Some(definition) = &self.schema_definition {
+            fragment["components"] = json!({"schemas": {self.error_schema.as_str(): definition}});
+        }
+        fragment
+    }
+
+    /// JSON Patch adding the schema (when missing), then each response.
+    pub fn patch(&self) -> Vec<Value> {
+        let mut operations = Vec::new();
+        if let Some(definition) = &self.schema_definition {
+            let schemas = || json!({ self.error_schema.as_str(): definition });
+            let value = match self.schema_pointer.as_str() {
+                "/components" => json!({ "schemas": schemas() }),
+                "/components/schemas" => schemas(),
+                _ => definition.clone(),
+            };
+            operations.push(json!({
+                "op": "add",
+                "path": self.schema_pointer,
+                "value": value,
+            }));
+        }
+        for (path, method, statuses) in self.operations() {
+            for status in statuses {
+                operations.push(json!({
+                    "op": "add",
+                    "path": format!("/paths/{}/{}/responses/{}", escape(path), method, status),
+                    "value": self.response(status),
+                }));
+            }
+        }
+        operations
+    }
+
+    pub fn render(&self, format: SkeletonFormat) -> String {
+        match format {
+            SkeletonFormat::Json => to_pretty_json(&self.fragment()),
+            SkeletonFormat::Yaml => to_yaml(&self.fragment()),
+            SkeletonFormat::Patch => to_pretty_json(&Value::Array(self.patch())),
+        }
+    }
+}
+
+fn to_pretty_json(value: &Value) -> String {
+    let mut json = serde_json::to_string_pretty(value).unwrap_or_default();
+    json.push('\n');
+    json
+}
+
+/// Block-style YAML. Keys and strings are written as JSON strings, which
+/// YAML reads as double-quoted scalars.
+pub fn to_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(value, 0, &mut out);
+    out
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Object(map) if map.is_empty() => "{}".to_string(),
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn is_block(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let key = Value::String(key.clone()).to_string();
+                if is_block(child) {
+                    out.push_str(&format!("{}{}:\n", pad, key));
+                    write_yaml(child, indent + 2, out);
+                } else {
+                    out.push_str(&format!("{}{}: {}\n", pad, key, yaml_scalar(child)));
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                if is_block(item) {
+                    out.push_str(&format!("{}-\n", pad));
+                    write_yaml(item, indent + 2, out);
+                } else {
+                    out.push_str(&format!("{}- {}\n", pad, yaml_scalar(item)));
+                }
+            }
+        }
+        scalar => out.push_str(&format!("{}{}\n", pad, yaml_scalar(scalar))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    fn spec() -> OpenApiSpec {
+        parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/orders": {
+                        "get": {"responses": {"200": {"description": "ok"}, "default": {"description": "error"}}},
+                        "post": {"responses": {"201": {"description": "created"}}}
+                    },
+                    "/orders/{id}": {"get": {"responses": {"200": {"description": "ok"}, "500": {"description": "error"}}}}
+                }
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_missing_statuses_per_endpoint() {
+        let spec = spec();
+        let index = build_field_index(&spec);
+        let endpoints: Vec<String> = ["GET /orders", "POST /orders", "GET /orders/{id}"]
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let skeleton = build_error_skeleton(&spec, &index, &endpoints);
+        assert_eq!(
+            skeleton.endpoints,
+            vec![
+                (
+                    "POST /orders".to_string(),
+                    vec!["400".to_string(), "500".to_string()]
+                ),
+                ("GET /orders/{id}".to_string(), vec!["404".to_string()]),
+            ]
+        );
+        assert_eq!(skeleton.error_schema, DEFAULT_ERROR_SCHEMA);
+        assert_eq!(skeleton.response_count(), 3);
+
+        let patch = skeleton.patch();
+        assert_eq!(patch.len(), 4);
+        assert_eq!(patch[0]["path"], "/components");
+        assert_eq!(
+            patch[0]["value"]["schemas"]["Error"]["required"],
+            json!(["code", "message"])
+        );
+        assert_eq!(patch[3]["path"], "/paths/~1orders~1{id}/get/responses/404");
+        assert_eq!(
+            patch[3]["value"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Error"
+        );
+
+        let fragment = skeleton.fragment();
+        assert_eq!(
+            fragment["paths"]["/orders"]["post"]["responses"]["400"]["description"],
+            "Invalid request"
+        );
+    }
+
+    #[test]
+    fn test_yaml_rendering() {
+        let value = json!({"paths": {"/a": {"responses": {"404": {"description": "Not found"}}}}, "tags": ["x"], "empty": {}});
+        assert_eq!(
+            to_yaml(&value),
+            "\"empty\": {}\n\"paths\":\n  \"/a\":\n    \"responses\":\n      \"404\":\n        \"description\": \"Not found\"\n\"tags\":\n  - \"x\"\n"
+        );
+    }
+}
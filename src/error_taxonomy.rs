@@ -0,0 +1,251 @@
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::{BTreeSet, HashMap};
+
+/// Content type defined by RFC 7807 / RFC 9457
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Properties that identify an inline Problem Details object
+const PROBLEM_FIELDS: &[&str] = &["type", "title", "status"];
+
+/// Shape of an error response body.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ErrorShape {
+    /// Component schema, e.g. `#/components/schemas/Error`
+    Named(String),
+    /// Inline Problem Details object
+    ProblemJson,
+    /// Any other inline schema, identified by its sorted property names
+    Inline(Vec<String>),
+    /// Error status documented without a body
+    NoBody,
+}
+
+impl ErrorShape {
+    pub fn label(&self) -> String {
+        match self {
+            ErrorShape::Named(name) => name.clone(),
+            ErrorShape::ProblemJson => "Problem+JSON (inline)".to_string(),
+            ErrorShape::Inline(fields) if fields.is_empty() => "inline (untyped)".to_string(),
+            ErrorShape::Inline(fields) => format!("inline {{{}}}", fields.join(", ")),
+            ErrorShape::NoBody => "no body".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorResponse {
+    pub endpoint: String,
+    /// `4xx`/`5xx` status or `default`
+    pub status: String,
+    pub shape: ErrorShape,
+    pub problem_json: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ErrorTaxonomy {
+    pub responses: Vec<ErrorResponse>,
+    /// Distinct shapes with their usage count, most used first
+    pub shapes: Vec<(ErrorShape, usize)>,
+    /// Most used shape among responses that have a body
+    pub canonical: Option<ErrorShape>,
+}
+
+impl ErrorTaxonomy {
+    /// Share of error responses using the canonical shape, in percent.
+    pub fn coverage(&self) -> f64 {
+        if self.responses.is_empty() {
+            return 100.0;
+        }
+        let canonical = self
+            .responses
+            .iter()
+            .filter(|r| Some(&r.shape) == self.canonical.as_ref())
+            .count();
+        canonical as f64 / self.responses.len() as f64 * 100.0
+    }
+
+    /// Endpoints with at least one error response that does not use the
+    /// canonical shape, sorted.
+    pub fn inconsistent_endpoints(&self) -> Vec<&str> {
+        let endpoints: BTreeSet<&str> = self
+            .responses
+            .iter()
+            .filter(|r| Some(&r.shape) != self.canonical.as_ref())
+            .map(|r| r.endpoint.as_str())
+            .collect();
+        endpoints.into_iter().collect()
+    }
+
+    pub fn problem_json_count(&self) -> usize {
+        self.responses.iter().filter(|r| r.problem_json).count()
+    }
+}
+
+fn is_error_status(status: &str) -> bool {
+    status == "default" || status.starts_with('4') || status.starts_with('5')
+}
+
+/// Classifies every 4xx/5xx/default response of the spec by body shape and
+/// elects the most common one as the canonical error schema.
+pub fn analyze_error_taxonomy(openapi_spec: &OpenApiSpec) -> ErrorTaxonomy {
+    let mut responses = Vec::new();
+
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            for (status, response) in &operation.responses {
+                if !is_error_status(status) {
+                    continue;
+                }
+
+                let media = response.content.as_ref().and_then(|content| {
+                    content
+                        .get(PROBLEM_JSON)
+                        .map(|m| (PROBLEM_JSON, m))
+                        .or_else(|| {
+                            content
+                                .get("application/json")
+                                .map(|m| ("application/json", m))
+                        })
+                        .or_else(|| content.iter().next().map(|(ct, m)| (ct.as_str(), m)))
+                });
+
+                let (shape, problem_json) = match media {
+                    Some((content_type, media)) => {
+                        let shape = media
+                            .schema
+                            .as_ref()
+                            .map(classify_schema)
+                            .unwrap_or(ErrorShape::Inline(Vec::new()));
+                        let problem_json =
+                            content_type == PROBLEM_JSON || shape == ErrorShape::ProblemJson;
+                        (shape, problem_json)
+                    }
+                    None => (ErrorShape::NoBody, false),
+                };
+
+                responses.push(ErrorResponse {
+                    endpoint: format!("{} {}", method.to_uppercase(), path),
+                    status: status.clone(),
+                    shape,
+                    problem_json,
+                });
+            }
+        }
+    }
+    responses.sort_by(|a, b| a.endpoint.cmp(&b.endpoint).then(a.status.cmp(&b.status)));
+
+    let mut counts: HashMap<ErrorShape, usize> = HashMap::new();
+    for response in &responses {
+        *counts.entry(response.shape.clone()).or_insert(0) += 1;
+    }
+    let mut shapes: Vec<(ErrorShape, usize)> = counts.into_iter().collect();
+    shapes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let canonical = shapes
+        .iter()
+        .find(|(shape, _)| *shape != ErrorShape::NoBody)
+        .map(|(shape, _)| shape.clone());
+
+    ErrorTaxonomy {
+        responses,
+        shapes,
+        canonical,
+    }
+}
+
+fn classify_schema(schema: &Schema) -> ErrorShape {
+    if let Some(name) = schema
+        .reference
+        .as_deref()
+        .and_then(extract_schema_name_from_ref)
+    {
+        return ErrorShape::Named(name.to_string());
+    }
+
+    let mut fields = schema.get_field_names();
+    if PROBLEM_FIELDS
+        .iter()
+        .all(|field| fields.iter().any(|f| f == field))
+    {
+        return ErrorShape::ProblemJson;
+    }
+    fields.sort_unstable();
+    ErrorShape::Inline(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn create_test_spec() -> OpenApiSpec {
+        parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/a": {"get": {"responses": {
+                        "200": {"description": "ok"},
+                        "404": {"description": "missing", "content": {"application/problem+json": {"schema": {"$ref": "#/components/schemas/Problem"}}}},
+                        "500": {"description": "boom", "content": {"application/problem+json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                    }}},
+                    "/b": {"post": {"responses": {
+                        "400": {"description": "bad", "content": {"application/json": {"schema": {"type": "object", "properties": {"message": {"type": "string"}}}}}},
+                        "default": {"description": "error", "content": {"application/problem+json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                    }}},
+                    "/c": {"delete": {"responses": {"401": {"description": "unauthorized"}}}}
+                },
+                "components": {"schemas": {
+                    "Problem": {"type": "object", "properties": {"type": {"type": "string"}, "title": {"type": "string"}, "status": {"type": "integer"}}}
+                }}
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_canonical_shape_and_coverage() {
+        let taxonomy = analyze_error_taxonomy(&create_test_spec());
+
+        assert_eq!(taxonomy.responses.len(), 5);
+        assert_eq!(
+            taxonomy.canonical,
+            Some(ErrorShape::Named("Problem".to_string()))
+        );
+        assert!((taxonomy.coverage() - 60.0).abs() < f64::EPSILON);
+        assert_eq!(taxonomy.problem_json_count(), 3);
+        assert_eq!(
+            taxonomy.inconsistent_endpoints(),
+            vec!["DELETE /c", "POST /b"]
+        );
+    }
+
+    #[test]
+    fn test_inline_problem_details_detected() {
+        let schema = Schema {
+            properties: Some(
+                ["type", "title", "status", "detail"]
+                    .iter()
+                    .map(|name| (name.to_string(), Schema::default()))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(classify_schema(&schema), ErrorShape::ProblemJson);
+        assert_eq!(
+            ErrorShape::Inline(vec!["code".to_string(), "message".to_string()]).label(),
+            "inline {code, message}"
+        );
+    }
+
+    #[test]
+    fn test_no_error_responses_is_fully_consistent() {
+        let spec = parse_openapi_content(
+            r#"{"openapi": "3.0.0", "info": {"title": "T", "version": "1"}, "paths": {}}"#,
+        )
+        .unwrap();
+        let taxonomy = analyze_error_taxonomy(&spec);
+        assert!(taxonomy.canonical.is_none());
+        assert_eq!(taxonomy.coverage(), 100.0);
+    }
+}
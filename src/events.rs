@@ -1,60 +1,740 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! The input pipeline: terminal input is mapped to an [`AppEvent`] by the
+//! keymap of the topmost open popup, then applied by [`App::handle_event`].
+//! Background tasks report through the same events.
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::app::{App, ScrollTarget, SpecLoad, View};
+use crate::codegen::Language;
+use crate::line_editor;
+use crate::notifications::Level;
+use crate::progress::LoadStage;
+use crate::runner::ExecutedResponse;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+/// File name of the Endpoints view parameter matrix export, written to the
+/// export directory
+pub const PARAMETER_MATRIX_FILE: &str = "parameter-matrix.csv";
+/// File name of the Stats view export, written to the export directory
+pub const STATS_FILE: &str = "openapi-stats.md";
+/// File name of the Graph view export, written to the export directory
+pub const GRAPH_FILE: &str = "openapi-graph.html";
+/// Default output directory for the Schemas view TypeScript export
+pub const TYPESCRIPT_DIR: &str = "generated/typescript";
+/// Default output directory for the Schemas view Rust export
+pub const RUST_DIR: &str = "generated/rust";
+/// Default output directory for the Schemas view JSON Schema export
+pub const JSON_SCHEMA_DIR: &str = "generated/json-schema";
+/// Default output directory for the Schemas view GraphQL SDL export
+pub const GRAPHQL_DIR: &str = "generated/graphql";
+/// Default output directory for the Schemas view protobuf export
+pub const PROTOBUF_DIR: &str = "generated/proto";
+
+#[derive(Debug)]
 pub enum AppEvent {
+    /// Terminal input read by the input task
+    Input(Event),
+    /// Nothing arrived within the tick rate
+    Tick,
+    /// Stage reached by a spec load running in the background
+    LoadProgress(LoadStage),
+    /// A background spec load finished
+    SpecLoaded(Box<SpecLoad>),
+    /// Spec files of a watched directory were created, edited or deleted
+    SpecFilesChanged(Vec<std::path::PathBuf>),
+    /// A try-it-out request finished
+    TryItOutResponse(Result<ExecutedResponse, String>),
+
+    // Shared by every popup
     Quit,
-    NextPanel,
-    PreviousPanel,
-    Search(String),
-    ClearSearch,
-    Select,
-    Back,
-    ChangeView(View),
-    Reload,
-    ToggleHelp,
+    /// Closes the topmost popup; in the main views, every report popup
+    Close,
+    Submit,
     NavigateUp,
     NavigateDown,
     NavigateLeft,
     NavigateRight,
-    ShowEndpointDetails,
-    HideEndpointDetails,
+    PageUp,
+    PageDown,
+    FocusNext,
+    FocusPrevious,
+    /// Completes the command palette input
+    Complete,
+    /// Sends the try-it-out request
+    Send,
+    Edit(EditAction),
+
+    // Main views
+    NextPanel,
+    GoBack,
+    GoForward,
+    StartSearch,
+    ToggleHelp,
+    ToggleTimeline,
+    ToggleSpecInfo,
+    ToggleWorkflows,
+    OpenDocumentation,
+    ToggleNotifications,
+    ChangeView(View),
+    Reload,
+    ToggleSortMode,
+    /// Fields table sorted by the next (1) or previous (-1) column
+    CycleFieldSort(isize),
+    ReverseFieldSort,
+    /// Fields table columns scrolled right (1) or left (-1)
+    ScrollFieldColumns(isize),
+    ToggleEndpointFieldsByField,
+    ToggleMark,
+    ClearMarks,
+    CopyNames,
+    CycleMethodFilter,
+    ToggleEndpointListing,
+    ToggleEndpointTree,
+    OpenImpactAnalysis,
+    OpenTreeReference,
+    CompareSchema,
+    OpenCrossSpecView,
+    OpenSensitiveReport,
+    OpenCoverageReport,
+    OpenDuplicateReport,
+    OpenPaginationReport,
+    OpenContentTypeReport,
+    OpenServerPicker,
+    OpenSavedViewPicker,
+    OpenLintPicker,
+    OpenSpecSwitcher,
+    OpenCommandPalette,
+    OpenFileBrowser,
+    /// Opens the URL prompt of the file browser
+    EnterUrl,
+    OpenDescriptionEditor,
+    OpenTryItOut,
+    ToggleBookmark,
+    SaveFilter,
+    ApplyNextSavedFilter,
+    ExportWorkspace,
+    ImportWorkspace,
+    ExportParameterMatrix,
+    ExportStats,
+    ExportGraph,
+    /// Exports the selected schema to the language's default directory
+    ExportModels(Language),
 }
 
+/// Edit of the focused text input.
 #[derive(Debug, Clone, PartialEq)]
-pub enum View {
-    Fields,
-    Schemas,
-    Endpoints,
-    Graph,
-    Stats,
+pub enum EditAction {
+    Insert(String),
+    DeleteBefore,
+    DeleteAt,
+    DeleteWordBefore,
+    Clear,
+    MoveLeft,
+    MoveRight,
+    MoveWordLeft,
+    MoveWordRight,
+    Home,
+    End,
+}
+
+impl EditAction {
+    /// Applies the edit to `text`, with `cursor` as a character index.
+    pub fn apply(&self, text: &mut String, cursor: &mut usize) {
+        match self {
+            EditAction::Insert(input) => line_editor::insert(text, cursor, input),
+            EditAction::DeleteBefore => line_editor::delete_before(text, cursor),
+            EditAction::DeleteAt => line_editor::delete_at(text, cursor),
+            EditAction::DeleteWordBefore => line_editor::delete_word_before(text, cursor),
+            EditAction::Clear => {
+                text.clear();
+                *cursor = 0;
+            }
+            EditAction::MoveLeft => line_editor::move_left(cursor),
+            EditAction::MoveRight => line_editor::move_right(text, cursor),
+            EditAction::MoveWordLeft => line_editor::move_word_left(text, cursor),
+            EditAction::MoveWordRight => line_editor::move_word_right(text, cursor),
+            EditAction::Home => *cursor = 0,
+            EditAction::End => *cursor = text.chars().count(),
+        }
+    }
+
+    /// Applies the edit to an input without a cursor, which only grows
+    /// and shrinks at its end.
+    pub fn apply_at_end(&self, text: &mut String) {
+        let mut cursor = text.chars().count();
+        match self {
+            EditAction::Insert(_)
+            | EditAction::DeleteBefore
+            | EditAction::DeleteWordBefore
+            | EditAction::Clear => self.apply(text, &mut cursor),
+            _ => {}
+        }
+    }
+}
+
+/// Which keymap applies, from the topmost open popup down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputMode {
+    FailedLoad,
+    TryItOut,
+    ServerPicker,
+    SpecSwitcher,
+    SavedViewPicker,
+    LintPicker,
+    CommandPalette,
+    DescriptionEditor,
+    FileBrowser,
+    SearchInput,
+    Normal,
+}
+
+impl InputMode {
+    pub fn of(app: &App) -> Self {
+        if app.failed_load.is_some() {
+            InputMode::FailedLoad
+        } else if app.try_it_out.is_some() {
+            InputMode::TryItOut
+        } else if app.server_picker.is_some() {
+            InputMode::ServerPicker
+        } else if app.spec_switcher.is_some() {
+            InputMode::SpecSwitcher
+        } else if app.saved_view_picker.is_some() {
+            InputMode::SavedViewPicker
+        } else if app.lint_picker.is_some() {
+            InputMode::LintPicker
+        } else if app.command_palette.is_some() {
+            InputMode::CommandPalette
+        } else if app.description_editor.is_some() {
+            InputMode::DescriptionEditor
+        } else if app.file_browser.is_some() {
+            InputMode::FileBrowser
+        } else if app.search_cursor.is_some() {
+            InputMode::SearchInput
+        } else {
+            InputMode::Normal
+        }
+    }
+}
+
+/// Event for terminal input, if it is bound in the current mode. Pastes
+/// go to the focused text input.
+pub fn handle_input(input: &Event, app: &App) -> Option<AppEvent> {
+    match input {
+        Event::Key(key) if key.kind == KeyEventKind::Press => handle_key_event(*key, app),
+        Event::Paste(text) => Some(AppEvent::Edit(EditAction::Insert(text.clone()))),
+        _ => None,
+    }
+}
+
+pub fn handle_key_event(key: KeyEvent, app: &App) -> Option<AppEvent> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    if ctrl && key.code == KeyCode::Char('c') {
+        return Some(AppEvent::Quit);
+    }
+
+    let mode = InputMode::of(app);
+    match mode {
+        InputMode::Normal => normal_key_event(key, app),
+        InputMode::FailedLoad => match key.code {
+            KeyCode::Esc | KeyCode::Enter => Some(AppEvent::Close),
+            KeyCode::Char('r') => Some(AppEvent::Reload),
+            KeyCode::Char('q') => Some(AppEvent::Quit),
+            _ => None,
+        },
+        InputMode::SearchInput | InputMode::DescriptionEditor => match key.code {
+            KeyCode::Esc => Some(AppEvent::Close),
+            KeyCode::Enter if mode == InputMode::SearchInput => Some(AppEvent::Close),
+            KeyCode::Enter => Some(AppEvent::Submit),
+            _ => text_edit(key).map(AppEvent::Edit),
+        },
+        InputMode::TryItOut => {
+            let body_focused = app.try_it_out.as_ref().is_some_and(|t| t.body_focused());
+            match key.code {
+                KeyCode::Esc => Some(AppEvent::Close),
+                KeyCode::Char('u') if ctrl => Some(AppEvent::Edit(EditAction::Clear)),
+                KeyCode::Enter if body_focused => {
+                    Some(AppEvent::Edit(EditAction::Insert("\n".to_string())))
+                }
+                KeyCode::Enter | KeyCode::F(5) => Some(AppEvent::Send),
+                KeyCode::Tab | KeyCode::Down => Some(AppEvent::FocusNext),
+                KeyCode::BackTab | KeyCode::Up => Some(AppEvent::FocusPrevious),
+                KeyCode::Backspace => Some(AppEvent::Edit(EditAction::DeleteBefore)),
+                KeyCode::Char(ch) if !ctrl => {
+                    Some(AppEvent::Edit(EditAction::Insert(ch.to_string())))
+                }
+                _ => None,
+            }
+        }
+        InputMode::ServerPicker | InputMode::CommandPalette => match key.code {
+            KeyCode::Esc => Some(AppEvent::Close),
+            KeyCode::Enter => Some(AppEvent::Submit),
+            KeyCode::Up => Some(AppEvent::NavigateUp),
+            KeyCode::Down => Some(AppEvent::NavigateDown),
+            KeyCode::Left => Some(AppEvent::NavigateLeft),
+            KeyCode::Right => Some(AppEvent::NavigateRight),
+            KeyCode::Tab if mode == InputMode::CommandPalette => Some(AppEvent::Complete),
+            KeyCode::Tab => Some(AppEvent::FocusNext),
+            KeyCode::Backspace => Some(AppEvent::Edit(EditAction::DeleteBefore)),
+            KeyCode::Char(ch) => Some(AppEvent::Edit(EditAction::Insert(ch.to_string()))),
+            _ => None,
+        },
+        InputMode::FileBrowser if app.file_browser.as_ref().is_some_and(|b| b.url.is_some()) => {
+            match key.code {
+                KeyCode::Esc => Some(AppEvent::Close),
+                KeyCode::Enter => Some(AppEvent::Submit),
+                _ => text_edit(key).map(AppEvent::Edit),
+            }
+        }
+        InputMode::SpecSwitcher
+        | InputMode::SavedViewPicker
+        | InputMode::LintPicker
+        | InputMode::FileBrowser => match key.code {
+            KeyCode::Esc => Some(AppEvent::Close),
+            KeyCode::Enter => Some(AppEvent::Submit),
+            KeyCode::Up => Some(AppEvent::NavigateUp),
+            KeyCode::Down => Some(AppEvent::NavigateDown),
+            KeyCode::Backspace if mode == InputMode::FileBrowser => Some(AppEvent::GoBack),
+            KeyCode::Char('u') if mode == InputMode::FileBrowser => Some(AppEvent::EnterUrl),
+            _ => None,
+        },
+    }
 }
 
-pub fn handle_key_event(key: KeyEvent) -> Option<AppEvent> {
+/// Line editing keys of the search bar, the description editor and the
+/// file browser's URL prompt.
+fn text_edit(key: KeyEvent) -> Option<EditAction> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
     match key.code {
-        KeyCode::Char('q') | KeyCode::Char('c')
-            if key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            Some(AppEvent::Quit)
-        }
-        KeyCode::Char('q') => Some(AppEvent::Quit),
-        KeyCode::Tab => Some(AppEvent::NextPanel),
-        KeyCode::BackTab => Some(AppEvent::PreviousPanel),
-        KeyCode::Char('/') => Some(AppEvent::ClearSearch),
-        KeyCode::Enter => Some(AppEvent::Select),
-        KeyCode::Esc => Some(AppEvent::Back),
-        KeyCode::Char('h') => Some(AppEvent::ToggleHelp),
-        KeyCode::Char('r') => Some(AppEvent::Reload),
-        KeyCode::Char('1') => Some(AppEvent::ChangeView(View::Fields)),
-        KeyCode::Char('2') => Some(AppEvent::ChangeView(View::Schemas)),
-        KeyCode::Char('3') => Some(AppEvent::ChangeView(View::Endpoints)),
-        KeyCode::Char('4') => Some(AppEvent::ChangeView(View::Graph)),
-        KeyCode::Char('5') => Some(AppEvent::ChangeView(View::Stats)),
-        KeyCode::Up => Some(AppEvent::NavigateUp),
-        KeyCode::Down => Some(AppEvent::NavigateDown),
-        KeyCode::Left => Some(AppEvent::NavigateLeft),
-        KeyCode::Right => Some(AppEvent::NavigateRight),
-        KeyCode::Char(ch) => Some(AppEvent::Search(ch.to_string())),
-        KeyCode::Backspace => Some(AppEvent::Search(String::new())), // Will be handled as backspace
+        KeyCode::Char('w') if ctrl => Some(EditAction::DeleteWordBefore),
+        KeyCode::Char('u') if ctrl => Some(EditAction::Clear),
+        KeyCode::Backspace if ctrl || alt => Some(EditAction::DeleteWordBefore),
+        KeyCode::Backspace => Some(EditAction::DeleteBefore),
+        KeyCode::Delete => Some(EditAction::DeleteAt),
+        KeyCode::Left if ctrl => Some(EditAction::MoveWordLeft),
+        KeyCode::Right if ctrl => Some(EditAction::MoveWordRight),
+        KeyCode::Left => Some(EditAction::MoveLeft),
+        KeyCode::Right => Some(EditAction::MoveRight),
+        KeyCode::Home => Some(EditAction::Home),
+        KeyCode::End => Some(EditAction::End),
+        KeyCode::Char(ch) if !ctrl => Some(EditAction::Insert(ch.to_string())),
         _ => None,
     }
 }
+
+fn normal_key_event(key: KeyEvent, app: &App) -> Option<AppEvent> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let view = &app.current_view;
+
+    let event = match key.code {
+        KeyCode::Char('q') => AppEvent::Quit,
+        KeyCode::Char('o') if ctrl => AppEvent::GoBack,
+        // Ctrl+I once keyboard enhancement tells it apart from Tab; some
+        // terminals report it as a Ctrl-modified Tab instead
+        KeyCode::Char('i') | KeyCode::Tab if ctrl => AppEvent::GoForward,
+        KeyCode::Char('s') if ctrl => AppEvent::SaveFilter,
+        KeyCode::Left if alt => AppEvent::GoBack,
+        KeyCode::Right if alt => AppEvent::GoForward,
+        KeyCode::Tab => AppEvent::NextPanel,
+        KeyCode::Backspace => AppEvent::GoBack,
+        KeyCode::Left => AppEvent::NavigateLeft,
+        KeyCode::Right => AppEvent::NavigateRight,
+        KeyCode::Up => AppEvent::NavigateUp,
+        KeyCode::Down => AppEvent::NavigateDown,
+        KeyCode::PageUp => AppEvent::PageUp,
+        KeyCode::PageDown => AppEvent::PageDown,
+        KeyCode::Enter if !app.show_help => AppEvent::Submit,
+        KeyCode::Esc => AppEvent::Close,
+        KeyCode::Char('/') => AppEvent::StartSearch,
+        KeyCode::Char('h') => AppEvent::ToggleHelp,
+        KeyCode::Char('1') => AppEvent::ChangeView(View::Fields),
+        KeyCode::Char('2') => AppEvent::ChangeView(View::Schemas),
+        KeyCode::Char('3') => AppEvent::ChangeView(View::Endpoints),
+        KeyCode::Char('4') => AppEvent::ChangeView(View::Graph),
+        KeyCode::Char('5') => AppEvent::ChangeView(View::Stats),
+        KeyCode::Char('6') => AppEvent::ChangeView(View::Headers),
+        KeyCode::Char('7') => AppEvent::ChangeView(View::Responses),
+        KeyCode::Char('r') => AppEvent::Reload,
+        KeyCode::Char('a') => AppEvent::OpenImpactAnalysis,
+        KeyCode::Char('m') if *view == View::Endpoints => AppEvent::CycleMethodFilter,
+        KeyCode::Char('k') if *view == View::Endpoints => AppEvent::ToggleEndpointListing,
+        KeyCode::Char('T') if *view == View::Endpoints => AppEvent::ToggleEndpointTree,
+        KeyCode::Char('v') if *view == View::Endpoints => AppEvent::ToggleEndpointFieldsByField,
+        KeyCode::Char('i') => AppEvent::ToggleSpecInfo,
+        KeyCode::Char('l') => AppEvent::ToggleWorkflows,
+        KeyCode::Char('O') => AppEvent::OpenDocumentation,
+        KeyCode::Char('e') if *view == View::Endpoints => AppEvent::ExportParameterMatrix,
+        KeyCode::Char('e') if *view == View::Schemas => {
+            AppEvent::ExportModels(Language::TypeScript)
+        }
+        KeyCode::Char('E') if *view == View::Schemas => AppEvent::ExportModels(Language::Rust),
+        KeyCode::Char('J') if *view == View::Schemas => {
+            AppEvent::ExportModels(Language::JsonSchema)
+        }
+        KeyCode::Char('G') if *view == View::Schemas => AppEvent::ExportModels(Language::GraphQl),
+        KeyCode::Char('X') if *view == View::Schemas => {
+            AppEvent::ExportModels(Language::Protobuf(Default::default()))
+        }
+        KeyCode::Char('g') if *view == View::Schemas => AppEvent::OpenTreeReference,
+        KeyCode::Char('e') if *view == View::Stats => AppEvent::ExportStats,
+        KeyCode::Char('e') if *view == View::Graph => AppEvent::ExportGraph,
+        KeyCode::Char('c') if *view == View::Fields => AppEvent::OpenCrossSpecView,
+        KeyCode::Char(' ') if matches!(view, View::Fields | View::Endpoints) => {
+            AppEvent::ToggleMark
+        }
+        KeyCode::Char('U') if matches!(view, View::Fields | View::Endpoints) => {
+            AppEvent::ClearMarks
+        }
+        KeyCode::Char('y') if matches!(view, View::Fields | View::Endpoints) => AppEvent::CopyNames,
+        KeyCode::Char('>') if *view == View::Fields => AppEvent::CycleFieldSort(1),
+        KeyCode::Char('<') if *view == View::Fields => AppEvent::CycleFieldSort(-1),
+        KeyCode::Char('R') if *view == View::Fields => AppEvent::ReverseFieldSort,
+        KeyCode::Char(']') if *view == View::Fields => AppEvent::ScrollFieldColumns(1),
+        KeyCode::Char('[') if *view == View::Fields => AppEvent::ScrollFieldColumns(-1),
+        KeyCode::Char('c') if *view == View::Schemas => AppEvent::CompareSchema,
+        KeyCode::Char('p') => AppEvent::OpenSensitiveReport,
+        KeyCode::Char('C') => AppEvent::OpenCoverageReport,
+        KeyCode::Char('D') => AppEvent::OpenDuplicateReport,
+        KeyCode::Char('P') => AppEvent::OpenPaginationReport,
+        KeyCode::Char('M') => AppEvent::OpenContentTypeReport,
+        KeyCode::Char('N') => AppEvent::ToggleSortMode,
+        KeyCode::Char('s') => AppEvent::OpenServerPicker,
+        KeyCode::Char('b') => AppEvent::ToggleBookmark,
+        KeyCode::Char('f') => AppEvent::ApplyNextSavedFilter,
+        KeyCode::Char('F') => AppEvent::OpenSavedViewPicker,
+        KeyCode::Char('L') => AppEvent::OpenLintPicker,
+        KeyCode::Char('W') => AppEvent::ExportWorkspace,
+        KeyCode::Char('I') => AppEvent::ImportWorkspace,
+        KeyCode::Char('S') => AppEvent::OpenSpecSwitcher,
+        KeyCode::Char(':') => AppEvent::OpenCommandPalette,
+        KeyCode::Char('o') => AppEvent::OpenFileBrowser,
+        KeyCode::Char('t') => AppEvent::ToggleTimeline,
+        KeyCode::Char('n') => AppEvent::ToggleNotifications,
+        KeyCode::Char('d') => AppEvent::OpenDescriptionEditor,
+        KeyCode::Char('x') if app.show_endpoint_details => AppEvent::OpenTryItOut,
+        _ => return None,
+    };
+    Some(event)
+}
+
+impl App {
+    /// Applies an event to the topmost open popup, or to the main views.
+    pub fn handle_event(&mut self, event: AppEvent) {
+        let mode = InputMode::of(self);
+        match event {
+            AppEvent::Input(input) => {
+                if let Some(event) = handle_input(&input, self) {
+                    self.handle_event(event);
+                }
+            }
+            AppEvent::Tick => {}
+            AppEvent::LoadProgress(stage) if self.is_loading => {
+                self.loading_message = format!("{} ({:.0}%)", stage, stage.ratio() * 100.0);
+            }
+            AppEvent::LoadProgress(_) => {}
+            AppEvent::SpecLoaded(load) => self.apply_spec_load(*load),
+            AppEvent::SpecFilesChanged(paths) => self.queue_spec_refresh(paths),
+            AppEvent::TryItOutResponse(response) => self.apply_try_it_out_response(response),
+            AppEvent::Quit => self.should_quit = true,
+            event if mode == InputMode::Normal => self.handle_normal_event(event),
+            event => self.handle_popup_event(mode, event),
+        }
+    }
+
+    fn handle_popup_event(&mut self, mode: InputMode, event: AppEvent) {
+        match (mode, event) {
+            (InputMode::FailedLoad, AppEvent::Close) => self.failed_load = None,
+            (InputMode::FailedLoad, AppEvent::Reload) => self.retry_failed_load(),
+            (InputMode::TryItOut, AppEvent::Close) => self.try_it_out = None,
+            (InputMode::ServerPicker, AppEvent::Close) => self.server_picker = None,
+            (InputMode::SpecSwitcher, AppEvent::Close) => self.spec_switcher = None,
+            (InputMode::SavedViewPicker, AppEvent::Close) => self.saved_view_picker = None,
+            (InputMode::LintPicker, AppEvent::Close) => self.lint_picker = None,
+            (InputMode::CommandPalette, AppEvent::Close) => self.command_palette = None,
+            (InputMode::DescriptionEditor, AppEvent::Close) => self.description_editor = None,
+            (InputMode::FileBrowser, AppEvent::Close) => {
+                // Esc leaves the URL prompt before closing the browser
+                match &mut self.file_browser {
+                    Some(browser) if browser.url.is_some() => browser.url = None,
+                    _ => self.file_browser = None,
+                }
+            }
+            (InputMode::SearchInput, AppEvent::Close) => self.stop_search_input(),
+
+            (InputMode::TryItOut, event) => {
+                let Some(try_it_out) = &mut self.try_it_out else {
+                    return;
+                };
+                match event {
+                    AppEvent::Send => {
+                        try_it_out.response = None;
+                        try_it_out.should_send = true;
+                    }
+                    AppEvent::FocusNext => try_it_out.focus_next(),
+                    AppEvent::FocusPrevious => try_it_out.focus_previous(),
+                    AppEvent::Edit(action) => action.apply_at_end(try_it_out.focused_value_mut()),
+                    _ => {}
+                }
+            }
+            (InputMode::ServerPicker, AppEvent::Submit) => self.apply_server_selection(),
+            (InputMode::ServerPicker, event) => {
+                let (Some(picker), Some(servers)) =
+                    (&mut self.server_picker, &self.openapi_spec.servers)
+                else {
+                    return;
+                };
+                match event {
+                    AppEvent::NavigateUp => picker.move_cursor(false),
+                    AppEvent::NavigateDown => picker.move_cursor(true),
+                    AppEvent::NavigateLeft => picker.cycle_value(servers, false),
+                    AppEvent::NavigateRight => picker.cycle_value(servers, true),
+                    AppEvent::FocusNext => picker.focus_next_variable(),
+                    AppEvent::Edit(action) => {
+                        if let Some(value) = picker.editable_value(servers) {
+                            action.apply_at_end(value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            (InputMode::SpecSwitcher, event) => {
+                let Some(cursor) = self.spec_switcher else {
+                    return;
+                };
+                match event {
+                    AppEvent::NavigateUp => self.spec_switcher = Some(cursor.saturating_sub(1)),
+                    AppEvent::NavigateDown => {
+                        self.spec_switcher = Some((cursor + 1).min(self.specs.len() - 1));
+                    }
+                    AppEvent::Submit => {
+                        self.spec_switcher = None;
+                        self.switch_spec(cursor);
+                    }
+                    _ => {}
+                }
+            }
+            (InputMode::SavedViewPicker, event) => {
+                let Some(cursor) = self.saved_view_picker else {
+                    return;
+                };
+                match event {
+                    AppEvent::NavigateUp => {
+                        self.saved_view_picker = Some(cursor.saturating_sub(1));
+                    }
+                    AppEvent::NavigateDown => {
+                        let last = self.workspace.saved_filters.len().saturating_sub(1);
+                        self.saved_view_picker = Some((cursor + 1).min(last));
+                    }
+                    AppEvent::Submit => {
+                        self.saved_view_picker = None;
+                        self.apply_saved_filter(cursor);
+                    }
+                    _ => {}
+                }
+            }
+            (InputMode::LintPicker, event) => {
+                let Some(cursor) = self.lint_picker else {
+                    return;
+                };
+                match event {
+                    AppEvent::NavigateUp => self.lint_picker = Some(cursor.saturating_sub(1)),
+                    AppEvent::NavigateDown => {
+                        let last = self.lint_findings.len().saturating_sub(1);
+                        self.lint_picker = Some((cursor + 1).min(last));
+                    }
+                    AppEvent::Submit => {
+                        self.lint_picker = None;
+                        self.jump_to_finding(cursor);
+                    }
+                    _ => {}
+                }
+            }
+            (InputMode::CommandPalette, event) => match event {
+                AppEvent::Submit => self.submit_command(),
+                AppEvent::Complete => self.complete_command(),
+                AppEvent::NavigateUp => self.recall_command(true),
+                AppEvent::NavigateDown => self.recall_command(false),
+                AppEvent::Edit(action) => {
+                    let Some(palette) = &mut self.command_palette else {
+                        return;
+                    };
+                    // Backspace on an empty palette closes it
+                    if action == EditAction::DeleteBefore && palette.input.is_empty() {
+                        self.command_palette = None;
+                        return;
+                    }
+                    action.apply_at_end(&mut palette.input);
+                    palette.error = None;
+                    palette.completions.clear();
+                }
+                _ => {}
+            },
+            (InputMode::DescriptionEditor, AppEvent::Submit) => self.save_description(),
+            (InputMode::DescriptionEditor, AppEvent::Edit(action)) => {
+                self.edit_description(|input, cursor| action.apply(input, cursor));
+            }
+            (InputMode::FileBrowser, event @ (AppEvent::NavigateUp | AppEvent::NavigateDown)) => {
+                if let Some(browser) = &mut self.file_browser {
+                    browser.move_cursor(matches!(event, AppEvent::NavigateDown));
+                }
+            }
+            (InputMode::FileBrowser, AppEvent::GoBack) => {
+                let Some(browser) = &mut self.file_browser else {
+                    return;
+                };
+                if let Err(e) = browser.parent() {
+                    self.notify(Level::Error, format!("Cannot list parent directory: {}", e));
+                }
+            }
+            (InputMode::FileBrowser, AppEvent::EnterUrl) => {
+                if let Some(browser) = &mut self.file_browser {
+                    browser.url = Some(String::new());
+                }
+            }
+            (InputMode::FileBrowser, AppEvent::Edit(action)) => {
+                if let Some(url) = self.file_browser.as_mut().and_then(|b| b.url.as_mut()) {
+                    action.apply_at_end(url);
+                }
+            }
+            (InputMode::FileBrowser, AppEvent::Submit) => self.select_browser_entry(),
+            (InputMode::SearchInput, AppEvent::Edit(action)) => {
+                self.edit_search(|query, cursor| action.apply(query, cursor));
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_normal_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::NextPanel => self.next_panel(),
+            AppEvent::GoBack => self.go_back(),
+            AppEvent::GoForward => self.go_forward(),
+            AppEvent::NavigateLeft => {
+                self.collapse_tree_node();
+                self.collapse_path_node();
+            }
+            AppEvent::NavigateRight => {
+                self.expand_tree_node();
+                self.expand_path_node();
+            }
+            AppEvent::NavigateUp
+            | AppEvent::NavigateDown
+            | AppEvent::PageUp
+            | AppEvent::PageDown
+                if self.scroll_target().is_some() =>
+            {
+                let Some(target) = self.scroll_target() else {
+                    return;
+                };
+                match event {
+                    AppEvent::NavigateUp => self.scroll_text(target, -1),
+                    AppEvent::NavigateDown => self.scroll_text(target, 1),
+                    AppEvent::PageUp => self.scroll_text_page(target, false),
+                    _ => self.scroll_text_page(target, true),
+                }
+            }
+            AppEvent::NavigateUp => self.navigate_up(),
+            AppEvent::NavigateDown => self.navigate_down(),
+            AppEvent::Submit => self.select_current_item(),
+            AppEvent::Close => self.close_popups(),
+            AppEvent::StartSearch => self.start_search_input(),
+            AppEvent::ToggleHelp => {
+                self.show_help = !self.show_help;
+                self.text_scroll.borrow_mut().remove(&ScrollTarget::Help);
+            }
+            AppEvent::ToggleTimeline => self.show_timeline = !self.show_timeline,
+            AppEvent::ToggleSpecInfo => self.toggle_spec_info(),
+            AppEvent::ToggleWorkflows => self.toggle_workflows(),
+            AppEvent::OpenDocumentation => self.open_documentation(),
+            AppEvent::ToggleNotifications => {
+                self.show_notifications = !self.show_notifications;
+                self.text_scroll
+                    .borrow_mut()
+                    .remove(&ScrollTarget::Notifications);
+            }
+            AppEvent::ChangeView(view) => self.set_view(view),
+            AppEvent::Reload => self.request_reload(),
+            AppEvent::ToggleSortMode => self.toggle_sort_mode(),
+            AppEvent::CycleFieldSort(step) => self.cycle_field_sort(step),
+            AppEvent::ReverseFieldSort => self.reverse_field_sort(),
+            AppEvent::ScrollFieldColumns(step) => self.scroll_field_columns(step),
+            AppEvent::ToggleEndpointFieldsByField => self.toggle_endpoint_fields_by_field(),
+            AppEvent::ToggleMark => self.toggle_mark(),
+            AppEvent::ClearMarks => self.clear_marks(),
+            AppEvent::CopyNames => self.copy_marked_names(),
+            AppEvent::CycleMethodFilter => self.cycle_method_filter(),
+            AppEvent::ToggleEndpointListing => self.toggle_endpoint_listing(),
+            AppEvent::ToggleEndpointTree => self.toggle_endpoint_tree(),
+            AppEvent::OpenImpactAnalysis => self.open_impact_analysis(),
+            AppEvent::OpenTreeReference => self.open_tree_reference(),
+            AppEvent::CompareSchema => self.compare_schema(),
+            AppEvent::OpenCrossSpecView => self.open_cross_spec_view(),
+            AppEvent::OpenSensitiveReport => self.open_sensitive_report(),
+            AppEvent::OpenCoverageReport => self.open_coverage_report(),
+            AppEvent::OpenDuplicateReport => self.open_duplicate_report(),
+            AppEvent::OpenPaginationReport => self.open_pagination_report(),
+            AppEvent::OpenContentTypeReport => self.open_content_type_report(),
+            AppEvent::OpenServerPicker => self.open_server_picker(),
+            AppEvent::OpenSavedViewPicker => self.open_saved_view_picker(),
+            AppEvent::OpenLintPicker => self.open_lint_picker(),
+            AppEvent::OpenSpecSwitcher => self.open_spec_switcher(),
+            AppEvent::OpenCommandPalette => self.open_command_palette(),
+            AppEvent::OpenFileBrowser => self.open_file_browser(),
+            AppEvent::OpenDescriptionEditor => self.open_description_editor(),
+            AppEvent::OpenTryItOut => self.open_try_it_out(),
+            AppEvent::ToggleBookmark => self.toggle_bookmark(),
+            AppEvent::SaveFilter => self.save_current_filter(),
+            AppEvent::ApplyNextSavedFilter => self.apply_next_saved_filter(),
+            AppEvent::ExportWorkspace => self.export_workspace(),
+            AppEvent::ImportWorkspace => self.import_workspace(),
+            AppEvent::ExportParameterMatrix => {
+                let path = self.export_dirs.files.join(PARAMETER_MATRIX_FILE);
+                self.export_parameter_matrix(&path);
+            }
+            AppEvent::ExportStats => {
+                let path = self.export_dirs.files.join(STATS_FILE);
+                self.export_stats(&path);
+            }
+            AppEvent::ExportGraph => {
+                let path = self.export_dirs.files.join(GRAPH_FILE);
+                self.export_graph(&path);
+            }
+            AppEvent::ExportModels(language) => {
+                let dir = match language {
+                    Language::TypeScript => self.export_dirs.typescript.clone(),
+                    Language::Rust => self.export_dirs.rust.clone(),
+                    Language::JsonSchema => self.export_dirs.json_schema.clone(),
+                    Language::GraphQl => self.export_dirs.graphql.clone(),
+                    Language::Protobuf(_) => self.export_dirs.protobuf.clone(),
+                };
+                if let Some(schema) = self.current_schema() {
+                    self.export_models(language, &[schema], &dir);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Closes every report popup and dismisses the current toast.
+    fn close_popups(&mut self) {
+        self.show_help = false;
+        self.show_endpoint_details = false;
+        self.selected_endpoint_for_details = None;
+        self.impact_report = None;
+        self.sensitive_report = None;
+        self.coverage_report = None;
+        self.duplicate_report = None;
+        self.pagination_report = None;
+        self.content_type_report = None;
+        self.stats_drilldown = None;
+        self.payload_validation = None;
+        self.rename_plan = None;
+        self.schema_compare_base = None;
+        self.schema_comparison = None;
+        self.show_timeline = false;
+        self.cross_spec_field = None;
+        self.show_notifications = false;
+        self.show_spec_info = false;
+        self.show_workflows = false;
+        self.notifications.dismiss();
+    }
+}
@@ -0,0 +1,197 @@
+use crate::parser::{extract_schema_name_from_ref, Schema};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Nesting depth after which objects and arrays are left empty
+const MAX_EXAMPLE_DEPTH: usize = 8;
+
+/// Builds an example JSON value for `schema`, resolving component references
+/// from `schemas`. Explicit `example`, `default` and `enum` values win over
+/// generated ones.
+pub fn generate_example(schema: &Schema, schemas: &HashMap<String, Schema>) -> Value {
+    generate(schema, schemas, &mut HashSet::new(), 0)
+}
+
+fn generate(
+    schema: &Schema,
+    schemas: &HashMap<String, Schema>,
+    visiting: &mut HashSet<String>,
+    depth: usize,
+) -> Value {
+    if let Some(example) = &schema.example {
+        return example.clone();
+    }
+    if let Some(default) = &schema.default {
+        return default.clone();
+    }
+    if let Some(first) = schema.enum_.as_ref().and_then(|values| values.first()) {
+        return first.clone();
+    }
+
+    if let Some(target) = schema
+        .reference
+        .as_deref()
+        .and_then(extract_schema_name_from_ref)
+    {
+        // Recursive structures stop at the first repetition
+        if !visiting.insert(target.to_string()) {
+            return Value::Null;
+        }
+        let value = schemas
+            .get(target)
+            .map(|resolved| generate(resolved, schemas, visiting, depth))
+            .unwrap_or(Value::Null);
+        visiting.remove(target);
+        return value;
+    }
+
+    if let Some(all_of) = &schema.all_of {
+        let mut merged = Map::new();
+        for sub_schema in all_of {
+            if let Value::Object(part) = generate(sub_schema, schemas, visiting, depth) {
+                merged.extend(part);
+            }
+        }
+        if let Value::Object(own) = generate_object(schema, schemas, visiting, depth) {
+            merged.extend(own);
+        }
+        return Value::Object(merged);
+    }
+
+    // A single variant is enough to produce a valid instance
+    for branches in [&schema.one_of, &schema.any_of].into_iter().flatten() {
+        if let Some(first) = branches.first() {
+            return generate(first, schemas, visiting, depth);
+        }
+    }
+
+    if schema.properties.is_some() || schema.schema_type.as_deref() == Some("object") {
+        return generate_object(schema, schemas, visiting, depth);
+    }
+
+    if let Some(items) = &schema.items {
+        if depth >= MAX_EXAMPLE_DEPTH {
+            return json!([]);
+        }
+        return json!([generate(items, schemas, visiting, depth + 1)]);
+    }
+
+    scalar_example(schema)
+}
+
+fn generate_object(
+    schema: &Schema,
+    schemas: &HashMap<String, Schema>,
+    visiting: &mut HashSet<String>,
+    depth: usize,
+) -> Value {
+    let mut object = Map::new();
+    if depth >= MAX_EXAMPLE_DEPTH {
+        return Value::Object(object);
+    }
+
+    if let Some(properties) = &schema.properties {
+        for (name, property) in properties {
+            if property.write_only == Some(true) {
+                continue;
+            }
+            object.insert(
+                name.clone(),
+                generate(property, schemas, visiting, depth + 1),
+            );
+        }
+    }
+    Value::Object(object)
+}
+
+fn scalar_example(schema: &Schema) -> Value {
+    match (schema.schema_type.as_deref(), schema.format.as_deref()) {
+        (Some("string"), Some("date-time")) => json!("2024-01-01T00:00:00Z"),
+        (Some("string"), Some("date")) => json!("2024-01-01"),
+        (Some("string"), Some("uuid")) => json!("3fa85f64-5717-4562-b3fc-2c963f66afa6"),
+        (Some("string"), Some("email")) => json!("user@example.com"),
+        (Some("string"), Some("uri")) | (Some("string"), Some("url")) => {
+            json!("https://example.com")
+        }
+        (Some("string"), _) => json!("string"),
+        (Some("integer"), _) => json!(0),
+        (Some("number"), _) => json!(0.0),
+        (Some("boolean"), _) => json!(true),
+        (Some("array"), _) => json!([]),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(schema_type: &str) -> Schema {
+        Schema {
+            schema_type: Some(schema_type.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn reference(name: &str) -> Schema {
+        Schema {
+            reference: Some(format!("#/components/schemas/{}", name)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_object_with_reference() {
+        let schemas = HashMap::from([(
+            "Pet".to_string(),
+            Schema {
+                schema_type: Some("object".to_string()),
+                properties: Some(HashMap::from([
+                    ("id".to_string(), typed("integer")),
+                    (
+                        "status".to_string(),
+                        Schema {
+                            enum_: Some(vec![json!("available"), json!("sold")]),
+                            ..typed("string")
+                        },
+                    ),
+                ])),
+                ..Default::default()
+            },
+        )]);
+        let list = Schema {
+            items: Some(Box::new(reference("Pet"))),
+            ..typed("array")
+        };
+
+        assert_eq!(
+            generate_example(&list, &schemas),
+            json!([{"id": 0, "status": "available"}])
+        );
+    }
+
+    #[test]
+    fn test_explicit_example_wins() {
+        let schema = Schema {
+            example: Some(json!("Rex")),
+            ..typed("string")
+        };
+        assert_eq!(generate_example(&schema, &HashMap::new()), json!("Rex"));
+    }
+
+    #[test]
+    fn test_recursive_schema_terminates() {
+        let schemas = HashMap::from([(
+            "Node".to_string(),
+            Schema {
+                schema_type: Some("object".to_string()),
+                properties: Some(HashMap::from([("parent".to_string(), reference("Node"))])),
+                ..Default::default()
+            },
+        )]);
+        assert_eq!(
+            generate_example(&reference("Node"), &schemas),
+            json!({"parent": null})
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::content_types::{analyze_content_types, ContentTypeReport};
+use crate::coverage::{analyze_coverage, CoverageReport};
+use crate::deprecation::{collect_deprecations, Deprecation};
+use crate::duplicates::{find_duplicate_schemas, DuplicatePair};
+use crate::error_taxonomy::{analyze_error_taxonomy, ErrorTaxonomy};
+use crate::impact::{analyze_impact, ImpactReport, ImpactTarget};
+use crate::indexer::{build_field_index, FieldData, FieldIndex, DEFAULT_FIELD_DEPTH};
+use crate::lint::{lint_findings, lint_path_parameters, Diagnostic, LintConfig, LintFinding};
+use crate::pagination::{analyze_pagination, PaginationReport};
+use crate::parser::{OpenApiSpec, Schema};
+use crate::responses::{build_response_matrix, ResponseMatrix};
+use crate::sensitive::{find_response_exposures, SensitiveDetector, SensitiveExposure};
+use anyhow::Result;
+use std::path::Path;
+
+/// Analysis engine without the TUI: a parsed spec and its field index,
+/// with the queries the explorer views are built on.
+///
+/// ```no_run
+/// # async fn run() -> anyhow::Result<()> {
+/// let explorer = openapi_explorer::Explorer::load("api.json").await?;
+/// for endpoint in explorer.endpoints_for_field("user_id") {
+///     println!("{}", endpoint);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Explorer {
+    spec: OpenApiSpec,
+    index: FieldIndex,
+}
+
+impl Explorer {
+    /// Parses and indexes a spec file.
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let (spec, index) =
+            crate::cache::load_indexed(path.as_ref(), None, DEFAULT_FIELD_DEPTH, false).await?;
+        Ok(Self { spec, index })
+    }
+
+    pub fn from_spec(spec: OpenApiSpec) -> Self {
+        let index = build_field_index(&spec);
+        Self { spec, index }
+    }
+
+    /// Wraps an already parsed and indexed spec.
+    pub fn from_parts(spec: OpenApiSpec, index: FieldIndex) -> Self {
+        Self { spec, index }
+    }
+
+    pub fn spec(&self) -> &OpenApiSpec {
+        &self.spec
+    }
+
+    pub fn index(&self) -> &FieldIndex {
+        &self.index
+    }
+
+    /// Field names, sorted.
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields: Vec<&str> = self.index.fields.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldData> {
+        self.index.fields.get(name)
+    }
+
+    /// Component schema names, sorted.
+    pub fn schemas(&self) -> Vec<&str> {
+        let mut schemas: Vec<&str> = self.index.schemas.keys().map(String::as_str).collect();
+        schemas.sort_unstable();
+        schemas
+    }
+
+    pub fn schema(&self, name: &str) -> Option<&Schema> {
+        self.index.schemas.get(name)
+    }
+
+    /// Every operation as a `"METHOD /path"` key, sorted.
+    pub fn endpoints(&self) -> Vec<String> {
+        let mut endpoints: Vec<String> = self.index.endpoint_fields.keys().cloned().collect();
+        endpoints.sort_unstable();
+        endpoints
+    }
+
+    /// Endpoints using `field_name` in a parameter, request or response, sorted.
+    pub fn endpoints_for_field(&self, field_name: &str) -> Vec<String> {
+        let mut endpoints = self.index.get_endpoints_for_field(field_name);
+        endpoints.sort_unstable();
+        endpoints
+    }
+
+    /// Fields used by an endpoint (`"METHOD /path"`), each named once.
+    pub fn fields_for_endpoint(&self, endpoint: &str) -> Vec<&str> {
+        self.index.endpoint_field_names(endpoint)
+    }
+
+    /// Endpoints affected by a change to a schema.
+    pub fn impact_of(&self, schema_name: &str) -> ImpactReport {
+        analyze_impact(
+            &self.spec,
+            &self.index,
+            &ImpactTarget::Schema(schema_name.to_string()),
+        )
+    }
+
+    /// Endpoints affected by a change to a field.
+    pub fn impact_of_field(&self, field_name: &str) -> ImpactReport {
+        analyze_impact(
+            &self.spec,
+            &self.index,
+            &ImpactTarget::Field(field_name.to_string()),
+        )
+    }
+
+    /// Validation warnings, as shown in the Stats view, suppressed ones
+    /// included.
+    pub fn lint(&self, config: &LintConfig) -> Vec<Diagnostic> {
+        crate::lint::lint_spec(&self.spec, &self.index, config)
+    }
+
+    /// Path templates against their path parameters.
+    pub fn path_parameter_findings(&self) -> Vec<LintFinding> {
+        lint_path_parameters(&self.spec)
+    }
+
+    /// Findings tied to an operation or path: path parameters and
+    /// operationIds.
+    pub fn lint_findings(&self, config: &LintConfig) -> Vec<LintFinding> {
+        lint_findings(&self.spec, config)
+    }
+
+    pub fn sensitive_exposures(&self, detector: &SensitiveDetector) -> Vec<SensitiveExposure> {
+        find_response_exposures(&self.spec, &self.index, detector)
+    }
+
+    pub fn deprecations(&self) -> Vec<Deprecation> {
+        collect_deprecations(&self.spec)
+    }
+
+    pub fn error_taxonomy(&self) -> ErrorTaxonomy {
+        analyze_error_taxonomy(&self.spec)
+    }
+
+    /// Unreferenced schemas, orphaned fields, empty responses and
+    /// schemaless endpoints.
+    pub fn coverage(&self) -> CoverageReport {
+        analyze_coverage(&self.spec, &self.index)
+    }
+
+    /// Exact and near-duplicate component schemas, most similar first.
+    pub fn duplicate_schemas(&self) -> Vec<DuplicatePair> {
+        find_duplicate_schemas(&self.spec)
+    }
+
+    /// Endpoints grouped by pagination style, and collections returned
+    /// without pagination.
+    pub fn pagination(&self) -> PaginationReport {
+        analyze_pagination(&self.spec)
+    }
+
+    /// Endpoints by request and response media type, with those mixing
+    /// media types or offering no JSON.
+    pub fn content_types(&self) -> ContentTypeReport {
+        analyze_content_types(&self.index)
+    }
+
+    /// Every path operation × its documented status codes, with the
+    /// missing error contracts.
+    pub fn response_matrix(&self) -> ResponseMatrix {
+        build_response_matrix(&self.index, &self.endpoints())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+    use crate::responses::ResponseGap;
+
+    #[test]
+    fn test_explorer_queries() {
+        let explorer = Explorer::from_spec(
+            parse_openapi_content(
+                r##"{
+                    "openapi": "3.0.0",
+                    "info": {"title": "T", "version": "1"},
+                    "paths": {
+                        "/users": {"get": {"summary": "List", "responses": {"200": {"description": "ok", "content": {
+                            "application/json": {"schema": {"type": "array", "items": {
+                                "type": "object", "properties": {"email": {"type": "string"}}
+                            }}}
+                        }}}}},
+                        "/orders": {"post": {"summary": "Create", "requestBody": {"content": {
+                            "application/json": {"schema": {"$ref": "#/components/schemas/Order"}}
+                        }}, "responses": {}}}
+                    },
+                    "components": {"schemas": {
+                        "User": {"type": "object", "properties": {"id": {"type": "integer"}, "email": {"type": "string"}}},
+                        "Order": {"type": "object", "properties": {"id": {"type": "integer"}, "buyer": {"$ref": "#/components/schemas/User"}}}
+                    }}
+                }"##,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(explorer.schemas(), vec!["Order", "User"]);
+        assert_eq!(explorer.endpoints(), vec!["GET /users", "POST /orders"]);
+        assert_eq!(explorer.fields(), vec!["buyer", "email", "id"]);
+        assert_eq!(explorer.field("email").unwrap().field_type, "string");
+        assert_eq!(explorer.endpoints_for_field("email"), vec!["GET /users"]);
+        assert!(explorer.fields_for_endpoint("GET /nope").is_empty());
+
+        let impact = explorer.impact_of("User");
+        assert!(impact
+            .endpoints
+            .iter()
+            .any(|e| e.endpoint == "POST /orders"));
+        let messages: Vec<String> = explorer
+            .lint(&LintConfig::default())
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Field 'buyer' has unknown type",
+                "1 schema(s) not used in any endpoint",
+                "GET /users: no operationId",
+                "POST /orders: no operationId"
+            ]
+        );
+        assert!(explorer.path_parameter_findings().is_empty());
+        assert_eq!(explorer.lint_findings(&LintConfig::default()).len(), 2);
+        // Order is sent by POST /orders and references User
+        let coverage = explorer.coverage();
+        assert!(coverage.unreferenced_schemas.is_empty());
+        assert!(coverage.orphaned_fields.is_empty());
+        // User and Order only share `id`
+        assert!(explorer.duplicate_schemas().is_empty());
+        assert_eq!(
+            explorer.pagination().unpaginated_collections,
+            vec!["GET /users"]
+        );
+        // POST /orders documents no response at all
+        let matrix = explorer.response_matrix();
+        assert_eq!(matrix.statuses, vec!["200"]);
+        assert_eq!(
+            matrix.rows[1].gaps,
+            vec![ResponseGap::NoSuccess, ResponseGap::NoClientError]
+        );
+        assert_eq!(
+            explorer.content_types().by_type["application/json"].responses,
+            vec!["GET /users"]
+        );
+    }
+}
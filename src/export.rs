@@ -0,0 +1,279 @@
+use crate::parser::{OpenApiSpec, Operation};
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterRequirement {
+    Required,
+    Optional,
+    Absent,
+}
+
+impl ParameterRequirement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterRequirement::Required => "required",
+            ParameterRequirement::Optional => "optional",
+            ParameterRequirement::Absent => "",
+        }
+    }
+}
+
+/// A parameter identified by its name and location (`query`, `path`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParameterKey {
+    pub location: String,
+    pub name: String,
+}
+
+/// Endpoints × parameters matrix, one row per endpoint.
+#[derive(Debug, Default)]
+pub struct ParameterMatrix {
+    pub parameters: Vec<ParameterKey>,
+    pub rows: Vec<(String, Vec<ParameterRequirement>)>,
+}
+
+/// Looks up the operation for an endpoint key (`"METHOD /path"`).
+pub fn find_operation<'a>(spec: &'a OpenApiSpec, endpoint: &str) -> Option<&'a Operation> {
+    let (method, path) = endpoint.split_once(' ')?;
+    spec.path_item(path)?.operations.get(&method.to_lowercase())
+}
+
+/// Catalog of every distinct parameter used by the given endpoints, sorted by
+/// location then name.
+pub fn parameter_catalog(spec: &OpenApiSpec, endpoints: &[String]) -> Vec<ParameterKey> {
+    let mut catalog = BTreeSet::new();
+    for endpoint in endpoints {
+        if let Some(parameters) =
+            find_operation(spec, endpoint).and_then(|op| op.parameters.as_ref())
+        {
+            for param in parameters {
+                catalog.insert(ParameterKey {
+                    location: param.in_.clone(),
+                    name: param.name.clone(),
+                });
+            }
+        }
+    }
+    catalog.into_iter().collect()
+}
+
+pub fn build_parameter_matrix(spec: &OpenApiSpec, endpoints: &[String]) -> ParameterMatrix {
+    let parameters = parameter_catalog(spec, endpoints);
+
+    let rows = endpoints
+        .iter()
+        .map(|endpoint| {
+            let operation_params = find_operation(spec, endpoint)
+                .and_then(|op| op.parameters.as_ref())
+                .map(|params| params.as_slice())
+                .unwrap_or_default();
+
+            let cells = parameters
+                .iter()
+                .map(|key| {
+                    match operation_params
+                        .iter()
+                        .find(|p| p.name == key.name && p.in_ == key.location)
+                    {
+                        // Path parameters are always required by the spec
+                        Some(p) if p.required.unwrap_or(p.in_ == "path") => {
+                            ParameterRequirement::Required
+                        }
+                        Some(_) => ParameterRequirement::Optional,
+                        None => ParameterRequirement::Absent,
+                    }
+                })
+                .collect();
+
+            (endpoint.clone(), cells)
+        })
+        .collect();
+
+    ParameterMatrix { parameters, rows }
+}
+
+impl ParameterMatrix {
+    pub fn to_csv(&self) -> String {
+        let mut header = vec!["endpoint".to_string()];
+        header.extend(
+            self.parameters
+                .iter()
+                .map(|key| format!("{} ({})", key.name, key.location)),
+        );
+
+        let mut out = csv_line(&header);
+        for (endpoint, cells) in &self.rows {
+            let mut record = vec![endpoint.clone()];
+            record.extend(cells.iter().map(|cell| cell.as_str().to_string()));
+            out.push_str(&csv_line(&record));
+        }
+        out
+    }
+}
+
+/// Writes the parameter matrix for `endpoints` as CSV and returns the number
+/// of rows written.
+pub fn write_parameter_matrix_csv(
+    spec: &OpenApiSpec,
+    endpoints: &[String],
+    path: &Path,
+) -> Result<usize> {
+    let matrix = build_parameter_matrix(spec, endpoints);
+    std::fs::write(path, matrix.to_csv())?;
+    Ok(matrix.rows.len())
+}
+
+pub fn csv_line(record: &[String]) -> String {
+    let mut line = record
+        .iter()
+        .map(|value| csv_escape(value))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Info, Parameter, PathItem};
+    use std::collections::HashMap;
+
+    fn param(name: &str, location: &str, required: Option<bool>) -> Parameter {
+        Parameter {
+            name: name.to_string(),
+            in_: location.to_string(),
+            description: None,
+            required,
+            schema: None,
+        }
+    }
+
+    fn operation(parameters: Vec<Parameter>) -> Operation {
+        Operation {
+            operation_id: None,
+            summary: None,
+            description: None,
+            tags: None,
+            parameters: Some(parameters),
+            request_body: None,
+            responses: HashMap::new(),
+            deprecated: None,
+            external_docs: None,
+            callbacks: None,
+            extensions: HashMap::new(),
+        }
+    }
+
+    fn create_test_spec() -> OpenApiSpec {
+        OpenApiSpec {
+            openapi: "3.0.0".to_string(),
+            webhooks: None,
+            info: Info {
+                title: "Test".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                terms_of_service: None,
+                contact: None,
+                license: None,
+            },
+            servers: None,
+            paths: HashMap::from([
+                (
+                    "/users".to_string(),
+                    PathItem {
+                        operations: HashMap::from([(
+                            "get".to_string(),
+                            operation(vec![param("limit", "query", None)]),
+                        )]),
+                    },
+                ),
+                (
+                    "/users/{id}".to_string(),
+                    PathItem {
+                        operations: HashMap::from([(
+                            "get".to_string(),
+                            operation(vec![
+                                param("id", "path", None),
+                                param("X-Trace, Id", "header", Some(true)),
+                            ]),
+                        )]),
+                    },
+                ),
+            ]),
+            tags: None,
+            external_docs: None,
+            parse_warnings: Vec::new(),
+            referenced_components: Default::default(),
+            unresolved_refs: Vec::new(),
+            callback_targets: Default::default(),
+            explorer_ignore: None,
+            components: None,
+        }
+    }
+
+    #[test]
+    fn test_parameter_catalog_is_sorted_and_unique() {
+        let spec = create_test_spec();
+        let endpoints = vec!["GET /users".to_string(), "GET /users/{id}".to_string()];
+
+        let catalog = parameter_catalog(&spec, &endpoints);
+        let names: Vec<&str> = catalog.iter().map(|k| k.name.as_str()).collect();
+        assert_eq!(names, vec!["X-Trace, Id", "id", "limit"]);
+    }
+
+    #[test]
+    fn test_build_parameter_matrix() {
+        let spec = create_test_spec();
+        let endpoints = vec!["GET /users".to_string(), "GET /users/{id}".to_string()];
+
+        let matrix = build_parameter_matrix(&spec, &endpoints);
+        assert_eq!(
+            matrix.rows[0].1,
+            vec![
+                ParameterRequirement::Absent,
+                ParameterRequirement::Absent,
+                ParameterRequirement::Optional
+            ]
+        );
+        assert_eq!(
+            matrix.rows[1].1,
+            vec![
+                ParameterRequirement::Required,
+                ParameterRequirement::Required,
+                ParameterRequirement::Absent
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matrix_only_covers_given_endpoints() {
+        let spec = create_test_spec();
+        let matrix = build_parameter_matrix(&spec, &["GET /users".to_string()]);
+        assert_eq!(matrix.parameters.len(), 1);
+        assert_eq!(matrix.rows.len(), 1);
+    }
+
+    #[test]
+    fn test_to_csv_escapes_values() {
+        let spec = create_test_spec();
+        let matrix = build_parameter_matrix(&spec, &["GET /users/{id}".to_string()]);
+        let csv = matrix.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("endpoint,\"X-Trace, Id (header)\",id (path)")
+        );
+        assert_eq!(lines.next(), Some("GET /users/{id},required,required"));
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Extensions listed by the browser; other files are hidden
+const SPEC_EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+/// State of the file browser popup used to open another spec.
+#[derive(Debug)]
+pub struct FileBrowser {
+    pub dir: PathBuf,
+    pub entries: Vec<BrowserEntry>,
+    pub cursor: usize,
+    /// URL typed in place of picking a file, while the prompt is open
+    pub url: Option<String>,
+}
+
+impl FileBrowser {
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        let dir = dir.canonicalize().unwrap_or(dir);
+        let entries = list_entries(&dir)?;
+        Ok(Self {
+            dir,
+            entries,
+            cursor: 0,
+            url: None,
+        })
+    }
+
+    pub fn selected(&self) -> Option<&BrowserEntry> {
+        self.entries.get(self.cursor)
+    }
+
+    pub fn move_cursor(&mut self, down: bool) {
+        if down {
+            self.cursor = (self.cursor + 1).min(self.entries.len().saturating_sub(1));
+        } else {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+    }
+
+    /// Lists `dir` in place of the current directory, keeping the current
+    /// listing when it cannot be read.
+    pub fn enter(&mut self, dir: &Path) -> Result<()> {
+        *self = Self::open(dir)?;
+        Ok(())
+    }
+
+    pub fn parent(&mut self) -> Result<()> {
+        match self.dir.parent().map(Path::to_path_buf) {
+            Some(parent) => self.enter(&parent),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `..`, then sub-directories, then spec files, each group sorted by name.
+/// Hidden entries are skipped.
+pub fn list_entries(dir: &Path) -> Result<Vec<BrowserEntry>> {
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            dirs.push(BrowserEntry {
+                name,
+                path,
+                is_dir: true,
+            });
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SPEC_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+        {
+            files.push(BrowserEntry {
+                name,
+                path,
+                is_dir: false,
+            });
+        }
+    }
+    dirs.sort_by(|a, b| a.name.cmp(&b.name));
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut entries = Vec::with_capacity(dirs.len() + files.len() + 1);
+    if let Some(parent) = dir.parent() {
+        entries.push(BrowserEntry {
+            name: "..".to_string(),
+            path: parent.to_path_buf(),
+            is_dir: true,
+        });
+    }
+    entries.extend(dirs);
+    entries.extend(files);
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_entries_shows_dirs_then_specs() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("specs")).unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        for file in ["orders.yaml", "api.json", "notes.txt", "Users.JSON"] {
+            std::fs::write(dir.path().join(file), "{}").unwrap();
+        }
+
+        let names: Vec<String> = list_entries(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["..", "specs", "Users.JSON", "api.json", "orders.yaml"]
+        );
+    }
+
+    #[test]
+    fn test_browser_navigation() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested").join("api.json"), "{}").unwrap();
+
+        let mut browser = FileBrowser::open(dir.path()).unwrap();
+        browser.move_cursor(true);
+        assert_eq!(browser.selected().unwrap().name, "nested");
+
+        let nested = browser.selected().unwrap().path.clone();
+        browser.enter(&nested).unwrap();
+        assert_eq!(browser.entries[1].name, "api.json");
+        assert_eq!(browser.cursor, 0);
+
+        browser.parent().unwrap();
+        assert!(browser.entries.iter().any(|entry| entry.name == "nested"));
+    }
+}
@@ -0,0 +1,398 @@
+//! The field / schema / endpoint graph of a spec, written as Graphviz DOT
+//! or as a self-contained HTML page drawing it with a small force layout.
+
+use crate::indexer::FieldIndex;
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Endpoint,
+    Schema,
+    Field,
+}
+
+impl NodeKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            NodeKind::Endpoint => "endpoint",
+            NodeKind::Schema => "schema",
+            NodeKind::Field => "field",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeKind {
+    /// Endpoint to a field of its parameters, request or responses
+    Uses,
+    /// Schema to one of its properties
+    Declares,
+    /// Schema to a schema it `$ref`s
+    References,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    /// `kind:label`, unique across kinds
+    pub id: String,
+    pub kind: NodeKind,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    /// Positions in [`ApiGraph::nodes`]
+    pub source: usize,
+    pub target: usize,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ApiGraph {
+    pub nodes: Vec<GraphNode>,
+    pub links: Vec<GraphEdge>,
+}
+
+/// Graph of the indexed endpoints, component schemas and top-level fields;
+/// nested paths are left out, the schemas they point to carry them.
+pub fn build_api_graph(index: &FieldIndex) -> ApiGraph {
+    let mut edges: BTreeSet<(NodeKind, &str, NodeKind, &str, EdgeKind)> = BTreeSet::new();
+    for (field, data) in &index.fields {
+        if field.contains('.') || field.contains("[]") {
+            continue;
+        }
+        for schema in &data.schemas {
+            edges.insert((
+                NodeKind::Schema,
+                schema,
+                NodeKind::Field,
+                field,
+                EdgeKind::Declares,
+            ));
+        }
+        for endpoint in &data.endpoints {
+            edges.insert((
+                NodeKind::Endpoint,
+                endpoint,
+                NodeKind::Field,
+                field,
+                EdgeKind::Uses,
+            ));
+        }
+    }
+    for (source, targets) in &index.schema_graph.edges {
+        for edge in targets {
+            edges.insert((
+                NodeKind::Schema,
+                source,
+                NodeKind::Schema,
+                &edge.target,
+                EdgeKind::References,
+            ));
+        }
+    }
+
+    // Every endpoint and schema is drawn, linked or not
+    let mut positions: BTreeMap<(NodeKind, &str), usize> = BTreeMap::new();
+    let endpoints = index
+        .endpoint_fields
+        .keys()
+        .map(|e| (NodeKind::Endpoint, e.as_str()));
+    let schemas = index.schemas.keys().map(|s| (NodeKind::Schema, s.as_str()));
+    let linked = edges
+        .iter()
+        .flat_map(|(sk, s, tk, t, _)| [(*sk, *s), (*tk, *t)]);
+    let keys: BTreeSet<(NodeKind, &str)> = endpoints.chain(schemas).chain(linked).collect();
+
+    let mut graph = ApiGraph::default();
+    for (kind, label) in keys {
+        positions.insert((kind, label), graph.nodes.len());
+        graph.nodes.push(GraphNode {
+            id: format!("{}:{}", kind.prefix(), label),
+            kind,
+            label: label.to_string(),
+        });
+    }
+    graph.links = edges
+        .into_iter()
+        .map(|(sk, s, tk, t, kind)| GraphEdge {
+            source: positions[&(sk, s)],
+            target: positions[&(tk, t)],
+            kind,
+        })
+        .collect();
+    graph
+}
+
+/// Graphviz rendering: endpoints as boxes, schemas as ellipses, fields as
+/// plain text, with `$ref`s drawn bold.
+pub fn to_dot(graph: &ApiGraph) -> String {
+    let mut out = String::from("digraph api {\n  rankdir=LR;\n  node [fontname=\"Helvetica\"];\n");
+    for node in &graph.nodes {
+        let shape = match node.kind {
+            NodeKind::Endpoint => "box",
+            NodeKind::Schema => "ellipse",
+            NodeKind::Field => "plaintext",
+        };
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\", shape={}];\n",
+            dot_escape(&node.id),
+            dot_escape(&node.label),
+            shape
+        ));
+    }
+    for link in &graph.links {
+        let style = match link.kind {
+            EdgeKind::Uses => "dashed",
+            EdgeKind::Declares => "solid",
+            EdgeKind::References => "bold",
+        };
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\" [style={}];\n",
+            dot_escape(&graph.nodes[link.source].id),
+            dot_escape(&graph.nodes[link.target].id),
+            style
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Standalone page embedding the graph as JSON, drawn on a canvas that can
+/// be panned, zoomed and searched without any network access.
+pub fn to_html(graph: &ApiGraph, title: &str) -> Result<String> {
+    // `</script>` inside a label must not close the data block
+    let data = serde_json::to_string(graph)?.replace("</", "<\\/");
+    Ok(HTML_TEMPLATE
+        .replace("{{title}}", &html_escape(title))
+        .replace("{{data}}", &data))
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes the graph as HTML for `.html` / `.htm` files and DOT for `.dot`
+/// / `.gv` ones.
+pub fn write_graph(path: &Path, graph: &ApiGraph, title: &str) -> Result<()> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    let content = match extension.as_str() {
+        "html" | "htm" => to_html(graph, title)?,
+        "dot" | "gv" => to_dot(graph),
+        _ => bail!("Graph exports are .html or .dot files"),
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+const HTML_TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{title}}</title>
+<style>
+  body { margin: 0; font: 13px Helvetica, Arial, sans-serif; background: #1e1e2e; color: #cdd6f4; overflow: hidden; }
+  #bar { position: fixed; top: 8px; left: 8px; background: #313244cc; padding: 6px 10px; border-radius: 6px; }
+  #bar input { background: #1e1e2e; color: inherit; border: 1px solid #585b70; padding: 2px 6px; }
+  .endpoint { color: #f38ba8; } .schema { color: #89b4fa; } .field { color: #a6e3a1; }
+  #info { margin-top: 4px; max-width: 420px; }
+</style>
+</head>
+<body>
+<div id="bar">
+  <b>{{title}}</b>
+  <span class="endpoint">● endpoint</span> <span class="schema">● schema</span> <span class="field">● field</span>
+  <input id="search" placeholder="Search..." size="24">
+  <div id="info">Drag to pan, scroll to zoom, click a node to highlight its neighbours.</div>
+</div>
+<canvas id="graph"></canvas>
+<script id="data" type="application/json">{{data}}</script>
+<script>
+const graph = JSON.parse(document.getElementById("data").textContent);
+const colors = { endpoint: "#f38ba8", schema: "#89b4fa", field: "#a6e3a1" };
+const canvas = document.getElementById("graph");
+const ctx = canvas.getContext("2d");
+const nodes = graph.nodes.map((n, i) => ({ ...n, x: Math.cos(i) * 200 * Math.sqrt(i + 1) / 4, y: Math.sin(i) * 200 * Math.sqrt(i + 1) / 4, vx: 0, vy: 0, links: [] }));
+graph.links.forEach(l => { nodes[l.source].links.push(l.target); nodes[l.target].links.push(l.source); });
+let view = { x: 0, y: 0, k: 1 }, selected = null, query = "", alpha = 1;
+
+function resize() { canvas.width = innerWidth; canvas.height = innerHeight; }
+addEventListener("resize", resize); resize();
+
+function tick() {
+  if (alpha < 0.005) return;
+  for (let i = 0; i < nodes.length; i++) {
+    for (let j = i + 1; j < nodes.length; j++) {
+      const a = nodes[i], b = nodes[j];
+      let dx = b.x - a.x, dy = b.y - a.y, d2 = dx * dx + dy * dy || 1;
+      if (d2 > 250000) continue;
+      const f = 400 * alpha / d2;
+      a.vx -= dx * f; a.vy -= dy * f; b.vx += dx * f; b.vy += dy * f;
+    }
+  }
+  for (const l of graph.links) {
+    const a = nodes[l.source], b = nodes[l.target];
+    const dx = b.x - a.x, dy = b.y - a.y, d = Math.sqrt(dx * dx + dy * dy) || 1;
+    const f = (d - 60) * 0.02 * alpha / d;
+    a.vx += dx * f; a.vy += dy * f; b.vx -= dx * f; b.vy -= dy * f;
+  }
+  for (const n of nodes) {
+    n.vx -= n.x * 0.002 * alpha; n.vy -= n.y * 0.002 * alpha;
+    n.x += n.vx; n.y += n.vy; n.vx *= 0.6; n.vy *= 0.6;
+  }
+  alpha *= 0.99;
+}
+
+function visible(n) { return !query || n.label.toLowerCase().includes(query); }
+
+function draw() {
+  tick();
+  ctx.setTransform(1, 0, 0, 1, 0, 0);
+  ctx.clearRect(0, 0, canvas.width, canvas.height);
+  ctx.setTransform(view.k, 0, 0, view.k, canvas.width / 2 + view.x, canvas.height / 2 + view.y);
+  const near = selected === null ? null : new Set([selected, ...nodes[selected].links]);
+  ctx.lineWidth = 1 / view.k;
+  for (const l of graph.links) {
+    const a = nodes[l.source], b = nodes[l.target];
+    const lit = near && (l.source === selected || l.target === selected);
+    ctx.strokeStyle = lit ? "#f9e2af" : l.kind === "references" ? "#89b4fa55" : "#6c708633";
+    ctx.beginPath(); ctx.moveTo(a.x, a.y); ctx.lineTo(b.x, b.y); ctx.stroke();
+  }
+  nodes.forEach((n, i) => {
+    const dim = (near && !near.has(i)) || !visible(n);
+    ctx.globalAlpha = dim ? 0.15 : 1;
+    ctx.fillStyle = colors[n.kind];
+    ctx.beginPath(); ctx.arc(n.x, n.y, n.kind === "field" ? 3 : 5, 0, 2 * Math.PI); ctx.fill();
+    if (!dim && (view.k > 1.2 || (near && near.has(i)) || (query && visible(n)) || n.kind !== "field")) {
+      ctx.fillStyle = "#cdd6f4"; ctx.fillText(n.label, n.x + 7, n.y + 4);
+    }
+  });
+  ctx.globalAlpha = 1;
+  requestAnimationFrame(draw);
+}
+
+function toGraph(e) { return { x: (e.clientX - canvas.width / 2 - view.x) / view.k, y: (e.clientY - canvas.height / 2 - view.y) / view.k }; }
+let drag = null;
+canvas.addEventListener("mousedown", e => { drag = { x: e.clientX, y: e.clientY, vx: view.x, vy: view.y, moved: false }; });
+addEventListener("mousemove", e => {
+  if (!drag) return;
+  view.x = drag.vx + e.clientX - drag.x; view.y = drag.vy + e.clientY - drag.y;
+  drag.moved = drag.moved || Math.abs(e.clientX - drag.x) + Math.abs(e.clientY - drag.y) > 3;
+});
+addEventListener("mouseup", e => {
+  if (drag && !drag.moved) {
+    const p = toGraph(e);
+    let best = null, bestD = 100 / (view.k * view.k);
+    nodes.forEach((n, i) => { const d = (n.x - p.x) ** 2 + (n.y - p.y) ** 2; if (d < bestD) { best = i; bestD = d; } });
+    selected = best;
+    const info = document.getElementById("info");
+    info.textContent = best === null ? "" : nodes[best].kind + " " + nodes[best].label + " (" + nodes[best].links.length + " links)";
+  }
+  drag = null;
+});
+canvas.addEventListener("wheel", e => { e.preventDefault(); view.k = Math.min(8, Math.max(0.1, view.k * (e.deltaY < 0 ? 1.1 : 0.9))); }, { passive: false });
+document.getElementById("search").addEventListener("input", e => { query = e.target.value.toLowerCase(); });
+draw();
+</script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    fn graph() -> ApiGraph {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"get": {"responses": {"200": {"description": "ok", "content": {
+                        "application/json": {"schema": {"type": "object", "properties": {
+                            "name": {"type": "string"}
+                        }}}
+                    }}}}}
+                },
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "name": {"type": "string"},
+                        "address": {"$ref": "#/components/schemas/Address"}
+                    }},
+                    "Address": {"type": "object", "properties": {"city": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        build_api_graph(&build_field_index(&spec))
+    }
+
+    #[test]
+    fn test_builds_endpoint_schema_and_field_nodes() {
+        let graph = graph();
+        let ids: Vec<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "endpoint:GET /users",
+                "schema:Address",
+                "schema:User",
+                "field:address",
+                "field:city",
+                "field:name",
+            ]
+        );
+        let links: Vec<(&str, &str, EdgeKind)> = graph
+            .links
+            .iter()
+            .map(|l| {
+                (
+                    graph.nodes[l.source].label.as_str(),
+                    graph.nodes[l.target].label.as_str(),
+                    l.kind,
+                )
+            })
+            .collect();
+        assert!(links.contains(&("GET /users", "name", EdgeKind::Uses)));
+        assert!(links.contains(&("User", "name", EdgeKind::Declares)));
+        assert!(links.contains(&("User", "Address", EdgeKind::References)));
+        assert!(!links.iter().any(|(_, target, _)| target.contains('.')));
+
+        let dot = to_dot(&graph);
+        assert!(dot.starts_with("digraph api {"));
+        assert!(dot.contains("\"schema:User\" -> \"schema:Address\" [style=bold];"));
+    }
+
+    #[test]
+    fn test_writes_self_contained_html() {
+        let mut graph = graph();
+        graph.nodes[0].label = "</script><b>".to_string();
+        let html = to_html(&graph, "Shop <1>").unwrap();
+        assert!(html.contains("<title>Shop &lt;1&gt;</title>"));
+        assert!(html.contains(r#""label":"<\/script><b>""#));
+        assert!(!html.contains("http://") && !html.contains("https://"));
+
+        let dir = tempfile::tempdir().unwrap();
+        write_graph(&dir.path().join("api.dot"), &graph, "Shop").unwrap();
+        assert!(write_graph(&dir.path().join("api.png"), &graph, "Shop").is_err());
+    }
+}
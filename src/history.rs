@@ -0,0 +1,77 @@
+/// Back and forward stacks of visited locations, like browser history.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    back: Vec<T>,
+    forward: Vec<T>,
+    /// Oldest entries are dropped past this many
+    limit: usize,
+}
+
+impl<T: PartialEq> History<T> {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            back: Vec::new(),
+            forward: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Records `from` as the location just left for a new one, which
+    /// drops the forward entries.
+    pub fn visit(&mut self, from: T) {
+        self.forward.clear();
+        if self.back.last() != Some(&from) {
+            self.back.push(from);
+            if self.back.len() > self.limit {
+                self.back.remove(0);
+            }
+        }
+    }
+
+    /// Location before `current`, which becomes the next forward entry.
+    pub fn back(&mut self, current: T) -> Option<T> {
+        let previous = self.back.pop()?;
+        self.forward.push(current);
+        Some(previous)
+    }
+
+    /// Location left with `back`, `current` becoming a back entry again.
+    pub fn forward(&mut self, current: T) -> Option<T> {
+        let next = self.forward.pop()?;
+        self.back.push(current);
+        Some(next)
+    }
+
+    pub fn clear(&mut self) {
+        self.back.clear();
+        self.forward.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut history = History::new(2);
+        history.visit("a");
+        history.visit("b");
+        history.visit("b");
+        history.visit("c");
+
+        // "a" fell past the limit
+        assert_eq!(history.back("d"), Some("c"));
+        assert_eq!(history.back("c"), Some("b"));
+        assert_eq!(history.back("b"), None);
+        assert_eq!(history.forward("b"), Some("c"));
+        assert_eq!(history.forward("c"), Some("d"));
+        assert_eq!(history.forward("d"), None);
+
+        // A new visit after going back drops the forward entries
+        history.back("d");
+        history.visit("c");
+        assert_eq!(history.forward("e"), None);
+        assert_eq!(history.back("e"), Some("c"));
+    }
+}
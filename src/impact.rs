@@ -0,0 +1,432 @@
+use crate::indexer::{extract_fields_from_schema, FieldIndex};
+use crate::parser::{OpenApiSpec, Schema};
+use crate::schema_graph::referenced_schemas;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImpactTarget {
+    Field(String),
+    /// Several fields analyzed together
+    Fields(Vec<String>),
+    Schema(String),
+}
+
+impl ImpactTarget {
+    pub fn name(&self) -> String {
+        match self {
+            ImpactTarget::Field(name) | ImpactTarget::Schema(name) => name.clone(),
+            ImpactTarget::Fields(names) => names.join(", "),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ImpactTarget::Field(_) => "field",
+            ImpactTarget::Fields(_) => "fields",
+            ImpactTarget::Schema(_) => "schema",
+        }
+    }
+}
+
+/// Where in an operation the target is used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UsageLocation {
+    Parameter {
+        location: String,
+    },
+    Request {
+        content_type: String,
+    },
+    Response {
+        status: String,
+        content_type: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Usage {
+    pub location: UsageLocation,
+    /// Component schema through which the target is reached, when the usage
+    /// is indirect.
+    pub via: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EndpointImpact {
+    pub endpoint: String,
+    pub usages: Vec<Usage>,
+}
+
+impl EndpointImpact {
+    pub fn is_write(&self) -> bool {
+        self.usages
+            .iter()
+            .any(|usage| matches!(usage.location, UsageLocation::Request { .. }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImpactReport {
+    pub target: ImpactTarget,
+    pub endpoints: Vec<EndpointImpact>,
+}
+
+impl ImpactReport {
+    pub fn request_count(&self) -> usize {
+        self.endpoints.iter().filter(|e| e.is_write()).count()
+    }
+
+    pub fn response_count(&self) -> usize {
+        self.endpoints
+            .iter()
+            .filter(|e| {
+                e.usages
+                    .iter()
+                    .any(|usage| matches!(usage.location, UsageLocation::Response { .. }))
+            })
+            .count()
+    }
+}
+
+pub fn analyze_impact(
+    openapi_spec: &OpenApiSpec,
+    index: &FieldIndex,
+    target: &ImpactTarget,
+) -> ImpactReport {
+    // Schemas whose change propagates to the target's users
+    let mut affected_schemas = HashSet::new();
+    let field_names: Vec<&str> = match target {
+        ImpactTarget::Field(name) => vec![name.as_str()],
+        ImpactTarget::Fields(names) => names.iter().map(String::as_str).collect(),
+        ImpactTarget::Schema(_) => Vec::new(),
+    };
+    let owners: Vec<String> = match target {
+        ImpactTarget::Schema(name) => vec![name.clone()],
+        ImpactTarget::Field(_) | ImpactTarget::Fields(_) => field_names
+            .iter()
+            .filter_map(|name| index.fields.get(*name))
+            .flat_map(|data| data.schemas.iter().map(|s| s.to_string()))
+            .collect(),
+    };
+    for owner in &owners {
+        affected_schemas.extend(index.schema_graph.transitive_dependents(owner));
+    }
+
+    let mut endpoints = Vec::new();
+    let mut paths: Vec<&String> = openapi_spec.paths.keys().collect();
+    paths.sort_unstable();
+
+    for path in paths {
+        let path_item = &openapi_spec.paths[path];
+        let mut methods: Vec<&String> = path_item.operations.keys().collect();
+        methods.sort_unstable();
+
+        for method in methods {
+            let operation = &path_item.operations[method];
+            let mut usages = Vec::new();
+
+            if let Some(parameters) = &operation.parameters {
+                for param in parameters
+                    .iter()
+                    .filter(|p| field_names.contains(&p.name.as_str()))
+                {
+                    usages.push(Usage {
+                        location: UsageLocation::Parameter {
+                            location: param.in_.clone(),
+                        },
+                        via: None,
+                    });
+                }
+            }
+
+            if let Some(request_body) = &operation.request_body {
+                let mut content_types: Vec<&String> = request_body.content.keys().collect();
+                content_types.sort_unstable();
+                for content_type in content_types {
+                    if let Some(schema) = &request_body.content[content_type].schema {
+                        if let Some(via) = schema_usage(schema, &affected_schemas, &field_names) {
+                            usages.push(Usage {
+                                location: UsageLocation::Request {
+                                    content_type: content_type.clone(),
+                                },
+                                via,
+                            });
+                        }
+                    }
+                }
+            }
+
+            let mut statuses: Vec<&String> = operation.responses.keys().collect();
+            statuses.sort_unstable();
+            for status in statuses {
+                let Some(content) = &operation.responses[status].content else {
+                    continue;
+                };
+                let mut content_types: Vec<&String> = content.keys().collect();
+                content_types.sort_unstable();
+                for content_type in content_types {
+                    if let Some(schema) = &content[content_type].schema {
+                        if let Some(via) = schema_usage(schema, &affected_schemas, &field_names) {
+                            usages.push(Usage {
+                                location: UsageLocation::Response {
+                                    status: status.clone(),
+                                    content_type: content_type.clone(),
+                                },
+                                via,
+                            });
+                        }
+                    }
+                }
+            }
+
+            if !usages.is_empty() {
+                endpoints.push(EndpointImpact {
+                    endpoint: format!("{} {}", method.to_uppercase(), path),
+                    usages,
+                });
+            }
+        }
+    }
+
+    ImpactReport {
+        target: target.clone(),
+        endpoints,
+    }
+}
+
+/// Returns `Some(via)` if `schema` uses the target, either inline (`None`) or
+/// through a referenced component schema (`Some(name)`).
+fn schema_usage(
+    schema: &Schema,
+    affected_schemas: &HashSet<String>,
+    field_names: &[&str],
+) -> Option<Option<String>> {
+    if let Some(via) = referenced_schemas(schema)
+        .into_iter()
+        .find(|name| affected_schemas.contains(name))
+    {
+        return Some(Some(via));
+    }
+
+    extract_fields_from_schema(schema)
+        .iter()
+        .any(|f| field_names.contains(&f.as_str()))
+        .then_some(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::{
+        Components, Info, MediaType, Operation, Parameter, PathItem, RequestBody, Response,
+    };
+    use std::collections::HashMap;
+
+    fn reference(name: &str) -> Schema {
+        Schema {
+            reference: Some(format!("#/components/schemas/{}", name)),
+            ..Default::default()
+        }
+    }
+
+    fn json(schema: Schema) -> HashMap<String, MediaType> {
+        HashMap::from([(
+            "application/json".to_string(),
+            MediaType {
+                schema: Some(schema),
+                encoding: None,
+            },
+        )])
+    }
+
+    fn operation(
+        parameters: Option<Vec<Parameter>>,
+        request: Option<Schema>,
+        responses: Vec<(&str, Schema)>,
+    ) -> Operation {
+        Operation {
+            operation_id: None,
+            summary: None,
+            description: None,
+            tags: None,
+            parameters,
+            request_body: request.map(|schema| RequestBody {
+                description: None,
+                required: None,
+                content: json(schema),
+            }),
+            responses: responses
+                .into_iter()
+                .map(|(status, schema)| {
+                    (
+                        status.to_string(),
+                        Response {
+                            description: "ok".to_string(),
+                            headers: None,
+                            links: None,
+                            content: Some(json(schema)),
+                        },
+                    )
+                })
+                .collect(),
+            deprecated: None,
+            external_docs: None,
+            callbacks: None,
+            extensions: HashMap::new(),
+        }
+    }
+
+    fn create_test_spec() -> OpenApiSpec {
+        let address = Schema {
+            schema_type: Some("object".to_string()),
+            properties: Some(HashMap::from([(
+                "city".to_string(),
+                Schema {
+                    schema_type: Some("string".to_string()),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+        let user = Schema {
+            schema_type: Some("object".to_string()),
+            properties: Some(HashMap::from([(
+                "address".to_string(),
+                reference("Address"),
+            )])),
+            ..Default::default()
+        };
+
+        OpenApiSpec {
+            openapi: "3.0.0".to_string(),
+            webhooks: None,
+            info: Info {
+                title: "Test".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                terms_of_service: None,
+                contact: None,
+                license: None,
+            },
+            servers: None,
+            paths: HashMap::from([
+                (
+                    "/users".to_string(),
+                    PathItem {
+                        operations: HashMap::from([
+                            (
+                                "get".to_string(),
+                                operation(
+                                    None,
+                                    None,
+                                    vec![(
+                                        "200",
+                                        Schema {
+                                            schema_type: Some("array".to_string()),
+                                            items: Some(Box::new(reference("User"))),
+                                            ..Default::default()
+                                        },
+                                    )],
+                                ),
+                            ),
+                            (
+                                "post".to_string(),
+                                operation(None, Some(reference("User")), vec![]),
+                            ),
+                        ]),
+                    },
+                ),
+                (
+                    "/cities".to_string(),
+                    PathItem {
+                        operations: HashMap::from([(
+                            "get".to_string(),
+                            operation(
+                                Some(vec![Parameter {
+                                    name: "city".to_string(),
+                                    in_: "query".to_string(),
+                                    description: None,
+                                    required: None,
+                                    schema: None,
+                                }]),
+                                None,
+                                vec![],
+                            ),
+                        )]),
+                    },
+                ),
+            ]),
+            tags: None,
+            external_docs: None,
+            parse_warnings: Vec::new(),
+            referenced_components: Default::default(),
+            unresolved_refs: Vec::new(),
+            callback_targets: Default::default(),
+            explorer_ignore: None,
+            components: Some(Components {
+                schemas: Some(HashMap::from([
+                    ("Address".to_string(), address),
+                    ("User".to_string(), user),
+                ])),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_schema_impact_is_transitive() {
+        let spec = create_test_spec();
+        let index = build_field_index(&spec);
+
+        let report = analyze_impact(&spec, &index, &ImpactTarget::Schema("Address".to_string()));
+        let endpoints: Vec<&str> = report
+            .endpoints
+            .iter()
+            .map(|e| e.endpoint.as_str())
+            .collect();
+        assert_eq!(endpoints, vec!["GET /users", "POST /users"]);
+
+        assert_eq!(
+            report.endpoints[0].usages[0],
+            Usage {
+                location: UsageLocation::Response {
+                    status: "200".to_string(),
+                    content_type: "application/json".to_string(),
+                },
+                via: Some("User".to_string()),
+            }
+        );
+        assert_eq!(report.request_count(), 1);
+        assert_eq!(report.response_count(), 1);
+    }
+
+    #[test]
+    fn test_field_impact_includes_parameters() {
+        let spec = create_test_spec();
+        let index = build_field_index(&spec);
+
+        let report = analyze_impact(&spec, &index, &ImpactTarget::Field("city".to_string()));
+        assert_eq!(report.endpoints.len(), 3);
+        let cities = report
+            .endpoints
+            .iter()
+            .find(|e| e.endpoint == "GET /cities")
+            .unwrap();
+        assert_eq!(
+            cities.usages[0].location,
+            UsageLocation::Parameter {
+                location: "query".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_unused_schema_has_no_impact() {
+        let spec = create_test_spec();
+        let index = build_field_index(&spec);
+
+        let report = analyze_impact(&spec, &index, &ImpactTarget::Schema("Missing".to_string()));
+        assert!(report.endpoints.is_empty());
+    }
+}
@@ -1,19 +1,331 @@
-use crate::parser::{OpenApiSpec, Schema};
-use std::collections::{HashMap, HashSet};
+use crate::direction::{schema_directions, SchemaDirection};
+use crate::parser::{Components, OpenApiSpec, Operation, Schema};
+use crate::progress::{no_progress, Progress, StageCounter};
+use crate::schema_graph::{build_schema_graph, SchemaGraph};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+/// HTTP method of an operation. Read methods sort first, then write
+/// methods from the least to the most destructive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Get,
+    Head,
+    Options,
+    Trace,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl HttpMethod {
+    pub fn parse(method: &str) -> Option<Self> {
+        match method.to_ascii_lowercase().as_str() {
+            "get" => Some(Self::Get),
+            "head" => Some(Self::Head),
+            "options" => Some(Self::Options),
+            "trace" => Some(Self::Trace),
+            "post" => Some(Self::Post),
+            "put" => Some(Self::Put),
+            "patch" => Some(Self::Patch),
+            "delete" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+
+    /// Method of a `"METHOD target"` endpoint key.
+    pub fn of_endpoint(endpoint: &str) -> Option<Self> {
+        endpoint.split_whitespace().next().and_then(Self::parse)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Head => "HEAD",
+            Self::Options => "OPTIONS",
+            Self::Trace => "TRACE",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+
+    /// POST, PUT, PATCH and DELETE change server state.
+    pub fn is_write(self) -> bool {
+        self >= Self::Post
+    }
+}
+
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldData {
     pub field_type: String,
     pub description: Option<String>,
-    pub schemas: Vec<String>,
-    pub endpoints: HashSet<String>,
+    /// Schema and endpoint names are shared by every field, see [`Interner`]
+    pub schemas: Vec<Arc<str>>,
+    pub endpoints: HashSet<Arc<str>>,
+    /// Methods of the endpoints using the field
+    pub methods: BTreeSet<HttpMethod>,
+    /// Attributes of the field in each schema declaring it with any
+    pub flags: Vec<(Arc<str>, FieldFlags)>,
+}
+
+impl FieldData {
+    /// Whether some schema declares the field with `flag`.
+    pub fn has_flag(&self, flag: FieldFlag) -> bool {
+        self.flags.iter().any(|(_, flags)| flags.has(flag))
+    }
+}
+
+/// `nullable`, `readOnly` and `writeOnly` of a property.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldFlags {
+    pub nullable: bool,
+    pub read_only: bool,
+    pub write_only: bool,
+}
+
+impl FieldFlags {
+    pub fn of(schema: &Schema) -> Self {
+        Self {
+            // OpenAPI 3.1 spells it `type: [..., "null"]`, kept as no type
+            nullable: schema.nullable.unwrap_or(false),
+            read_only: schema.read_only.unwrap_or(false),
+            write_only: schema.write_only.unwrap_or(false),
+        }
+    }
+
+    pub fn has(self, flag: FieldFlag) -> bool {
+        match flag {
+            FieldFlag::Nullable => self.nullable,
+            FieldFlag::ReadOnly => self.read_only,
+            FieldFlag::WriteOnly => self.write_only,
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Set flags, in display order.
+    pub fn iter(self) -> impl Iterator<Item = FieldFlag> {
+        FieldFlag::ALL
+            .into_iter()
+            .filter(move |flag| self.has(*flag))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldFlag {
+    Nullable,
+    ReadOnly,
+    WriteOnly,
+}
+
+impl FieldFlag {
+    pub const ALL: [FieldFlag; 3] = [
+        FieldFlag::ReadOnly,
+        FieldFlag::WriteOnly,
+        FieldFlag::Nullable,
+    ];
+
+    /// Name in filters, e.g. `readonly:true`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FieldFlag::Nullable => "nullable",
+            FieldFlag::ReadOnly => "readonly",
+            FieldFlag::WriteOnly => "writeonly",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|flag| flag.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Short badge of the Fields view.
+    pub fn badge(self) -> &'static str {
+        match self {
+            FieldFlag::Nullable => "null",
+            FieldFlag::ReadOnly => "RO",
+            FieldFlag::WriteOnly => "WO",
+        }
+    }
+}
+
+/// Hands out one shared allocation per distinct name, so schema and
+/// endpoint names repeated across thousands of fields are stored once.
+#[derive(Debug, Default)]
+pub struct Interner(HashSet<Arc<str>>);
+
+impl Interner {
+    pub fn intern(&mut self, name: &str) -> Arc<str> {
+        if let Some(shared) = self.0.get(name) {
+            return Arc::clone(shared);
+        }
+        let shared: Arc<str> = Arc::from(name);
+        self.0.insert(Arc::clone(&shared));
+        shared
+    }
+}
+
+/// A response header (rate limits, pagination cursors, ...) and the
+/// responses declaring it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeaderData {
+    pub header_type: String,
+    pub description: Option<String>,
+    /// `"METHOD /path"` to the status codes declaring the header
+    pub endpoints: BTreeMap<String, Vec<String>>,
+}
+
+/// Status codes an operation documents, as shown in the Responses view.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResponseData {
+    /// In matrix order: codes by class, `4XX` ranges after their class,
+    /// `default` last
+    pub statuses: Vec<String>,
+    /// Declares its own `security` requirements
+    pub secured: bool,
+}
+
+/// Media types an operation accepts and returns.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentTypeData {
+    /// Request body media types, sorted
+    pub requests: Vec<String>,
+    /// Media types of all its responses, sorted
+    pub responses: Vec<String>,
+}
+
+/// Where an operation declares a field, in the order the field tree lists
+/// them: parameters, request body, then responses by status.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum FieldOrigin {
+    PathParameter,
+    QueryParameter,
+    HeaderParameter,
+    CookieParameter,
+    RequestBody,
+    /// Response of a status code or `default`
+    Response(String),
+}
+
+impl FieldOrigin {
+    /// Origin of a parameter by its `in`.
+    pub fn of_parameter(location: &str) -> Self {
+        match location {
+            "path" => FieldOrigin::PathParameter,
+            "header" => FieldOrigin::HeaderParameter,
+            "cookie" => FieldOrigin::CookieParameter,
+            _ => FieldOrigin::QueryParameter,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            FieldOrigin::PathParameter => "Path parameters".to_string(),
+            FieldOrigin::QueryParameter => "Query parameters".to_string(),
+            FieldOrigin::HeaderParameter => "Header parameters".to_string(),
+            FieldOrigin::CookieParameter => "Cookie parameters".to_string(),
+            FieldOrigin::RequestBody => "Request body".to_string(),
+            FieldOrigin::Response(status) => format!("Response {}", status),
+        }
+    }
 }
 
-#[derive(Debug)]
+/// Field of an operation with where it is declared; a field declared in
+/// several places is listed once per origin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointField {
+    pub name: String,
+    pub origin: FieldOrigin,
+}
+
+/// Field of an operation with every place declaring it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldUsage {
+    pub name: String,
+    /// Sorted
+    pub origins: Vec<FieldOrigin>,
+}
+
+impl FieldUsage {
+    pub fn occurrences(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// Where the field is declared, e.g. `path parameter + request + 2
+    /// responses`; a single response is named by its status.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let mut statuses: Vec<&str> = Vec::new();
+        for origin in &self.origins {
+            match origin {
+                FieldOrigin::PathParameter => parts.push("path parameter".to_string()),
+                FieldOrigin::QueryParameter => parts.push("query parameter".to_string()),
+                FieldOrigin::HeaderParameter => parts.push("header parameter".to_string()),
+                FieldOrigin::CookieParameter => parts.push("cookie parameter".to_string()),
+                FieldOrigin::RequestBody => parts.push("request".to_string()),
+                FieldOrigin::Response(status) => statuses.push(status),
+            }
+        }
+        match statuses.as_slice() {
+            [] => {}
+            [status] => parts.push(format!("response {}", status)),
+            statuses => parts.push(format!("{} responses", statuses.len())),
+        }
+        parts.join(" + ")
+    }
+}
+
+/// Property of a `multipart/form-data` or form-urlencoded request body,
+/// with how its part is encoded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormField {
+    pub name: String,
+    pub field_type: String,
+    /// Request media type declaring the field
+    pub media_type: String,
+    /// File upload: binary or base64 string, or with a `contentMediaType`
+    pub is_file: bool,
+    /// From the `encoding` section, else the default for the field type
+    pub content_type: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FieldIndex {
     pub fields: HashMap<String, FieldData>,
+    pub headers: HashMap<String, HeaderData>,
     pub schemas: HashMap<String, Schema>,
-    pub endpoint_fields: HashMap<String, Vec<String>>,
+    /// Fields of each operation (`METHOD /path`) with their origin
+    pub endpoint_fields: HashMap<String, Vec<EndpointField>>,
+    pub schema_graph: SchemaGraph,
+    /// Whether each component schema is sent, returned or both
+    pub schema_directions: HashMap<String, SchemaDirection>,
+    /// Nested property depth the index was built with
+    pub max_depth: usize,
+    /// Endpoint key of each `operationId`
+    pub operation_ids: HashMap<String, String>,
+    /// Documented status codes of each path operation
+    pub responses: HashMap<String, ResponseData>,
+    /// Request and response media types of each path operation
+    pub content_types: HashMap<String, ContentTypeData>,
+    /// Form and multipart request body fields of each path operation
+    pub form_fields: HashMap<String, Vec<FormField>>,
 }
 
 impl Default for FieldIndex {
@@ -26,24 +338,83 @@ impl FieldIndex {
     pub fn new() -> Self {
         Self {
             fields: HashMap::new(),
+            headers: HashMap::new(),
             schemas: HashMap::new(),
             endpoint_fields: HashMap::new(),
+            schema_graph: SchemaGraph::default(),
+            schema_directions: HashMap::new(),
+            max_depth: DEFAULT_FIELD_DEPTH,
+            operation_ids: HashMap::new(),
+            responses: HashMap::new(),
+            content_types: HashMap::new(),
+            form_fields: HashMap::new(),
         }
     }
 
+    /// Distinct names of the fields of an operation, in declaration order.
+    pub fn endpoint_field_names(&self, endpoint: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for field in self.endpoint_fields.get(endpoint).into_iter().flatten() {
+            if !names.contains(&field.name.as_str()) {
+                names.push(&field.name);
+            }
+        }
+        names
+    }
+
+    /// Fields of an operation, each once with the places declaring it, in
+    /// the order they are first declared.
+    pub fn endpoint_field_usages(&self, endpoint: &str) -> Vec<FieldUsage> {
+        let mut usages: Vec<FieldUsage> = Vec::new();
+        for field in self.endpoint_fields.get(endpoint).into_iter().flatten() {
+            match usages.iter_mut().find(|usage| usage.name == field.name) {
+                Some(usage) => usage.origins.push(field.origin.clone()),
+                None => usages.push(FieldUsage {
+                    name: field.name.clone(),
+                    origins: vec![field.origin.clone()],
+                }),
+            }
+        }
+        for usage in &mut usages {
+            usage.origins.sort();
+        }
+        usages
+    }
+
     pub fn get_endpoints_for_field(&self, field_name: &str) -> Vec<String> {
         self.fields
             .get(field_name)
-            .map(|data| data.endpoints.iter().cloned().collect())
+            .map(|data| data.endpoints.iter().map(|e| e.to_string()).collect())
             .unwrap_or_default()
     }
 
+    /// Makes equal schema and endpoint names share one allocation again,
+    /// after deserializing gave each occurrence its own.
+    pub fn share_names(&mut self) {
+        let mut names = Interner::default();
+        for data in self.fields.values_mut() {
+            for (schema, _) in &mut data.flags {
+                *schema = names.intern(schema);
+            }
+            for schema in &mut data.schemas {
+                *schema = names.intern(schema);
+            }
+            data.endpoints = data
+                .endpoints
+                .iter()
+                .map(|endpoint| names.intern(endpoint))
+                .collect();
+        }
+    }
+
+    pub fn endpoint_for_operation(&self, operation_id: &str) -> Option<&String> {
+        self.operation_ids.get(operation_id)
+    }
+
     pub fn is_critical_field(&self, field_name: &str) -> bool {
         if let Some(data) = self.fields.get(field_name) {
-            // Consider a field critical if it's used in POST/PUT operations
-            data.endpoints.iter().any(|endpoint| {
-                endpoint.to_lowercase().contains("post") || endpoint.to_lowercase().contains("put")
-            })
+            // Consider a field critical if it's used in write operations
+            data.methods.iter().any(|method| method.is_write())
         } else {
             false
         }
@@ -57,104 +428,549 @@ impl FieldIndex {
     }
 }
 
+/// Field declared by a schema
+struct SchemaField {
+    name: String,
+    field_type: String,
+    description: Option<String>,
+    flags: FieldFlags,
+}
+
+/// Levels of nested properties indexed by default: `address.city` is
+/// depth 2, `order.lines[].sku` depth 3
+pub const DEFAULT_FIELD_DEPTH: usize = 3;
+
 pub fn build_field_index(openapi_spec: &OpenApiSpec) -> FieldIndex {
+    build_field_index_with_depth(openapi_spec, DEFAULT_FIELD_DEPTH)
+}
+
+/// Indexes the spec, including properties nested in inline objects as
+/// dotted paths down to `max_depth` levels (1 keeps top-level fields only).
+pub fn build_field_index_with_depth(openapi_spec: &OpenApiSpec, max_depth: usize) -> FieldIndex {
+    build_field_index_with_progress(openapi_spec, max_depth, &no_progress)
+}
+
+/// [`build_field_index_with_depth`], reporting schemas and paths indexed.
+pub fn build_field_index_with_progress(
+    openapi_spec: &OpenApiSpec,
+    max_depth: usize,
+    progress: Progress<'_>,
+) -> FieldIndex {
     let mut index = FieldIndex::new();
+    index.max_depth = max_depth;
+    let mut names = Interner::default();
 
     log::debug!("Building field index from OpenAPI specification");
 
-    // Index all schemas first
+    // Index all schemas first: fields are extracted in parallel, then merged
+    // in schema name order so the first declaration of a field is stable
     if let Some(components) = &openapi_spec.components {
         if let Some(schemas) = &components.schemas {
             log::debug!("Processing {} schemas", schemas.len());
-            for (schema_name, schema) in schemas {
-                index.schemas.insert(schema_name.clone(), schema.clone());
-
-                // Index fields from this schema
-                let field_names = schema.get_field_names();
-                log::trace!("Schema '{}' has {} fields", schema_name, field_names.len());
-
-                for field_name in field_names {
-                    let field_data =
-                        index
-                            .fields
-                            .entry(field_name.clone())
-                            .or_insert_with(|| FieldData {
-                                field_type: schema
-                                    .get_field_type(&field_name)
-                                    .unwrap_or_else(|| "unknown".to_string()),
-                                description: schema.get_field_description(&field_name),
-                                schemas: Vec::new(),
-                                endpoints: HashSet::new(),
-                            });
-
-                    if !field_data.schemas.contains(schema_name) {
-                        field_data.schemas.push(schema_name.clone());
+            let counter = StageCounter::start(schemas.len(), StageCounter::schemas, progress);
+            let mut schema_fields: Vec<(&String, Vec<SchemaField>)> = schemas
+                .par_iter()
+                .map(|(schema_name, schema)| {
+                    let fields = schema_fields(schema, max_depth);
+                    counter.tick();
+                    (schema_name, fields)
+                })
+                .collect();
+            schema_fields.sort_by(|a, b| a.0.cmp(b.0));
+
+            for (schema_name, fields) in schema_fields {
+                index
+                    .schemas
+                    .insert(schema_name.clone(), schemas[schema_name].clone());
+                log::trace!("Schema '{}' has {} fields", schema_name, fields.len());
+                let shared_name = names.intern(schema_name);
+
+                for field in fields {
+                    let field_data = index.fields.entry(field.name).or_insert_with(|| FieldData {
+                        field_type: field.field_type,
+                        description: field.description,
+                        schemas: Vec::new(),
+                        endpoints: HashSet::new(),
+                        methods: BTreeSet::new(),
+                        flags: Vec::new(),
+                    });
+
+                    if !field_data.schemas.contains(&shared_name) {
+                        field_data.schemas.push(Arc::clone(&shared_name));
+                        if !field.flags.is_empty() {
+                            field_data
+                                .flags
+                                .push((Arc::clone(&shared_name), field.flags));
+                        }
                     }
                 }
             }
         }
+        index_shared_components(&mut index, components, max_depth);
     } else {
         log::warn!("No components found in OpenAPI specification");
     }
 
-    // Index endpoints and their field usage
+    // Index endpoints and their field usage, one path per task
     log::debug!("Processing {} endpoints", openapi_spec.paths.len());
+    let counter = StageCounter::start(openapi_spec.paths.len(), StageCounter::endpoints, progress);
+    let mut endpoint_fields: Vec<(String, Vec<EndpointField>)> = openapi_spec
+        .paths
+        .par_iter()
+        .flat_map_iter(|(path, path_item)| {
+            let fields: Vec<(String, Vec<EndpointField>)> = path_item
+                .operations
+                .iter()
+                .map(|(method, operation)| {
+                    let endpoint_key = format!("{} {}", method.to_uppercase(), path);
+                    log::trace!("Processing endpoint: {}", endpoint_key);
+                    (endpoint_key, operation_fields(operation, max_depth))
+                })
+                .collect();
+            counter.tick();
+            fields
+        })
+        .collect();
+
+    // Webhooks and callbacks are indexed as `METHOD webhook:name` /
+    // `METHOD callback:METHOD /path/name` so their payload fields are
+    // traceable too
+    for (target, path_item) in openapi_spec.event_targets() {
+        for (method, operation) in &path_item.operations {
+            let endpoint_key = format!("{} {}", method.to_uppercase(), target);
+            log::trace!("Processing event: {}", endpoint_key);
+            endpoint_fields.push((endpoint_key, operation_fields(operation, max_depth)));
+        }
+    }
+
+    for (endpoint_key, fields) in endpoint_fields {
+        let method = HttpMethod::of_endpoint(&endpoint_key);
+        let shared_key = names.intern(&endpoint_key);
+        for field in &fields {
+            if let Some(field_data) = index.fields.get_mut(&field.name) {
+                field_data.endpoints.insert(Arc::clone(&shared_key));
+                field_data.methods.extend(method);
+            }
+        }
+        index.endpoint_fields.insert(endpoint_key, fields);
+    }
+
+    index.headers = collect_response_headers(openapi_spec);
+    index.operation_ids = collect_operation_ids(openapi_spec);
+    index.responses = collect_responses(openapi_spec);
+    index.content_types = collect_content_types(openapi_spec);
+    index.form_fields = collect_form_fields(openapi_spec);
+
+    // Schema-level dependencies from the raw $refs
+    index.schema_graph = build_schema_graph(openapi_spec);
+    index.schema_directions = schema_directions(openapi_spec, &index.schema_graph);
+
+    index
+}
+
+/// Top-level fields, then the nested ones as dotted paths.
+fn schema_fields(schema: &Schema, max_depth: usize) -> Vec<SchemaField> {
+    let mut fields: Vec<SchemaField> = schema
+        .get_field_names()
+        .into_iter()
+        .map(|name| SchemaField {
+            field_type: schema
+                .get_field_type(&name)
+                .unwrap_or_else(|| "unknown".to_string()),
+            description: schema.get_field_description(&name),
+            flags: schema
+                .property(&name)
+                .map(FieldFlags::of)
+                .unwrap_or_default(),
+            name,
+        })
+        .collect();
+    fields.extend(
+        schema
+            .nested_field_paths(max_depth)
+            .into_iter()
+            .map(|(path, nested)| SchemaField {
+                name: path,
+                field_type: nested
+                    .schema_type
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string()),
+                description: nested.description.clone(),
+                flags: FieldFlags::of(nested),
+            }),
+    );
+    fields
+}
+
+/// Declares the fields of shared parameters, request bodies and responses
+/// (`components.parameters`, `requestBodies`, `responses`) that no schema
+/// declares, so their endpoints are tracked too. They belong to no schema.
+fn index_shared_components(index: &mut FieldIndex, components: &Components, max_depth: usize) {
+    let mut declared: Vec<(String, SchemaField)> = Vec::new();
+    for (name, parameter) in components.parameters.iter().flatten() {
+        let field_type = parameter
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.schema_type.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        declared.push((
+            name.clone(),
+            SchemaField {
+                name: parameter.name.clone(),
+                field_type,
+                description: parameter.description.clone(),
+                flags: FieldFlags::default(),
+            },
+        ));
+    }
+    let bodies = components
+        .request_bodies
+        .iter()
+        .flatten()
+        .map(|(name, body)| (name, Some(&body.content)));
+    let responses = components
+        .responses
+        .iter()
+        .flatten()
+        .map(|(name, response)| (name, response.content.as_ref()));
+    for (name, content) in bodies.chain(responses) {
+        for media_type in content.into_iter().flat_map(|content| content.values()) {
+            if let Some(schema) = &media_type.schema {
+                declared.extend(
+                    schema_fields(schema, max_depth)
+                        .into_iter()
+                        .map(|field| (name.clone(), field)),
+                );
+            }
+        }
+    }
+
+    // Component name order keeps the first declaration stable
+    declared.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, field) in declared {
+        index.fields.entry(field.name).or_insert_with(|| FieldData {
+            field_type: field.field_type,
+            description: field.description,
+            schemas: Vec::new(),
+            endpoints: HashSet::new(),
+            methods: BTreeSet::new(),
+            flags: Vec::new(),
+        });
+    }
+}
+
+/// Endpoint key of every `operationId`, paths then webhooks and callbacks.
+/// A duplicated id keeps its first endpoint in key order.
+pub fn collect_operation_ids(openapi_spec: &OpenApiSpec) -> HashMap<String, String> {
+    let mut operations: Vec<(String, &Operation)> = openapi_spec
+        .paths
+        .iter()
+        .map(|(path, item)| (path.clone(), item))
+        .chain(openapi_spec.event_targets())
+        .flat_map(|(target, item)| {
+            item.operations.iter().map(move |(method, operation)| {
+                (format!("{} {}", method.to_uppercase(), target), operation)
+            })
+        })
+        .collect();
+    operations.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut operation_ids = HashMap::new();
+    for (endpoint, operation) in operations {
+        let Some(operation_id) = &operation.operation_id else {
+            continue;
+        };
+        match operation_ids.entry(operation_id.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(endpoint);
+            }
+            Entry::Occupied(entry) => log::warn!(
+                "Duplicate operationId '{}' on {} (already used by {})",
+                operation_id,
+                endpoint,
+                entry.get()
+            ),
+        }
+    }
+    operation_ids
+}
+
+/// Response headers of every operation, keyed by header name as declared.
+/// The first declaration (in endpoint order) provides type and description.
+pub fn collect_response_headers(openapi_spec: &OpenApiSpec) -> HashMap<String, HeaderData> {
+    let mut operations: Vec<(String, &Operation)> = openapi_spec
+        .paths
+        .iter()
+        .flat_map(|(path, path_item)| {
+            path_item.operations.iter().map(move |(method, operation)| {
+                (format!("{} {}", method.to_uppercase(), path), operation)
+            })
+        })
+        .collect();
+    operations.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut headers: HashMap<String, HeaderData> = HashMap::new();
+    for (endpoint_key, operation) in operations {
+        let mut statuses: Vec<&String> = operation.responses.keys().collect();
+        statuses.sort();
+        for status in statuses {
+            let response = &operation.responses[status];
+            for (name, header) in response.headers.iter().flatten() {
+                let data = headers.entry(name.clone()).or_insert_with(|| HeaderData {
+                    header_type: header
+                        .schema
+                        .as_ref()
+                        .and_then(|schema| schema.schema_type.clone())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    description: header.description.clone(),
+                    endpoints: BTreeMap::new(),
+                });
+                data.endpoints
+                    .entry(endpoint_key.clone())
+                    .or_default()
+                    .push(status.clone());
+            }
+        }
+    }
+    headers
+}
+
+pub fn collect_responses(openapi_spec: &OpenApiSpec) -> HashMap<String, ResponseData> {
+    openapi_spec
+        .paths
+        .iter()
+        .flat_map(|(path, path_item)| {
+            path_item.operations.iter().map(move |(method, operation)| {
+                let mut statuses: Vec<String> = operation.responses.keys().cloned().collect();
+                statuses.sort_by_key(|status| status_sort_key(status));
+                let secured = operation
+                    .extension("security")
+                    .and_then(|security| security.as_array())
+                    .is_some_and(|requirements| !requirements.is_empty());
+                (
+                    format!("{} {}", method.to_uppercase(), path),
+                    ResponseData { statuses, secured },
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn collect_content_types(openapi_spec: &OpenApiSpec) -> HashMap<String, ContentTypeData> {
+    let sorted = |media_types: BTreeSet<&String>| media_types.into_iter().cloned().collect();
+    openapi_spec
+        .paths
+        .iter()
+        .flat_map(|(path, path_item)| {
+            path_item.operations.iter().map(move |(method, operation)| {
+                let requests = operation
+                    .request_body
+                    .iter()
+                    .flat_map(|body| body.content.keys())
+                    .collect();
+                let responses = operation
+                    .responses
+                    .values()
+                    .flat_map(|response| response.content.iter().flatten())
+                    .map(|(media_type, _)| media_type)
+                    .collect();
+                (
+                    format!("{} {}", method.to_uppercase(), path),
+                    ContentTypeData {
+                        requests: sorted(requests),
+                        responses: sorted(responses),
+                    },
+                )
+            })
+        })
+        .collect()
+}
+
+/// Form fields of every path operation with a `multipart/*` or
+/// `application/x-www-form-urlencoded` request body, in body then
+/// property name order.
+pub fn collect_form_fields(openapi_spec: &OpenApiSpec) -> HashMap<String, Vec<FormField>> {
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    let mut form_fields = HashMap::new();
     for (path, path_item) in &openapi_spec.paths {
         for (method, operation) in &path_item.operations {
-            let endpoint_key = format!("{} {}", method.to_uppercase(), path);
-            let mut endpoint_fields = Vec::new();
-            log::trace!("Processing endpoint: {}", endpoint_key);
-
-            // Check parameters
-            if let Some(parameters) = &operation.parameters {
-                for param in parameters {
-                    if param.schema.is_some() {
-                        let field_name = param.name.clone();
-                        endpoint_fields.push(field_name.clone());
-                        if let Some(field_data) = index.fields.get_mut(&field_name) {
-                            field_data.endpoints.insert(endpoint_key.clone());
-                        }
-                    }
+            let mut fields = Vec::new();
+            let mut bodies: Vec<_> = operation
+                .request_body
+                .iter()
+                .flat_map(|body| &body.content)
+                .filter(|(media_type, _)| is_form_media_type(media_type))
+                .collect();
+            bodies.sort_by(|a, b| a.0.cmp(b.0));
+            for (media_type, content) in bodies {
+                let Some(schema) = &content.schema else {
+                    continue;
+                };
+                let mut properties: Vec<(&String, &Schema)> = Vec::new();
+                let mut required: Vec<&String> = Vec::new();
+                for part in std::iter::once(schema).chain(schema.all_of.iter().flatten()) {
+                    let part = resolve_schema_ref(part, schemas);
+                    properties.extend(part.properties.iter().flatten());
+                    required.extend(part.required.iter().flatten());
                 }
+                properties.sort_by(|a, b| a.0.cmp(b.0));
+                properties.dedup_by(|a, b| a.0 == b.0);
+                for (name, property) in properties {
+                    let property = resolve_schema_ref(property, schemas);
+                    let encoding = content
+                        .encoding
+                        .as_ref()
+                        .and_then(|encoding| encoding.get(name))
+                        .and_then(|encoding| encoding.content_type.clone());
+                    fields.push(FormField {
+                        name: name.clone(),
+                        field_type: property
+                            .schema_type
+                            .clone()
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        media_type: media_type.clone(),
+                        is_file: is_file_schema(property),
+                        content_type: encoding
+                            .unwrap_or_else(|| default_part_content_type(property).to_string()),
+                        required: required.contains(&name),
+                    });
+                }
+            }
+            if !fields.is_empty() {
+                form_fields.insert(format!("{} {}", method.to_uppercase(), path), fields);
             }
+        }
+    }
+    form_fields
+}
 
-            // Check request body
-            if let Some(request_body) = &operation.request_body {
-                for media_type in request_body.content.values() {
-                    if let Some(schema) = &media_type.schema {
-                        let body_fields = extract_fields_from_schema(schema);
-                        for field in body_fields {
-                            endpoint_fields.push(field.clone());
-                            if let Some(field_data) = index.fields.get_mut(&field) {
-                                field_data.endpoints.insert(endpoint_key.clone());
-                            }
-                        }
-                    }
+fn is_form_media_type(media_type: &str) -> bool {
+    let media_type = media_type.to_ascii_lowercase();
+    media_type.starts_with("multipart/") || media_type == "application/x-www-form-urlencoded"
+}
+
+/// The component schema a `$ref` points to, else the schema itself.
+pub(crate) fn resolve_schema_ref<'a>(
+    schema: &'a Schema,
+    schemas: Option<&'a HashMap<String, Schema>>,
+) -> &'a Schema {
+    schema
+        .reference
+        .as_deref()
+        .and_then(|reference| reference.strip_prefix("#/components/schemas/"))
+        .and_then(|name| schemas?.get(name))
+        .unwrap_or(schema)
+}
+
+/// A file part: a binary or base64 string, or one with a media type; an
+/// array of those uploads several files.
+fn is_file_schema(schema: &Schema) -> bool {
+    if schema.schema_type.as_deref() == Some("array") {
+        return schema.items.as_deref().is_some_and(is_file_schema);
+    }
+    schema.content_media_type.is_some()
+        || matches!(schema.format.as_deref(), Some("binary" | "base64"))
+}
+
+/// Part content type when `encoding` names none: `application/octet-stream`
+/// for files, `text/plain` for primitives, `application/json` for objects.
+fn default_part_content_type(schema: &Schema) -> &str {
+    if let Some(media_type) = &schema.content_media_type {
+        return media_type;
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => schema
+            .items
+            .as_deref()
+            .map_or("text/plain", default_part_content_type),
+        _ if is_file_schema(schema) => "application/octet-stream",
+        Some("object") => "application/json",
+        _ => "text/plain",
+    }
+}
+
+/// Orders status codes by class, a class's `4XX` range after its codes and
+/// `default` (or anything unrecognised) last.
+pub fn status_sort_key(status: &str) -> (u8, bool, String) {
+    match status.as_bytes().first() {
+        Some(class @ b'1'..=b'5') => {
+            let status = status.to_ascii_uppercase();
+            (*class, status.ends_with("XX"), status)
+        }
+        _ => (u8::MAX, false, status.to_ascii_lowercase()),
+    }
+}
+
+/// Fields used by an operation: parameters, then request body, then
+/// response properties, each schema's nested paths after its own fields.
+fn operation_fields(operation: &Operation, max_depth: usize) -> Vec<EndpointField> {
+    let mut fields = Vec::new();
+    let mut push = |name: String, origin: &FieldOrigin| {
+        let field = EndpointField {
+            name,
+            origin: origin.clone(),
+        };
+        // Media types of one body or response repeat the same fields
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    };
+    let schema_fields = |schema: &Schema| {
+        let mut names: Vec<String> = extract_fields_from_schema(schema)
+            .into_iter()
+            .chain(nested_paths(schema, max_depth))
+            .collect();
+        names.sort_unstable();
+        names
+    };
+
+    // Check parameters
+    for param in operation.parameters.iter().flatten() {
+        if param.schema.is_some() {
+            push(param.name.clone(), &FieldOrigin::of_parameter(&param.in_));
+        }
+    }
+
+    // Check request body
+    if let Some(request_body) = &operation.request_body {
+        for media_type in request_body.content.values() {
+            if let Some(schema) = &media_type.schema {
+                for name in schema_fields(schema) {
+                    push(name, &FieldOrigin::RequestBody);
                 }
             }
+        }
+    }
 
-            // Check responses
-            for response in operation.responses.values() {
-                if let Some(content) = &response.content {
-                    for media_type in content.values() {
-                        if let Some(schema) = &media_type.schema {
-                            let response_fields = extract_fields_from_schema(schema);
-                            for field in response_fields {
-                                endpoint_fields.push(field.clone());
-                                if let Some(field_data) = index.fields.get_mut(&field) {
-                                    field_data.endpoints.insert(endpoint_key.clone());
-                                }
-                            }
-                        }
-                    }
+    // Check responses, by status so the field tree is stable
+    let mut statuses: Vec<&String> = operation.responses.keys().collect();
+    statuses.sort_unstable();
+    for status in statuses {
+        let origin = FieldOrigin::Response(status.clone());
+        for media_type in operation.responses[status]
+            .content
+            .iter()
+            .flat_map(|c| c.values())
+        {
+            if let Some(schema) = &media_type.schema {
+                for name in schema_fields(schema) {
+                    push(name, &origin);
                 }
             }
-
-            index.endpoint_fields.insert(endpoint_key, endpoint_fields);
         }
     }
 
-    index
+    fields
+}
+
+fn nested_paths(schema: &Schema, max_depth: usize) -> impl Iterator<Item = String> + '_ {
+    schema
+        .nested_field_paths(max_depth)
+        .into_iter()
+        .map(|(path, _)| path)
 }
 
 pub fn analyze_field_relationships(index: &FieldIndex) -> HashMap<String, Vec<String>> {
@@ -165,7 +981,7 @@ pub fn analyze_field_relationships(index: &FieldIndex) -> HashMap<String, Vec<St
 
         // Find fields that appear in the same schemas
         for schema_name in &field_data.schemas {
-            if let Some(schema) = index.schemas.get(schema_name) {
+            if let Some(schema) = index.schemas.get(&**schema_name) {
                 let schema_fields = schema.get_field_names();
                 for other_field in schema_fields {
                     if other_field != *field_name {
@@ -184,7 +1000,7 @@ pub fn analyze_field_relationships(index: &FieldIndex) -> HashMap<String, Vec<St
     relationships
 }
 
-fn extract_fields_from_schema(schema: &crate::parser::Schema) -> Vec<String> {
+pub fn extract_fields_from_schema(schema: &crate::parser::Schema) -> Vec<String> {
     let mut fields = Vec::new();
 
     // Direct properties
@@ -228,11 +1044,16 @@ mod tests {
     fn create_test_spec() -> OpenApiSpec {
         OpenApiSpec {
             openapi: "3.0.0".to_string(),
+            webhooks: None,
             info: Info {
                 title: "Test API".to_string(),
                 version: "1.0.0".to_string(),
                 description: None,
+                terms_of_service: None,
+                contact: None,
+                license: None,
             },
+            servers: None,
             paths: HashMap::from([(
                 "/users".to_string(),
                 PathItem {
@@ -256,6 +1077,10 @@ mod tests {
                                 }]),
                                 request_body: None,
                                 responses: HashMap::new(),
+                                deprecated: None,
+                                external_docs: None,
+                                callbacks: None,
+                                extensions: HashMap::new(),
                             },
                         ),
                         (
@@ -268,6 +1093,7 @@ mod tests {
                                 parameters: None,
                                 request_body: Some(crate::parser::RequestBody {
                                     description: None,
+                                    required: None,
                                     content: HashMap::from([(
                                         "application/json".to_string(),
                                         crate::parser::MediaType {
@@ -292,15 +1118,27 @@ mod tests {
                                                 ])),
                                                 ..Default::default()
                                             }),
+                                            encoding: None,
                                         },
                                     )]),
                                 }),
                                 responses: HashMap::new(),
+                                deprecated: None,
+                                external_docs: None,
+                                callbacks: None,
+                                extensions: HashMap::new(),
                             },
                         ),
                     ]),
                 },
             )]),
+            tags: None,
+            external_docs: None,
+            parse_warnings: Vec::new(),
+            referenced_components: Default::default(),
+            unresolved_refs: Vec::new(),
+            callback_targets: Default::default(),
+            explorer_ignore: None,
             components: Some(Components {
                 schemas: Some(HashMap::from([(
                     "User".to_string(),
@@ -327,6 +1165,7 @@ mod tests {
                         ..Default::default()
                     },
                 )])),
+                ..Default::default()
             }),
         }
     }
@@ -360,7 +1199,7 @@ mod tests {
         // Check field data
         let id_field = index.fields.get("id").unwrap();
         assert_eq!(id_field.field_type, "integer");
-        assert!(id_field.schemas.contains(&"User".to_string()));
+        assert!(id_field.schemas.contains(&Arc::from("User")));
         assert!(!id_field.endpoints.is_empty());
 
         // Check endpoints
@@ -390,6 +1229,83 @@ mod tests {
         assert!(!index.is_critical_field("nonexistent"));
     }
 
+    #[test]
+    fn test_names_are_shared_across_fields() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/users": {"post": {"requestBody": {"content": {"application/json": {
+                    "schema": {"type": "object", "properties": {"id": {"type": "integer"}, "email": {"type": "string"}}}
+                }}}, "responses": {}}}},
+                "components": {"schemas": {"User": {"type": "object", "properties": {
+                    "id": {"type": "integer"},
+                    "email": {"type": "string"}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+        let shared = |index: &FieldIndex| {
+            let (id, email) = (&index.fields["id"], &index.fields["email"]);
+            Arc::ptr_eq(&id.schemas[0], &email.schemas[0])
+                && Arc::ptr_eq(
+                    id.endpoints.iter().next().unwrap(),
+                    email.endpoints.iter().next().unwrap(),
+                )
+        };
+
+        let index = build_field_index(&spec);
+        assert!(shared(&index));
+
+        // Deserializing allocates each name again until they are re-shared
+        let mut cached: FieldIndex =
+            serde_json::from_str(&serde_json::to_string(&index).unwrap()).unwrap();
+        assert!(!shared(&cached));
+        cached.share_names();
+        assert!(shared(&cached));
+    }
+
+    #[test]
+    fn test_delete_and_patch_are_write_methods() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/users/{id}": {
+                    "get": {"responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"type": "object", "properties": {"nickname": {"type": "string"}}}}}}}},
+                    "patch": {"requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {"email": {"type": "string"}}}}}}, "responses": {}},
+                    "delete": {"responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"type": "object", "properties": {"deleted_at": {"type": "string"}}}}}}}}
+                }},
+                "components": {"schemas": {"User": {"type": "object", "properties": {
+                    "nickname": {"type": "string"},
+                    "email": {"type": "string"},
+                    "deleted_at": {"type": "string"}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+
+        let index = build_field_index(&spec);
+        assert_eq!(
+            index.fields["email"].methods,
+            BTreeSet::from([HttpMethod::Patch])
+        );
+        assert!(index.is_critical_field("email"));
+        assert!(index.is_critical_field("deleted_at"));
+        assert!(!index.is_critical_field("nickname"));
+
+        assert_eq!(
+            HttpMethod::of_endpoint("DELETE /users/{id}"),
+            Some(HttpMethod::Delete)
+        );
+        assert_eq!(
+            HttpMethod::of_endpoint("POST webhook:newPet"),
+            Some(HttpMethod::Post)
+        );
+        assert_eq!(HttpMethod::parse("connect"), None);
+        assert!(!HttpMethod::Options.is_write());
+    }
+
     #[test]
     fn test_get_schema_fields() {
         let spec = create_test_spec();
@@ -404,4 +1320,325 @@ mod tests {
         let empty_fields = index.get_schema_fields("NonExistent");
         assert!(empty_fields.is_empty());
     }
+
+    #[test]
+    fn test_response_headers_are_indexed() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/users": {"get": {"responses": {
+                        "200": {"description": "ok", "headers": {
+                            "X-Next-Cursor": {"description": "Next page", "schema": {"type": "string"}},
+                            "X-RateLimit-Remaining": {"$ref": "#/components/headers/RateLimit"}
+                        }},
+                        "429": {"description": "slow down", "headers": {
+                            "X-RateLimit-Remaining": {"$ref": "#/components/headers/RateLimit"}
+                        }}
+                    }}},
+                    "/orders": {"post": {"responses": {"201": {"description": "created", "headers": {
+                        "X-RateLimit-Remaining": {"$ref": "#/components/headers/RateLimit"}
+                    }}}}}
+                },
+                "components": {"headers": {"RateLimit": {
+                    "description": "Requests left", "schema": {"type": "integer"}
+                }}}
+            }"##,
+        )
+        .unwrap();
+
+        let index = build_field_index(&spec);
+        assert_eq!(index.headers.len(), 2);
+
+        let rate_limit = &index.headers["X-RateLimit-Remaining"];
+        assert_eq!(rate_limit.header_type, "integer");
+        assert_eq!(rate_limit.description.as_deref(), Some("Requests left"));
+        assert_eq!(rate_limit.endpoints["GET /users"], vec!["200", "429"]);
+        assert_eq!(rate_limit.endpoints["POST /orders"], vec!["201"]);
+        assert_eq!(
+            index.headers["X-Next-Cursor"]
+                .endpoints
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["GET /users"]
+        );
+    }
+
+    #[test]
+    fn test_event_payload_fields_are_indexed() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/subscriptions": {"post": {
+                    "responses": {},
+                    "callbacks": {"onEvent": {"{$request.body#/callbackUrl}": {"post": {
+                        "requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {"event_id": {"type": "string"}}}}}},
+                        "responses": {}
+                    }}}}
+                }}},
+                "webhooks": {"newPet": {"post": {
+                    "requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {"pet_id": {"type": "string"}}}}}},
+                    "responses": {}
+                }}},
+                "components": {"schemas": {
+                    "Pet": {"type": "object", "properties": {"pet_id": {"type": "string"}}},
+                    "Event": {"type": "object", "properties": {"event_id": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let index = build_field_index(&spec);
+        assert_eq!(
+            index.get_endpoints_for_field("pet_id"),
+            vec!["POST webhook:newPet"]
+        );
+        assert_eq!(
+            index.get_endpoints_for_field("event_id"),
+            vec!["POST callback:POST /subscriptions/onEvent"]
+        );
+        assert_eq!(
+            index.endpoint_field_names("POST webhook:newPet"),
+            vec!["pet_id"]
+        );
+        assert!(index.endpoint_fields.contains_key("POST /subscriptions"));
+    }
+
+    #[test]
+    fn test_endpoint_fields_record_their_origin() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/orders/{id}": {"put": {
+                    "parameters": [
+                        {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}},
+                        {"name": "dryRun", "in": "query", "schema": {"type": "boolean"}}
+                    ],
+                    "requestBody": {"content": {
+                        "application/json": {"schema": {"type": "object", "properties": {"status": {"type": "string"}}}},
+                        "application/xml": {"schema": {"type": "object", "properties": {"status": {"type": "string"}}}}
+                    }},
+                    "responses": {
+                        "404": {"description": "missing", "content": {"application/json": {"schema": {
+                            "type": "object", "properties": {"status": {"type": "integer"}}}}}},
+                        "200": {"description": "ok", "content": {"application/json": {"schema": {
+                            "type": "object", "properties": {"id": {"type": "string"}, "status": {"type": "string"}}}}}}
+                    }
+                }}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        let fields: Vec<(&str, &FieldOrigin)> = index.endpoint_fields["PUT /orders/{id}"]
+            .iter()
+            .map(|field| (field.name.as_str(), &field.origin))
+            .collect();
+        let ok = FieldOrigin::Response("200".to_string());
+        let missing = FieldOrigin::Response("404".to_string());
+        assert_eq!(
+            fields,
+            vec![
+                ("id", &FieldOrigin::PathParameter),
+                ("dryRun", &FieldOrigin::QueryParameter),
+                ("status", &FieldOrigin::RequestBody),
+                ("id", &ok),
+                ("status", &ok),
+                ("status", &missing),
+            ]
+        );
+        assert_eq!(
+            index.endpoint_field_names("PUT /orders/{id}"),
+            vec!["id", "dryRun", "status"]
+        );
+        assert_eq!(missing.label(), "Response 404");
+
+        let usages = index.endpoint_field_usages("PUT /orders/{id}");
+        let summaries: Vec<(&str, usize, String)> = usages
+            .iter()
+            .map(|u| (u.name.as_str(), u.occurrences(), u.summary()))
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                ("id", 2, "path parameter + response 200".to_string()),
+                ("dryRun", 1, "query parameter".to_string()),
+                ("status", 3, "request + 2 responses".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_fields_are_indexed_as_paths() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/orders": {"post": {
+                    "requestBody": {"content": {"application/json": {"schema": {
+                        "type": "object",
+                        "properties": {"shipping": {"type": "object", "properties": {
+                            "address": {"type": "object", "properties": {"city": {"type": "string"}}}
+                        }}}
+                    }}}},
+                    "responses": {}
+                }}},
+                "components": {"schemas": {"Order": {"type": "object", "properties": {
+                    "address": {"type": "object", "properties": {
+                        "city": {"type": "string", "description": "City name"},
+                        "geo": {"type": "object", "properties": {"lat": {"type": "number"}}}
+                    }},
+                    "lines": {"type": "array", "items": {"type": "object", "properties": {
+                        "sku": {"type": "string"}
+                    }}}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+
+        let index = build_field_index(&spec);
+        assert_eq!(index.max_depth, DEFAULT_FIELD_DEPTH);
+        let city = &index.fields["address.city"];
+        assert_eq!(city.field_type, "string");
+        assert_eq!(city.description.as_deref(), Some("City name"));
+        assert_eq!(city.schemas, vec![Arc::from("Order")]);
+        assert_eq!(index.fields["lines[].sku"].field_type, "string");
+        assert!(index.fields.contains_key("address.geo.lat"));
+        // Inline operation schemas contribute their nested paths too
+        assert!(index
+            .endpoint_field_names("POST /orders")
+            .contains(&"shipping.address.city"));
+
+        let shallow = build_field_index_with_depth(&spec, 2);
+        assert!(shallow.fields.contains_key("address.geo"));
+        assert!(!shallow.fields.contains_key("address.geo.lat"));
+        let flat = build_field_index_with_depth(&spec, 1);
+        assert!(flat.fields.keys().all(|field| !field.contains('.')));
+    }
+
+    #[test]
+    fn test_recursive_schemas_index_finitely() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Node": {"type": "object", "properties": {
+                        "name": {"type": "string"},
+                        "children": {"type": "array", "items": {"$ref": "#/components/schemas/Node"}},
+                        "tree": {"$ref": "#/components/schemas/Tree"}
+                    }},
+                    "Tree": {"type": "object", "properties": {"root": {"$ref": "#/components/schemas/Node"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        // References are kept, so the cycles end the fields whatever the depth
+        let index = build_field_index_with_depth(&spec, 50);
+        let mut fields: Vec<&String> = index.fields.keys().collect();
+        fields.sort();
+        assert_eq!(fields, vec!["children", "name", "root", "tree"]);
+    }
+
+    #[test]
+    fn test_operation_ids_are_indexed() {
+        let mut spec = create_test_spec();
+        let users = spec.paths.get_mut("/users").unwrap();
+        // A duplicate id keeps the first endpoint in key order
+        users.operations.get_mut("post").unwrap().operation_id = Some("listUsers".to_string());
+
+        let index = build_field_index(&spec);
+        assert_eq!(
+            index
+                .endpoint_for_operation("listUsers")
+                .map(String::as_str),
+            Some("GET /users")
+        );
+        assert!(index.endpoint_for_operation("missing").is_none());
+    }
+
+    #[test]
+    fn test_multipart_form_fields_are_indexed() {
+        let spec = crate::parser::parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/avatars": {"post": {
+                    "requestBody": {"content": {"multipart/form-data": {
+                        "schema": {"$ref": "#/components/schemas/Upload"},
+                        "encoding": {"file": {"contentType": "image/png, image/jpeg"}}
+                    }}},
+                    "responses": {}
+                }}},
+                "components": {"schemas": {"Upload": {"type": "object", "required": ["file"],
+                    "properties": {
+                        "file": {"type": "string", "format": "binary"},
+                        "attachments": {"type": "array", "items": {"type": "string", "contentMediaType": "application/pdf"}},
+                        "meta": {"type": "object"},
+                        "caption": {"type": "string"}
+                    }}}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        let fields = &index.form_fields["POST /avatars"];
+        let summary: Vec<(&str, bool, &str, bool)> = fields
+            .iter()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.is_file,
+                    f.content_type.as_str(),
+                    f.required,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("attachments", true, "application/pdf", false),
+                ("caption", false, "text/plain", false),
+                ("file", true, "image/png, image/jpeg", true),
+                ("meta", false, "application/json", false),
+            ]
+        );
+        assert!(fields.iter().all(|f| f.media_type == "multipart/form-data"));
+    }
+
+    #[test]
+    fn test_field_flags_are_tracked_per_schema() {
+        let spec = crate::parser::parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "id": {"type": "string", "readOnly": true},
+                        "password": {"type": "string", "writeOnly": true},
+                        "nickname": {"type": "string", "nullable": true}}},
+                    "Order": {"allOf": [{"type": "object", "properties": {
+                        "id": {"type": "string"}}}]}
+                }}
+            }"#,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let id = &index.fields["id"];
+        assert_eq!(id.schemas.len(), 2);
+        assert_eq!(id.flags.len(), 1);
+        assert_eq!(&*id.flags[0].0, "User");
+        assert!(id.has_flag(FieldFlag::ReadOnly));
+        assert!(!id.has_flag(FieldFlag::WriteOnly));
+        assert!(index.fields["password"].has_flag(FieldFlag::WriteOnly));
+        let nickname = index.fields["nickname"].flags[0].1;
+        assert_eq!(nickname.iter().collect::<Vec<_>>(), [FieldFlag::Nullable]);
+        assert_eq!(FieldFlag::parse("ReadOnly"), Some(FieldFlag::ReadOnly));
+        assert_eq!(FieldFlag::parse("required"), None);
+    }
 }
@@ -1,11 +1,75 @@
-// Library module to expose internal modules for testing
+// Library module: `Explorer` is the embedding entry point; the modules are
+// exposed for tools that need the lower-level building blocks
 
 pub mod app;
+pub mod asyncapi;
+pub mod bundle;
+pub mod cache;
+pub mod check;
+pub mod clipboard;
+pub mod clusters;
+pub mod codegen;
+pub mod command;
+pub mod compare;
+pub mod compression;
+pub mod config;
+pub mod content_types;
+pub mod coverage;
+pub mod criticality;
+pub mod deprecation;
+pub mod diff;
+pub mod direction;
+pub mod discovery;
+pub mod duplicates;
+pub mod error_skeleton;
+pub mod error_taxonomy;
 pub mod events;
+pub mod example;
+pub mod explorer;
+pub mod export;
+pub mod file_browser;
+pub mod graph_export;
+pub mod history;
+pub mod impact;
 pub mod indexer;
+pub mod line_editor;
+pub mod lint;
+pub mod marks;
+pub mod merge;
+pub mod meta_schema;
+pub mod mock;
+pub mod monitor;
+pub mod multi_spec;
+pub mod notifications;
+pub mod pagination;
+pub mod parse_error;
 pub mod parser;
+pub mod path_tree;
+pub mod payload;
+pub mod progress;
+pub mod remote;
+pub mod rename;
+pub mod report;
+pub mod responses;
+pub mod runner;
+pub mod schema_graph;
+pub mod schema_tree;
+pub mod search;
+pub mod sensitive;
+pub mod servers;
+pub mod sorting;
+pub mod spec_dir;
+pub mod stats;
+pub mod synthetic;
+pub mod ui;
+pub mod validate;
+pub mod view_model;
+pub mod workflows;
+pub mod workspace;
+pub mod writeback;
 
 // Re-export commonly used types
 pub use app::App;
+pub use explorer::Explorer;
 pub use indexer::{FieldData, FieldIndex};
 pub use parser::{OpenApiSpec, Schema};
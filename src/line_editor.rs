@@ -0,0 +1,114 @@
+//! Cursor-aware editing of single-line inputs. The cursor is a character
+//! index into the text, from 0 to its length.
+
+/// Byte offset of the character at `cursor`.
+fn byte_offset(text: &str, cursor: usize) -> usize {
+    text.char_indices()
+        .nth(cursor)
+        .map(|(offset, _)| offset)
+        .unwrap_or(text.len())
+}
+
+/// Inserts typed or pasted text at the cursor and moves past it. Line
+/// breaks of a multi-line paste become spaces.
+pub fn insert(text: &mut String, cursor: &mut usize, input: &str) {
+    let input: String = input
+        .chars()
+        .map(|ch| if ch == '\n' || ch == '\r' { ' ' } else { ch })
+        .collect();
+    text.insert_str(byte_offset(text, *cursor), &input);
+    *cursor += input.chars().count();
+}
+
+/// Backspace.
+pub fn delete_before(text: &mut String, cursor: &mut usize) {
+    if *cursor > 0 {
+        *cursor -= 1;
+        text.remove(byte_offset(text, *cursor));
+    }
+}
+
+/// Delete.
+pub fn delete_at(text: &mut String, cursor: &mut usize) {
+    if *cursor < text.chars().count() {
+        text.remove(byte_offset(text, *cursor));
+    }
+}
+
+/// Ctrl+W: the word before the cursor and the spaces after it.
+pub fn delete_word_before(text: &mut String, cursor: &mut usize) {
+    let start = word_start(text, *cursor);
+    text.replace_range(byte_offset(text, start)..byte_offset(text, *cursor), "");
+    *cursor = start;
+}
+
+pub fn move_left(cursor: &mut usize) {
+    *cursor = cursor.saturating_sub(1);
+}
+
+pub fn move_right(text: &str, cursor: &mut usize) {
+    *cursor = (*cursor + 1).min(text.chars().count());
+}
+
+pub fn move_word_left(text: &str, cursor: &mut usize) {
+    *cursor = word_start(text, *cursor);
+}
+
+pub fn move_word_right(text: &str, cursor: &mut usize) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut position = *cursor;
+    while position < chars.len() && chars[position].is_whitespace() {
+        position += 1;
+    }
+    while position < chars.len() && !chars[position].is_whitespace() {
+        position += 1;
+    }
+    *cursor = position;
+}
+
+/// Start of the word before `cursor`, skipping the spaces in between.
+fn word_start(text: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = text.chars().take(cursor).collect();
+    let mut position = chars.len();
+    while position > 0 && chars[position - 1].is_whitespace() {
+        position -= 1;
+    }
+    while position > 0 && !chars[position - 1].is_whitespace() {
+        position -= 1;
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_at_the_cursor() {
+        let mut text = String::from("user id");
+        let mut cursor = 4;
+        insert(&mut text, &mut cursor, "_é");
+        assert_eq!((text.as_str(), cursor), ("user_é id", 6));
+
+        delete_before(&mut text, &mut cursor);
+        move_right(&text, &mut cursor);
+        delete_at(&mut text, &mut cursor);
+        assert_eq!((text.as_str(), cursor), ("user_ d", 6));
+
+        move_word_left(&text, &mut cursor);
+        assert_eq!(cursor, 0);
+        move_word_right(&text, &mut cursor);
+        assert_eq!(cursor, 5);
+
+        cursor = text.chars().count();
+        insert(&mut text, &mut cursor, "ata\nset ");
+        assert_eq!(text, "user_ data set ");
+        delete_word_before(&mut text, &mut cursor);
+        assert_eq!((text.as_str(), cursor), ("user_ data ", 11));
+
+        move_left(&mut cursor);
+        move_right(&text, &mut cursor);
+        move_right(&text, &mut cursor);
+        assert_eq!(cursor, 11);
+    }
+}
@@ -0,0 +1,1200 @@
+use crate::direction::{schema_directions, SchemaDirection};
+use crate::indexer::{resolve_schema_ref, FieldIndex};
+use crate::parser::{OpenApiSpec, Schema};
+use crate::schema_graph::build_schema_graph;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// operationIds are expected in lower camel case, as most code generators
+/// name methods after them
+pub const DEFAULT_OPERATION_ID_PATTERN: &str = r"^[a-z][A-Za-z0-9]*$";
+
+/// Extension turning rules off for the document, an operation or a
+/// component schema: `true` for every rule, else a rule id or a list of them
+pub const IGNORE_EXTENSION: &str = "x-explorer-ignore";
+
+/// Ids of the [`lint_spec`] rules not tied to a [`LintRule`]
+pub const SPEC_RULES: &[&str] = &[
+    "skipped_invalid",
+    "unresolved_ref",
+    "no_components",
+    "no_schemas",
+    "no_paths",
+    "unknown_field_type",
+    "path_without_operations",
+    "missing_description",
+    "unused_schema",
+    "overdue_sunset",
+];
+
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    /// operationIds not matching it are reported
+    pub operation_id_pattern: Regex,
+    /// Report strings without maxLength and arrays without maxItems
+    pub require_bounds: bool,
+    /// Check the document against the OpenAPI JSON Schema (see
+    /// [`crate::meta_schema`])
+    pub strict: bool,
+    /// Rules turned off by the config file
+    pub ignore: Vec<Ignore>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            operation_id_pattern: Regex::new(DEFAULT_OPERATION_ID_PATTERN)
+                .expect("built-in pattern is valid"),
+            require_bounds: false,
+            strict: false,
+            ignore: Vec::new(),
+        }
+    }
+}
+
+impl LintConfig {
+    /// Default settings, with `pattern` for operationIds when given.
+    pub fn with_operation_id_pattern(pattern: Option<&str>) -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(pattern) = pattern {
+            config.operation_id_pattern = Regex::new(pattern)
+                .with_context(|| format!("Invalid operationId pattern '{}'", pattern))?;
+        }
+        Ok(config)
+    }
+
+    pub fn with_require_bounds(mut self, require_bounds: bool) -> Self {
+        self.require_bounds = require_bounds;
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Turns off the rules of the config's ignore list (see [`Ignore`]).
+    pub fn with_ignored(mut self, entries: &[String]) -> Result<Self> {
+        self.ignore = entries
+            .iter()
+            .map(|entry| entry.parse())
+            .collect::<Result<_>>()?;
+        Ok(self)
+    }
+}
+
+/// Entry of the config's ignore list: `rule` turns the rule off everywhere,
+/// `rule:location` at one operation (`GET /users`), path or component
+/// schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ignore {
+    pub rule: String,
+    pub location: Option<String>,
+}
+
+impl std::str::FromStr for Ignore {
+    type Err = anyhow::Error;
+
+    fn from_str(entry: &str) -> Result<Self> {
+        let (rule, location) = match entry.split_once(':') {
+            Some((rule, location)) => (rule.trim(), Some(location.trim().to_string())),
+            None => (entry.trim(), None),
+        };
+        if !is_rule_id(rule) {
+            anyhow::bail!(
+                "Unknown rule '{}' in ignore list (known: {})",
+                rule,
+                rule_ids().join(", ")
+            );
+        }
+        Ok(Self {
+            rule: rule.to_string(),
+            location,
+        })
+    }
+}
+
+/// How much a diagnostic matters, most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+    Hint,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Hint => "hint",
+        })
+    }
+}
+
+/// Entry of the validation warnings of the Stats view.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Id of the rule raising it, to turn it off with
+    pub rule: &'static str,
+    pub message: String,
+    /// Turned off by [`IGNORE_EXTENSION`] or the config's ignore list; kept
+    /// to be counted
+    pub suppressed: bool,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Check a [`LintFinding`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// `{param}` in a path template without a path parameter for it
+    UndeclaredPathParameter,
+    /// Path parameter that does not appear in the path template
+    UnusedPathParameter,
+    /// Parameter at the same position of related paths named differently
+    InconsistentPathParameter,
+    MissingOperationId,
+    /// operationId shared by several operations, which breaks codegen
+    DuplicateOperationId,
+    /// operationId not matching the configured pattern
+    OperationIdNaming,
+    /// Request body requiring a `readOnly` property, which clients cannot
+    /// send
+    ReadOnlyRequiredInRequest,
+    /// `writeOnly` property of a schema only ever returned, so never
+    /// populated
+    WriteOnlyInResponse,
+    /// String property without `maxLength` (with `require_bounds`)
+    UnboundedString,
+    /// Array property without `maxItems` (with `require_bounds`)
+    UnboundedArray,
+    /// Document not matching the official OpenAPI JSON Schema (with
+    /// `strict`), located by JSON pointer
+    MetaSchema,
+}
+
+impl LintRule {
+    pub const ALL: &'static [LintRule] = &[
+        LintRule::UndeclaredPathParameter,
+        LintRule::UnusedPathParameter,
+        LintRule::InconsistentPathParameter,
+        LintRule::MissingOperationId,
+        LintRule::DuplicateOperationId,
+        LintRule::OperationIdNaming,
+        LintRule::ReadOnlyRequiredInRequest,
+        LintRule::WriteOnlyInResponse,
+        LintRule::UnboundedString,
+        LintRule::UnboundedArray,
+        LintRule::MetaSchema,
+    ];
+
+    /// Id of the rule, as serialized
+    pub fn id(self) -> &'static str {
+        match self {
+            LintRule::UndeclaredPathParameter => "undeclared_path_parameter",
+            LintRule::UnusedPathParameter => "unused_path_parameter",
+            LintRule::InconsistentPathParameter => "inconsistent_path_parameter",
+            LintRule::MissingOperationId => "missing_operation_id",
+            LintRule::DuplicateOperationId => "duplicate_operation_id",
+            LintRule::OperationIdNaming => "operation_id_naming",
+            LintRule::ReadOnlyRequiredInRequest => "read_only_required_in_request",
+            LintRule::WriteOnlyInResponse => "write_only_in_response",
+            LintRule::UnboundedString => "unbounded_string",
+            LintRule::UnboundedArray => "unbounded_array",
+            LintRule::MetaSchema => "meta_schema",
+        }
+    }
+
+    /// Invalid documents are errors, what breaks clients or codegen
+    /// warnings, and style or sizing advice hints.
+    pub fn severity(self) -> Severity {
+        match self {
+            LintRule::UndeclaredPathParameter
+            | LintRule::UnusedPathParameter
+            | LintRule::DuplicateOperationId
+            | LintRule::MetaSchema => Severity::Error,
+            LintRule::InconsistentPathParameter
+            | LintRule::MissingOperationId
+            | LintRule::ReadOnlyRequiredInRequest
+            | LintRule::WriteOnlyInResponse => Severity::Warning,
+            LintRule::OperationIdNaming | LintRule::UnboundedString | LintRule::UnboundedArray => {
+                Severity::Hint
+            }
+        }
+    }
+}
+
+fn rule_ids() -> Vec<&'static str> {
+    LintRule::ALL
+        .iter()
+        .map(|rule| rule.id())
+        .chain(SPEC_RULES.iter().copied())
+        .collect()
+}
+
+fn is_rule_id(id: &str) -> bool {
+    SPEC_RULES.contains(&id) || LintRule::ALL.iter().any(|rule| rule.id() == id)
+}
+
+/// Warning tied to a place in the spec.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    /// Operation (`GET /users/{id}`), path or component schema the finding
+    /// is about
+    pub location: String,
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// Structural warnings about a spec: missing components or paths, untyped
+/// fields, undocumented operations, unused schemas and overdue removals,
+/// then the findings of [`lint_findings`]. Suppressed ones are included,
+/// flagged.
+pub fn lint_spec(
+    openapi_spec: &OpenApiSpec,
+    index: &FieldIndex,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let suppressed =
+        |rule: &str, location: Option<&str>| is_suppressed(openapi_spec, config, rule, location);
+    let mut warnings = Vec::new();
+    let mut warn = |severity, rule: &'static str, message: String, suppressed: bool| {
+        warnings.push(Diagnostic {
+            severity,
+            rule,
+            message,
+            suppressed,
+        })
+    };
+
+    // Check for empty or missing components
+    if let Some(components) = &openapi_spec.components {
+        match &components.schemas {
+            Some(schemas) if !schemas.is_empty() => {}
+            _ => warn(
+                Severity::Hint,
+                "no_schemas",
+                "No schemas defined in components".to_string(),
+                suppressed("no_schemas", None),
+            ),
+        }
+    } else {
+        warn(
+            Severity::Warning,
+            "no_components",
+            "No components section found in OpenAPI spec".to_string(),
+            suppressed("no_components", None),
+        );
+    }
+
+    // Check for paths
+    if openapi_spec.paths.is_empty() {
+        warn(
+            Severity::Warning,
+            "no_paths",
+            "No paths/endpoints defined in spec".to_string(),
+            suppressed("no_paths", None),
+        );
+    }
+
+    // Check for fields without types; ignoring the rule on every schema
+    // declaring the field suppresses it
+    for (field_name, field_data) in &index.fields {
+        if field_data.field_type == "unknown" {
+            let ignored_by_schemas = !field_data.schemas.is_empty()
+                && field_data
+                    .schemas
+                    .iter()
+                    .all(|schema| suppressed("unknown_field_type", Some(schema)));
+            warn(
+                Severity::Warning,
+                "unknown_field_type",
+                format!("Field '{}' has unknown type", field_name),
+                ignored_by_schemas || suppressed("unknown_field_type", None),
+            );
+        }
+    }
+
+    // Check for endpoints without operations
+    for (path, path_item) in &openapi_spec.paths {
+        if path_item.operations.is_empty() {
+            warn(
+                Severity::Warning,
+                "path_without_operations",
+                format!("Path '{}' has no operations defined", path),
+                suppressed("path_without_operations", Some(path)),
+            );
+        }
+    }
+
+    // Check for missing descriptions, counting suppressed ones apart
+    let mut missing_descriptions = [0, 0];
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            if operation.description.is_none() && operation.summary.is_none() {
+                let location = format!("{} {}", method.to_uppercase(), path);
+                missing_descriptions
+                    [suppressed("missing_description", Some(&location)) as usize] += 1;
+            }
+        }
+    }
+    for (count, is_suppressed) in missing_descriptions.into_iter().zip([false, true]) {
+        if count > 0 {
+            warn(
+                Severity::Hint,
+                "missing_description",
+                format!("{} endpoint(s) missing description/summary", count),
+                is_suppressed,
+            );
+        }
+    }
+
+    // Check for schemas not used in any endpoint
+    let mut unused_schemas = [0, 0];
+    for schema_name in index.schemas.keys() {
+        let is_used = index.fields.values().any(|field_data| {
+            field_data.schemas.iter().any(|s| **s == **schema_name)
+                && !field_data.endpoints.is_empty()
+        });
+        if !is_used {
+            unused_schemas[suppressed("unused_schema", Some(schema_name)) as usize] += 1;
+        }
+    }
+    for (count, is_suppressed) in unused_schemas.into_iter().zip([false, true]) {
+        if count > 0 {
+            warn(
+                Severity::Hint,
+                "unused_schema",
+                format!("{} schema(s) not used in any endpoint", count),
+                is_suppressed,
+            );
+        }
+    }
+
+    // Check for deprecated endpoints past their sunset date
+    let today = chrono::Utc::now().date_naive();
+    let mut overdue = [0, 0];
+    for deprecation in crate::deprecation::collect_deprecations(openapi_spec)
+        .iter()
+        .filter(|d| d.is_overdue(today))
+    {
+        overdue[suppressed("overdue_sunset", Some(&deprecation.endpoint)) as usize] += 1;
+    }
+    for (count, is_suppressed) in overdue.into_iter().zip([false, true]) {
+        if count > 0 {
+            warn(
+                Severity::Warning,
+                "overdue_sunset",
+                format!("{} deprecated endpoint(s) past their sunset date", count),
+                is_suppressed,
+            );
+        }
+    }
+
+    warnings.extend(
+        all_findings(openapi_spec, config)
+            .iter()
+            .map(|finding| finding_diagnostic(openapi_spec, config, finding)),
+    );
+
+    warnings
+}
+
+/// Elements a lenient parse dropped, then those dropped for a `$ref` that
+/// does not resolve, as diagnostics.
+pub fn parse_warning_diagnostics(
+    openapi_spec: &OpenApiSpec,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let suppressed = is_suppressed(openapi_spec, config, "skipped_invalid", None);
+    let mut diagnostics: Vec<Diagnostic> = openapi_spec
+        .parse_warnings
+        .iter()
+        .map(|warning| Diagnostic {
+            severity: Severity::Error,
+            rule: "skipped_invalid",
+            message: format!("Skipped invalid {}", warning),
+            suppressed,
+        })
+        .collect();
+    let suppressed = is_suppressed(openapi_spec, config, "unresolved_ref", None);
+    diagnostics.extend(
+        openapi_spec
+            .unresolved_refs
+            .iter()
+            .map(|unresolved| Diagnostic {
+                severity: Severity::Error,
+                rule: "unresolved_ref",
+                message: format!("Dropped {}", unresolved),
+                suppressed,
+            }),
+    );
+    diagnostics
+}
+
+/// `finding` as a diagnostic of its rule.
+pub fn finding_diagnostic(
+    openapi_spec: &OpenApiSpec,
+    config: &LintConfig,
+    finding: &LintFinding,
+) -> Diagnostic {
+    Diagnostic {
+        severity: finding.rule.severity(),
+        rule: finding.rule.id(),
+        message: finding.to_string(),
+        suppressed: is_finding_suppressed(openapi_spec, config, finding),
+    }
+}
+
+/// Whether `rule` is turned off at `location` (an operation, path or
+/// component schema; None for the whole document), by the config's ignore
+/// list or an [`IGNORE_EXTENSION`] on the document, the operation or the
+/// schema.
+pub fn is_suppressed(
+    openapi_spec: &OpenApiSpec,
+    config: &LintConfig,
+    rule: &str,
+    location: Option<&str>,
+) -> bool {
+    let configured = config.ignore.iter().any(|ignore| {
+        ignore.rule == rule
+            && ignore
+                .location
+                .as_deref()
+                .is_none_or(|ignored| Some(ignored) == location)
+    });
+    let inline = || {
+        let location = location?;
+        let operation = crate::export::find_operation(openapi_spec, location)
+            .and_then(|operation| operation.extension(IGNORE_EXTENSION));
+        let schema = openapi_spec
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.as_ref()?.get(location))
+            .and_then(|schema| schema.explorer_ignore.as_ref());
+        Some(ignores(operation, rule) || ignores(schema, rule))
+    };
+    configured || ignores(openapi_spec.explorer_ignore.as_ref(), rule) || inline() == Some(true)
+}
+
+/// Whether a finding is turned off, meta-schema violations by the
+/// operation, path or schema their pointer falls under.
+pub fn is_finding_suppressed(
+    openapi_spec: &OpenApiSpec,
+    config: &LintConfig,
+    finding: &LintFinding,
+) -> bool {
+    let location = match finding.rule {
+        LintRule::MetaSchema => crate::meta_schema::pointer_target(&finding.location),
+        _ => Some(finding.location.clone()),
+    };
+    is_suppressed(openapi_spec, config, finding.rule.id(), location.as_deref())
+}
+
+/// Whether an [`IGNORE_EXTENSION`] value turns `rule` off.
+fn ignores(extension: Option<&serde_json::Value>, rule: &str) -> bool {
+    match extension {
+        Some(serde_json::Value::Bool(all)) => *all,
+        Some(serde_json::Value::String(ignored)) => ignored == rule,
+        Some(serde_json::Value::Array(ignored)) => ignored.iter().any(|ignored| ignored == rule),
+        _ => false,
+    }
+}
+
+/// Findings tied to an operation or path, sorted by rule, then location.
+/// Suppressed findings are left out.
+pub fn lint_findings(openapi_spec: &OpenApiSpec, config: &LintConfig) -> Vec<LintFinding> {
+    let mut findings = all_findings(openapi_spec, config);
+    findings.retain(|finding| !is_finding_suppressed(openapi_spec, config, finding));
+    findings
+}
+
+fn all_findings(openapi_spec: &OpenApiSpec, config: &LintConfig) -> Vec<LintFinding> {
+    let mut findings = lint_path_parameters(openapi_spec);
+    findings.extend(lint_operation_ids(
+        openapi_spec,
+        &config.operation_id_pattern,
+    ));
+    findings.extend(lint_read_only_required(openapi_spec));
+    findings.extend(lint_write_only_in_responses(openapi_spec));
+    if config.require_bounds {
+        findings.extend(lint_unbounded_fields(openapi_spec));
+    }
+    sort_findings(&mut findings);
+    findings
+}
+
+fn sort_findings(findings: &mut [LintFinding]) {
+    findings.sort_by(|a, b| {
+        a.rule
+            .cmp(&b.rule)
+            .then(a.location.cmp(&b.location))
+            .then(a.message.cmp(&b.message))
+    });
+}
+
+/// Operations, webhooks and callbacks without an operationId, sharing one,
+/// or with one not matching `pattern`. Sorted by rule, then location.
+pub fn lint_operation_ids(openapi_spec: &OpenApiSpec, pattern: &Regex) -> Vec<LintFinding> {
+    let mut by_id: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut findings = Vec::new();
+    let targets = openapi_spec
+        .paths
+        .iter()
+        .map(|(path, item)| (path.clone(), item))
+        .chain(openapi_spec.event_targets());
+    for (target, path_item) in targets {
+        for (method, operation) in &path_item.operations {
+            let location = format!("{} {}", method.to_uppercase(), target);
+            match operation.operation_id.as_deref() {
+                Some(id) => {
+                    if !pattern.is_match(id) {
+                        findings.push(LintFinding {
+                            rule: LintRule::OperationIdNaming,
+                            location: location.clone(),
+                            message: format!(
+                                "operationId '{}' does not match {}",
+                                id,
+                                pattern.as_str()
+                            ),
+                        });
+                    }
+                    by_id.entry(id).or_default().push(location);
+                }
+                None => findings.push(LintFinding {
+                    rule: LintRule::MissingOperationId,
+                    location,
+                    message: "no operationId".to_string(),
+                }),
+            }
+        }
+    }
+
+    for (id, locations) in by_id
+        .iter_mut()
+        .filter(|(_, locations)| locations.len() > 1)
+    {
+        // Operations are visited in hash order
+        locations.sort();
+        for location in locations.iter() {
+            let others: Vec<&str> = locations
+                .iter()
+                .filter(|other| *other != location)
+                .map(String::as_str)
+                .collect();
+            findings.push(LintFinding {
+                rule: LintRule::DuplicateOperationId,
+                location: location.clone(),
+                message: format!("operationId '{}' is also used by {}", id, others.join(", ")),
+            });
+        }
+    }
+    sort_findings(&mut findings);
+    findings
+}
+
+/// Request bodies listing a `readOnly` property in `required`: response
+/// only fields no client can send. Covers the body schema and its `allOf`
+/// parts, one `$ref` deep. Sorted by location, then field.
+pub fn lint_read_only_required(openapi_spec: &OpenApiSpec) -> Vec<LintFinding> {
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    let mut findings = Vec::new();
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            let Some(body) = &operation.request_body else {
+                continue;
+            };
+            let location = format!("{} {}", method.to_uppercase(), path);
+            let mut fields = BTreeSet::new();
+            for schema in body.content.values().filter_map(|m| m.schema.as_ref()) {
+                let schema = resolve_schema_ref(schema, schemas);
+                let parts = std::iter::once(schema).chain(
+                    schema
+                        .all_of
+                        .iter()
+                        .flatten()
+                        .map(|part| resolve_schema_ref(part, schemas)),
+                );
+                let parts: Vec<&Schema> = parts.collect();
+                for name in parts.iter().flat_map(|part| part.required.iter().flatten()) {
+                    let read_only = parts
+                        .iter()
+                        .filter_map(|part| part.properties.as_ref()?.get(name))
+                        .any(|property| {
+                            resolve_schema_ref(property, schemas).read_only == Some(true)
+                        });
+                    if read_only {
+                        fields.insert(name.as_str());
+                    }
+                }
+            }
+            for name in fields {
+                findings.push(LintFinding {
+                    rule: LintRule::ReadOnlyRequiredInRequest,
+                    location: location.clone(),
+                    message: format!("required field '{}' is readOnly", name),
+                });
+            }
+        }
+    }
+    sort_findings(&mut findings);
+    findings
+}
+
+/// `writeOnly` properties of component schemas that only responses use:
+/// the API never sends them back, so the field is dead. Sorted by schema,
+/// then field.
+pub fn lint_write_only_in_responses(openapi_spec: &OpenApiSpec) -> Vec<LintFinding> {
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    let directions = schema_directions(openapi_spec, &build_schema_graph(openapi_spec));
+    let mut findings = Vec::new();
+    for (schema_name, schema) in schemas.into_iter().flatten() {
+        if directions.get(schema_name) != Some(&SchemaDirection::Response) {
+            continue;
+        }
+        let names: BTreeSet<String> = schema.get_field_names().into_iter().collect();
+        for name in &names {
+            let write_only = schema.property(name).is_some_and(|property| {
+                resolve_schema_ref(property, schemas).write_only == Some(true)
+            });
+            if write_only {
+                findings.push(LintFinding {
+                    rule: LintRule::WriteOnlyInResponse,
+                    location: schema_name.clone(),
+                    message: format!("'{}' is writeOnly but the schema is response only", name),
+                });
+            }
+        }
+    }
+    sort_findings(&mut findings);
+    findings
+}
+
+/// String formats whose values have a known maximum length
+const BOUNDED_STRING_FORMATS: &[&str] = &["date", "date-time", "time", "uuid", "ipv4", "ipv6"];
+
+/// Component schema properties a database column cannot size: strings
+/// without `maxLength` (unless an enum or a fixed-size format) and arrays
+/// without `maxItems`. Sorted by rule, then schema.
+pub fn lint_unbounded_fields(openapi_spec: &OpenApiSpec) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref());
+    for (schema_name, schema) in schemas.into_iter().flatten() {
+        let names: BTreeSet<String> = schema.get_field_names().into_iter().collect();
+        for name in &names {
+            let Some(property) = schema.property(name) else {
+                continue;
+            };
+            let finding = match property.schema_type.as_deref() {
+                Some("string")
+                    if property.max_length.is_none()
+                        && property.enum_.is_none()
+                        && !property
+                            .format
+                            .as_deref()
+                            .is_some_and(|format| BOUNDED_STRING_FORMATS.contains(&format)) =>
+                {
+                    (LintRule::UnboundedString, "a string without maxLength")
+                }
+                Some("array") if property.max_items.is_none() => {
+                    (LintRule::UnboundedArray, "an array without maxItems")
+                }
+                _ => continue,
+            };
+            findings.push(LintFinding {
+                rule: finding.0,
+                location: schema_name.clone(),
+                message: format!("'{}' is {}", name, finding.1),
+            });
+        }
+    }
+    sort_findings(&mut findings);
+    findings
+}
+
+/// Names of the `{param}` placeholders of a path template, in order.
+pub fn template_parameters(path: &str) -> Vec<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// Path templates against their operations' path parameters: placeholders
+/// without a parameter, parameters without a placeholder, and placeholders
+/// named differently by related paths (`/users/{id}` and
+/// `/users/{userId}/orders`). Sorted by rule, then location.
+pub fn lint_path_parameters(openapi_spec: &OpenApiSpec) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    for (path, path_item) in &openapi_spec.paths {
+        let placeholders = template_parameters(path);
+        for (method, operation) in &path_item.operations {
+            let location = format!("{} {}", method.to_uppercase(), path);
+            let declared: Vec<&str> = operation
+                .parameters
+                .iter()
+                .flatten()
+                .filter(|parameter| parameter.in_ == "path")
+                .map(|parameter| parameter.name.as_str())
+                .collect();
+            for name in placeholders.iter().filter(|name| !declared.contains(name)) {
+                findings.push(LintFinding {
+                    rule: LintRule::UndeclaredPathParameter,
+                    location: location.clone(),
+                    message: format!("{{{}}} has no path parameter", name),
+                });
+            }
+            for name in declared.iter().filter(|name| !placeholders.contains(name)) {
+                findings.push(LintFinding {
+                    rule: LintRule::UnusedPathParameter,
+                    location: location.clone(),
+                    message: format!("path parameter '{}' is not in the path", name),
+                });
+            }
+        }
+    }
+
+    // Placeholder names by the path leading to them, placeholders there
+    // written `{}`: `/users/{}` for both `/users/{id}` and
+    // `/users/{userId}/orders`
+    let mut names: BTreeMap<String, BTreeMap<&str, BTreeSet<&str>>> = BTreeMap::new();
+    for path in openapi_spec.paths.keys() {
+        let mut prefix = String::new();
+        for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+            let placeholders = template_parameters(segment);
+            if let [name] = placeholders[..] {
+                if segment.starts_with('{') && segment.ends_with('}') {
+                    prefix.push_str("/{}");
+                    names
+                        .entry(prefix.clone())
+                        .or_default()
+                        .entry(name)
+                        .or_default()
+                        .insert(path);
+                    continue;
+                }
+            }
+            prefix.push('/');
+            prefix.push_str(segment);
+        }
+    }
+    for usages in names.values().filter(|usages| usages.len() > 1) {
+        // The name most paths use, else the first alphabetically
+        let (usual, usual_paths) = usages
+            .iter()
+            .max_by(|a, b| a.1.len().cmp(&b.1.len()).then(b.0.cmp(a.0)))
+            .expect("at least two names");
+        let example = usual_paths.iter().next().expect("a path per name");
+        for (name, paths) in usages.iter().filter(|(name, _)| *name != usual) {
+            for path in paths {
+                findings.push(LintFinding {
+                    rule: LintRule::InconsistentPathParameter,
+                    location: path.to_string(),
+                    message: format!("{{{}}} is named {{{}}} in {}", name, usual, example),
+                });
+            }
+        }
+    }
+
+    sort_findings(&mut findings);
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_operation_ids_are_unique_and_named() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.1.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"operationId": "listUsers", "responses": {}},
+                        "post": {"operationId": "Create_User", "responses": {}}
+                    },
+                    "/people": {"get": {"operationId": "listUsers", "responses": {}}},
+                    "/health": {"get": {"responses": {}}}
+                },
+                "webhooks": {"userCreated": {"post": {"operationId": "listUsers", "responses": {}}}}
+            }"#,
+        )
+        .unwrap();
+
+        let findings = lint_operation_ids(&spec, &LintConfig::default().operation_id_pattern);
+        assert_eq!(
+            findings
+                .iter()
+                .map(|finding| (finding.rule, finding.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    LintRule::MissingOperationId,
+                    "GET /health: no operationId".to_string()
+                ),
+                (
+                    LintRule::DuplicateOperationId,
+                    "GET /people: operationId 'listUsers' is also used by GET /users, POST webhook:userCreated".to_string()
+                ),
+                (
+                    LintRule::DuplicateOperationId,
+                    "GET /users: operationId 'listUsers' is also used by GET /people, POST webhook:userCreated".to_string()
+                ),
+                (
+                    LintRule::DuplicateOperationId,
+                    "POST webhook:userCreated: operationId 'listUsers' is also used by GET /people, GET /users".to_string()
+                ),
+                (
+                    LintRule::OperationIdNaming,
+                    "POST /users: operationId 'Create_User' does not match ^[a-z][A-Za-z0-9]*$"
+                        .to_string()
+                ),
+            ]
+        );
+
+        let snake = LintConfig::with_operation_id_pattern(Some("^[a-z_]+$")).unwrap();
+        let naming: Vec<String> = lint_operation_ids(&spec, &snake.operation_id_pattern)
+            .into_iter()
+            .filter(|finding| finding.rule == LintRule::OperationIdNaming)
+            .map(|finding| finding.location)
+            .collect();
+        assert_eq!(
+            naming,
+            vec![
+                "GET /people",
+                "GET /users",
+                "POST /users",
+                "POST webhook:userCreated"
+            ]
+        );
+        assert!(LintConfig::with_operation_id_pattern(Some("(")).is_err());
+    }
+
+    #[test]
+    fn test_path_parameters_match_templates() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users/{id}": {
+                        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "get": {"responses": {}},
+                        "delete": {"responses": {}}
+                    },
+                    "/users/{id}/posts": {"get": {
+                        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "responses": {}
+                    }},
+                    "/users/{userId}/orders/{orderId}": {"get": {
+                        "parameters": [
+                            {"name": "userId", "in": "path", "required": true, "schema": {"type": "string"}},
+                            {"name": "order", "in": "path", "required": true, "schema": {"type": "string"}},
+                            {"name": "limit", "in": "query", "schema": {"type": "integer"}}
+                        ],
+                        "responses": {}
+                    }},
+                    "/files/{name}.{ext}": {"get": {
+                        "parameters": [{"name": "name", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "responses": {}
+                    }}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            template_parameters("/files/{name}.{ext}"),
+            vec!["name", "ext"]
+        );
+        let findings = lint_path_parameters(&spec);
+        assert_eq!(
+            findings
+                .iter()
+                .map(|finding| (finding.rule, finding.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    LintRule::UndeclaredPathParameter,
+                    "GET /files/{name}.{ext}: {ext} has no path parameter".to_string()
+                ),
+                (
+                    LintRule::UndeclaredPathParameter,
+                    "GET /users/{userId}/orders/{orderId}: {orderId} has no path parameter"
+                        .to_string()
+                ),
+                (
+                    LintRule::UnusedPathParameter,
+                    "GET /users/{userId}/orders/{orderId}: path parameter 'order' is not in the path"
+                        .to_string()
+                ),
+                (
+                    LintRule::InconsistentPathParameter,
+                    "/users/{userId}/orders/{orderId}: {userId} is named {id} in /users/{id}"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_only_fields_required_in_requests() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"post": {"requestBody": {"content": {"application/json": {
+                        "schema": {"$ref": "#/components/schemas/NewUser"}}}}, "responses": {}}},
+                    "/users/{id}": {"put": {"requestBody": {"content": {"application/json": {
+                        "schema": {"allOf": [{"$ref": "#/components/schemas/User"},
+                                             {"required": ["id", "name"]}]}}}}, "responses": {}}}
+                },
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "id": {"type": "string", "readOnly": true},
+                        "name": {"type": "string"}}},
+                    "NewUser": {"type": "object", "required": ["name", "createdAt"], "properties": {
+                        "name": {"type": "string"},
+                        "createdAt": {"type": "string", "readOnly": true}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let findings: Vec<String> = lint_read_only_required(&spec)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            findings,
+            [
+                "POST /users: required field 'createdAt' is readOnly",
+                "PUT /users/{id}: required field 'id' is readOnly",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_only_fields_in_response_only_schemas() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {"/users": {
+                    "get": {"operationId": "listUsers", "responses": {"200": {"description": "ok", "content": {"application/json": {
+                        "schema": {"type": "array", "items": {"$ref": "#/components/schemas/User"}}}}}}},
+                    "post": {"operationId": "createUser", "requestBody": {"content": {"application/json": {
+                        "schema": {"$ref": "#/components/schemas/Credentials"}}}},
+                        "responses": {"201": {"description": "created", "content": {"application/json": {
+                            "schema": {"$ref": "#/components/schemas/Credentials"}}}}}}
+                }},
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "name": {"type": "string"},
+                        "password": {"type": "string", "writeOnly": true}}},
+                    "Credentials": {"type": "object", "properties": {
+                        "secret": {"type": "string", "writeOnly": true}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let findings: Vec<String> = lint_write_only_in_responses(&spec)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        // Credentials is also sent, so its writeOnly field is fine
+        assert_eq!(
+            findings,
+            ["User: 'password' is writeOnly but the schema is response only"]
+        );
+        assert_eq!(lint_findings(&spec, &LintConfig::default()).len(), 1);
+    }
+
+    #[test]
+    fn test_unbounded_strings_and_arrays() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {"User": {"type": "object", "properties": {
+                    "name": {"type": "string"},
+                    "code": {"type": "string", "maxLength": 8},
+                    "status": {"type": "string", "enum": ["active"]},
+                    "createdAt": {"type": "string", "format": "date-time"},
+                    "tags": {"type": "array", "items": {"type": "string", "maxLength": 16}},
+                    "roles": {"type": "array", "maxItems": 4, "items": {"type": "string"}}
+                }}}}
+            }"#,
+        )
+        .unwrap();
+
+        let findings: Vec<(LintRule, String)> = lint_unbounded_fields(&spec)
+            .into_iter()
+            .map(|finding| (finding.rule, finding.to_string()))
+            .collect();
+        assert_eq!(
+            findings,
+            [
+                (
+                    LintRule::UnboundedString,
+                    "User: 'name' is a string without maxLength".to_string()
+                ),
+                (
+                    LintRule::UnboundedArray,
+                    "User: 'tags' is an array without maxItems".to_string()
+                ),
+            ]
+        );
+        assert!(lint_findings(&spec, &LintConfig::default()).is_empty());
+        let strict = LintConfig::default().with_require_bounds(true);
+        assert_eq!(lint_findings(&spec, &strict).len(), 2);
+    }
+
+    #[test]
+    fn test_unresolved_component_refs_are_reported() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.3",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {"/users": {"get": {
+                    "parameters": [{"$ref": "#/components/parameters/Missing"}],
+                    "responses": {"200": {"$ref": "#/components/responses/Gone"}}
+                }}}
+            }"##,
+        )
+        .unwrap();
+        let diagnostics = parse_warning_diagnostics(&spec, &LintConfig::default());
+        let messages: Vec<(&str, &str)> = diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "unresolved_ref",
+                    "Dropped GET /users: unresolved reference '#/components/parameters/Missing'"
+                ),
+                (
+                    "unresolved_ref",
+                    "Dropped GET /users: unresolved reference '#/components/responses/Gone'"
+                ),
+            ]
+        );
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_diagnostics_have_severity_and_can_be_suppressed() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.3",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"get": {"operationId": "listUsers", "responses": {}}},
+                    "/health": {"get": {"x-explorer-ignore": ["missing_operation_id"], "responses": {}}},
+                    "/ping": {"get": {"responses": {}}}
+                },
+                "components": {"schemas": {
+                    "Legacy": {"type": "object", "x-explorer-ignore": "unused_schema"},
+                    "Draft": {"type": "object"}
+                }}
+            }"#,
+        )
+        .unwrap();
+        let index = crate::indexer::build_field_index(&spec);
+        let unsuppressed = |config: &LintConfig| -> Vec<(Severity, &'static str, String)> {
+            lint_spec(&spec, &index, config)
+                .into_iter()
+                .filter(|diagnostic| !diagnostic.suppressed)
+                .map(|diagnostic| (diagnostic.severity, diagnostic.rule, diagnostic.message))
+                .collect()
+        };
+
+        assert_eq!(
+            unsuppressed(&LintConfig::default()),
+            [
+                (
+                    Severity::Hint,
+                    "missing_description",
+                    "3 endpoint(s) missing description/summary".to_string()
+                ),
+                (
+                    Severity::Hint,
+                    "unused_schema",
+                    "1 schema(s) not used in any endpoint".to_string()
+                ),
+                (
+                    Severity::Warning,
+                    "missing_operation_id",
+                    "GET /ping: no operationId".to_string()
+                ),
+            ]
+        );
+        let suppressed: Vec<&str> = lint_spec(&spec, &index, &LintConfig::default())
+            .into_iter()
+            .filter(|diagnostic| diagnostic.suppressed)
+            .map(|diagnostic| diagnostic.rule)
+            .collect();
+        assert_eq!(suppressed, ["unused_schema", "missing_operation_id"]);
+        assert_eq!(lint_findings(&spec, &LintConfig::default()).len(), 1);
+
+        let config = LintConfig::default()
+            .with_ignored(&[
+                "missing_description".to_string(),
+                "missing_operation_id: GET /ping".to_string(),
+            ])
+            .unwrap();
+        assert_eq!(
+            unsuppressed(&config),
+            [(
+                Severity::Hint,
+                "unused_schema",
+                "1 schema(s) not used in any endpoint".to_string()
+            )]
+        );
+        assert!(lint_findings(&spec, &config).is_empty());
+
+        let error = LintConfig::default()
+            .with_ignored(&["unused_schemas".to_string()])
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Unknown rule 'unused_schemas'"));
+    }
+}
@@ -1,20 +1,33 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use openapi_explorer::{
+    app, bundle, cache, check, command, config, discovery, error_skeleton, indexer, lint, merge,
+    mock, monitor, multi_spec, parse_error, parser, report, sensitive, sorting, spec_dir, stats,
+    ui, validate, workspace, Explorer,
+};
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
-mod app;
-mod events;
-mod indexer;
-mod parser;
-mod ui;
-
 #[derive(Parser)]
 #[command(name = "openapi-explorer")]
 #[command(about = "TUI OpenAPI Field Explorer - Analyze database fields across API endpoints")]
 struct Args {
-    /// Path to OpenAPI specification file (JSON or YAML)
-    #[arg(short, long)]
-    file: Option<PathBuf>,
+    /// Path to OpenAPI specification file (JSON or YAML), `-` for stdin, or
+    /// a directory whose specs are all loaded and kept fresh as files
+    /// change; repeat to explore several specs side by side (default: `spec`
+    /// of the config file)
+    #[arg(short, long = "file", global = true)]
+    files: Vec<PathBuf>,
+
+    /// Read the spec from standard input, like `--file -` (e.g. `kubectl get
+    /// --raw /openapi/v2 | openapi-explorer --stdin`); it cannot be reloaded
+    #[arg(long, global = true)]
+    stdin: bool,
+
+    /// Config file with startup defaults, overridden by flags (default:
+    /// ~/.config/openapi-explorer/config.toml when present)
+    #[arg(long, value_name = "FILE", global = true)]
+    config: Option<PathBuf>,
 
     /// Enable debug logging
     #[arg(short, long)]
@@ -23,6 +36,259 @@ struct Args {
     /// Interactive mode - choose file from current directory
     #[arg(short, long)]
     interactive: bool,
+
+    /// Probe the `[discovery]` services of the config file at the
+    /// well-known spec paths (`/openapi.json`, `/swagger.json`,
+    /// `/v3/api-docs`) and explore every spec found side by side
+    #[arg(long)]
+    discover: bool,
+
+    /// Extra sensitive field pattern, `label=regex` or `regex` (repeatable;
+    /// replaces the config file's `sensitive-patterns`)
+    #[arg(long = "sensitive-pattern", value_name = "PATTERN")]
+    sensitive_patterns: Vec<String>,
+
+    /// Regex operationIds must match (default: lower camel case,
+    /// `^[a-z][A-Za-z0-9]*$`)
+    #[arg(long, value_name = "REGEX")]
+    operation_id_pattern: Option<String>,
+
+    /// Lint strings without maxLength and arrays without maxItems
+    #[arg(long)]
+    require_bounds: bool,
+
+    /// Also check the document against the official OpenAPI 3.0/3.1 JSON
+    /// Schema, listing violations by JSON pointer in the Lint view
+    #[arg(long)]
+    strict: bool,
+
+    /// Print the documentation coverage and undocumented items instead of
+    /// starting the TUI, exiting with status 1 below PERCENT (default:
+    /// `min-doc-coverage` of the config file)
+    #[arg(long, value_name = "PERCENT", num_args = 0..=1, value_parser = |value: &str| stats::parse_min_coverage(value).map_err(|e| e.to_string()))]
+    min_doc_coverage: Option<Option<f64>>,
+
+    /// Base URL used by the try-it-out runner (default: first server of the
+    /// spec, then http://localhost:8080)
+    #[arg(long)]
+    base_url: Option<String>,
+
+    /// Ordering of field, schema and endpoint lists
+    #[arg(long, value_enum, default_value_t = sorting::SortMode::Natural)]
+    sort: sorting::SortMode,
+
+    /// Workspace file (specs, bookmarks, notes, saved filters, suppressions,
+    /// owners) imported at startup and used by the export/import keys
+    #[arg(long, value_name = "FILE")]
+    workspace: Option<PathBuf>,
+
+    /// Output format: the interactive TUI, or a JSON analysis report on
+    /// stdout (field index, relationships, warnings, stats)
+    #[arg(long, value_enum, default_value_t = report::OutputFormat::Tui)]
+    output: report::OutputFormat,
+
+    /// Color theme: dark, light, high-contrast or a TOML theme file
+    /// (default: `theme` of the config file, else
+    /// ~/.config/openapi-explorer/theme.toml when present, else dark)
+    #[arg(long, value_name = "NAME|FILE")]
+    theme: Option<String>,
+
+    /// View shown at startup: fields, schemas, endpoints, graph, stats,
+    /// headers or responses
+    #[arg(long, value_name = "VIEW", value_parser = |name: &str| command::parse_view(name).map_err(|e| e.to_string()))]
+    view: Option<app::View>,
+
+    /// Milliseconds between two redraws while idle
+    #[arg(long, value_name = "MS")]
+    tick_rate: Option<u64>,
+
+    /// Directory the parameter matrix, stats and graph exports are written to
+    #[arg(long, value_name = "DIR")]
+    export_dir: Option<PathBuf>,
+
+    /// Levels of nested object properties indexed as dotted paths
+    /// (`address.city` is 2); 1 indexes top-level fields only
+    #[arg(long, value_name = "N", default_value_t = indexer::DEFAULT_FIELD_DEPTH, global = true)]
+    field_depth: usize,
+
+    /// Record a dated stats snapshot in DIR on each run and show the change
+    /// since the previous one on the Stats view
+    #[arg(long, value_name = "DIR")]
+    stats_snapshot_dir: Option<PathBuf>,
+
+    /// Always parse and index from scratch, bypassing the on-disk cache
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Skip elements of the spec that fail to parse (operations, schemas,
+    /// components, servers) instead of rejecting the whole file, listing
+    /// them as warnings
+    #[arg(long, global = true)]
+    lenient: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Fetch a remote spec on a schedule, store dated snapshots and write
+    /// changelogs; exits with status 1 when a breaking change is detected
+    Monitor {
+        /// URL of the OpenAPI document to monitor
+        #[arg(long)]
+        url: String,
+
+        /// Directory where snapshots and changelogs are stored
+        #[arg(long, default_value = "snapshots")]
+        snapshot_dir: PathBuf,
+
+        /// Seconds between two fetches
+        #[arg(long, default_value_t = 86400)]
+        interval: u64,
+
+        /// Run a single cycle and exit (for cron jobs)
+        #[arg(long)]
+        once: bool,
+    },
+
+    /// Compare the spec against a baseline for CI: prints a human-readable
+    /// report on stderr and a JSON report on stdout, and exits with status 1
+    /// when the --fail-on condition is met
+    Check {
+        /// Baseline spec the current one must stay compatible with
+        #[arg(long, value_name = "FILE")]
+        against: PathBuf,
+
+        /// Changes that fail the check
+        #[arg(long, value_enum, default_value_t = check::FailOn::Breaking)]
+        fail_on: check::FailOn,
+    },
+
+    /// Check a JSON payload against the request body or a response schema
+    /// of an endpoint; exits with status 1 when it does not match
+    ValidatePayload {
+        /// Endpoint as "METHOD /path"
+        endpoint: String,
+
+        /// JSON file holding the payload
+        payload: PathBuf,
+
+        /// Check a response instead of the request body; without a status,
+        /// the first documented 2xx response
+        #[arg(long, value_name = "STATUS", num_args = 0..=1, default_missing_value = "2xx")]
+        response: Option<String>,
+    },
+
+    /// Print the error responses operations are missing (400, 401, 404,
+    /// 500), referencing the spec's error schema
+    ErrorSkeleton {
+        /// Endpoints as "METHOD /path"; every operation when omitted
+        endpoints: Vec<String>,
+
+        /// Merge fragment or JSON Patch
+        #[arg(long, value_enum, default_value_t = error_skeleton::SkeletonFormat::Json)]
+        format: error_skeleton::SkeletonFormat,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Run the whole analysis (stats, lint findings, critical fields,
+    /// sensitive data) through a Tera template, e.g. an API review document
+    Report {
+        /// Tera template; the built-in Markdown audit when omitted (see
+        /// examples/audit.md.tera)
+        #[arg(short, long, value_name = "FILE")]
+        template: Option<PathBuf>,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Merge the paths, webhooks and components of partial specs into one
+    /// document; conflicting definitions are reported on stderr (the first
+    /// file wins) and make the command exit with status 1
+    Merge {
+        /// JSON specs to merge (`-` for stdin, gzip or zstd compressed
+        /// files too), in order of precedence; YAML is not supported
+        #[arg(required = true, num_args = 2..)]
+        inputs: Vec<PathBuf>,
+
+        /// Write to this file instead of stdout; YAML for `.yaml`/`.yml`
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Copy the targets of references to other files into the spec's
+    /// components, giving a self-contained document
+    Bundle {
+        /// Write to this file instead of stdout; YAML for `.yaml`/`.yml`
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Split the spec into openapi.json, keeping everything but the paths,
+    /// and one file of paths per tag referenced from it
+    Split {
+        /// Directory the files are written to
+        #[arg(short, long, value_name = "DIR", default_value = "split")]
+        dir: PathBuf,
+    },
+
+    /// Serve example responses generated from the spec's schemas
+    Serve {
+        /// Port to listen on (localhost)
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+    },
+}
+
+/// Exits after printing a parse error with the source lines around it;
+/// other errors are returned as they are.
+fn exit_on_parse_error(error: anyhow::Error) -> anyhow::Error {
+    if let Some(parse_error) = error.downcast_ref::<parse_error::ParseError>() {
+        eprintln!("Error: {}", parse_error);
+        eprint!("{}", parse_error.snippet_text());
+        std::process::exit(1);
+    }
+    error
+}
+
+/// Prints a JSON document, or writes it to `output`: YAML for `.yaml` and
+/// `.yml` files, JSON otherwise.
+fn write_document(document: &serde_json::Value, output: Option<&std::path::Path>) -> Result<()> {
+    let rendered = match output.map(error_skeleton::SkeletonFormat::for_path) {
+        Some(error_skeleton::SkeletonFormat::Yaml) => error_skeleton::to_yaml(document),
+        _ => format!("{}\n", serde_json::to_string_pretty(document)?),
+    };
+    match output {
+        Some(output) => std::fs::write(output, rendered)?,
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
+
+/// Sensitive field patterns and lint options of the flags, else of the
+/// config file.
+fn analysis_settings(
+    args: &Args,
+    config: &config::Config,
+) -> Result<(sensitive::SensitiveDetector, lint::LintConfig)> {
+    let sensitive_patterns = if args.sensitive_patterns.is_empty() {
+        &config.sensitive_patterns
+    } else {
+        &args.sensitive_patterns
+    };
+    let sensitive_detector = sensitive::SensitiveDetector::with_extra_patterns(sensitive_patterns)?;
+    let lint_config =
+        lint::LintConfig::with_operation_id_pattern(args.operation_id_pattern.as_deref())?
+            .with_require_bounds(args.require_bounds)
+            .with_strict(args.strict)
+            .with_ignored(&config.lint_ignore)?;
+    Ok((sensitive_detector, lint_config))
 }
 
 #[tokio::main]
@@ -39,23 +305,366 @@ async fn main() -> Result<()> {
             .init();
     }
 
+    let config = config::Config::load(args.config.as_deref())?;
+    let field_depth = args.field_depth.max(1);
+    let cache = (!args.no_cache).then(|| cache::SpecCache::new(cache::SpecCache::default_dir()));
+    // Subcommands fall back on the config's spec right away; the TUI looks
+    // at the workspace first
+    let mut files = args.files.clone();
+    if args.stdin && !files.iter().any(|path| parser::is_stdin(path)) {
+        files.insert(0, PathBuf::from(parser::STDIN_PATH));
+    }
+    if files.is_empty() && args.command.is_some() {
+        files.extend(config.spec_path(&cache::SpecCache::default_dir()).await?);
+    }
+
+    match args.command {
+        Some(Command::Monitor {
+            url,
+            snapshot_dir,
+            interval,
+            once,
+        }) => {
+            let options = monitor::MonitorOptions {
+                url,
+                snapshot_dir,
+                interval: std::time::Duration::from_secs(interval),
+                once,
+            };
+            if monitor::run_monitor(&options).await? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Check { against, fail_on }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let (baseline, _) =
+                cache::load_indexed(&against, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            let (current, _) =
+                cache::load_indexed(&path, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            let report = check::check_specs(
+                &baseline,
+                &current,
+                (&against.display().to_string(), &path.display().to_string()),
+                fail_on,
+            );
+            eprint!("{}", report.to_text());
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            if report.failed {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::ValidatePayload {
+            endpoint,
+            payload,
+            response,
+        }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let (openapi_spec, _) =
+                cache::load_indexed(&path, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            let kind = match response {
+                None => validate::PayloadKind::Request,
+                Some(status) if status == "2xx" => validate::PayloadKind::Response(None),
+                Some(status) => validate::PayloadKind::Response(Some(status)),
+            };
+            let payload: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&payload)?)?;
+            let validation = validate::validate_payload(&openapi_spec, &endpoint, &kind, &payload)?;
+            print!("{}", validation.to_text());
+            if !validation.is_valid() {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::ErrorSkeleton {
+            endpoints,
+            format,
+            output,
+        }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let (openapi_spec, field_index) =
+                cache::load_indexed(&path, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            let endpoints = if endpoints.is_empty() {
+                let mut all: Vec<String> = field_index.responses.keys().cloned().collect();
+                all.sort();
+                all
+            } else {
+                endpoints
+            };
+            let skeleton =
+                error_skeleton::build_error_skeleton(&openapi_spec, &field_index, &endpoints);
+            let rendered = skeleton.render(format);
+            match output {
+                Some(output) => std::fs::write(output, rendered)?,
+                None => print!("{}", rendered),
+            }
+            eprintln!(
+                "{} missing error response(s) across {} endpoint(s)",
+                skeleton.response_count(),
+                skeleton.endpoints.len()
+            );
+            return Ok(());
+        }
+        Some(Command::Report {
+            ref template,
+            ref output,
+        }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let (openapi_spec, field_index) =
+                cache::load_indexed(&path, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            let (sensitive_detector, lint_config) = analysis_settings(&args, &config)?;
+            let context = report::build_template_context(
+                &Explorer::from_parts(openapi_spec, field_index),
+                &sensitive_detector,
+                &lint_config,
+                &config.criticality,
+                chrono::Utc::now(),
+            );
+            let (name, source) = match template {
+                Some(template) => (
+                    template.display().to_string(),
+                    std::fs::read_to_string(template)?,
+                ),
+                None => (
+                    "audit.md.tera".to_string(),
+                    report::DEFAULT_TEMPLATE.to_string(),
+                ),
+            };
+            let rendered = report::render_template(&name, &source, &context)?;
+            match output {
+                Some(output) => std::fs::write(output, rendered)?,
+                None => print!("{}", rendered),
+            }
+            return Ok(());
+        }
+        Some(Command::Merge { inputs, output }) => {
+            let report = merge::merge_files(&inputs).await?;
+            write_document(&report.merged, output.as_deref())?;
+            eprint!("{}", report.to_text());
+            if !report.conflicts.is_empty() {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Bundle { output }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            write_document(&bundle::bundle_file(&path)?, output.as_deref())?;
+            return Ok(());
+        }
+        Some(Command::Split { dir }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let document: serde_json::Value =
+                serde_json::from_str(&parser::read_spec_content(&path).await?)?;
+            let split = bundle::split_document(&document);
+            bundle::write_split(&dir, &split)?;
+            eprintln!("Wrote {} file(s) to {}", split.len(), dir.display());
+            return Ok(());
+        }
+        Some(Command::Serve { port }) => {
+            let path = parser::spec_path_or_default(&files.first().cloned())?;
+            let (openapi_spec, field_index) =
+                cache::load_indexed(&path, cache.as_ref(), field_depth, args.lenient)
+                    .await
+                    .map_err(exit_on_parse_error)?;
+            return mock::serve(&openapi_spec, &field_index, port).await;
+        }
+        None => {}
+    }
+
+    let (sensitive_detector, lint_config) = analysis_settings(&args, &config)?;
+
     log::info!("Starting OpenAPI Field Explorer");
-    log::debug!("Loading OpenAPI spec from: {:?}", args.file);
 
-    // Parse OpenAPI specification
-    let openapi_spec = parser::parse_openapi_or_default(&args.file).await?;
-    log::info!("Successfully parsed OpenAPI specification");
+    // The workspace may list the specs to load when no --file is given
+    let workspace = match &args.workspace {
+        Some(path) if path.exists() => workspace::Workspace::load(path)?,
+        _ => workspace::Workspace::default(),
+    };
+    if let Some(path) = args.workspace.as_ref().filter(|_| files.is_empty()) {
+        files = workspace.spec_paths(path);
+    }
+    if args.discover {
+        if config.discovery.services.is_empty() {
+            anyhow::bail!("--discover needs services in the [discovery] section of the config");
+        }
+        let probes =
+            discovery::discover(&config.discovery, &cache::SpecCache::default_dir()).await?;
+        eprint!("{}", discovery::to_text(&probes));
+        let found: Vec<PathBuf> = probes
+            .into_iter()
+            .filter_map(|probe| probe.result.ok().map(|(_, stored)| stored))
+            .collect();
+        if found.is_empty() {
+            anyhow::bail!("No spec found across the discovery services");
+        }
+        files.extend(found);
+    }
+    if files.is_empty() {
+        files.extend(config.spec_path(&cache::SpecCache::default_dir()).await?);
+    }
+    // A directory stands for every spec in it, watched for changes
+    let mut spec_dirs = Vec::new();
+    let mut expanded = Vec::with_capacity(files.len());
+    for path in files {
+        if path.is_dir() {
+            let dir_files = spec_dir::spec_files(&path)?;
+            if dir_files.is_empty() {
+                anyhow::bail!("No spec files in {}", path.display());
+            }
+            expanded.extend(dir_files);
+            spec_dirs.push(path.canonicalize()?);
+        } else {
+            expanded.push(path);
+        }
+    }
+    let files = expanded;
+    log::debug!("Loading OpenAPI spec from: {:?}", files);
 
-    // Index fields and relationships
-    let field_index = indexer::build_field_index(&openapi_spec);
+    // Parse OpenAPI specification and index fields and relationships, with a
+    // progress splash when starting the TUI
+    let primary_file = parser::spec_path_or_default(&files.first().cloned())?;
+    let theme = ui::theme::Theme::load(args.theme.as_deref().or(config.theme.as_deref()))?;
+    let (openapi_spec, field_index) = if args.output != report::OutputFormat::Json
+        && args.min_doc_coverage.is_none()
+        && std::io::stdout().is_terminal()
+    {
+        ui::splash::load_with_splash(
+            &primary_file,
+            cache.as_ref(),
+            field_depth,
+            args.lenient,
+            theme,
+        )
+        .await?
+    } else {
+        cache::load_indexed(&primary_file, cache.as_ref(), field_depth, args.lenient)
+            .await
+            .map_err(exit_on_parse_error)?
+    };
+    for warning in &openapi_spec.parse_warnings {
+        log::warn!("Skipped invalid {}", warning);
+    }
     log::info!(
         "Indexed {} fields across {} schemas",
         field_index.fields.len(),
         field_index.schemas.len()
     );
 
-    // Initialize application state with file path for reload capability
-    let mut app = app::App::new(openapi_spec, field_index, args.file);
+    let stats_trend = match &args.stats_snapshot_dir {
+        Some(dir) => {
+            let now = chrono::Utc::now();
+            let snapshot = stats::StatsSnapshot::capture(&openapi_spec, &field_index, now);
+            let (path, trend) = stats::record_stats_snapshot(dir, &snapshot, now)?;
+            log::info!("Recorded stats snapshot {}", path.display());
+            if let Some(trend) = &trend {
+                log::info!("{}", trend.summary());
+            }
+            trend
+        }
+        None => None,
+    };
+
+    if let Some(min_doc_coverage) = args.min_doc_coverage {
+        let Some(min_percent) = min_doc_coverage.or(config.min_doc_coverage) else {
+            anyhow::bail!("--min-doc-coverage needs a PERCENT, or min-doc-coverage in the config");
+        };
+        let coverage = stats::documentation_coverage(&openapi_spec, &field_index);
+        print!("{}", coverage.to_text(min_percent));
+        if !coverage.meets(min_percent) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if args.output == report::OutputFormat::Json {
+        let explorer = Explorer::from_parts(openapi_spec, field_index);
+        let report = report::build_report(
+            &explorer,
+            &sensitive_detector,
+            &lint_config,
+            &config.criticality,
+        );
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    // Initialize application state with file path for reload capability;
+    // specs piped on stdin have none
+    let source_file = |path: PathBuf| (!parser::is_stdin(&path)).then_some(path);
+    let mut app = app::App::new(openapi_spec, field_index, source_file(primary_file));
+    let extra_files = files.get(1..).unwrap_or_default();
+    let loaded = if spec_dirs.is_empty() {
+        multi_spec::load_specs(extra_files, cache.as_ref(), field_depth, args.lenient).await?
+    } else {
+        let (loaded, failed) = multi_spec::load_specs_skipping_failures(
+            extra_files,
+            cache.as_ref(),
+            field_depth,
+            args.lenient,
+        )
+        .await;
+        for (path, error) in failed {
+            log::warn!("Skipped {}: {:#}", path.display(), error);
+            app.spec_dir_failures.insert(path, format!("{:#}", error));
+        }
+        loaded
+    };
+    for (spec, index, path) in loaded {
+        app.add_spec(spec, index, source_file(path));
+    }
+    app.spec_dirs = spec_dirs;
+    if app.specs.len() > 1 {
+        log::info!(
+            "Loaded {} specs, {} field name(s) shared across them",
+            app.specs.len(),
+            app.cross_index.shared_fields().len()
+        );
+    }
+
+    app.cache = cache;
+    app.lenient = args.lenient;
+    app.stats_trend = stats_trend;
+    app.min_doc_coverage = config.min_doc_coverage;
+    app.theme = theme;
+    app.sensitive_detector = sensitive_detector;
+    app.lint_config = lint_config;
+    app.criticality = config.criticality;
+    app.export_dirs = config.export;
+    if let Some(dir) = args.export_dir {
+        app.export_dirs.files = dir;
+    }
+    if let Some(tick_rate) = args.tick_rate.map(std::time::Duration::from_millis) {
+        app.tick_rate = tick_rate.max(std::time::Duration::from_millis(1));
+    } else if let Some(tick_rate) = config.tick_rate {
+        app.tick_rate = tick_rate;
+    }
+    if let Some(view) = args.view.or(config.default_view) {
+        app.set_view(view);
+    }
+    app.validate_spec();
+    if let Some(base_url) = args.base_url {
+        app.base_url = base_url;
+        app.active_server = None;
+    }
+    if let Some(path) = args.workspace {
+        app.workspace = workspace;
+        app.workspace_file = path;
+    }
+    if app.sort_mode != args.sort {
+        app.sort_mode = args.sort;
+        app.update_filters();
+    }
 
     // Run the TUI application
     ui::run(&mut app)
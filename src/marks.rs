@@ -0,0 +1,276 @@
+//! Items marked with Space in the Fields and Endpoints lists, and the bulk
+//! actions run on them: CSV export, copying the names, bookmarks and a
+//! combined impact analysis.
+
+use crate::app::{App, View};
+use crate::export::csv_line;
+use crate::impact::{analyze_impact, ImpactTarget};
+use crate::notifications::Level;
+use crate::view_model::FieldColumn;
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+impl App {
+    /// Marks of the current view's list; only Fields and Endpoints have any.
+    fn marks(&self) -> Option<&BTreeSet<String>> {
+        match self.current_view {
+            View::Fields => Some(&self.marked_fields),
+            View::Endpoints => Some(&self.marked_endpoints),
+            _ => None,
+        }
+    }
+
+    fn marks_mut(&mut self) -> Option<&mut BTreeSet<String>> {
+        match self.current_view {
+            View::Fields => Some(&mut self.marked_fields),
+            View::Endpoints => Some(&mut self.marked_endpoints),
+            _ => None,
+        }
+    }
+
+    /// Marked items of the current list, sorted.
+    pub fn marked(&self) -> Vec<String> {
+        self.marks()
+            .map(|marks| marks.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn is_marked(&self, item: &str) -> bool {
+        self.marks().is_some_and(|marks| marks.contains(item))
+    }
+
+    /// Marks or unmarks the item under the cursor, then moves down so runs
+    /// of items are marked by holding Space.
+    pub fn toggle_mark(&mut self) {
+        let item = match self.current_view {
+            View::Fields => self
+                .filtered_fields
+                .get(self.field_list_state)
+                .map(|f| f.to_string()),
+            View::Endpoints => self.endpoint_at_cursor().map(|e| e.to_string()),
+            _ => None,
+        };
+        if let (Some(item), Some(marks)) = (item, self.marks_mut()) {
+            if !marks.remove(&item) {
+                marks.insert(item);
+            }
+        }
+        self.navigate_down();
+    }
+
+    pub fn clear_marks(&mut self) {
+        if let Some(marks) = self.marks_mut() {
+            let count = marks.len();
+            marks.clear();
+            self.notify(Level::Info, format!("Cleared {} mark(s)", count));
+        }
+    }
+
+    /// Marked items, or the one under the cursor when nothing is marked.
+    fn bulk_targets(&self) -> Vec<String> {
+        let marked = self.marked();
+        if !marked.is_empty() {
+            return marked;
+        }
+        let current = match self.current_view {
+            View::Fields => self
+                .filtered_fields
+                .get(self.field_list_state)
+                .map(|f| f.to_string()),
+            View::Endpoints => self.endpoint_at_cursor().map(|e| e.to_string()),
+            _ => None,
+        };
+        current.into_iter().collect()
+    }
+
+    /// Copies the marked names, one per line, to the clipboard.
+    pub fn copy_marked_names(&mut self) {
+        let names = self.bulk_targets();
+        if names.is_empty() {
+            return;
+        }
+        self.pending_clipboard = Some(names.join("\n"));
+        self.notify(
+            Level::Success,
+            format!("Copied {} name(s) to the clipboard", names.len()),
+        );
+    }
+
+    /// Takes the text to copy, written to the terminal by the UI loop.
+    pub fn take_clipboard(&mut self) -> Option<String> {
+        self.pending_clipboard.take()
+    }
+
+    /// Bookmarks every marked item; returns false when nothing is marked.
+    pub fn bookmark_marked(&mut self) -> bool {
+        let marked = self.marked();
+        if marked.is_empty() {
+            return false;
+        }
+        let added = marked
+            .iter()
+            .filter(|item| self.workspace.bookmarks.insert(item.to_string()))
+            .count();
+        self.notify(
+            Level::Success,
+            format!("Bookmarked {} of {} marked item(s)", added, marked.len()),
+        );
+        true
+    }
+
+    /// Impact analysis of all the marked fields at once; returns false when
+    /// no field is marked.
+    pub fn analyze_marked_impact(&mut self) -> bool {
+        if self.current_view != View::Fields || self.marked_fields.is_empty() {
+            return false;
+        }
+        let target = ImpactTarget::Fields(self.marked_fields.iter().cloned().collect());
+        self.impact_report = Some(analyze_impact(
+            &self.openapi_spec,
+            &self.field_index,
+            &target,
+        ));
+        true
+    }
+
+    pub fn export_marked(&mut self, path: &Path) {
+        let items = self.bulk_targets();
+        match self.write_marked_csv(path, &items) {
+            Ok(()) => self.notify(
+                Level::Success,
+                format!("Exported {} item(s) to {}", items.len(), path.display()),
+            ),
+            Err(e) => self.notify(Level::Error, format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Fields with the Fields table columns, or endpoints with their
+    /// operations.
+    fn write_marked_csv(&self, path: &Path, items: &[String]) -> Result<()> {
+        let mut out = String::new();
+        if self.current_view == View::Fields {
+            let header: Vec<String> = FieldColumn::ALL
+                .iter()
+                .map(|column| column.title().to_string())
+                .collect();
+            out.push_str(&csv_line(&header));
+            for item in items {
+                let columns = self.field_columns(item);
+                let mut record = vec![item.clone()];
+                record.extend(FieldColumn::ALL[1..].iter().map(|c| columns.cell(*c)));
+                out.push_str(&csv_line(&record));
+            }
+        } else {
+            let header = ["endpoint", "operations", "operation_ids"].map(str::to_string);
+            out.push_str(&csv_line(&header));
+            for item in items {
+                let record = [
+                    item.clone(),
+                    self.operation_keys(item).join(" | "),
+                    self.operation_ids(item).join(" "),
+                ];
+                out.push_str(&csv_line(&record));
+            }
+        }
+        std::fs::write(path, out)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::{App, View};
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    fn app() -> App {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"post": {"operationId": "createUser", "requestBody": {"content": {
+                        "application/json": {"schema": {"$ref": "#/components/schemas/User"}}
+                    }}, "responses": {"201": {"description": "created"}}}},
+                    "/pets": {"get": {"responses": {"200": {"description": "ok", "content": {
+                        "application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}
+                    }}}}}
+                },
+                "components": {"schemas": {
+                    "User": {"type": "object", "required": ["email"], "properties": {
+                        "email": {"type": "string"}, "name": {"type": "string"}
+                    }},
+                    "Pet": {"type": "object", "properties": {"tag": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        App::new(spec, index, None)
+    }
+
+    #[test]
+    fn test_marked_fields_bulk_actions() {
+        let mut app = app();
+        // email, name, tag: mark email and tag
+        app.toggle_mark();
+        app.toggle_mark();
+        app.toggle_mark();
+        app.field_list_state = 1;
+        app.toggle_mark();
+        assert_eq!(app.marked(), vec!["email", "tag"]);
+        assert!(app.is_marked("tag"));
+
+        app.copy_marked_names();
+        assert_eq!(app.take_clipboard().as_deref(), Some("email\ntag"));
+
+        assert!(app.analyze_marked_impact());
+        let report = app.impact_report.as_ref().unwrap();
+        assert_eq!(report.target.name(), "email, tag");
+        let endpoints: Vec<&str> = report
+            .endpoints
+            .iter()
+            .map(|e| e.endpoint.as_str())
+            .collect();
+        assert_eq!(endpoints, vec!["GET /pets", "POST /users"]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fields.csv");
+        app.export_marked(&path);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Name,Type,#Schemas,#Endpoints,Required,Critical\n\
+             email,string,1,0,1/1,\n\
+             tag,string,1,0,0/1,\n"
+        );
+
+        assert!(app.bookmark_marked());
+        assert!(app.is_bookmarked("email"));
+        app.clear_marks();
+        assert!(app.marked().is_empty());
+        assert!(!app.bookmark_marked());
+    }
+
+    #[test]
+    fn test_marked_endpoints_are_kept_per_list() {
+        let mut app = app();
+        app.toggle_mark();
+        app.set_view(View::Endpoints);
+        assert!(app.marked().is_empty());
+        app.toggle_mark();
+        assert_eq!(app.marked(), vec!["/pets"]);
+        // Impact analysis runs on fields only
+        assert!(!app.analyze_marked_impact());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("endpoints.csv");
+        app.export_marked(&path);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "endpoint,operations,operation_ids\n/pets,GET /pets,\n"
+        );
+        app.set_view(View::Fields);
+        assert_eq!(app.marked(), vec!["email"]);
+    }
+}
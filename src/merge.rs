@@ -0,0 +1,440 @@
+//! Merging partial specs maintained in separate files into one document:
+//! paths, webhooks and components are combined, and definitions two files
+//! give different shapes are reported as conflicts (the first file wins).
+
+use crate::parser::{self, HTTP_METHODS};
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Keys that only document a definition, ignored when comparing shapes
+const DOCUMENTATION_KEYS: &[&str] = &[
+    "description",
+    "summary",
+    "title",
+    "example",
+    "examples",
+    "externalDocs",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// Different `openapi` versions
+    Version,
+    /// Same method and path (or webhook), different operations
+    Operation,
+    /// Same component schema name, different shapes
+    Schema,
+    /// Same name in another components section (parameters, responses...)
+    Component,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MergeConflict {
+    pub kind: ConflictKind,
+    /// `GET /users`, `components/schemas/User`...
+    pub location: String,
+    /// File whose definition was kept
+    pub kept: String,
+    /// File whose definition was dropped
+    pub dropped: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeReport {
+    pub merged: Value,
+    /// Sorted by kind, then location
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeReport {
+    pub fn to_text(&self) -> String {
+        if self.conflicts.is_empty() {
+            return "Merged without conflicts\n".to_string();
+        }
+        let mut out = format!("{} conflict(s):\n", self.conflicts.len());
+        for conflict in &self.conflicts {
+            let what = match conflict.kind {
+                ConflictKind::Version => "openapi version differs",
+                ConflictKind::Operation => "different operations",
+                ConflictKind::Schema => "different schema shapes",
+                ConflictKind::Component => "different definitions",
+            };
+            out.push_str(&format!(
+                "  {}: {} (kept {}, dropped {})\n",
+                conflict.location, what, conflict.kept, conflict.dropped
+            ));
+        }
+        out
+    }
+}
+
+/// `value` without documentation keys, at any depth, so wording changes
+/// are not conflicts. Property names are kept even when they look like
+/// documentation keys.
+fn shape(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, _)| !DOCUMENTATION_KEYS.contains(&key.as_str()))
+                .map(|(key, child)| {
+                    let child = match (key.as_str(), child) {
+                        ("properties" | "patternProperties", Value::Object(properties)) => {
+                            Value::Object(
+                                properties
+                                    .iter()
+                                    .map(|(name, property)| (name.clone(), shape(property)))
+                                    .collect(),
+                            )
+                        }
+                        _ => shape(child),
+                    };
+                    (key.clone(), child)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(shape).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Merge state: the document so far and where its entries come from.
+struct Merger<'a> {
+    merged: Map<String, Value>,
+    /// File of each entry added after the first document, by location
+    origins: HashMap<String, &'a str>,
+    first_source: &'a str,
+    conflicts: Vec<MergeConflict>,
+}
+
+impl<'a> Merger<'a> {
+    fn conflict(&mut self, kind: ConflictKind, location: String, source: &str) {
+        let kept = self.origins.get(&location).unwrap_or(&self.first_source);
+        self.conflicts.push(MergeConflict {
+            kind,
+            kept: kept.to_string(),
+            location,
+            dropped: source.to_string(),
+        });
+    }
+
+    /// Adds the `incoming` entries missing from `merged[section][group]`,
+    /// reporting those defined differently.
+    fn merge_entries(
+        &mut self,
+        section: &str,
+        group: &str,
+        incoming: &Map<String, Value>,
+        source: &'a str,
+        kind: ConflictKind,
+        location: impl Fn(&str) -> String,
+    ) {
+        let mut conflicts = Vec::new();
+        let existing = self
+            .merged
+            .entry(section)
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .and_then(|groups| {
+                groups
+                    .entry(group)
+                    .or_insert_with(|| Value::Object(Map::new()))
+                    .as_object_mut()
+            });
+        let Some(existing) = existing else {
+            return;
+        };
+        for (name, definition) in incoming {
+            match existing.get(name) {
+                Some(current) if shape(current) != shape(definition) => {
+                    conflicts.push(location(name))
+                }
+                Some(_) => {}
+                None => {
+                    existing.insert(name.clone(), definition.clone());
+                    self.origins.insert(location(name), source);
+                }
+            }
+        }
+        for location in conflicts {
+            self.conflict(kind, location, source);
+        }
+    }
+
+    fn merge_document(&mut self, source: &'a str, document: &Map<String, Value>) {
+        for (key, value) in document {
+            match (key.as_str(), value) {
+                ("openapi", version) => match self.merged.get("openapi") {
+                    Some(current) if current != version => {
+                        self.conflict(ConflictKind::Version, "openapi".to_string(), source)
+                    }
+                    Some(_) => {}
+                    None => {
+                        self.merged.insert(key.clone(), version.clone());
+                    }
+                },
+                ("paths" | "webhooks", Value::Object(targets)) => {
+                    for (target, item) in targets {
+                        let Value::Object(item) = item else {
+                            continue;
+                        };
+                        self.merge_entries(
+                            key,
+                            target,
+                            item,
+                            source,
+                            ConflictKind::Operation,
+                            |entry| entry_location(key, target, entry),
+                        );
+                    }
+                }
+                ("components", Value::Object(sections)) => {
+                    for (section, entries) in sections {
+                        let Value::Object(entries) = entries else {
+                            continue;
+                        };
+                        let kind = if section == "schemas" {
+                            ConflictKind::Schema
+                        } else {
+                            ConflictKind::Component
+                        };
+                        self.merge_entries(key, section, entries, source, kind, |name| {
+                            format!("components/{}/{}", section, name)
+                        });
+                    }
+                }
+                ("tags" | "servers", Value::Array(items)) => {
+                    // Union, told apart by name or URL
+                    let id = if key == "tags" { "name" } else { "url" };
+                    let list = self
+                        .merged
+                        .entry(key.clone())
+                        .or_insert_with(|| Value::Array(Vec::new()));
+                    if let Some(list) = list.as_array_mut() {
+                        for item in items {
+                            if !list.iter().any(|known| known.get(id) == item.get(id)) {
+                                list.push(item.clone());
+                            }
+                        }
+                    }
+                }
+                // info, security...: from the first file that has them
+                _ => {
+                    self.merged
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Merges documents given with the file they come from, in order.
+pub fn merge_documents(documents: &[(String, Value)]) -> MergeReport {
+    let mut merger = Merger {
+        merged: Map::new(),
+        origins: HashMap::new(),
+        first_source: documents.first().map_or("", |(source, _)| source.as_str()),
+        conflicts: Vec::new(),
+    };
+    for (source, document) in documents {
+        if let Value::Object(document) = document {
+            merger.merge_document(source, document);
+        }
+    }
+    // Stable, so conflicts at the same location stay in file order
+    merger
+        .conflicts
+        .sort_by(|a, b| a.kind.cmp(&b.kind).then(a.location.cmp(&b.location)));
+    MergeReport {
+        merged: Value::Object(merger.merged),
+        conflicts: merger.conflicts,
+    }
+}
+
+/// `GET /users` for operations, `/users parameters` for other path item
+/// keys.
+fn entry_location(section: &str, target: &str, entry: &str) -> String {
+    let target = if section == "webhooks" {
+        format!("webhook {}", target)
+    } else {
+        target.to_string()
+    };
+    if HTTP_METHODS.contains(&entry) {
+        format!("{} {}", entry.to_uppercase(), target)
+    } else {
+        format!("{} {}", target, entry)
+    }
+}
+
+/// Reads and merges the JSON specs at `paths`, in order, through
+/// [`parser::read_spec_content`] (so compressed files and `-` for stdin
+/// work). YAML inputs are rejected: specs are only parsed as JSON.
+pub async fn merge_files(paths: &[impl AsRef<Path>]) -> Result<MergeReport> {
+    let mut documents = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        if is_yaml(path) {
+            return Err(anyhow!(
+                "Cannot merge {}: YAML specs are not supported, convert it to JSON first",
+                path.display()
+            ));
+        }
+        let content = parser::read_spec_content(path).await?;
+        let document: Value = serde_json::from_str(&content)
+            .with_context(|| format!("{} is not a JSON document", path.display()))?;
+        documents.push((path.display().to_string(), document));
+    }
+    Ok(merge_documents(&documents))
+}
+
+/// `.yaml`/`.yml` files, compressed or not.
+fn is_yaml(path: &Path) -> bool {
+    let extension = |path: &Path| {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+    };
+    let path = match extension(path).as_deref() {
+        Some("gz" | "zst") => path.with_extension(""),
+        _ => path.to_path_buf(),
+    };
+    matches!(extension(&path).as_deref(), Some("yaml" | "yml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn documents() -> Vec<(String, Value)> {
+        vec![
+            (
+                "users.json".to_string(),
+                json!({
+                    "openapi": "3.0.0",
+                    "info": {"title": "Users", "version": "1"},
+                    "tags": [{"name": "users"}],
+                    "paths": {"/users": {"get": {"responses": {"200": {"description": "ok"}}}}},
+                    "components": {"schemas": {
+                        "User": {"type": "object", "properties": {"title": {"type": "string"}}},
+                        "Error": {"type": "object", "description": "Failure"}
+                    }}
+                }),
+            ),
+            (
+                "orders.json".to_string(),
+                json!({
+                    "openapi": "3.0.0",
+                    "info": {"title": "Orders", "version": "2"},
+                    "tags": [{"name": "users"}, {"name": "orders"}],
+                    "paths": {
+                        "/users": {
+                            "get": {"responses": {"204": {"description": "empty"}}},
+                            "post": {"responses": {"201": {"description": "created"}}}
+                        },
+                        "/orders": {"get": {"responses": {"200": {"description": "ok"}}}}
+                    },
+                    "components": {
+                        "schemas": {
+                            "User": {"type": "object", "properties": {"title": {"type": "integer"}}},
+                            "Error": {"type": "object", "description": "Any error"},
+                            "Order": {"type": "object"}
+                        },
+                        "parameters": {"Page": {"name": "page", "in": "query"}}
+                    }
+                }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_merges_paths_and_components() {
+        let report = merge_documents(&documents());
+        let merged = &report.merged;
+        assert_eq!(merged["info"]["title"], "Users");
+        assert_eq!(
+            merged["tags"],
+            json!([{"name": "users"}, {"name": "orders"}])
+        );
+        assert!(merged["paths"]["/users"]["post"].is_object());
+        assert!(merged["paths"]["/orders"]["get"].is_object());
+        // The first definition wins
+        assert!(merged["paths"]["/users"]["get"]["responses"]["200"].is_object());
+        assert_eq!(
+            merged["components"]["schemas"]["User"]["properties"]["title"]["type"],
+            "string"
+        );
+        assert!(merged["components"]["schemas"]["Order"].is_object());
+        assert!(merged["components"]["parameters"]["Page"].is_object());
+    }
+
+    #[test]
+    fn test_reports_conflicting_shapes() {
+        let report = merge_documents(&documents());
+        let conflicts: Vec<(ConflictKind, &str)> = report
+            .conflicts
+            .iter()
+            .map(|conflict| (conflict.kind, conflict.location.as_str()))
+            .collect();
+        // Error only differs by its description
+        assert_eq!(
+            conflicts,
+            [
+                (ConflictKind::Operation, "GET /users"),
+                (ConflictKind::Schema, "components/schemas/User"),
+            ]
+        );
+        assert_eq!(report.conflicts[0].kept, "users.json");
+        assert_eq!(report.conflicts[0].dropped, "orders.json");
+
+        let mut third = documents().remove(1);
+        third.0 = "third.json".to_string();
+        third.1["openapi"] = json!("3.1.0");
+        third.1["paths"]["/orders"]["get"]["deprecated"] = json!(true);
+        let mut inputs = documents();
+        inputs.push(third);
+        let report = merge_documents(&inputs);
+        let orders = report
+            .conflicts
+            .iter()
+            .find(|conflict| conflict.location == "GET /orders")
+            .unwrap();
+        assert_eq!(orders.kept, "orders.json");
+        assert!(report
+            .conflicts
+            .iter()
+            .any(|conflict| conflict.kind == ConflictKind::Version));
+        assert!(report
+            .to_text()
+            .contains("GET /orders: different operations (kept orders.json, dropped third.json)"));
+    }
+
+    #[tokio::test]
+    async fn test_merge_files_reads_compressed_and_rejects_yaml() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let [users, orders] = documents().try_into().unwrap();
+        let users_path = dir.path().join("users.json");
+        std::fs::write(&users_path, users.1.to_string()).unwrap();
+        let orders_path = dir.path().join("orders.json.gz");
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gzip.write_all(orders.1.to_string().as_bytes()).unwrap();
+        std::fs::write(&orders_path, gzip.finish().unwrap()).unwrap();
+
+        let report = merge_files(&[&users_path, &orders_path]).await.unwrap();
+        assert!(report.merged["paths"]["/orders"].is_object());
+        assert!(report.merged["paths"]["/users"].is_object());
+
+        for name in ["orders.yaml", "orders.yml.gz"] {
+            let yaml = dir.path().join(name);
+            std::fs::write(&yaml, "openapi: 3.0.0\n").unwrap();
+            let error = merge_files(&[&users_path, &yaml]).await.unwrap_err();
+            assert!(error.to_string().contains("YAML specs are not supported"));
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Strict validation: the document checked against the official OpenAPI
+//! 3.0 or 3.1 JSON Schema (bundled in `schemas/`), which catches the
+//! structural mistakes parsing tolerates: misspelled or misplaced
+//! keywords, values of the wrong type, missing required members.
+
+use crate::lint::{LintFinding, LintRule};
+use crate::parser::{HTTP_METHODS, WEBHOOK_PREFIX};
+use anyhow::{Context, Result};
+use jsonschema::{error::ValidationErrorKind, ValidationError, Validator};
+use serde_json::Value;
+use std::path::Path;
+use std::sync::OnceLock;
+
+const OPENAPI_30: &str = include_str!("../schemas/openapi-3.0.json");
+const OPENAPI_31: &str = include_str!("../schemas/openapi-3.1.json");
+
+/// Compiled once, on first use.
+fn validator(schema: &'static str, compiled: &'static OnceLock<Validator>) -> &'static Validator {
+    compiled.get_or_init(|| {
+        let schema: Value = serde_json::from_str(schema).expect("bundled schema is JSON");
+        jsonschema::validator_for(&schema).expect("bundled schema is valid")
+    })
+}
+
+/// Where `document` does not match the JSON Schema of its OpenAPI
+/// version, located by JSON pointer. Documents of other versions (and
+/// AsyncAPI ones) are not checked.
+pub fn validate(document: &Value) -> Vec<LintFinding> {
+    static OPENAPI_30_VALIDATOR: OnceLock<Validator> = OnceLock::new();
+    static OPENAPI_31_VALIDATOR: OnceLock<Validator> = OnceLock::new();
+
+    let version = document.get("openapi").and_then(Value::as_str);
+    let validator = match version {
+        Some(version) if version.starts_with("3.0.") => {
+            validator(OPENAPI_30, &OPENAPI_30_VALIDATOR)
+        }
+        Some(version) if version.starts_with("3.1.") => {
+            validator(OPENAPI_31, &OPENAPI_31_VALIDATOR)
+        }
+        _ => return Vec::new(),
+    };
+    let mut findings = Vec::new();
+    for error in validator.iter_errors(document) {
+        explain(&error, &mut findings);
+    }
+    findings
+}
+
+/// Findings of `error`. A value matching none of the alternatives of a
+/// `oneOf` or `anyOf` (in 3.0, mostly "a `$ref` or the object itself") is
+/// explained by the alternative it got furthest into, rather than by
+/// "not valid under any of the schemas".
+fn explain(error: &ValidationError, findings: &mut Vec<LintFinding>) {
+    let alternatives = match error.kind() {
+        ValidationErrorKind::OneOfNotValid { context } | ValidationErrorKind::AnyOf { context } => {
+            context
+        }
+        _ => &Vec::new(),
+    };
+    let mut scored: Vec<_> = alternatives
+        .iter()
+        // An object without `$ref` was not meant as a Reference Object
+        .filter(|errors| !errors.is_empty() && !errors.iter().any(requires_ref))
+        .map(|errors| {
+            let depth = errors
+                .iter()
+                .map(|error| error.instance_path().as_str().matches('/').count())
+                .max()
+                .unwrap_or(0);
+            ((depth, std::cmp::Reverse(errors.len())), errors)
+        })
+        .collect();
+    scored.sort_by_key(|(score, _)| *score);
+    // Alternatives as close as each other do not tell which was meant
+    let closest = match scored.as_slice() {
+        [.., (runner_up, _), (best, _)] if runner_up == best => None,
+        [.., (_, errors)] => Some(errors),
+        [] => None,
+    };
+    match closest {
+        Some(errors) => errors.iter().for_each(|error| explain(error, findings)),
+        None => findings.push(LintFinding {
+            rule: LintRule::MetaSchema,
+            location: pointer_or_root(error.instance_path().as_str()),
+            message: error.masked().to_string(),
+        }),
+    }
+}
+
+/// [`validate`] for the spec file at `path`.
+pub fn validate_file(path: &Path) -> Result<Vec<LintFinding>> {
+    let bytes = std::fs::read(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    let content = crate::compression::decode_spec(bytes)?;
+    let document: Value = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a JSON document", path.display()))?;
+    Ok(validate(&document))
+}
+
+fn requires_ref(error: &ValidationError) -> bool {
+    matches!(error.kind(), ValidationErrorKind::Required { property } if property == "$ref")
+}
+
+fn pointer_or_root(pointer: &str) -> String {
+    if pointer.is_empty() {
+        "/".to_string()
+    } else {
+        pointer.to_string()
+    }
+}
+
+/// The operation (`GET /users`), path or component schema a JSON pointer
+/// of a violation falls under, for jumping to it.
+pub fn pointer_target(pointer: &str) -> Option<String> {
+    let mut tokens = pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"));
+    let (section, name) = (tokens.next()?, tokens.next()?);
+    match section.as_str() {
+        "paths" | "webhooks" => {
+            let target = match section.as_str() {
+                "paths" => name,
+                _ => format!("{}{}", WEBHOOK_PREFIX, name),
+            };
+            match tokens
+                .next()
+                .filter(|method| HTTP_METHODS.contains(&method.as_str()))
+            {
+                Some(method) => Some(format!("{} {}", method.to_uppercase(), target)),
+                None => Some(target),
+            }
+        }
+        "components" if name == "schemas" => tokens.next(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_violations_are_located_by_pointer() {
+        let document = json!({
+            "openapi": "3.0.3",
+            "info": {"title": "T", "version": "1"},
+            "paths": {"/users": {"get": {
+                "responses": {"200": {"descriptions": "typo"}}
+            }}}
+        });
+        let findings = validate(&document);
+        // Explained by the Response Object alternative, not the Reference one
+        let messages: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|finding| (finding.location.as_str(), finding.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "/paths/~1users/get/responses/200",
+                    "\"description\" is a required property"
+                ),
+                (
+                    "/paths/~1users/get/responses/200",
+                    "Additional properties are not allowed ('descriptions' was unexpected)"
+                ),
+            ]
+        );
+        assert_eq!(findings[0].rule, LintRule::MetaSchema);
+
+        let mut valid = document.clone();
+        valid["paths"]["/users"]["get"]["responses"]["200"] = json!({"description": "ok"});
+        assert_eq!(validate(&valid), []);
+        // 3.1 documents may describe webhooks only
+        let webhooks = json!({
+            "openapi": "3.1.0",
+            "info": {"title": "T", "version": "1"},
+            "webhooks": {}
+        });
+        assert_eq!(validate(&webhooks), []);
+        let untitled = validate(&json!({"openapi": "3.1.0", "info": {"version": "1"}}));
+        let locations: Vec<&str> = untitled.iter().map(|f| f.location.as_str()).collect();
+        assert_eq!(locations, ["/info", "/"]);
+        // Missing all of paths, components and webhooks: no alternative stands out
+        assert!(untitled[1].message.contains("anyOf"));
+        // Not checked
+        assert_eq!(validate(&json!({"swagger": "2.0"})), []);
+    }
+
+    #[test]
+    fn test_pointer_targets() {
+        assert_eq!(
+            pointer_target("/paths/~1users~1{id}/get/responses").as_deref(),
+            Some("GET /users/{id}")
+        );
+        assert_eq!(
+            pointer_target("/paths/~1users/parameters/0").as_deref(),
+            Some("/users")
+        );
+        assert_eq!(
+            pointer_target("/webhooks/newPet/post").as_deref(),
+            Some("POST webhook:newPet")
+        );
+        assert_eq!(
+            pointer_target("/components/schemas/User/properties").as_deref(),
+            Some("User")
+        );
+        assert_eq!(pointer_target("/info"), None);
+    }
+}
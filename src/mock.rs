@@ -0,0 +1,221 @@
+use crate::example::generate_example;
+use crate::indexer::FieldIndex;
+use crate::parser::OpenApiSpec;
+use anyhow::Result;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, Method, Request, StatusCode},
+    response::{IntoResponse, Response},
+    Router,
+};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Canned response for one operation of the spec.
+#[derive(Debug, Clone)]
+pub struct MockRoute {
+    pub method: String,
+    /// Path template, e.g. `/pets/{petId}`
+    pub path: String,
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Option<Value>,
+}
+
+impl MockRoute {
+    /// Whether `path` matches this route's template; `{param}` segments
+    /// match any non-empty segment.
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        if !self.method.eq_ignore_ascii_case(method) {
+            return false;
+        }
+
+        let template: Vec<&str> = self.path.trim_matches('/').split('/').collect();
+        let actual: Vec<&str> = path.trim_matches('/').split('/').collect();
+        template.len() == actual.len()
+            && template.iter().zip(&actual).all(|(expected, segment)| {
+                (is_path_parameter(expected) && !segment.is_empty()) || expected == segment
+            })
+    }
+
+    fn parameter_count(&self) -> usize {
+        self.path
+            .split('/')
+            .filter(|s| is_path_parameter(s))
+            .count()
+    }
+}
+
+fn is_path_parameter(segment: &str) -> bool {
+    segment.starts_with('{') && segment.ends_with('}')
+}
+
+/// One route per operation, answering with the lowest documented 2xx status
+/// and an example body generated from its schema. Literal paths are listed
+/// before templated ones so `/pets/mine` wins over `/pets/{id}`.
+pub fn build_mock_routes(openapi_spec: &OpenApiSpec, index: &FieldIndex) -> Vec<MockRoute> {
+    let mut routes = Vec::new();
+
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            let mut statuses: Vec<&String> = operation
+                .responses
+                .keys()
+                .filter(|status| status.starts_with('2'))
+                .collect();
+            statuses.sort_unstable();
+
+            let (status, response) = match statuses.first() {
+                Some(status) => (
+                    status.parse().unwrap_or(200),
+                    operation.responses.get(*status),
+                ),
+                None => (200, None),
+            };
+
+            let media = response
+                .and_then(|r| r.content.as_ref())
+                .and_then(|content| {
+                    content
+                        .get("application/json")
+                        .map(|m| ("application/json", m))
+                        .or_else(|| content.iter().next().map(|(ct, m)| (ct.as_str(), m)))
+                });
+
+            routes.push(MockRoute {
+                method: method.to_uppercase(),
+                path: path.clone(),
+                status,
+                content_type: media.map(|(content_type, _)| content_type.to_string()),
+                body: media
+                    .and_then(|(_, m)| m.schema.as_ref())
+                    .map(|schema| generate_example(schema, &index.schemas)),
+            });
+        }
+    }
+
+    routes.sort_by(|a, b| {
+        a.parameter_count()
+            .cmp(&b.parameter_count())
+            .then(a.path.cmp(&b.path))
+            .then(a.method.cmp(&b.method))
+    });
+    routes
+}
+
+/// Serves example responses for every operation of the spec until the
+/// process is stopped.
+pub async fn serve(openapi_spec: &OpenApiSpec, index: &FieldIndex, port: u16) -> Result<()> {
+    let routes = Arc::new(build_mock_routes(openapi_spec, index));
+    for route in routes.iter() {
+        log::info!("{} {} -> {}", route.method, route.path, route.status);
+    }
+
+    let app = Router::new().fallback(handle).with_state(routes);
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    println!("Mock server listening on http://{}", addr);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn handle(State(routes): State<Arc<Vec<MockRoute>>>, request: Request<Body>) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    log::debug!("{} {}", method, path);
+
+    // HEAD behaves like GET without a body
+    let lookup = if method == Method::HEAD {
+        Method::GET
+    } else {
+        method.clone()
+    };
+
+    match routes.iter().find(|r| r.matches(lookup.as_str(), &path)) {
+        Some(route) => {
+            let status = StatusCode::from_u16(route.status).unwrap_or(StatusCode::OK);
+            match &route.body {
+                Some(body) => (
+                    status,
+                    [(
+                        header::CONTENT_TYPE,
+                        route
+                            .content_type
+                            .clone()
+                            .unwrap_or_else(|| "application/json".to_string()),
+                    )],
+                    body.to_string(),
+                )
+                    .into_response(),
+                None => status.into_response(),
+            }
+        }
+        None => (
+            StatusCode::NOT_FOUND,
+            [(header::CONTENT_TYPE, "application/json")],
+            json!({"error": format!("No operation for {} {}", method, path)}).to_string(),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    fn create_test_spec() -> OpenApiSpec {
+        parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/pets/{petId}": {"get": {"responses": {
+                        "404": {"description": "missing"},
+                        "200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}}
+                    }}},
+                    "/pets/mine": {"get": {"responses": {"200": {"description": "ok"}}}},
+                    "/pets": {"post": {"responses": {"201": {"description": "created"}}}}
+                },
+                "components": {"schemas": {
+                    "Pet": {"type": "object", "properties": {"name": {"type": "string", "example": "Rex"}}}
+                }}
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_mock_routes() {
+        let spec = create_test_spec();
+        let routes = build_mock_routes(&spec, &build_field_index(&spec));
+
+        let pet = routes.iter().find(|r| r.path == "/pets/{petId}").unwrap();
+        assert_eq!(pet.status, 200);
+        assert_eq!(pet.body, Some(json!({"name": "Rex"})));
+
+        let create = routes.iter().find(|r| r.method == "POST").unwrap();
+        assert_eq!(create.status, 201);
+        assert!(create.body.is_none());
+    }
+
+    #[test]
+    fn test_route_matching_prefers_literal_paths() {
+        let spec = create_test_spec();
+        let routes = build_mock_routes(&spec, &build_field_index(&spec));
+
+        let find = |method: &str, path: &str| {
+            routes
+                .iter()
+                .find(|r| r.matches(method, path))
+                .map(|r| r.path.as_str())
+        };
+        assert_eq!(find("GET", "/pets/mine"), Some("/pets/mine"));
+        assert_eq!(find("get", "/pets/42"), Some("/pets/{petId}"));
+        assert_eq!(find("GET", "/pets"), None);
+        assert_eq!(find("DELETE", "/pets/42"), None);
+    }
+}
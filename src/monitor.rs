@@ -0,0 +1,187 @@
+use crate::diff::{diff_specs, SpecDiff};
+use crate::parser::{fetch_openapi_content, parse_openapi_content};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const SNAPSHOT_PREFIX: &str = "snapshot-";
+const CHANGELOG_PREFIX: &str = "changelog-";
+pub(crate) const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+#[derive(Debug, Clone)]
+pub struct MonitorOptions {
+    pub url: String,
+    pub snapshot_dir: PathBuf,
+    pub interval: Duration,
+    /// Run a single fetch/compare cycle (e.g. from cron) instead of looping
+    pub once: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct SnapshotOutcome {
+    /// New snapshot file, `None` when the spec did not change
+    pub snapshot: Option<PathBuf>,
+    pub changelog: Option<PathBuf>,
+    pub diff: Option<SpecDiff>,
+}
+
+impl SnapshotOutcome {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.diff
+            .as_ref()
+            .is_some_and(|diff| diff.has_breaking_changes())
+    }
+}
+
+/// Fetches the remote spec on a schedule and records snapshots until a
+/// breaking change is detected. Returns `true` if one was found.
+pub async fn run_monitor(options: &MonitorOptions) -> Result<bool> {
+    std::fs::create_dir_all(&options.snapshot_dir)?;
+
+    loop {
+        log::info!("Fetching {}", options.url);
+        let outcome = match fetch_openapi_content(&options.url).await {
+            Ok(content) => record_snapshot(&options.snapshot_dir, &content, Utc::now()),
+            Err(e) => Err(e),
+        };
+
+        match outcome {
+            Ok(outcome) => {
+                match (&outcome.snapshot, &outcome.diff) {
+                    (None, _) => log::info!("Spec unchanged since last snapshot"),
+                    (Some(path), None) => log::info!("Stored first snapshot {}", path.display()),
+                    (Some(path), Some(diff)) => log::info!(
+                        "Stored snapshot {} ({} change(s), {} breaking)",
+                        path.display(),
+                        diff.changes.len(),
+                        diff.breaking_changes().count()
+                    ),
+                }
+
+                if outcome.has_breaking_changes() {
+                    if let Some(changelog) = &outcome.changelog {
+                        log::error!("Breaking changes detected, see {}", changelog.display());
+                    }
+                    return Ok(true);
+                }
+            }
+            Err(e) if options.once => return Err(e),
+            Err(e) => log::error!("Monitoring cycle failed: {}", e),
+        }
+
+        if options.once {
+            return Ok(false);
+        }
+        tokio::time::sleep(options.interval).await;
+    }
+}
+
+/// Stores `content` as a dated snapshot in `dir` if it differs from the latest
+/// one, and writes a Markdown changelog describing the differences.
+pub fn record_snapshot(
+    dir: &Path,
+    content: &str,
+    timestamp: DateTime<Utc>,
+) -> Result<SnapshotOutcome> {
+    let new_spec = parse_openapi_content(content)?;
+    let previous = latest_snapshot(dir)?;
+
+    if let Some(previous_path) = &previous {
+        if std::fs::read_to_string(previous_path)? == content {
+            return Ok(SnapshotOutcome::default());
+        }
+    }
+
+    let stamp = timestamp.format(TIMESTAMP_FORMAT).to_string();
+    let snapshot_path = dir.join(format!("{}{}.json", SNAPSHOT_PREFIX, stamp));
+    std::fs::write(&snapshot_path, content)?;
+
+    let mut outcome = SnapshotOutcome {
+        snapshot: Some(snapshot_path),
+        ..Default::default()
+    };
+
+    if let Some(previous_path) = previous {
+        let old_spec = parse_openapi_content(&std::fs::read_to_string(&previous_path)?)?;
+        let diff = diff_specs(&old_spec, &new_spec);
+
+        let title = format!(
+            "API changes {} ({} → {})",
+            timestamp.format("%Y-%m-%d %H:%M UTC"),
+            old_spec.info.version,
+            new_spec.info.version
+        );
+        let changelog_path = dir.join(format!("{}{}.md", CHANGELOG_PREFIX, stamp));
+        std::fs::write(&changelog_path, diff.to_markdown(&title))?;
+
+        outcome.changelog = Some(changelog_path);
+        outcome.diff = Some(diff);
+    }
+
+    Ok(outcome)
+}
+
+/// Most recent snapshot in `dir`; timestamps sort lexicographically.
+fn latest_snapshot(dir: &Path) -> Result<Option<PathBuf>> {
+    if !dir.exists() {
+        return Ok(None);
+    }
+
+    let mut snapshots: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(SNAPSHOT_PREFIX) && n.ends_with(".json"))
+        })
+        .collect();
+    snapshots.sort();
+    Ok(snapshots.pop())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn spec(version: &str, paths: &str) -> String {
+        format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "T", "version": "{}"}}, "paths": {}}}"#,
+            version, paths
+        )
+    }
+
+    #[test]
+    fn test_record_snapshot_sequence() {
+        let dir = tempfile::tempdir().unwrap();
+        let day1 = Utc.with_ymd_and_hms(2026, 1, 1, 2, 0, 0).unwrap();
+        let day2 = Utc.with_ymd_and_hms(2026, 1, 2, 2, 0, 0).unwrap();
+        let day3 = Utc.with_ymd_and_hms(2026, 1, 3, 2, 0, 0).unwrap();
+
+        let v1 = spec("1", r#"{"/a": {"get": {"responses": {}}}}"#);
+        let first = record_snapshot(dir.path(), &v1, day1).unwrap();
+        assert!(first.snapshot.is_some());
+        assert!(first.diff.is_none());
+
+        // Unchanged content does not create a new snapshot
+        let same = record_snapshot(dir.path(), &v1, day2).unwrap();
+        assert!(same.snapshot.is_none());
+
+        let v2 = spec("2", "{}");
+        let second = record_snapshot(dir.path(), &v2, day3).unwrap();
+        assert!(second.has_breaking_changes());
+
+        let changelog = std::fs::read_to_string(second.changelog.unwrap()).unwrap();
+        assert!(changelog.contains("(1 → 2)"));
+        assert!(changelog.contains("endpoint removed"));
+        assert!(dir.path().join("snapshot-20260103T020000Z.json").exists());
+    }
+
+    #[test]
+    fn test_record_snapshot_rejects_invalid_spec() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(record_snapshot(dir.path(), "not json", Utc::now()).is_err());
+        assert!(latest_snapshot(dir.path()).unwrap().is_none());
+    }
+}
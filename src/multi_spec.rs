@@ -0,0 +1,206 @@
+use crate::cache::{load_indexed, SpecCache};
+use crate::indexer::FieldIndex;
+use crate::parser::OpenApiSpec;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// One spec of a multi-spec session. The active spec lives in `App`; the
+/// others are parked here until switched to.
+#[derive(Debug)]
+pub struct SpecEntry {
+    pub name: String,
+    pub file_path: Option<PathBuf>,
+    pub parked: Option<(OpenApiSpec, FieldIndex)>,
+}
+
+/// Display name of a spec: its title, or the file stem for untitled specs.
+pub fn spec_name(openapi_spec: &OpenApiSpec, file_path: Option<&Path>) -> String {
+    let title = openapi_spec.info.title.trim();
+    if !title.is_empty() {
+        return title.to_string();
+    }
+    file_path
+        .and_then(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "spec".to_string())
+}
+
+/// Headline counts of one loaded spec, for the cross-spec totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecSummary {
+    pub name: String,
+    pub paths: usize,
+    pub operations: usize,
+    pub schemas: usize,
+    pub fields: usize,
+}
+
+impl SpecSummary {
+    pub fn of(name: &str, openapi_spec: &OpenApiSpec, field_index: &FieldIndex) -> Self {
+        Self {
+            name: name.to_string(),
+            paths: openapi_spec.paths.len(),
+            operations: field_index.endpoint_fields.len(),
+            schemas: field_index.schemas.len(),
+            fields: field_index.fields.len(),
+        }
+    }
+}
+
+/// Parses and indexes every file, failing on the first unreadable one.
+pub async fn load_specs(
+    paths: &[PathBuf],
+    cache: Option<&SpecCache>,
+    max_depth: usize,
+    lenient: bool,
+) -> Result<Vec<(OpenApiSpec, FieldIndex, PathBuf)>> {
+    let mut loaded = Vec::with_capacity(paths.len());
+    for path in paths {
+        let (spec, index) = load_indexed(path, cache, max_depth, lenient)
+            .await
+            .with_context(|| format!("Failed to load {}", path.display()))?;
+        loaded.push((spec, index, path.clone()));
+    }
+    Ok(loaded)
+}
+
+/// [`load_specs`] for the files of a watched directory: the files that
+/// fail to load are returned with their error instead of failing the rest.
+pub async fn load_specs_skipping_failures(
+    paths: &[PathBuf],
+    cache: Option<&SpecCache>,
+    max_depth: usize,
+    lenient: bool,
+) -> (
+    Vec<(OpenApiSpec, FieldIndex, PathBuf)>,
+    Vec<(PathBuf, anyhow::Error)>,
+) {
+    let mut loaded = Vec::with_capacity(paths.len());
+    let mut failed = Vec::new();
+    for path in paths {
+        match load_indexed(path, cache, max_depth, lenient).await {
+            Ok((spec, index)) => loaded.push((spec, index, path.clone())),
+            Err(error) => failed.push((path.clone(), error)),
+        }
+    }
+    (loaded, failed)
+}
+
+/// A field as declared by one spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldUsage {
+    pub spec: String,
+    pub field_type: String,
+    pub schemas: Vec<String>,
+    pub endpoints: usize,
+}
+
+/// Field name to its usages across all loaded specs.
+#[derive(Debug, Default)]
+pub struct CrossSpecIndex {
+    pub fields: BTreeMap<String, Vec<FieldUsage>>,
+}
+
+impl CrossSpecIndex {
+    pub fn build<'a>(specs: impl IntoIterator<Item = (&'a str, &'a FieldIndex)>) -> Self {
+        let mut fields: BTreeMap<String, Vec<FieldUsage>> = BTreeMap::new();
+        for (spec, index) in specs {
+            for (name, data) in &index.fields {
+                let mut schemas: Vec<String> = data.schemas.iter().map(|s| s.to_string()).collect();
+                schemas.sort();
+                fields.entry(name.clone()).or_default().push(FieldUsage {
+                    spec: spec.to_string(),
+                    field_type: data.field_type.clone(),
+                    schemas,
+                    endpoints: data.endpoints.len(),
+                });
+            }
+        }
+        Self { fields }
+    }
+
+    pub fn usages(&self, field_name: &str) -> &[FieldUsage] {
+        self.fields
+            .get(field_name)
+            .map(|usages| usages.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Fields declared by more than one spec
+    pub fn shared_fields(&self) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|(_, usages)| usages.len() > 1)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Shared fields whose type differs between specs
+    pub fn type_conflicts(&self) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|(_, usages)| {
+                usages
+                    .iter()
+                    .any(|usage| usage.field_type != usages[0].field_type)
+            })
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    fn index(schema: &str) -> FieldIndex {
+        let spec = parse_openapi_content(&format!(
+            r#"{{
+                "openapi": "3.0.0",
+                "info": {{"title": "T", "version": "1"}},
+                "paths": {{}},
+                "components": {{"schemas": {{"Model": {}}}}}
+            }}"#,
+            schema
+        ))
+        .unwrap();
+        build_field_index(&spec)
+    }
+
+    #[test]
+    fn test_cross_spec_index() {
+        let users = index(
+            r#"{"type": "object", "properties": {"user_id": {"type": "string"}, "email": {"type": "string"}}}"#,
+        );
+        let orders = index(
+            r#"{"type": "object", "properties": {"user_id": {"type": "integer"}, "total": {"type": "number"}}}"#,
+        );
+
+        let cross = CrossSpecIndex::build([("users", &users), ("orders", &orders)]);
+        assert_eq!(cross.shared_fields(), vec!["user_id"]);
+        assert_eq!(cross.type_conflicts(), vec!["user_id"]);
+
+        let usages = cross.usages("user_id");
+        assert_eq!(usages.len(), 2);
+        assert_eq!(usages[1].spec, "orders");
+        assert_eq!(usages[1].schemas, vec!["Model".to_string()]);
+        assert!(cross.usages("missing").is_empty());
+    }
+
+    #[test]
+    fn test_spec_name_falls_back_to_file_stem() {
+        let mut spec = parse_openapi_content(
+            r#"{"openapi": "3.0.0", "info": {"title": " ", "version": "1"}, "paths": {}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            spec_name(&spec, Some(Path::new("specs/billing.yaml"))),
+            "billing"
+        );
+        spec.info.title = "Billing API".to_string();
+        assert_eq!(spec_name(&spec, None), "Billing API");
+    }
+}
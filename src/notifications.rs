@@ -0,0 +1,154 @@
+//! Toasts shown in the status bar for a few seconds, with the history of
+//! past ones kept for the notification popup.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Past notifications kept for the history popup
+pub const HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Success,
+    Error,
+}
+
+impl Level {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Level::Info => "ℹ",
+            Level::Success => "✓",
+            Level::Error => "✗",
+        }
+    }
+
+    /// How long the toast stays in the status bar: errors longer, so they
+    /// can be read.
+    pub fn duration(self) -> Duration {
+        match self {
+            Level::Info | Level::Success => Duration::from_secs(4),
+            Level::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub level: Level,
+    pub message: String,
+    pub at: Instant,
+}
+
+impl Notification {
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.at) >= self.level.duration()
+    }
+}
+
+/// Notifications, newest last. Only the newest one is shown as a toast,
+/// until it expires or is dismissed.
+#[derive(Debug, Clone, Default)]
+pub struct Notifications {
+    history: VecDeque<Notification>,
+    dismissed: bool,
+}
+
+impl Notifications {
+    pub fn push(&mut self, level: Level, message: impl Into<String>) {
+        self.push_at(level, message, Instant::now());
+    }
+
+    pub fn push_at(&mut self, level: Level, message: impl Into<String>, at: Instant) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(Notification {
+            level,
+            message: message.into(),
+            at,
+        });
+        self.dismissed = false;
+    }
+
+    /// The toast to show now, if any.
+    pub fn current(&self) -> Option<&Notification> {
+        self.current_at(Instant::now())
+    }
+
+    pub fn current_at(&self, now: Instant) -> Option<&Notification> {
+        self.history
+            .back()
+            .filter(|notification| !self.dismissed && !notification.is_expired(now))
+    }
+
+    /// Hides the current toast; it stays in the history.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    /// The newest notification, shown or not.
+    pub fn latest(&self) -> Option<&Notification> {
+        self.history.back()
+    }
+
+    /// Every kept notification, newest first.
+    pub fn history(&self) -> impl Iterator<Item = &Notification> {
+        self.history.iter().rev()
+    }
+
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_expires_and_dismisses() {
+        let start = Instant::now();
+        let mut notifications = Notifications::default();
+        notifications.push_at(Level::Success, "Exported", start);
+        let shown = |n: &Notifications, after: u64| {
+            n.current_at(start + Duration::from_secs(after))
+                .map(|toast| toast.message.clone())
+        };
+        assert_eq!(shown(&notifications, 1).as_deref(), Some("Exported"));
+        assert_eq!(shown(&notifications, 5), None);
+
+        // Errors stay longer
+        notifications.push_at(Level::Error, "Export failed", start);
+        assert_eq!(shown(&notifications, 5).as_deref(), Some("Export failed"));
+        notifications.dismiss();
+        assert_eq!(shown(&notifications, 1), None);
+        assert_eq!(notifications.latest().unwrap().level, Level::Error);
+
+        // A new toast shows again
+        notifications.push_at(Level::Info, "Sorting: natural", start);
+        assert_eq!(
+            shown(&notifications, 1).as_deref(),
+            Some("Sorting: natural")
+        );
+    }
+
+    #[test]
+    fn test_history_is_newest_first_and_bounded() {
+        let mut notifications = Notifications::default();
+        for i in 0..HISTORY_LIMIT + 5 {
+            notifications.push(Level::Info, format!("message {}", i));
+        }
+        assert_eq!(notifications.len(), HISTORY_LIMIT);
+        let messages: Vec<&str> = notifications
+            .history()
+            .map(|notification| notification.message.as_str())
+            .collect();
+        assert_eq!(messages[0], format!("message {}", HISTORY_LIMIT + 4));
+        assert_eq!(messages.last().copied(), Some("message 5"));
+    }
+}
@@ -0,0 +1,333 @@
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Operation, Schema};
+use std::collections::{BTreeMap, HashMap};
+
+/// Query parameters selecting a page by opaque token
+const CURSOR_PARAMETERS: &[&str] = &[
+    "cursor",
+    "after",
+    "before",
+    "pagetoken",
+    "nexttoken",
+    "continuationtoken",
+    "startingafter",
+    "endingbefore",
+];
+/// Query parameters skipping a number of items
+const OFFSET_PARAMETERS: &[&str] = &["offset", "skip", "start"];
+/// Query parameters selecting a page by number
+const PAGE_PARAMETERS: &[&str] = &["page", "pagenumber", "pageno"];
+/// Query parameters bounding the page size, reported as evidence only
+const SIZE_PARAMETERS: &[&str] = &["limit", "pagesize", "perpage", "size", "count", "top"];
+/// Response properties carrying the token of the next page
+const CURSOR_FIELDS: &[&str] = &["nextcursor", "cursor", "nextpagetoken", "nexttoken"];
+/// Response properties linking to the next page
+const LINK_FIELDS: &[&str] = &["next", "nextpage", "nextlink", "links", "_links"];
+/// Envelope properties holding the items of a collection
+const ITEMS_FIELDS: &[&str] = &[
+    "data", "items", "results", "records", "content", "entries", "values", "elements",
+];
+
+/// Pagination convention of an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaginationStyle {
+    Cursor,
+    Offset,
+    Page,
+    /// `next` link in the body or a `Link` response header
+    NextLink,
+}
+
+impl PaginationStyle {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PaginationStyle::Cursor => "cursor",
+            PaginationStyle::Offset => "offset/limit",
+            PaginationStyle::Page => "page number",
+            PaginationStyle::NextLink => "next link",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaginatedEndpoint {
+    pub endpoint: String,
+    pub style: PaginationStyle,
+    /// Parameters, response properties and headers the style was read from
+    pub evidence: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaginationReport {
+    /// Paginated endpoints by style, each list sorted by endpoint
+    pub by_style: BTreeMap<PaginationStyle, Vec<PaginatedEndpoint>>,
+    /// GET endpoints returning a collection without any pagination
+    pub unpaginated_collections: Vec<String>,
+}
+
+impl PaginationReport {
+    /// More than one style in use across the API.
+    pub fn is_mixed(&self) -> bool {
+        self.by_style.len() > 1
+    }
+}
+
+/// Detects the pagination convention of every path operation from its
+/// query parameters, response properties and `Link` headers.
+pub fn analyze_pagination(openapi_spec: &OpenApiSpec) -> PaginationReport {
+    let empty = HashMap::new();
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|c| c.schemas.as_ref())
+        .unwrap_or(&empty);
+
+    let mut report = PaginationReport::default();
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            let endpoint = format!("{} {}", method.to_uppercase(), path);
+            let response = success_schema(operation).map(|schema| resolve(schema, schemas));
+            match detect_style(operation, response, schemas) {
+                Some((style, evidence)) => {
+                    report
+                        .by_style
+                        .entry(style)
+                        .or_default()
+                        .push(PaginatedEndpoint {
+                            endpoint,
+                            style,
+                            evidence,
+                        })
+                }
+                None if method.eq_ignore_ascii_case("get")
+                    && response.is_some_and(|schema| is_collection(schema, schemas)) =>
+                {
+                    report.unpaginated_collections.push(endpoint)
+                }
+                None => {}
+            }
+        }
+    }
+    for endpoints in report.by_style.values_mut() {
+        endpoints.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+    }
+    report.unpaginated_collections.sort_unstable();
+    report
+}
+
+/// Lowercase name without separators: `page_size` and `pageSize` both
+/// become `pagesize`.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn detect_style(
+    operation: &Operation,
+    response: Option<&Schema>,
+    schemas: &HashMap<String, Schema>,
+) -> Option<(PaginationStyle, Vec<String>)> {
+    let query: Vec<&str> = operation
+        .parameters
+        .iter()
+        .flatten()
+        .filter(|param| param.in_ == "query")
+        .map(|param| param.name.as_str())
+        .collect();
+    let matching = |names: &[&str]| -> Vec<String> {
+        query
+            .iter()
+            .filter(|param| names.contains(&normalize(param).as_str()))
+            .map(|param| param.to_string())
+            .collect()
+    };
+
+    for (style, names) in [
+        (PaginationStyle::Cursor, CURSOR_PARAMETERS),
+        (PaginationStyle::Offset, OFFSET_PARAMETERS),
+        (PaginationStyle::Page, PAGE_PARAMETERS),
+    ] {
+        let mut evidence = matching(names);
+        if !evidence.is_empty() {
+            evidence.extend(matching(SIZE_PARAMETERS));
+            return Some((style, evidence));
+        }
+    }
+
+    let properties: Vec<&String> = response
+        .map(|schema| {
+            object_properties(schema, schemas)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        })
+        .unwrap_or_default();
+    let matching_fields = |names: &[&str]| -> Vec<String> {
+        properties
+            .iter()
+            .filter(|name| names.contains(&normalize(name).as_str()))
+            .map(|name| name.to_string())
+            .collect()
+    };
+    let cursor_fields = matching_fields(CURSOR_FIELDS);
+    if !cursor_fields.is_empty() {
+        return Some((PaginationStyle::Cursor, cursor_fields));
+    }
+    let mut links = matching_fields(LINK_FIELDS);
+    let link_header = operation
+        .responses
+        .iter()
+        .filter(|(status, _)| status.starts_with('2'))
+        .filter_map(|(_, response)| response.headers.as_ref())
+        .flat_map(|headers| headers.keys())
+        .find(|name| name.eq_ignore_ascii_case("link"));
+    if let Some(header) = link_header {
+        links.push(format!("{} header", header));
+    }
+    (!links.is_empty()).then_some((PaginationStyle::NextLink, links))
+}
+
+/// JSON schema of the first 2xx response declaring one.
+fn success_schema(operation: &Operation) -> Option<&Schema> {
+    let mut statuses: Vec<&String> = operation
+        .responses
+        .keys()
+        .filter(|status| status.starts_with('2'))
+        .collect();
+    statuses.sort_unstable();
+    statuses.into_iter().find_map(|status| {
+        let content = operation.responses[status].content.as_ref()?;
+        content
+            .get("application/json")
+            .or_else(|| {
+                content
+                    .iter()
+                    .find(|(media_type, _)| media_type.contains("json"))
+                    .map(|(_, media)| media)
+            })?
+            .schema
+            .as_ref()
+    })
+}
+
+/// Follows component references, up to a few hops.
+fn resolve<'a>(schema: &'a Schema, schemas: &'a HashMap<String, Schema>) -> &'a Schema {
+    let mut current = schema;
+    for _ in 0..8 {
+        match current
+            .reference
+            .as_deref()
+            .and_then(extract_schema_name_from_ref)
+            .and_then(|name| schemas.get(name))
+        {
+            Some(target) => current = target,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Properties of an object schema, `allOf` parts included.
+fn object_properties<'a>(
+    schema: &'a Schema,
+    schemas: &'a HashMap<String, Schema>,
+) -> Vec<(&'a String, &'a Schema)> {
+    let schema = resolve(schema, schemas);
+    let mut properties: Vec<(&String, &Schema)> = schema.properties.iter().flatten().collect();
+    for part in schema.all_of.iter().flatten() {
+        properties.extend(object_properties(part, schemas));
+    }
+    properties
+}
+
+/// An array, or an envelope object with an array of items.
+fn is_collection(schema: &Schema, schemas: &HashMap<String, Schema>) -> bool {
+    schema.schema_type.as_deref() == Some("array")
+        || object_properties(schema, schemas)
+            .into_iter()
+            .any(|(name, property)| {
+                ITEMS_FIELDS.contains(&normalize(name).as_str())
+                    && resolve(property, schemas).schema_type.as_deref() == Some("array")
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_detects_styles_and_unpaginated_collections() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {"get": {
+                        "parameters": [
+                            {"name": "page", "in": "query"},
+                            {"name": "page_size", "in": "query"}
+                        ],
+                        "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/UserPage"}}}}}
+                    }},
+                    "/orders": {"get": {
+                        "parameters": [{"name": "limit", "in": "query"}],
+                        "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/OrderList"}}}}}
+                    }},
+                    "/events": {"get": {
+                        "parameters": [{"name": "startingAfter", "in": "query"}],
+                        "responses": {"200": {"description": "ok"}}
+                    }},
+                    "/invoices": {"get": {
+                        "responses": {"200": {"description": "ok", "headers": {"Link": {"schema": {"type": "string"}}}, "content": {"application/json": {"schema": {"type": "array", "items": {"type": "object"}}}}}}
+                    }},
+                    "/tags": {"get": {
+                        "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "array", "items": {"type": "string"}}}}}}
+                    }},
+                    "/me": {"get": {
+                        "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "object", "properties": {"id": {"type": "string"}}}}}}}
+                    }}
+                },
+                "components": {"schemas": {
+                    "UserPage": {"type": "object", "properties": {"items": {"type": "array", "items": {"type": "object"}}}},
+                    "OrderList": {"type": "object", "properties": {
+                        "data": {"type": "array", "items": {"type": "object"}},
+                        "next_cursor": {"type": "string"}
+                    }}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let report = analyze_pagination(&spec);
+        let styles: Vec<(PaginationStyle, Vec<&str>)> = report
+            .by_style
+            .iter()
+            .map(|(style, endpoints)| {
+                (
+                    *style,
+                    endpoints.iter().map(|e| e.endpoint.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            vec![
+                (PaginationStyle::Cursor, vec!["GET /events", "GET /orders"]),
+                (PaginationStyle::Page, vec!["GET /users"]),
+                (PaginationStyle::NextLink, vec!["GET /invoices"]),
+            ]
+        );
+        assert_eq!(
+            report.by_style[&PaginationStyle::Page][0].evidence,
+            vec!["page", "page_size"]
+        );
+        assert_eq!(
+            report.by_style[&PaginationStyle::NextLink][0].evidence,
+            vec!["Link header"]
+        );
+        assert_eq!(report.unpaginated_collections, vec!["GET /tags"]);
+        assert!(report.is_mixed());
+    }
+}
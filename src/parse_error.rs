@@ -0,0 +1,419 @@
+//! Parse failures located in the source document: line and column, JSON
+//! pointer of the failing element and the lines around it.
+
+use std::fmt;
+
+/// Lines shown before the failing line in a snippet
+const CONTEXT_BEFORE: usize = 2;
+/// Lines shown after it
+const CONTEXT_AFTER: usize = 1;
+/// Characters of each snippet line kept around the error column, so
+/// minified documents stay readable
+const SNIPPET_WIDTH: usize = 80;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    /// 1-based line of the error
+    pub line: usize,
+    /// 1-based column of the error, in characters
+    pub column: usize,
+    /// JSON pointer of the element that failed, unless it is the document
+    pub pointer: Option<String>,
+    /// Numbered source lines around the error, clipped to a common window
+    pub snippet: Vec<(usize, String)>,
+    /// 1-based column of the error within the clipped snippet lines
+    pub snippet_column: usize,
+}
+
+impl ParseError {
+    /// Error for a document that is not valid JSON, pointing at the
+    /// element being read when parsing stopped.
+    pub fn syntax(content: &str, error: &serde_json::Error) -> Self {
+        let (line, column) = (error.line().max(1), error.column().max(1));
+        let message = error.to_string();
+        let message = message
+            .strip_suffix(&format!(
+                " at line {} column {}",
+                error.line(),
+                error.column()
+            ))
+            .unwrap_or(&message)
+            .to_string();
+        let pointer = pointer_at(content, offset_of(content, line, column));
+        Self::new(content, message, line, column, pointer)
+    }
+
+    /// Error for the element at `pointer`, located at its start in the
+    /// source (or the start of the document when it cannot be found).
+    pub fn at_pointer(content: &str, pointer: &str, message: String) -> Self {
+        let offset = locate_pointer(content, pointer).unwrap_or(0);
+        let (line, column) = position_of(content, offset);
+        let pointer = (!pointer.is_empty()).then(|| pointer.to_string());
+        Self::new(content, message, line, column, pointer)
+    }
+
+    fn new(
+        content: &str,
+        message: String,
+        line: usize,
+        column: usize,
+        pointer: Option<String>,
+    ) -> Self {
+        let first = line.saturating_sub(CONTEXT_BEFORE).max(1);
+        // Lines are only scrolled when the error would be near the edge
+        let window_start = if column + 10 > SNIPPET_WIDTH {
+            column - SNIPPET_WIDTH / 2
+        } else {
+            0
+        };
+        let snippet = content
+            .lines()
+            .enumerate()
+            .skip(first - 1)
+            .take(line + CONTEXT_AFTER + 1 - first)
+            .map(|(index, text)| {
+                let clipped: String = text
+                    .chars()
+                    .skip(window_start)
+                    .take(SNIPPET_WIDTH)
+                    .collect();
+                (index + 1, clipped.trim_end().to_string())
+            })
+            .collect();
+        Self {
+            message,
+            line,
+            column,
+            pointer,
+            snippet,
+            snippet_column: column - window_start,
+        }
+    }
+
+    /// The snippet as numbered lines, with a caret under the error column.
+    pub fn snippet_text(&self) -> String {
+        let width = self
+            .snippet
+            .last()
+            .map_or(1, |(number, _)| number.to_string().len());
+        let mut text = String::new();
+        for (number, line) in &self.snippet {
+            text.push_str(&format!("{:>width$} | {}\n", number, line, width = width));
+            if *number == self.line {
+                text.push_str(&format!(
+                    "{:>width$} | {}^\n",
+                    "",
+                    " ".repeat(self.snippet_column - 1),
+                    width = width
+                ));
+            }
+        }
+        text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to parse OpenAPI file at line {}, column {}",
+            self.line, self.column
+        )?;
+        if let Some(pointer) = &self.pointer {
+            write!(f, " ({})", pointer)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of a 1-based line and character column.
+fn offset_of(content: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = content
+        .split_inclusive('\n')
+        .take(line - 1)
+        .map(str::len)
+        .sum();
+    content[line_start..]
+        .char_indices()
+        .nth(column - 1)
+        .map_or(content.len(), |(offset, _)| line_start + offset)
+}
+
+/// 1-based line and character column of a byte offset.
+fn position_of(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Pointer of the element being read at `offset`, or None at the root:
+/// where the walk hit invalid JSON, else the last value started by then.
+fn pointer_at(content: &str, offset: usize) -> Option<String> {
+    let mut walker = Walker::new(content);
+    let mut last = Vec::new();
+    let walk = walker.value(&mut |path, start| {
+        if start > offset {
+            return true;
+        }
+        last = path.to_vec();
+        false
+    });
+    let path = match walk {
+        Err(Stop::Invalid) => walker.path,
+        _ => last,
+    };
+    (!path.is_empty()).then(|| {
+        path.iter()
+            .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+            .collect()
+    })
+}
+
+/// Byte offset where the value at `pointer` starts.
+fn locate_pointer(content: &str, pointer: &str) -> Option<usize> {
+    let target: Vec<String> = pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect();
+    let mut found = None;
+    let _ = Walker::new(content).value(&mut |path, start| {
+        let matched = path == target.as_slice();
+        if matched {
+            found = Some(start);
+        }
+        matched
+    });
+    found
+}
+
+/// Why a walk ended early
+enum Stop {
+    /// The visitor found what it was looking for
+    Found,
+    /// The document is not valid JSON here
+    Invalid,
+}
+
+/// Minimal JSON reader following the path to each value, for mapping
+/// source offsets to pointers and back. Scalars are skipped, not checked.
+struct Walker<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    path: Vec<String>,
+}
+
+impl<'a> Walker<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            bytes: content.as_bytes(),
+            pos: 0,
+            path: Vec::new(),
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|b| b.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Stop> {
+        if self.peek() != Some(byte) {
+            return Err(Stop::Invalid);
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Reads one value, calling `visit` with its path and start offset
+    /// first; the walk stops when `visit` returns true.
+    fn value(&mut self, visit: &mut dyn FnMut(&[String], usize) -> bool) -> Result<(), Stop> {
+        let start = self.peek().ok_or(Stop::Invalid)?;
+        if visit(&self.path, self.pos) {
+            return Err(Stop::Found);
+        }
+        match start {
+            b'{' => {
+                self.pos += 1;
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                loop {
+                    if self.peek() != Some(b'"') {
+                        return Err(Stop::Invalid);
+                    }
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    self.path.push(key);
+                    self.value(visit)?;
+                    self.path.pop();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(());
+                        }
+                        _ => return Err(Stop::Invalid),
+                    }
+                }
+            }
+            b'[' => {
+                self.pos += 1;
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                for index in 0.. {
+                    self.path.push(index.to_string());
+                    self.value(visit)?;
+                    self.path.pop();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(());
+                        }
+                        _ => return Err(Stop::Invalid),
+                    }
+                }
+                Ok(())
+            }
+            b'"' => self.string().map(|_| ()),
+            _ => {
+                let scalar_start = self.pos;
+                while self
+                    .bytes
+                    .get(self.pos)
+                    .is_some_and(|b| !b",]}".contains(b) && !b.is_ascii_whitespace())
+                {
+                    self.pos += 1;
+                }
+                if self.pos == scalar_start {
+                    return Err(Stop::Invalid);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Reads a string at the current position, decoding escapes.
+    fn string(&mut self) -> Result<String, Stop> {
+        self.expect(b'"')?;
+        let mut text = Vec::new();
+        loop {
+            let byte = *self.bytes.get(self.pos).ok_or(Stop::Invalid)?;
+            self.pos += 1;
+            match byte {
+                b'"' => return Ok(String::from_utf8_lossy(&text).into_owned()),
+                b'\\' => {
+                    let escaped = *self.bytes.get(self.pos).ok_or(Stop::Invalid)?;
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => text.push(b'\n'),
+                        b't' => text.push(b'\t'),
+                        b'r' => text.push(b'\r'),
+                        b'b' => text.push(0x08),
+                        b'f' => text.push(0x0c),
+                        b'u' => {
+                            let code = self
+                                .bytes
+                                .get(self.pos..self.pos + 4)
+                                .and_then(|hex| std::str::from_utf8(hex).ok())
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .ok_or(Stop::Invalid)?;
+                            self.pos += 4;
+                            let decoded = char::from_u32(code).unwrap_or('\u{fffd}');
+                            text.extend_from_slice(decoded.to_string().as_bytes());
+                        }
+                        other => text.push(other),
+                    }
+                }
+                other => text.push(other),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{
+  "openapi": "3.0.0",
+  "paths": {
+    "/users": {
+      "post": {"responses": {"201": {}}}
+    }
+  },
+  "tags": ["a", "b"]
+}"#;
+
+    #[test]
+    fn test_pointer_is_located_in_source() {
+        let error = ParseError::at_pointer(
+            DOCUMENT,
+            "/paths/~1users/post",
+            "missing field `description`".to_string(),
+        );
+        assert_eq!((error.line, error.column), (5, 15));
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse OpenAPI file at line 5, column 15 (/paths/~1users/post): missing field `description`"
+        );
+        assert_eq!(
+            error.snippet_text(),
+            concat!(
+                "3 |   \"paths\": {\n",
+                "4 |     \"/users\": {\n",
+                "5 |       \"post\": {\"responses\": {\"201\": {}}}\n",
+                "  |               ^\n",
+                "6 |     }\n",
+            )
+        );
+        assert_eq!(
+            locate_pointer(DOCUMENT, "/tags/1"),
+            Some(DOCUMENT.len() - 6)
+        );
+        assert_eq!(locate_pointer(DOCUMENT, "/missing"), None);
+    }
+
+    #[test]
+    fn test_syntax_error_points_at_element() {
+        let content = DOCUMENT.replace(r#""201": {}"#, r#""201": {,}"#);
+        let error = serde_json::from_str::<serde_json::Value>(&content).unwrap_err();
+        let error = ParseError::syntax(&content, &error);
+        assert_eq!(error.line, 5);
+        assert_eq!(
+            error.pointer.as_deref(),
+            Some("/paths/~1users/post/responses/201")
+        );
+        assert!(!error.message.contains("at line"));
+    }
+
+    #[test]
+    fn test_long_lines_are_clipped_around_the_error() {
+        let content = format!(r#"{{"padding": "{}", "bad": tru}}"#, "x".repeat(300));
+        let error = serde_json::from_str::<serde_json::Value>(&content).unwrap_err();
+        let error = ParseError::syntax(&content, &error);
+        assert_eq!(error.pointer.as_deref(), Some("/bad"));
+        let (_, line) = &error.snippet[0];
+        assert!(line.chars().count() <= SNIPPET_WIDTH);
+        assert_eq!(
+            line.chars().nth(error.snippet_column - 1),
+            content.chars().nth(error.column - 1)
+        );
+    }
+}
@@ -1,14 +1,207 @@
-use anyhow::{anyhow, Result};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use crate::asyncapi;
+use crate::compression;
+use crate::parse_error::ParseError;
+use crate::progress::{no_progress, LoadStage, Progress};
+use crate::remote;
+use crate::schema_graph::find_cycles;
+use anyhow::{anyhow, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::sync::OnceLock;
 use tokio::fs;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenApiSpec {
     pub openapi: String,
     pub info: Info,
+    pub servers: Option<Vec<Server>>,
     pub paths: HashMap<String, PathItem>,
+    /// Events the API sends to its consumers (OpenAPI 3.1), by name
+    pub webhooks: Option<HashMap<String, PathItem>>,
     pub components: Option<Components>,
+    /// Descriptions and documentation of the tags operations use
+    pub tags: Option<Vec<Tag>>,
+    #[serde(rename = "externalDocs")]
+    pub external_docs: Option<ExternalDocs>,
+    /// Elements lenient parsing dropped, empty for a strict parse
+    #[serde(skip)]
+    pub parse_warnings: Vec<ParseWarning>,
+    /// Components the paths and webhooks use, directly or through other
+    /// components, as `section/name`; set by parsing, since inlining drops
+    /// the `$ref`s (see [`referenced_components`])
+    #[serde(
+        rename = "x-referenced-components",
+        default,
+        skip_serializing_if = "BTreeSet::is_empty"
+    )]
+    pub referenced_components: BTreeSet<String>,
+    /// Rules not reported for the whole document (see
+    /// [`crate::lint::IGNORE_EXTENSION`])
+    #[serde(
+        rename = "x-explorer-ignore",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub explorer_ignore: Option<serde_json::Value>,
+    /// Parameters, responses, request bodies, headers, links, path items
+    /// and callbacks dropped by parsing because their `$ref` does not
+    /// resolve, as `location: reason` (see [`inline_component_refs`])
+    #[serde(
+        rename = "x-unresolved-refs",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub unresolved_refs: Vec<String>,
+    #[serde(skip)]
+    pub callback_targets: CallbackTargets,
+}
+
+/// Element of the document dropped by lenient parsing because it does not
+/// deserialize, located by its JSON pointer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParseWarning {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.message)
+    }
+}
+
+/// Prefix of webhook targets in endpoint keys, e.g. `POST webhook:newPet`
+pub const WEBHOOK_PREFIX: &str = "webhook:";
+/// Prefix of callback targets in endpoint keys, followed by the declaring
+/// operation and the callback name, e.g. `POST callback:POST /subs/onEvent`
+pub const CALLBACK_PREFIX: &str = "callback:";
+
+/// Whether `target` (an endpoint key without its method) is a webhook or a
+/// callback rather than a path.
+pub fn is_event_target(target: &str) -> bool {
+    target.starts_with(WEBHOOK_PREFIX) || target.starts_with(CALLBACK_PREFIX)
+}
+
+/// Callback targets of a spec by name, built on first use.
+#[derive(Debug, Clone, Default)]
+pub struct CallbackTargets(OnceLock<BTreeMap<String, CallbackLocation>>);
+
+/// Where a callback target is declared.
+#[derive(Debug, Clone)]
+struct CallbackLocation {
+    path: String,
+    method: String,
+    name: String,
+    expression: String,
+}
+
+impl OpenApiSpec {
+    /// Webhooks then operation callbacks, as `webhook:name` /
+    /// `callback:METHOD /path/name` targets sorted by name. A callback
+    /// declaring several expressions is listed once per expression, which
+    /// is appended to its target.
+    pub fn event_targets(&self) -> Vec<(String, &PathItem)> {
+        let mut webhooks: Vec<(String, &PathItem)> = self
+            .webhooks
+            .iter()
+            .flatten()
+            .map(|(name, item)| (format!("{}{}", WEBHOOK_PREFIX, name), item))
+            .collect();
+        webhooks.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let callbacks = self
+            .callback_targets()
+            .keys()
+            .filter_map(|target| Some((target.clone(), self.path_item(target)?)));
+        webhooks.extend(callbacks);
+        webhooks
+    }
+
+    fn callback_targets(&self) -> &BTreeMap<String, CallbackLocation> {
+        self.callback_targets.0.get_or_init(|| {
+            let mut targets = BTreeMap::new();
+            for (path, item) in &self.paths {
+                for (method, operation) in &item.operations {
+                    for (name, expressions) in operation.callbacks.iter().flatten() {
+                        let target = format!(
+                            "{}{} {}/{}",
+                            CALLBACK_PREFIX,
+                            method.to_uppercase(),
+                            path,
+                            name
+                        );
+                        for expression in expressions.keys() {
+                            let target = if expressions.len() > 1 {
+                                format!("{} {}", target, expression)
+                            } else {
+                                target.clone()
+                            };
+                            let location = CallbackLocation {
+                                path: path.clone(),
+                                method: method.clone(),
+                                name: name.clone(),
+                                expression: expression.clone(),
+                            };
+                            targets.insert(target, location);
+                        }
+                    }
+                }
+            }
+            targets
+        })
+    }
+
+    /// Operation of an endpoint key, e.g. `GET /users`.
+    pub fn operation(&self, endpoint: &str) -> Option<&Operation> {
+        let (method, target) = endpoint.split_once(' ')?;
+        self.path_item(target)?
+            .operations
+            .get(&method.to_lowercase())
+    }
+
+    /// Documentation declared for a tag in the top-level `tags`.
+    pub fn tag_docs(&self, name: &str) -> Option<&ExternalDocs> {
+        self.tags
+            .iter()
+            .flatten()
+            .find(|tag| tag.name == name)?
+            .external_docs
+            .as_ref()
+    }
+
+    /// Documentation of an operation: its own, else that of its first
+    /// documented tag.
+    pub fn operation_docs(&self, endpoint: &str) -> Option<&ExternalDocs> {
+        let operation = self.operation(endpoint)?;
+        operation.external_docs.as_ref().or_else(|| {
+            operation
+                .tags
+                .iter()
+                .flatten()
+                .find_map(|tag| self.tag_docs(tag))
+        })
+    }
+
+    /// The path item of a path or event target.
+    pub fn path_item(&self, target: &str) -> Option<&PathItem> {
+        if let Some(name) = target.strip_prefix(WEBHOOK_PREFIX) {
+            return self.webhooks.as_ref()?.get(name);
+        }
+        if target.starts_with(CALLBACK_PREFIX) {
+            let location = self.callback_targets().get(target)?;
+            return self
+                .paths
+                .get(&location.path)?
+                .operations
+                .get(&location.method)?
+                .callbacks
+                .as_ref()?
+                .get(&location.name)?
+                .get(&location.expression);
+        }
+        self.paths.get(target)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,8 +209,76 @@ pub struct Info {
     pub title: String,
     pub version: String,
     pub description: Option<String>,
+    #[serde(rename = "termsOfService")]
+    pub terms_of_service: Option<String>,
+    pub contact: Option<Contact>,
+    pub license: Option<License>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub email: Option<String>,
+}
+
+impl Contact {
+    /// Name, email and URL, those given, e.g. `API Team <api@example.com>`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(name) = &self.name {
+            parts.push(name.clone());
+        }
+        if let Some(email) = &self.email {
+            parts.push(format!("<{}>", email));
+        }
+        if let Some(url) = &self.url {
+            parts.push(url.clone());
+        }
+        parts.join(" ")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct License {
+    pub name: String,
+    pub url: Option<String>,
+    /// SPDX expression (OpenAPI 3.1)
+    pub identifier: Option<String>,
+}
+
+/// Link to documentation outside the spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalDocs {
+    pub url: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    pub name: String,
+    pub description: Option<String>,
+    pub external_docs: Option<ExternalDocs>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Server {
+    pub url: String,
+    pub description: Option<String>,
+    pub variables: Option<HashMap<String, ServerVariable>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerVariable {
+    pub default: String,
+    #[serde(rename = "enum")]
+    pub enum_: Option<Vec<String>>,
+    pub description: Option<String>,
+}
+
+/// Path-level `parameters` are merged into each operation while parsing
+/// (see [`inline_component_refs`]), so only operations are kept here.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PathItem {
     #[serde(flatten)]
@@ -25,6 +286,7 @@ pub struct PathItem {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Operation {
     pub operation_id: Option<String>,
     pub summary: Option<String>,
@@ -33,6 +295,24 @@ pub struct Operation {
     pub parameters: Option<Vec<Parameter>>,
     pub request_body: Option<RequestBody>,
     pub responses: HashMap<String, Response>,
+    pub deprecated: Option<bool>,
+    pub external_docs: Option<ExternalDocs>,
+    /// Out-of-band requests the API makes, by name then runtime expression
+    pub callbacks: Option<HashMap<String, HashMap<String, PathItem>>>,
+    /// Specification extensions (`x-...`) and keys not modelled above
+    #[serde(flatten)]
+    pub extensions: HashMap<String, serde_json::Value>,
+}
+
+impl Operation {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+    }
+
+    /// Value of a specification extension, e.g. `x-sunset`.
+    pub fn extension(&self, name: &str) -> Option<&serde_json::Value> {
+        self.extensions.get(name)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,26 +328,116 @@ pub struct Parameter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestBody {
     pub description: Option<String>,
+    pub required: Option<bool>,
     pub content: HashMap<String, MediaType>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Response {
     pub description: String,
+    pub headers: Option<HashMap<String, Header>>,
     pub content: Option<HashMap<String, MediaType>>,
+    /// Operations this response feeds, by link name
+    pub links: Option<HashMap<String, Link>>,
 }
 
+/// How values of a response feed the parameters or body of another
+/// operation, named by `operationId` or by an `operationRef` pointer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MediaType {
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    pub operation_id: Option<String>,
+    pub operation_ref: Option<String>,
+    /// Runtime expressions (e.g. `$response.body#/id`) by parameter name
+    pub parameters: Option<HashMap<String, serde_json::Value>>,
+    pub request_body: Option<serde_json::Value>,
+    pub description: Option<String>,
+}
+
+impl Link {
+    /// Endpoint key of the linked operation when given by `operationRef`
+    /// (`#/paths/~1users~1{id}/get` is `GET /users/{id}`).
+    pub fn operation_ref_endpoint(&self) -> Option<String> {
+        let pointer = self.operation_ref.as_deref()?;
+        let fragment = pointer.split_once('#').map_or(pointer, |(_, f)| f);
+        let rest = fragment.strip_prefix("/paths/")?;
+        let (path, method) = rest.rsplit_once('/')?;
+        let path = path.replace("~1", "/").replace("~0", "~");
+        Some(format!("{} {}", method.to_uppercase(), path))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub description: Option<String>,
     pub schema: Option<Schema>,
+    pub example: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaType {
+    pub schema: Option<Schema>,
+    /// Serialization of `multipart` and form-urlencoded properties, by
+    /// property name
+    pub encoding: Option<HashMap<String, Encoding>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Encoding {
+    /// Media type(s) of the part, e.g. `image/png, image/jpeg`
+    pub content_type: Option<String>,
+    /// Headers of a multipart part
+    pub headers: Option<HashMap<String, Header>>,
+    pub style: Option<String>,
+    pub explode: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct Components {
     pub schemas: Option<HashMap<String, Schema>>,
+    pub parameters: Option<HashMap<String, Parameter>>,
+    pub responses: Option<HashMap<String, Response>>,
+    pub request_bodies: Option<HashMap<String, RequestBody>>,
+    pub headers: Option<HashMap<String, Header>>,
+    pub security_schemes: Option<HashMap<String, SecurityScheme>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityScheme {
+    #[serde(rename = "type")]
+    pub scheme_type: Option<String>,
+    pub description: Option<String>,
+    /// Header, query or cookie name of an `apiKey`
+    pub name: Option<String>,
+    #[serde(rename = "in")]
+    pub in_: Option<String>,
+    /// HTTP authorization scheme, e.g. `bearer`
+    pub scheme: Option<String>,
+    pub bearer_format: Option<String>,
+}
+
+impl SecurityScheme {
+    /// `http bearer (JWT)`, `apiKey in header X-Api-Key`, `oauth2`.
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = self.scheme_type.iter().cloned().collect();
+        if let Some(scheme) = &self.scheme {
+            parts.push(scheme.clone());
+        }
+        if let Some(format) = &self.bearer_format {
+            parts.push(format!("({})", format));
+        }
+        if let (Some(location), Some(name)) = (&self.in_, &self.name) {
+            parts.push(format!("in {} {}", location, name));
+        }
+        parts.join(" ")
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct Schema {
     #[serde(rename = "type")]
     pub schema_type: Option<String>,
@@ -80,215 +450,916 @@ pub struct Schema {
     pub one_of: Option<Vec<Schema>>,
     pub any_of: Option<Vec<Schema>>,
     pub not: Option<Box<Schema>>,
+    #[serde(default, deserialize_with = "deserialize_additional_properties")]
     pub additional_properties: Option<Box<Schema>>,
     pub nullable: Option<bool>,
     pub read_only: Option<bool>,
     pub write_only: Option<bool>,
     pub example: Option<serde_json::Value>,
+    #[serde(rename = "enum")]
     pub enum_: Option<Vec<serde_json::Value>>,
     pub default: Option<serde_json::Value>,
+    /// Media type of string content, e.g. a file part (OpenAPI 3.1)
+    pub content_media_type: Option<String>,
+    pub min_length: Option<u64>,
+    pub max_length: Option<u64>,
+    pub pattern: Option<String>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub multiple_of: Option<f64>,
+    pub min_items: Option<u64>,
+    pub max_items: Option<u64>,
     #[serde(rename = "$ref")]
     pub reference: Option<String>,
+    pub discriminator: Option<Discriminator>,
+    pub external_docs: Option<ExternalDocs>,
+    /// Set by reference resolution on a `$ref` that closes a cycle
+    /// (`Node.children: [Node]`): whoever follows references stops there
+    #[serde(
+        rename = "x-recursive",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub recursive: bool,
+    /// Rules not reported for this component schema (see
+    /// [`crate::lint::IGNORE_EXTENSION`])
+    #[serde(
+        rename = "x-explorer-ignore",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub explorer_ignore: Option<serde_json::Value>,
 }
 
-pub async fn parse_openapi(file_path: &std::path::Path) -> Result<OpenApiSpec> {
-    if !file_path.exists() {
-        return Err(anyhow!("OpenAPI file not found: {}", file_path.display()));
+/// Property telling the variants of a polymorphic schema apart, with
+/// optional explicit value to schema mappings.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Discriminator {
+    pub property_name: String,
+    pub mapping: Option<HashMap<String, String>>,
+}
+
+/// One alternative of a polymorphic (`oneOf` / `anyOf`) schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaVariant {
+    /// Discriminator value selecting the variant; without a discriminator,
+    /// the referenced schema name or `#n` for inline variants
+    pub value: String,
+    /// Referenced component schema, `None` for inline variants
+    pub schema: Option<String>,
+    /// Fields declared inline by the variant
+    pub fields: Vec<String>,
+}
+
+/// `additionalProperties` may be a boolean or a schema; `true` maps to an empty
+/// schema and `false` to `None`.
+fn deserialize_additional_properties<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Box<Schema>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrSchema {
+        Bool(bool),
+        Schema(Box<Schema>),
     }
 
-    let content = fs::read_to_string(file_path).await?;
+    Ok(match Option::<BoolOrSchema>::deserialize(deserializer)? {
+        Some(BoolOrSchema::Schema(schema)) => Some(schema),
+        Some(BoolOrSchema::Bool(true)) => Some(Box::default()),
+        Some(BoolOrSchema::Bool(false)) | None => None,
+    })
+}
+
+pub async fn parse_openapi(file_path: &std::path::Path) -> Result<OpenApiSpec> {
+    let content = read_spec_content(file_path).await?;
+    parse_openapi_document(file_path, &content)
+}
+
+/// Spec path standing for the standard input (`-f -`)
+pub const STDIN_PATH: &str = "-";
 
-    // Try to parse as JSON first
-    if file_path.extension().and_then(|s| s.to_str()) == Some("json") {
-        let spec: OpenApiSpec = serde_json::from_str(&content)
-            .map_err(|e| anyhow!("Failed to parse OpenAPI JSON: {}", e))?;
-        return Ok(spec);
+pub fn is_stdin(path: &std::path::Path) -> bool {
+    path.as_os_str() == STDIN_PATH
+}
+
+/// Content of the spec file at `path`, or of the spec piped on stdin for
+/// [`STDIN_PATH`], decompressed when gzip or zstd compressed.
+pub async fn read_spec_content(path: &std::path::Path) -> Result<String> {
+    if is_stdin(path) {
+        return tokio::task::spawn_blocking(stdin_content).await?;
+    }
+    if !path.exists() {
+        return Err(anyhow!("OpenAPI file not found: {}", path.display()));
     }
+    compression::decode_spec(fs::read(path).await?)
+        .with_context(|| format!("Cannot read {}", path.display()))
+}
 
-    // Try YAML (for now, just attempt JSON parsing - YAML support can be added later)
-    let spec: OpenApiSpec = serde_json::from_str(&content)
-        .map_err(|e| anyhow!("Failed to parse OpenAPI file: {}", e))?;
+/// Standard input, read to the end on the first call; later calls (e.g.
+/// retrying a failed parse) get the same content.
+fn stdin_content() -> Result<String> {
+    use std::io::{IsTerminal, Read};
+    use std::sync::OnceLock;
 
-    Ok(spec)
+    static CONTENT: OnceLock<String> = OnceLock::new();
+    if let Some(content) = CONTENT.get() {
+        return Ok(content.clone());
+    }
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        return Err(anyhow!(
+            "No spec piped on stdin (e.g. `cat openapi.json | openapi-explorer --stdin`)"
+        ));
+    }
+    let mut bytes = Vec::new();
+    stdin
+        .read_to_end(&mut bytes)
+        .map_err(|e| anyhow!("Cannot read the spec from stdin: {}", e))?;
+    let content = compression::decode_spec(bytes).context("Cannot read the spec from stdin")?;
+    Ok(CONTENT.get_or_init(|| content).clone())
 }
 
-pub async fn parse_openapi_or_default(
-    file_path: &Option<std::path::PathBuf>,
+/// Parses the content of `file_path`, already read from disk.
+pub fn parse_openapi_document(file_path: &std::path::Path, content: &str) -> Result<OpenApiSpec> {
+    parse_openapi_document_with_progress(file_path, content, false, &no_progress)
+}
+
+/// [`parse_openapi_document`], reporting the parsing and reference
+/// resolution stages. When `lenient`, elements that do not deserialize are
+/// dropped instead of failing the parse (see [`drop_invalid_elements`]).
+/// Failures are [`ParseError`]s, located in `content`.
+pub fn parse_openapi_document_with_progress(
+    _file_path: &std::path::Path,
+    content: &str,
+    lenient: bool,
+    progress: Progress<'_>,
 ) -> Result<OpenApiSpec> {
-    match file_path {
-        Some(path) => parse_openapi(path).await,
-        None => {
-            // Try to find a default OpenAPI file in examples/
-            let default_path = std::path::Path::new("examples/petstore.json");
-            if default_path.exists() {
-                parse_openapi(default_path).await
-            } else {
-                Err(anyhow!(
-                    "No OpenAPI file specified and no default file found"
-                ))
-            }
+    // Only JSON for now, whatever the extension; the path is kept for
+    // telling formats apart once YAML support is added
+    Ok(deserialize_spec(content, lenient, progress)?)
+}
+
+pub fn parse_openapi_content(content: &str) -> Result<OpenApiSpec> {
+    Ok(deserialize_spec(content, false, &no_progress)?)
+}
+
+/// [`parse_openapi_content`], keeping what deserializes of a partially
+/// invalid document.
+pub fn parse_openapi_content_lenient(content: &str) -> Result<OpenApiSpec> {
+    Ok(deserialize_spec(content, true, &no_progress)?)
+}
+
+fn deserialize_spec(
+    content: &str,
+    lenient: bool,
+    progress: Progress<'_>,
+) -> std::result::Result<OpenApiSpec, ParseError> {
+    let parse = || {
+        let document = serde_json::from_str::<serde_json::Value>(content)
+            .map_err(|e| ParseError::syntax(content, &e))?;
+        match asyncapi::version(&document) {
+            None => Ok(document),
+            Some(version) if version.starts_with("2.") => Ok(asyncapi::to_openapi(&document)),
+            Some(version) => Err(ParseError::at_pointer(
+                content,
+                "/asyncapi",
+                format!("AsyncAPI {} is not supported, only 2.x", version),
+            )),
+        }
+    };
+    progress(LoadStage::Parsing);
+    let mut document = parse()?;
+    progress(LoadStage::ResolvingRefs);
+    let referenced = referenced_components(&document);
+    inline_component_refs(&mut document);
+    let error = match serde_json::from_value::<OpenApiSpec>(document) {
+        Ok(mut spec) => {
+            spec.referenced_components = referenced;
+            return Ok(spec);
+        }
+        Err(error) => error,
+    };
+
+    // The document was consumed; failures are rare enough to build it
+    // again for locating the invalid elements
+    let mut document = parse()?;
+    inline_component_refs(&mut document);
+    let parse_warnings = drop_invalid_elements(&mut document);
+    if lenient {
+        if let Ok(mut spec) = serde_json::from_value::<OpenApiSpec>(document) {
+            spec.parse_warnings = parse_warnings;
+            spec.referenced_components = referenced;
+            return Ok(spec);
         }
     }
+    Err(match parse_warnings.into_iter().next() {
+        Some(warning) => ParseError::at_pointer(content, &warning.pointer, warning.message),
+        None => ParseError::at_pointer(content, "", error.to_string()),
+    })
 }
 
-pub fn resolve_references(spec: &mut OpenApiSpec) -> Result<()> {
-    if let Some(components) = &mut spec.components {
-        if let Some(schemas) = &mut components.schemas {
-            resolve_schema_references(schemas)?;
-        }
+/// Removes from `document` every element that does not deserialize, and
+/// returns a warning for each: operations of paths and webhooks, servers
+/// and component entries. An invalid `info` or `openapi` is replaced by a
+/// placeholder, and an invalid section (e.g. `paths` that is not an
+/// object) emptied, so the rest of the spec stays explorable.
+pub fn drop_invalid_elements(document: &mut serde_json::Value) -> Vec<ParseWarning> {
+    use serde_json::{json, Value};
+
+    let mut warnings = Vec::new();
+    let Some(root) = document.as_object_mut() else {
+        return warnings;
+    };
+
+    if !root.get("openapi").is_some_and(Value::is_string) {
+        warnings.push(ParseWarning {
+            pointer: "/openapi".to_string(),
+            message: "missing or not a string, assuming 3.0.0".to_string(),
+        });
+        root.insert("openapi".to_string(), json!("3.0.0"));
+    }
+    let info = root.get("info").unwrap_or(&Value::Null);
+    if let Err(e) = Info::deserialize(info) {
+        warnings.push(ParseWarning {
+            pointer: "/info".to_string(),
+            message: e.to_string(),
+        });
+        let title = info.get("title").and_then(Value::as_str).unwrap_or("");
+        let version = info.get("version").and_then(Value::as_str).unwrap_or("");
+        root.insert(
+            "info".to_string(),
+            json!({"title": title, "version": version}),
+        );
     }
 
-    // Resolve references in paths
-    let spec_clone = spec.clone();
-    for path_item in spec.paths.values_mut() {
-        for operation in path_item.operations.values_mut() {
-            resolve_operation_references(operation, &spec_clone)?;
-        }
+    if root
+        .get("externalDocs")
+        .is_some_and(|docs| ExternalDocs::deserialize(docs).is_err())
+    {
+        root.remove("externalDocs");
+        warnings.push(ParseWarning {
+            pointer: "/externalDocs".to_string(),
+            message: "not a valid external documentation object".to_string(),
+        });
     }
 
-    Ok(())
-}
+    if let Some(servers) = root.get_mut("servers") {
+        match servers.as_array_mut() {
+            Some(entries) => {
+                let mut index = 0;
+                entries.retain(|server| {
+                    let pointer = format!("/servers/{}", index);
+                    index += 1;
+                    is_valid::<Server>(server, pointer, &mut warnings)
+                });
+            }
+            None => {
+                warnings.push(ParseWarning {
+                    pointer: "/servers".to_string(),
+                    message: "not an array".to_string(),
+                });
+                *servers = json!([]);
+            }
+        }
+    }
 
-fn resolve_schema_references(schemas: &mut HashMap<String, Schema>) -> Result<()> {
-    let schema_names: Vec<String> = schemas.keys().cloned().collect();
+    for section in ["paths", "webhooks"] {
+        let Some(items) = root.get_mut(section) else {
+            if section == "paths" {
+                warnings.push(ParseWarning {
+                    pointer: "/paths".to_string(),
+                    message: "missing".to_string(),
+                });
+                root.insert("paths".to_string(), json!({}));
+            }
+            continue;
+        };
+        let Some(items) = items.as_object_mut() else {
+            warnings.push(ParseWarning {
+                pointer: format!("/{}", section),
+                message: "not an object".to_string(),
+            });
+            *items = json!({});
+            continue;
+        };
+        items.retain(|target, item| {
+            let pointer = format!("/{}/{}", section, pointer_segment(target));
+            let Some(operations) = item.as_object_mut() else {
+                warnings.push(ParseWarning {
+                    pointer,
+                    message: "not an object".to_string(),
+                });
+                return false;
+            };
+            operations.retain(|method, operation| {
+                let pointer = format!("{}/{}", pointer, pointer_segment(method));
+                is_valid::<Operation>(operation, pointer, &mut warnings)
+            });
+            true
+        });
+    }
 
-    for schema_name in schema_names {
-        let schemas_clone = schemas.clone();
-        if let Some(schema) = schemas.get_mut(&schema_name) {
-            resolve_schema_refs_recursive(schema, &schemas_clone)?;
+    if let Some(components) = root.get_mut("components") {
+        let Some(sections) = components.as_object_mut() else {
+            warnings.push(ParseWarning {
+                pointer: "/components".to_string(),
+                message: "not an object".to_string(),
+            });
+            *components = json!({});
+            return warnings;
+        };
+        for (section, entries) in sections.iter_mut() {
+            let check: fn(&Value, String, &mut Vec<ParseWarning>) -> bool = match section.as_str() {
+                "schemas" => is_valid::<Schema>,
+                "parameters" => is_valid::<Parameter>,
+                "responses" => is_valid::<Response>,
+                "requestBodies" => is_valid::<RequestBody>,
+                "headers" => is_valid::<Header>,
+                "securitySchemes" => is_valid::<SecurityScheme>,
+                _ => continue,
+            };
+            let Some(entries) = entries.as_object_mut() else {
+                warnings.push(ParseWarning {
+                    pointer: format!("/components/{}", section),
+                    message: "not an object".to_string(),
+                });
+                *entries = json!({});
+                continue;
+            };
+            entries.retain(|name, entry| {
+                let pointer = format!("/components/{}/{}", section, pointer_segment(name));
+                check(entry, pointer, &mut warnings)
+            });
         }
     }
+    warnings
+}
 
-    Ok(())
+/// Whether `value` deserializes as a `T`, recording a warning at `pointer`
+/// when it does not.
+fn is_valid<T: DeserializeOwned>(
+    value: &serde_json::Value,
+    pointer: String,
+    warnings: &mut Vec<ParseWarning>,
+) -> bool {
+    match T::deserialize(value) {
+        Ok(_) => true,
+        Err(e) => {
+            warnings.push(ParseWarning {
+                pointer,
+                message: e.to_string(),
+            });
+            false
+        }
+    }
 }
 
-fn resolve_schema_refs_recursive(
-    schema: &mut Schema,
-    all_schemas: &HashMap<String, Schema>,
-) -> Result<()> {
-    // Resolve reference if present
-    if let Some(ref_path) = &schema.reference {
-        if let Some(target_name) = extract_schema_name_from_ref(ref_path) {
-            if let Some(target_schema) = all_schemas.get(target_name) {
-                // Copy properties from referenced schema
-                if schema.properties.is_none() {
-                    schema.properties = target_schema.properties.clone();
-                }
-                if schema.schema_type.is_none() {
-                    schema.schema_type = target_schema.schema_type.clone();
-                }
-                if schema.description.is_none() {
-                    schema.description = target_schema.description.clone();
-                }
-                if schema.required.is_none() {
-                    schema.required = target_schema.required.clone();
+/// Components reachable from outside `components` (paths, webhooks, ...):
+/// `$ref` targets and the security schemes named by `security`
+/// requirements, then what those components reference in turn. Each is
+/// `section/name`, e.g. `parameters/Limit`.
+pub fn referenced_components(document: &serde_json::Value) -> BTreeSet<String> {
+    use serde_json::Value;
+
+    fn collect_entry(key: &str, value: &Value, found: &mut BTreeSet<String>) {
+        match (key, value) {
+            ("$ref", Value::String(reference)) => {
+                if let Some((section, name)) = reference
+                    .strip_prefix("#/components/")
+                    .and_then(|rest| rest.split_once('/'))
+                {
+                    let name = name.split('/').next().unwrap_or(name);
+                    found.insert(format!("{}/{}", section, unescape_pointer(name)));
                 }
             }
+            ("security", Value::Array(requirements)) => found.extend(
+                requirements
+                    .iter()
+                    .filter_map(Value::as_object)
+                    .flat_map(|requirement| requirement.keys())
+                    .map(|name| format!("securitySchemes/{}", name)),
+            ),
+            _ => collect(value, found),
         }
-        schema.reference = None;
     }
 
-    // Resolve nested references
-    if let Some(properties) = &mut schema.properties {
-        for field_schema in properties.values_mut() {
-            resolve_schema_refs_recursive(field_schema, all_schemas)?;
+    fn collect(value: &Value, found: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    collect_entry(key, value, found);
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|item| collect(item, found)),
+            _ => {}
         }
     }
 
-    if let Some(items) = &mut schema.items {
-        resolve_schema_refs_recursive(items, all_schemas)?;
+    let mut referenced = BTreeSet::new();
+    for (key, value) in document.as_object().into_iter().flatten() {
+        if key != "components" {
+            collect_entry(key, value, &mut referenced);
+        }
     }
-
-    if let Some(all_of) = &mut schema.all_of {
-        for sub_schema in all_of {
-            resolve_schema_refs_recursive(sub_schema, all_schemas)?;
+    let components = document.get("components");
+    let mut pending: Vec<String> = referenced.iter().cloned().collect();
+    while let Some(target) = pending.pop() {
+        let Some((section, name)) = target.split_once('/') else {
+            continue;
+        };
+        let Some(component) = components.and_then(|c| c.get(section)?.get(name)) else {
+            continue;
+        };
+        let mut found = BTreeSet::new();
+        collect(component, &mut found);
+        for reference in found {
+            if referenced.insert(reference.clone()) {
+                pending.push(reference);
+            }
         }
     }
+    referenced
+}
+
+/// A JSON pointer segment unescaped (`~1` is `/`, `~0` is `~`).
+pub fn unescape_pointer(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// `segment` escaped for use in a JSON pointer.
+pub fn pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
 
-    if let Some(one_of) = &mut schema.one_of {
-        for sub_schema in one_of {
-            resolve_schema_refs_recursive(sub_schema, all_schemas)?;
+/// Operation keys of a path item; the other keys (`parameters`, `summary`,
+/// `servers`, ...) apply to every operation.
+pub const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Chained references (a component pointing at another) followed at most
+/// this deep, which also stops reference cycles
+const MAX_REF_DEPTH: usize = 8;
+
+/// Replaces `$ref`s to `components/parameters`, `components/responses`,
+/// `components/requestBodies` and `components/headers` in operations by
+/// copies of their targets, and merges path-level parameters into each
+/// operation (an operation parameter with the same name and location wins).
+/// Webhooks and operation callbacks are processed like paths, after
+/// resolving their own `components/pathItems` and `components/callbacks`
+/// references. Elements whose reference does not resolve are dropped, and
+/// listed under `x-unresolved-refs` for the lint to report. Schema
+/// references are kept, those closing a cycle marked `x-recursive` (see
+/// [`mark_recursive_schema_refs`]).
+pub fn inline_component_refs(document: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    let components = document.get("components").cloned().unwrap_or(Value::Null);
+    let mut unresolved = Vec::new();
+
+    // Components may alias each other; store them resolved as well
+    for section in ["parameters", "responses", "requestBodies", "headers"] {
+        if let Some(entries) = document
+            .get_mut("components")
+            .and_then(|c| c.get_mut(section))
+            .and_then(Value::as_object_mut)
+        {
+            let resolved: serde_json::Map<String, Value> = entries
+                .iter()
+                .filter_map(|(name, entry)| {
+                    let context = format!("{}/{}", section, name);
+                    let mut entry =
+                        resolve_component(entry, section, &components, &context, &mut unresolved)?;
+                    if section == "responses" {
+                        inline_header_refs(&mut entry, &components, &context, &mut unresolved);
+                        inline_link_refs(&mut entry, &components, &context, &mut unresolved);
+                    }
+                    Some((name.clone(), entry))
+                })
+                .collect();
+            *entries = resolved;
         }
     }
 
-    if let Some(any_of) = &mut schema.any_of {
-        for sub_schema in any_of {
-            resolve_schema_refs_recursive(sub_schema, all_schemas)?;
+    for section in ["paths", "webhooks"] {
+        if let Some(items) = document.get_mut(section).and_then(Value::as_object_mut) {
+            let resolved: serde_json::Map<String, Value> = items
+                .iter()
+                .filter_map(|(target, path_item)| {
+                    let mut path_item = resolve_component(
+                        path_item,
+                        "pathItems",
+                        &components,
+                        target,
+                        &mut unresolved,
+                    )?;
+                    inline_path_item_refs(&mut path_item, target, &components, &mut unresolved);
+                    Some((target.clone(), path_item))
+                })
+                .collect();
+            *items = resolved;
         }
     }
 
-    Ok(())
+    if !unresolved.is_empty() {
+        let listed = document.as_object_mut().map(|document| {
+            document
+                .entry("x-unresolved-refs")
+                .or_insert(Value::Array(Vec::new()))
+        });
+        if let Some(Value::Array(listed)) = listed {
+            listed.extend(unresolved.into_iter().map(Value::String));
+        }
+    }
+    mark_recursive_schema_refs(document);
 }
 
-fn resolve_operation_references(operation: &mut Operation, spec: &OpenApiSpec) -> Result<()> {
-    // Resolve parameter references
-    if let Some(parameters) = &mut operation.parameters {
-        for parameter in parameters {
-            if let Some(schema) = &mut parameter.schema {
-                resolve_parameter_schema_refs(schema, spec)?;
+/// Inlines the references of one path item, webhook or callback expression.
+fn inline_path_item_refs(
+    path_item: &mut serde_json::Value,
+    path: &str,
+    components: &serde_json::Value,
+    unresolved: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    let Some(path_item) = path_item.as_object_mut() else {
+        return;
+    };
+    let shared: Vec<Value> = match path_item.remove("parameters") {
+        Some(Value::Array(parameters)) => parameters
+            .iter()
+            .filter_map(|p| resolve_component(p, "parameters", components, path, unresolved))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for method in HTTP_METHODS {
+        let Some(operation) = path_item.get_mut(*method).and_then(Value::as_object_mut) else {
+            continue;
+        };
+        let context = format!("{} {}", method.to_uppercase(), path);
+        let mut parameters: Vec<Value> = operation
+            .get("parameters")
+            .and_then(Value::as_array)
+            .map(|parameters| {
+                parameters
+                    .iter()
+                    .filter_map(|p| {
+                        resolve_component(p, "parameters", components, &context, unresolved)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        for inherited in &shared {
+            let overridden = parameters.iter().any(|p| {
+                p.get("name") == inherited.get("name") && p.get("in") == inherited.get("in")
+            });
+            if !overridden {
+                parameters.push(inherited.clone());
             }
         }
+        // An operation whose parameters were all dropped lists none
+        let listed = operation.get("parameters").is_some_and(Value::is_array);
+        if !parameters.is_empty() || listed {
+            operation.insert("parameters".to_string(), Value::Array(parameters));
+        }
+
+        if let Some(body) = operation.remove("requestBody") {
+            if let Some(body) =
+                resolve_component(&body, "requestBodies", components, &context, unresolved)
+            {
+                operation.insert("requestBody".to_string(), body);
+            }
+        }
+
+        if let Some(responses) = operation
+            .get_mut("responses")
+            .and_then(Value::as_object_mut)
+        {
+            let resolved: serde_json::Map<String, Value> = responses
+                .iter()
+                .filter_map(|(status, response)| {
+                    let mut response =
+                        resolve_component(response, "responses", components, &context, unresolved)?;
+                    inline_header_refs(&mut response, components, &context, unresolved);
+                    inline_link_refs(&mut response, components, &context, unresolved);
+                    Some((status.clone(), response))
+                })
+                .collect();
+            *responses = resolved;
+        }
+
+        if let Some(callbacks) = operation
+            .get_mut("callbacks")
+            .and_then(Value::as_object_mut)
+        {
+            let resolved: serde_json::Map<String, Value> = callbacks
+                .iter()
+                .filter_map(|(name, expressions)| {
+                    let context = format!("{} callback {}", context, name);
+                    let mut expressions = resolve_component(
+                        expressions,
+                        "callbacks",
+                        components,
+                        &context,
+                        unresolved,
+                    )?;
+                    for (expression, item) in expressions.as_object_mut().into_iter().flatten() {
+                        inline_path_item_refs(
+                            item,
+                            &format!("{} {}", context, expression),
+                            components,
+                            unresolved,
+                        );
+                    }
+                    Some((name.clone(), expressions))
+                })
+                .collect();
+            *callbacks = resolved;
+        }
+    }
+}
+
+/// Resolves `$ref`s to `components/headers` in a response's `headers`.
+fn inline_header_refs(
+    response: &mut serde_json::Value,
+    components: &serde_json::Value,
+    context: &str,
+    unresolved: &mut Vec<String>,
+) {
+    if let Some(headers) = response
+        .get_mut("headers")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        let resolved: serde_json::Map<String, serde_json::Value> = headers
+            .iter()
+            .filter_map(|(name, header)| {
+                resolve_component(header, "headers", components, context, unresolved)
+                    .map(|header| (name.clone(), header))
+            })
+            .collect();
+        *headers = resolved;
+    }
+}
+
+/// Resolves `$ref`s to `components/links` in a response's `links`.
+fn inline_link_refs(
+    response: &mut serde_json::Value,
+    components: &serde_json::Value,
+    context: &str,
+    unresolved: &mut Vec<String>,
+) {
+    if let Some(links) = response
+        .get_mut("links")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        let resolved: serde_json::Map<String, serde_json::Value> = links
+            .iter()
+            .filter_map(|(name, link)| {
+                resolve_component(link, "links", components, context, unresolved)
+                    .map(|link| (name.clone(), link))
+            })
+            .collect();
+        *links = resolved;
     }
+}
 
-    // Resolve request body references
-    if let Some(request_body) = &mut operation.request_body {
-        for media_type in request_body.content.values_mut() {
-            if let Some(schema) = &mut media_type.schema {
-                resolve_parameter_schema_refs(schema, spec)?;
+/// `value` itself, or the component its `$ref` points to in `section`;
+/// None, with a message in `unresolved`, when it does not resolve.
+fn resolve_component(
+    value: &serde_json::Value,
+    section: &str,
+    components: &serde_json::Value,
+    context: &str,
+    unresolved: &mut Vec<String>,
+) -> Option<serde_json::Value> {
+    let mut current = value;
+    for _ in 0..MAX_REF_DEPTH {
+        let Some(reference) = current.get("$ref").and_then(|r| r.as_str()) else {
+            return Some(current.clone());
+        };
+        let target = reference
+            .strip_prefix("#/components/")
+            .and_then(|rest| rest.strip_prefix(section))
+            .and_then(|rest| rest.strip_prefix('/'))
+            .map(|name| name.replace("~1", "/").replace("~0", "~"))
+            .and_then(|name| components.get(section)?.get(name));
+        match target {
+            Some(target) => current = target,
+            None => {
+                let message = format!("{}: unresolved reference '{}'", context, reference);
+                log::warn!("{}", message);
+                unresolved.push(message);
+                return None;
             }
         }
     }
+    let message = format!("{}: reference chain too deep or cyclic", context);
+    log::warn!("{}", message);
+    unresolved.push(message);
+    None
+}
+
+/// Downloads the raw specification document from `url`, accepting gzip
+/// and zstd encoded (or compressed) documents.
+pub async fn fetch_openapi_content(url: &str) -> Result<String> {
+    fetch_openapi_content_with(&reqwest::Client::new(), url).await
+}
+
+/// [`fetch_openapi_content`] through `client` (timeouts, connection reuse).
+pub async fn fetch_openapi_content_with(client: &reqwest::Client, url: &str) -> Result<String> {
+    match remote::fetch(client, url, &remote::Validators::default()).await? {
+        remote::Fetched::Modified { content, .. } => Ok(content),
+        remote::Fetched::NotModified => Err(anyhow!("{} answered 304 to a plain request", url)),
+    }
+}
 
-    // Resolve response references
-    for response in operation.responses.values_mut() {
-        if let Some(content) = &mut response.content {
-            for media_type in content.values_mut() {
-                if let Some(schema) = &mut media_type.schema {
-                    resolve_parameter_schema_refs(schema, spec)?;
-                }
+pub async fn parse_openapi_url(url: &str) -> Result<OpenApiSpec> {
+    let content = fetch_openapi_content(url).await?;
+    parse_openapi_content(&content)
+}
+
+pub async fn parse_openapi_or_default(
+    file_path: &Option<std::path::PathBuf>,
+) -> Result<OpenApiSpec> {
+    parse_openapi(&spec_path_or_default(file_path)?).await
+}
+
+/// The given spec path, or the bundled `examples/petstore.json` when none
+/// is specified.
+pub fn spec_path_or_default(file_path: &Option<std::path::PathBuf>) -> Result<std::path::PathBuf> {
+    match file_path {
+        Some(path) => Ok(path.clone()),
+        None => {
+            // Try to find a default OpenAPI file in examples/
+            let default_path = std::path::Path::new("examples/petstore.json");
+            if default_path.exists() {
+                Ok(default_path.to_path_buf())
+            } else {
+                Err(anyhow!(
+                    "No OpenAPI file specified and no default file found"
+                ))
             }
         }
     }
+}
 
+/// Resolves the references of a spec built or modified in code, as parsing
+/// does (see [`inline_component_refs`]).
+pub fn resolve_references(spec: &mut OpenApiSpec) -> Result<()> {
+    let mut document = serde_json::to_value(&*spec)?;
+    inline_component_refs(&mut document);
+    let resolved: OpenApiSpec = serde_json::from_value(document)?;
+    spec.paths = resolved.paths;
+    spec.webhooks = resolved.webhooks;
+    spec.components = resolved.components;
+    spec.callback_targets = CallbackTargets::default();
     Ok(())
 }
 
-fn resolve_parameter_schema_refs(schema: &mut Schema, spec: &OpenApiSpec) -> Result<()> {
-    if let Some(ref_path) = &schema.reference {
-        if let Some(target_name) = extract_schema_name_from_ref(ref_path) {
-            if let Some(components) = &spec.components {
-                if let Some(schemas) = &components.schemas {
-                    if let Some(target_schema) = schemas.get(target_name) {
-                        // Create a copy of the target schema
-                        let mut resolved_schema = target_schema.clone();
-                        resolved_schema.reference = None;
-                        *schema = resolved_schema;
-                    }
-                }
+/// Marks with `x-recursive` the `$ref`s of component schemas that close a
+/// cycle: those whose target leads back to the schema holding them, directly
+/// (`Node.children: [Node]`) or through others (`Node.tree` to `Tree.root`
+/// to `Node`). References are kept rather than inlined, so each schema is
+/// walked twice whatever the nesting, and the cycles found once.
+fn mark_recursive_schema_refs(document: &mut serde_json::Value) {
+    let Some(schemas) = document
+        .pointer_mut("/components/schemas")
+        .and_then(serde_json::Value::as_object_mut)
+    else {
+        return;
+    };
+
+    let mut targets: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, schema) in schemas.iter_mut() {
+        let mut found = Vec::new();
+        visit_schema_refs(schema, &mut |reference| {
+            if let Some(target) = schema_ref_target(reference) {
+                found.push(target.to_string());
             }
-        }
+        });
+        targets.insert(name.clone(), found);
+    }
+    for found in targets.values_mut() {
+        found.retain(|target| schemas.contains_key(target));
     }
 
-    // Recursively resolve nested references
-    if let Some(properties) = &mut schema.properties {
-        for field_schema in properties.values_mut() {
-            resolve_parameter_schema_refs(field_schema, spec)?;
-        }
+    let mut cycle_of: HashMap<String, usize> = HashMap::new();
+    for (i, cycle) in find_cycles(&targets).into_iter().enumerate() {
+        cycle_of.extend(cycle.into_iter().map(|name| (name, i)));
+    }
+    for (name, schema) in schemas.iter_mut() {
+        let Some(cycle) = cycle_of.get(name) else {
+            continue;
+        };
+        visit_schema_refs(schema, &mut |reference| {
+            let closes_cycle = schema_ref_target(reference)
+                .is_some_and(|target| cycle_of.get(target) == Some(cycle));
+            if closes_cycle {
+                reference.insert("x-recursive".to_string(), serde_json::Value::Bool(true));
+            }
+        });
     }
+}
+
+/// Component schema named by the `$ref` of a schema object.
+fn schema_ref_target(schema: &serde_json::Map<String, serde_json::Value>) -> Option<&str> {
+    schema
+        .get("$ref")
+        .and_then(serde_json::Value::as_str)
+        .and_then(extract_schema_name_from_ref)
+}
 
-    if let Some(items) = &mut schema.items {
-        resolve_parameter_schema_refs(items, spec)?;
+/// Calls `visit` on every schema object holding a `$ref` under `value`,
+/// leaving out example and default values.
+fn visit_schema_refs(
+    value: &mut serde_json::Value,
+    visit: &mut dyn FnMut(&mut serde_json::Map<String, serde_json::Value>),
+) {
+    match value {
+        serde_json::Value::Object(object) => {
+            if object.contains_key("$ref") {
+                visit(object);
+            }
+            for (key, child) in object.iter_mut() {
+                if !matches!(key.as_str(), "example" | "examples" | "default" | "enum") {
+                    visit_schema_refs(child, visit);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                visit_schema_refs(item, visit);
+            }
+        }
+        _ => {}
     }
+}
 
-    Ok(())
+/// Adds the properties under `prefix` (an object, or an array whose items
+/// are objects, marked `[]`) at `depth`, then their own nested properties.
+fn collect_nested_paths<'a>(
+    prefix: &str,
+    schema: &'a Schema,
+    depth: usize,
+    max_depth: usize,
+    paths: &mut Vec<(String, &'a Schema)>,
+) {
+    if depth > max_depth {
+        return;
+    }
+    let (container, prefix) = match &schema.items {
+        Some(items) => (items.as_ref(), format!("{}[]", prefix)),
+        None => (schema, prefix.to_string()),
+    };
+    // A recursive reference is not expanded again
+    if container.recursive {
+        return;
+    }
+    let Some(properties) = &container.properties else {
+        return;
+    };
+    let mut names: Vec<&String> = properties.keys().collect();
+    names.sort();
+    for name in names {
+        let path = format!("{}.{}", prefix, name);
+        let child = &properties[name];
+        paths.push((path.clone(), child));
+        collect_nested_paths(&path, child, depth + 1, max_depth, paths);
+    }
 }
 
-fn extract_schema_name_from_ref(ref_path: &str) -> Option<&str> {
+pub fn extract_schema_name_from_ref(ref_path: &str) -> Option<&str> {
     ref_path.strip_prefix("#/components/schemas/")
 }
 
 impl Schema {
     pub fn get_field_names(&self) -> Vec<String> {
+        let mut fields = self.common_field_names();
+
+        if let Some(one_of) = &self.one_of {
+            for schema in one_of {
+                fields.extend(schema.get_field_names());
+            }
+        }
+
+        if let Some(any_of) = &self.any_of {
+            for schema in any_of {
+                fields.extend(schema.get_field_names());
+            }
+        }
+
+        fields
+    }
+
+    /// Fields shared by every instance: own properties, array items and
+    /// `allOf` parts, leaving out the `oneOf` / `anyOf` alternatives.
+    pub fn common_field_names(&self) -> Vec<String> {
         let mut fields = Vec::new();
 
         if let Some(properties) = &self.properties {
@@ -307,19 +1378,178 @@ impl Schema {
             }
         }
 
-        if let Some(one_of) = &self.one_of {
-            for schema in one_of {
-                fields.extend(schema.get_field_names());
+        fields
+    }
+
+    /// Paths of the properties nested in inline objects and arrays of
+    /// objects, e.g. `address.city` or `lines[].sku`, down to `max_depth`
+    /// levels. Top-level properties (depth 1) are not included.
+    pub fn nested_field_paths(&self, max_depth: usize) -> Vec<(String, &Schema)> {
+        let mut paths = Vec::new();
+        if let Some(properties) = &self.properties {
+            let mut names: Vec<&String> = properties.keys().collect();
+            names.sort();
+            for name in names {
+                collect_nested_paths(name, &properties[name], 2, max_depth, &mut paths);
             }
         }
 
-        if let Some(any_of) = &self.any_of {
-            for schema in any_of {
-                fields.extend(schema.get_field_names());
+        // Items and allOf parts contribute top-level fields of their own
+        if let Some(items) = &self.items {
+            paths.extend(items.nested_field_paths(max_depth));
+        }
+        if let Some(all_of) = &self.all_of {
+            for schema in all_of {
+                paths.extend(schema.nested_field_paths(max_depth));
             }
         }
 
-        fields
+        paths
+    }
+
+    pub fn is_polymorphic(&self) -> bool {
+        self.discriminator.is_some() || self.one_of.is_some() || self.any_of.is_some()
+    }
+
+    /// Alternatives of a `oneOf` (else `anyOf`) schema, followed by mapping
+    /// targets that are not listed there (a base schema whose subtypes
+    /// extend it with `allOf`). A referenced variant without a mapping
+    /// entry is selected by its schema name.
+    pub fn variants(&self) -> Vec<SchemaVariant> {
+        let mapping = self.discriminator.as_ref().and_then(|d| d.mapping.as_ref());
+        // Mapping targets are either references or bare schema names
+        let mapped_value = |name: &str| {
+            mapping.and_then(|mapping| {
+                let mut values: Vec<&String> = mapping
+                    .iter()
+                    .filter(|(_, target)| {
+                        extract_schema_name_from_ref(target).unwrap_or(target) == name
+                    })
+                    .map(|(value, _)| value)
+                    .collect();
+                values.sort();
+                values.first().map(|value| value.to_string())
+            })
+        };
+
+        let alternatives = self.one_of.as_ref().or(self.any_of.as_ref());
+        let mut variants: Vec<SchemaVariant> = alternatives
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, alternative)| {
+                let schema = alternative
+                    .reference
+                    .as_deref()
+                    .and_then(extract_schema_name_from_ref)
+                    .map(str::to_string);
+                let value = match &schema {
+                    Some(name) => mapped_value(name).unwrap_or_else(|| name.clone()),
+                    None => format!("#{}", i + 1),
+                };
+                SchemaVariant {
+                    value,
+                    schema,
+                    fields: alternative.get_field_names(),
+                }
+            })
+            .collect();
+
+        let mut unlisted: Vec<SchemaVariant> = mapping
+            .into_iter()
+            .flatten()
+            .filter_map(|(value, target)| {
+                let name = extract_schema_name_from_ref(target).unwrap_or(target);
+                let listed = variants
+                    .iter()
+                    .any(|variant| variant.schema.as_deref() == Some(name));
+                (!listed).then(|| SchemaVariant {
+                    value: value.clone(),
+                    schema: Some(name.to_string()),
+                    fields: Vec::new(),
+                })
+            })
+            .collect();
+        unlisted.sort_by(|a, b| a.value.cmp(&b.value));
+        variants.extend(unlisted);
+        variants
+    }
+
+    /// Schema of the property `name`: own properties first, then array
+    /// items and the `allOf`, `oneOf` and `anyOf` parts, like
+    /// [`Schema::get_field_names`].
+    pub fn property(&self, name: &str) -> Option<&Schema> {
+        if let Some(schema) = self.properties.as_ref().and_then(|p| p.get(name)) {
+            return Some(schema);
+        }
+        let parts = [&self.all_of, &self.one_of, &self.any_of];
+        self.items
+            .iter()
+            .map(|items| items.as_ref())
+            .chain(parts.into_iter().flatten().flatten())
+            .find_map(|part| part.property(name))
+    }
+
+    /// Schema of a field path as indexed, e.g. `address.city` or
+    /// `items[].sku`.
+    pub fn property_at(&self, path: &str) -> Option<&Schema> {
+        let mut schema = self;
+        for segment in path.split('.') {
+            let (name, is_array) = match segment.strip_suffix("[]") {
+                Some(name) => (name, true),
+                None => (segment, false),
+            };
+            schema = schema.property(name)?;
+            if is_array {
+                schema = schema.items.as_deref()?;
+            }
+        }
+        Some(schema)
+    }
+
+    /// Whether a field path as indexed is in the `required` list of the
+    /// object declaring it, or of one of that object's `allOf` parts.
+    pub fn is_required_at(&self, path: &str) -> bool {
+        let (parent, name) = match path.rsplit_once('.') {
+            Some((parent, name)) => (self.property_at(parent), name),
+            None => (Some(self), path),
+        };
+        let name = name.strip_suffix("[]").unwrap_or(name);
+        parent.is_some_and(|parent| parent.requires(name))
+    }
+
+    fn requires(&self, name: &str) -> bool {
+        self.is_field_required(name) || self.all_of.iter().flatten().any(|part| part.requires(name))
+    }
+
+    /// Validation keywords, as written: `maxLength 64`, `pattern ^[a-z]+$`.
+    pub fn constraints(&self) -> Vec<String> {
+        let mut constraints = Vec::new();
+        let counts = [
+            ("minLength", self.min_length),
+            ("maxLength", self.max_length),
+            ("minItems", self.min_items),
+            ("maxItems", self.max_items),
+        ];
+        let numbers = [
+            ("minimum", self.minimum),
+            ("maximum", self.maximum),
+            ("multipleOf", self.multiple_of),
+        ];
+        for (keyword, value) in counts {
+            if let Some(value) = value {
+                constraints.push(format!("{} {}", keyword, value));
+            }
+        }
+        for (keyword, value) in numbers {
+            if let Some(value) = value {
+                constraints.push(format!("{} {}", keyword, value));
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            constraints.push(format!("pattern {}", pattern));
+        }
+        constraints
     }
 
     pub fn get_field_type(&self, field_name: &str) -> Option<String> {
@@ -412,6 +1642,7 @@ mod tests {
                 ),
             ])),
             items: None,
+            explorer_ignore: None,
             required: None,
             all_of: None,
             one_of: None,
@@ -424,7 +1655,19 @@ mod tests {
             example: None,
             enum_: None,
             default: None,
+            content_media_type: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            minimum: None,
+            maximum: None,
+            multiple_of: None,
+            min_items: None,
+            max_items: None,
             reference: None,
+            discriminator: None,
+            external_docs: None,
+            recursive: false,
         };
 
         let field_names = schema.get_field_names();
@@ -460,6 +1703,469 @@ mod tests {
         assert_eq!(spec.info.version, "1.0.0");
     }
 
+    #[test]
+    fn test_schema_deserializes_camel_case_keywords() {
+        let schema: Schema = serde_json::from_str(
+            r##"{
+                "allOf": [{"$ref": "#/components/schemas/Base"}],
+                "readOnly": true,
+                "enum": ["a", "b"],
+                "additionalProperties": false
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schema.all_of.unwrap()[0].reference.as_deref(),
+            Some("#/components/schemas/Base")
+        );
+        assert_eq!(schema.read_only, Some(true));
+        assert_eq!(schema.enum_.map(|values| values.len()), Some(2));
+        assert!(schema.additional_properties.is_none());
+
+        let open: Schema = serde_json::from_str(r#"{"additionalProperties": true}"#).unwrap();
+        assert!(open.additional_properties.is_some());
+    }
+
+    #[test]
+    fn test_component_refs_are_inlined() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/users/{userId}": {
+                    "parameters": [
+                        {"$ref": "#/components/parameters/UserId"},
+                        {"name": "limit", "in": "query", "schema": {"type": "integer"}}
+                    ],
+                    "get": {
+                        "parameters": [
+                            {"$ref": "#/components/parameters/Limit"},
+                            {"$ref": "#/components/parameters/Missing"}
+                        ],
+                        "responses": {"404": {"$ref": "#/components/responses/NotFound"}}
+                    },
+                    "put": {
+                        "requestBody": {"$ref": "#/components/requestBodies/UserBody"},
+                        "responses": {}
+                    }
+                }},
+                "components": {
+                    "parameters": {
+                        "UserId": {"name": "userId", "in": "path", "required": true, "schema": {"type": "string"}},
+                        "Limit": {"$ref": "#/components/parameters/PageLimit"},
+                        "PageLimit": {"name": "limit", "in": "query", "description": "Page size"}
+                    },
+                    "responses": {"NotFound": {"description": "Not found", "content": {
+                        "application/json": {"schema": {"type": "object", "properties": {"error_code": {"type": "string"}}}}
+                    }}},
+                    "requestBodies": {"UserBody": {"content": {
+                        "application/json": {"schema": {"type": "object", "properties": {"email": {"type": "string"}}}}
+                    }}}
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let path = &spec.paths["/users/{userId}"];
+        let get = &path.operations["get"];
+        let parameters = get.parameters.as_ref().unwrap();
+        let names: Vec<(&str, Option<&str>)> = parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.description.as_deref()))
+            .collect();
+        // The operation's chained `limit` overrides the path-level one
+        assert_eq!(names, vec![("limit", Some("Page size")), ("userId", None)]);
+        assert_eq!(get.responses["404"].description, "Not found");
+        assert!(path.operations["put"].request_body.is_some());
+        assert_eq!(path.operations["put"].parameters.as_ref().unwrap().len(), 2);
+
+        let components = spec.components.clone().unwrap();
+        assert_eq!(components.parameters.unwrap().len(), 3);
+        assert!(components.request_bodies.unwrap().contains_key("UserBody"));
+
+        let index = crate::indexer::build_field_index(&spec);
+        let endpoints = index.get_endpoints_for_field("userId");
+        assert!(endpoints.contains(&"GET /users/{userId}".to_string()));
+        assert!(endpoints.contains(&"PUT /users/{userId}".to_string()));
+        assert!(index
+            .get_endpoints_for_field("email")
+            .contains(&"PUT /users/{userId}".to_string()));
+    }
+
+    #[test]
+    fn test_discriminator_variants() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {"petType": {"type": "string"}},
+                        "oneOf": [
+                            {"$ref": "#/components/schemas/Cat"},
+                            {"$ref": "#/components/schemas/Dog"},
+                            {"type": "object", "properties": {"wings": {"type": "integer"}}}
+                        ],
+                        "discriminator": {"propertyName": "petType", "mapping": {
+                            "cat": "#/components/schemas/Cat",
+                            "lizard": "Lizard"
+                        }}
+                    },
+                    "Cat": {"type": "object", "properties": {"meows": {"type": "boolean"}}},
+                    "Dog": {"type": "object", "properties": {"barks": {"type": "boolean"}}},
+                    "Lizard": {"type": "object"}
+                }}
+            }"##,
+        )
+        .unwrap();
+
+        let pet = &spec.components.as_ref().unwrap().schemas.as_ref().unwrap()["Pet"];
+        assert!(pet.is_polymorphic());
+        assert_eq!(pet.discriminator.as_ref().unwrap().property_name, "petType");
+        assert_eq!(pet.common_field_names(), vec!["petType"]);
+        assert!(pet.get_field_names().contains(&"wings".to_string()));
+
+        let variants = pet.variants();
+        assert_eq!(variants.len(), 4);
+        assert_eq!(
+            (variants[0].value.as_str(), variants[0].schema.as_deref()),
+            ("cat", Some("Cat"))
+        );
+        // No mapping entry: selected by schema name
+        assert_eq!(
+            (variants[1].value.as_str(), variants[1].schema.as_deref()),
+            ("Dog", Some("Dog"))
+        );
+        assert_eq!(variants[2].value, "#3");
+        assert_eq!(variants[2].fields, vec!["wings"]);
+        // Mapping target missing from oneOf (allOf inheritance)
+        assert_eq!(
+            (variants[3].value.as_str(), variants[3].schema.as_deref()),
+            ("lizard", Some("Lizard"))
+        );
+    }
+
+    const RECURSIVE_SPEC: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "T", "version": "1"},
+        "paths": {"/nodes": {"get": {"responses": {"200": {"description": "ok", "content": {
+            "application/json": {"schema": {"$ref": "#/components/schemas/Node"}}
+        }}}}}},
+        "components": {"schemas": {
+            "Node": {"type": "object", "properties": {
+                "name": {"type": "string"},
+                "children": {"type": "array", "items": {"$ref": "#/components/schemas/Node"}},
+                "tree": {"$ref": "#/components/schemas/Tree"},
+                "leaf": {"$ref": "#/components/schemas/Leaf"}
+            }},
+            "Tree": {"type": "object", "properties": {"root": {"$ref": "#/components/schemas/Node"}}},
+            "Leaf": {"type": "string"}
+        }}
+    }"##;
+
+    #[test]
+    fn test_resolve_references_handles_cycles() {
+        let mut spec = parse_openapi_content(RECURSIVE_SPEC).unwrap();
+
+        let schemas = spec.components.as_ref().unwrap().schemas.as_ref().unwrap();
+        let node = schemas["Node"].properties.as_ref().unwrap();
+        // Direct self-reference is kept and marked
+        let children = node["children"].items.as_ref().unwrap();
+        assert!(children.recursive);
+        assert_eq!(
+            children.reference.as_deref(),
+            Some("#/components/schemas/Node")
+        );
+        // Indirect cycle: Node.tree leads to Tree, whose root points back
+        let tree = &node["tree"];
+        assert!(tree.recursive && tree.properties.is_none());
+        let tree_schema = schemas["Tree"].properties.as_ref().unwrap();
+        assert!(tree_schema["root"].recursive);
+        // Outside any cycle, references are kept unmarked
+        assert!(!node["leaf"].recursive);
+        assert_eq!(
+            node["leaf"].reference.as_deref(),
+            Some("#/components/schemas/Leaf")
+        );
+
+        let body = spec.paths["/nodes"].operations["get"].responses["200"]
+            .content
+            .as_ref()
+            .unwrap()["application/json"]
+            .schema
+            .as_ref()
+            .unwrap();
+        assert!(!body.recursive && body.properties.is_none());
+
+        // Resolving again changes nothing
+        let parsed = serde_json::to_value(&spec).unwrap();
+        resolve_references(&mut spec).unwrap();
+        assert_eq!(serde_json::to_value(&spec).unwrap(), parsed);
+    }
+
+    #[test]
+    fn test_shared_references_are_not_copied() {
+        // Each level references the next twice: copying targets would
+        // build 2^40 objects
+        let schemas: Vec<String> = (0..40)
+            .map(|i| {
+                let next = format!("{{\"$ref\": \"#/components/schemas/S{}\"}}", i + 1);
+                format!(
+                    "\"S{}\": {{\"type\": \"object\", \"properties\": {{\"a\": {}, \"b\": {}}}}}",
+                    i, next, next
+                )
+            })
+            .collect();
+        let content = format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "T", "version": "1"}}, "paths": {{}},
+                "components": {{"schemas": {{{}, "S40": {{"type": "string"}}}}}}}}"#,
+            schemas.join(", ")
+        );
+
+        let spec = parse_openapi_content(&content).unwrap();
+        let schemas = spec.components.unwrap().schemas.unwrap();
+        let first = &schemas["S0"].properties.as_ref().unwrap()["a"];
+        assert_eq!(first.reference.as_deref(), Some("#/components/schemas/S1"));
+        assert!(!first.recursive && first.properties.is_none());
+    }
+
+    #[test]
+    fn test_constraints_of_nested_fields() {
+        let schema: Schema = serde_json::from_str(
+            r#"{"type": "object", "properties": {
+                "name": {"type": "string", "minLength": 1, "maxLength": 64, "pattern": "^[a-z]+$"},
+                "lines": {"type": "array", "maxItems": 50, "items": {"type": "object", "properties": {
+                    "quantity": {"type": "integer", "minimum": 1, "maximum": 99, "multipleOf": 1}}}}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            schema.property_at("name").unwrap().constraints(),
+            ["minLength 1", "maxLength 64", "pattern ^[a-z]+$"]
+        );
+        assert_eq!(
+            schema.property_at("lines").unwrap().constraints(),
+            ["maxItems 50"]
+        );
+        assert_eq!(
+            schema
+                .property_at("lines[].quantity")
+                .unwrap()
+                .constraints(),
+            ["minimum 1", "maximum 99", "multipleOf 1"]
+        );
+        assert!(schema.property_at("lines[].missing").is_none());
+    }
+
+    #[test]
+    fn test_required_nested_fields() {
+        let schema: Schema = serde_json::from_str(
+            r#"{"type": "object", "required": ["lines"], "properties": {
+                "name": {"type": "string"},
+                "lines": {"type": "array", "items": {"allOf": [
+                    {"type": "object", "required": ["quantity"], "properties": {
+                        "quantity": {"type": "integer"}, "note": {"type": "string"}}}
+                ]}}
+            }}"#,
+        )
+        .unwrap();
+        assert!(schema.is_required_at("lines"));
+        assert!(!schema.is_required_at("name"));
+        assert!(schema.is_required_at("lines[].quantity"));
+        assert!(!schema.is_required_at("lines[].note"));
+        assert!(!schema.is_required_at("missing.name"));
+    }
+
+    #[test]
+    fn test_info_metadata_is_parsed() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "T", "version": "1",
+                    "termsOfService": "https://example.com/terms",
+                    "contact": {"name": "API Team", "email": "api@example.com"},
+                    "license": {"name": "Apache 2.0", "identifier": "Apache-2.0"}},
+                "externalDocs": {"url": "https://docs.example.com"},
+                "paths": {}
+            }"##,
+        )
+        .unwrap();
+        let info = &spec.info;
+        assert_eq!(
+            info.terms_of_service.as_deref(),
+            Some("https://example.com/terms")
+        );
+        assert_eq!(
+            info.contact.as_ref().unwrap().describe(),
+            "API Team <api@example.com>"
+        );
+        let license = info.license.as_ref().unwrap();
+        assert_eq!(license.identifier.as_deref(), Some("Apache-2.0"));
+        assert_eq!(spec.external_docs.unwrap().url, "https://docs.example.com");
+    }
+
+    #[test]
+    fn test_operation_docs_fall_back_to_tag_docs() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "tags": [{"name": "pets", "externalDocs": {"url": "https://docs.example.com/pets"}}],
+                "paths": {"/pets": {
+                    "get": {"tags": ["pets"], "responses": {}},
+                    "post": {"tags": ["pets"], "responses": {},
+                        "externalDocs": {"url": "https://docs.example.com/create", "description": "Guide"}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let url = |endpoint: &str| spec.operation_docs(endpoint).map(|docs| docs.url.as_str());
+        assert_eq!(url("GET /pets"), Some("https://docs.example.com/pets"));
+        assert_eq!(url("POST /pets"), Some("https://docs.example.com/create"));
+        assert_eq!(url("DELETE /pets"), None);
+        assert!(spec.operation("POST /pets").unwrap().extensions.is_empty());
+    }
+
+    #[test]
+    fn test_webhooks_and_callbacks_are_parsed() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.1.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/subscriptions": {"post": {
+                    "responses": {},
+                    "callbacks": {
+                        "onEvent": {"{$request.body#/callbackUrl}": {"post": {
+                            "requestBody": {"$ref": "#/components/requestBodies/Event"},
+                            "responses": {}
+                        }}},
+                        "onCancel": {"$ref": "#/components/callbacks/Cancelled"}
+                    }
+                }}, "/orders": {"post": {
+                    "responses": {},
+                    "callbacks": {"onEvent": {
+                        "{$request.body#/shipped}": {"put": {"responses": {}}},
+                        "{$request.body#/cancelled}": {"patch": {"responses": {}}}
+                    }}
+                }}},
+                "webhooks": {
+                    "newPet": {"post": {"summary": "A pet was added", "responses": {}}},
+                    "petSold": {"$ref": "#/components/pathItems/PetSold"}
+                },
+                "components": {
+                    "requestBodies": {"Event": {"content": {
+                        "application/json": {"schema": {"type": "object", "properties": {"event_id": {"type": "string"}}}}
+                    }}},
+                    "callbacks": {"Cancelled": {"{$request.body#/callbackUrl}": {"delete": {"responses": {}}}}},
+                    "pathItems": {"PetSold": {"put": {"responses": {}}}}
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let targets: Vec<String> = spec
+            .event_targets()
+            .into_iter()
+            .map(|(target, _)| target)
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "webhook:newPet",
+                "webhook:petSold",
+                "callback:POST /orders/onEvent {$request.body#/cancelled}",
+                "callback:POST /orders/onEvent {$request.body#/shipped}",
+                "callback:POST /subscriptions/onCancel",
+                "callback:POST /subscriptions/onEvent"
+            ]
+        );
+        assert!(is_event_target("webhook:newPet"));
+        assert!(!is_event_target("/subscriptions"));
+
+        // Callbacks of the same name are told apart by their operation
+        let on_event = spec
+            .path_item("callback:POST /subscriptions/onEvent")
+            .unwrap();
+        assert!(on_event.operations["post"].request_body.is_some());
+        let shipped = spec
+            .path_item("callback:POST /orders/onEvent {$request.body#/shipped}")
+            .unwrap();
+        assert!(shipped.operations.contains_key("put"));
+        assert!(spec.path_item("callback:POST /orders/onEvent").is_none());
+        assert!(spec
+            .path_item("callback:POST /subscriptions/onCancel")
+            .unwrap()
+            .operations
+            .contains_key("delete"));
+        assert!(spec
+            .path_item("webhook:petSold")
+            .unwrap()
+            .operations
+            .contains_key("put"));
+        assert_eq!(
+            spec.path_item("webhook:newPet").unwrap().operations["post"]
+                .summary
+                .as_deref(),
+            Some("A pet was added")
+        );
+        assert!(spec.path_item("webhook:missing").is_none());
+    }
+
+    #[test]
+    fn test_lenient_parse_drops_invalid_elements() {
+        let content = r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Draft", "version": 2},
+            "paths": {
+                "/users": {
+                    "get": {"responses": {"200": {"description": "ok"}}},
+                    "post": {"responses": {"201": {"content": {}}}}
+                },
+                "/users/{id}": "todo"
+            },
+            "components": {"schemas": {
+                "User": {"type": "object", "properties": {"id": {"type": "string"}}},
+                "Broken": {"type": ["string"]}
+            }}
+        }"##;
+        assert!(parse_openapi_content(content).is_err());
+
+        let spec = parse_openapi_content_lenient(content).unwrap();
+        let pointers: Vec<&str> = spec
+            .parse_warnings
+            .iter()
+            .map(|w| w.pointer.as_str())
+            .collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/info",
+                "/paths/~1users/post",
+                "/paths/~1users~1{id}",
+                "/components/schemas/Broken"
+            ]
+        );
+        assert_eq!(spec.info.title, "Draft");
+        assert!(spec.paths["/users"].operations.contains_key("get"));
+        assert!(!spec.paths.contains_key("/users/{id}"));
+        assert!(spec
+            .components
+            .unwrap()
+            .schemas
+            .unwrap()
+            .contains_key("User"));
+
+        // Fixing an element clears its warning
+        let fixed =
+            parse_openapi_content_lenient(&content.replace("\"version\": 2", "\"version\": \"2\""))
+                .unwrap();
+        assert_eq!(fixed.parse_warnings.len(), 3);
+        assert_eq!(fixed.info.version, "2");
+    }
+
     #[tokio::test]
     async fn test_parse_openapi_file_not_found() {
         use std::path::Path;
@@ -0,0 +1,179 @@
+//! Endpoint paths grouped by their segments (`/users` → `/users/{id}` →
+//! `/users/{id}/orders`), flattened into the rows currently visible.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathRow {
+    /// Path prefix the row stands for, which identifies it across redraws
+    pub prefix: String,
+    /// 0 for the top-level segments
+    pub depth: usize,
+    /// Segments added to the parent row's prefix, with their leading `/`;
+    /// a run of prefixes that are neither paths nor forks is shown as one row
+    pub label: String,
+    /// Listed path when the prefix is one itself
+    pub target: Option<Arc<str>>,
+    /// Listed paths below the row
+    pub descendants: usize,
+    pub collapsed: bool,
+}
+
+impl PathRow {
+    pub fn has_children(&self) -> bool {
+        self.descendants > 0
+    }
+}
+
+#[derive(Default)]
+struct Node {
+    segment: String,
+    target: Option<Arc<str>>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn insert(&mut self, segments: &[&str], target: &Arc<str>) {
+        let Some((first, rest)) = segments.split_first() else {
+            self.target = Some(target.clone());
+            return;
+        };
+        let position = match self.children.iter().position(|c| c.segment == *first) {
+            Some(position) => position,
+            None => {
+                self.children.push(Node {
+                    segment: first.to_string(),
+                    ..Node::default()
+                });
+                self.children.len() - 1
+            }
+        };
+        self.children[position].insert(rest, target);
+    }
+
+    fn descendants(&self) -> usize {
+        self.children
+            .iter()
+            .map(|c| usize::from(c.target.is_some()) + c.descendants())
+            .sum()
+    }
+}
+
+/// Rows of `paths` grouped by segment, in the order they are listed,
+/// hiding what lies below the prefixes in `collapsed`.
+pub fn path_rows(paths: &[Arc<str>], collapsed: &HashSet<String>) -> Vec<PathRow> {
+    let mut root = Node::default();
+    for path in paths {
+        let segments: Vec<&str> = path.strip_prefix('/').unwrap_or(path).split('/').collect();
+        root.insert(&segments, path);
+    }
+    let mut rows = Vec::new();
+    for child in &root.children {
+        push_rows(child, "", 0, collapsed, &mut rows);
+    }
+    rows
+}
+
+fn push_rows(
+    node: &Node,
+    parent: &str,
+    depth: usize,
+    collapsed: &HashSet<String>,
+    rows: &mut Vec<PathRow>,
+) {
+    // Walk down prefixes that only lead to a single child
+    let mut node = node;
+    let mut label = format!("/{}", node.segment);
+    while node.target.is_none() && node.children.len() == 1 {
+        node = &node.children[0];
+        label.push('/');
+        label.push_str(&node.segment);
+    }
+    let prefix = format!("{}{}", parent, label);
+    let descendants = node.descendants();
+    let is_collapsed = descendants > 0 && collapsed.contains(&prefix);
+    rows.push(PathRow {
+        prefix: prefix.clone(),
+        depth,
+        label,
+        target: node.target.clone(),
+        descendants,
+        collapsed: is_collapsed,
+    });
+    if !is_collapsed {
+        for child in &node.children {
+            push_rows(child, &prefix, depth + 1, collapsed, rows);
+        }
+    }
+}
+
+/// Whether `prefix` is `path` or one of the prefixes grouping it.
+pub fn contains(prefix: &str, path: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(paths: &[&str]) -> Vec<Arc<str>> {
+        paths.iter().map(|p| Arc::from(*p)).collect()
+    }
+
+    fn visible(rows: &[PathRow]) -> Vec<(usize, &str, bool)> {
+        rows.iter()
+            .map(|row| (row.depth, row.label.as_str(), row.target.is_some()))
+            .collect()
+    }
+
+    #[test]
+    fn test_groups_paths_by_segment() {
+        let paths = paths(&[
+            "/users",
+            "/users/{id}",
+            "/users/{id}/orders",
+            "/users/{id}/orders/{orderId}",
+            "/api/v1/health",
+            "/api/v1/status",
+            "/",
+        ]);
+        let rows = path_rows(&paths, &HashSet::new());
+        assert_eq!(
+            visible(&rows),
+            vec![
+                (0, "/users", true),
+                (1, "/{id}", true),
+                (2, "/orders", true),
+                (3, "/{orderId}", true),
+                (0, "/api/v1", false),
+                (1, "/health", true),
+                (1, "/status", true),
+                (0, "/", true),
+            ]
+        );
+        assert_eq!(rows[0].descendants, 3);
+        assert_eq!(rows[4].prefix, "/api/v1");
+        assert_eq!(rows[5].prefix, "/api/v1/health");
+        assert_eq!(rows[5].target.as_deref(), Some("/api/v1/health"));
+    }
+
+    #[test]
+    fn test_collapsed_prefixes_hide_their_paths() {
+        let paths = paths(&["/users", "/users/{id}", "/users/{id}/orders", "/pets"]);
+        let collapsed = HashSet::from(["/users/{id}".to_string(), "/pets".to_string()]);
+        let rows = path_rows(&paths, &collapsed);
+        assert_eq!(
+            visible(&rows),
+            vec![(0, "/users", true), (1, "/{id}", true), (0, "/pets", true)]
+        );
+        assert!(rows[1].collapsed);
+        // A prefix without paths below is never collapsed
+        assert!(!rows[2].collapsed);
+
+        assert!(contains("/users", "/users/{id}"));
+        assert!(contains("/users", "/users"));
+        assert!(!contains("/users", "/usersettings"));
+    }
+}
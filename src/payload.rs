@@ -0,0 +1,365 @@
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use std::collections::{HashMap, HashSet};
+
+/// Assumed number of elements in an array without an example
+const ARRAY_ITEM_ESTIMATE: usize = 10;
+
+/// Responses estimated above this size are flagged as large
+pub const LARGE_PAYLOAD_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayloadEstimate {
+    pub endpoint: String,
+    pub status: String,
+    /// Estimated serialized JSON size in bytes
+    pub bytes: usize,
+    /// Estimated number of scalar values in the payload
+    pub field_count: usize,
+}
+
+impl PayloadEstimate {
+    pub fn is_large(&self) -> bool {
+        self.bytes >= LARGE_PAYLOAD_BYTES
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Weight {
+    bytes: usize,
+    fields: usize,
+}
+
+/// Estimates the largest 2xx response of every operation, sorted by size
+/// (largest first).
+pub fn rank_response_payloads(openapi_spec: &OpenApiSpec) -> Vec<PayloadEstimate> {
+    let empty = HashMap::new();
+    let schemas = openapi_spec
+        .components
+        .as_ref()
+        .and_then(|c| c.schemas.as_ref())
+        .unwrap_or(&empty);
+
+    let mut estimates = Vec::new();
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            let largest = operation
+                .responses
+                .iter()
+                .filter(|(status, _)| status.starts_with('2'))
+                .filter_map(|(status, response)| {
+                    let content = response.content.as_ref()?;
+                    let weight = content
+                        .values()
+                        .filter_map(|media| media.schema.as_ref())
+                        .map(|schema| estimate(schema, schemas, &mut HashSet::new()))
+                        .max_by_key(|weight| weight.bytes)?;
+                    Some((status, weight))
+                })
+                .max_by(|a, b| a.1.bytes.cmp(&b.1.bytes).then(b.0.cmp(a.0)));
+
+            if let Some((status, weight)) = largest {
+                estimates.push(PayloadEstimate {
+                    endpoint: format!("{} {}", method.to_uppercase(), path),
+                    status: status.clone(),
+                    bytes: weight.bytes,
+                    field_count: weight.fields,
+                });
+            }
+        }
+    }
+
+    estimates.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.endpoint.cmp(&b.endpoint)));
+    estimates
+}
+
+/// Estimated serialized size of a single schema instance.
+pub fn estimate_schema_size(schema: &Schema, schemas: &HashMap<String, Schema>) -> usize {
+    estimate(schema, schemas, &mut HashSet::new()).bytes
+}
+
+fn estimate(
+    schema: &Schema,
+    schemas: &HashMap<String, Schema>,
+    visiting: &mut HashSet<String>,
+) -> Weight {
+    if let Some(example) = &schema.example {
+        return Weight {
+            bytes: example.to_string().len(),
+            fields: count_scalars(example),
+        };
+    }
+
+    if let Some(target) = schema
+        .reference
+        .as_deref()
+        .and_then(extract_schema_name_from_ref)
+    {
+        // Recursive structures are counted once
+        if !visiting.insert(target.to_string()) {
+            return Weight {
+                bytes: 4,
+                fields: 1,
+            };
+        }
+        let weight = schemas
+            .get(target)
+            .map(|resolved| estimate(resolved, schemas, visiting))
+            .unwrap_or_default();
+        visiting.remove(target);
+        return weight;
+    }
+
+    let mut weight = Weight::default();
+
+    if let Some(properties) = &schema.properties {
+        weight.bytes += 2; // {}
+        for (name, property) in properties {
+            let value = estimate(property, schemas, visiting);
+            // "name":value,
+            weight.bytes += name.len() + 4 + value.bytes;
+            weight.fields += value.fields;
+        }
+    }
+
+    if let Some(all_of) = &schema.all_of {
+        for sub_schema in all_of {
+            let part = estimate(sub_schema, schemas, visiting);
+            weight.bytes += part.bytes;
+            weight.fields += part.fields;
+        }
+    }
+
+    // Only one variant is sent at a time: take the largest
+    for branches in [&schema.one_of, &schema.any_of].into_iter().flatten() {
+        if let Some(largest) = branches
+            .iter()
+            .map(|sub_schema| estimate(sub_schema, schemas, visiting))
+            .max_by_key(|w| w.bytes)
+        {
+            weight.bytes += largest.bytes;
+            weight.fields += largest.fields;
+        }
+    }
+
+    if let Some(items) = &schema.items {
+        let item = estimate(items, schemas, visiting);
+        weight.bytes += 2 + (item.bytes + 1) * ARRAY_ITEM_ESTIMATE;
+        weight.fields += item.fields * ARRAY_ITEM_ESTIMATE;
+    }
+
+    if weight.bytes == 0 {
+        weight = Weight {
+            bytes: scalar_size(schema),
+            fields: 1,
+        };
+    }
+
+    weight
+}
+
+fn scalar_size(schema: &Schema) -> usize {
+    if let Some(values) = &schema.enum_ {
+        if let Some(longest) = values.iter().map(|v| v.to_string().len()).max() {
+            return longest;
+        }
+    }
+
+    match (schema.schema_type.as_deref(), schema.format.as_deref()) {
+        (Some("string"), Some("date-time")) => 26,
+        (Some("string"), Some("date")) => 12,
+        (Some("string"), Some("uuid")) => 38,
+        (Some("string"), Some("email")) => 28,
+        (Some("string"), Some("uri")) => 48,
+        (Some("string"), Some("byte")) | (Some("string"), Some("binary")) => 1024,
+        (Some("string"), _) => 24,
+        (Some("integer"), _) => 8,
+        (Some("number"), _) => 10,
+        (Some("boolean"), _) => 5,
+        (Some("object"), _) => 2,
+        _ => 16,
+    }
+}
+
+fn count_scalars(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Object(map) => map.values().map(count_scalars).sum(),
+        serde_json::Value::Array(items) => items.iter().map(count_scalars).sum(),
+        _ => 1,
+    }
+}
+
+/// Formats a byte count as a short human-readable string.
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Components, Info, MediaType, Operation, PathItem, Response};
+
+    fn typed(schema_type: &str) -> Schema {
+        Schema {
+            schema_type: Some(schema_type.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn object(properties: Vec<(&str, Schema)>) -> Schema {
+        Schema {
+            schema_type: Some("object".to_string()),
+            properties: Some(
+                properties
+                    .into_iter()
+                    .map(|(name, schema)| (name.to_string(), schema))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn reference(name: &str) -> Schema {
+        Schema {
+            reference: Some(format!("#/components/schemas/{}", name)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_estimate_object_size() {
+        let schema = object(vec![("id", typed("integer")), ("name", typed("string"))]);
+        // {} + "id":8, + "name":24,
+        assert_eq!(
+            estimate_schema_size(&schema, &HashMap::new()),
+            2 + 6 + 8 + 8 + 24
+        );
+    }
+
+    #[test]
+    fn test_example_overrides_heuristics() {
+        let schema = Schema {
+            schema_type: Some("string".to_string()),
+            example: Some(serde_json::json!("hi")),
+            ..Default::default()
+        };
+        assert_eq!(estimate_schema_size(&schema, &HashMap::new()), 4);
+    }
+
+    #[test]
+    fn test_recursive_reference_terminates() {
+        let schemas = HashMap::from([(
+            "Node".to_string(),
+            object(vec![(
+                "children",
+                Schema {
+                    schema_type: Some("array".to_string()),
+                    items: Some(Box::new(reference("Node"))),
+                    ..Default::default()
+                },
+            )]),
+        )]);
+        assert!(estimate_schema_size(&reference("Node"), &schemas) > 0);
+    }
+
+    #[test]
+    fn test_rank_response_payloads() {
+        let list = Schema {
+            schema_type: Some("array".to_string()),
+            items: Some(Box::new(reference("Item"))),
+            ..Default::default()
+        };
+        let response = |schema: Schema| Response {
+            description: "ok".to_string(),
+            headers: None,
+            links: None,
+            content: Some(HashMap::from([(
+                "application/json".to_string(),
+                MediaType {
+                    schema: Some(schema),
+                    encoding: None,
+                },
+            )])),
+        };
+        let operation = |schema: Schema| Operation {
+            operation_id: None,
+            summary: None,
+            description: None,
+            tags: None,
+            parameters: None,
+            request_body: None,
+            responses: HashMap::from([
+                ("200".to_string(), response(schema)),
+                ("404".to_string(), response(typed("string"))),
+            ]),
+            deprecated: None,
+            external_docs: None,
+            callbacks: None,
+            extensions: HashMap::new(),
+        };
+
+        let spec = OpenApiSpec {
+            openapi: "3.0.0".to_string(),
+            webhooks: None,
+            info: Info {
+                title: "Test".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                terms_of_service: None,
+                contact: None,
+                license: None,
+            },
+            servers: None,
+            paths: HashMap::from([
+                (
+                    "/items".to_string(),
+                    PathItem {
+                        operations: HashMap::from([("get".to_string(), operation(list))]),
+                    },
+                ),
+                (
+                    "/items/{id}".to_string(),
+                    PathItem {
+                        operations: HashMap::from([(
+                            "get".to_string(),
+                            operation(reference("Item")),
+                        )]),
+                    },
+                ),
+            ]),
+            tags: None,
+            external_docs: None,
+            parse_warnings: Vec::new(),
+            referenced_components: Default::default(),
+            unresolved_refs: Vec::new(),
+            callback_targets: Default::default(),
+            explorer_ignore: None,
+            components: Some(Components {
+                schemas: Some(HashMap::from([(
+                    "Item".to_string(),
+                    object(vec![("id", typed("integer"))]),
+                )])),
+                ..Default::default()
+            }),
+        };
+
+        let ranking = rank_response_payloads(&spec);
+        assert_eq!(ranking.len(), 2);
+        assert_eq!(ranking[0].endpoint, "GET /items");
+        assert_eq!(ranking[0].status, "200");
+        assert_eq!(ranking[0].field_count, ARRAY_ITEM_ESTIMATE);
+        assert!(!ranking[0].is_large());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
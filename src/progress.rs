@@ -0,0 +1,142 @@
+//! Stages of loading a spec, reported while a large spec is read, parsed
+//! and indexed so the UI has something to show meanwhile.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadStage {
+    Reading,
+    Parsing,
+    ResolvingRefs,
+    IndexingSchemas { done: usize, total: usize },
+    IndexingEndpoints { done: usize, total: usize },
+}
+
+/// Receives load stages; called from the indexing threads too.
+pub type Progress<'a> = &'a (dyn Fn(LoadStage) + Send + Sync);
+
+/// Progress that ignores every stage.
+pub fn no_progress(_: LoadStage) {}
+
+impl LoadStage {
+    /// Overall completion of the load, from 0.0 to 1.0. Indexing weighs
+    /// the most since it dominates on large specs.
+    pub fn ratio(&self) -> f64 {
+        let fraction = |done: usize, total: usize| {
+            if total == 0 {
+                1.0
+            } else {
+                done.min(total) as f64 / total as f64
+            }
+        };
+        match self {
+            LoadStage::Reading => 0.0,
+            LoadStage::Parsing => 0.1,
+            LoadStage::ResolvingRefs => 0.3,
+            LoadStage::IndexingSchemas { done, total } => 0.4 + 0.3 * fraction(*done, *total),
+            LoadStage::IndexingEndpoints { done, total } => 0.7 + 0.3 * fraction(*done, *total),
+        }
+    }
+}
+
+impl fmt::Display for LoadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadStage::Reading => write!(f, "Reading file"),
+            LoadStage::Parsing => write!(f, "Parsing"),
+            LoadStage::ResolvingRefs => write!(f, "Resolving references"),
+            LoadStage::IndexingSchemas { done, total } => {
+                write!(f, "Indexing schemas {}/{}", done, total)
+            }
+            LoadStage::IndexingEndpoints { done, total } => {
+                write!(f, "Indexing endpoints {}/{}", done, total)
+            }
+        }
+    }
+}
+
+/// Counts items finished across threads for an n/N stage, reporting at
+/// most once per percent so large specs do not flood the receiver.
+pub struct StageCounter<'a> {
+    done: AtomicUsize,
+    total: usize,
+    stage: fn(usize, usize) -> LoadStage,
+    progress: Progress<'a>,
+}
+
+impl<'a> StageCounter<'a> {
+    /// Starts the stage, reporting 0/N.
+    pub fn start(
+        total: usize,
+        stage: fn(usize, usize) -> LoadStage,
+        progress: Progress<'a>,
+    ) -> Self {
+        progress(stage(0, total));
+        Self {
+            done: AtomicUsize::new(0),
+            total,
+            stage,
+            progress,
+        }
+    }
+
+    pub fn schemas(done: usize, total: usize) -> LoadStage {
+        LoadStage::IndexingSchemas { done, total }
+    }
+
+    pub fn endpoints(done: usize, total: usize) -> LoadStage {
+        LoadStage::IndexingEndpoints { done, total }
+    }
+
+    /// Marks one more item done.
+    pub fn tick(&self) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if done == self.total || done * 100 / self.total != (done - 1) * 100 / self.total {
+            (self.progress)((self.stage)(done, self.total));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_counter_reports_each_percent() {
+        let stages = Mutex::new(Vec::new());
+        let progress = |stage: LoadStage| stages.lock().unwrap().push(stage);
+        let counter = StageCounter::start(250, StageCounter::schemas, &progress);
+        for _ in 0..250 {
+            counter.tick();
+        }
+        let stages = stages.into_inner().unwrap();
+        // 0/250, then one report per percent
+        assert_eq!(stages.len(), 101);
+        assert_eq!(
+            stages.last(),
+            Some(&LoadStage::IndexingSchemas {
+                done: 250,
+                total: 250
+            })
+        );
+        assert_eq!(stages[1].to_string(), "Indexing schemas 3/250");
+    }
+
+    #[test]
+    fn test_ratio_grows_with_each_stage() {
+        let stages = [
+            LoadStage::Reading,
+            LoadStage::Parsing,
+            LoadStage::ResolvingRefs,
+            LoadStage::IndexingSchemas { done: 0, total: 4 },
+            LoadStage::IndexingSchemas { done: 4, total: 4 },
+            LoadStage::IndexingEndpoints { done: 1, total: 2 },
+            LoadStage::IndexingEndpoints { done: 0, total: 0 },
+        ];
+        let ratios: Vec<f64> = stages.iter().map(LoadStage::ratio).collect();
+        assert!(ratios.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(ratios.last(), Some(&1.0));
+    }
+}
@@ -0,0 +1,241 @@
+//! Specs downloaded from a URL, stored under the cache directory so they
+//! load like local files. The response's validators (`ETag`,
+//! `Last-Modified`) are stored alongside, and reloads ask the server
+//! whether the spec changed before re-reading it.
+
+use crate::cache::hash_hex;
+use crate::compression;
+use anyhow::{anyhow, Context, Result};
+use reqwest::header::{
+    HeaderMap, ACCEPT_ENCODING, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Cache validators of a downloaded spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    fn of(headers: &HeaderMap) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        Self {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }
+    }
+}
+
+/// What a stored spec was downloaded from, next to it in
+/// `<hash>.source.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteSource {
+    url: String,
+    #[serde(flatten)]
+    validators: Validators,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Modified {
+        content: String,
+        validators: Validators,
+    },
+    /// The server answered 304 to the conditional request
+    NotModified,
+}
+
+/// Downloads the spec at `url`, accepting gzip and zstd encoded (or
+/// compressed) documents. The request is conditional when `validators`
+/// holds any.
+pub async fn fetch(
+    client: &reqwest::Client,
+    url: &str,
+    validators: &Validators,
+) -> Result<Fetched> {
+    let mut request = client
+        .get(url)
+        .header(ACCEPT_ENCODING, compression::ACCEPT_ENCODING);
+    if let Some(etag) = &validators.etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to fetch {}: {}", url, e))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(Fetched::NotModified);
+    }
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "Failed to fetch {}: HTTP {}",
+            url,
+            response.status()
+        ));
+    }
+
+    let validators = Validators::of(response.headers());
+    let bytes = response.bytes().await?.to_vec();
+    let content =
+        compression::decode_spec(bytes).with_context(|| format!("Cannot read {}", url))?;
+    Ok(Fetched::Modified {
+        content,
+        validators,
+    })
+}
+
+/// Writes the spec downloaded from `url` under `cache_dir`, with its
+/// validators.
+pub fn store(
+    cache_dir: &Path,
+    url: &str,
+    content: &str,
+    validators: &Validators,
+) -> Result<PathBuf> {
+    let path = stored_path(cache_dir, url);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, content)
+        .with_context(|| format!("Cannot store {} in {}", url, path.display()))?;
+    write_source(&path, url, validators)?;
+    Ok(path)
+}
+
+/// Where [`store`] puts the spec downloaded from `url`.
+pub fn stored_path(cache_dir: &Path, url: &str) -> PathBuf {
+    cache_dir
+        .join("remote")
+        .join(format!("{}.json", hash_hex(url.as_bytes())))
+}
+
+/// Downloads the spec at `url` and stores it under `cache_dir`.
+pub async fn download(client: &reqwest::Client, url: &str, cache_dir: &Path) -> Result<PathBuf> {
+    match fetch(client, url, &Validators::default()).await? {
+        Fetched::Modified {
+            content,
+            validators,
+        } => store(cache_dir, url, &content, &validators),
+        Fetched::NotModified => Err(anyhow!("{} answered 304 to a plain request", url)),
+    }
+}
+
+/// Brings a stored spec up to date with its URL, true when it had not
+/// changed so it needs no re-parsing. Local files are left alone (false).
+pub async fn revalidate(path: &Path) -> Result<bool> {
+    let Some(source) = read_source(path) else {
+        return Ok(false);
+    };
+    let fetched = fetch(&reqwest::Client::new(), &source.url, &source.validators).await?;
+    let Fetched::Modified {
+        content,
+        validators,
+    } = fetched
+    else {
+        return Ok(true);
+    };
+    // Servers without validators resend the same document
+    if std::fs::read_to_string(path).is_ok_and(|stored| stored == content) {
+        write_source(path, &source.url, &validators)?;
+        return Ok(true);
+    }
+    std::fs::write(path, &content)
+        .with_context(|| format!("Cannot store {} in {}", source.url, path.display()))?;
+    write_source(path, &source.url, &validators)?;
+    Ok(false)
+}
+
+fn source_path(path: &Path) -> PathBuf {
+    path.with_extension("source.json")
+}
+
+fn write_source(path: &Path, url: &str, validators: &Validators) -> Result<()> {
+    let source = RemoteSource {
+        url: url.to_string(),
+        validators: validators.clone(),
+    };
+    std::fs::write(source_path(path), serde_json::to_vec(&source)?)?;
+    Ok(())
+}
+
+fn read_source(path: &Path) -> Option<RemoteSource> {
+    serde_json::from_slice(&std::fs::read(source_path(path)).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{header, HeaderMap, StatusCode};
+    use axum::response::IntoResponse;
+    use axum::{extract::State, routing::get, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn spec(version: usize) -> String {
+        format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "T", "version": "{}"}}, "paths": {{}}}}"#,
+            version
+        )
+    }
+
+    /// Serves version n of a spec, tagged `"vn"`, honouring If-None-Match.
+    async fn serve_spec(
+        State(version): State<Arc<AtomicUsize>>,
+        headers: HeaderMap,
+    ) -> axum::response::Response {
+        let version = version.load(Ordering::SeqCst);
+        let etag = format!("\"v{}\"", version);
+        if headers
+            .get(header::IF_NONE_MATCH)
+            .is_some_and(|value| value == etag.as_str())
+        {
+            return StatusCode::NOT_MODIFIED.into_response();
+        }
+        ([(header::ETAG, etag)], spec(version)).into_response()
+    }
+
+    #[tokio::test]
+    async fn test_revalidates_with_etag() {
+        let version = Arc::new(AtomicUsize::new(1));
+        let app = Router::new()
+            .route("/openapi.json", get(serve_spec))
+            .with_state(version.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/openapi.json", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = download(&reqwest::Client::new(), &url, dir.path())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), spec(1));
+        assert_eq!(
+            read_source(&path).unwrap().validators.etag.as_deref(),
+            Some("\"v1\"")
+        );
+        assert!(revalidate(&path).await.unwrap());
+
+        version.store(2, Ordering::SeqCst);
+        assert!(!revalidate(&path).await.unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), spec(2));
+        assert!(revalidate(&path).await.unwrap());
+
+        // Local files are not revalidated
+        let local = dir.path().join("local.json");
+        std::fs::write(&local, spec(1)).unwrap();
+        assert!(!revalidate(&local).await.unwrap());
+    }
+}
@@ -0,0 +1,462 @@
+//! Renaming a field across the original document: every place mentioning
+//! it, located by JSON Pointer so the plan can drive automated refactors.
+//!
+//! The raw document is walked rather than the parsed spec because the
+//! parser inlines shared components and path-level parameters, which would
+//! give pointers that do not exist in the source file.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SiteKind {
+    /// Key of a schema's `properties`
+    Property,
+    /// Entry of a schema's `required` list
+    Required,
+    /// `discriminator.propertyName`
+    Discriminator,
+    /// `name` of a parameter object
+    Parameter,
+    /// Key of an example value
+    Example,
+}
+
+/// Part of the document a site belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(tag = "type", content = "name", rename_all = "snake_case")]
+pub enum Owner {
+    Schema(String),
+    /// `"METHOD /path"`, or the bare path for path-level parameters
+    Endpoint(String),
+    /// Other shared component, as `"parameters/UserId"`
+    Component(String),
+    Document,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenameSite {
+    pub kind: SiteKind,
+    pub owner: Owner,
+    /// JSON Pointer to the key or value holding the old name
+    pub pointer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenamePlan {
+    pub field: String,
+    pub new_name: String,
+    pub sites: Vec<RenameSite>,
+    /// Objects already declaring the new name, which the rename would
+    /// overwrite
+    pub conflicts: Vec<String>,
+}
+
+impl RenamePlan {
+    fn owners(&self, matches: impl Fn(&Owner) -> Option<&String>) -> Vec<&str> {
+        let owners: BTreeSet<&str> = self
+            .sites
+            .iter()
+            .filter_map(|site| matches(&site.owner))
+            .map(String::as_str)
+            .collect();
+        owners.into_iter().collect()
+    }
+
+    pub fn schemas(&self) -> Vec<&str> {
+        self.owners(|owner| match owner {
+            Owner::Schema(name) => Some(name),
+            _ => None,
+        })
+    }
+
+    pub fn endpoints(&self) -> Vec<&str> {
+        self.owners(|owner| match owner {
+            Owner::Endpoint(name) => Some(name),
+            _ => None,
+        })
+    }
+
+    pub fn sites_of(&self, kind: SiteKind) -> impl Iterator<Item = &RenameSite> {
+        self.sites.iter().filter(move |site| site.kind == kind)
+    }
+
+    /// JSON Patch (RFC 6902) operations applying the rename: keys are
+    /// moved, names in lists and parameters replaced.
+    pub fn patch(&self) -> Vec<Value> {
+        self.sites
+            .iter()
+            .map(|site| match site.kind {
+                SiteKind::Property | SiteKind::Example => json!({
+                    "op": "move",
+                    "from": site.pointer,
+                    "path": sibling_pointer(&site.pointer, &self.new_name),
+                }),
+                SiteKind::Required | SiteKind::Discriminator | SiteKind::Parameter => json!({
+                    "op": "replace",
+                    "path": site.pointer,
+                    "value": self.new_name,
+                }),
+            })
+            .collect()
+    }
+}
+
+/// Plans renaming `field` to `new_name` in `document`. A nested field
+/// (`address.city`, `lines[].sku`) only renames its last segment, where it
+/// sits under the same parent properties.
+pub fn plan_rename(document: &Value, field: &str, new_name: &str) -> Result<RenamePlan> {
+    if new_name.is_empty()
+        || new_name.contains(['.', '[', ']'])
+        || new_name.contains(char::is_whitespace)
+    {
+        return Err(anyhow!("'{}' is not a valid property name", new_name));
+    }
+    let mut segments: Vec<&str> = field.split('.').collect();
+    let leaf = segments.pop().unwrap_or_default();
+    if leaf == new_name {
+        return Err(anyhow!("'{}' already has that name", field));
+    }
+
+    let mut walker = Walker {
+        parents: segments,
+        leaf,
+        new_name,
+        sites: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    walker.visit(document, &mut Vec::new(), &[]);
+    walker.sites.sort_by(|a, b| a.pointer.cmp(&b.pointer));
+    walker.conflicts.sort();
+
+    Ok(RenamePlan {
+        field: field.to_string(),
+        new_name: new_name.to_string(),
+        sites: walker.sites,
+        conflicts: walker.conflicts,
+    })
+}
+
+struct Walker<'a> {
+    /// Field path segments above the renamed property
+    parents: Vec<&'a str>,
+    leaf: &'a str,
+    new_name: &'a str,
+    sites: Vec<RenameSite>,
+    conflicts: Vec<String>,
+}
+
+impl Walker<'_> {
+    /// `chain` is the field path of `value` when it is a schema reached
+    /// through `properties` and `items`, empty at schema roots.
+    fn visit(&mut self, value: &Value, path: &mut Vec<String>, chain: &[String]) {
+        match value {
+            Value::Object(object) => {
+                self.check_object(object, path, chain);
+                for (key, child) in object {
+                    path.push(key.clone());
+                    match key.as_str() {
+                        "properties" => {
+                            if let Value::Object(properties) = child {
+                                for (name, schema) in properties {
+                                    path.push(name.clone());
+                                    let mut nested = chain.to_vec();
+                                    nested.push(name.clone());
+                                    self.visit(schema, path, &nested);
+                                    path.pop();
+                                }
+                            }
+                        }
+                        "items" => {
+                            let mut nested = chain.to_vec();
+                            if let Some(last) = nested.last_mut() {
+                                last.push_str("[]");
+                            }
+                            self.visit(child, path, &nested);
+                        }
+                        "allOf" | "oneOf" | "anyOf" => self.visit(child, path, chain),
+                        "example" | "default" => self.visit_example(child, path, chain),
+                        "examples" => match child {
+                            // Schema examples (3.1) are values, media type
+                            // and parameter examples are Example objects
+                            Value::Array(values) => {
+                                for (i, example) in values.iter().enumerate() {
+                                    path.push(i.to_string());
+                                    self.visit_example(example, path, chain);
+                                    path.pop();
+                                }
+                            }
+                            Value::Object(examples) => {
+                                for (name, example) in examples {
+                                    if let Some(example_value) = example.get("value") {
+                                        path.push(name.clone());
+                                        path.push("value".to_string());
+                                        self.visit_example(example_value, path, chain);
+                                        path.pop();
+                                        path.pop();
+                                    }
+                                }
+                            }
+                            _ => {}
+                        },
+                        _ => self.visit(child, path, &[]),
+                    }
+                    path.pop();
+                }
+            }
+            Value::Array(values) => {
+                for (i, child) in values.iter().enumerate() {
+                    path.push(i.to_string());
+                    self.visit(child, path, chain);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        object: &serde_json::Map<String, Value>,
+        path: &[String],
+        chain: &[String],
+    ) {
+        if chain == self.parents.as_slice() {
+            if let Some(Value::Object(properties)) = object.get("properties") {
+                if properties.contains_key(self.leaf) {
+                    self.add(SiteKind::Property, path, &["properties", self.leaf]);
+                    if properties.contains_key(self.new_name) {
+                        self.conflicts
+                            .push(pointer(path, &["properties", self.new_name]));
+                    }
+                }
+            }
+            if let Some(Value::Array(required)) = object.get("required") {
+                if let Some(i) = required.iter().position(|name| name == self.leaf) {
+                    self.add(SiteKind::Required, path, &["required", &i.to_string()]);
+                }
+            }
+            let discriminator = object
+                .get("discriminator")
+                .and_then(|d| d.get("propertyName"));
+            if discriminator.is_some_and(|name| name == self.leaf) {
+                self.add(
+                    SiteKind::Discriminator,
+                    path,
+                    &["discriminator", "propertyName"],
+                );
+            }
+        }
+        // Parameters are indexed by name, as top-level fields
+        let is_parameter = object.get("in").is_some_and(Value::is_string);
+        if self.parents.is_empty()
+            && is_parameter
+            && object.get("name").is_some_and(|name| name == self.leaf)
+        {
+            self.add(SiteKind::Parameter, path, &["name"]);
+        }
+    }
+
+    /// `value` is an instance of the schema at `chain`.
+    fn visit_example(&mut self, value: &Value, path: &mut Vec<String>, chain: &[String]) {
+        if chain.len() > self.parents.len() || self.parents[..chain.len()] != *chain {
+            return;
+        }
+        let remaining = self.parents[chain.len()..].to_vec();
+        self.visit_instance(value, path, &remaining);
+    }
+
+    fn visit_instance(&mut self, value: &Value, path: &mut Vec<String>, remaining: &[&str]) {
+        match (value, remaining.split_first()) {
+            (Value::Array(values), _) => {
+                // A root array example holds instances of its items
+                for (i, item) in values.iter().enumerate() {
+                    path.push(i.to_string());
+                    self.visit_instance(item, path, remaining);
+                    path.pop();
+                }
+            }
+            (Value::Object(object), None) if object.contains_key(self.leaf) => {
+                self.add(SiteKind::Example, path, &[self.leaf]);
+                if object.contains_key(self.new_name) {
+                    self.conflicts.push(pointer(path, &[self.new_name]));
+                }
+            }
+            (Value::Object(object), Some((segment, rest))) => {
+                let name = segment.trim_end_matches("[]");
+                if let Some(child) = object.get(name) {
+                    path.push(name.to_string());
+                    self.visit_instance(child, path, rest);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, kind: SiteKind, path: &[String], tail: &[&str]) {
+        self.sites.push(RenameSite {
+            kind,
+            owner: owner_of(path),
+            pointer: pointer(path, tail),
+        });
+    }
+}
+
+/// Part of the document holding `path`.
+fn owner_of(path: &[String]) -> Owner {
+    let segment = |i: usize| path.get(i).map(String::as_str);
+    match (segment(0), segment(1), segment(2)) {
+        (Some("components"), Some("schemas"), Some(name)) => Owner::Schema(name.to_string()),
+        (Some("components"), Some(kind), Some(name)) => {
+            Owner::Component(format!("{}/{}", kind, name))
+        }
+        (Some("paths"), Some(target), Some(method)) if HTTP_METHODS.contains(&method) => {
+            Owner::Endpoint(format!("{} {}", method.to_uppercase(), target))
+        }
+        (Some("paths"), Some(target), _) => Owner::Endpoint(target.to_string()),
+        (Some("webhooks"), Some(name), Some(method)) if HTTP_METHODS.contains(&method) => {
+            Owner::Endpoint(format!("{} webhook:{}", method.to_uppercase(), name))
+        }
+        _ => Owner::Document,
+    }
+}
+
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn pointer(path: &[String], tail: &[&str]) -> String {
+    path.iter()
+        .map(String::as_str)
+        .chain(tail.iter().copied())
+        .map(|segment| format!("/{}", escape(segment)))
+        .collect()
+}
+
+/// `pointer` with its last segment replaced by `name`.
+fn sibling_pointer(pointer: &str, name: &str) -> String {
+    let parent = pointer
+        .rsplit_once('/')
+        .map(|(parent, _)| parent)
+        .unwrap_or("");
+    format!("{}/{}", parent, escape(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document() -> Value {
+        serde_json::from_str(
+            r##"{
+                "openapi": "3.0.0",
+                "paths": {
+                    "/users/{user_id}": {
+                        "parameters": [{"name": "user_id", "in": "path", "required": true}],
+                        "get": {
+                            "parameters": [{"name": "email", "in": "query"}],
+                            "responses": {"200": {"description": "ok", "content": {"application/json": {
+                                "schema": {"$ref": "#/components/schemas/User"},
+                                "examples": {"alice": {"value": {"email": "a@example.com", "address": {"city": "Lyon"}}}}
+                            }}}}
+                        }
+                    }
+                },
+                "components": {"schemas": {
+                    "User": {
+                        "type": "object",
+                        "required": ["id", "email"],
+                        "properties": {
+                            "id": {"type": "integer"},
+                            "email": {"type": "string"},
+                            "address": {"type": "object", "properties": {"city": {"type": "string"}}}
+                        },
+                        "example": {"id": 1, "email": "b@example.com"}
+                    },
+                    "Contact": {"type": "object", "properties": {"email": {"type": "string"}, "mail": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_plans_every_mention_of_a_field() {
+        let plan = plan_rename(&document(), "email", "mail").unwrap();
+        let sites: Vec<(SiteKind, &str)> = plan
+            .sites
+            .iter()
+            .map(|site| (site.kind, site.pointer.as_str()))
+            .collect();
+        assert_eq!(
+            sites,
+            vec![
+                (SiteKind::Property, "/components/schemas/Contact/properties/email"),
+                (SiteKind::Example, "/components/schemas/User/example/email"),
+                (SiteKind::Property, "/components/schemas/User/properties/email"),
+                (SiteKind::Required, "/components/schemas/User/required/1"),
+                (SiteKind::Parameter, "/paths/~1users~1{user_id}/get/parameters/0/name"),
+                (
+                    SiteKind::Example,
+                    "/paths/~1users~1{user_id}/get/responses/200/content/application~1json/examples/alice/value/email"
+                ),
+            ]
+        );
+        assert_eq!(plan.schemas(), vec!["Contact", "User"]);
+        assert_eq!(plan.endpoints(), vec!["GET /users/{user_id}"]);
+        assert_eq!(
+            plan.conflicts,
+            vec!["/components/schemas/Contact/properties/mail"]
+        );
+
+        let patch = plan.patch();
+        assert_eq!(
+            patch[0],
+            json!({
+                "op": "move",
+                "from": "/components/schemas/Contact/properties/email",
+                "path": "/components/schemas/Contact/properties/mail"
+            })
+        );
+        assert_eq!(
+            patch[3],
+            json!({"op": "replace", "path": "/components/schemas/User/required/1", "value": "mail"})
+        );
+    }
+
+    #[test]
+    fn test_nested_fields_rename_under_their_parent() {
+        let document = document();
+        let plan = plan_rename(&document, "address.city", "town").unwrap();
+        let pointers: Vec<&str> = plan
+            .sites
+            .iter()
+            .map(|site| site.pointer.as_str())
+            .collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/components/schemas/User/properties/address/properties/city",
+                "/paths/~1users~1{user_id}/get/responses/200/content/application~1json/examples/alice/value/address/city",
+            ]
+        );
+
+        // Path-level parameters keep their place in the source document
+        let plan = plan_rename(&document, "user_id", "id").unwrap();
+        assert_eq!(
+            plan.sites[0].owner,
+            Owner::Endpoint("/users/{user_id}".to_string())
+        );
+
+        assert!(plan_rename(&document, "email", "e mail").is_err());
+        assert!(plan_rename(&document, "email", "email").is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use crate::criticality::CriticalityRules;
+use crate::explorer::Explorer;
+use crate::indexer::{analyze_field_relationships, FieldIndex, HttpMethod};
+use crate::lint::{Diagnostic, LintConfig, LintFinding};
+use crate::parser::{OpenApiSpec, ParseWarning};
+use crate::sensitive::{find_response_exposures, SensitiveDetector, SensitiveExposure};
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tera::Tera;
+
+/// How the analysis is presented.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Interactive terminal UI
+    #[default]
+    Tui,
+    /// Machine-readable report on stdout
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SpecInfo {
+    pub title: String,
+    pub version: String,
+    pub openapi: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub schemas: usize,
+    pub fields: usize,
+    pub paths: usize,
+    pub operations: usize,
+    /// POST, PUT, PATCH and DELETE path operations
+    #[serde(default)]
+    pub write_operations: usize,
+    pub critical_fields: usize,
+    pub field_types: BTreeMap<String, usize>,
+    pub methods: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldReport {
+    #[serde(rename = "type")]
+    pub field_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub schemas: Vec<String>,
+    pub endpoints: Vec<String>,
+    pub critical: bool,
+    /// Label of the sensitive-data rule matching the field name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitive: Option<String>,
+}
+
+/// Whole-spec analysis, with sorted keys so reports diff cleanly between
+/// runs.
+#[derive(Debug, Serialize)]
+pub struct AnalysisReport {
+    pub spec: SpecInfo,
+    pub stats: Stats,
+    pub fields: BTreeMap<String, FieldReport>,
+    /// Fields declared together in at least one schema
+    pub relationships: BTreeMap<String, Vec<String>>,
+    /// Unsuppressed validation warnings, most severe first
+    pub warnings: Vec<Diagnostic>,
+    /// Warnings tied to an operation or path, with the rule raising them
+    pub findings: Vec<LintFinding>,
+    /// Elements a lenient parse dropped (`--lenient`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parse_warnings: Vec<ParseWarning>,
+}
+
+pub fn build_report(
+    explorer: &Explorer,
+    detector: &SensitiveDetector,
+    lint_config: &LintConfig,
+    criticality: &CriticalityRules,
+) -> AnalysisReport {
+    let spec = explorer.spec();
+    let index = explorer.index();
+
+    let fields: BTreeMap<String, FieldReport> = explorer
+        .fields()
+        .into_iter()
+        .filter_map(|name| {
+            let data = explorer.field(name)?;
+            let mut schemas: Vec<String> = data.schemas.iter().map(|s| s.to_string()).collect();
+            schemas.sort();
+            Some((
+                name.to_string(),
+                FieldReport {
+                    field_type: data.field_type.clone(),
+                    description: data.description.clone(),
+                    schemas,
+                    endpoints: explorer.endpoints_for_field(name),
+                    critical: criticality.is_critical(index, name),
+                    sensitive: detector.classify(name).map(str::to_string),
+                },
+            ))
+        })
+        .collect();
+
+    let mut warnings = explorer.lint(lint_config);
+    warnings.retain(|warning| !warning.suppressed);
+    warnings.sort_by(|a, b| (a.severity, &a.message).cmp(&(b.severity, &b.message)));
+
+    AnalysisReport {
+        spec: SpecInfo {
+            title: spec.info.title.clone(),
+            version: spec.info.version.clone(),
+            openapi: spec.openapi.clone(),
+        },
+        stats: Stats {
+            critical_fields: fields.values().filter(|field| field.critical).count(),
+            ..compute_stats(spec, index)
+        },
+        fields,
+        relationships: analyze_field_relationships(index).into_iter().collect(),
+        warnings,
+        findings: explorer.lint_findings(lint_config),
+        parse_warnings: spec.parse_warnings.clone(),
+    }
+}
+
+/// Template used by the `report` subcommand without `--template`.
+pub const DEFAULT_TEMPLATE: &str = include_str!("../examples/audit.md.tera");
+
+/// What report templates see: the JSON analysis report at the top level,
+/// plus the critical and sensitive fields and the responses exposing them.
+#[derive(Debug, Serialize)]
+pub struct TemplateContext {
+    #[serde(flatten)]
+    pub report: AnalysisReport,
+    pub critical_fields: Vec<String>,
+    /// Sensitive data label of each matching field name
+    pub sensitive_fields: BTreeMap<String, String>,
+    pub exposures: Vec<SensitiveExposure>,
+    /// RFC 3339 time of the run
+    pub generated_at: String,
+}
+
+pub fn build_template_context(
+    explorer: &Explorer,
+    detector: &SensitiveDetector,
+    lint_config: &LintConfig,
+    criticality: &CriticalityRules,
+    now: DateTime<Utc>,
+) -> TemplateContext {
+    let report = build_report(explorer, detector, lint_config, criticality);
+    let critical_fields = report
+        .fields
+        .iter()
+        .filter(|(_, field)| field.critical)
+        .map(|(name, _)| name.clone())
+        .collect();
+    let sensitive_fields = report
+        .fields
+        .iter()
+        .filter_map(|(name, field)| Some((name.clone(), field.sensitive.clone()?)))
+        .collect();
+    TemplateContext {
+        critical_fields,
+        sensitive_fields,
+        exposures: find_response_exposures(explorer.spec(), explorer.index(), detector),
+        generated_at: now.to_rfc3339_opts(SecondsFormat::Secs, true),
+        report,
+    }
+}
+
+/// Renders a Tera template named `name` over the context; `.html` names
+/// escape the values.
+pub fn render_template(name: &str, template: &str, context: &TemplateContext) -> Result<String> {
+    let mut tera = Tera::default();
+    tera.add_raw_template(name, template)
+        .with_context(|| format!("Invalid report template {}", name))?;
+    let context = tera::Context::from_serialize(context)?;
+    tera.render(name, &context)
+        .with_context(|| format!("Failed to render report template {}", name))
+}
+
+/// Overview numbers shown on the Stats view.
+pub fn compute_stats(spec: &OpenApiSpec, index: &FieldIndex) -> Stats {
+    let mut stats = Stats {
+        schemas: index.schemas.len(),
+        fields: index.fields.len(),
+        paths: spec.paths.len(),
+        operations: index.endpoint_fields.len(),
+        critical_fields: index
+            .fields
+            .keys()
+            .filter(|name| index.is_critical_field(name))
+            .count(),
+        ..Stats::default()
+    };
+    for field in index.fields.values() {
+        *stats
+            .field_types
+            .entry(field.field_type.clone())
+            .or_default() += 1;
+    }
+    for path_item in spec.paths.values() {
+        for method in path_item.operations.keys() {
+            *stats.methods.entry(method.to_uppercase()).or_default() += 1;
+            if HttpMethod::parse(method).is_some_and(HttpMethod::is_write) {
+                stats.write_operations += 1;
+            }
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_build_report() {
+        let explorer = Explorer::from_spec(
+            parse_openapi_content(
+                r#"{
+                    "openapi": "3.0.0",
+                    "info": {"title": "Users", "version": "2"},
+                    "paths": {"/users": {"post": {"requestBody": {"content": {"application/json": {"schema": {
+                        "type": "object", "properties": {"email": {"type": "string"}}
+                    }}}}, "responses": {}}}},
+                    "components": {"schemas": {"User": {"type": "object", "properties": {
+                        "id": {"type": "integer"}, "email": {"type": "string"}
+                    }}}}
+                }"#,
+            )
+            .unwrap(),
+        );
+
+        let report = build_report(
+            &explorer,
+            &SensitiveDetector::default(),
+            &LintConfig::default(),
+            &CriticalityRules::default(),
+        );
+        assert_eq!(report.stats.fields, 2);
+        assert_eq!(report.stats.methods["POST"], 1);
+        assert_eq!(report.stats.write_operations, 1);
+        assert_eq!(report.stats.critical_fields, 1);
+        assert_eq!(report.fields["email"].endpoints, vec!["POST /users"]);
+        assert_eq!(report.fields["email"].sensitive.as_deref(), Some("email"));
+        assert_eq!(report.relationships["id"], vec!["email"]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["spec"]["title"], "Users");
+        assert_eq!(json["fields"]["id"]["type"], "integer");
+        assert!(json["fields"]["id"].get("sensitive").is_none());
+        assert_eq!(json["findings"][0]["rule"], "missing_operation_id");
+        assert_eq!(json["findings"][0]["location"], "POST /users");
+    }
+
+    #[test]
+    fn test_render_default_template() {
+        let explorer = Explorer::from_spec(
+            parse_openapi_content(
+                r##"{
+                    "openapi": "3.0.0",
+                    "info": {"title": "Users", "version": "2"},
+                    "paths": {"/users": {"post": {"operationId": "createUser", "requestBody": {"content": {"application/json": {"schema": {
+                        "type": "object", "properties": {"email": {"type": "string"}}
+                    }}}}, "responses": {"201": {"description": "created", "content": {"application/json": {"schema": {
+                        "$ref": "#/components/schemas/User"
+                    }}}}}}}},
+                    "components": {"schemas": {"User": {"type": "object", "properties": {
+                        "email": {"type": "string"}
+                    }}}}
+                }"##,
+            )
+            .unwrap(),
+        );
+        let now = DateTime::parse_from_rfc3339("2026-10-15T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let context = build_template_context(
+            &explorer,
+            &SensitiveDetector::default(),
+            &LintConfig::default(),
+            &CriticalityRules::default(),
+            now,
+        );
+        assert_eq!(context.critical_fields, vec!["email"]);
+        assert_eq!(context.exposures[0].endpoint, "POST /users");
+
+        let markdown = render_template("audit.md.tera", DEFAULT_TEMPLATE, &context).unwrap();
+        assert!(markdown.starts_with("# API review: Users 2\n"));
+        assert!(markdown.contains("Generated 2026-10-15T09:00:00Z"));
+        assert!(markdown.contains("| Write operations | 1 |"));
+        assert!(markdown.contains("No findings."));
+        assert!(markdown.contains("- `email` (string), used by 1 endpoint(s)"));
+        assert!(markdown.contains("| `POST /users` | 201 | email (email) |"));
+
+        let custom = render_template(
+            "short.txt",
+            "{{ spec.title }}: {{ findings | length }}",
+            &context,
+        );
+        assert_eq!(custom.unwrap(), "Users: 0");
+        let error = render_template("bad.txt", "{{ missing.value }}", &context).unwrap_err();
+        assert!(format!("{:#}", error).contains("bad.txt"));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::indexer::{status_sort_key, FieldIndex, ResponseData};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Methods expected to document how invalid input is rejected
+const WRITE_METHODS: &[&str] = &["POST", "PUT", "PATCH"];
+
+/// Error contract an operation is expected to document but does not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResponseGap {
+    /// No 2xx or 3xx response
+    NoSuccess,
+    /// POST, PUT or PATCH without any 4xx response
+    NoClientError,
+    /// Path with parameters but no 404
+    NoNotFound,
+    /// Own `security` requirements but no 401
+    NoUnauthorized,
+}
+
+impl ResponseGap {
+    pub const ALL: [ResponseGap; 4] = [
+        ResponseGap::NoSuccess,
+        ResponseGap::NoClientError,
+        ResponseGap::NoNotFound,
+        ResponseGap::NoUnauthorized,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResponseGap::NoSuccess => "no 2xx",
+            ResponseGap::NoClientError => "no 4xx",
+            ResponseGap::NoNotFound => "no 404",
+            ResponseGap::NoUnauthorized => "no 401",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ResponseGap::NoSuccess => "no success response",
+            ResponseGap::NoClientError => "write without 4xx",
+            ResponseGap::NoNotFound => "path param, no 404",
+            ResponseGap::NoUnauthorized => "secured, no 401",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixRow {
+    pub endpoint: String,
+    pub statuses: Vec<String>,
+    pub gaps: Vec<ResponseGap>,
+}
+
+impl MatrixRow {
+    pub fn documents(&self, status: &str) -> bool {
+        self.statuses.iter().any(|s| s.eq_ignore_ascii_case(status))
+    }
+}
+
+/// Endpoints × documented status codes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResponseMatrix {
+    /// Every status documented by one of the rows, in matrix order
+    pub statuses: Vec<String>,
+    pub rows: Vec<MatrixRow>,
+}
+
+impl ResponseMatrix {
+    /// Rows with at least one gap.
+    pub fn rows_with_gaps(&self) -> usize {
+        self.rows.iter().filter(|row| !row.gaps.is_empty()).count()
+    }
+
+    /// Number of rows with each gap.
+    pub fn gap_counts(&self) -> BTreeMap<ResponseGap, usize> {
+        let mut counts = BTreeMap::new();
+        for gap in self.rows.iter().flat_map(|row| &row.gaps) {
+            *counts.entry(*gap).or_default() += 1;
+        }
+        counts
+    }
+}
+
+/// Matrix of the given `"METHOD /path"` endpoints, in that order. Endpoints
+/// without indexed responses (webhooks, callbacks) are left out.
+pub fn build_response_matrix(index: &FieldIndex, endpoints: &[String]) -> ResponseMatrix {
+    let rows: Vec<MatrixRow> = endpoints
+        .iter()
+        .filter_map(|endpoint| {
+            let data = index.responses.get(endpoint)?;
+            Some(MatrixRow {
+                endpoint: endpoint.clone(),
+                statuses: data.statuses.clone(),
+                gaps: response_gaps(endpoint, data),
+            })
+        })
+        .collect();
+    let statuses: BTreeSet<(u8, bool, String)> = rows
+        .iter()
+        .flat_map(|row| &row.statuses)
+        .map(|status| status_sort_key(status))
+        .collect();
+    ResponseMatrix {
+        statuses: statuses.into_iter().map(|(_, _, status)| status).collect(),
+        rows,
+    }
+}
+
+/// Gaps of one operation. A `4XX` range or a `default` response covers
+/// the error codes; only an explicit 2xx or 3xx covers success.
+pub fn response_gaps(endpoint: &str, data: &ResponseData) -> Vec<ResponseGap> {
+    let (method, path) = endpoint.split_once(' ').unwrap_or(("", endpoint));
+    let has_class = |class: char| data.statuses.iter().any(|s| s.starts_with(class));
+    let has_default = data
+        .statuses
+        .iter()
+        .any(|s| s.eq_ignore_ascii_case("default"));
+    let covers = |code: &str| {
+        has_default
+            || data.statuses.iter().any(|s| {
+                s == code
+                    || (s.len() == 3
+                        && s.starts_with(&code[..1])
+                        && s[1..].eq_ignore_ascii_case("xx"))
+            })
+    };
+
+    let mut gaps = Vec::new();
+    if !has_class('2') && !has_class('3') {
+        gaps.push(ResponseGap::NoSuccess);
+    }
+    if WRITE_METHODS.contains(&method) && !has_class('4') && !has_default {
+        gaps.push(ResponseGap::NoClientError);
+    }
+    if path.contains('{') && !covers("404") {
+        gaps.push(ResponseGap::NoNotFound);
+    }
+    if data.secured && !covers("401") {
+        gaps.push(ResponseGap::NoUnauthorized);
+    }
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_matrix_highlights_missing_error_contracts() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/orders": {
+                        "get": {"responses": {"200": {"description": "ok"}, "default": {"description": "error"}}},
+                        "post": {"security": [{"oauth": []}], "responses": {"201": {"description": "created"}}}
+                    },
+                    "/orders/{id}": {
+                        "get": {"responses": {"200": {"description": "ok"}, "4XX": {"description": "client error"}}},
+                        "put": {"responses": {"200": {"description": "ok"}, "400": {"description": "bad"}}},
+                        "delete": {"responses": {"500": {"description": "oops"}}}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        assert_eq!(
+            index.responses["GET /orders/{id}"].statuses,
+            vec!["200", "4XX"]
+        );
+        assert!(index.responses["POST /orders"].secured);
+
+        let endpoints: Vec<String> = [
+            "GET /orders",
+            "POST /orders",
+            "GET /orders/{id}",
+            "PUT /orders/{id}",
+            "DELETE /orders/{id}",
+            "POST webhook:unknown",
+        ]
+        .map(String::from)
+        .to_vec();
+        let matrix = build_response_matrix(&index, &endpoints);
+        assert_eq!(
+            matrix.statuses,
+            vec!["200", "201", "400", "4XX", "500", "default"]
+        );
+        let gaps: Vec<(&str, Vec<ResponseGap>)> = matrix
+            .rows
+            .iter()
+            .map(|row| (row.endpoint.as_str(), row.gaps.clone()))
+            .collect();
+        assert_eq!(
+            gaps,
+            vec![
+                ("GET /orders", vec![]),
+                (
+                    "POST /orders",
+                    vec![ResponseGap::NoClientError, ResponseGap::NoUnauthorized]
+                ),
+                ("GET /orders/{id}", vec![]),
+                ("PUT /orders/{id}", vec![ResponseGap::NoNotFound]),
+                (
+                    "DELETE /orders/{id}",
+                    vec![ResponseGap::NoSuccess, ResponseGap::NoNotFound]
+                ),
+            ]
+        );
+        assert!(matrix.rows[2].documents("4xx"));
+        assert_eq!(matrix.rows_with_gaps(), 3);
+        assert_eq!(matrix.gap_counts()[&ResponseGap::NoNotFound], 2);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::example::generate_example;
+use crate::parser::{Operation, Schema};
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default target for the try-it-out runner (the `serve` mock server)
+pub const DEFAULT_BASE_URL: &str = "http://localhost:8080";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct ParamInput {
+    pub name: String,
+    /// `path`, `query` or `header`; cookie parameters are not sent
+    pub location: String,
+    pub required: bool,
+    pub value: String,
+}
+
+/// Editable request built from an operation.
+#[derive(Debug, Clone)]
+pub struct RequestDraft {
+    pub method: String,
+    /// Path template, e.g. `/pets/{petId}`
+    pub path: String,
+    pub base_url: String,
+    pub params: Vec<ParamInput>,
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+impl RequestDraft {
+    /// Prefills parameters and body from the operation's schemas.
+    pub fn from_operation(
+        method: &str,
+        path: &str,
+        operation: &Operation,
+        schemas: &HashMap<String, Schema>,
+        base_url: &str,
+    ) -> Self {
+        let params = operation
+            .parameters
+            .iter()
+            .flatten()
+            .filter(|p| p.in_ != "cookie")
+            .map(|p| ParamInput {
+                name: p.name.clone(),
+                location: p.in_.clone(),
+                required: p.required.unwrap_or(p.in_ == "path"),
+                value: p
+                    .schema
+                    .as_ref()
+                    .map(|schema| value_to_param(&generate_example(schema, schemas)))
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let media = operation.request_body.as_ref().and_then(|body| {
+            body.content
+                .get("application/json")
+                .map(|m| ("application/json", m))
+                .or_else(|| body.content.iter().next().map(|(ct, m)| (ct.as_str(), m)))
+        });
+        let body = media
+            .and_then(|(_, m)| m.schema.as_ref())
+            .map(|schema| {
+                serde_json::to_string_pretty(&generate_example(schema, schemas)).unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+        Self {
+            method: method.to_uppercase(),
+            path: path.to_string(),
+            base_url: base_url.to_string(),
+            params,
+            content_type: media.map(|(content_type, _)| content_type.to_string()),
+            body,
+        }
+    }
+
+    /// Full URL with path parameters substituted and query parameters
+    /// appended. Empty optional parameters are skipped.
+    pub fn url(&self) -> Result<reqwest::Url> {
+        let mut path = self.path.clone();
+        for param in self.params.iter().filter(|p| p.location == "path") {
+            if param.value.is_empty() {
+                return Err(anyhow!("Path parameter '{}' is required", param.name));
+            }
+            path = path.replace(
+                &format!("{{{}}}", param.name),
+                &encode_path_segment(&param.value),
+            );
+        }
+
+        let mut url =
+            reqwest::Url::parse(&format!("{}{}", self.base_url.trim_end_matches('/'), path))
+                .map_err(|e| anyhow!("Invalid URL: {}", e))?;
+
+        let query: Vec<&ParamInput> = self
+            .params
+            .iter()
+            .filter(|p| p.location == "query" && !p.value.is_empty())
+            .collect();
+        if !query.is_empty() {
+            let mut pairs = url.query_pairs_mut();
+            for param in query {
+                pairs.append_pair(&param.name, &param.value);
+            }
+        }
+        Ok(url)
+    }
+}
+
+fn value_to_param(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn encode_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutedResponse {
+    pub status: u16,
+    pub reason: String,
+    pub headers: Vec<(String, String)>,
+    /// Body, pretty-printed when it is JSON
+    pub body: String,
+    pub elapsed: Duration,
+}
+
+impl ExecutedResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub async fn execute(draft: &RequestDraft) -> Result<ExecutedResponse> {
+    let url = draft.url()?;
+    let method = reqwest::Method::from_bytes(draft.method.as_bytes())
+        .map_err(|_| anyhow!("Unsupported method {}", draft.method))?;
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    let mut request = client.request(method, url);
+    for param in draft
+        .params
+        .iter()
+        .filter(|p| p.location == "header" && !p.value.is_empty())
+    {
+        request = request.header(param.name.as_str(), param.value.as_str());
+    }
+    if !draft.body.trim().is_empty() {
+        request = request
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                draft.content_type.as_deref().unwrap_or("application/json"),
+            )
+            .body(draft.body.clone());
+    }
+
+    let started = Instant::now();
+    let response = request.send().await?;
+    let status = response.status();
+    let mut headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                value.to_str().unwrap_or("<binary>").to_string(),
+            )
+        })
+        .collect();
+    headers.sort();
+    let text = response.text().await?;
+    let elapsed = started.elapsed();
+
+    let body = serde_json::from_str::<Value>(&text)
+        .ok()
+        .and_then(|json| serde_json::to_string_pretty(&json).ok())
+        .unwrap_or(text);
+
+    Ok(ExecutedResponse {
+        status: status.as_u16(),
+        reason: status.canonical_reason().unwrap_or("").to_string(),
+        headers,
+        body,
+        elapsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn draft() -> RequestDraft {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {"/pets/{petId}": {"put": {
+                    "parameters": [
+                        {"name": "petId", "in": "path", "required": true, "schema": {"type": "integer", "example": 7}},
+                        {"name": "q", "in": "query", "schema": {"type": "string"}},
+                        {"name": "limit", "in": "query", "schema": {"type": "integer"}},
+                        {"name": "session", "in": "cookie", "schema": {"type": "string"}}
+                    ],
+                    "requestBody": {"content": {"application/json": {"schema": {
+                        "type": "object", "properties": {"name": {"type": "string", "example": "Rex"}}
+                    }}}},
+                    "responses": {}
+                }}}
+            }"#,
+        )
+        .unwrap();
+        let operation = &spec.paths["/pets/{petId}"].operations["put"];
+        RequestDraft::from_operation(
+            "put",
+            "/pets/{petId}",
+            operation,
+            &HashMap::new(),
+            "http://localhost:8080/",
+        )
+    }
+
+    #[test]
+    fn test_draft_prefilled_from_schemas() {
+        let draft = draft();
+        assert_eq!(draft.method, "PUT");
+        assert_eq!(draft.params.len(), 3);
+        assert_eq!(draft.params[0].value, "7");
+        assert!(draft.params[0].required);
+        assert_eq!(draft.content_type.as_deref(), Some("application/json"));
+        assert!(draft.body.contains("\"name\": \"Rex\""));
+    }
+
+    #[test]
+    fn test_url_substitutes_and_encodes() {
+        let mut draft = draft();
+        draft.params[0].value = "a b".to_string();
+        draft.params[1].value = "x&y".to_string();
+        draft.params[2].value.clear();
+        assert_eq!(
+            draft.url().unwrap().as_str(),
+            "http://localhost:8080/pets/a%20b?q=x%26y"
+        );
+
+        draft.params[0].value.clear();
+        assert!(draft.url().is_err());
+    }
+}
@@ -0,0 +1,438 @@
+use crate::parser::{extract_schema_name_from_ref, OpenApiSpec, Schema};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A `$ref` from one component schema to another.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaEdge {
+    pub target: String,
+    /// Location of the reference inside the source schema, e.g. `owner`,
+    /// `tags[]` or `allOf[0]`.
+    pub via: String,
+}
+
+/// Schema-level dependency graph built from the raw `$ref`s of
+/// `components.schemas`, before any reference flattening happens.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SchemaGraph {
+    pub edges: HashMap<String, Vec<SchemaEdge>>,
+    pub reverse_edges: HashMap<String, Vec<(String, String)>>,
+    pub cycles: Vec<Vec<String>>,
+}
+
+impl SchemaGraph {
+    /// Schemas referenced by `schema_name` (deduplicated).
+    pub fn dependencies(&self, schema_name: &str) -> Vec<String> {
+        let mut targets: Vec<String> = self
+            .edges
+            .get(schema_name)
+            .map(|edges| edges.iter().map(|e| e.target.clone()).collect())
+            .unwrap_or_default();
+        targets.sort_unstable();
+        targets.dedup();
+        targets
+    }
+
+    /// Schemas that reference `schema_name` (deduplicated).
+    pub fn dependents(&self, schema_name: &str) -> Vec<String> {
+        let mut sources: Vec<String> = self
+            .reverse_edges
+            .get(schema_name)
+            .map(|edges| edges.iter().map(|(source, _)| source.clone()).collect())
+            .unwrap_or_default();
+        sources.sort_unstable();
+        sources.dedup();
+        sources
+    }
+
+    pub fn fan_out(&self, schema_name: &str) -> usize {
+        self.dependencies(schema_name).len()
+    }
+
+    pub fn fan_in(&self, schema_name: &str) -> usize {
+        self.dependents(schema_name).len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.values().map(|edges| edges.len()).sum()
+    }
+
+    /// `schema_name` plus every schema that references it, directly or
+    /// through other schemas.
+    pub fn transitive_dependents(&self, schema_name: &str) -> HashSet<String> {
+        let mut visited = HashSet::from([schema_name.to_string()]);
+        let mut queue = vec![schema_name.to_string()];
+        while let Some(current) = queue.pop() {
+            for (source, _) in self.reverse_edges.get(&current).into_iter().flatten() {
+                if visited.insert(source.clone()) {
+                    queue.push(source.clone());
+                }
+            }
+        }
+        visited
+    }
+
+    /// `schema_name` plus every schema it references, directly or through
+    /// other schemas.
+    pub fn transitive_dependencies(&self, schema_name: &str) -> HashSet<String> {
+        let mut visited = HashSet::from([schema_name.to_string()]);
+        let mut queue = vec![schema_name.to_string()];
+        while let Some(current) = queue.pop() {
+            for edge in self.edges.get(&current).into_iter().flatten() {
+                if visited.insert(edge.target.clone()) {
+                    queue.push(edge.target.clone());
+                }
+            }
+        }
+        visited
+    }
+
+    pub fn is_in_cycle(&self, schema_name: &str) -> bool {
+        self.cycles
+            .iter()
+            .any(|cycle| cycle.iter().any(|name| name == schema_name))
+    }
+}
+
+pub fn build_schema_graph(openapi_spec: &OpenApiSpec) -> SchemaGraph {
+    let mut graph = SchemaGraph::default();
+
+    let schemas = match openapi_spec
+        .components
+        .as_ref()
+        .and_then(|c| c.schemas.as_ref())
+    {
+        Some(schemas) => schemas,
+        None => return graph,
+    };
+
+    for (schema_name, schema) in schemas {
+        let mut edges = Vec::new();
+        collect_refs(schema, "", &mut edges);
+        // Only keep references that point to known component schemas
+        edges.retain(|edge| schemas.contains_key(&edge.target));
+
+        for edge in &edges {
+            graph
+                .reverse_edges
+                .entry(edge.target.clone())
+                .or_default()
+                .push((schema_name.clone(), edge.via.clone()));
+        }
+        graph.edges.insert(schema_name.clone(), edges);
+    }
+
+    let targets = graph
+        .edges
+        .iter()
+        .map(|(name, edges)| {
+            let targets = edges.iter().map(|edge| edge.target.clone()).collect();
+            (name.clone(), targets)
+        })
+        .collect();
+    graph.cycles = find_cycles(&targets);
+    log::debug!(
+        "Schema graph: {} edges, {} cycle(s)",
+        graph.edge_count(),
+        graph.cycles.len()
+    );
+
+    graph
+}
+
+/// Component schema names referenced anywhere inside `schema` (deduplicated).
+pub fn referenced_schemas(schema: &Schema) -> Vec<String> {
+    let mut edges = Vec::new();
+    collect_refs(schema, "", &mut edges);
+    let mut names: Vec<String> = edges.into_iter().map(|edge| edge.target).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+fn collect_refs(schema: &Schema, path: &str, edges: &mut Vec<SchemaEdge>) {
+    if let Some(ref_path) = &schema.reference {
+        if let Some(target) = extract_schema_name_from_ref(ref_path) {
+            edges.push(SchemaEdge {
+                target: target.to_string(),
+                via: if path.is_empty() {
+                    "$ref".to_string()
+                } else {
+                    path.to_string()
+                },
+            });
+        }
+    }
+
+    let join = |segment: &str| {
+        if path.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}.{}", path, segment)
+        }
+    };
+
+    if let Some(properties) = &schema.properties {
+        for (name, property) in properties {
+            collect_refs(property, &join(name), edges);
+        }
+    }
+
+    if let Some(items) = &schema.items {
+        collect_refs(items, &format!("{}[]", path), edges);
+    }
+
+    if let Some(additional) = &schema.additional_properties {
+        collect_refs(additional, &join("{*}"), edges);
+    }
+
+    if let Some(not) = &schema.not {
+        collect_refs(not, &join("not"), edges);
+    }
+
+    for (keyword, branches) in [
+        ("allOf", &schema.all_of),
+        ("oneOf", &schema.one_of),
+        ("anyOf", &schema.any_of),
+    ] {
+        if let Some(branches) = branches {
+            for (i, branch) in branches.iter().enumerate() {
+                collect_refs(branch, &join(&format!("{}[{}]", keyword, i)), edges);
+            }
+        }
+    }
+}
+
+/// Returns the strongly connected components that form cycles (including
+/// self-references) in the graph of each schema's targets, using Tarjan's
+/// algorithm.
+pub(crate) fn find_cycles(edges: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    struct Tarjan<'a> {
+        edges: &'a HashMap<String, Vec<String>>,
+        index: usize,
+        indices: HashMap<&'a str, usize>,
+        lowlinks: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: HashSet<&'a str>,
+        cycles: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn visit(&mut self, node: &'a str) {
+            self.indices.insert(node, self.index);
+            self.lowlinks.insert(node, self.index);
+            self.index += 1;
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let edges = self.edges;
+            for target in edges.get(node).into_iter().flatten() {
+                let target = target.as_str();
+                if !self.indices.contains_key(target) {
+                    self.visit(target);
+                    let low = self.lowlinks[node].min(self.lowlinks[target]);
+                    self.lowlinks.insert(node, low);
+                } else if self.on_stack.contains(target) {
+                    let low = self.lowlinks[node].min(self.indices[target]);
+                    self.lowlinks.insert(node, low);
+                }
+            }
+
+            if self.lowlinks[node] == self.indices[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member.to_string());
+                    if member == node {
+                        break;
+                    }
+                }
+
+                let self_loop = edges
+                    .get(node)
+                    .is_some_and(|targets| targets.iter().any(|target| target == node));
+                if component.len() > 1 || self_loop {
+                    component.sort_unstable();
+                    self.cycles.push(component);
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        edges,
+        index: 0,
+        indices: HashMap::new(),
+        lowlinks: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        cycles: Vec::new(),
+    };
+
+    let mut nodes: Vec<&str> = edges.keys().map(|k| k.as_str()).collect();
+    nodes.sort_unstable();
+    for node in nodes {
+        if !tarjan.indices.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+
+    tarjan.cycles.sort();
+    tarjan.cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Components, Info};
+
+    fn reference(name: &str) -> Schema {
+        Schema {
+            reference: Some(format!("#/components/schemas/{}", name)),
+            ..Default::default()
+        }
+    }
+
+    fn object(properties: Vec<(&str, Schema)>) -> Schema {
+        Schema {
+            schema_type: Some("object".to_string()),
+            properties: Some(
+                properties
+                    .into_iter()
+                    .map(|(name, schema)| (name.to_string(), schema))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn spec_with(schemas: Vec<(&str, Schema)>) -> OpenApiSpec {
+        OpenApiSpec {
+            openapi: "3.0.0".to_string(),
+            webhooks: None,
+            info: Info {
+                title: "Test".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                terms_of_service: None,
+                contact: None,
+                license: None,
+            },
+            servers: None,
+            paths: HashMap::new(),
+            tags: None,
+            external_docs: None,
+            parse_warnings: Vec::new(),
+            referenced_components: Default::default(),
+            unresolved_refs: Vec::new(),
+            callback_targets: Default::default(),
+            explorer_ignore: None,
+            components: Some(Components {
+                schemas: Some(
+                    schemas
+                        .into_iter()
+                        .map(|(name, schema)| (name.to_string(), schema))
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_edges_and_fan_in_out() {
+        let spec = spec_with(vec![
+            (
+                "Order",
+                object(vec![
+                    ("customer", reference("User")),
+                    (
+                        "lines",
+                        Schema {
+                            schema_type: Some("array".to_string()),
+                            items: Some(Box::new(reference("OrderLine"))),
+                            ..Default::default()
+                        },
+                    ),
+                ]),
+            ),
+            ("OrderLine", object(vec![("product", reference("Product"))])),
+            ("User", object(vec![])),
+            ("Product", object(vec![])),
+            ("Invoice", object(vec![("customer", reference("User"))])),
+        ]);
+
+        let graph = build_schema_graph(&spec);
+
+        assert_eq!(graph.dependencies("Order"), vec!["OrderLine", "User"]);
+        assert_eq!(graph.fan_out("Order"), 2);
+        assert_eq!(graph.fan_in("User"), 2);
+        assert_eq!(graph.dependents("User"), vec!["Invoice", "Order"]);
+        assert!(graph.edges["Order"]
+            .iter()
+            .any(|e| e.target == "OrderLine" && e.via == "lines[]"));
+        assert!(graph.cycles.is_empty());
+    }
+
+    #[test]
+    fn test_composition_refs() {
+        let spec = spec_with(vec![
+            ("Base", object(vec![])),
+            (
+                "Dog",
+                Schema {
+                    all_of: Some(vec![reference("Base"), object(vec![])]),
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let graph = build_schema_graph(&spec);
+        assert_eq!(graph.edges["Dog"][0].via, "allOf[0]");
+        assert_eq!(graph.fan_in("Base"), 1);
+    }
+
+    #[test]
+    fn test_detects_cycles() {
+        let spec = spec_with(vec![
+            ("Node", object(vec![("parent", reference("Node"))])),
+            ("A", object(vec![("b", reference("B"))])),
+            ("B", object(vec![("a", reference("A"))])),
+            ("C", object(vec![("a", reference("A"))])),
+        ]);
+
+        let graph = build_schema_graph(&spec);
+        assert_eq!(
+            graph.cycles,
+            vec![
+                vec!["A".to_string(), "B".to_string()],
+                vec!["Node".to_string()]
+            ]
+        );
+        assert!(graph.is_in_cycle("Node"));
+        assert!(!graph.is_in_cycle("C"));
+    }
+
+    #[test]
+    fn test_transitive_dependents() {
+        let spec = spec_with(vec![
+            ("Address", object(vec![])),
+            ("User", object(vec![("address", reference("Address"))])),
+            ("Order", object(vec![("customer", reference("User"))])),
+            ("Product", object(vec![])),
+        ]);
+
+        let graph = build_schema_graph(&spec);
+        let dependents = graph.transitive_dependents("Address");
+        assert_eq!(dependents.len(), 3);
+        assert!(dependents.contains("Order"));
+        assert!(!dependents.contains("Product"));
+    }
+
+    #[test]
+    fn test_ignores_unknown_targets() {
+        let spec = spec_with(vec![("A", object(vec![("x", reference("Missing"))]))]);
+        let graph = build_schema_graph(&spec);
+        assert!(graph.edges["A"].is_empty());
+    }
+}
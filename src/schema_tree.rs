@@ -0,0 +1,481 @@
+//! A schema as a tree whose objects, arrays and composition branches are
+//! expanded on demand, flattened into the rows currently visible.
+
+use crate::parser::{extract_schema_name_from_ref, Schema};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Property,
+    /// `allOf`, `oneOf` or `anyOf` alternative, a discriminator mapping
+    /// target not listed among them, or `extends` for a component schema
+    /// referenced by `allOf`
+    Branch(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    /// Segments from the root joined by `/`, which identifies the node
+    /// across redraws (`lines/sku`, `oneOf[1]/name`)
+    pub path: String,
+    /// 0 for the schema's own properties and branches
+    pub depth: usize,
+    pub kind: NodeKind,
+    /// Property name, or the discriminator value or position of a branch
+    pub label: String,
+    /// Referenced schema name, else the type, with `[]` for arrays
+    pub type_label: String,
+    /// Component schema the node references
+    pub reference: Option<String>,
+    pub required: bool,
+    /// Under an `extends` branch: declared by a parent schema
+    pub inherited: bool,
+    pub expandable: bool,
+    pub expanded: bool,
+    /// `$ref` back to a schema being expanded above it, never expanded
+    pub recursive: bool,
+}
+
+/// Node under a schema, `target` naming the component schema it stands for
+/// when it is not a `$ref` itself (discriminator mapping targets).
+struct Child<'a> {
+    segment: String,
+    kind: NodeKind,
+    label: String,
+    node: &'a Schema,
+    target: Option<&'a str>,
+    required: bool,
+}
+
+/// Component schema referenced by a property or its array items.
+pub fn referenced_name(schema: &Schema) -> Option<&str> {
+    let target = schema.items.as_deref().unwrap_or(schema);
+    target
+        .reference
+        .as_deref()
+        .and_then(extract_schema_name_from_ref)
+}
+
+/// Type shown for a node: the referenced schema name, else its type, with
+/// `[]` for arrays.
+pub fn type_label(schema: &Schema) -> String {
+    let target = schema.items.as_deref().unwrap_or(schema);
+    let label = referenced_name(schema)
+        .map(str::to_string)
+        .or_else(|| target.schema_type.clone())
+        .unwrap_or_else(|| {
+            if target.properties.is_some() || target.all_of.is_some() {
+                "object".to_string()
+            } else {
+                "unknown".to_string()
+            }
+        });
+    if schema.items.is_some() {
+        format!("{}[]", label)
+    } else {
+        label
+    }
+}
+
+/// Rows of the schema `name`, descending into the nodes whose path is in
+/// `expanded`.
+pub fn tree_rows(
+    name: &str,
+    schema: &Schema,
+    schemas: &HashMap<String, Schema>,
+    expanded: &HashSet<String>,
+) -> Vec<TreeRow> {
+    let mut builder = RowBuilder {
+        schemas,
+        expanded,
+        ancestors: vec![name.to_string()],
+        rows: Vec::new(),
+    };
+    builder.push_rows(schema, None, "", 0, false);
+    builder.rows
+}
+
+/// Path of the row above `path` in the tree, `None` at the top level.
+pub fn parent_path(path: &str) -> Option<&str> {
+    path.rsplit_once('/').map(|(parent, _)| parent)
+}
+
+struct RowBuilder<'a> {
+    schemas: &'a HashMap<String, Schema>,
+    expanded: &'a HashSet<String>,
+    /// Component schemas being expanded, from the root down
+    ancestors: Vec<String>,
+    rows: Vec<TreeRow>,
+}
+
+impl RowBuilder<'_> {
+    fn push_rows(
+        &mut self,
+        schema: &Schema,
+        target: Option<&str>,
+        prefix: &str,
+        depth: usize,
+        inherited: bool,
+    ) {
+        for child in children(schema, target, self.schemas) {
+            let path = if prefix.is_empty() {
+                child.segment.clone()
+            } else {
+                format!("{}/{}", prefix, child.segment)
+            };
+            let name = child.target.or_else(|| referenced_name(child.node));
+            let recursive = child.node.items.as_deref().unwrap_or(child.node).recursive
+                || name.is_some_and(|name| self.ancestors.iter().any(|a| a == name));
+            let expandable =
+                !recursive && !children(child.node, child.target, self.schemas).is_empty();
+            let expanded = expandable && self.expanded.contains(&path);
+            self.rows.push(TreeRow {
+                path: path.clone(),
+                depth,
+                kind: child.kind,
+                label: child.label,
+                type_label: child
+                    .target
+                    .map(str::to_string)
+                    .unwrap_or_else(|| type_label(child.node)),
+                reference: name.map(str::to_string),
+                required: child.required,
+                inherited,
+                expandable,
+                expanded,
+                recursive,
+            });
+            if expanded {
+                if let Some(name) = name {
+                    self.ancestors.push(name.to_string());
+                }
+                let inherited = inherited || child.kind == NodeKind::Branch("extends");
+                self.push_rows(child.node, child.target, &path, depth + 1, inherited);
+                if name.is_some() {
+                    self.ancestors.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Properties of the object behind `schema` (itself, its array items or
+/// the component schema either references), sorted, then its composition
+/// branches.
+fn children<'a>(
+    schema: &'a Schema,
+    target: Option<&'a str>,
+    schemas: &'a HashMap<String, Schema>,
+) -> Vec<Child<'a>> {
+    let container = match target.or_else(|| referenced_name(schema)) {
+        Some(name) => match schemas.get(name) {
+            Some(container) => container,
+            None => return Vec::new(),
+        },
+        None => schema.items.as_deref().unwrap_or(schema),
+    };
+
+    let mut children = Vec::new();
+    if let Some(properties) = &container.properties {
+        let required = container.required.as_deref().unwrap_or_default();
+        let mut names: Vec<&String> = properties.keys().collect();
+        names.sort();
+        children.extend(names.into_iter().map(|name| Child {
+            segment: name.clone(),
+            kind: NodeKind::Property,
+            label: name.clone(),
+            node: &properties[name],
+            target: None,
+            required: required.contains(name),
+        }));
+    }
+
+    for (keyword, branches) in [
+        ("allOf", &container.all_of),
+        ("oneOf", &container.one_of),
+        ("anyOf", &container.any_of),
+    ] {
+        for (i, branch) in branches.iter().flatten().enumerate() {
+            let parent = referenced_name(branch).filter(|_| keyword == "allOf");
+            children.push(Child {
+                segment: format!("{}[{}]", keyword, i),
+                kind: NodeKind::Branch(if parent.is_some() { "extends" } else { keyword }),
+                label: parent.map_or_else(|| format!("#{}", i + 1), str::to_string),
+                node: branch,
+                target: None,
+                required: false,
+            });
+        }
+    }
+
+    // Discriminator values label the alternatives they select; mapping
+    // targets extending a base schema are branches of their own
+    if container.discriminator.is_some() {
+        let alternatives = container
+            .one_of
+            .as_ref()
+            .or(container.any_of.as_ref())
+            .map_or(0, Vec::len);
+        let first = children
+            .iter()
+            .position(|child| matches!(child.kind, NodeKind::Branch("oneOf" | "anyOf")));
+        for (i, variant) in container.variants().into_iter().enumerate() {
+            if i < alternatives {
+                if let Some(first) = first {
+                    children[first + i].label = variant.value;
+                }
+                continue;
+            }
+            let Some((name, node)) = variant
+                .schema
+                .as_deref()
+                .and_then(|name| schemas.get_key_value(name))
+            else {
+                continue;
+            };
+            children.push(Child {
+                segment: format!("mapping[{}]", variant.value),
+                kind: NodeKind::Branch("mapping"),
+                label: variant.value,
+                node,
+                target: Some(name.as_str()),
+                required: false,
+            });
+        }
+    }
+    children
+}
+
+/// Where a schema's fields come from.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Composition {
+    /// Component schemas referenced by `allOf`, in order
+    pub parents: Vec<String>,
+    /// Declared by the schema itself or an inline `allOf` member, sorted
+    pub own: Vec<String>,
+    /// Declared by the parents or their own parents and not redeclared,
+    /// sorted
+    pub inherited: Vec<String>,
+    /// `oneOf`/`anyOf` keyword and its alternatives: schema names, else
+    /// `#n`
+    pub alternatives: Vec<(&'static str, Vec<String>)>,
+}
+
+impl Composition {
+    pub fn is_composed(&self) -> bool {
+        !self.parents.is_empty() || !self.alternatives.is_empty()
+    }
+}
+
+pub fn composition(schema: &Schema, schemas: &HashMap<String, Schema>) -> Composition {
+    let mut own = BTreeSet::new();
+    let mut parents = Vec::new();
+    own.extend(schema.properties.iter().flat_map(|p| p.keys().cloned()));
+    for member in schema.all_of.iter().flatten() {
+        match referenced_name(member) {
+            Some(parent) => parents.push(parent.to_string()),
+            None => own.extend(member.get_field_names()),
+        }
+    }
+
+    let mut inherited = BTreeSet::new();
+    let mut seen = Vec::new();
+    for parent in &parents {
+        collect_inherited(parent, schemas, &mut seen, &mut inherited);
+    }
+
+    let alternatives = [("oneOf", &schema.one_of), ("anyOf", &schema.any_of)]
+        .into_iter()
+        .filter_map(|(keyword, branches)| {
+            let branches = branches.as_ref()?;
+            let labels = branches
+                .iter()
+                .enumerate()
+                .map(|(i, branch)| {
+                    referenced_name(branch).map_or_else(|| format!("#{}", i + 1), str::to_string)
+                })
+                .collect();
+            Some((keyword, labels))
+        })
+        .collect();
+
+    Composition {
+        parents,
+        inherited: inherited.difference(&own).cloned().collect(),
+        own: own.into_iter().collect(),
+        alternatives,
+    }
+}
+
+/// Fields of the schema `name` and of the schemas it extends, each schema
+/// visited once.
+fn collect_inherited(
+    name: &str,
+    schemas: &HashMap<String, Schema>,
+    seen: &mut Vec<String>,
+    fields: &mut BTreeSet<String>,
+) {
+    if seen.iter().any(|visited| visited == name) {
+        return;
+    }
+    seen.push(name.to_string());
+    let Some(schema) = schemas.get(name) else {
+        return;
+    };
+    fields.extend(schema.properties.iter().flat_map(|p| p.keys().cloned()));
+    for member in schema.all_of.iter().flatten() {
+        match referenced_name(member) {
+            Some(parent) => collect_inherited(parent, schemas, seen, fields),
+            None => fields.extend(member.get_field_names()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn schemas() -> HashMap<String, Schema> {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Order": {
+                        "type": "object",
+                        "required": ["id"],
+                        "properties": {
+                            "id": {"type": "integer"},
+                            "lines": {"type": "array", "items": {"type": "object", "properties": {
+                                "sku": {"type": "string"},
+                                "parent": {"$ref": "#/components/schemas/Order"}
+                            }}},
+                            "payment": {"$ref": "#/components/schemas/Payment"}
+                        }
+                    },
+                    "Payment": {
+                        "discriminator": {"propertyName": "kind", "mapping": {"card": "#/components/schemas/Card"}},
+                        "oneOf": [{"$ref": "#/components/schemas/Card"}, {"type": "object", "properties": {"iban": {"type": "string"}}}]
+                    },
+                    "Card": {"type": "object", "properties": {"kind": {"type": "string"}, "number": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        spec.components.unwrap().schemas.unwrap()
+    }
+
+    fn visible(rows: &[TreeRow]) -> Vec<(&str, usize, &str)> {
+        rows.iter()
+            .map(|row| (row.path.as_str(), row.depth, row.type_label.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_separates_own_and_inherited_fields() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Zoo", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Entity": {"type": "object", "properties": {"id": {"type": "string"}, "createdAt": {"type": "string"}}},
+                    "Pet": {"allOf": [
+                        {"$ref": "#/components/schemas/Entity"},
+                        {"type": "object", "properties": {"id": {"type": "integer"}, "name": {"type": "string"}}}
+                    ]},
+                    "Dog": {
+                        "allOf": [{"$ref": "#/components/schemas/Pet"}],
+                        "properties": {"bark": {"type": "boolean"}},
+                        "oneOf": [{"$ref": "#/components/schemas/Entity"}, {"type": "object"}]
+                    }
+                }}
+            }"##,
+        )
+        .unwrap();
+        let schemas = spec.components.unwrap().schemas.unwrap();
+
+        let pet = composition(&schemas["Pet"], &schemas);
+        assert_eq!(pet.parents, vec!["Entity"]);
+        assert_eq!(pet.own, vec!["id", "name"]);
+        assert_eq!(pet.inherited, vec!["createdAt"]);
+        assert!(pet.alternatives.is_empty());
+
+        let dog = composition(&schemas["Dog"], &schemas);
+        assert_eq!(dog.own, vec!["bark"]);
+        assert_eq!(dog.inherited, vec!["createdAt", "id", "name"]);
+        assert_eq!(
+            dog.alternatives,
+            vec![("oneOf", vec!["Entity".to_string(), "#2".to_string()])]
+        );
+        assert!(!composition(&schemas["Entity"], &schemas).is_composed());
+
+        let expanded = HashSet::from(["allOf[0]".to_string()]);
+        let rows = tree_rows("Pet", &schemas["Pet"], &schemas, &expanded);
+        assert_eq!(
+            rows.iter()
+                .map(|row| (row.path.as_str(), row.label.as_str(), row.inherited))
+                .collect::<Vec<_>>(),
+            vec![
+                ("allOf[0]", "Entity", false),
+                ("allOf[0]/createdAt", "createdAt", true),
+                ("allOf[0]/id", "id", true),
+                ("allOf[1]", "#2", false),
+            ]
+        );
+        assert_eq!(rows[0].kind, NodeKind::Branch("extends"));
+        assert_eq!(rows[0].reference.as_deref(), Some("Entity"));
+        assert_eq!(rows[3].kind, NodeKind::Branch("allOf"));
+    }
+
+    #[test]
+    fn test_expands_objects_arrays_and_branches() {
+        let schemas = schemas();
+        let order = &schemas["Order"];
+        let mut expanded = HashSet::new();
+
+        let rows = tree_rows("Order", order, &schemas, &expanded);
+        assert_eq!(
+            visible(&rows),
+            vec![
+                ("id", 0, "integer"),
+                ("lines", 0, "object[]"),
+                ("payment", 0, "Payment")
+            ]
+        );
+        assert!(rows[0].required && !rows[0].expandable);
+        assert!(rows[1].expandable && !rows[1].expanded);
+
+        expanded.insert("lines".to_string());
+        expanded.insert("payment".to_string());
+        expanded.insert("payment/oneOf[0]".to_string());
+        // Not visible while its parent is collapsed
+        expanded.insert("id/missing".to_string());
+        let rows = tree_rows("Order", order, &schemas, &expanded);
+        assert_eq!(
+            visible(&rows),
+            vec![
+                ("id", 0, "integer"),
+                ("lines", 0, "object[]"),
+                ("lines/parent", 1, "Order"),
+                ("lines/sku", 1, "string"),
+                ("payment", 0, "Payment"),
+                ("payment/oneOf[0]", 1, "Card"),
+                ("payment/oneOf[0]/kind", 2, "string"),
+                ("payment/oneOf[0]/number", 2, "string"),
+                ("payment/oneOf[1]", 1, "object"),
+            ]
+        );
+        assert!(rows[2].recursive && !rows[2].expandable);
+        assert_eq!(rows[5].kind, NodeKind::Branch("oneOf"));
+        assert_eq!(rows[5].label, "card");
+        assert_eq!(rows[8].label, "#2");
+        assert_eq!(
+            parent_path("payment/oneOf[0]/kind"),
+            Some("payment/oneOf[0]")
+        );
+        assert_eq!(parent_path("payment"), None);
+    }
+}
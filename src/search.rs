@@ -0,0 +1,326 @@
+use crate::indexer::FieldIndex;
+use crate::parser::OpenApiSpec;
+use crate::sorting::SortMode;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Quiet time after the last keystroke before a search runs
+pub const SEARCH_DEBOUNCE: Duration = Duration::from_millis(120);
+
+/// Candidates scored between two cancellation checks
+const CANCEL_CHECK_INTERVAL: usize = 256;
+
+/// A searchable name with its lowercase form computed once. The name is
+/// shared with the results, so filtering does not copy it.
+#[derive(Debug, Clone)]
+pub struct SearchKey {
+    pub name: Arc<str>,
+    pub lower: String,
+    /// Other lowercase names the key also matches (operationIds of a path)
+    pub aliases: Vec<String>,
+}
+
+impl SearchKey {
+    fn new(name: &str) -> Self {
+        Self {
+            name: Arc::from(name),
+            lower: name.to_lowercase(),
+            aliases: Vec::new(),
+        }
+    }
+
+    fn with_aliases<'a>(name: &str, aliases: impl IntoIterator<Item = &'a String>) -> Self {
+        Self {
+            aliases: aliases.into_iter().map(|a| a.to_lowercase()).collect(),
+            ..Self::new(name)
+        }
+    }
+}
+
+/// Everything the search bar filters, rebuilt when the spec changes.
+#[derive(Debug, Default)]
+pub struct SearchCandidates {
+    pub fields: Vec<SearchKey>,
+    pub schemas: Vec<SearchKey>,
+    pub endpoints: Vec<SearchKey>,
+    pub headers: Vec<SearchKey>,
+}
+
+impl SearchCandidates {
+    pub fn build(field_index: &FieldIndex, openapi_spec: &OpenApiSpec) -> Self {
+        Self {
+            fields: field_index
+                .fields
+                .keys()
+                .map(|k| SearchKey::new(k))
+                .collect(),
+            schemas: field_index
+                .schemas
+                .keys()
+                .map(|k| SearchKey::new(k))
+                .collect(),
+            endpoints: openapi_spec
+                .paths
+                .keys()
+                .cloned()
+                .chain(
+                    openapi_spec
+                        .event_targets()
+                        .into_iter()
+                        .map(|(target, _)| target),
+                )
+                .collect::<BTreeSet<String>>()
+                .iter()
+                .map(|target| {
+                    let operation_ids = openapi_spec
+                        .path_item(target)
+                        .into_iter()
+                        .flat_map(|item| item.operations.values())
+                        .filter_map(|operation| operation.operation_id.as_ref());
+                    SearchKey::with_aliases(target, operation_ids)
+                })
+                .collect(),
+            headers: field_index
+                .headers
+                .keys()
+                .map(|k| SearchKey::new(k))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchResults {
+    pub generation: u64,
+    pub fields: Vec<Arc<str>>,
+    pub schemas: Vec<Arc<str>>,
+    pub endpoints: Vec<Arc<str>>,
+    pub headers: Vec<Arc<str>>,
+}
+
+/// Filters every list with `query`. Returns `None` as soon as
+/// `is_cancelled` reports true.
+pub fn filter_candidates(
+    candidates: &SearchCandidates,
+    query: &str,
+    sort_mode: SortMode,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<SearchResults> {
+    Some(SearchResults {
+        generation: 0,
+        fields: filter_list(&candidates.fields, query, sort_mode, is_cancelled)?,
+        schemas: filter_list(&candidates.schemas, query, sort_mode, is_cancelled)?,
+        endpoints: filter_list(&candidates.endpoints, query, sort_mode, is_cancelled)?,
+        headers: filter_list(&candidates.headers, query, sort_mode, is_cancelled)?,
+    })
+}
+
+fn filter_list(
+    keys: &[SearchKey],
+    query: &str,
+    sort_mode: SortMode,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<Arc<str>>> {
+    if query.is_empty() {
+        // Fast path: no filtering needed
+        let mut names: Vec<Arc<str>> = keys.iter().map(|key| Arc::clone(&key.name)).collect();
+        sort_mode.sort(&mut names);
+        return Some(names);
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let query = query.to_lowercase();
+    let mut matches: Vec<(&Arc<str>, i64)> = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        if i % CANCEL_CHECK_INTERVAL == 0 && is_cancelled() {
+            return None;
+        }
+        // Best score of the name and its aliases
+        let score = std::iter::once(&key.lower)
+            .chain(&key.aliases)
+            .filter_map(|candidate| matcher.fuzzy_match(candidate, &query))
+            .max();
+        if let Some(score) = score {
+            matches.push((&key.name, score));
+        }
+    }
+
+    // Sort by score descending, ties in list order
+    matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| sort_mode.compare(a.0, b.0)));
+    Some(
+        matches
+            .into_iter()
+            .map(|(name, _)| Arc::clone(name))
+            .collect(),
+    )
+}
+
+/// Runs searches off the UI thread. Each keystroke bumps the generation,
+/// which cancels the search in flight; only results of the latest
+/// generation are delivered.
+#[derive(Debug)]
+pub struct SearchEngine {
+    pub candidates: Arc<SearchCandidates>,
+    generation: Arc<AtomicU64>,
+    deadline: Option<Instant>,
+    sender: mpsc::UnboundedSender<SearchResults>,
+    receiver: mpsc::UnboundedReceiver<SearchResults>,
+}
+
+impl Default for SearchEngine {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            candidates: Arc::default(),
+            generation: Arc::default(),
+            deadline: None,
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl SearchEngine {
+    pub fn rebuild(&mut self, field_index: &FieldIndex, openapi_spec: &OpenApiSpec) {
+        self.candidates = Arc::new(SearchCandidates::build(field_index, openapi_spec));
+        self.cancel();
+    }
+
+    /// Cancels any pending or running search.
+    pub fn cancel(&mut self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.deadline = None;
+    }
+
+    /// Restarts the debounce timer after the query changed.
+    pub fn schedule(&mut self) {
+        self.cancel();
+        self.deadline = Some(Instant::now() + SEARCH_DEBOUNCE);
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Starts the scheduled search once the debounce delay has elapsed.
+    pub fn start_if_due(&mut self, query: &str, sort_mode: SortMode) {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {}
+            _ => return,
+        }
+        self.deadline = None;
+
+        let candidates = Arc::clone(&self.candidates);
+        let current = Arc::clone(&self.generation);
+        let generation = current.load(Ordering::SeqCst);
+        let sender = self.sender.clone();
+        let query = query.to_string();
+        tokio::task::spawn_blocking(move || {
+            let is_cancelled = || current.load(Ordering::SeqCst) != generation;
+            if let Some(mut results) =
+                filter_candidates(&candidates, &query, sort_mode, &is_cancelled)
+            {
+                results.generation = generation;
+                let _ = sender.send(results);
+            }
+        });
+    }
+
+    /// Latest results, dropping any that a newer query has superseded.
+    pub fn try_recv(&mut self) -> Option<SearchResults> {
+        let mut latest = None;
+        while let Ok(results) = self.receiver.try_recv() {
+            if results.generation == self.generation.load(Ordering::SeqCst) {
+                latest = Some(results);
+            }
+        }
+        latest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[Arc<str>]) -> Vec<&str> {
+        list.iter().map(|name| &**name).collect()
+    }
+
+    fn candidates() -> SearchCandidates {
+        SearchCandidates {
+            fields: ["USER_ID", "user_name", "order_id", "item2", "item10"]
+                .iter()
+                .map(|name| SearchKey::new(name))
+                .collect(),
+            ..SearchCandidates::default()
+        }
+    }
+
+    #[test]
+    fn test_filter_is_case_insensitive_and_sorted() {
+        let results =
+            filter_candidates(&candidates(), "usid", SortMode::Natural, &|| false).unwrap();
+        assert_eq!(names(&results.fields), vec!["USER_ID"]);
+
+        let results = filter_candidates(&candidates(), "", SortMode::Natural, &|| false).unwrap();
+        assert_eq!(
+            names(&results.fields),
+            vec!["item2", "item10", "order_id", "USER_ID", "user_name"]
+        );
+    }
+
+    #[test]
+    fn test_endpoints_match_operation_ids() {
+        let operation_ids = ["listInvoices".to_string(), "createInvoice".to_string()];
+        let candidates = SearchCandidates {
+            endpoints: vec![
+                SearchKey::with_aliases("/invoices", &operation_ids),
+                SearchKey::new("/users"),
+            ],
+            ..SearchCandidates::default()
+        };
+        let results =
+            filter_candidates(&candidates, "createinv", SortMode::Natural, &|| false).unwrap();
+        assert_eq!(names(&results.endpoints), vec!["/invoices"]);
+    }
+
+    #[test]
+    fn test_cancelled_search_returns_nothing() {
+        assert!(filter_candidates(&candidates(), "id", SortMode::Natural, &|| true).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engine_delivers_latest_generation_only() {
+        let mut engine = SearchEngine {
+            candidates: Arc::new(candidates()),
+            ..SearchEngine::default()
+        };
+
+        engine.schedule();
+        engine.deadline = Some(Instant::now());
+        engine.start_if_due("order", SortMode::Natural);
+        // A newer keystroke supersedes the search already started
+        engine.schedule();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(engine.try_recv().is_none());
+        assert!(engine.is_pending());
+
+        engine.deadline = Some(Instant::now());
+        engine.start_if_due("item", SortMode::Natural);
+        let mut results = None;
+        for _ in 0..50 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            results = engine.try_recv();
+            if results.is_some() {
+                break;
+            }
+        }
+        assert_eq!(names(&results.unwrap().fields), vec!["item2", "item10"]);
+    }
+}
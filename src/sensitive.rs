@@ -0,0 +1,225 @@
+use crate::indexer::{extract_fields_from_schema, FieldIndex};
+use crate::parser::{OpenApiSpec, Schema};
+use crate::schema_graph::referenced_schemas;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Built-in (category, pattern) pairs matched against field names.
+pub const DEFAULT_SENSITIVE_PATTERNS: &[(&str, &str)] = &[
+    ("password", r"(?i)pass(word|wd|phrase)|^pwd$"),
+    ("secret", r"(?i)secret|api_?key|private_?key"),
+    ("token", r"(?i)token|^session_?id$"),
+    ("ssn", r"(?i)^ssn$|social_?security|national_?id|tax_?id"),
+    ("email", r"(?i)e_?mail"),
+    ("dob", r"(?i)^dob$|birth_?date|date_?of_?birth|birthday"),
+    (
+        "card",
+        r"(?i)card_?number|credit_?card|^pan$|^cvv$|^cvc$|^iban$",
+    ),
+    ("phone", r"(?i)phone|mobile_?number"),
+];
+
+#[derive(Debug, Clone)]
+pub struct SensitiveRule {
+    pub label: String,
+    pub pattern: Regex,
+}
+
+#[derive(Debug, Clone)]
+pub struct SensitiveDetector {
+    pub rules: Vec<SensitiveRule>,
+}
+
+impl Default for SensitiveDetector {
+    fn default() -> Self {
+        Self {
+            rules: DEFAULT_SENSITIVE_PATTERNS
+                .iter()
+                .map(|(label, pattern)| SensitiveRule {
+                    label: label.to_string(),
+                    pattern: Regex::new(pattern).expect("built-in pattern is valid"),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SensitiveDetector {
+    /// Built-in rules plus user patterns, written either `label=regex` or
+    /// just `regex` (labelled `custom`).
+    pub fn with_extra_patterns(patterns: &[String]) -> Result<Self> {
+        let mut detector = Self::default();
+        for raw in patterns {
+            let (label, pattern) = match raw.split_once('=') {
+                Some((label, pattern)) if !label.is_empty() => (label, pattern),
+                _ => ("custom", raw.as_str()),
+            };
+            detector.rules.push(SensitiveRule {
+                label: label.to_string(),
+                pattern: Regex::new(pattern)
+                    .with_context(|| format!("Invalid sensitive field pattern '{}'", raw))?,
+            });
+        }
+        Ok(detector)
+    }
+
+    /// Category of the first rule matching `field_name`, if any.
+    pub fn classify(&self, field_name: &str) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|rule| rule.pattern.is_match(field_name))
+            .map(|rule| rule.label.as_str())
+    }
+
+    pub fn is_sensitive(&self, field_name: &str) -> bool {
+        self.classify(field_name).is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SensitiveField {
+    pub name: String,
+    pub label: String,
+}
+
+/// Sensitive fields returned by one response of an endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct SensitiveExposure {
+    pub endpoint: String,
+    pub status: String,
+    pub fields: Vec<SensitiveField>,
+}
+
+/// Lists every endpoint response whose body (including referenced component
+/// schemas) contains a sensitive field, sorted by endpoint then status.
+pub fn find_response_exposures(
+    openapi_spec: &OpenApiSpec,
+    index: &FieldIndex,
+    detector: &SensitiveDetector,
+) -> Vec<SensitiveExposure> {
+    let mut exposures = Vec::new();
+
+    for (path, path_item) in &openapi_spec.paths {
+        for (method, operation) in &path_item.operations {
+            for (status, response) in &operation.responses {
+                let Some(content) = &response.content else {
+                    continue;
+                };
+
+                let mut fields = BTreeMap::new();
+                for schema in content.values().filter_map(|media| media.schema.as_ref()) {
+                    for field in response_fields(schema, index) {
+                        if let Some(label) = detector.classify(&field) {
+                            fields.insert(field, label.to_string());
+                        }
+                    }
+                }
+
+                if !fields.is_empty() {
+                    exposures.push(SensitiveExposure {
+                        endpoint: format!("{} {}", method.to_uppercase(), path),
+                        status: status.clone(),
+                        fields: fields
+                            .into_iter()
+                            .map(|(name, label)| SensitiveField { name, label })
+                            .collect(),
+                    });
+                }
+            }
+        }
+    }
+
+    exposures.sort_by(|a, b| a.endpoint.cmp(&b.endpoint).then(a.status.cmp(&b.status)));
+    exposures
+}
+
+/// Field names reachable from `schema`, following component references.
+fn response_fields(schema: &Schema, index: &FieldIndex) -> Vec<String> {
+    let mut fields = extract_fields_from_schema(schema);
+    for referenced in referenced_schemas(schema) {
+        for name in index.schema_graph.transitive_dependencies(&referenced) {
+            if let Some(component) = index.schemas.get(&name) {
+                fields.extend(extract_fields_from_schema(component));
+            }
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_default_rules() {
+        let detector = SensitiveDetector::default();
+        assert_eq!(detector.classify("password"), Some("password"));
+        assert_eq!(detector.classify("accessToken"), Some("token"));
+        assert_eq!(detector.classify("user_email"), Some("email"));
+        assert_eq!(detector.classify("dateOfBirth"), Some("dob"));
+        assert_eq!(detector.classify("cardNumber"), Some("card"));
+        assert_eq!(detector.classify("ssn"), Some("ssn"));
+        assert!(!detector.is_sensitive("name"));
+        assert!(!detector.is_sensitive("passenger_count"));
+    }
+
+    #[test]
+    fn test_extra_patterns() {
+        let detector = SensitiveDetector::with_extra_patterns(&[
+            "health=(?i)diagnosis".to_string(),
+            "^iban_.*".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(detector.classify("diagnosisCode"), Some("health"));
+        assert_eq!(detector.classify("iban_number"), Some("custom"));
+
+        assert!(SensitiveDetector::with_extra_patterns(&["bad=(".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_response_exposures_follow_references() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"responses": {"200": {"description": "ok", "content": {
+                            "application/json": {"schema": {"type": "array", "items": {"$ref": "#/components/schemas/User"}}}
+                        }}}},
+                        "post": {
+                            "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Credentials"}}}},
+                            "responses": {"201": {"description": "created"}}
+                        }
+                    }
+                },
+                "components": {"schemas": {
+                    "User": {"type": "object", "properties": {
+                        "name": {"type": "string"},
+                        "contact": {"$ref": "#/components/schemas/Contact"}
+                    }},
+                    "Contact": {"type": "object", "properties": {"email": {"type": "string"}}},
+                    "Credentials": {"type": "object", "properties": {"password": {"type": "string"}}}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let exposures = find_response_exposures(&spec, &index, &SensitiveDetector::default());
+        assert_eq!(exposures.len(), 1);
+        assert_eq!(exposures[0].endpoint, "GET /users");
+        assert_eq!(exposures[0].status, "200");
+        assert_eq!(
+            exposures[0].fields,
+            vec![SensitiveField {
+                name: "email".to_string(),
+                label: "email".to_string()
+            }]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::parser::{OpenApiSpec, Server};
+use crate::runner::DEFAULT_BASE_URL;
+use std::collections::BTreeMap;
+
+/// Default value of every variable of `server`, sorted by name.
+pub fn default_variables(server: &Server) -> BTreeMap<String, String> {
+    server
+        .variables
+        .iter()
+        .flatten()
+        .map(|(name, variable)| (name.clone(), variable.default.clone()))
+        .collect()
+}
+
+/// Server URL with `{variable}` placeholders substituted. Relative URLs
+/// (`/v1`) are resolved against the default base URL since a local file has
+/// no origin.
+pub fn resolve_server_url(server: &Server, values: &BTreeMap<String, String>) -> String {
+    let mut resolved = default_variables(server);
+    resolved.extend(values.clone());
+
+    let mut url = server.url.clone();
+    for (name, value) in &resolved {
+        url = url.replace(&format!("{{{}}}", name), value);
+    }
+
+    if url.starts_with('/') {
+        format!("{}{}", DEFAULT_BASE_URL, url.trim_end_matches('/'))
+    } else {
+        url.trim_end_matches('/').to_string()
+    }
+}
+
+/// Base URL of the first declared server with default variables, if any.
+pub fn default_base_url(openapi_spec: &OpenApiSpec) -> Option<String> {
+    let server = openapi_spec.servers.as_ref()?.first()?;
+    Some(resolve_server_url(server, &default_variables(server)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+
+    fn create_test_spec() -> OpenApiSpec {
+        parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "servers": [
+                    {
+                        "url": "https://{region}.api.example.com:{port}/v1/",
+                        "variables": {
+                            "region": {"default": "eu", "enum": ["eu", "us"]},
+                            "port": {"default": "443"}
+                        }
+                    },
+                    {"url": "/api", "description": "Same origin"}
+                ],
+                "paths": {}
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_server_variables() {
+        let spec = create_test_spec();
+        let servers = spec.servers.as_ref().unwrap();
+
+        assert_eq!(
+            default_base_url(&spec).as_deref(),
+            Some("https://eu.api.example.com:443/v1")
+        );
+
+        let mut values = default_variables(&servers[0]);
+        values.insert("region".to_string(), "us".to_string());
+        assert_eq!(
+            resolve_server_url(&servers[0], &values),
+            "https://us.api.example.com:443/v1"
+        );
+    }
+
+    #[test]
+    fn test_relative_server_url() {
+        let spec = create_test_spec();
+        let server = &spec.servers.as_ref().unwrap()[1];
+        assert_eq!(
+            resolve_server_url(server, &BTreeMap::new()),
+            format!("{}/api", DEFAULT_BASE_URL)
+        );
+    }
+}
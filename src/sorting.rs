@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Ordering used for field, schema and endpoint lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SortMode {
+    /// Numbers compared by value (`item2` before `item10`), accents and case
+    /// folded so `état` sorts next to `etat`
+    #[default]
+    Natural,
+    /// Plain byte-wise ordering
+    Lexical,
+}
+
+impl SortMode {
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            SortMode::Natural => natural_cmp(a, b),
+            SortMode::Lexical => a.cmp(b),
+        }
+    }
+
+    pub fn sort<T: AsRef<str>>(self, items: &mut [T]) {
+        items.sort_by(|a, b| self.compare(a.as_ref(), b.as_ref()));
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            SortMode::Natural => SortMode::Lexical,
+            SortMode::Lexical => SortMode::Natural,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Natural => "natural",
+            SortMode::Lexical => "lexical",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Chunk {
+    // Digits sort before letters, as in plain ASCII ordering. Numbers are
+    // compared by digit count, then digit by digit.
+    Number(usize, String),
+    Text(String),
+}
+
+/// Compares strings chunk by chunk: digit runs by numeric value, text runs by
+/// their case- and accent-folded form. Falls back to byte order so distinct
+/// strings never compare equal.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    chunks(a).cmp(&chunks(b)).then_with(|| a.cmp(b))
+}
+
+fn chunks(s: &str) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut in_number = false;
+
+    for ch in s.chars() {
+        let is_digit = ch.is_ascii_digit();
+        if !current.is_empty() && is_digit != in_number {
+            chunks.push(make_chunk(std::mem::take(&mut current), in_number));
+        }
+        in_number = is_digit;
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        chunks.push(make_chunk(current, in_number));
+    }
+    chunks
+}
+
+fn make_chunk(raw: String, is_number: bool) -> Chunk {
+    if is_number {
+        let digits = raw.trim_start_matches('0').to_string();
+        Chunk::Number(digits.len(), digits)
+    } else {
+        Chunk::Text(fold(&raw))
+    }
+}
+
+/// Lowercases and strips diacritics (`É` → `e`).
+fn fold(s: &str) -> String {
+    s.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mode: SortMode, items: &[&str]) -> Vec<String> {
+        let mut items: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+        mode.sort(&mut items);
+        items
+    }
+
+    #[test]
+    fn test_natural_numbers() {
+        assert_eq!(
+            sorted(SortMode::Natural, &["/v1/item10", "/v1/item2", "/v1/item1"]),
+            vec!["/v1/item1", "/v1/item2", "/v1/item10"]
+        );
+        assert_eq!(
+            sorted(SortMode::Lexical, &["item10", "item2"]),
+            vec!["item10", "item2"]
+        );
+    }
+
+    #[test]
+    fn test_natural_folds_case_and_accents() {
+        assert_eq!(
+            sorted(SortMode::Natural, &["zone", "État", "etat", "Apple"]),
+            vec!["Apple", "etat", "État", "zone"]
+        );
+    }
+
+    #[test]
+    fn test_natural_is_total() {
+        assert_eq!(natural_cmp("a01", "a1"), Ordering::Less);
+        assert_eq!(natural_cmp("same", "same"), Ordering::Equal);
+        assert_eq!(natural_cmp("v2", "version"), Ordering::Less);
+        assert_eq!(
+            natural_cmp("id99999999999999999999", "id100000000000000000000"),
+            Ordering::Less
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Directories of specs: every spec file of a directory is loaded, and the
+//! directory watched so added, edited and deleted files are picked up.
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Quiet time after the last change before files are reloaded, so an
+/// editor's burst of writes costs one reload
+pub const REFRESH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+const SPEC_EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+const COMPRESSED_EXTENSIONS: &[&str] = &["gz", "zst"];
+
+/// Spec files by extension (`.json`, `.yaml`, `.yml`, optionally `.gz` or
+/// `.zst` compressed), skipping hidden files such as editor swap files.
+pub fn is_spec_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if name.starts_with('.') {
+        return false;
+    }
+    let mut parts = name.rsplit('.');
+    let mut extension = parts.next().unwrap_or_default();
+    if COMPRESSED_EXTENSIONS.contains(&extension) {
+        extension = parts.next().unwrap_or_default();
+    }
+    // `rsplit` yields the whole name when there is no dot
+    extension != name && SPEC_EXTENSIONS.contains(&extension)
+}
+
+/// The spec files directly in `dir`, sorted, as absolute paths (the watcher
+/// reports changes with absolute paths).
+pub fn spec_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let dir = dir
+        .canonicalize()
+        .with_context(|| format!("Cannot open {}", dir.display()))?;
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .with_context(|| format!("Cannot list {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && is_spec_file(path))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Watches `dirs` (not their subdirectories), calling `on_change` with the
+/// spec files created, modified, renamed or removed. Changes stop being
+/// reported once the watcher is dropped.
+pub fn watch(
+    dirs: &[PathBuf],
+    on_change: impl Fn(Vec<PathBuf>) + Send + 'static,
+) -> notify::Result<RecommendedWatcher> {
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return;
+        }
+        let paths: Vec<PathBuf> = event
+            .paths
+            .into_iter()
+            .filter(|path| is_spec_file(path))
+            .collect();
+        if !paths.is_empty() {
+            on_change(paths);
+        }
+    })?;
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+    Ok(watcher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_files_of_a_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "users.json",
+            "orders.yaml",
+            "billing.json.gz",
+            "README.md",
+            ".users.json.swp",
+            ".hidden.json",
+            "json",
+        ] {
+            std::fs::write(dir.path().join(name), "{}").unwrap();
+        }
+        std::fs::create_dir(dir.path().join("nested.json")).unwrap();
+
+        let names: Vec<String> = spec_files(dir.path())
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["billing.json.gz", "orders.yaml", "users.json"]);
+    }
+}
@@ -0,0 +1,1002 @@
+use crate::indexer::{FieldIndex, HttpMethod};
+use crate::parser::{OpenApiSpec, Operation, Schema};
+use crate::report::{compute_stats, Stats};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+const STATS_SNAPSHOT_PREFIX: &str = "stats-";
+/// Number of most used fields kept in exports and snapshots
+const TOP_FIELDS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldUsage {
+    pub name: String,
+    pub endpoints: usize,
+}
+
+/// The Stats dashboard at a point in time, as exported or recorded in the
+/// snapshot directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsSnapshot {
+    /// RFC 3339 timestamp
+    pub recorded_at: String,
+    pub title: String,
+    pub version: String,
+    pub stats: Stats,
+    pub top_fields: Vec<FieldUsage>,
+}
+
+/// Change of the headline counts since the previous snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsTrend {
+    /// `recorded_at` of the snapshot compared against
+    pub since: String,
+    pub fields: i64,
+    pub schemas: i64,
+    pub endpoints: i64,
+    pub operations: i64,
+}
+
+#[derive(Serialize)]
+struct StatsExport<'a> {
+    #[serde(flatten)]
+    snapshot: &'a StatsSnapshot,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trend: Option<&'a StatsTrend>,
+}
+
+impl StatsSnapshot {
+    pub fn capture(spec: &OpenApiSpec, index: &FieldIndex, timestamp: DateTime<Utc>) -> Self {
+        let mut top_fields: Vec<FieldUsage> = index
+            .fields
+            .iter()
+            .filter(|(_, data)| !data.endpoints.is_empty())
+            .map(|(name, data)| FieldUsage {
+                name: name.clone(),
+                endpoints: data.endpoints.len(),
+            })
+            .collect();
+        top_fields.sort_by(|a, b| b.endpoints.cmp(&a.endpoints).then(a.name.cmp(&b.name)));
+        top_fields.truncate(TOP_FIELDS);
+
+        Self {
+            recorded_at: timestamp.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            title: spec.info.title.clone(),
+            version: spec.info.version.clone(),
+            stats: compute_stats(spec, index),
+            top_fields,
+        }
+    }
+
+    pub fn trend_since(&self, previous: &StatsSnapshot) -> StatsTrend {
+        let delta = |new: usize, old: usize| new as i64 - old as i64;
+        StatsTrend {
+            since: previous.recorded_at.clone(),
+            fields: delta(self.stats.fields, previous.stats.fields),
+            schemas: delta(self.stats.schemas, previous.stats.schemas),
+            endpoints: delta(self.stats.paths, previous.stats.paths),
+            operations: delta(self.stats.operations, previous.stats.operations),
+        }
+    }
+
+    pub fn to_markdown(&self, trend: Option<&StatsTrend>) -> String {
+        let stats = &self.stats;
+        let mut out = format!(
+            "# {} {} statistics\n\n_Recorded {}_\n\n## Overview\n\n",
+            self.title, self.version, self.recorded_at
+        );
+        out.push_str("| Metric | Count |\n|---|---|\n");
+        for (metric, count) in [
+            ("Schemas", stats.schemas),
+            ("Fields", stats.fields),
+            ("Endpoints", stats.paths),
+            ("Operations", stats.operations),
+            ("Write operations", stats.write_operations),
+            ("Critical fields", stats.critical_fields),
+        ] {
+            out.push_str(&format!("| {} | {} |\n", metric, count));
+        }
+        if let Some(trend) = trend {
+            out.push_str(&format!("\n## Trend\n\n{}\n", trend.summary()));
+        }
+        for (heading, counts) in [
+            ("Field types", &stats.field_types),
+            ("HTTP methods", &stats.methods),
+        ] {
+            if counts.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n## {}\n\n", heading));
+            let mut counts: Vec<_> = counts.iter().collect();
+            counts.sort_by(|a, b| b.1.cmp(a.1));
+            for (name, count) in counts {
+                out.push_str(&format!("- {}: {}\n", name, count));
+            }
+        }
+        if !self.top_fields.is_empty() {
+            out.push_str("\n## Top fields (by endpoint usage)\n\n");
+            for field in &self.top_fields {
+                out.push_str(&format!(
+                    "- {}: {} endpoint(s)\n",
+                    field.name, field.endpoints
+                ));
+            }
+        }
+        out
+    }
+}
+
+impl StatsTrend {
+    pub fn is_unchanged(&self) -> bool {
+        self.fields == 0 && self.schemas == 0 && self.endpoints == 0 && self.operations == 0
+    }
+
+    /// `+12 fields, +3 endpoints since last snapshot`, listing the counts
+    /// that changed.
+    pub fn summary(&self) -> String {
+        if self.is_unchanged() {
+            return "No change since last snapshot".to_string();
+        }
+        let changes: Vec<String> = [
+            (self.fields, "fields"),
+            (self.schemas, "schemas"),
+            (self.endpoints, "endpoints"),
+            (self.operations, "operations"),
+        ]
+        .into_iter()
+        .filter(|(delta, _)| *delta != 0)
+        .map(|(delta, label)| format!("{:+} {}", delta, label))
+        .collect();
+        format!("{} since last snapshot", changes.join(", "))
+    }
+}
+
+/// Writes the snapshot as Markdown for `.md` files, JSON otherwise.
+pub fn write_stats(
+    path: &Path,
+    snapshot: &StatsSnapshot,
+    trend: Option<&StatsTrend>,
+) -> Result<()> {
+    let is_markdown = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "md" | "markdown"));
+    let content = if is_markdown {
+        snapshot.to_markdown(trend)
+    } else {
+        serde_json::to_string_pretty(&StatsExport { snapshot, trend })?
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Stores `snapshot` as a dated file in `dir` and returns the trend against
+/// the most recent earlier snapshot, if any.
+pub fn record_stats_snapshot(
+    dir: &Path,
+    snapshot: &StatsSnapshot,
+    timestamp: DateTime<Utc>,
+) -> Result<(PathBuf, Option<StatsTrend>)> {
+    std::fs::create_dir_all(dir)?;
+    let trend = latest_stats_snapshot(dir)?.map(|previous| snapshot.trend_since(&previous));
+
+    let stamp = timestamp.format(crate::monitor::TIMESTAMP_FORMAT);
+    let path = dir.join(format!("{}{}.json", STATS_SNAPSHOT_PREFIX, stamp));
+    std::fs::write(&path, serde_json::to_string_pretty(snapshot)?)?;
+    Ok((path, trend))
+}
+
+/// Most recent readable stats snapshot in `dir`; timestamps sort
+/// lexicographically.
+pub fn latest_stats_snapshot(dir: &Path) -> Result<Option<StatsSnapshot>> {
+    if !dir.exists() {
+        return Ok(None);
+    }
+
+    let mut snapshots: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(STATS_SNAPSHOT_PREFIX) && n.ends_with(".json"))
+        })
+        .collect();
+    snapshots.sort();
+    while let Some(path) = snapshots.pop() {
+        match serde_json::from_str(&std::fs::read_to_string(&path)?) {
+            Ok(snapshot) => return Ok(Some(snapshot)),
+            Err(e) => log::warn!(
+                "Skipping unreadable stats snapshot {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+    Ok(None)
+}
+
+/// Chart sections of the Stats view, in display order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatsSection {
+    #[default]
+    FieldTypes,
+    Methods,
+    SchemaSizes,
+    Documentation,
+    FieldUsage,
+    Tags,
+}
+
+impl StatsSection {
+    pub const ALL: [StatsSection; 6] = [
+        StatsSection::FieldTypes,
+        StatsSection::Methods,
+        StatsSection::SchemaSizes,
+        StatsSection::Documentation,
+        StatsSection::FieldUsage,
+        StatsSection::Tags,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            StatsSection::FieldTypes => "Field Types",
+            StatsSection::Methods => "HTTP Methods",
+            StatsSection::SchemaSizes => "Schema Sizes (fields)",
+            StatsSection::Documentation => "Documentation",
+            StatsSection::FieldUsage => "Field Usage",
+            StatsSection::Tags => "Tags",
+        }
+    }
+
+    /// The next section, wrapping around; `previous` goes the other way.
+    pub fn next(&self) -> Self {
+        let position = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        Self::ALL[(position + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let position = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        Self::ALL[(position + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// A bar of a chart and the items it counts, sorted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    pub label: String,
+    pub items: Vec<String>,
+}
+
+/// Largest buckets first, then by label.
+fn into_buckets(groups: BTreeMap<String, Vec<String>>) -> Vec<Bucket> {
+    let mut buckets: Vec<Bucket> = groups
+        .into_iter()
+        .map(|(label, mut items)| {
+            items.sort_unstable();
+            Bucket { label, items }
+        })
+        .collect();
+    buckets.sort_by_key(|bucket| std::cmp::Reverse(bucket.items.len()));
+    buckets
+}
+
+/// Fields by type.
+pub fn field_type_buckets(index: &FieldIndex) -> Vec<Bucket> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, data) in &index.fields {
+        groups
+            .entry(data.field_type.clone())
+            .or_default()
+            .push(name.clone());
+    }
+    into_buckets(groups)
+}
+
+/// Path operations by HTTP method, as `"METHOD /path"` keys.
+pub fn method_buckets(spec: &OpenApiSpec) -> Vec<Bucket> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (path, path_item) in &spec.paths {
+        for method in path_item.operations.keys() {
+            let method = method.to_uppercase();
+            let endpoint = format!("{} {}", method, path);
+            groups.entry(method).or_default().push(endpoint);
+        }
+    }
+    into_buckets(groups)
+}
+
+/// Fields, most used first, each with the endpoints using it.
+pub fn field_usage_buckets(index: &FieldIndex) -> Vec<Bucket> {
+    let groups = index
+        .fields
+        .iter()
+        .map(|(name, data)| {
+            (
+                name.clone(),
+                data.endpoints.iter().map(|e| e.to_string()).collect(),
+            )
+        })
+        .collect();
+    into_buckets(groups)
+}
+
+/// Fields per schema from which a schema is worth splitting
+pub const LARGE_SCHEMA_FIELDS: usize = 30;
+/// Object nesting from which a schema is worth flattening
+pub const DEEP_SCHEMA_NESTING: usize = 4;
+
+/// Bins of the schema size histogram: label and largest field count
+const SCHEMA_SIZE_BINS: &[(&str, usize)] = &[
+    ("0-5", 5),
+    ("6-10", 10),
+    ("11-20", 20),
+    ("21-50", 50),
+    ("51+", usize::MAX),
+];
+
+/// Top-level fields and object nesting of a component schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaSize {
+    pub name: String,
+    pub fields: usize,
+    /// Levels of nested objects, 1 for an object of scalars
+    pub depth: usize,
+}
+
+impl SchemaSize {
+    pub fn is_large(&self) -> bool {
+        self.fields >= LARGE_SCHEMA_FIELDS
+    }
+
+    pub fn is_deep(&self) -> bool {
+        self.depth >= DEEP_SCHEMA_NESTING
+    }
+}
+
+/// Every component schema, largest first, then by name.
+pub fn schema_sizes(index: &FieldIndex) -> Vec<SchemaSize> {
+    let mut sizes: Vec<SchemaSize> = index
+        .schemas
+        .iter()
+        .map(|(name, schema)| {
+            let fields: BTreeSet<String> = schema.get_field_names().into_iter().collect();
+            SchemaSize {
+                name: name.clone(),
+                fields: fields.len(),
+                depth: nesting_depth(schema, &index.schemas, &mut vec![name.as_str()]),
+            }
+        })
+        .collect();
+    sizes.sort_by(|a, b| b.fields.cmp(&a.fields).then(a.name.cmp(&b.name)));
+    sizes
+}
+
+/// Levels of objects with properties: array items and composition parts
+/// are on the level of their schema. `$ref`s to `schemas` are followed,
+/// except back to one in `expanding`, which ends the level like a
+/// recursive reference.
+pub fn nesting_depth<'a>(
+    schema: &'a Schema,
+    schemas: &'a HashMap<String, Schema>,
+    expanding: &mut Vec<&'a str>,
+) -> usize {
+    if schema.recursive {
+        return 0;
+    }
+    if let Some(name) = schema
+        .reference
+        .as_deref()
+        .and_then(|reference| reference.strip_prefix("#/components/schemas/"))
+    {
+        let Some(target) = schemas.get(name).filter(|_| !expanding.contains(&name)) else {
+            return 0;
+        };
+        expanding.push(name);
+        let depth = nesting_depth(target, schemas, expanding);
+        expanding.pop();
+        return depth;
+    }
+    let children = schema
+        .properties
+        .iter()
+        .flat_map(|properties| properties.values())
+        .chain(schema.additional_properties.as_deref())
+        .map(|child| nesting_depth(child, schemas, expanding))
+        .max();
+    let own = children.map_or(0, |depth| depth + 1);
+    let parts = [&schema.all_of, &schema.one_of, &schema.any_of];
+    schema
+        .items
+        .as_deref()
+        .into_iter()
+        .chain(parts.into_iter().flatten().flatten())
+        .map(|part| nesting_depth(part, schemas, expanding))
+        .fold(own, usize::max)
+}
+
+/// Schemas by field count, as a histogram: every bin, smallest first.
+pub fn schema_size_buckets(index: &FieldIndex) -> Vec<Bucket> {
+    let sizes = schema_sizes(index);
+    let mut lower = 0;
+    SCHEMA_SIZE_BINS
+        .iter()
+        .map(|(label, upper)| {
+            let mut items: Vec<String> = sizes
+                .iter()
+                .filter(|size| (lower..=*upper).contains(&size.fields))
+                .map(|size| size.name.clone())
+                .collect();
+            items.sort_unstable();
+            lower = upper.saturating_add(1);
+            Bucket {
+                label: label.to_string(),
+                items,
+            }
+        })
+        .collect()
+}
+
+/// Tag of the operations declaring none, in the per-tag breakdown
+pub const UNTAGGED: &str = "(untagged)";
+
+/// Whether `operation` is listed under `tag` ([`UNTAGGED`] for operations
+/// without tags).
+pub fn operation_has_tag(operation: &Operation, tag: &str) -> bool {
+    match operation.tags.as_deref() {
+        Some(tags) if !tags.is_empty() => tags.iter().any(|t| t == tag),
+        _ => tag == UNTAGGED,
+    }
+}
+
+/// Path operations listed under a tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagStats {
+    pub tag: String,
+    /// `"METHOD /path"` keys, sorted
+    pub endpoints: Vec<String>,
+    pub write_operations: usize,
+    /// Operations without a summary or description
+    pub undocumented: Vec<String>,
+    /// Fields no operation of another tag uses
+    pub unique_fields: Vec<String>,
+}
+
+impl TagStats {
+    /// Share of write operations, 0.0 without operations.
+    pub fn write_ratio(&self) -> f64 {
+        if self.endpoints.is_empty() {
+            0.0
+        } else {
+            self.write_operations as f64 / self.endpoints.len() as f64
+        }
+    }
+}
+
+/// Path operations by tag, most operations first. An operation with
+/// several tags counts for each.
+pub fn tag_stats(spec: &OpenApiSpec, index: &FieldIndex) -> Vec<TagStats> {
+    let is_documented =
+        |text: &Option<String>| text.as_deref().is_some_and(|t| !t.trim().is_empty());
+    let mut tags: BTreeMap<String, TagStats> = BTreeMap::new();
+    // Tags using each field, to find those used by a single tag
+    let mut field_tags: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (path, path_item) in &spec.paths {
+        for (method, operation) in &path_item.operations {
+            let key = format!("{} {}", method.to_uppercase(), path);
+            let names: Vec<&str> = match operation.tags.as_deref() {
+                Some(names) if !names.is_empty() => names.iter().map(String::as_str).collect(),
+                _ => vec![UNTAGGED],
+            };
+            for &name in &names {
+                let stats = tags.entry(name.to_string()).or_insert_with(|| TagStats {
+                    tag: name.to_string(),
+                    endpoints: Vec::new(),
+                    write_operations: 0,
+                    undocumented: Vec::new(),
+                    unique_fields: Vec::new(),
+                });
+                stats.endpoints.push(key.clone());
+                if HttpMethod::parse(method).is_some_and(HttpMethod::is_write) {
+                    stats.write_operations += 1;
+                }
+                if !is_documented(&operation.summary) && !is_documented(&operation.description) {
+                    stats.undocumented.push(key.clone());
+                }
+            }
+            for field in index.endpoint_field_names(&key) {
+                let used_by = field_tags.entry(field).or_default();
+                for &name in &names {
+                    if !used_by.contains(&name) {
+                        used_by.push(name);
+                    }
+                }
+            }
+        }
+    }
+    for (field, used_by) in field_tags {
+        if let [tag] = used_by.as_slice() {
+            if let Some(stats) = tags.get_mut(*tag) {
+                stats.unique_fields.push(field.to_string());
+            }
+        }
+    }
+
+    let mut tags: Vec<TagStats> = tags
+        .into_values()
+        .map(|mut stats| {
+            stats.endpoints.sort_unstable();
+            stats.undocumented.sort_unstable();
+            stats
+        })
+        .collect();
+    tags.sort_by_key(|stats| std::cmp::Reverse(stats.endpoints.len()));
+    tags
+}
+
+/// Words marking a description as unfinished
+const PLACEHOLDER_WORDS: &[&str] = &["TODO", "TBD", "FIXME", "XXX"];
+
+/// Fields and schemas with a description and path operations with a
+/// summary or description, and how good those texts are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentationCoverage {
+    pub documented: usize,
+    pub total: usize,
+    pub fields: usize,
+    pub operations: usize,
+    pub schemas: usize,
+    pub undocumented_fields: Vec<String>,
+    pub undocumented_operations: Vec<String>,
+    pub undocumented_schemas: Vec<String>,
+    /// Mean length in characters of the descriptions, summaries included,
+    /// 0 without any
+    pub average_length: f64,
+    /// Operations whose summary or description is placeholder text, such as
+    /// "TODO" or "TBD"
+    pub placeholder_operations: Vec<String>,
+}
+
+impl DocumentationCoverage {
+    /// Undocumented items and placeholder texts, as the Stats drill-down
+    /// lists them.
+    pub fn buckets(&self) -> Vec<Bucket> {
+        [
+            ("Undocumented fields", &self.undocumented_fields),
+            ("Undocumented operations", &self.undocumented_operations),
+            ("Undocumented schemas", &self.undocumented_schemas),
+            (
+                "Placeholder text (TODO, TBD...)",
+                &self.placeholder_operations,
+            ),
+        ]
+        .into_iter()
+        .map(|(label, items)| Bucket {
+            label: label.to_string(),
+            items: items.clone(),
+        })
+        .collect()
+    }
+
+    /// Documented share, 1.0 when there is nothing to document.
+    pub fn ratio(&self) -> f64 {
+        share(self.total - self.documented, self.total)
+    }
+
+    pub fn field_ratio(&self) -> f64 {
+        share(self.undocumented_fields.len(), self.fields)
+    }
+
+    pub fn operation_ratio(&self) -> f64 {
+        share(self.undocumented_operations.len(), self.operations)
+    }
+
+    pub fn schema_ratio(&self) -> f64 {
+        share(self.undocumented_schemas.len(), self.schemas)
+    }
+
+    /// Whether the documented share reaches `min_percent` (0 to 100).
+    pub fn meets(&self, min_percent: f64) -> bool {
+        self.ratio() * 100.0 >= min_percent
+    }
+
+    /// Coverage against `min_percent` and the undocumented items, for CI
+    /// logs (`--min-doc-coverage`).
+    pub fn to_text(&self, min_percent: f64) -> String {
+        let mut out = format!(
+            "Documentation coverage: {:.1}% ({}/{}), minimum {}%\n",
+            self.ratio() * 100.0,
+            self.documented,
+            self.total,
+            min_percent
+        );
+        for (heading, items) in [
+            ("operations", &self.undocumented_operations),
+            ("schemas", &self.undocumented_schemas),
+            ("fields", &self.undocumented_fields),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\nUndocumented {} ({}):\n", heading, items.len()));
+            for item in items {
+                out.push_str(&format!("  ✗ {}\n", item));
+            }
+        }
+        out.push_str(if self.meets(min_percent) {
+            "\nOK\n"
+        } else {
+            "\nFAILED (--min-doc-coverage)\n"
+        });
+        out
+    }
+}
+
+/// A documentation coverage minimum, as a percentage from 0 to 100.
+pub fn parse_min_coverage(value: &str) -> Result<f64> {
+    let percent: f64 = value
+        .trim_end_matches('%')
+        .parse()
+        .with_context(|| format!("Invalid coverage '{}'", value))?;
+    check_min_coverage(percent)
+}
+
+pub fn check_min_coverage(percent: f64) -> Result<f64> {
+    if !(0.0..=100.0).contains(&percent) {
+        anyhow::bail!("Coverage minimum {} is not between 0 and 100", percent);
+    }
+    Ok(percent)
+}
+
+fn share(undocumented: usize, total: usize) -> f64 {
+    if total == 0 {
+        1.0
+    } else {
+        (total - undocumented) as f64 / total as f64
+    }
+}
+
+/// Text holding a placeholder word such as TODO or TBD, or lorem ipsum.
+pub fn is_placeholder(text: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric())
+        .any(|word| PLACEHOLDER_WORDS.contains(&word.to_uppercase().as_str()))
+        || text.to_lowercase().contains("lorem ipsum")
+}
+
+pub fn documentation_coverage(spec: &OpenApiSpec, index: &FieldIndex) -> DocumentationCoverage {
+    let is_documented =
+        |text: &Option<String>| text.as_deref().is_some_and(|t| !t.trim().is_empty());
+    let mut lengths = Vec::new();
+    let mut measure = |text: &Option<String>| {
+        if let Some(text) = text.as_deref().filter(|t| !t.trim().is_empty()) {
+            lengths.push(text.trim().chars().count());
+        }
+    };
+
+    let mut undocumented_fields = Vec::new();
+    for (name, data) in &index.fields {
+        measure(&data.description);
+        if !is_documented(&data.description) {
+            undocumented_fields.push(name.clone());
+        }
+    }
+    undocumented_fields.sort_unstable();
+
+    let mut undocumented_schemas = Vec::new();
+    for (name, schema) in &index.schemas {
+        measure(&schema.description);
+        if !is_documented(&schema.description) {
+            undocumented_schemas.push(name.clone());
+        }
+    }
+    undocumented_schemas.sort_unstable();
+
+    let mut operations = 0;
+    let mut undocumented_operations = Vec::new();
+    let mut placeholder_operations = Vec::new();
+    for (path, path_item) in &spec.paths {
+        for (method, operation) in &path_item.operations {
+            operations += 1;
+            let key = format!("{} {}", method.to_uppercase(), path);
+            measure(&operation.summary);
+            measure(&operation.description);
+            if !is_documented(&operation.summary) && !is_documented(&operation.description) {
+                undocumented_operations.push(key);
+            } else if [&operation.summary, &operation.description]
+                .into_iter()
+                .flatten()
+                .any(|text| is_placeholder(text))
+            {
+                placeholder_operations.push(key);
+            }
+        }
+    }
+    undocumented_operations.sort_unstable();
+    placeholder_operations.sort_unstable();
+
+    let total = index.fields.len() + operations + index.schemas.len();
+    let average_length = if lengths.is_empty() {
+        0.0
+    } else {
+        lengths.iter().sum::<usize>() as f64 / lengths.len() as f64
+    };
+    DocumentationCoverage {
+        documented: total
+            - undocumented_fields.len()
+            - undocumented_operations.len()
+            - undocumented_schemas.len(),
+        total,
+        fields: index.fields.len(),
+        operations,
+        schemas: index.schemas.len(),
+        undocumented_fields,
+        undocumented_operations,
+        undocumented_schemas,
+        average_length,
+        placeholder_operations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+    use chrono::TimeZone;
+
+    fn capture(paths: &str, schemas: &str, timestamp: DateTime<Utc>) -> StatsSnapshot {
+        let spec = parse_openapi_content(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Shop", "version": "1"}},
+                "paths": {{{}}}, "components": {{"schemas": {{{}}}}}}}"#,
+            paths, schemas
+        ))
+        .unwrap();
+        let index = build_field_index(&spec);
+        StatsSnapshot::capture(&spec, &index, timestamp)
+    }
+
+    const USERS: &str = r#""/users": {"post": {"requestBody": {"content": {"application/json": {"schema": {
+        "type": "object", "properties": {"email": {"type": "string"}, "name": {"type": "string"}}
+    }}}}, "responses": {}}}"#;
+    const USER: &str = r#""User": {"type": "object", "properties": {
+        "email": {"type": "string"}, "name": {"type": "string"}
+    }}"#;
+    const ORDERS: &str = r#""/orders": {"get": {"responses": {"200": {"description": "ok"}}}}"#;
+    const ORDER: &str =
+        r#""Order": {"type": "object", "properties": {"total": {"type": "number"}}}"#;
+
+    #[test]
+    fn test_snapshots_record_trend() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2026, 10, 15, 9, 0, 0).unwrap();
+
+        let (path, trend) =
+            record_stats_snapshot(dir.path(), &capture(USERS, USER, first), first).unwrap();
+        assert!(trend.is_none());
+        assert!(path.ends_with("stats-20261001T090000Z.json"));
+
+        let snapshot = capture(
+            &format!("{}, {}", USERS, ORDERS),
+            &format!("{}, {}", USER, ORDER),
+            second,
+        );
+        let (_, trend) = record_stats_snapshot(dir.path(), &snapshot, second).unwrap();
+        let trend = trend.unwrap();
+        assert_eq!(trend.since, "2026-10-01T09:00:00Z");
+        assert_eq!(
+            trend.summary(),
+            "+1 fields, +1 schemas, +1 endpoints, +1 operations since last snapshot"
+        );
+        assert_eq!(
+            latest_stats_snapshot(dir.path()).unwrap().unwrap(),
+            snapshot
+        );
+        assert!(snapshot.trend_since(&snapshot).is_unchanged());
+    }
+
+    #[test]
+    fn test_write_stats_by_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = capture(
+            USERS,
+            USER,
+            Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap(),
+        );
+
+        let markdown = dir.path().join("stats.md");
+        write_stats(&markdown, &snapshot, None).unwrap();
+        let content = std::fs::read_to_string(&markdown).unwrap();
+        assert!(content.starts_with("# Shop 1 statistics"));
+        assert!(content.contains("| Fields | 2 |"));
+        assert!(content.contains("- POST: 1"));
+
+        let json = dir.path().join("stats.json");
+        let trend = snapshot.trend_since(&snapshot);
+        write_stats(&json, &snapshot, Some(&trend)).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!(value["stats"]["critical_fields"], 2);
+        assert_eq!(value["trend"]["fields"], 0);
+        assert_eq!(value["top_fields"][0]["name"], "email");
+    }
+
+    #[test]
+    fn test_section_buckets() {
+        let spec = parse_openapi_content(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Shop", "version": "1"}},
+                "paths": {{{}, {}}}, "components": {{"schemas": {{{}, {}}}}}}}"#,
+            USERS, ORDERS, USER, ORDER
+        ))
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let types = field_type_buckets(&index);
+        assert_eq!(types[0].label, "string");
+        assert_eq!(types[0].items, vec!["email", "name"]);
+        assert_eq!(types[1].items, vec!["total"]);
+
+        let methods = method_buckets(&spec);
+        let labels: Vec<&str> = methods.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, vec!["GET", "POST"]);
+        assert_eq!(methods[0].items, vec!["GET /orders"]);
+
+        // 3 fields, 2 operations and 2 schemas, none of them documented
+        let coverage = documentation_coverage(&spec, &index);
+        assert_eq!((coverage.documented, coverage.total), (0, 7));
+        assert_eq!(
+            coverage.undocumented_operations,
+            vec!["GET /orders", "POST /users"]
+        );
+        assert_eq!(coverage.ratio(), 0.0);
+
+        assert_eq!(StatsSection::FieldTypes.previous(), StatsSection::Tags);
+        assert_eq!(StatsSection::Methods.next(), StatsSection::SchemaSizes);
+        assert_eq!(StatsSection::Tags.next(), StatsSection::FieldTypes);
+    }
+
+    #[test]
+    fn test_schema_sizes() {
+        let spec = parse_openapi_content(
+            r##"{"openapi": "3.0.0", "info": {"title": "Shop", "version": "1"}, "paths": {},
+                "components": {"schemas": {
+                    "Tag": {"type": "string"},
+                    "Address": {"type": "object", "properties": {"city": {"type": "string"}}},
+                    "Customer": {"type": "object", "properties": {
+                        "name": {"type": "string"},
+                        "addresses": {"type": "array", "items": {"$ref": "#/components/schemas/Address"}}}},
+                    "Order": {"allOf": [{"type": "object", "properties": {
+                        "buyer": {"$ref": "#/components/schemas/Customer"},
+                        "total": {"type": "number"}, "note": {"type": "string"}}}]},
+                    "Node": {"type": "object", "properties": {
+                        "children": {"type": "array", "items": {"$ref": "#/components/schemas/Node"}}}}
+                }}}"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let sizes = schema_sizes(&index);
+        let summary: Vec<(&str, usize, usize)> = sizes
+            .iter()
+            .map(|size| (size.name.as_str(), size.fields, size.depth))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Order", 3, 3),
+                ("Customer", 2, 2),
+                ("Address", 1, 1),
+                ("Node", 1, 1),
+                ("Tag", 0, 0),
+            ]
+        );
+
+        let buckets = schema_size_buckets(&index);
+        assert_eq!(buckets.len(), 5);
+        assert_eq!(buckets[0].label, "0-5");
+        assert_eq!(buckets[0].items.len(), 5);
+        assert!(buckets[1..].iter().all(|bucket| bucket.items.is_empty()));
+    }
+
+    #[test]
+    fn test_documentation_quality() {
+        let spec = parse_openapi_content(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"summary": "List users", "responses": {}},
+                        "post": {"summary": "Create a user", "description": "TODO: document errors", "responses": {}}
+                    },
+                    "/orders": {"get": {"summary": "tbd.", "responses": {}}, "delete": {"responses": {}}}
+                },
+                "components": {"schemas": {
+                    "User": {"description": "A customer", "type": "object", "properties": {
+                        "id": {"type": "integer", "description": "Identifier"}
+                    }},
+                    "Order": {"type": "object", "properties": {"total": {"type": "number"}}}
+                }}
+            }"#,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let coverage = documentation_coverage(&spec, &index);
+        assert_eq!(coverage.operation_ratio(), 0.75);
+        assert_eq!(coverage.field_ratio(), 0.5);
+        assert_eq!(coverage.schema_ratio(), 0.5);
+        assert_eq!((coverage.documented, coverage.total), (5, 8));
+        assert_eq!(
+            coverage.placeholder_operations,
+            vec!["GET /orders", "POST /users"]
+        );
+        assert_eq!(coverage.undocumented_schemas, vec!["Order"]);
+        // "List users", "Create a user", "TODO: document errors", "tbd.",
+        // "A customer" and "Identifier"
+        assert_eq!(coverage.average_length, 68.0 / 6.0);
+
+        assert!(coverage.meets(62.5));
+        assert!(!coverage.meets(80.0));
+        let text = coverage.to_text(80.0);
+        assert!(text.starts_with("Documentation coverage: 62.5% (5/8), minimum 80%\n"));
+        assert!(text.contains("Undocumented operations (1):\n  ✗ DELETE /orders\n"));
+        assert!(text.contains("Undocumented fields (1):\n  ✗ total\n"));
+        assert!(text.ends_with("FAILED (--min-doc-coverage)\n"));
+        assert!(coverage.to_text(50.0).ends_with("\nOK\n"));
+        assert_eq!(parse_min_coverage("80%").unwrap(), 80.0);
+        assert!(parse_min_coverage("120").is_err());
+        assert!(parse_min_coverage("most").is_err());
+
+        assert!(is_placeholder("Returns the user (TBD)"));
+        assert!(!is_placeholder("Returns todos"));
+        let buckets = coverage.buckets();
+        assert_eq!(buckets[2].items, vec!["Order"]);
+        assert_eq!(buckets[3].items, vec!["GET /orders", "POST /users"]);
+    }
+
+    #[test]
+    fn test_tag_stats() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"tags": ["users"], "summary": "List users", "parameters": [
+                            {"name": "email", "in": "query", "schema": {"type": "string"}}], "responses": {}},
+                        "post": {"tags": ["users", "admin"], "requestBody": {"content": {"application/json": {"schema": {
+                            "type": "object", "properties": {"name": {"type": "string"}}}}}},
+                            "responses": {}}
+                    },
+                    "/health": {"get": {"responses": {}}}
+                },
+                "components": {"schemas": {"User": {"type": "object", "properties": {
+                    "email": {"type": "string"}, "name": {"type": "string"}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+
+        let tags = tag_stats(&spec, &index);
+        let names: Vec<&str> = tags.iter().map(|t| t.tag.as_str()).collect();
+        assert_eq!(names, vec!["users", UNTAGGED, "admin"]);
+
+        let users = &tags[0];
+        assert_eq!(users.endpoints, vec!["GET /users", "POST /users"]);
+        assert_eq!(users.write_operations, 1);
+        assert_eq!(users.write_ratio(), 0.5);
+        assert_eq!(users.undocumented, vec!["POST /users"]);
+        // `name` is also used by the admin operation
+        assert_eq!(users.unique_fields, vec!["email"]);
+        assert!(tags[2].unique_fields.is_empty());
+        assert_eq!(tags[1].endpoints, vec!["GET /health"]);
+
+        let health = &spec.paths["/health"].operations["get"];
+        assert!(operation_has_tag(health, UNTAGGED));
+        assert!(!operation_has_tag(health, "users"));
+    }
+}
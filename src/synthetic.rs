@@ -0,0 +1,90 @@
+//! Synthetic specs of a given size, for benchmarks and scale tests.
+
+use serde_json::{json, Map, Value};
+
+/// Properties declared by each generated schema
+pub const FIELDS_PER_SCHEMA: usize = 20;
+
+/// JSON spec declaring about `fields` properties: schemas of
+/// [`FIELDS_PER_SCHEMA`] properties drawn from a pool of `fields / 4`
+/// names (so fields are shared across schemas), each with a GET path
+/// returning it and a POST path taking the same properties inline.
+/// Operations go through `components/parameters` and
+/// `components/requestBodies` references, so reference resolution has
+/// work to do too.
+pub fn synthetic_spec(fields: usize) -> String {
+    let schemas = fields.div_ceil(FIELDS_PER_SCHEMA).max(1);
+    let names = (fields / 4).max(FIELDS_PER_SCHEMA);
+    let mut components = Map::new();
+    let mut bodies = Map::new();
+    let mut paths = Map::new();
+    for i in 0..schemas {
+        let properties: Map<String, Value> = (0..FIELDS_PER_SCHEMA)
+            .map(|j| {
+                let kind = if j % 3 == 0 { "integer" } else { "string" };
+                (
+                    format!("field_{}", (i * 7 + j) % names),
+                    json!({"type": kind, "description": format!("Field {} of Model{}", j, i)}),
+                )
+            })
+            .collect();
+        let model = format!("Model{}", i);
+        let schema = json!({"type": "object", "properties": properties});
+        let reference = json!({"$ref": format!("#/components/schemas/{}", model)});
+        bodies.insert(
+            model.clone(),
+            json!({"content": {"application/json": {"schema": schema}}}),
+        );
+        components.insert(model.clone(), schema);
+        paths.insert(
+            format!("/models{}/{{id}}", i),
+            json!({
+                "get": {
+                    "operationId": format!("getModel{}", i),
+                    "parameters": [{"$ref": "#/components/parameters/Id"}],
+                    "responses": {"200": {
+                        "description": "ok",
+                        "content": {"application/json": {"schema": reference}}
+                    }}
+                },
+                "post": {
+                    "operationId": format!("createModel{}", i),
+                    "requestBody": {"$ref": format!("#/components/requestBodies/{}", model)},
+                    "responses": {"201": {"description": "created"}}
+                }
+            }),
+        );
+    }
+    json!({
+        "openapi": "3.0.0",
+        "info": {"title": "Synthetic", "version": "1"},
+        "paths": paths,
+        "components": {
+            "schemas": components,
+            "requestBodies": bodies,
+            "parameters": {"Id": {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}}
+        }
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_synthetic_spec_size() {
+        let spec = parse_openapi_content(&synthetic_spec(1000)).unwrap();
+        let index = build_field_index(&spec);
+        assert_eq!(index.schemas.len(), 50);
+        assert_eq!(index.endpoint_fields.len(), 100);
+        // The name pool, plus the shared `id` parameter
+        assert_eq!(index.fields.len(), 251);
+        // The referenced request body was inlined
+        let post = &spec.paths["/models0/{id}"].operations["post"];
+        assert!(post.request_body.is_some());
+        assert!(!index.fields["field_0"].endpoints.is_empty());
+    }
+}
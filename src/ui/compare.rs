@@ -0,0 +1,156 @@
+use crate::compare::{FieldComparison, FieldSide, SchemaComparison};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_comparison_popup(f: &mut Frame, theme: &Theme, comparison: &SchemaComparison) {
+    let area = popup_area(f.area());
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let name_width = comparison
+        .fields
+        .iter()
+        .map(|field| field.name.chars().count() + 2)
+        .max()
+        .unwrap_or(0)
+        .clamp(8, inner_width / 4);
+    let side_width = inner_width.saturating_sub(name_width) / 2;
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("{} ⇄ {}", comparison.left, comparison.right),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} shared field(s), {} difference(s)",
+            comparison.shared_count(),
+            comparison.mismatch_count()
+        )),
+        Line::from(""),
+        Line::from(
+            [
+                pad("Field", name_width),
+                pad(&comparison.left, side_width),
+                comparison.right.clone(),
+            ]
+            .map(|heading| {
+                Span::styled(
+                    heading,
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::UNDERLINED),
+                )
+            })
+            .to_vec(),
+        ),
+    ];
+
+    for field in &comparison.fields {
+        let name_style = if field.is_match() {
+            Style::default()
+        } else {
+            Style::default()
+                .fg(theme.critical)
+                .add_modifier(Modifier::BOLD)
+        };
+        let mut spans = vec![Span::styled(pad(&field.name, name_width), name_style)];
+        spans.extend(side_spans(theme, field, field.left.as_ref(), side_width));
+        spans.extend(side_spans(theme, field, field.right.as_ref(), side_width));
+        lines.push(Line::from(spans));
+
+        if field.description_differs() {
+            let description = |side: &Option<FieldSide>| {
+                side.as_ref()
+                    .and_then(|side| side.description.clone())
+                    .unwrap_or_else(|| "(no description)".to_string())
+            };
+            let style = Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC);
+            lines.push(Line::from(vec![
+                Span::raw(pad("", name_width)),
+                Span::styled(
+                    pad(&truncate(&description(&field.left), side_width), side_width),
+                    style,
+                ),
+                Span::styled(truncate(&description(&field.right), side_width), style),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Red: missing on one side or declared differently — Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Schema Comparison "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
+
+/// Type and required flag of one side, the differing parts in red.
+fn side_spans<'a>(
+    theme: &Theme,
+    field: &FieldComparison,
+    side: Option<&FieldSide>,
+    width: usize,
+) -> Vec<Span<'a>> {
+    let Some(side) = side else {
+        return vec![Span::styled(
+            pad("— missing", width),
+            Style::default().fg(theme.critical),
+        )];
+    };
+    let flag = if side.required {
+        " required"
+    } else {
+        " optional"
+    };
+    let type_style = if field.type_differs() {
+        Style::default().fg(theme.critical)
+    } else {
+        Style::default().fg(theme.success)
+    };
+    let flag_style = if field.required_differs() {
+        Style::default().fg(theme.critical)
+    } else {
+        Style::default().fg(theme.muted)
+    };
+    let used = side.type_label.chars().count() + flag.len();
+    vec![
+        Span::styled(side.type_label.clone(), type_style),
+        Span::styled(flag, flag_style),
+        Span::raw(" ".repeat(width.saturating_sub(used))),
+    ]
+}
+
+fn pad(text: &str, width: usize) -> String {
+    format!("{:<width$}", text, width = width)
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    let max = width.saturating_sub(2);
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        text.chars().take(max.saturating_sub(1)).collect::<String>() + "…"
+    }
+}
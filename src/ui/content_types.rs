@@ -0,0 +1,106 @@
+use crate::content_types::ContentTypeReport;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_content_types_popup(f: &mut Frame, theme: &Theme, report: &ContentTypeReport) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Content types",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} media type(s), {} endpoint(s) without JSON, {} mixing media types",
+            report.by_type.len(),
+            report.without_json.len(),
+            report.mixed.len()
+        )),
+        Line::from(""),
+    ];
+
+    for (title, endpoints, hint) in [
+        (
+            "Without JSON",
+            &report.without_json,
+            "  no JSON alternative offered",
+        ),
+        (
+            "Mixing media types",
+            &report.mixed,
+            "  e.g. form uploads answered in JSON",
+        ),
+    ] {
+        if endpoints.is_empty() {
+            continue;
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ({})", title, endpoints.len()),
+                Style::default()
+                    .fg(theme.critical)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(hint, Style::default().fg(theme.muted)),
+        ]));
+        for endpoint in endpoints {
+            lines.push(Line::from(format!("  • {}", endpoint)));
+        }
+        lines.push(Line::from(""));
+    }
+
+    for (media_type, usage) in &report.by_type {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} ({} request(s), {} response(s))",
+                media_type,
+                usage.requests.len(),
+                usage.responses.len()
+            ),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )));
+        for endpoint in &usage.requests {
+            lines.push(Line::from(vec![
+                Span::styled("  → ", Style::default().fg(theme.info)),
+                Span::raw(endpoint.as_str()),
+            ]));
+        }
+        for endpoint in &usage.responses {
+            lines.push(Line::from(vec![
+                Span::styled("  ← ", Style::default().fg(theme.success)),
+                Span::raw(endpoint.as_str()),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![Span::styled(
+        "→ request body  ← response — Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Content Types "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,114 @@
+use crate::coverage::CoverageReport;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_coverage_popup(f: &mut Frame, theme: &Theme, report: &CoverageReport) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Coverage: schemas vs endpoints vs fields",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!("{} item(s) to review", report.issue_count())),
+        Line::from(""),
+    ];
+
+    if report.is_complete() {
+        lines.push(Line::from(Span::styled(
+            "Every schema, field and response is exercised by an operation",
+            Style::default().fg(theme.success),
+        )));
+    }
+
+    let empty_responses: Vec<String> = report
+        .empty_responses
+        .iter()
+        .map(|response| format!("{}  {}", response.endpoint, response.status))
+        .collect();
+    let unused_components: Vec<String> = report
+        .unused_components
+        .iter()
+        .map(|component| match component.preview.as_str() {
+            "" => format!("{}/{}", component.section, component.name),
+            preview => format!("{}/{}  {}", component.section, component.name, preview),
+        })
+        .collect();
+    for (title, hint, items) in [
+        (
+            "Unreferenced schemas",
+            "reference them from an operation or remove them",
+            &report.unreferenced_schemas,
+        ),
+        (
+            "Unused components",
+            "parameters, responses, bodies, headers, security",
+            &unused_components,
+        ),
+        (
+            "Orphaned fields",
+            "declared but never sent or received",
+            &report.orphaned_fields,
+        ),
+        (
+            "Responses without content",
+            "describe the body or use 204",
+            &empty_responses,
+        ),
+        (
+            "Endpoints without any schema",
+            "add a request or response schema",
+            &report.schemaless_endpoints,
+        ),
+        (
+            "Required fields without examples",
+            "add an example for the SDK docs",
+            &report.required_without_examples,
+        ),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ({})", title, items.len()),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(format!("  {}", hint), Style::default().fg(theme.muted)),
+        ]));
+        for item in items {
+            lines.push(Line::from(format!("  • {}", item)));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close  (:export unused <file> writes the unused components)",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Coverage Report "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,51 @@
+use crate::app::DescriptionEditor;
+use crate::ui::layout::popup_area_with_height;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_description_editor(f: &mut Frame, theme: &Theme, editor: &DescriptionEditor) {
+    let (before, after): (String, String) = {
+        let split = editor
+            .input
+            .char_indices()
+            .nth(editor.cursor)
+            .map(|(offset, _)| offset)
+            .unwrap_or(editor.input.len());
+        let (before, after) = editor.input.split_at(split);
+        (before.to_string(), after.to_string())
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(before),
+            Span::styled("█", Style::default().fg(theme.accent)),
+            Span::raw(after),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Enter to save to the spec file, 'Esc' to cancel",
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )]),
+    ];
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(format!(" Description of {} ", editor.target.label())),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area_with_height(f.area(), 8);
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
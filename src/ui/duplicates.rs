@@ -0,0 +1,78 @@
+use crate::duplicates::{DuplicatePair, NEAR_DUPLICATE_THRESHOLD};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_duplicates_popup(f: &mut Frame, theme: &Theme, pairs: &[DuplicatePair]) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Duplicate schemas",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} pair(s) sharing at least {:.0}% of their fields with the same types",
+            pairs.len(),
+            NEAR_DUPLICATE_THRESHOLD * 100.0
+        )),
+        Line::from(""),
+    ];
+
+    if pairs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No duplicate schemas found",
+            Style::default().fg(theme.success),
+        )));
+    }
+
+    for pair in pairs {
+        let (label, color) = if pair.exact {
+            ("identical".to_string(), theme.critical)
+        } else {
+            (
+                format!("{:.0}% similar", pair.similarity * 100.0),
+                theme.highlight,
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<13}", label), Style::default().fg(color)),
+            Span::styled(
+                format!("{} ≈ {}", pair.left, pair.right),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}/{} fields", pair.shared_fields, pair.total_fields),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Compare a pair with 'c' in the Schemas view — Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Duplicate Schemas "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -1,222 +1,487 @@
-use crate::app::{App, Panel};
+use crate::app::{Panel, ScrollTarget, TextScrolls};
+use crate::indexer::FormField;
+use crate::ui::theme::Theme;
+use crate::view_model::{
+    EndpointDetails, EndpointFieldRow, EndpointFieldsViewModel, EndpointRow, EndpointsViewModel,
+    OperationDetails,
+};
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{List, ListItem, Paragraph, Wrap},
+    widgets::{List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-pub fn render_endpoints_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
-    // Left panel - Endpoints list
-    let endpoint_items: Vec<ListItem> = app
-        .filtered_endpoints
-        .iter()
-        .map(|endpoint| {
-            let style = if Some(endpoint.as_str()) == app.selected_endpoint.as_deref() {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                let is_critical = endpoint.to_lowercase().contains("post")
-                    || endpoint.to_lowercase().contains("put");
-                if is_critical {
-                    Style::default().fg(Color::Red)
-                } else {
-                    Style::default()
-                }
+pub fn render_endpoints_view(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &EndpointsViewModel,
+    chunks: Vec<Rect>,
+) {
+    // Left panel - Endpoints list, with webhooks and callbacks in an Events
+    // section after the paths
+    let mut endpoint_items: Vec<ListItem> = Vec::new();
+    let mut cursor = None;
+    for row in &model.rows {
+        if row.starts_events {
+            endpoint_items
+                .push(ListItem::new("── Events ──").style(Style::default().fg(theme.muted)));
+        }
+        if row.row.cursor {
+            cursor = Some(endpoint_items.len());
+        }
+        let mut line = endpoint_line(theme, model, row);
+        if model.show_cursor {
+            let fold = match row.folded {
+                None => "  ",
+                Some(false) => "▾ ",
+                Some(true) => "▸ ",
             };
-            ListItem::new(endpoint.as_str()).style(style)
-        })
-        .collect();
+            line.spans
+                .insert(0, Span::raw(format!("{}{}", "  ".repeat(row.depth), fold)));
+        }
+        if row.hidden > 0 {
+            line.spans.push(Span::styled(
+                format!("  ({} paths)", row.hidden),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        endpoint_items.push(ListItem::new(line));
+    }
 
     let endpoints_list = List::new(endpoint_items)
         .block(crate::ui::layout::panel_block(
-            "Endpoints",
-            app.current_panel == Panel::Left,
+            theme,
+            model.title,
+            model.panel == Panel::Left,
         ))
         .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
-    f.render_widget(endpoints_list, chunks[0]);
+    if model.show_cursor {
+        let mut state =
+            ListState::default().with_selected(cursor.filter(|_| model.panel == Panel::Left));
+        f.render_stateful_widget(endpoints_list, chunks[0], &mut state);
+    } else {
+        f.render_widget(endpoints_list, chunks[0]);
+    }
 
     // Center panel - Endpoint details
-    if let Some(selected_endpoint) = &app.selected_endpoint {
-        let parts: Vec<&str> = selected_endpoint.splitn(2, ' ').collect();
-        if parts.len() == 2 {
-            let method = parts[0];
-            let path = parts[1];
-
-            if let Some(path_item) = app.openapi_spec.paths.get(path) {
-                if let Some(operation) = path_item.operations.get(method.to_lowercase().as_str()) {
-                    let mut details_text = vec![
-                        Line::from(vec![
-                            Span::styled("Endpoint: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(
-                                selected_endpoint,
-                                Style::default().add_modifier(Modifier::BOLD),
-                            ),
-                        ]),
-                        Line::from(""),
-                        Line::from(vec![
-                            Span::styled("Method: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(method.to_uppercase(), Style::default().fg(Color::Yellow)),
-                        ]),
-                        Line::from(""),
-                        Line::from(vec![
-                            Span::styled("Path: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(path, Style::default()),
-                        ]),
-                        Line::from(""),
-                    ];
-
-                    if let Some(summary) = &operation.summary {
-                        details_text.push(Line::from(vec![
-                            Span::styled("Summary: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(summary, Style::default()),
-                        ]));
-                        details_text.push(Line::from(""));
-                    }
-
-                    if let Some(description) = &operation.description {
-                        details_text.push(Line::from(vec![
-                            Span::styled("Description: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(description, Style::default()),
-                        ]));
-                        details_text.push(Line::from(""));
-                    }
-
-                    if let Some(tags) = &operation.tags {
-                        if !tags.is_empty() {
-                            details_text.push(Line::from(vec![
-                                Span::styled("Tags: ", Style::default().fg(Color::Cyan)),
-                                Span::styled(tags.join(", "), Style::default().fg(Color::Green)),
-                            ]));
-                            details_text.push(Line::from(""));
-                        }
-                    }
-
-                    if let Some(parameters) = &operation.parameters {
-                        details_text.push(Line::from(vec![
-                            Span::styled("Parameters: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(
-                                format!("{} parameters", parameters.len()),
-                                Style::default(),
-                            ),
-                        ]));
-                        for param in parameters {
-                            let required = param.required.unwrap_or(false);
-                            details_text.push(Line::from(vec![
-                                Span::styled("  • ", Style::default().fg(Color::DarkGray)),
-                                Span::styled(
-                                    &param.name,
-                                    Style::default().add_modifier(Modifier::BOLD),
-                                ),
-                                Span::styled(
-                                    format!(" ({})", param.in_),
-                                    Style::default().fg(Color::Green),
-                                ),
-                                Span::styled(
-                                    if required {
-                                        " (required)"
-                                    } else {
-                                        " (optional)"
-                                    },
-                                    Style::default().fg(if required {
-                                        Color::Red
-                                    } else {
-                                        Color::DarkGray
-                                    }),
-                                ),
-                            ]));
-                        }
-                        details_text.push(Line::from(""));
-                    }
-
-                    let details_widget = Paragraph::new(details_text)
-                        .wrap(Wrap { trim: true })
-                        .block(crate::ui::layout::panel_block(
-                            "Endpoint Details",
-                            app.current_panel == Panel::Center,
-                        ));
-                    f.render_widget(details_widget, chunks[1]);
-                } else {
-                    let no_operation = Paragraph::new("Operation not found")
-                        .style(Style::default().fg(Color::Red))
-                        .block(crate::ui::layout::panel_block(
-                            "Endpoint Details",
-                            app.current_panel == Panel::Center,
-                        ));
-                    f.render_widget(no_operation, chunks[1]);
-                }
-            } else {
-                let no_path = Paragraph::new("Path not found")
-                    .style(Style::default().fg(Color::Red))
-                    .block(crate::ui::layout::panel_block(
-                        "Endpoint Details",
-                        app.current_panel == Panel::Center,
-                    ));
-                f.render_widget(no_path, chunks[1]);
+    let center_focused = model.panel == Panel::Center;
+    let details = match &model.details {
+        Some(EndpointDetails::Operation(operation)) => Some((
+            operation.endpoint.as_str(),
+            operation_lines(theme, operation),
+        )),
+        Some(EndpointDetails::Target {
+            kind,
+            target,
+            operations,
+        }) => {
+            // A path or event target: list its operations
+            let mut details_text = vec![
+                Line::from(vec![
+                    Span::styled(*kind, Style::default().fg(theme.accent)),
+                    Span::styled(
+                        target.as_str(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                ]),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "Operations:",
+                    Style::default().fg(theme.accent),
+                )),
+            ];
+            for (method, summary) in operations {
+                details_text.push(Line::from(vec![
+                    Span::styled("  • ", Style::default().fg(theme.muted)),
+                    Span::styled(method.as_str(), Style::default().fg(theme.highlight)),
+                    Span::raw(
+                        summary
+                            .as_ref()
+                            .map(|summary| format!("  {}", summary))
+                            .unwrap_or_default(),
+                    ),
+                ]));
             }
-        } else {
-            let invalid_format = Paragraph::new("Invalid endpoint format")
-                .style(Style::default().fg(Color::Red))
+            Some((target.as_str(), details_text))
+        }
+        Some(EndpointDetails::Missing(reason)) => {
+            let missing = Paragraph::new(*reason)
+                .style(Style::default().fg(theme.critical))
                 .block(crate::ui::layout::panel_block(
+                    theme,
                     "Endpoint Details",
-                    app.current_panel == Panel::Center,
+                    center_focused,
                 ));
-            f.render_widget(invalid_format, chunks[1]);
+            f.render_widget(missing, chunks[1]);
+            None
         }
-    } else {
-        let no_selection = Paragraph::new("Select an endpoint to view details")
-            .style(Style::default().fg(Color::DarkGray))
+        None => {
+            let no_selection = Paragraph::new("Select an endpoint to view details")
+                .style(Style::default().fg(theme.muted))
+                .block(crate::ui::layout::panel_block(
+                    theme,
+                    "Endpoint Details",
+                    center_focused,
+                ));
+            f.render_widget(no_selection, chunks[1]);
+            None
+        }
+    };
+    if let Some((subject, details_text)) = details {
+        let details_widget = Paragraph::new(details_text)
+            .wrap(Wrap { trim: true })
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Endpoint Details",
-                app.current_panel == Panel::Center,
+                center_focused,
             ));
-        f.render_widget(no_selection, chunks[1]);
-    }
-
-    // Right panel - Fields used by this endpoint
-    if let Some(selected_endpoint) = &app.selected_endpoint {
-        if let Some(fields) = app.field_index.endpoint_fields.get(selected_endpoint) {
-            let field_items: Vec<ListItem> = fields
-                .iter()
-                .map(|field| {
-                    let is_critical = app.field_index.is_critical_field(field);
-                    let style = if is_critical {
-                        Style::default().fg(Color::Red)
-                    } else {
-                        Style::default()
-                    };
-                    ListItem::new(field.as_str()).style(style)
-                })
+        crate::ui::layout::render_scrolled_paragraph(
+            f,
+            theme,
+            scrolls,
+            ScrollTarget::EndpointDetails,
+            subject,
+            details_widget,
+            chunks[1],
+        );
+    }
+
+    // Right panel - Fields used by this endpoint, by where they are declared
+    let right_focused = model.panel == Panel::Right;
+    if let Some(fields) = &model.fields {
+        if !fields.operations.is_empty() {
+            let field_items: Vec<ListItem> = endpoint_field_tree(theme, fields)
+                .into_iter()
+                .map(ListItem::new)
                 .collect();
 
-            let title = format!("Fields ({})", fields.len());
+            let title = format!("Fields ({})", fields.distinct);
             let fields_list = List::new(field_items)
-                .block(crate::ui::layout::panel_block(
-                    &title,
-                    app.current_panel == Panel::Right,
-                ))
+                .block(crate::ui::layout::panel_block(theme, &title, right_focused))
                 .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
             f.render_widget(fields_list, chunks[2]);
         } else {
             let no_fields = Paragraph::new("No fields found for this endpoint")
-                .style(Style::default().fg(Color::DarkGray))
+                .style(Style::default().fg(theme.muted))
                 .block(crate::ui::layout::panel_block(
+                    theme,
                     "Fields",
-                    app.current_panel == Panel::Right,
+                    right_focused,
                 ));
             f.render_widget(no_fields, chunks[2]);
         }
     } else {
         let no_endpoint = Paragraph::new("Select an endpoint to see related fields")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Fields",
-                app.current_panel == Panel::Right,
+                right_focused,
             ));
         f.render_widget(no_endpoint, chunks[2]);
     }
 }
+
+/// Details of a `METHOD /path` operation: summary, documentation,
+/// parameters, form fields and the response links from and to it.
+fn operation_lines<'a>(theme: &Theme, operation: &'a OperationDetails) -> Vec<Line<'a>> {
+    let mut details_text = vec![
+        Line::from(vec![
+            Span::styled("Endpoint: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                operation.endpoint.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Method: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                operation.method.as_str(),
+                Style::default().fg(theme.highlight),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Path: ", Style::default().fg(theme.accent)),
+            Span::styled(operation.path.as_str(), Style::default()),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(summary) = &operation.summary {
+        details_text.push(Line::from(vec![
+            Span::styled("Summary: ", Style::default().fg(theme.accent)),
+            Span::styled(summary.as_str(), Style::default()),
+        ]));
+        details_text.push(Line::from(""));
+    }
+
+    if let Some(description) = &operation.description {
+        details_text.push(Line::from(vec![
+            Span::styled("Description: ", Style::default().fg(theme.accent)),
+            Span::styled(description.as_str(), Style::default()),
+        ]));
+        details_text.push(Line::from(""));
+    }
+
+    if let Some(docs) = &operation.docs {
+        details_text.push(crate::ui::layout::docs_line(theme, docs));
+        details_text.push(Line::from(""));
+    }
+
+    if !operation.tags.is_empty() {
+        details_text.push(Line::from(vec![
+            Span::styled("Tags: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                operation.tags.join(", "),
+                Style::default().fg(theme.success),
+            ),
+        ]));
+        details_text.push(Line::from(""));
+    }
+
+    if let Some(parameters) = &operation.parameters {
+        details_text.push(Line::from(vec![
+            Span::styled("Parameters: ", Style::default().fg(theme.accent)),
+            Span::styled(format!("{} parameters", parameters.len()), Style::default()),
+        ]));
+        for param in parameters {
+            details_text.push(Line::from(vec![
+                Span::styled("  • ", Style::default().fg(theme.muted)),
+                Span::styled(
+                    param.name.as_str(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" ({})", param.location),
+                    Style::default().fg(theme.success),
+                ),
+                Span::styled(
+                    if param.required {
+                        " (required)"
+                    } else {
+                        " (optional)"
+                    },
+                    Style::default().fg(if param.required {
+                        theme.critical
+                    } else {
+                        theme.muted
+                    }),
+                ),
+            ]));
+        }
+        details_text.push(Line::from(""));
+    }
+
+    if let Some(fields) = &operation.form_fields {
+        details_text.push(Line::from(Span::styled(
+            "Form fields: ",
+            Style::default().fg(theme.accent),
+        )));
+        details_text.extend(form_field_lines(theme, fields));
+        details_text.push(Line::from(""));
+    }
+
+    // Response links from and to this operation
+    if !operation.links_out.is_empty() || !operation.links_in.is_empty() {
+        details_text.push(Line::from(Span::styled(
+            "Links: ",
+            Style::default().fg(theme.accent),
+        )));
+        for link in &operation.links_out {
+            details_text.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} {} → ", link.status, link.name),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::raw(
+                    link.to
+                        .clone()
+                        .unwrap_or_else(|| format!("{} (unresolved)", link.target)),
+                ),
+            ]));
+        }
+        for link in &operation.links_in {
+            details_text.push(Line::from(vec![
+                Span::styled("  ← ", Style::default().fg(theme.muted)),
+                Span::raw(link.from.clone()),
+                Span::styled(
+                    format!(" {} {}", link.status, link.name),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+        details_text.push(Line::from(""));
+    }
+    details_text
+}
+
+/// Operations, then the places each declares fields in (parameters,
+/// request body, responses by status), then the fields.
+/// With `by_field`, each field once with where it is declared instead.
+fn endpoint_field_tree(theme: &Theme, model: &EndpointFieldsViewModel) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for operation in &model.operations {
+        lines.push(Line::from(Span::styled(
+            operation.operation.clone(),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if model.by_field {
+            for usage in &operation.usages {
+                let mut spans = vec![
+                    Span::raw("  "),
+                    Span::styled(
+                        usage.field.name.clone(),
+                        endpoint_field_style(theme, &usage.field),
+                    ),
+                ];
+                spans.push(Span::styled(
+                    format!(" ({})", usage.summary),
+                    Style::default().fg(if usage.occurrences > 1 {
+                        theme.info
+                    } else {
+                        theme.muted
+                    }),
+                ));
+                lines.push(Line::from(spans));
+            }
+            continue;
+        }
+        for (i, group) in operation.groups.iter().enumerate() {
+            let last_group = i + 1 == operation.groups.len();
+            let (branch, indent) = if last_group {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            lines.push(Line::from(vec![
+                Span::raw(branch),
+                Span::styled(
+                    format!("{} ({})", group.origin.label(), group.fields.len()),
+                    Style::default().fg(theme.info),
+                ),
+            ]));
+            for (j, field) in group.fields.iter().enumerate() {
+                let leaf = if j + 1 == group.fields.len() {
+                    "└─ "
+                } else {
+                    "├─ "
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{}{}", indent, leaf)),
+                    Span::styled(field.name.clone(), endpoint_field_style(theme, field)),
+                ]));
+            }
+        }
+    }
+    lines
+}
+
+fn endpoint_field_style(theme: &Theme, field: &EndpointFieldRow) -> Style {
+    if field.sensitive {
+        crate::ui::sensitive::sensitive_style(theme)
+    } else if field.critical {
+        Style::default().fg(theme.critical)
+    } else {
+        Style::default()
+    }
+}
+
+/// List line of a path, webhook or callback: colored by its most
+/// destructive write operation, with its operationIds next to it, or before
+/// it when toggled. Tree prefixes that are not paths are drawn as headings.
+fn endpoint_line(theme: &Theme, model: &EndpointsViewModel, row: &EndpointRow) -> Line<'static> {
+    if row.target.is_none() {
+        return Line::from(Span::styled(
+            row.row.label.clone(),
+            Style::default().fg(theme.accent),
+        ));
+    }
+    let style = if row.row.selected {
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else if row.is_event {
+        Style::default().fg(theme.secondary)
+    } else {
+        match row.write_method {
+            Some(method) => Style::default().fg(theme.method_color(method)),
+            None => Style::default(),
+        }
+    };
+    let marker = match (row.bookmarked, row.is_event) {
+        (true, _) => "★ ",
+        (false, true) => "⚡ ",
+        (false, false) => "",
+    };
+    let operation_ids = row.operation_ids.join(", ");
+    let (primary, secondary) = if model.operation_ids_first && !operation_ids.is_empty() {
+        (operation_ids, row.row.label.clone())
+    } else {
+        (row.row.label.clone(), operation_ids)
+    };
+    let mut spans = Vec::new();
+    if row.marked {
+        spans.push(Span::styled("✓ ", Style::default().fg(theme.highlight)));
+    }
+    spans.push(Span::styled(format!("{}{}", marker, primary), style));
+    if !secondary.is_empty() {
+        spans.push(Span::styled(
+            format!("  {}", secondary),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// One line per form field: uploads marked, with the part content type.
+pub fn form_field_lines(theme: &Theme, fields: &[FormField]) -> Vec<Line<'static>> {
+    fields
+        .iter()
+        .map(|field| {
+            let (marker, color) = if field.is_file {
+                ("  📎 ", theme.highlight)
+            } else {
+                ("  • ", theme.muted)
+            };
+            let mut spans = vec![
+                Span::styled(marker, Style::default().fg(color)),
+                Span::styled(
+                    field.name.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        " ({}{})",
+                        if field.is_file {
+                            "file"
+                        } else {
+                            &field.field_type
+                        },
+                        if field.required { ", required" } else { "" }
+                    ),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!(" {}", field.content_type),
+                    Style::default().fg(theme.info),
+                ),
+            ];
+            if !field.media_type.eq_ignore_ascii_case("multipart/form-data") {
+                spans.push(Span::styled(
+                    format!(" [{}]", field.media_type),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
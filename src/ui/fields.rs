@@ -1,52 +1,107 @@
-use crate::app::{App, Panel};
+use crate::app::Panel;
+use crate::indexer::FieldFlag;
+use crate::ui::theme::Theme;
+use crate::view_model::{FieldColumn, FieldsViewModel};
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{List, ListItem, Paragraph, Wrap},
+    widgets::{Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap},
     Frame,
 };
 
-pub fn render_fields_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
-    // Left panel - Fields list
-    let field_items: Vec<ListItem> = app
-        .filtered_fields
+pub fn render_fields_view(
+    f: &mut Frame,
+    theme: &Theme,
+    model: &FieldsViewModel,
+    chunks: Vec<Rect>,
+) {
+    // Left panel - Fields table
+    let header = Row::new(model.columns.iter().map(|column| {
+        let arrow = match (*column == model.sort, model.descending) {
+            (false, _) => "",
+            (true, false) => "▲",
+            (true, true) => "▼",
+        };
+        Cell::from(format!("{}{}", column.title(), arrow))
+    }))
+    .style(
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    );
+    let field_rows: Vec<Row> = model
+        .rows
         .iter()
-        .enumerate()
-        .map(|(i, field)| {
-            let is_selected = Some(field.as_str()) == app.selected_field.as_deref();
-            let is_cursor = i == app.field_list_state;
+        .map(|field| {
+            let prefix = if field.row.cursor { "► " } else { "  " };
+            let marker = if field.bookmarked { "★ " } else { "" };
+            let content = format!("{}{}", marker, field.row.label);
 
-            let prefix = if is_cursor { "► " } else { "  " };
-            let content = format!("{}{}", prefix, field);
-
-            let style = if is_selected {
+            let style = if field.row.selected {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD)
-            } else if is_cursor {
-                Style::default().fg(Color::Cyan)
+            } else if field.row.cursor {
+                Style::default().fg(theme.accent)
+            } else if field.sensitive {
+                crate::ui::sensitive::sensitive_style(theme)
             } else {
                 Style::default()
             };
 
-            ListItem::new(content).style(style)
+            // Attributes set in any declaring schema, e.g. [RO] [null]
+            let mut spans = vec![Span::raw(prefix)];
+            if field.marked {
+                spans.push(Span::styled("✓ ", Style::default().fg(theme.highlight)));
+            }
+            spans.push(Span::raw(content));
+            for flag in &field.flags {
+                spans.push(Span::styled(
+                    format!(" [{}]", flag.badge()),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            let mut cells = vec![Cell::from(Line::from(spans))];
+            for column in &model.columns[1..] {
+                let cell = field.columns.cell(*column);
+                cells.push(if *column == FieldColumn::Critical {
+                    Cell::from(cell).style(Style::default().fg(theme.critical))
+                } else {
+                    Cell::from(cell)
+                });
+            }
+            Row::new(cells).style(style)
         })
         .collect();
 
-    let fields_list = List::new(field_items).block(crate::ui::layout::panel_block(
-        "Fields",
-        app.current_panel == Panel::Left,
-    ));
+    let widths: Vec<Constraint> = model
+        .columns
+        .iter()
+        .map(|column| match column {
+            FieldColumn::Name => Constraint::Min(12),
+            column => Constraint::Length(column.title().chars().count() as u16 + 1),
+        })
+        .collect();
+    let fields_table =
+        Table::new(field_rows, widths)
+            .header(header)
+            .block(crate::ui::layout::panel_block(
+                theme,
+                "Fields",
+                model.panel == Panel::Left,
+            ));
+    let mut table_state =
+        TableState::default().with_selected(model.rows.iter().position(|r| r.row.cursor));
 
-    f.render_widget(fields_list, chunks[0]);
+    f.render_stateful_widget(fields_table, chunks[0], &mut table_state);
 
     // Center panel - Field details
-    if let Some(selected_field) = &app.selected_field {
-        if let Some(field_info) = app.get_field_info(selected_field) {
-            let details_text = vec![
+    if model.selected.is_some() {
+        if let Some(field_info) = &model.details {
+            let mut details_text = vec![
                 Line::from(vec![
-                    Span::styled("Field: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Field: ", Style::default().fg(theme.accent)),
                     Span::styled(
                         &field_info.name,
                         Style::default().add_modifier(Modifier::BOLD),
@@ -54,12 +109,12 @@ pub fn render_fields_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
                 ]),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Type: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Type: ", Style::default().fg(theme.accent)),
                     Span::styled(&field_info.field_type, Style::default()),
                 ]),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Description: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Description: ", Style::default().fg(theme.accent)),
                     Span::styled(
                         field_info
                             .description
@@ -70,7 +125,7 @@ pub fn render_fields_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
                 ]),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Used in schemas: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Used in schemas: ", Style::default().fg(theme.accent)),
                     Span::styled(
                         format!("{} schemas", field_info.schemas.len()),
                         Style::default(),
@@ -78,76 +133,191 @@ pub fn render_fields_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
                 ]),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Critical: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Critical: ", Style::default().fg(theme.accent)),
                     Span::styled(
                         if field_info.is_critical { "Yes" } else { "No" },
                         Style::default().fg(if field_info.is_critical {
-                            Color::Red
+                            theme.critical
                         } else {
-                            Color::Green
+                            theme.success
                         }),
                     ),
                 ]),
             ];
 
+            if !field_info.flags.is_empty() {
+                details_text.push(Line::from(""));
+                let attributes: Vec<String> = field_info
+                    .flags
+                    .iter()
+                    .map(|(schema, flags)| {
+                        let names: Vec<&str> = flags.iter().map(FieldFlag::badge).collect();
+                        format!("{} in {}", names.join("+"), schema)
+                    })
+                    .collect();
+                details_text.push(Line::from(vec![
+                    Span::styled("Attributes: ", Style::default().fg(theme.accent)),
+                    Span::styled(attributes.join(", "), Style::default().fg(theme.info)),
+                ]));
+            }
+
+            if !field_info.constraints.is_empty() {
+                details_text.push(Line::from(""));
+                details_text.push(Line::from(Span::styled(
+                    "Constraints:",
+                    Style::default().fg(theme.accent),
+                )));
+                for (schema, constraints) in &field_info.constraints {
+                    details_text.push(Line::from(vec![
+                        Span::styled(format!("  {}: ", schema), Style::default().fg(theme.muted)),
+                        Span::raw(constraints.join(", ")),
+                    ]));
+                }
+            }
+
+            if !field_info.values.is_empty() {
+                details_text.push(Line::from(""));
+                details_text.push(Line::from(Span::styled(
+                    "Defaults and examples:",
+                    Style::default().fg(theme.accent),
+                )));
+            }
+            for values in &field_info.values {
+                let mut spans = vec![Span::styled(
+                    format!("  {}: ", values.schema),
+                    Style::default().fg(theme.muted),
+                )];
+                if let Some(default) = &values.default {
+                    spans.push(Span::styled("default ", Style::default().fg(theme.info)));
+                    spans.push(Span::raw(format!("{}  ", default)));
+                }
+                if let Some(example) = &values.example {
+                    spans.push(Span::styled("example ", Style::default().fg(theme.info)));
+                    spans.push(Span::raw(example.clone()));
+                }
+                details_text.push(Line::from(spans));
+            }
+
+            if let Some(label) = &field_info.sensitive {
+                details_text.push(Line::from(""));
+                details_text.push(Line::from(vec![
+                    Span::styled("Sensitive: ", Style::default().fg(theme.accent)),
+                    Span::styled(
+                        format!("Yes ({})", label),
+                        crate::ui::sensitive::sensitive_style(theme),
+                    ),
+                ]));
+            }
+
+            // Details, then the schemas as a list jumping to the Schemas view
+            let center = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(details_text.len() as u16 + 2),
+                    Constraint::Min(3),
+                ])
+                .split(chunks[1]);
             let details_widget = Paragraph::new(details_text)
                 .wrap(Wrap { trim: true })
                 .block(crate::ui::layout::panel_block(
+                    theme,
                     "Field Details",
-                    app.current_panel == Panel::Center,
+                    model.panel == Panel::Center,
                 ));
-            f.render_widget(details_widget, chunks[1]);
+            f.render_widget(details_widget, center[0]);
+
+            let is_active = model.panel == Panel::Center;
+            let schema_items: Vec<ListItem> = field_info
+                .schemas
+                .iter()
+                .map(|schema| {
+                    let flags = field_info
+                        .flags
+                        .iter()
+                        .find(|(name, _)| name == schema)
+                        .map(|(_, flags)| *flags)
+                        .unwrap_or_default();
+                    let mut spans = vec![Span::raw(schema.as_str())];
+                    spans.extend(flags.iter().map(|flag| {
+                        Span::styled(
+                            format!(" [{}]", flag.badge()),
+                            Style::default().fg(theme.muted),
+                        )
+                    }));
+                    ListItem::new(Line::from(spans))
+                })
+                .collect();
+            let title = if is_active {
+                format!(
+                    "Used in schemas ({}) — Enter to open",
+                    field_info.schemas.len()
+                )
+            } else {
+                format!("Used in schemas ({})", field_info.schemas.len())
+            };
+            let schemas_list = List::new(schema_items)
+                .block(crate::ui::layout::panel_block(theme, &title, is_active))
+                .highlight_style(
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("► ");
+            let mut state =
+                ListState::default().with_selected(is_active.then_some(model.detail_cursor));
+            f.render_stateful_widget(schemas_list, center[1], &mut state);
         } else {
             let no_details = Paragraph::new("No field selected")
-                .style(Style::default().fg(Color::DarkGray))
+                .style(Style::default().fg(theme.muted))
                 .block(crate::ui::layout::panel_block(
+                    theme,
                     "Field Details",
-                    app.current_panel == Panel::Center,
+                    model.panel == Panel::Center,
                 ));
             f.render_widget(no_details, chunks[1]);
         }
     } else {
         let no_selection = Paragraph::new("Select a field to view details")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Field Details",
-                app.current_panel == Panel::Center,
+                model.panel == Panel::Center,
             ));
         f.render_widget(no_selection, chunks[1]);
     }
 
     // Right panel - Endpoints using this field
-    if let Some(selected_field) = &app.selected_field {
-        let endpoints = app.field_index.get_endpoints_for_field(selected_field);
+    if model.selected.is_some() {
+        let endpoints = model
+            .details
+            .as_ref()
+            .map_or(&[][..], |info| &info.endpoints[..]);
         let endpoint_items: Vec<ListItem> = endpoints
             .iter()
             .map(|endpoint| {
-                let is_critical = endpoint.to_lowercase().contains("post")
-                    || endpoint.to_lowercase().contains("put");
-                let style = if is_critical {
-                    Style::default().fg(Color::Red)
-                } else {
-                    Style::default()
-                };
-                ListItem::new(endpoint.as_str()).style(style)
+                ListItem::new(endpoint.as_str())
+                    .style(crate::ui::layout::endpoint_style(theme, endpoint))
             })
             .collect();
 
         let title = format!("Endpoints ({})", endpoints.len());
         let endpoints_list = List::new(endpoint_items)
             .block(crate::ui::layout::panel_block(
+                theme,
                 &title,
-                app.current_panel == Panel::Right,
+                model.panel == Panel::Right,
             ))
             .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
         f.render_widget(endpoints_list, chunks[2]);
     } else {
         let no_endpoints = Paragraph::new("Select a field to see related endpoints")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Endpoints",
-                app.current_panel == Panel::Right,
+                model.panel == Panel::Right,
             ));
         f.render_widget(no_endpoints, chunks[2]);
     }
@@ -0,0 +1,90 @@
+use crate::file_browser::FileBrowser;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render_file_browser_popup(f: &mut Frame, theme: &Theme, browser: &FileBrowser) {
+    let area = popup_area(f.area());
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            browser.dir.display().to_string(),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    // Scroll so the cursor stays visible (borders, header, footer and URL
+    // prompt take 7 rows)
+    let visible = area.height.saturating_sub(7).max(1) as usize;
+    let offset = browser.cursor.saturating_sub(visible - 1);
+    for (i, entry) in browser
+        .entries
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+    {
+        let is_cursor = i == browser.cursor;
+        let style = match (is_cursor, entry.is_dir) {
+            (true, _) => Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+            (false, true) => Style::default().fg(theme.info),
+            (false, false) => Style::default(),
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{}{}{}",
+                if is_cursor { "► " } else { "  " },
+                entry.name,
+                if entry.is_dir { "/" } else { "" }
+            ),
+            style,
+        )));
+    }
+    if browser.entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No sub-directories or spec files",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    let footer = match &browser.url {
+        Some(url) => {
+            lines.push(Line::from(vec![
+                Span::styled("URL: ", Style::default().fg(theme.accent)),
+                Span::raw(url.clone()),
+                Span::styled("█", Style::default().fg(theme.accent)),
+            ]));
+            "Enter: download and open  Esc: back to files"
+        }
+        None => "Enter: open  Backspace: parent directory  u: open a URL  Esc: close",
+    };
+    lines.push(Line::from(Span::styled(
+        footer,
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title("Open Spec"),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text));
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -1,166 +1,287 @@
-use crate::app::{App, Panel};
+use crate::app::{Panel, ScrollTarget, TextScrolls};
+use crate::clusters::FieldCluster;
+use crate::ui::theme::Theme;
+use crate::view_model::{GraphStats, GraphViewModel, SchemaDependencies};
 use ratatui::{
     layout::Rect,
-    text::Line,
-    widgets::{Paragraph, Wrap},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph, Wrap},
     Frame,
 };
 
-pub fn render_graph_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
-    // Left panel - Graph options
-    let options_text = vec![
-        Line::from("Graph Options"),
-        Line::from(""),
-        Line::from("• Field relationships"),
-        Line::from("• Schema dependencies"),
-        Line::from("• Endpoint connections"),
-        Line::from("• Critical path analysis"),
-        Line::from(""),
-        Line::from("Press 'g' to generate"),
-        Line::from("Press 's' to save"),
-    ];
+pub fn render_graph_view(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &GraphViewModel,
+    chunks: Vec<Rect>,
+) {
+    // Left panel - Schemas with fan-in/fan-out
+    let schema_items: Vec<ListItem> = model
+        .rows
+        .iter()
+        .map(|schema| {
+            let prefix = if schema.row.cursor { "► " } else { "  " };
+            let cycle_marker = if schema.in_cycle { " ↻" } else { "" };
+            let content = format!(
+                "{}{} (in:{} out:{}){}",
+                prefix, schema.row.label, schema.fan_in, schema.fan_out, cycle_marker
+            );
 
-    let options_widget = Paragraph::new(options_text)
-        .wrap(Wrap { trim: true })
-        .block(crate::ui::layout::panel_block(
-            "Options",
-            app.current_panel == Panel::Left,
-        ));
-    f.render_widget(options_widget, chunks[0]);
+            let style = if schema.row.selected {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else if schema.row.cursor {
+                Style::default().fg(theme.accent)
+            } else if schema.in_cycle {
+                Style::default().fg(theme.secondary)
+            } else {
+                Style::default()
+            };
 
-    // Center panel - ASCII graph visualization
-    let graph_text = generate_ascii_graph(app);
+            ListItem::new(content).style(style)
+        })
+        .collect();
 
-    let graph_widget =
-        Paragraph::new(graph_text)
-            .wrap(Wrap { trim: true })
-            .block(crate::ui::layout::panel_block(
-                "Field Relationship Graph",
-                app.current_panel == Panel::Center,
+    let schemas_list = List::new(schema_items).block(crate::ui::layout::panel_block(
+        theme,
+        "Schemas",
+        model.panel == Panel::Left,
+    ));
+    f.render_widget(schemas_list, chunks[0]);
+
+    // Center panel - Dependencies of the selected schema
+    let dependency_text = match &model.dependencies {
+        Some(dependencies) => {
+            let mut lines = generate_dependency_tree(theme, dependencies);
+            lines.extend(generate_schema_clusters(
+                theme,
+                &model.clusters,
+                &dependencies.schema,
             ));
-    f.render_widget(graph_widget, chunks[1]);
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "No schemas to display",
+            Style::default().fg(theme.muted),
+        ))],
+    };
 
-    // Right panel - Graph statistics
-    let stats_text = vec![
-        Line::from("Graph Statistics"),
-        Line::from(""),
-        Line::from(format!("Total Nodes: {}", app.field_index.fields.len())),
-        Line::from(format!("Total Schemas: {}", app.field_index.schemas.len())),
-        Line::from(format!("Total Endpoints: {}", app.openapi_spec.paths.len())),
-        Line::from(""),
-        Line::from("Critical Fields:"),
-        Line::from(format!("  • {} high-impact", count_critical_fields(app))),
-        Line::from(""),
-        Line::from("Most Connected:"),
-        Line::from(format!("  • {}", get_most_connected_field(app))),
-        Line::from(""),
-        Line::from("Graph Density:"),
-        Line::from(format!("  • {:.2}%", calculate_graph_density(app))),
-    ];
+    let dependency_widget = Paragraph::new(dependency_text)
+        .wrap(Wrap { trim: false })
+        .block(crate::ui::layout::panel_block(
+            theme,
+            "Schema Dependencies",
+            model.panel == Panel::Center,
+        ));
+    crate::ui::layout::render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::SchemaDependencies,
+        model
+            .dependencies
+            .as_ref()
+            .map_or("", |dependencies| dependencies.schema.as_str()),
+        dependency_widget,
+        chunks[1],
+    );
 
-    let stats_widget =
-        Paragraph::new(stats_text)
-            .wrap(Wrap { trim: true })
-            .block(crate::ui::layout::panel_block(
-                "Statistics",
-                app.current_panel == Panel::Right,
-            ));
+    // Right panel - Graph statistics
+    let stats_widget = Paragraph::new(generate_graph_stats(theme, &model.stats, &model.clusters))
+        .wrap(Wrap { trim: true })
+        .block(crate::ui::layout::panel_block(
+            theme,
+            "Statistics",
+            model.panel == Panel::Right,
+        ));
     f.render_widget(stats_widget, chunks[2]);
 }
 
-fn generate_ascii_graph(app: &App) -> Vec<Line> {
-    let mut lines = vec![Line::from("Field Relationship Graph"), Line::from("")];
+fn generate_dependency_tree<'a>(
+    theme: &Theme,
+    dependencies: &'a SchemaDependencies,
+) -> Vec<Line<'a>> {
+    let schema_name = dependencies.schema.as_str();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Schema: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                schema_name.to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+    ];
 
-    if app.field_index.fields.is_empty() {
-        lines.push(Line::from("No fields to display"));
-        return lines;
+    if let Some(cycle) = &dependencies.cycle {
+        lines.push(Line::from(Span::styled(
+            format!("↻ Part of cycle: {}", cycle.join(" ↔ ")),
+            Style::default().fg(theme.secondary),
+        )));
+        lines.push(Line::from(""));
     }
 
-    // Simple ASCII graph showing field relationships
-    let mut field_count = 0;
-    let max_fields = std::cmp::min(10, app.field_index.fields.len()); // Limit display
-
-    lines.push(Line::from("┌─ Field Dependencies ──────────────────┐"));
-
-    for (field_name, field_data) in &app.field_index.fields {
-        if field_count >= max_fields {
-            break;
-        }
-
-        let is_critical = app.field_index.is_critical_field(field_name);
-        let marker = if is_critical { "🔴" } else { "⚪" };
-
-        lines.push(Line::from(format!(
-            "│ {} {} ({} schemas)",
-            marker,
-            field_name,
-            field_data.schemas.len()
+    // Outgoing references
+    let outgoing = &dependencies.references;
+    lines.push(Line::from(Span::styled(
+        format!("References (fan-out: {})", dependencies.fan_out),
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::UNDERLINED),
+    )));
+    if outgoing.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (none)",
+            Style::default().fg(theme.muted),
         )));
-
-        // Show connections to other fields (simplified)
-        if field_data.schemas.len() > 1 {
-            lines.push(Line::from("│    ├─ Shared schemas"));
-            for schema in &field_data.schemas {
-                if field_data.schemas.len() > 2 {
-                    lines.push(Line::from(format!("│    │  • {}", schema)));
-                }
-            }
-        }
-
-        field_count += 1;
     }
-
-    lines.push(Line::from("└──────────────────────────────────────┘"));
+    for (i, edge) in outgoing.iter().enumerate() {
+        let branch = if i + 1 == outgoing.len() {
+            "└─"
+        } else {
+            "├─"
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", branch)),
+            Span::styled(edge.target.clone(), Style::default().fg(theme.success)),
+            Span::styled(
+                format!("  via {}", edge.via),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
     lines.push(Line::from(""));
 
-    if app.field_index.fields.len() > max_fields {
-        lines.push(Line::from(format!(
-            "... and {} more fields",
-            app.field_index.fields.len() - max_fields
+    // Incoming references
+    let incoming = &dependencies.referenced_by;
+    lines.push(Line::from(Span::styled(
+        format!("Referenced by (fan-in: {})", dependencies.fan_in),
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::UNDERLINED),
+    )));
+    if incoming.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (none)",
+            Style::default().fg(theme.muted),
         )));
     }
-
-    lines.push(Line::from(""));
-    lines.push(Line::from("Legend: 🔴 Critical field  ⚪ Regular field"));
+    for (i, (source, via)) in incoming.iter().enumerate() {
+        let branch = if i + 1 == incoming.len() {
+            "└─"
+        } else {
+            "├─"
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", branch)),
+            Span::styled(source.clone(), Style::default().fg(theme.info)),
+            Span::styled(format!("  .{}", via), Style::default().fg(theme.muted)),
+        ]));
+    }
 
     lines
 }
 
-fn count_critical_fields(app: &App) -> usize {
-    app.field_index
-        .fields
-        .keys()
-        .filter(|field| app.field_index.is_critical_field(field))
-        .count()
-}
-
-fn get_most_connected_field(app: &App) -> String {
-    app.field_index
-        .fields
+/// Field clusters the schema declares, as value-object candidates.
+fn generate_schema_clusters<'a>(
+    theme: &Theme,
+    clusters: &[FieldCluster],
+    schema_name: &str,
+) -> Vec<Line<'a>> {
+    let clusters: Vec<&FieldCluster> = clusters
         .iter()
-        .max_by_key(|(_, field_data)| field_data.schemas.len())
-        .map(|(name, _)| name.clone())
-        .unwrap_or_else(|| "None".to_string())
-}
-
-fn calculate_graph_density(app: &App) -> f64 {
-    let total_fields = app.field_index.fields.len();
-    if total_fields == 0 {
-        return 0.0;
+        .filter(|cluster| cluster.schemas.iter().any(|s| s == schema_name))
+        .collect();
+    if clusters.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Fields always declared together ({})", clusters.len()),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )),
+    ];
+    for cluster in clusters {
+        let others: Vec<&str> = cluster
+            .schemas
+            .iter()
+            .filter(|s| *s != schema_name)
+            .map(String::as_str)
+            .collect();
+        lines.push(Line::from(vec![
+            Span::raw("  • "),
+            Span::styled(cluster.label(), Style::default().fg(theme.success)),
+            Span::styled(
+                format!("  also in {}", others.join(", ")),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
     }
+    lines
+}
 
-    let total_connections: usize = app
-        .field_index
-        .fields
-        .values()
-        .map(|field_data| field_data.schemas.len())
-        .sum();
+fn generate_graph_stats<'a>(
+    theme: &Theme,
+    stats: &GraphStats,
+    clusters: &[FieldCluster],
+) -> Vec<Line<'a>> {
+    let mut lines = vec![
+        Line::from("Graph Statistics"),
+        Line::from(""),
+        Line::from(format!("Schemas: {}", stats.schemas)),
+        Line::from(format!("References: {}", stats.references)),
+        Line::from(format!("Isolated: {}", stats.isolated)),
+        Line::from(""),
+    ];
 
-    let max_possible_connections = total_fields * (total_fields - 1) / 2;
+    for (heading, ranked) in [
+        ("Most referenced:", &stats.most_referenced),
+        ("Most dependencies:", &stats.most_dependencies),
+    ] {
+        lines.push(Line::from(heading));
+        if ranked.is_empty() {
+            lines.push(Line::from("  • None"));
+        }
+        for (name, count) in ranked {
+            lines.push(Line::from(format!("  • {} ({})", name, count)));
+        }
+        lines.push(Line::from(""));
+    }
 
-    if max_possible_connections == 0 {
-        0.0
+    if stats.cycles.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "✓ No reference cycles",
+            Style::default().fg(theme.success),
+        )));
     } else {
-        (total_connections as f64 / max_possible_connections as f64) * 100.0
+        lines.push(Line::from(Span::styled(
+            format!("↻ Cycles: {}", stats.cycles.len()),
+            Style::default().fg(theme.secondary),
+        )));
+        for cycle in &stats.cycles {
+            lines.push(Line::from(format!("  • {}", cycle.join(" ↔ "))));
+        }
+    }
+    lines.push(Line::from(""));
+
+    // Groups of fields repeated across schemas (:export clusters <file>)
+    lines.push(Line::from(format!(
+        "Value-object candidates: {}",
+        clusters.len()
+    )));
+    for cluster in clusters.iter().take(5) {
+        lines.push(Line::from(format!(
+            "  • {} ×{}",
+            cluster.label(),
+            cluster.schemas.len()
+        )));
     }
+
+    lines
 }
@@ -0,0 +1,128 @@
+use crate::app::{Panel, ScrollTarget, TextScrolls};
+use crate::ui::theme::Theme;
+use crate::view_model::HeadersViewModel;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_headers_view(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &HeadersViewModel,
+    chunks: Vec<Rect>,
+) {
+    // Left panel - Response headers list
+    let header_items: Vec<ListItem> = model
+        .rows
+        .iter()
+        .map(|header| {
+            let prefix = if header.cursor { "► " } else { "  " };
+            let style = if header.selected {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else if header.cursor {
+                Style::default().fg(theme.accent)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(format!("{}{}", prefix, header.label)).style(style)
+        })
+        .collect();
+
+    let headers_list = List::new(header_items).block(crate::ui::layout::panel_block(
+        theme,
+        &model.title,
+        model.panel == Panel::Left,
+    ));
+    f.render_widget(headers_list, chunks[0]);
+
+    // Center panel - Header details
+    let Some(details) = &model.details else {
+        let no_selection = Paragraph::new("Select a header to view details")
+            .style(Style::default().fg(theme.muted))
+            .block(crate::ui::layout::panel_block(
+                theme,
+                "Header Details",
+                model.panel == Panel::Center,
+            ));
+        f.render_widget(no_selection, chunks[1]);
+
+        let no_endpoints = Paragraph::new("Select a header to see the responses declaring it")
+            .style(Style::default().fg(theme.muted))
+            .block(crate::ui::layout::panel_block(
+                theme,
+                "Endpoints",
+                model.panel == Panel::Right,
+            ));
+        f.render_widget(no_endpoints, chunks[2]);
+        return;
+    };
+
+    let (name, data) = (&details.name, &details.header);
+    let details_text = vec![
+        Line::from(vec![
+            Span::styled("Header: ", Style::default().fg(theme.accent)),
+            Span::styled(name.as_str(), Style::default().add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Type: ", Style::default().fg(theme.accent)),
+            Span::raw(data.header_type.as_str()),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Description: ", Style::default().fg(theme.accent)),
+            Span::raw(data.description.as_deref().unwrap_or("No description")),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Returned by: ", Style::default().fg(theme.accent)),
+            Span::raw(format!("{} endpoint(s)", data.endpoints.len())),
+        ]),
+    ];
+    let details_widget = Paragraph::new(details_text)
+        .wrap(Wrap { trim: true })
+        .block(crate::ui::layout::panel_block(
+            theme,
+            "Header Details",
+            model.panel == Panel::Center,
+        ));
+    crate::ui::layout::render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::HeaderDetails,
+        name,
+        details_widget,
+        chunks[1],
+    );
+
+    // Right panel - Endpoints and status codes returning the header
+    let endpoint_items: Vec<ListItem> = data
+        .endpoints
+        .iter()
+        .map(|(endpoint, statuses)| {
+            ListItem::new(Line::from(vec![
+                Span::raw(endpoint.as_str()),
+                Span::styled(
+                    format!("  {}", statuses.join(", ")),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+        })
+        .collect();
+    let title = format!("Endpoints ({})", data.endpoints.len());
+    let endpoints_list = List::new(endpoint_items).block(crate::ui::layout::panel_block(
+        theme,
+        &title,
+        model.panel == Panel::Right,
+    ));
+    f.render_widget(endpoints_list, chunks[2]);
+}
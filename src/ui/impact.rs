@@ -0,0 +1,112 @@
+use crate::impact::{ImpactReport, UsageLocation};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_impact_popup(f: &mut Frame, theme: &Theme, report: &ImpactReport) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!(
+                "Impact of {} '{}'",
+                report.target.kind(),
+                report.target.name()
+            ),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} endpoint(s) affected • {} via request • {} via response",
+            report.endpoints.len(),
+            report.request_count(),
+            report.response_count()
+        )),
+        Line::from(""),
+    ];
+
+    if report.endpoints.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No endpoint uses this item",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    for (i, endpoint) in report.endpoints.iter().enumerate() {
+        let last_endpoint = i + 1 == report.endpoints.len();
+        let (branch, indent) = if last_endpoint {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+
+        let style = if endpoint.is_write() {
+            Style::default().fg(theme.critical)
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        };
+        lines.push(Line::from(vec![
+            Span::raw(branch),
+            Span::styled(endpoint.endpoint.clone(), style),
+        ]));
+
+        for (j, usage) in endpoint.usages.iter().enumerate() {
+            let leaf = if j + 1 == endpoint.usages.len() {
+                "└─ "
+            } else {
+                "├─ "
+            };
+            let (label, color) = match &usage.location {
+                UsageLocation::Parameter { location } => {
+                    (format!("Parameter ({})", location), theme.success)
+                }
+                UsageLocation::Request { content_type } => {
+                    (format!("Request {}", content_type), theme.critical)
+                }
+                UsageLocation::Response {
+                    status,
+                    content_type,
+                } => (format!("Response {} {}", status, content_type), theme.info),
+            };
+
+            let mut spans = vec![
+                Span::raw(format!("{}{}", indent, leaf)),
+                Span::styled(label, Style::default().fg(color)),
+            ];
+            if let Some(via) = &usage.via {
+                spans.push(Span::styled(
+                    format!("  via {}", via),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Impact Analysis "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -1,7 +1,13 @@
+use crate::app::{fit_text_scroll, ScrollTarget, TextScrolls};
+use crate::indexer::HttpMethod;
+use crate::parser::ExternalDocs;
+use crate::ui::theme::Theme;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
 };
 
 pub fn create_main_layout(area: Rect) -> Vec<Rect> {
@@ -16,9 +22,20 @@ pub fn create_main_layout(area: Rect) -> Vec<Rect> {
         .to_vec()
 }
 
+/// Terminals narrower than this stack the main panels and widen popups
+pub const NARROW_WIDTH: u16 = 100;
+
+/// The three main panels: side by side, or stacked top to bottom on
+/// terminals narrower than [`NARROW_WIDTH`] where columns would be too
+/// thin to read.
 pub fn create_three_column_layout(area: Rect) -> Vec<Rect> {
+    let direction = if area.width < NARROW_WIDTH {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    };
     Layout::default()
-        .direction(Direction::Horizontal)
+        .direction(direction)
         .constraints([
             Constraint::Percentage(30), // Left panel
             Constraint::Percentage(40), // Center panel
@@ -28,6 +45,22 @@ pub fn create_three_column_layout(area: Rect) -> Vec<Rect> {
         .to_vec()
 }
 
+/// Width of the spec list left of the panels when watching a directory
+pub const SPEC_RAIL_WIDTH: u16 = 26;
+
+/// The spec rail and the area left for the panels: a rail only while
+/// watching spec directories, on terminals wide enough for it.
+pub fn split_spec_rail(area: Rect, spec_dirs: bool) -> (Option<Rect>, Rect) {
+    if !spec_dirs || area.width < NARROW_WIDTH {
+        return (None, area);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(SPEC_RAIL_WIDTH), Constraint::Min(0)])
+        .split(area);
+    (Some(chunks[0]), chunks[1])
+}
+
 pub fn create_two_column_layout(area: Rect) -> Vec<Rect> {
     Layout::default()
         .direction(Direction::Horizontal)
@@ -39,10 +72,56 @@ pub fn create_two_column_layout(area: Rect) -> Vec<Rect> {
         .to_vec()
 }
 
-pub fn panel_block(title: &str, is_active: bool) -> Block {
+/// Area of a popup over `area`: three quarters of the width (all of it on
+/// narrow terminals) and all the height but a row above and below.
+pub fn popup_area(area: Rect) -> Rect {
+    popup_area_with_height(area, area.height.saturating_sub(2))
+}
+
+/// [`popup_area`] at most `height` rows high, centered vertically.
+pub fn popup_area_with_height(area: Rect, height: u16) -> Rect {
+    let width = if area.width < NARROW_WIDTH {
+        area.width
+    } else {
+        area.width * 3 / 4
+    };
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Write operations in their method color, reads in the default style.
+pub fn endpoint_style(theme: &Theme, endpoint: &str) -> Style {
+    match HttpMethod::of_endpoint(endpoint) {
+        Some(method) if method.is_write() => Style::default().fg(theme.method_color(method)),
+        _ => Style::default(),
+    }
+}
+
+/// `Docs:` line of a detail panel, with the description when given.
+pub fn docs_line(theme: &Theme, docs: &ExternalDocs) -> Line<'static> {
+    let mut spans = vec![Span::styled("Docs: ", Style::default().fg(theme.accent))];
+    if let Some(description) = &docs.description {
+        spans.push(Span::raw(format!("{} ", description)));
+    }
+    spans.push(Span::styled(
+        docs.url.clone(),
+        Style::default()
+            .fg(theme.info)
+            .add_modifier(Modifier::UNDERLINED),
+    ));
+    spans.push(Span::styled(" (O opens)", Style::default().fg(theme.muted)));
+    Line::from(spans)
+}
+
+pub fn panel_block<'a>(theme: &Theme, title: &'a str, is_active: bool) -> Block<'a> {
     let style = if is_active {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
@@ -54,15 +133,70 @@ pub fn panel_block(title: &str, is_active: bool) -> Block {
         .border_style(style)
 }
 
-pub fn search_bar_block() -> Block<'static> {
+pub fn search_bar_block(theme: &Theme) -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
         .title("Search")
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.accent))
 }
 
-pub fn status_bar_block() -> Block<'static> {
+pub fn status_bar_block(theme: &Theme) -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(Style::default().fg(theme.muted))
+}
+
+/// Renders a bordered `paragraph` scrolled to the position of `target`,
+/// with a scrollbar on the right border when it does not fit. `subject`
+/// identifies what is shown: showing another one scrolls back to the top.
+pub fn render_scrolled_paragraph(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    target: ScrollTarget,
+    subject: &str,
+    paragraph: Paragraph,
+    area: Rect,
+) {
+    let content_rows = paragraph.line_count(area.width.saturating_sub(2));
+    let scroll = fit_text_scroll(
+        scrolls,
+        target,
+        subject,
+        content_rows.min(u16::MAX as usize) as u16,
+        area.height,
+    );
+    f.render_widget(paragraph.scroll((scroll.offset, 0)), area);
+    if scroll.max_offset > 0 {
+        render_scrollbar(
+            f,
+            theme,
+            area,
+            scroll.max_offset as usize + 1,
+            scroll.offset as usize,
+        );
+    }
+}
+
+/// Scrollbar on the right border of `area` for `content_length` positions
+pub fn render_scrollbar(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    content_length: usize,
+    position: usize,
+) {
+    let mut state = ScrollbarState::new(content_length).position(position);
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .thumb_style(Style::default().fg(theme.accent))
+            .track_style(Style::default().fg(theme.muted)),
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut state,
+    );
 }
@@ -0,0 +1,99 @@
+use crate::lint::{LintFinding, LintRule};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+fn rule_label(rule: LintRule) -> &'static str {
+    match rule {
+        LintRule::UndeclaredPathParameter => "undeclared path param",
+        LintRule::UnusedPathParameter => "unused path param",
+        LintRule::InconsistentPathParameter => "path param naming",
+        LintRule::MissingOperationId => "missing operationId",
+        LintRule::DuplicateOperationId => "duplicate operationId",
+        LintRule::OperationIdNaming => "operationId naming",
+        LintRule::ReadOnlyRequiredInRequest => "readOnly required",
+        LintRule::WriteOnlyInResponse => "writeOnly in response",
+        LintRule::UnboundedString => "no maxLength",
+        LintRule::UnboundedArray => "no maxItems",
+        LintRule::MetaSchema => "meta-schema",
+    }
+}
+
+pub fn render_lint_picker(f: &mut Frame, theme: &Theme, findings: &[LintFinding], cursor: usize) {
+    let area = popup_area(f.area());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Lint Findings ")
+        .style(Style::default().bg(theme.background).fg(theme.text));
+    let inner = block.inner(area);
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![Span::styled(
+            format!(
+                "{} finding(s) on operations, paths and schemas",
+                findings.len()
+            ),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )])),
+        chunks[0],
+    );
+
+    let items: Vec<ListItem> = findings
+        .iter()
+        .map(|finding| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<22} ", rule_label(finding.rule)),
+                    Style::default().fg(theme.critical),
+                ),
+                Span::styled(
+                    finding.location.as_str(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", finding.message),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("► ");
+    let mut state = ListState::default().with_selected(Some(cursor));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![Span::styled(
+            "↑/↓ to choose, Enter to show it in the Endpoints view, 'Esc' to close",
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )])),
+        chunks[2],
+    );
+}
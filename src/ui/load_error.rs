@@ -0,0 +1,116 @@
+use crate::parse_error::ParseError;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::path::Path;
+
+/// Why `path` could not be loaded: for an invalid document, where the
+/// error is and the lines around it, else the error message alone.
+/// `keys` describes the keys available, e.g. retrying.
+pub fn render_load_error(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    path: &Path,
+    error: &anyhow::Error,
+    keys: &str,
+) {
+    let mut lines = Vec::new();
+    match error.downcast_ref::<ParseError>() {
+        Some(parse_error) => {
+            let mut location = vec![Span::styled(
+                format!("Line {}, column {}", parse_error.line, parse_error.column),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            if let Some(pointer) = &parse_error.pointer {
+                location.push(Span::raw("  "));
+                location.push(Span::styled(
+                    pointer.clone(),
+                    Style::default().fg(theme.info),
+                ));
+            }
+            lines.push(Line::from(location));
+            lines.push(Line::from(Span::styled(
+                parse_error.message.clone(),
+                Style::default().fg(theme.critical),
+            )));
+            lines.push(Line::from(""));
+            lines.extend(snippet_lines(theme, parse_error));
+        }
+        None => lines.push(Line::from(Span::styled(
+            error.to_string(),
+            Style::default().fg(theme.critical),
+        ))),
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        keys.to_string(),
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.critical))
+                .title(format!(" Could not load {} ", name)),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
+
+/// Numbered source lines, the failing one highlighted and followed by a
+/// caret under the error column.
+fn snippet_lines<'a>(theme: &Theme, parse_error: &'a ParseError) -> Vec<Line<'a>> {
+    let width = parse_error
+        .snippet
+        .last()
+        .map_or(1, |(number, _)| number.to_string().len());
+    let mut lines = Vec::new();
+    for (number, text) in &parse_error.snippet {
+        let failing = *number == parse_error.line;
+        let text_style = if failing {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>width$} │ ", number, width = width),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(text.as_str(), text_style),
+        ]));
+        if failing {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:>width$} │ ", "", width = width),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!("{}^", " ".repeat(parse_error.snippet_column - 1)),
+                    Style::default()
+                        .fg(theme.critical)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+    }
+    lines
+}
@@ -1,85 +1,239 @@
+pub mod compare;
+pub mod content_types;
+pub mod coverage;
+pub mod description;
+pub mod duplicates;
 pub mod endpoints;
 pub mod fields;
+pub mod file_browser;
 pub mod graph;
+pub mod headers;
+pub mod impact;
 pub mod layout;
+pub mod lint;
+pub mod load_error;
+pub mod notifications;
+pub mod pagination;
+pub mod palette;
+pub mod rename;
+pub mod responses;
+pub mod saved_views;
 pub mod schemas;
+pub mod sensitive;
+pub mod servers;
+pub mod spec_info;
+pub mod specs;
+pub mod splash;
+pub mod stats;
+pub mod theme;
+pub mod timeline;
+pub mod try_it_out;
+pub mod validation;
+pub mod workflows;
 
-use crate::app::{App, Panel, View};
+use crate::app::{App, LoadRequest, ScrollTarget, TextScrolls, View};
+use crate::events::AppEvent;
+use crate::progress::LoadStage;
+use crate::ui::theme::Theme;
+use crate::view_model::EndpointPopupViewModel;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame, Terminal,
 };
 use std::io;
-use std::time::{Duration, Instant};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+const SEARCH_PROMPT: &str = "Search: ";
+/// Longest wait for an event before redrawing
+pub const TICK_RATE: Duration = Duration::from_millis(250);
+/// How often the input task checks whether the UI is gone
+const INPUT_POLL: Duration = Duration::from_millis(100);
 
 pub async fn run(app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
+    // Reports Ctrl+I apart from Tab, on terminals that support it
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut last_tick = Instant::now();
-    let tick_rate = Duration::from_millis(250);
+    // Input, spec loads and requests all report back through one channel,
+    // so parsing and network calls never block rendering
+    let (events, mut receiver) = mpsc::unbounded_channel();
+    spawn_input_reader(events.clone());
+    let mut loading = false;
+    // Kept alive for the session: dropping it stops the watching
+    let _watcher = watch_spec_dirs(app, events.clone());
 
     loop {
-        // Handle reload request
-        if app.should_reload {
-            app.should_reload = false;
-            match app.reload().await {
-                Ok(_) => {
-                    // Reload successful - the app state is updated
-                }
-                Err(_) => {
-                    // Error is stored in app.reload_error
-                }
+        // One load at a time: later requests wait for the current one
+        if !loading {
+            if let Some(request) = app.take_load_request() {
+                loading = true;
+                spawn_spec_load(request, events.clone());
             }
         }
 
-        // Handle input
-        if event::poll(tick_rate - last_tick.elapsed())? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    handle_key_events(key, app);
-                }
-            }
+        if let Some(draft) = app.take_try_it_out_request() {
+            let events = events.clone();
+            tokio::spawn(async move {
+                let response = crate::runner::execute(&draft)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = events.send(AppEvent::TryItOutResponse(response));
+            });
         }
 
-        if last_tick.elapsed() >= tick_rate {
-            last_tick = Instant::now();
+        if let Some(text) = app.take_clipboard() {
+            if let Err(e) = crate::clipboard::copy(&text) {
+                app.notify(
+                    crate::notifications::Level::Error,
+                    format!("Copy failed: {}", e),
+                );
+            }
         }
 
+        // Start debounced searches and swap in finished results
+        app.poll_search();
+
         // Render UI
         terminal.draw(|f| ui(f, app))?;
 
         if app.should_quit {
             break;
         }
+
+        // Wait for the next event, waking up early while a search is pending
+        let timeout = if app.search.is_pending() {
+            crate::search::SEARCH_DEBOUNCE
+        } else {
+            app.tick_rate
+        };
+        let event = match tokio::time::timeout(timeout, receiver.recv()).await {
+            Ok(Some(event)) => event,
+            Ok(None) => break,
+            Err(_) => AppEvent::Tick,
+        };
+        handle_app_event(event, app, &mut loading);
+        // Drain what queued up meanwhile so bursts cost a single redraw
+        while let Ok(event) = receiver.try_recv() {
+            handle_app_event(event, app, &mut loading);
+        }
     }
+    // Closing the channel stops the input task
+    drop(receiver);
 
     // Restore terminal
+    if keyboard_enhanced {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
     Ok(())
 }
 
+/// Reads terminal input on its own thread, since crossterm reads block.
+/// The thread ends once the event loop has dropped its receiver.
+fn spawn_input_reader(events: UnboundedSender<AppEvent>) {
+    std::thread::spawn(move || {
+        while !events.is_closed() {
+            match event::poll(INPUT_POLL) {
+                Ok(true) => match event::read() {
+                    Ok(input) => {
+                        if events.send(AppEvent::Input(input)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                },
+                Ok(false) => {}
+                Err(_) => break,
+            }
+        }
+    });
+}
+
+/// Watches the spec directories of the session, if any, reporting changed
+/// files as events.
+fn watch_spec_dirs(
+    app: &mut App,
+    events: UnboundedSender<AppEvent>,
+) -> Option<notify::RecommendedWatcher> {
+    if app.spec_dirs.is_empty() {
+        return None;
+    }
+    let watcher = crate::spec_dir::watch(&app.spec_dirs, move |paths| {
+        let _ = events.send(AppEvent::SpecFilesChanged(paths));
+    });
+    match watcher {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            app.notify(
+                crate::notifications::Level::Error,
+                format!("Cannot watch the spec directory: {}", e),
+            );
+            None
+        }
+    }
+}
+
+/// Runs a spec load as a task, streaming its stages to the status bar.
+fn spawn_spec_load(request: LoadRequest, events: UnboundedSender<AppEvent>) {
+    tokio::spawn(async move {
+        let progress_events = events.clone();
+        let progress = move |stage: LoadStage| {
+            let _ = progress_events.send(AppEvent::LoadProgress(stage));
+        };
+        let load = request.run(&progress).await;
+        let _ = events.send(AppEvent::SpecLoaded(Box::new(load)));
+    });
+}
+
+fn handle_app_event(event: AppEvent, app: &mut App, loading: &mut bool) {
+    if matches!(event, AppEvent::SpecLoaded(_)) {
+        *loading = false;
+    }
+    app.handle_event(event);
+}
+
 fn ui(f: &mut Frame, app: &mut App) {
+    let theme = app.theme;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -89,48 +243,133 @@ fn ui(f: &mut Frame, app: &mut App) {
         ])
         .split(f.area());
 
-    // Search bar
-    let search_text = Paragraph::new(format!("Search: {}", app.search_query))
-        .style(Style::default().fg(Color::Yellow))
-        .block(Block::default().borders(Borders::ALL));
+    // Search bar, with a cursor while it has focus
+    let search_block = match app.search_cursor {
+        Some(_) => Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.highlight))
+            .title(" Enter/Esc: done  Ctrl+W: delete word  Ctrl+U: clear "),
+        None => Block::default().borders(Borders::ALL),
+    };
+    let search_text = Paragraph::new(format!(
+        "{}{}{}",
+        SEARCH_PROMPT,
+        app.search_query,
+        if app.search.is_pending() { " …" } else { "" }
+    ))
+    .style(Style::default().fg(theme.highlight))
+    .block(search_block);
     f.render_widget(search_text, chunks[0]);
+    if let Some(cursor) = app.search_cursor {
+        let column = (SEARCH_PROMPT.len() + cursor) as u16;
+        f.set_cursor_position((
+            (chunks[0].x + 1 + column).min(chunks[0].right().saturating_sub(2)),
+            chunks[0].y + 1,
+        ));
+    }
 
-    // Main content area
-    let main_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(30),
-            Constraint::Percentage(40),
-            Constraint::Percentage(30),
-        ])
-        .split(chunks[1]);
+    // Main content area, stacked on narrow terminals, right of the spec
+    // rail when watching spec directories
+    let (rail, content) = layout::split_spec_rail(chunks[1], !app.spec_dirs.is_empty());
+    if let Some(rail) = rail {
+        specs::render_spec_rail(f, &theme, &app.spec_list_view_model(), rail);
+    }
+    let main_chunks = layout::create_three_column_layout(content);
 
     match app.current_view {
-        View::Fields => fields::render_fields_view(f, app, main_chunks.to_vec()),
-        View::Schemas => schemas::render_schemas_view(f, app, main_chunks.to_vec()),
-        View::Endpoints => endpoints::render_endpoints_view(f, app, main_chunks.to_vec()),
-        View::Graph => graph::render_graph_view(f, app, main_chunks.to_vec()),
-        View::Stats => render_stats_view(f, app, main_chunks.to_vec()),
+        View::Fields => {
+            let model = app.fields_view_model();
+            fields::render_fields_view(f, &theme, &model, main_chunks.to_vec())
+        }
+        View::Schemas => {
+            let model = app.schemas_view_model();
+            schemas::render_schemas_view(f, &theme, &model, main_chunks.to_vec())
+        }
+        View::Endpoints => {
+            let model = app.endpoints_view_model();
+            let scrolls = &app.text_scroll;
+            endpoints::render_endpoints_view(f, &theme, scrolls, &model, main_chunks.to_vec())
+        }
+        View::Graph => {
+            let model = app.graph_view_model();
+            graph::render_graph_view(f, &theme, &app.text_scroll, &model, main_chunks.to_vec())
+        }
+        View::Stats => {
+            let model = app.stats_view_model();
+            stats::render_stats_view(f, &theme, &model, main_chunks.to_vec())
+        }
+        View::Headers => {
+            let model = app.headers_view_model();
+            headers::render_headers_view(f, &theme, &app.text_scroll, &model, main_chunks.to_vec())
+        }
+        View::Responses => {
+            let model = app.responses_view_model();
+            responses::render_responses_view(f, &theme, &model, main_chunks.to_vec())
+        }
     }
 
     // Status bar
     let mut status_text = vec![
-        Span::styled("h:Help", Style::default().fg(Color::Cyan)),
+        Span::styled("h:Help", Style::default().fg(theme.accent)),
         Span::raw("  "),
-        Span::styled("r:Reload", Style::default().fg(Color::Cyan)),
+        Span::styled("r:Reload", Style::default().fg(theme.accent)),
         Span::raw("  "),
-        Span::styled("q:Quit", Style::default().fg(Color::Red)),
+        Span::styled("q:Quit", Style::default().fg(theme.critical)),
         Span::raw("  "),
         Span::styled(
             format!("View: {:?}", app.current_view),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         ),
         Span::raw("  "),
         Span::styled(
             format!("Panel: {:?}", app.current_panel),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         ),
     ];
+    if let Some(method) = app.method_filter {
+        status_text.push(Span::raw("  "));
+        status_text.push(Span::styled(
+            format!("Method: {}", method),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if !app.field_flag_filters.is_empty() {
+        let flags: Vec<String> = app
+            .field_flag_filters
+            .iter()
+            .map(|(flag, value)| format!("{}:{}", flag.name(), value))
+            .collect();
+        status_text.push(Span::raw("  "));
+        status_text.push(Span::styled(
+            flags.join(" "),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(tag) = &app.tag_filter {
+        status_text.push(Span::raw("  "));
+        status_text.push(Span::styled(
+            format!("Tag: {}", tag),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.specs.len() > 1 {
+        status_text.push(Span::raw("  "));
+        status_text.push(Span::styled(
+            format!(
+                "Spec: {} ({}/{})",
+                app.active_spec_name(),
+                app.active_spec + 1,
+                app.specs.len()
+            ),
+            Style::default().fg(theme.secondary),
+        ));
+    }
 
     // Add loading, reload status or error message
     if app.is_loading && !app.loading_message.is_empty() {
@@ -138,280 +377,301 @@ fn ui(f: &mut Frame, app: &mut App) {
         status_text.push(Span::styled(
             format!("⟳ {}", app.loading_message),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         ));
     } else if app.should_reload {
         status_text.push(Span::raw("  "));
         status_text.push(Span::styled(
             "⟳ Reloading...",
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.highlight),
         ));
-    } else if let Some(error) = &app.reload_error {
+    } else if let Some(toast) = app.notifications.current() {
         status_text.push(Span::raw("  "));
         status_text.push(Span::styled(
-            format!("✗ {}", error),
-            Style::default().fg(Color::Red),
+            format!("{} {}", toast.level.symbol(), toast.message),
+            Style::default().fg(notifications::level_color(&theme, toast.level)),
         ));
     }
 
     let status_bar = Paragraph::new(Line::from(status_text))
-        .style(Style::default().bg(Color::DarkGray))
+        .style(Style::default().bg(theme.status_bar))
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(status_bar, chunks[2]);
 
     // Help popup
     if app.show_help {
-        render_help_popup(f);
+        render_help_popup(f, &theme, &app.text_scroll);
+    }
+
+    // Spec metadata popup
+    if app.show_spec_info {
+        let model = app.spec_info_view_model();
+        spec_info::render_spec_info_popup(f, &theme, &app.text_scroll, &model);
+    }
+
+    // Linked operation chains
+    if app.show_workflows {
+        let model = app.workflows_view_model();
+        workflows::render_workflows_popup(f, &theme, &app.text_scroll, &model);
+    }
+
+    // Notification history popup
+    if app.show_notifications {
+        let history = &app.notifications;
+        notifications::render_notifications_popup(f, &theme, &app.text_scroll, history);
     }
 
     // Endpoint details popup
-    if app.show_endpoint_details && app.selected_endpoint_for_details.is_some() {
-        render_endpoint_details_popup(f, app);
+    if let Some(model) = app
+        .endpoint_popup_view_model()
+        .filter(|_| app.show_endpoint_details)
+    {
+        render_endpoint_details_popup(f, &theme, &app.text_scroll, &model);
     }
-}
 
-fn render_stats_view(f: &mut Frame, app: &App, chunks: Vec<ratatui::layout::Rect>) {
-    // Calculate statistics
-    let total_schemas = app.field_index.schemas.len();
-    let total_fields = app.field_index.fields.len();
-    let total_endpoints = app.openapi_spec.paths.len();
-
-    // Count field types
-    let mut type_counts: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
-    for field_data in app.field_index.fields.values() {
-        *type_counts
-            .entry(field_data.field_type.clone())
-            .or_insert(0) += 1;
-    }
-
-    // Count HTTP methods
-    let mut method_counts: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
-    for path_item in app.openapi_spec.paths.values() {
-        for method in path_item.operations.keys() {
-            *method_counts.entry(method.to_uppercase()).or_insert(0) += 1;
-        }
+    // Server picker popup
+    if let (Some(picker), Some(servers)) = (&app.server_picker, &app.openapi_spec.servers) {
+        servers::render_server_picker_popup(f, &theme, servers, picker, app.active_server);
     }
 
-    // Count critical fields
-    let critical_fields = app
-        .field_index
-        .fields
-        .values()
-        .filter(|f| {
-            !f.endpoints.is_empty()
-                && f.endpoints
-                    .iter()
-                    .any(|e| e.to_lowercase().contains("post") || e.to_lowercase().contains("put"))
-        })
-        .count();
-
-    // Find most used fields
-    let mut field_usage: Vec<(&String, usize)> = app
-        .field_index
-        .fields
-        .iter()
-        .map(|(name, data)| (name, data.endpoints.len()))
-        .collect();
-    field_usage.sort_by(|a, b| b.1.cmp(&a.1));
-
-    // Build stats text
-    let mut stats_text = vec![
-        Line::from(vec![Span::styled(
-            "📊 OpenAPI Statistics",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Overview",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from(format!("  • Schemas: {}", total_schemas)),
-        Line::from(format!("  • Fields: {}", total_fields)),
-        Line::from(format!("  • Endpoints: {}", total_endpoints)),
-        Line::from(format!(
-            "  • Critical Fields: {} ({:.1}%)",
-            critical_fields,
-            (critical_fields as f64 / total_fields.max(1) as f64) * 100.0
-        )),
-        Line::from(""),
-    ];
+    // Try-it-out form, on top of the endpoint details it was opened from
+    if let Some(try_it_out) = &app.try_it_out {
+        try_it_out::render_try_it_out_popup(f, &theme, try_it_out);
+    }
 
-    // Field types distribution
-    if !type_counts.is_empty() {
-        stats_text.push(Line::from(vec![Span::styled(
-            "Field Types",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::UNDERLINED),
-        )]));
-        let mut types: Vec<_> = type_counts.iter().collect();
-        types.sort_by(|a, b| b.1.cmp(a.1));
-        for (field_type, count) in types.iter().take(5) {
-            let percentage = (**count as f64 / total_fields as f64) * 100.0;
-            stats_text.push(Line::from(format!(
-                "  • {}: {} ({:.1}%)",
-                field_type, count, percentage
-            )));
-        }
-        stats_text.push(Line::from(""));
+    // Deprecation timeline popup
+    if app.show_timeline {
+        timeline::render_timeline_popup(
+            f,
+            &theme,
+            &app.stats_analysis.deprecations,
+            chrono::Utc::now().date_naive(),
+        );
     }
 
-    // HTTP methods distribution
-    if !method_counts.is_empty() {
-        stats_text.push(Line::from(vec![Span::styled(
-            "HTTP Methods",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::UNDERLINED),
-        )]));
-        let mut methods: Vec<_> = method_counts.iter().collect();
-        methods.sort_by(|a, b| b.1.cmp(a.1));
-        for (method, count) in methods.iter() {
-            let color = match method.as_str() {
-                "GET" => Color::Green,
-                "POST" => Color::Blue,
-                "PUT" => Color::Yellow,
-                "DELETE" => Color::Red,
-                _ => Color::White,
-            };
-            stats_text.push(Line::from(vec![
-                Span::raw("  • "),
-                Span::styled(format!("{}: {}", method, count), Style::default().fg(color)),
-            ]));
-        }
-        stats_text.push(Line::from(""));
+    // Impact analysis popup
+    if let Some(report) = &app.impact_report {
+        impact::render_impact_popup(f, &theme, report);
     }
 
-    // Most used fields
-    if !field_usage.is_empty() {
-        stats_text.push(Line::from(vec![Span::styled(
-            "Top Fields (by endpoint usage)",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::UNDERLINED),
-        )]));
-        for (field_name, usage_count) in field_usage.iter().take(5) {
-            if *usage_count > 0 {
-                stats_text.push(Line::from(format!(
-                    "  • {}: {} endpoint(s)",
-                    field_name, usage_count
-                )));
-            }
-        }
-        stats_text.push(Line::from(""));
+    // Sensitive data report popup
+    if let Some(exposures) = &app.sensitive_report {
+        sensitive::render_sensitive_popup(f, &theme, exposures);
     }
 
-    // Validation warnings
-    if !app.validation_warnings.is_empty() {
-        stats_text.push(Line::from(vec![Span::styled(
-            "⚠ Validation Warnings",
-            Style::default()
-                .fg(Color::Red)
-                .add_modifier(Modifier::UNDERLINED),
-        )]));
-        for (i, warning) in app.validation_warnings.iter().enumerate().take(10) {
-            stats_text.push(Line::from(vec![
-                Span::styled(format!("  {}. ", i + 1), Style::default().fg(Color::Red)),
-                Span::raw(warning),
-            ]));
-        }
-        if app.validation_warnings.len() > 10 {
-            stats_text.push(Line::from(vec![Span::styled(
-                format!(
-                    "  ... and {} more warnings",
-                    app.validation_warnings.len() - 10
-                ),
-                Style::default()
-                    .fg(Color::DarkGray)
-                    .add_modifier(Modifier::ITALIC),
-            )]));
-        }
-    } else {
-        stats_text.push(Line::from(vec![Span::styled(
-            "✓ No validation warnings",
-            Style::default().fg(Color::Green),
-        )]));
+    // Coverage analysis popup
+    if let Some(report) = &app.coverage_report {
+        coverage::render_coverage_popup(f, &theme, report);
     }
 
-    let stats_widget = Paragraph::new(stats_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Statistics Dashboard"),
-        )
-        .wrap(Wrap { trim: true });
-    f.render_widget(stats_widget, chunks[1]);
+    // Pagination conventions popup
+    if let Some(report) = &app.pagination_report {
+        pagination::render_pagination_popup(f, &theme, report);
+    }
+
+    // Content type inventory popup
+    if let Some(report) = &app.content_type_report {
+        content_types::render_content_types_popup(f, &theme, report);
+    }
+
+    // Stats chart details popup
+    if let Some((section, buckets)) = &app.stats_drilldown {
+        stats::render_drilldown_popup(f, &theme, *section, buckets);
+    }
+
+    // Duplicate schema popup
+    if let Some(pairs) = &app.duplicate_report {
+        duplicates::render_duplicates_popup(f, &theme, pairs);
+    }
+
+    // Schema comparison popup
+    if let Some(comparison) = &app.schema_comparison {
+        compare::render_comparison_popup(f, &theme, comparison);
+    }
+
+    // Payload validation popup
+    if let Some(validation) = &app.payload_validation {
+        validation::render_validation_popup(f, &theme, validation);
+    }
+
+    // Field rename plan popup
+    if let Some(plan) = &app.rename_plan {
+        rename::render_rename_popup(f, &theme, plan);
+    }
+
+    // Cross-service field usage popup
+    if let Some(field_name) = &app.cross_spec_field {
+        specs::render_cross_spec_popup(f, &theme, field_name, app.cross_index.usages(field_name));
+    }
+
+    // Spec switcher popup, unless the rail shows the cursor
+    if let Some(cursor) = app.spec_switcher.filter(|_| rail.is_none()) {
+        specs::render_spec_switcher_popup(f, &theme, &app.spec_list_view_model(), cursor);
+    }
+
+    // Saved filters and views picker
+    if let Some(cursor) = app.saved_view_picker {
+        let filters = &app.workspace.saved_filters;
+        saved_views::render_saved_view_picker(f, &theme, filters, cursor);
+    }
+
+    // Lint findings picker
+    if let Some(cursor) = app.lint_picker {
+        lint::render_lint_picker(f, &theme, &app.lint_findings, cursor);
+    }
+
+    // Spec file browser popup
+    if let Some(browser) = &app.file_browser {
+        file_browser::render_file_browser_popup(f, &theme, browser);
+    }
+
+    // Description editor popup
+    if let Some(editor) = &app.description_editor {
+        description::render_description_editor(f, &theme, editor);
+    }
+
+    // Command palette, over the status bar
+    if let Some(palette) = &app.command_palette {
+        palette::render_command_palette(f, &theme, palette, chunks[2]);
+    }
+
+    // Failed reload or :open, over everything until retried or dismissed
+    if let Some(failed) = &app.failed_load {
+        load_error::render_load_error(
+            f,
+            &theme,
+            layout::popup_area_with_height(f.area(), 16),
+            &failed.path,
+            &failed.error,
+            "r: retry  Esc: keep the current spec  q: quit",
+        );
+    }
 }
 
-fn render_help_popup(f: &mut Frame) {
+fn render_help_popup(f: &mut Frame, theme: &Theme, scrolls: &TextScrolls) {
     let help_text = vec![
         Line::from(vec![Span::styled(
             "OpenAPI Field Explorer - Help",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "⌨  Keyboard Shortcuts",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::UNDERLINED),
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Navigation",
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         )]),
         Line::from("    ↑/↓         Navigate items in current panel"),
+        Line::from("    ↑/↓ PgUp/Dn Scroll details panels and popups that do not fit"),
         Line::from("    Tab         Switch between panels (Left/Center/Right)"),
         Line::from("    Enter       Select item / Show details"),
+        Line::from(
+            "    Enter       On a field's schema or a schema's endpoint: open it in its view",
+        ),
+        Line::from("    ←/→         Schema tree: collapse / expand (Enter toggles)"),
         Line::from("    Esc         Go back / Clear errors / Close help"),
+        Line::from("    Backspace   Previous view & selection (also Ctrl+O, Alt+←)"),
+        Line::from("    Alt+→       Next view & selection (also Ctrl+I where the terminal"),
+        Line::from("                reports it apart from Tab)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Views",
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         )]),
         Line::from("    1           Fields View (search by field name)"),
         Line::from("    2           Schemas View (browse by schema)"),
         Line::from("    3           Endpoints View (navigate endpoints)"),
-        Line::from("    4           Graph View (visualize relationships)"),
+        Line::from("    4           Graph View (schema dependencies, cycles, field clusters)"),
         Line::from("    5           Stats View (dashboard & metrics)"),
+        Line::from("    6           Headers View (response headers by endpoint)"),
+        Line::from("    7           Responses View (status codes by endpoint, gaps in red)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Search & Actions",
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         )]),
-        Line::from("    /           Start typing to search (fuzzy match)"),
-        Line::from("    Backspace   Delete search character"),
+        Line::from("    /           Focus the search bar (fuzzy match), Enter/Esc to leave"),
+        Line::from("    ←/→         Move the cursor (Ctrl: by word), Home/End"),
+        Line::from("    Ctrl+W/U    Delete the previous word / the whole query"),
         Line::from("    r           Reload OpenAPI file"),
+        Line::from("    a           Impact analysis of selected field/schema, or marked fields"),
+        Line::from("    e           Export parameter matrix CSV (Endpoints view)"),
+        Line::from("    e           Export stats as Markdown (Stats view)"),
+        Line::from("    e           Export the graph as an interactive HTML page (Graph view)"),
+        Line::from("    ↑/↓ Enter   Select a chart / list its items (Stats view)"),
+        Line::from("    Enter       On the Tags chart: list the tag's endpoints (:tag clears)"),
+        Line::from("    e           Export schema as TypeScript (Schemas view)"),
+        Line::from("    E           Export schema as Rust serde structs (Schemas view)"),
+        Line::from("    J           Export schema as JSON Schema 2020-12 (Schemas view)"),
+        Line::from("    G           Export schema as GraphQL SDL (Schemas view)"),
+        Line::from("    X           Export schema as protobuf messages (Schemas view)"),
+        Line::from("    k           Path / operationId first listing (Endpoints view)"),
+        Line::from("    T           Group endpoints by path prefix, ←/→ or Enter fold (Endpoints view)"),
+        Line::from("    v           Endpoint fields by origin or once each with counts (Endpoints view)"),
+        Line::from("    m           Cycle GET/POST/PUT/PATCH/DELETE filter (Endpoints view)"),
+        Line::from("    p           Sensitive fields exposed in responses"),
+        Line::from("    C           Coverage: unused schemas, orphaned fields, empty responses"),
+        Line::from("    D           Duplicate and near-duplicate schemas"),
+        Line::from("    P           Pagination styles and unpaginated collections"),
+        Line::from("    M           Media types by endpoint, non-JSON and mixed ones"),
+        Line::from("    N           Toggle natural / lexical sorting"),
+        Line::from("    x           Try it out (from endpoint details)"),
+        Line::from("    d           Edit the field / operation description, saved to the spec file"),
+        Line::from("    t           Deprecation timeline (sunset dates)"),
+        Line::from("    n           Notification history (exports, reloads...)"),
+        Line::from("    i           Spec info: contact, license, counts (or :info)"),
+        Line::from("    O           Open the selected item's external documentation in the browser"),
+        Line::from("    l           Workflows: operations chained by response links"),
+        Line::from("    s           Pick the server used as base URL"),
+        Line::from("    b           Bookmark endpoint (Endpoints view / details), or marked items"),
+        Line::from("    Space       Mark field/endpoint for bulk actions, U clears marks"),
+        Line::from("    y           Copy marked (or highlighted) names to the clipboard"),
+        Line::from("    Ctrl+S      Save current search as a filter"),
+        Line::from("    f           Apply next saved filter"),
+        Line::from("    F           Pick a saved filter or view (:save <name> to add one)"),
+        Line::from("    L           Lint findings (path params, operationIds), Enter jumps"),
+        Line::from("    W / I       Export / import the workspace file"),
+        Line::from("    S           Switch between loaded specs"),
+        Line::from("    c           Field usage across specs (Fields view)"),
+        Line::from("    < / >       Sort the Fields table by column, R reverses"),
+        Line::from("    [ / ]       Scroll the Fields table columns"),
+        Line::from("    c           Compare two schemas side by side (Schemas view)"),
+        Line::from(
+            "    g           Open the schema referenced by the tree row (parent, variant, type)",
+        ),
+        Line::from("    o           Open another spec file or URL (file browser, u types a URL)"),
+        Line::from("    :           Command palette (:view, :filter, :op, :open...)"),
         Line::from("    h           Toggle this help screen"),
         Line::from("    q / Ctrl+C  Quit application"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "💡 Tips",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::UNDERLINED),
         )]),
         Line::from(""),
         Line::from("  • Fuzzy search: Type 'usid' to find 'USER_ID'"),
         Line::from("  • Yellow = Selected, Cyan = Cursor position"),
-        Line::from("  • Critical fields (POST/PUT) shown in red"),
+        Line::from("  • Critical fields (used by POST/PUT/PATCH/DELETE) shown in red"),
+        Line::from("  • Sensitive fields (password, token, email...) shown in magenta"),
         Line::from("  • Press 'r' after editing OpenAPI file to reload"),
+        Line::from("  • ':validate body.json [status]' checks a payload against the open endpoint"),
+        Line::from("  • ':rename <name> [plan.json]' lists what renaming the field touches, with a JSON Patch plan"),
         Line::from("  • Use Tab to navigate between panels efficiently"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Press 'h' or 'Esc' to close",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(theme.muted)
                 .add_modifier(Modifier::ITALIC),
         )]),
     ];
@@ -420,262 +680,402 @@ fn render_help_popup(f: &mut Frame) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(theme.accent))
                 .title(" Help "),
         )
-        .style(Style::default().bg(Color::Black).fg(Color::White))
+        .style(Style::default().bg(theme.background).fg(theme.text))
         .wrap(Wrap { trim: true });
 
     // Make the help window larger to fit all content
-    let area = ratatui::layout::Rect {
-        x: f.area().x + f.area().width / 6,
-        y: f.area().y + 1,
-        width: (f.area().width * 2) / 3,
-        height: f.area().height.saturating_sub(2),
-    };
+    let area = layout::popup_area(f.area());
 
     f.render_widget(Clear, area);
-    f.render_widget(help_widget, area);
+    layout::render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::Help,
+        "help",
+        help_widget,
+        area,
+    );
 }
 
-fn render_endpoint_details_popup(f: &mut Frame, app: &App) {
-    if let Some(endpoint_str) = &app.selected_endpoint_for_details {
-        // Parse endpoint string (format: "METHOD /path")
-        let parts: Vec<&str> = endpoint_str.splitn(2, ' ').collect();
-        if parts.len() != 2 {
-            return;
-        }
-
-        let method = parts[0];
-        let path = parts[1];
-
-        // Find the operation in the spec
-        if let Some(path_item) = app.openapi_spec.paths.get(path) {
-            if let Some(operation) = path_item.operations.get(&method.to_lowercase()) {
-                let mut details_text = vec![
-                    Line::from(vec![Span::styled(
-                        format!("{} {}", method, path),
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )]),
-                    Line::from(""),
-                ];
-
-                // Summary
-                if let Some(summary) = &operation.summary {
-                    details_text.push(Line::from(vec![
-                        Span::styled("Summary: ", Style::default().fg(Color::Yellow)),
-                        Span::raw(summary),
-                    ]));
-                    details_text.push(Line::from(""));
-                }
-
-                // Description
-                if let Some(description) = &operation.description {
-                    details_text.push(Line::from(vec![Span::styled(
-                        "Description: ",
-                        Style::default().fg(Color::Yellow),
-                    )]));
-                    details_text.push(Line::from(format!("  {}", description)));
-                    details_text.push(Line::from(""));
-                }
+fn render_endpoint_details_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &EndpointPopupViewModel,
+) {
+    let heading = |text: &'static str| {
+        Line::from(vec![Span::styled(
+            text,
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )])
+    };
+    let mut details_text = vec![
+        Line::from(vec![Span::styled(
+            model.endpoint.clone(),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
 
-                // Tags
-                if let Some(tags) = &operation.tags {
-                    if !tags.is_empty() {
-                        details_text.push(Line::from(vec![
-                            Span::styled("Tags: ", Style::default().fg(Color::Yellow)),
-                            Span::raw(tags.join(", ")),
-                        ]));
-                        details_text.push(Line::from(""));
-                    }
-                }
+    // Team context from the workspace
+    if model.bookmarked || model.owner.is_some() {
+        let mut spans = Vec::new();
+        if model.bookmarked {
+            spans.push(Span::styled(
+                "★ Bookmarked  ",
+                Style::default().fg(theme.highlight),
+            ));
+        }
+        if let Some(owner) = &model.owner {
+            spans.push(Span::styled(
+                "Owner: ",
+                Style::default().fg(theme.highlight),
+            ));
+            spans.push(Span::raw(owner.as_str()));
+        }
+        details_text.push(Line::from(spans));
+        details_text.push(Line::from(""));
+    }
+    for note in &model.notes {
+        details_text.push(Line::from(vec![
+            Span::styled("Note: ", Style::default().fg(theme.highlight)),
+            Span::styled(
+                note.as_str(),
+                Style::default().add_modifier(Modifier::ITALIC),
+            ),
+        ]));
+        details_text.push(Line::from(""));
+    }
 
-                // Parameters
-                if let Some(parameters) = &operation.parameters {
-                    if !parameters.is_empty() {
-                        details_text.push(Line::from(vec![Span::styled(
-                            "Parameters:",
-                            Style::default()
-                                .fg(Color::Yellow)
-                                .add_modifier(Modifier::UNDERLINED),
-                        )]));
-                        for param in parameters {
-                            let required = if param.required.unwrap_or(false) {
-                                " *"
-                            } else {
-                                ""
-                            };
-                            details_text.push(Line::from(format!(
-                                "  • {} ({}){} - {}",
-                                param.name,
-                                param.in_,
-                                required,
-                                param.description.as_deref().unwrap_or("No description")
-                            )));
-                        }
-                        details_text.push(Line::from(""));
-                    }
-                }
+    if let Some(summary) = &model.summary {
+        details_text.push(Line::from(vec![
+            Span::styled("Summary: ", Style::default().fg(theme.highlight)),
+            Span::raw(summary.as_str()),
+        ]));
+        details_text.push(Line::from(""));
+    }
 
-                // Request body
-                if let Some(request_body) = &operation.request_body {
-                    details_text.push(Line::from(vec![Span::styled(
-                        "Request Body:",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::UNDERLINED),
-                    )]));
-                    if let Some(desc) = &request_body.description {
-                        details_text.push(Line::from(format!("  {}", desc)));
-                    }
-                    details_text.push(Line::from(format!(
-                        "  Content types: {}",
-                        request_body
-                            .content
-                            .keys()
-                            .map(|k| k.as_str())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    )));
-                    details_text.push(Line::from(""));
-                }
+    if let Some(description) = &model.description {
+        details_text.push(Line::from(vec![Span::styled(
+            "Description: ",
+            Style::default().fg(theme.highlight),
+        )]));
+        details_text.push(Line::from(format!("  {}", description)));
+        details_text.push(Line::from(""));
+    }
 
-                // Responses
-                if !operation.responses.is_empty() {
-                    details_text.push(Line::from(vec![Span::styled(
-                        "Responses:",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::UNDERLINED),
-                    )]));
-                    for (status_code, response) in &operation.responses {
-                        let color = if status_code.starts_with('2') {
-                            Color::Green
-                        } else if status_code.starts_with('4') || status_code.starts_with('5') {
-                            Color::Red
-                        } else {
-                            Color::Yellow
-                        };
-                        details_text.push(Line::from(vec![
-                            Span::styled(
-                                format!("  • {}: ", status_code),
-                                Style::default().fg(color),
-                            ),
-                            Span::raw(&response.description),
-                        ]));
-                    }
-                }
+    if let Some(docs) = &model.docs {
+        details_text.push(layout::docs_line(theme, docs));
+        details_text.push(Line::from(""));
+    }
 
-                details_text.push(Line::from(""));
-                details_text.push(Line::from(vec![Span::styled(
-                    "Press 'Esc' to close",
-                    Style::default()
-                        .fg(Color::DarkGray)
-                        .add_modifier(Modifier::ITALIC),
-                )]));
-
-                let details_widget = Paragraph::new(details_text)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .border_style(Style::default().fg(Color::Cyan))
-                            .title(" Endpoint Details "),
-                    )
-                    .style(Style::default().bg(Color::Black).fg(Color::White))
-                    .wrap(Wrap { trim: true });
-
-                // Large popup to fit all details
-                let area = ratatui::layout::Rect {
-                    x: f.area().x + f.area().width / 8,
-                    y: f.area().y + 1,
-                    width: (f.area().width * 3) / 4,
-                    height: f.area().height.saturating_sub(2),
-                };
-
-                f.render_widget(Clear, area);
-                f.render_widget(details_widget, area);
-            }
-        }
+    if !model.tags.is_empty() {
+        details_text.push(Line::from(vec![
+            Span::styled("Tags: ", Style::default().fg(theme.highlight)),
+            Span::raw(model.tags.join(", ")),
+        ]));
+        details_text.push(Line::from(""));
     }
-}
 
-fn handle_key_events(key: crossterm::event::KeyEvent, app: &mut App) {
-    match key.code {
-        KeyCode::Char('q') | KeyCode::Char('c')
-            if key
-                .modifiers
-                .contains(crossterm::event::KeyModifiers::CONTROL) =>
-        {
-            app.should_quit = true;
-        }
-        KeyCode::Char('q') => {
-            app.should_quit = true;
+    if let Some((deprecation, status)) = &model.deprecation {
+        let mut spans = vec![Span::styled(
+            "Deprecated: ",
+            Style::default().fg(theme.highlight),
+        )];
+        if let Some(since) = deprecation.since {
+            spans.push(Span::raw(format!("since {} ", since)));
         }
-        KeyCode::Tab => {
-            app.next_panel();
+        if let Some(sunset) = deprecation.sunset {
+            spans.push(Span::raw(format!("• sunset {} ", sunset)));
         }
-        KeyCode::Char('/') => {
-            app.search_query.clear();
-            app.update_filters();
-        }
-        KeyCode::Char('h') => {
-            app.show_help = !app.show_help;
-        }
-        KeyCode::Char('1') => {
-            app.set_view(View::Fields);
-        }
-        KeyCode::Char('2') => {
-            app.set_view(View::Schemas);
-        }
-        KeyCode::Char('3') => {
-            app.set_view(View::Endpoints);
-        }
-        KeyCode::Char('4') => {
-            app.set_view(View::Graph);
-        }
-        KeyCode::Char('5') => {
-            app.set_view(View::Stats);
-        }
-        KeyCode::Char('r') => {
-            app.request_reload();
-        }
-        KeyCode::Esc => {
-            app.show_help = false;
-            app.show_endpoint_details = false;
-            app.selected_endpoint_for_details = None;
-            app.reload_error = None; // Clear reload error on Esc
+        spans.push(Span::styled(
+            format!("({})", status.describe()),
+            Style::default().fg(timeline::status_color(theme, status)),
+        ));
+        details_text.push(Line::from(spans));
+        details_text.push(Line::from(""));
+    }
+
+    if !model.parameters.is_empty() {
+        details_text.push(heading("Parameters:"));
+        for param in &model.parameters {
+            details_text.push(Line::from(format!(
+                "  • {} ({}){} - {}",
+                param.name,
+                param.location,
+                if param.required { " *" } else { "" },
+                param.description.as_deref().unwrap_or("No description")
+            )));
         }
-        KeyCode::Char(ch) => {
-            if !app.search_query.is_empty() || ch == '/' {
-                if ch != '/' {
-                    app.search_query.push(ch);
-                    app.update_filters();
-                }
-            }
+        details_text.push(Line::from(""));
+    }
+
+    if let Some(request_body) = &model.request_body {
+        details_text.push(heading("Request Body:"));
+        if let Some(desc) = &request_body.description {
+            details_text.push(Line::from(format!("  {}", desc)));
         }
-        KeyCode::Backspace => {
-            if !app.search_query.is_empty() {
-                app.search_query.pop();
-                app.update_filters();
-            }
+        details_text.push(Line::from(format!(
+            "  Content types: {}",
+            request_body.content_types.join(", ")
+        )));
+        if let Some(fields) = &request_body.form_fields {
+            details_text.push(Line::from("  Form fields:"));
+            details_text.extend(endpoints::form_field_lines(theme, fields));
         }
-        KeyCode::Up => {
-            if !app.show_help {
-                app.navigate_up();
+        details_text.push(Line::from(""));
+    }
+
+    if !model.responses.is_empty() {
+        details_text.push(heading("Responses:"));
+        for response in &model.responses {
+            let status = &response.status;
+            let color = if status.starts_with('2') {
+                theme.success
+            } else if status.starts_with('4') || status.starts_with('5') {
+                theme.critical
+            } else {
+                theme.highlight
+            };
+            details_text.push(Line::from(vec![
+                Span::styled(format!("  • {}: ", status), Style::default().fg(color)),
+                Span::raw(response.description.as_str()),
+            ]));
+            for header in &response.headers {
+                details_text.push(Line::from(vec![
+                    Span::styled(
+                        format!("      ↳ {} ", header.name),
+                        Style::default().fg(theme.info),
+                    ),
+                    Span::styled(
+                        format!(
+                            "({}) {}",
+                            header.header_type,
+                            header.description.as_deref().unwrap_or("")
+                        ),
+                        Style::default().fg(theme.muted),
+                    ),
+                ]));
             }
         }
-        KeyCode::Down => {
-            if !app.show_help {
-                app.navigate_down();
+    }
+
+    details_text.push(Line::from(""));
+    details_text.push(Line::from(vec![Span::styled(
+        "Press 'x' to try it out, 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let details_widget = Paragraph::new(details_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Endpoint Details "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: true });
+
+    // Large popup to fit all details
+    let area = layout::popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    layout::render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::EndpointDetailsPopup,
+        &model.endpoint,
+        details_widget,
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Panel;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+    use ratatui::backend::TestBackend;
+
+    fn app() -> App {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {"/users/{id}": {"get": {"parameters": [{"name": "id", "in": "path", "required": true}],
+                    "responses": {"200": {"description": "ok", "content": {"application/json": {
+                        "schema": {"$ref": "#/components/schemas/User"}}}}}}}},
+                "components": {"schemas": {"User": {"type": "object", "properties": {
+                    "id": {"type": "string"}, "email": {"type": "string", "format": "email"}
+                }}}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        App::new(spec, index, None)
+    }
+
+    #[test]
+    fn test_every_view_renders_on_small_and_large_terminals() {
+        let mut app = app();
+        for (width, height) in [(1, 1), (20, 6), (60, 20), (99, 30), (200, 60)] {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            for view in [
+                View::Fields,
+                View::Schemas,
+                View::Endpoints,
+                View::Graph,
+                View::Stats,
+                View::Headers,
+                View::Responses,
+            ] {
+                app.set_view(view);
+                for show_help in [false, true] {
+                    app.show_help = show_help;
+                    terminal.draw(|f| ui(f, &mut app)).unwrap();
+                }
             }
         }
-        KeyCode::Enter => {
-            if !app.show_help {
-                app.select_current_item();
-            }
+    }
+
+    #[test]
+    fn test_spec_info_popup_shows_metadata() {
+        let mut app = app();
+        app.openapi_spec.info.license = Some(crate::parser::License {
+            name: "MIT".to_string(),
+            url: None,
+            identifier: None,
+        });
+        app.handle_event(AppEvent::Input(crossterm::event::Event::Key(
+            crossterm::event::KeyCode::Char('i').into(),
+        )));
+        assert!(app.show_spec_info);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|f| ui(f, &mut app)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Spec Info"));
+        assert!(screen.contains("MIT"));
+        assert!(screen.contains("Operations  1 (0 write)"));
+
+        app.handle_event(AppEvent::Close);
+        assert!(!app.show_spec_info);
+    }
+
+    #[test]
+    fn test_workflows_popup_chains_linked_operations() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/orders": {"post": {"operationId": "createOrder", "responses": {"201": {
+                        "description": "created",
+                        "links": {"GetOrder": {"operationId": "getOrder",
+                            "parameters": {"orderId": "$response.body#/id"}}}}}}},
+                    "/orders/{orderId}": {"get": {"operationId": "getOrder", "responses": {}}}
+                }
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        let mut app = App::new(spec, index, None);
+        app.handle_event(AppEvent::ToggleWorkflows);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|f| ui(f, &mut app)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("1 link(s) in 1 workflow(s)"));
+        assert!(screen.contains("└─ 201 GetOrder → GET /orders/{orderId}"));
+        assert!(screen.contains("orderId ← $response.body#/id"));
+    }
+
+    #[test]
+    fn test_narrow_terminals_stack_panels() {
+        let area = ratatui::layout::Rect::new(0, 0, 80, 30);
+        let panels = layout::create_three_column_layout(area);
+        assert!(panels.iter().all(|panel| panel.width == 80));
+        assert!(panels[0].bottom() <= panels[1].y);
+
+        let wide = layout::create_three_column_layout(ratatui::layout::Rect::new(0, 0, 120, 30));
+        assert!(wide.iter().all(|panel| panel.height == 30));
+
+        let popup = layout::popup_area_with_height(area, 50);
+        assert_eq!((popup.width, popup.height), (80, 30));
+        assert_eq!(layout::popup_area(wide[0]).height, 28);
+    }
+
+    #[test]
+    fn test_help_popup_scrolls_within_its_content() {
+        let mut app = app();
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        app.handle_event(AppEvent::ToggleHelp);
+        terminal.draw(|f| ui(f, &mut app)).unwrap();
+        let bounds = app.text_scroll.borrow()[&ScrollTarget::Help].clone();
+        assert_eq!(bounds.offset, 0);
+        assert!(bounds.max_offset > 0);
+
+        app.handle_event(AppEvent::NavigateDown);
+        app.handle_event(AppEvent::PageDown);
+        terminal.draw(|f| ui(f, &mut app)).unwrap();
+        assert_eq!(
+            app.text_scroll.borrow()[&ScrollTarget::Help].offset,
+            1 + bounds.page
+        );
+
+        for _ in 0..50 {
+            app.handle_event(AppEvent::PageDown);
         }
-        _ => {}
+        assert_eq!(
+            app.text_scroll.borrow()[&ScrollTarget::Help].offset,
+            bounds.max_offset
+        );
+        app.handle_event(AppEvent::PageUp);
+        app.handle_event(AppEvent::NavigateUp);
+        assert_eq!(
+            app.text_scroll.borrow()[&ScrollTarget::Help].offset,
+            bounds.max_offset - bounds.page - 1
+        );
+
+        // Reopening starts from the top
+        app.handle_event(AppEvent::ToggleHelp);
+        app.handle_event(AppEvent::ToggleHelp);
+        terminal.draw(|f| ui(f, &mut app)).unwrap();
+        assert_eq!(app.text_scroll.borrow()[&ScrollTarget::Help].offset, 0);
+    }
+
+    #[test]
+    fn test_focused_details_panel_scrolls_instead_of_the_list() {
+        let mut app = app();
+        app.set_view(View::Endpoints);
+        assert_eq!(app.scroll_target(), None);
+        app.current_panel = Panel::Center;
+        assert_eq!(app.scroll_target(), Some(ScrollTarget::EndpointDetails));
+        app.set_view(View::Fields);
+        assert_eq!(app.scroll_target(), None);
     }
 }
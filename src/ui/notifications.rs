@@ -0,0 +1,88 @@
+use crate::app::{ScrollTarget, TextScrolls};
+use crate::notifications::{Level, Notifications};
+use crate::ui::layout::{popup_area, render_scrolled_paragraph};
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::time::{Duration, Instant};
+
+pub fn level_color(theme: &Theme, level: Level) -> Color {
+    match level {
+        Level::Info => theme.info,
+        Level::Success => theme.success,
+        Level::Error => theme.critical,
+    }
+}
+
+/// Every kept notification, newest first, with how long ago it came.
+pub fn render_notifications_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    notifications: &Notifications,
+) {
+    let now = Instant::now();
+    let mut lines = Vec::new();
+    if notifications.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No notifications yet",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for notification in notifications.history() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>4}  ", ago(now.duration_since(notification.at))),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                format!("{} ", notification.level.symbol()),
+                Style::default().fg(level_color(theme, notification.level)),
+            ),
+            Span::raw(notification.message.as_str()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "↑/↓ PgUp/PgDn: scroll  n/Esc: close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(format!(" Notifications ({}) ", notifications.len())),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+    render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::Notifications,
+        "notifications",
+        widget,
+        area,
+    );
+}
+
+/// Compact age, e.g. `12s`, `3m`, `2h`
+fn ago(elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m", seconds / 60),
+        _ => format!("{}h", seconds / 3600),
+    }
+}
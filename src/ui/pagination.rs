@@ -0,0 +1,99 @@
+use crate::pagination::PaginationReport;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_pagination_popup(f: &mut Frame, theme: &Theme, report: &PaginationReport) {
+    let paginated: usize = report.by_style.values().map(Vec::len).sum();
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Pagination conventions",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} paginated endpoint(s) in {} style(s), {} collection(s) without pagination",
+            paginated,
+            report.by_style.len(),
+            report.unpaginated_collections.len()
+        )),
+        Line::from(""),
+    ];
+
+    if report.is_mixed() {
+        lines.push(Line::from(Span::styled(
+            "⚠ Several pagination styles are in use",
+            Style::default().fg(theme.highlight),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    for (style, endpoints) in &report.by_style {
+        lines.push(Line::from(Span::styled(
+            format!("{} ({})", style.label(), endpoints.len()),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )));
+        for endpoint in endpoints {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  • {}", endpoint.endpoint)),
+                Span::styled(
+                    format!("  {}", endpoint.evidence.join(", ")),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    if !report.unpaginated_collections.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "Collections without pagination ({})",
+                    report.unpaginated_collections.len()
+                ),
+                Style::default()
+                    .fg(theme.critical)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(
+                "  add paging parameters or a next link",
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+        for endpoint in &report.unpaginated_collections {
+            lines.push(Line::from(format!("  • {}", endpoint)));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Pagination "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,49 @@
+use crate::app::CommandPalette;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Draws the palette input in place of the status bar, with completions
+/// or the last error in the title.
+pub fn render_command_palette(f: &mut Frame, theme: &Theme, palette: &CommandPalette, area: Rect) {
+    let (title, title_style) = match &palette.error {
+        Some(error) => (
+            format!(" ✗ {} ", error),
+            Style::default().fg(theme.critical),
+        ),
+        None if !palette.completions.is_empty() => (
+            format!(" {} ", palette.completions.join("  ")),
+            Style::default().fg(theme.muted),
+        ),
+        None => (
+            " Command (Tab: complete, ↑/↓: history, Esc: cancel) ".to_string(),
+            Style::default().fg(theme.muted),
+        ),
+    };
+
+    let widget = Paragraph::new(Line::from(vec![
+        Span::styled(
+            ":",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(palette.input.as_str()),
+        Span::styled("█", Style::default().fg(theme.accent)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(Span::styled(title, title_style)),
+    )
+    .style(Style::default().bg(theme.background).fg(theme.text));
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
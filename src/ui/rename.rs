@@ -0,0 +1,116 @@
+use crate::rename::{RenamePlan, SiteKind};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+fn section<'a>(theme: &Theme, title: String, items: Vec<String>) -> Vec<Line<'a>> {
+    let mut lines = vec![Line::from(vec![Span::styled(
+        title,
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::UNDERLINED),
+    )])];
+    if items.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (none)",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    lines.extend(
+        items
+            .into_iter()
+            .map(|item| Line::from(format!("  • {}", item))),
+    );
+    lines.push(Line::from(""));
+    lines
+}
+
+pub fn render_rename_popup(f: &mut Frame, theme: &Theme, plan: &RenamePlan) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!(
+                "{} → {} — {} site(s) to update",
+                plan.field,
+                plan.new_name,
+                plan.sites.len()
+            ),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    if !plan.conflicts.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("⚠ '{}' is already declared at:", plan.new_name),
+            Style::default()
+                .fg(theme.critical)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for pointer in &plan.conflicts {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", pointer),
+                Style::default().fg(theme.critical),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
+    let schemas = plan.schemas();
+    let endpoints = plan.endpoints();
+    let pointers = |kind: SiteKind| -> Vec<String> {
+        plan.sites_of(kind)
+            .map(|site| site.pointer.clone())
+            .collect()
+    };
+    let parameters = pointers(SiteKind::Parameter);
+    let examples = pointers(SiteKind::Example);
+    lines.extend(section(
+        theme,
+        format!("Schemas ({})", schemas.len()),
+        schemas.iter().map(|name| name.to_string()).collect(),
+    ));
+    lines.extend(section(
+        theme,
+        format!("Endpoints ({})", endpoints.len()),
+        endpoints.iter().map(|name| name.to_string()).collect(),
+    ));
+    lines.extend(section(
+        theme,
+        format!("Parameters ({})", parameters.len()),
+        parameters,
+    ));
+    lines.extend(section(
+        theme,
+        format!("Examples ({})", examples.len()),
+        examples,
+    ));
+
+    lines.push(Line::from(vec![Span::styled(
+        "':rename <name> <plan.json>' writes the JSON Patch plan, 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Rename Field "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,213 @@
+use crate::responses::{MatrixRow, ResponseGap, ResponseMatrix};
+use crate::ui::theme::Theme;
+use crate::view_model::ResponsesViewModel;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_responses_view(
+    f: &mut Frame,
+    theme: &Theme,
+    model: &ResponsesViewModel,
+    chunks: Vec<Rect>,
+) {
+    let matrix = &model.matrix;
+    let cursor = matrix.rows.get(model.cursor);
+
+    // Left panel - Gap summary and the highlighted endpoint
+    let mut summary = vec![
+        Line::from(vec![
+            Span::styled("Endpoints: ", Style::default().fg(theme.accent)),
+            Span::raw(matrix.rows.len().to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("With gaps: ", Style::default().fg(theme.accent)),
+            Span::raw(matrix.rows_with_gaps().to_string()),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Gaps",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+    ];
+    let counts = matrix.gap_counts();
+    for gap in ResponseGap::ALL {
+        let count = counts.get(&gap).copied().unwrap_or(0);
+        let style = if count > 0 {
+            Style::default().fg(theme.critical)
+        } else {
+            Style::default().fg(theme.muted)
+        };
+        summary.push(Line::from(vec![
+            Span::styled(format!("  {:>3} ", count), style),
+            Span::raw(gap.description()),
+        ]));
+    }
+
+    if let Some(row) = cursor {
+        summary.push(Line::from(""));
+        summary.push(Line::from(vec![Span::styled(
+            row.endpoint.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        for (status, description) in row.statuses.iter().zip(&model.descriptions) {
+            summary.push(Line::from(vec![
+                Span::styled(format!("  {:<8}", status), status_style(theme, status)),
+                Span::styled(description.as_str(), Style::default().fg(theme.muted)),
+            ]));
+        }
+        for gap in &row.gaps {
+            summary.push(Line::from(vec![Span::styled(
+                format!("  ✗ {}", gap.description()),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+    }
+
+    let summary_widget = Paragraph::new(summary)
+        .block(crate::ui::layout::panel_block(
+            theme,
+            "Response Gaps",
+            false,
+        ))
+        .wrap(Wrap { trim: false });
+    f.render_widget(summary_widget, chunks[0]);
+
+    // Center and right panels - Endpoints × status codes
+    let area = chunks[1].union(chunks[2]);
+    let title = format!(
+        "Status Codes ({} endpoints × {} codes)",
+        matrix.rows.len(),
+        matrix.statuses.len()
+    );
+    let block = crate::ui::layout::panel_block(theme, &title, true);
+    if matrix.rows.is_empty() {
+        let empty = Paragraph::new("No path operations match the current filters")
+            .style(Style::default().fg(theme.muted))
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let lines = matrix_lines(theme, matrix, model.cursor, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Header line then the rows visible around the cursor.
+fn matrix_lines<'a>(
+    theme: &Theme,
+    matrix: &'a ResponseMatrix,
+    cursor: usize,
+    area: Rect,
+) -> Vec<Line<'a>> {
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let visible = (area.height.saturating_sub(3) as usize).max(1);
+    let endpoint_width = matrix
+        .rows
+        .iter()
+        .map(|row| row.endpoint.chars().count() + 3)
+        .max()
+        .unwrap_or(0)
+        .min(inner_width / 2);
+
+    let mut header = vec![Span::raw(" ".repeat(endpoint_width))];
+    header.extend(matrix.statuses.iter().map(|status| {
+        Span::styled(
+            format!("{:^width$}", status, width = column_width(status)),
+            status_style(theme, status).add_modifier(Modifier::UNDERLINED),
+        )
+    }));
+    let mut lines = vec![Line::from(header)];
+
+    let offset = (cursor + 1).saturating_sub(visible);
+    for (i, row) in matrix.rows.iter().enumerate().skip(offset).take(visible) {
+        lines.push(row_line(theme, matrix, row, i == cursor, endpoint_width));
+    }
+    lines
+}
+
+fn row_line<'a>(
+    theme: &Theme,
+    matrix: &'a ResponseMatrix,
+    row: &'a MatrixRow,
+    is_cursor: bool,
+    endpoint_width: usize,
+) -> Line<'a> {
+    let prefix = if is_cursor { "► " } else { "  " };
+    let endpoint_style = match (is_cursor, row.gaps.is_empty()) {
+        (true, _) => Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD),
+        (false, false) => Style::default().fg(theme.critical),
+        (false, true) => Style::default(),
+    };
+    let endpoint = truncate(&row.endpoint, endpoint_width.saturating_sub(3));
+    let mut spans = vec![Span::styled(
+        format!(
+            "{}{:<width$}",
+            prefix,
+            endpoint,
+            width = endpoint_width.saturating_sub(2)
+        ),
+        endpoint_style,
+    )];
+
+    for status in &matrix.statuses {
+        let width = column_width(status);
+        let missing = row.gaps.iter().any(|gap| match gap {
+            ResponseGap::NoNotFound => status == "404",
+            ResponseGap::NoUnauthorized => status == "401",
+            _ => false,
+        });
+        let (mark, style) = if row.documents(status) {
+            ("●", status_style(theme, status))
+        } else if missing {
+            ("✗", Style::default().fg(theme.critical))
+        } else {
+            ("·", Style::default().fg(theme.muted))
+        };
+        spans.push(Span::styled(
+            format!("{:^width$}", mark, width = width),
+            style,
+        ));
+    }
+
+    if !row.gaps.is_empty() {
+        let gaps: Vec<&str> = row.gaps.iter().map(ResponseGap::label).collect();
+        spans.push(Span::styled(
+            format!(" {}", gaps.join(", ")),
+            Style::default().fg(theme.critical),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn column_width(status: &str) -> usize {
+    status.chars().count().max(3) + 2
+}
+
+/// Colour of a status code's class, `default` and ranges included.
+fn status_style(theme: &Theme, status: &str) -> Style {
+    let color = match status.chars().next() {
+        Some('2') => theme.success,
+        Some('3') => theme.info,
+        Some('4') => theme.highlight,
+        Some('5') => theme.critical,
+        _ => theme.secondary,
+    };
+    Style::default().fg(color)
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        text.chars().take(max.saturating_sub(1)).collect::<String>() + "…"
+    }
+}
@@ -0,0 +1,80 @@
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use crate::workspace::SavedFilter;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_saved_view_picker(
+    f: &mut Frame,
+    theme: &Theme,
+    filters: &[SavedFilter],
+    cursor: usize,
+) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("{} saved filter(s) and view(s)", filters.len()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for (i, filter) in filters.iter().enumerate() {
+        let style = if i == cursor {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let mut details = vec![format!("\"{}\"", filter.query)];
+        if let Some(view) = &filter.view {
+            details.push(format!("{} view", view));
+            details.extend(filter.sort.clone());
+            details.push(
+                filter
+                    .method
+                    .clone()
+                    .unwrap_or_else(|| "all methods".to_string()),
+            );
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}{}", if i == cursor { "► " } else { "  " }, filter.name),
+                style,
+            ),
+            Span::styled(
+                format!("  {}", details.join(" • ")),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "↑/↓ to choose, Enter to apply, ':save <name>' to add the current view, 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Saved Views "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -1,160 +1,336 @@
-use crate::app::{App, Panel};
+use crate::app::Panel;
+use crate::direction::SchemaDirection;
+use crate::schema_tree::{Composition, NodeKind};
+use crate::ui::theme::Theme;
+use crate::view_model::{SchemaTreeRow, SchemasViewModel};
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{List, ListItem, Paragraph, Wrap},
+    widgets::{List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-pub fn render_schemas_view(f: &mut Frame, app: &mut App, chunks: Vec<Rect>) {
+pub fn render_schemas_view(
+    f: &mut Frame,
+    theme: &Theme,
+    model: &SchemasViewModel,
+    chunks: Vec<Rect>,
+) {
     // Left panel - Schemas list
-    let schema_items: Vec<ListItem> = app
-        .filtered_schemas
+    let schema_items: Vec<ListItem> = model
+        .rows
         .iter()
         .map(|schema| {
-            let style = if Some(schema.as_str()) == app.selected_schema.as_deref() {
+            let style = if schema.row.selected {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             };
-            ListItem::new(schema.as_str()).style(style)
+            let icon = schema.direction.map_or(" ", SchemaDirection::icon);
+            if schema.compare_base {
+                ListItem::new(format!("{} {} ⇄", icon, schema.row.label))
+                    .style(style.fg(theme.info))
+            } else {
+                ListItem::new(format!("{} {}", icon, schema.row.label)).style(style)
+            }
         })
         .collect();
 
     let schemas_list = List::new(schema_items)
         .block(crate::ui::layout::panel_block(
+            theme,
             "Schemas",
-            app.current_panel == Panel::Left,
+            model.panel == Panel::Left,
         ))
         .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
     f.render_widget(schemas_list, chunks[0]);
 
-    // Center panel - Schema details
-    if let Some(selected_schema) = &app.selected_schema {
-        if let Some(schema) = app.field_index.schemas.get(selected_schema) {
-            let fields = schema.get_field_names();
+    // Center panel - Schema summary above its property tree
+    if model.selected.is_some() {
+        if let Some(schema) = &model.details {
+            let discriminator = schema.discriminator.as_deref();
+            let composition = &schema.composition;
+            let is_active = model.panel == Panel::Center;
+            let block = crate::ui::layout::panel_block(theme, "Schema Details", is_active);
+            let inner = block.inner(chunks[1]);
+            f.render_widget(block, chunks[1]);
+
             let mut details_text = vec![
                 Line::from(vec![
-                    Span::styled("Schema: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Schema: ", Style::default().fg(theme.accent)),
                     Span::styled(
-                        selected_schema,
+                        schema.name.as_str(),
                         Style::default().add_modifier(Modifier::BOLD),
                     ),
                 ]),
-                Line::from(""),
                 Line::from(vec![
-                    Span::styled("Type: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Type: ", Style::default().fg(theme.accent)),
+                    Span::styled(schema.schema_type.as_str(), Style::default()),
+                    Span::styled("   Fields: ", Style::default().fg(theme.accent)),
                     Span::styled(
-                        schema.schema_type.as_deref().unwrap_or("object"),
+                        if composition.parents.is_empty() {
+                            format!("{} fields", schema.fields)
+                        } else {
+                            format!(
+                                "{} own, {} inherited",
+                                composition.own.len(),
+                                composition.inherited.len()
+                            )
+                        },
+                        Style::default(),
+                    ),
+                    Span::styled("   Used in: ", Style::default().fg(theme.accent)),
+                    Span::styled(
+                        schema
+                            .direction
+                            .map_or("unknown", |direction| direction.label()),
                         Style::default(),
                     ),
                 ]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("Fields: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(format!("{} fields", fields.len()), Style::default()),
-                ]),
-                Line::from(""),
             ];
-
+            details_text.extend(composition_lines(theme, composition));
+            // Wrapped description lines, estimated from the panel width
+            let mut header_height = details_text.len() as u16 + 1;
             if let Some(description) = &schema.description {
                 details_text.push(Line::from(vec![
-                    Span::styled("Description: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(description, Style::default()),
+                    Span::styled("Description: ", Style::default().fg(theme.accent)),
+                    Span::styled(description.as_str(), Style::default()),
                 ]));
-                details_text.push(Line::from(""));
+                let width = inner.width.max(1) as usize;
+                header_height +=
+                    ("Description: ".len() + description.chars().count()).div_ceil(width) as u16;
             }
-
-            details_text.push(Line::from("Field List:"));
-            for (i, field) in fields.iter().enumerate() {
-                let field_type = schema
-                    .get_field_type(field)
-                    .unwrap_or_else(|| "unknown".to_string());
-                details_text.push(Line::from(vec![
-                    Span::styled(
-                        format!("  {}. ", i + 1),
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                    Span::styled(field, Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled(
-                        format!(" ({})", field_type),
-                        Style::default().fg(Color::Green),
-                    ),
-                ]));
+            if let Some(docs) = &schema.docs {
+                let line = crate::ui::layout::docs_line(theme, docs);
+                header_height += (line.width().max(1)).div_ceil(inner.width.max(1) as usize) as u16;
+                details_text.push(line);
             }
+            details_text.push(Line::from(Span::styled(
+                if is_active {
+                    "Tree (←/→ collapse/expand, Enter toggles):"
+                } else {
+                    "Tree:"
+                },
+                Style::default().fg(theme.muted),
+            )));
 
-            let details_widget = Paragraph::new(details_text)
-                .wrap(Wrap { trim: true })
-                .block(crate::ui::layout::panel_block(
-                    "Schema Details",
-                    app.current_panel == Panel::Center,
-                ));
-            f.render_widget(details_widget, chunks[1]);
+            let areas = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(header_height), Constraint::Min(1)])
+                .split(inner);
+            f.render_widget(
+                Paragraph::new(details_text).wrap(Wrap { trim: true }),
+                areas[0],
+            );
+
+            let rows = &schema.tree;
+            let items: Vec<ListItem> = rows
+                .iter()
+                .map(|row| tree_row_item(theme, row, discriminator))
+                .collect();
+            let tree =
+                List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            let mut state =
+                ListState::default().with_selected(is_active.then_some(model.tree_cursor));
+            f.render_stateful_widget(tree, areas[1], &mut state);
+            if rows.len() > areas[1].height as usize {
+                crate::ui::layout::render_scrollbar(
+                    f,
+                    theme,
+                    chunks[1],
+                    rows.len(),
+                    model.tree_cursor,
+                );
+            }
         } else {
             let no_details = Paragraph::new("Schema not found")
-                .style(Style::default().fg(Color::Red))
+                .style(Style::default().fg(theme.critical))
                 .block(crate::ui::layout::panel_block(
+                    theme,
                     "Schema Details",
-                    app.current_panel == Panel::Center,
+                    model.panel == Panel::Center,
                 ));
             f.render_widget(no_details, chunks[1]);
         }
     } else {
         let no_selection = Paragraph::new("Select a schema to view details")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Schema Details",
-                app.current_panel == Panel::Center,
+                model.panel == Panel::Center,
             ));
         f.render_widget(no_selection, chunks[1]);
     }
 
     // Right panel - Related endpoints
-    if let Some(selected_schema) = &app.selected_schema {
-        let schema_fields = app.field_index.get_schema_fields(selected_schema);
-        let mut related_endpoints = std::collections::HashSet::new();
-
-        for field in &schema_fields {
-            let endpoints = app.field_index.get_endpoints_for_field(field);
-            for endpoint in endpoints {
-                related_endpoints.insert(endpoint);
-            }
-        }
-
+    if model.selected.is_some() {
+        let related_endpoints = &model.related_endpoints;
         let endpoint_items: Vec<ListItem> = related_endpoints
             .iter()
             .map(|endpoint| {
-                let is_critical = endpoint.to_lowercase().contains("post")
-                    || endpoint.to_lowercase().contains("put");
-                let style = if is_critical {
-                    Style::default().fg(Color::Red)
-                } else {
-                    Style::default()
-                };
-                ListItem::new(endpoint.as_str()).style(style)
+                ListItem::new(endpoint.as_str())
+                    .style(crate::ui::layout::endpoint_style(theme, endpoint))
             })
             .collect();
 
-        let title = format!("Related Endpoints ({})", related_endpoints.len());
+        let is_active = model.panel == Panel::Right;
+        let title = if is_active {
+            format!(
+                "Related Endpoints ({}) — Enter to open",
+                related_endpoints.len()
+            )
+        } else {
+            format!("Related Endpoints ({})", related_endpoints.len())
+        };
         let endpoints_list = List::new(endpoint_items)
-            .block(crate::ui::layout::panel_block(
-                &title,
-                app.current_panel == Panel::Right,
-            ))
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            .block(crate::ui::layout::panel_block(theme, &title, is_active))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("► ");
 
-        f.render_widget(endpoints_list, chunks[2]);
+        let mut state =
+            ListState::default().with_selected(is_active.then_some(model.detail_cursor));
+        f.render_stateful_widget(endpoints_list, chunks[2], &mut state);
     } else {
         let no_endpoints = Paragraph::new("Select a schema to see related endpoints")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .block(crate::ui::layout::panel_block(
+                theme,
                 "Related Endpoints",
-                app.current_panel == Panel::Right,
+                model.panel == Panel::Right,
             ));
         f.render_widget(no_endpoints, chunks[2]);
     }
 }
+
+/// Field name in bold, highlighted when it is the discriminator property.
+fn field_span<'a>(theme: &Theme, field: &'a str, discriminator: Option<&str>) -> Span<'a> {
+    if Some(field) == discriminator {
+        Span::styled(
+            format!("{} ◆", field),
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(field, Style::default().add_modifier(Modifier::BOLD))
+    }
+}
+
+/// Parent schemas and alternatives above the tree, one line each.
+fn composition_lines<'a>(theme: &Theme, composition: &'a Composition) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+    if !composition.parents.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Extends: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                composition.parents.join(", "),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "  (g on a section opens it)",
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+    for (keyword, alternatives) in &composition.alternatives {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", keyword), Style::default().fg(theme.accent)),
+            Span::styled(
+                alternatives.join(" | "),
+                Style::default().fg(theme.secondary),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// Row of the schema tree: ▸/▾ for collapsed and expanded nodes, `*` for
+/// required properties, ↺ for references back to an enclosing schema;
+/// fields inherited from a parent schema are dimmed.
+fn tree_row_item<'a>(
+    theme: &Theme,
+    tree_row: &'a SchemaTreeRow,
+    discriminator: Option<&str>,
+) -> ListItem<'a> {
+    let row = &tree_row.row;
+    let marker = if row.recursive {
+        "↺ "
+    } else if row.expanded {
+        "▾ "
+    } else if row.expandable {
+        "▸ "
+    } else {
+        "  "
+    };
+    let mut spans = vec![Span::styled(
+        format!("{}{}", "  ".repeat(row.depth), marker),
+        Style::default().fg(theme.muted),
+    )];
+    match row.kind {
+        NodeKind::Property if row.inherited && Some(row.label.as_str()) != discriminator => {
+            spans.push(Span::styled(
+                row.label.as_str(),
+                Style::default().fg(theme.muted),
+            ));
+            spans.push(Span::styled(
+                format!(" ({})", row.type_label),
+                Style::default().fg(theme.success),
+            ));
+        }
+        NodeKind::Property => {
+            spans.push(field_span(theme, &row.label, discriminator));
+            if row.required {
+                spans.push(Span::styled("*", Style::default().fg(theme.critical)));
+            }
+            spans.push(Span::styled(
+                format!(" ({})", row.type_label),
+                Style::default().fg(theme.success),
+            ));
+        }
+        NodeKind::Branch("extends") => {
+            spans.push(Span::styled("extends ", Style::default().fg(theme.muted)));
+            spans.push(Span::styled(
+                row.label.as_str(),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        NodeKind::Branch(keyword) => {
+            spans.push(Span::styled(
+                format!("{} ", keyword),
+                Style::default().fg(theme.muted),
+            ));
+            spans.push(Span::styled(
+                row.label.as_str(),
+                Style::default().fg(theme.secondary),
+            ));
+            if row.type_label != row.label {
+                spans.push(Span::styled(
+                    format!(" → {}", row.type_label),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+            }
+        }
+    }
+    if row.recursive {
+        spans.push(Span::styled(
+            " (recursive)",
+            Style::default().fg(theme.secondary),
+        ));
+    }
+    if let Some(label) = &tree_row.sensitive {
+        spans.push(Span::styled(
+            format!(" ⚠ {}", label),
+            crate::ui::sensitive::sensitive_style(theme),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
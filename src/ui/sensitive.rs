@@ -0,0 +1,90 @@
+use crate::sensitive::SensitiveExposure;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Style used to highlight sensitive fields in every view
+pub fn sensitive_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.secondary)
+}
+
+pub fn render_sensitive_popup(f: &mut Frame, theme: &Theme, exposures: &[SensitiveExposure]) {
+    let endpoint_count = exposures
+        .iter()
+        .map(|e| e.endpoint.as_str())
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Sensitive fields exposed in responses",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} endpoint(s) • {} response(s)",
+            endpoint_count,
+            exposures.len()
+        )),
+        Line::from(""),
+    ];
+
+    if exposures.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No response exposes a sensitive field",
+            Style::default().fg(theme.success),
+        )));
+    }
+
+    for exposure in exposures {
+        lines.push(Line::from(vec![
+            Span::styled(
+                exposure.endpoint.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", exposure.status),
+                Style::default().fg(theme.info),
+            ),
+        ]));
+        for field in &exposure.fields {
+            lines.push(Line::from(vec![
+                Span::raw("  • "),
+                Span::styled(field.name.clone(), sensitive_style(theme)),
+                Span::styled(
+                    format!("  ({})", field.label),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Sensitive Data Report "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
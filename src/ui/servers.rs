@@ -0,0 +1,116 @@
+use crate::app::ServerPicker;
+use crate::parser::Server;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_server_picker_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    servers: &[Server],
+    picker: &ServerPicker,
+    active_server: Option<usize>,
+) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Servers",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for (i, server) in servers.iter().enumerate() {
+        let is_cursor = i == picker.cursor;
+        let prefix = if is_cursor { "► " } else { "  " };
+        let style = if is_cursor {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+
+        let mut spans = vec![Span::styled(format!("{}{}", prefix, server.url), style)];
+        if active_server == Some(i) {
+            spans.push(Span::styled(" ✓", Style::default().fg(theme.success)));
+        }
+        if let Some(description) = &server.description {
+            spans.push(Span::styled(
+                format!("  {}", description),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines.push(Line::from(spans));
+
+        if !is_cursor {
+            continue;
+        }
+
+        // Variables of the server under the cursor
+        let values = &picker.values[i];
+        for (j, (name, value)) in values.iter().enumerate() {
+            let focused = j == picker.variable_focus;
+            let options = server
+                .variables
+                .as_ref()
+                .and_then(|variables| variables.get(name))
+                .and_then(|variable| variable.enum_.as_ref());
+            let mut spans = vec![
+                Span::raw(if focused { "    ▸ " } else { "      " }),
+                Span::styled(format!("{}: ", name), Style::default().fg(theme.highlight)),
+                Span::styled(
+                    value.clone(),
+                    if focused {
+                        Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                    } else {
+                        Style::default()
+                    },
+                ),
+            ];
+            if let Some(options) = options {
+                spans.push(Span::styled(
+                    format!("  [{}]", options.join(" | ")),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("    → ", Style::default().fg(theme.muted)),
+            Span::styled(
+                crate::servers::resolve_server_url(server, values),
+                Style::default().fg(theme.success),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "↑↓: server • Tab: variable • ←→: enum value • type to edit • Enter: use • Esc: close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Base URL "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,127 @@
+use crate::app::{ScrollTarget, TextScrolls};
+use crate::ui::layout::{popup_area, render_scrolled_paragraph};
+use crate::ui::theme::Theme;
+use crate::view_model::SpecInfoViewModel;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Identity of the active spec (info, contact, license, documentation,
+/// source) and what it contains.
+pub fn render_spec_info_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &SpecInfoViewModel,
+) {
+    let heading = |text: &'static str| {
+        Line::from(Span::styled(
+            text,
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        ))
+    };
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("  {:<12}", label), Style::default().fg(theme.muted)),
+            Span::raw(value),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                model.title.clone(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  v{}  (OpenAPI {})", model.version, model.openapi),
+                Style::default().fg(theme.muted),
+            ),
+        ]),
+        Line::from(""),
+    ];
+    if let Some(description) = &model.description {
+        lines.extend(description.lines().map(|line| Line::from(line.to_string())));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(heading("About"));
+    let source = model
+        .source
+        .clone()
+        .unwrap_or_else(|| "(not from a file)".to_string());
+    lines.push(row("Source", source));
+    if let Some((position, loaded)) = model.position {
+        lines.push(row("Workspace", format!("spec {} of {}", position, loaded)));
+    }
+    for (label, value) in [
+        ("Contact", &model.contact),
+        ("License", &model.license),
+        ("Terms", &model.terms),
+        ("Docs", &model.docs),
+    ] {
+        if let Some(value) = value {
+            lines.push(row(label, value.clone()));
+        }
+    }
+    for server in &model.servers {
+        lines.push(row("Server", server.clone()));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Contents"));
+    lines.push(row("Paths", model.paths.to_string()));
+    lines.push(row(
+        "Operations",
+        format!("{} ({} write)", model.operations, model.write_operations),
+    ));
+    lines.push(row("Schemas", model.schemas.to_string()));
+    lines.push(row("Fields", model.fields.to_string()));
+    lines.push(row("Tags", model.tags.to_string()));
+    lines.push(row("Webhooks", model.webhooks.to_string()));
+    if model.dropped > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  ⚠ {} element(s) dropped by lenient parsing",
+                model.dropped
+            ),
+            Style::default().fg(theme.critical),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "↑/↓ PgUp/PgDn: scroll  i/Esc: close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Spec Info "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+    render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::SpecInfo,
+        "spec info",
+        widget,
+        area,
+    );
+}
@@ -0,0 +1,204 @@
+use crate::multi_spec::FieldUsage;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use crate::view_model::SpecListViewModel;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+fn render_popup(f: &mut Frame, theme: &Theme, title: &str, lines: Vec<Line>) {
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(title),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
+
+pub fn render_spec_switcher_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    model: &SpecListViewModel,
+    cursor: usize,
+) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("{} loaded specs", model.specs.len()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for (i, entry) in model.specs.iter().enumerate() {
+        let style = if i == cursor {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+
+        let mut spans = vec![
+            Span::styled(
+                format!("{}{}", if i == cursor { "► " } else { "  " }, entry.name),
+                style,
+            ),
+            Span::styled(
+                format!("  {} path(s) • {} field(s)", entry.paths, entry.fields),
+                Style::default().fg(theme.muted),
+            ),
+        ];
+        if i == model.active {
+            spans.push(Span::styled(" ✓", Style::default().fg(theme.success)));
+        }
+        lines.push(Line::from(spans));
+        if let Some(path) = &entry.file_path {
+            lines.push(Line::from(Span::styled(
+                format!("    {}", path),
+                Style::default().fg(theme.muted),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "↑↓: select • Enter: switch • Esc: close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    render_popup(f, theme, " Specs ", lines);
+}
+
+/// The specs of the watched directories, left of the panels: the active
+/// one checked, the switcher cursor (`S`) highlighted, and the files that
+/// failed to load last.
+pub fn render_spec_rail(f: &mut Frame, theme: &Theme, model: &SpecListViewModel, area: Rect) {
+    let mut lines = Vec::new();
+    for (i, entry) in model.specs.iter().enumerate() {
+        let selected = model.cursor == Some(i);
+        let style = if selected {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else if i == model.active {
+            Style::default().fg(theme.success)
+        } else {
+            Style::default()
+        };
+        let marker = match (selected, i == model.active) {
+            (true, _) => "► ",
+            (false, true) => "✓ ",
+            (false, false) => "  ",
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", marker, entry.name),
+            style,
+        )));
+    }
+    for name in &model.failures {
+        lines.push(Line::from(Span::styled(
+            format!("✗ {}", name),
+            Style::default().fg(theme.critical),
+        )));
+    }
+
+    let (title, border) = if model.cursor.is_some() {
+        (" Specs: ↑↓ Enter ", theme.accent)
+    } else {
+        (" Specs (S) ", theme.muted)
+    };
+    let widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+            .title(title),
+    );
+    f.render_widget(widget, area);
+}
+
+pub fn render_cross_spec_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    field_name: &str,
+    usages: &[FieldUsage],
+) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("Field '{}' across services", field_name),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!("Declared by {} spec(s)", usages.len())),
+        Line::from(""),
+    ];
+
+    let conflicting = usages
+        .iter()
+        .any(|usage| usage.field_type != usages[0].field_type);
+    if conflicting {
+        lines.push(Line::from(vec![Span::styled(
+            "⚠ Declared with different types",
+            Style::default()
+                .fg(theme.critical)
+                .add_modifier(Modifier::BOLD),
+        )]));
+        lines.push(Line::from(""));
+    }
+
+    for usage in usages {
+        lines.push(Line::from(vec![
+            Span::styled(
+                usage.spec.clone(),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(
+                format!("  {}", usage.field_type),
+                Style::default().fg(if conflicting {
+                    theme.critical
+                } else {
+                    theme.success
+                }),
+            ),
+        ]));
+        lines.push(Line::from(format!(
+            "  • Schemas: {}",
+            if usage.schemas.is_empty() {
+                "-".to_string()
+            } else {
+                usage.schemas.join(", ")
+            }
+        )));
+        lines.push(Line::from(format!(
+            "  • Used by {} endpoint(s)",
+            usage.endpoints
+        )));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    render_popup(f, theme, " Cross-Service Usage ", lines);
+}
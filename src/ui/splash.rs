@@ -0,0 +1,189 @@
+use crate::app::{LoadKind, LoadRequest};
+use crate::cache::SpecCache;
+use crate::indexer::FieldIndex;
+use crate::parser::OpenApiSpec;
+use crate::progress::LoadStage;
+use crate::ui::load_error::render_load_error;
+use crate::ui::theme::Theme;
+use anyhow::Result;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame, Terminal,
+};
+use std::io;
+use std::path::Path;
+use tokio::sync::mpsc;
+
+type SplashTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+
+/// Loads the spec the explorer starts with behind a splash screen, with a
+/// gauge following the load stages. Raw mode stays off so Ctrl+C still
+/// interrupts a load that takes too long. When the load fails, the error
+/// is shown (located in the document for parse errors) until the user
+/// retries or quits.
+pub async fn load_with_splash(
+    path: &Path,
+    cache: Option<&SpecCache>,
+    max_depth: usize,
+    lenient: bool,
+    theme: Theme,
+) -> Result<(OpenApiSpec, FieldIndex)> {
+    let request = LoadRequest {
+        kind: LoadKind::Open,
+        path: path.to_path_buf(),
+        cache: cache.cloned(),
+        max_depth,
+        lenient,
+        url: None,
+    };
+
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+    let loaded = loop {
+        let error = match load_with_gauge(&mut terminal, &theme, request.clone()).await {
+            Ok(loaded) => break Ok(loaded),
+            Err(error) => error,
+        };
+        match wait_for_retry(&mut terminal, &theme, path, &error).await {
+            Ok(true) => {}
+            Ok(false) => break Err(error),
+            Err(e) => break Err(e),
+        }
+    };
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, cursor::Show)?;
+    loaded
+}
+
+async fn load_with_gauge(
+    terminal: &mut SplashTerminal,
+    theme: &Theme,
+    request: LoadRequest,
+) -> Result<(OpenApiSpec, FieldIndex)> {
+    let name = request
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let (stages, mut receiver) = mpsc::unbounded_channel();
+    let mut task = tokio::spawn(async move {
+        let progress = move |stage: LoadStage| {
+            let _ = stages.send(stage);
+        };
+        request.run(&progress).await
+    });
+
+    let mut stage = LoadStage::Reading;
+    let load = loop {
+        terminal.draw(|f| render_splash(f, theme, &name, &stage))?;
+        tokio::select! {
+            Some(next) = receiver.recv() => {
+                stage = next;
+                // Only the latest stage is worth drawing
+                while let Ok(next) = receiver.try_recv() {
+                    stage = next;
+                }
+            }
+            load = &mut task => break load,
+        }
+    };
+    load?
+        .result?
+        .ok_or_else(|| anyhow::anyhow!("Only reloads skip unchanged specs"))
+}
+
+/// Shows why the load failed until `r` (retry, returns true) or `q`/Esc
+/// (returns false). Raw mode is only on while waiting for the key.
+async fn wait_for_retry(
+    terminal: &mut SplashTerminal,
+    theme: &Theme,
+    path: &Path,
+    error: &anyhow::Error,
+) -> Result<bool> {
+    enable_raw_mode()?;
+    let retry = loop {
+        let drawn = terminal.draw(|f| {
+            render_load_error(f, theme, f.area(), path, error, "r: retry  q: quit");
+        });
+        if let Err(e) = drawn {
+            break Err(e.into());
+        }
+        let key = match tokio::task::spawn_blocking(event::read).await {
+            Ok(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => key,
+            Ok(Ok(_)) => continue,
+            Ok(Err(e)) => break Err(e.into()),
+            Err(e) => break Err(e.into()),
+        };
+        match key.code {
+            KeyCode::Char('r') => break Ok(true),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break Ok(false),
+            KeyCode::Char('q') | KeyCode::Esc => break Ok(false),
+            _ => {}
+        }
+    };
+    disable_raw_mode()?;
+    retry
+}
+
+fn render_splash(f: &mut Frame, theme: &Theme, name: &str, stage: &LoadStage) {
+    let area = f.area();
+    let width = area.width.min(60);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(6) / 2,
+        width,
+        height: area.height.min(6),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" OpenAPI Explorer ")
+        .style(Style::default().bg(theme.background).fg(theme.text));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::raw("Loading "),
+            Span::styled(name, Style::default().add_modifier(Modifier::BOLD)),
+        ]))
+        .alignment(Alignment::Center),
+        chunks[0],
+    );
+    f.render_widget(
+        Gauge::default()
+            .gauge_style(Style::default().fg(theme.accent).bg(theme.background))
+            .ratio(stage.ratio())
+            .label(format!("{:.0}%", stage.ratio() * 100.0)),
+        chunks[1],
+    );
+    f.render_widget(
+        Paragraph::new(Line::from(vec![Span::styled(
+            stage.to_string(),
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )]))
+        .alignment(Alignment::Center),
+        chunks[2],
+    );
+}
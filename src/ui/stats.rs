@@ -0,0 +1,715 @@
+use crate::indexer::HttpMethod;
+use crate::lint::Severity;
+use crate::stats::{Bucket, StatsSection};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use crate::view_model::StatsViewModel;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Clear, Gauge, Paragraph, Sparkline, Wrap},
+    Frame,
+};
+
+pub fn render_stats_view(f: &mut Frame, theme: &Theme, model: &StatsViewModel, chunks: Vec<Rect>) {
+    let analysis = model.analysis;
+    let counts = &analysis.counts;
+
+    // Build stats text
+    let mut stats_text = vec![
+        Line::from(vec![Span::styled(
+            "📊 OpenAPI Statistics",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            "↑/↓ select a chart, Enter lists its items",
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Overview",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]),
+        Line::from(format!("  • Schemas: {}", counts.schemas)),
+        Line::from(format!("  • Fields: {}", counts.fields)),
+        Line::from(format!("  • Endpoints: {}", counts.paths)),
+        Line::from(format!(
+            "  • Write Operations: {} (POST, PUT, PATCH, DELETE)",
+            counts.write_operations
+        )),
+        Line::from(format!(
+            "  • Critical Fields: {} ({:.1}%)",
+            counts.critical_fields,
+            (counts.critical_fields as f64 / counts.fields.max(1) as f64) * 100.0
+        )),
+        Line::from(""),
+    ];
+
+    // Change since the last recorded snapshot
+    if let Some(trend) = model.trend {
+        stats_text.push(Line::from(vec![Span::styled(
+            "Trend",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        let color = if trend.is_unchanged() {
+            theme.muted
+        } else {
+            theme.info
+        };
+        stats_text.push(Line::from(vec![
+            Span::raw("  • "),
+            Span::styled(trend.summary(), Style::default().fg(color)),
+        ]));
+        stats_text.push(Line::from(vec![Span::styled(
+            format!("    (snapshot of {})", trend.since),
+            Style::default().fg(theme.muted),
+        )]));
+        stats_text.push(Line::from(""));
+    }
+
+    // Most used fields
+    if !analysis.top_fields.is_empty() {
+        stats_text.push(Line::from(vec![Span::styled(
+            "Top Fields (by endpoint usage)",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        for (field_name, usage_count) in analysis.top_fields.iter().take(5) {
+            stats_text.push(Line::from(format!(
+                "  • {}: {} endpoint(s)",
+                field_name, usage_count
+            )));
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Largest and most nested schemas, candidates for splitting
+    let sizes = &analysis.schema_sizes;
+    if !sizes.is_empty() {
+        stats_text.push(Line::from(vec![Span::styled(
+            "Schema Sizes",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        let large = sizes.iter().filter(|size| size.is_large()).count();
+        let deep = sizes.iter().filter(|size| size.is_deep()).count();
+        if large + deep > 0 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ⚠ {} schema(s) with {}+ fields, {} nested {}+ levels",
+                    large,
+                    crate::stats::LARGE_SCHEMA_FIELDS,
+                    deep,
+                    crate::stats::DEEP_SCHEMA_NESTING
+                ),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+        let size_style = |flagged: bool| {
+            if flagged {
+                Style::default().fg(theme.critical)
+            } else {
+                Style::default()
+            }
+        };
+        stats_text.push(Line::from("  Largest:"));
+        for size in sizes.iter().take(5) {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!("    • {}: {} field(s)", size.name, size.fields),
+                size_style(size.is_large()),
+            )]));
+        }
+        let mut deepest: Vec<&crate::stats::SchemaSize> =
+            sizes.iter().filter(|size| size.depth > 1).collect();
+        deepest.sort_by(|a, b| b.depth.cmp(&a.depth).then(a.name.cmp(&b.name)));
+        if !deepest.is_empty() {
+            stats_text.push(Line::from("  Deepest:"));
+            for size in deepest.iter().take(5) {
+                stats_text.push(Line::from(vec![Span::styled(
+                    format!("    • {}: {} level(s)", size.name, size.depth),
+                    size_style(size.is_deep()),
+                )]));
+            }
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Payload weight ranking
+    let payloads = &analysis.payloads;
+    if !payloads.is_empty() {
+        stats_text.push(Line::from(vec![Span::styled(
+            "Payload Weight (estimated 2xx responses)",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        let large_count = payloads.iter().filter(|p| p.is_large()).count();
+        if large_count > 0 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ⚠ {} endpoint(s) above {}",
+                    large_count,
+                    crate::payload::format_bytes(crate::payload::LARGE_PAYLOAD_BYTES)
+                ),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+        for payload in payloads.iter().take(5) {
+            let style = if payload.is_large() {
+                Style::default().fg(theme.critical)
+            } else {
+                Style::default()
+            };
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  • {} [{}]: ~{} ({} values)",
+                    payload.endpoint,
+                    payload.status,
+                    crate::payload::format_bytes(payload.bytes),
+                    payload.field_count
+                ),
+                style,
+            )]));
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Deprecations and removal schedule
+    let deprecations = &analysis.deprecations;
+    if !deprecations.is_empty() {
+        let today = model.today;
+        stats_text.push(Line::from(vec![Span::styled(
+            "Deprecations",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        stats_text.push(Line::from(format!(
+            "  • {} deprecated endpoint(s), {} with a sunset date",
+            deprecations.len(),
+            deprecations.iter().filter(|d| d.sunset.is_some()).count()
+        )));
+        let overdue = deprecations.iter().filter(|d| d.is_overdue(today)).count();
+        if overdue > 0 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!("  ⚠ {} removal(s) overdue", overdue),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+        if let Some(next) = deprecations
+            .iter()
+            .find(|d| d.sunset.is_some_and(|sunset| sunset >= today))
+        {
+            let status = next.status(today);
+            stats_text.push(Line::from(vec![
+                Span::raw(format!("  • Next removal: {} ", next.endpoint)),
+                Span::styled(
+                    format!("({})", status.describe()),
+                    Style::default().fg(crate::ui::timeline::status_color(theme, &status)),
+                ),
+            ]));
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Fields shared with the other loaded specs
+    if let Some(cross) = &model.cross_service {
+        let summaries = &cross.stats.summaries;
+        stats_text.push(Line::from(vec![Span::styled(
+            "Cross-Service",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        stats_text.push(Line::from(format!(
+            "  • {} specs loaded, viewing {}",
+            summaries.len(),
+            cross.active
+        )));
+        let total = |count: fn(&crate::multi_spec::SpecSummary) -> usize| {
+            summaries.iter().map(count).sum::<usize>()
+        };
+        stats_text.push(Line::from(format!(
+            "  • Total: {} path(s), {} operation(s), {} schema(s), {} distinct field name(s)",
+            total(|summary| summary.paths),
+            total(|summary| summary.operations),
+            total(|summary| summary.schemas),
+            cross.stats.distinct_fields
+        )));
+        stats_text.push(Line::from(format!(
+            "  • {} field name(s) shared across specs",
+            cross.stats.shared_fields
+        )));
+        for summary in summaries {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "    - {}: {} path(s), {} operation(s), {} schema(s), {} field(s)",
+                    summary.name,
+                    summary.paths,
+                    summary.operations,
+                    summary.schemas,
+                    summary.fields
+                ),
+                Style::default().fg(theme.muted),
+            )]));
+        }
+        if cross.failures > 0 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ⚠ {} file(s) of the watched directory fail to load",
+                    cross.failures
+                ),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+        if cross.stats.type_conflicts > 0 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ⚠ {} shared field(s) with differing types",
+                    cross.stats.type_conflicts
+                ),
+                Style::default().fg(theme.critical),
+            )]));
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Error response consistency
+    let taxonomy = &analysis.error_taxonomy;
+    if !taxonomy.responses.is_empty() {
+        stats_text.push(Line::from(vec![Span::styled(
+            "Error Consistency (4xx/5xx/default)",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::UNDERLINED),
+        )]));
+        let coverage = taxonomy.coverage();
+        let coverage_color = if coverage >= 90.0 {
+            theme.success
+        } else if coverage >= 60.0 {
+            theme.highlight
+        } else {
+            theme.critical
+        };
+        stats_text.push(Line::from(vec![
+            Span::raw(format!(
+                "  • Canonical: {} ",
+                taxonomy
+                    .canonical
+                    .as_ref()
+                    .map(|shape| shape.label())
+                    .unwrap_or_else(|| "none".to_string())
+            )),
+            Span::styled(
+                format!("({:.1}% coverage)", coverage),
+                Style::default().fg(coverage_color),
+            ),
+        ]));
+        stats_text.push(Line::from(format!(
+            "  • {} error response(s), {} shape(s), {} Problem+JSON",
+            taxonomy.responses.len(),
+            taxonomy.shapes.len(),
+            taxonomy.problem_json_count()
+        )));
+        for (shape, count) in taxonomy.shapes.iter().skip(1).take(4) {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!("    - {}: {}", shape.label(), count),
+                Style::default().fg(theme.muted),
+            )]));
+        }
+        let inconsistent = taxonomy.inconsistent_endpoints();
+        if !inconsistent.is_empty() {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!(
+                    "  ⚠ {} endpoint(s) with other error shapes:",
+                    inconsistent.len()
+                ),
+                Style::default().fg(theme.critical),
+            )]));
+            for endpoint in inconsistent.iter().take(5) {
+                stats_text.push(Line::from(format!("    • {}", endpoint)));
+            }
+            if inconsistent.len() > 5 {
+                stats_text.push(Line::from(vec![Span::styled(
+                    format!("    ... and {} more", inconsistent.len() - 5),
+                    Style::default()
+                        .fg(theme.muted)
+                        .add_modifier(Modifier::ITALIC),
+                )]));
+            }
+        }
+        stats_text.push(Line::from(""));
+    }
+
+    // Validation warnings, most severe first; suppressed ones only counted
+    let diagnostics = &model.diagnostics;
+    let suppressed = model.suppressed;
+    if !diagnostics.is_empty() {
+        let count = |severity| {
+            diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == severity)
+                .count()
+        };
+        stats_text.push(Line::from(vec![
+            Span::styled(
+                "⚠ Validation Warnings",
+                Style::default()
+                    .fg(theme.critical)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(
+                format!(
+                    "  {} error(s), {} warning(s), {} hint(s)",
+                    count(Severity::Error),
+                    count(Severity::Warning),
+                    count(Severity::Hint)
+                ),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+        for (i, diagnostic) in diagnostics.iter().enumerate().take(10) {
+            let color = match diagnostic.severity {
+                Severity::Error => theme.critical,
+                Severity::Warning => theme.highlight,
+                Severity::Hint => theme.info,
+            };
+            stats_text.push(Line::from(vec![
+                Span::styled(format!("  {}. ", i + 1), Style::default().fg(color)),
+                Span::styled(
+                    format!("[{}] ", diagnostic.severity),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(diagnostic.message.as_str()),
+                Span::styled(
+                    format!("  {}", diagnostic.rule),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+        if diagnostics.len() > 10 {
+            stats_text.push(Line::from(vec![Span::styled(
+                format!("  ... and {} more warnings", diagnostics.len() - 10),
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::ITALIC),
+            )]));
+        }
+    } else {
+        stats_text.push(Line::from(vec![Span::styled(
+            "✓ No validation warnings",
+            Style::default().fg(theme.success),
+        )]));
+    }
+    if suppressed > 0 {
+        stats_text.push(Line::from(vec![Span::styled(
+            format!(
+                "  {} suppressed (x-explorer-ignore or [lint] ignore)",
+                suppressed
+            ),
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::ITALIC),
+        )]));
+    }
+
+    let stats_widget = Paragraph::new(stats_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Statistics Dashboard"),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(stats_widget, chunks[1]);
+
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(chunks[0]);
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7),
+            Constraint::Length(6),
+            Constraint::Min(0),
+        ])
+        .split(chunks[2]);
+
+    render_bar_chart(
+        f,
+        theme,
+        model,
+        StatsSection::FieldTypes,
+        &analysis.field_types,
+        left[0],
+    );
+    render_bar_chart(
+        f,
+        theme,
+        model,
+        StatsSection::Methods,
+        &analysis.methods,
+        left[1],
+    );
+    let sizes = &analysis.schema_size_buckets;
+    render_bar_chart(f, theme, model, StatsSection::SchemaSizes, sizes, left[2]);
+
+    let coverage = &analysis.documentation;
+    let ratio = coverage.ratio();
+    // Against the configured minimum when there is one
+    let gauge_color = match model.min_doc_coverage {
+        Some(min_percent) if coverage.meets(min_percent) => theme.success,
+        Some(_) => theme.critical,
+        None if ratio >= 0.9 => theme.success,
+        None if ratio >= 0.6 => theme.highlight,
+        None => theme.critical,
+    };
+    let block = section_block(theme, model, StatsSection::Documentation);
+    let documentation = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(block.inner(right[0]));
+    f.render_widget(block, right[0]);
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(gauge_color))
+        .ratio(ratio)
+        .label(match model.min_doc_coverage {
+            Some(min_percent) => format!(
+                "{:.0}% ({}/{}), minimum {}%",
+                ratio * 100.0,
+                coverage.documented,
+                coverage.total,
+                min_percent
+            ),
+            None => format!(
+                "{:.0}% ({}/{})",
+                ratio * 100.0,
+                coverage.documented,
+                coverage.total
+            ),
+        });
+    f.render_widget(gauge, documentation[0]);
+    let percent = |ratio: f64| format!("{:.0}%", ratio * 100.0);
+    let muted = Style::default().fg(theme.muted);
+    let quality = vec![
+        Line::from(vec![
+            Span::styled("Operations ", muted),
+            Span::raw(percent(coverage.operation_ratio())),
+            Span::styled("  Fields ", muted),
+            Span::raw(percent(coverage.field_ratio())),
+            Span::styled("  Schemas ", muted),
+            Span::raw(percent(coverage.schema_ratio())),
+        ]),
+        Line::from(vec![
+            Span::styled("Average length ", muted),
+            Span::raw(format!("{:.0} chars", coverage.average_length)),
+        ]),
+        Line::from(vec![
+            Span::styled("Placeholders ", muted),
+            Span::styled(
+                format!(
+                    "{} endpoint(s) with TODO/TBD",
+                    coverage.placeholder_operations.len()
+                ),
+                if coverage.placeholder_operations.is_empty() {
+                    Style::default()
+                } else {
+                    Style::default().fg(theme.critical)
+                },
+            ),
+        ]),
+        Line::from(Span::styled("Enter lists undocumented items", muted)),
+    ];
+    f.render_widget(
+        Paragraph::new(quality).wrap(Wrap { trim: true }),
+        documentation[1],
+    );
+
+    let usage: Vec<u64> = analysis
+        .field_usage
+        .iter()
+        .map(|bucket| bucket.items.len() as u64)
+        .collect();
+    let sparkline = Sparkline::default()
+        .block(section_block(theme, model, StatsSection::FieldUsage))
+        .data(&usage)
+        .style(Style::default().fg(theme.info));
+    f.render_widget(sparkline, right[1]);
+
+    render_tags_panel(f, theme, model, right[2]);
+}
+
+/// One row per tag; Enter lists the endpoints of the highlighted one.
+fn render_tags_panel(f: &mut Frame, theme: &Theme, model: &StatsViewModel, area: Rect) {
+    let muted = Style::default().fg(theme.muted);
+    let selected = model.section == StatsSection::Tags;
+    let mut lines: Vec<Line> = model
+        .analysis
+        .tags
+        .iter()
+        .enumerate()
+        .map(|(i, stats)| {
+            let tag_style = if selected && i == model.tag_cursor {
+                Style::default()
+                    .fg(theme.background)
+                    .bg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.accent)
+            };
+            let undocumented_style = if stats.undocumented.is_empty() {
+                muted
+            } else {
+                Style::default().fg(theme.critical)
+            };
+            Line::from(vec![
+                Span::styled(stats.tag.clone(), tag_style),
+                Span::raw(format!("  {} endpoint(s)", stats.endpoints.len())),
+                Span::styled(
+                    format!("  {:.0}% writes", stats.write_ratio() * 100.0),
+                    muted,
+                ),
+                Span::styled(
+                    format!("  {} undocumented", stats.undocumented.len()),
+                    undocumented_style,
+                ),
+                Span::styled(format!("  {} unique", stats.unique_fields.len()), muted),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled("No operations", muted)));
+    }
+    // Keep the highlighted row in view
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = model.tag_cursor.saturating_sub(visible.saturating_sub(1));
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(section_block(theme, model, StatsSection::Tags))
+            .scroll((offset as u16, 0)),
+        area,
+    );
+}
+
+/// Panel of a chart, highlighted when selected.
+fn section_block(theme: &Theme, model: &StatsViewModel, section: StatsSection) -> Block<'static> {
+    crate::ui::layout::panel_block(theme, section.title(), model.section == section)
+}
+
+/// One bar per bucket, sized to fit the panel width.
+fn render_bar_chart(
+    f: &mut Frame,
+    theme: &Theme,
+    model: &StatsViewModel,
+    section: StatsSection,
+    buckets: &[Bucket],
+    area: Rect,
+) {
+    let inner_width = area.width.saturating_sub(2);
+    let count = (buckets.len() as u16).max(1);
+    let bar_width = (inner_width / count).saturating_sub(1).clamp(3, 9);
+    let bars: Vec<Bar> = buckets
+        .iter()
+        .map(|bucket| {
+            let color = match HttpMethod::parse(&bucket.label) {
+                Some(method) if section == StatsSection::Methods => theme.method_color(method),
+                _ => theme.accent,
+            };
+            Bar::default()
+                .value(bucket.items.len() as u64)
+                .label(Line::from(bucket.label.clone()))
+                .style(Style::default().fg(color))
+        })
+        .collect();
+    let chart = BarChart::default()
+        .block(section_block(theme, model, section))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .value_style(
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(chart, area);
+}
+
+/// Items behind a Stats chart, one heading per bar.
+pub fn render_drilldown_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    section: StatsSection,
+    buckets: &[Bucket],
+) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            section.title(),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for bucket in buckets {
+        lines.push(Line::from(vec![
+            Span::styled(
+                bucket.label.clone(),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(
+                format!(" ({})", bucket.items.len()),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+        if bucket.items.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  none",
+                Style::default().fg(theme.success),
+            )));
+        } else {
+            lines.push(Line::from(format!("  {}", bucket.items.join(", "))));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Stats Details "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,228 @@
+use crate::indexer::HttpMethod;
+use anyhow::{anyhow, Context, Result};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const BUILTIN_THEMES: &[&str] = &["dark", "light", "high-contrast"];
+
+/// Colors of every UI element, by role rather than by hue so a theme can
+/// remap them for light or low-vision terminals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Popup borders, headings and the palette prompt
+    pub accent: Color,
+    /// Active panel, selection, search bar and PUT endpoints
+    pub highlight: Color,
+    /// Critical fields, errors, DELETE and removed endpoints
+    pub critical: Color,
+    /// Confirmations, GET endpoints and successful responses
+    pub success: Color,
+    /// Secondary text, hints and separators
+    pub muted: Color,
+    /// Spec names, cycles, sensitive fields and PATCH endpoints
+    pub secondary: Color,
+    /// POST endpoints, response locations and graph sources
+    pub info: Color,
+    /// Popup text and background
+    pub text: Color,
+    pub background: Color,
+    pub status_bar: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Theme config file: an optional built-in to start from and per-role
+/// color overrides, e.g.
+///
+/// ```toml
+/// base = "light"
+///
+/// [colors]
+/// highlight = "#af5f00"
+/// critical = "red"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    base: Option<String>,
+    colors: BTreeMap<String, String>,
+}
+
+impl Theme {
+    /// Color of an endpoint by method, one per write method.
+    pub fn method_color(&self, method: HttpMethod) -> Color {
+        match method {
+            HttpMethod::Get => self.success,
+            HttpMethod::Post => self.info,
+            HttpMethod::Put => self.highlight,
+            HttpMethod::Patch => self.secondary,
+            HttpMethod::Delete => self.critical,
+            HttpMethod::Head | HttpMethod::Options | HttpMethod::Trace => self.text,
+        }
+    }
+
+    /// The original palette, for dark terminals.
+    pub fn dark() -> Self {
+        Self {
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            critical: Color::Red,
+            success: Color::Green,
+            muted: Color::DarkGray,
+            secondary: Color::Magenta,
+            info: Color::Blue,
+            text: Color::White,
+            background: Color::Black,
+            status_bar: Color::DarkGray,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Rgb(0, 95, 175),
+            highlight: Color::Rgb(175, 95, 0),
+            critical: Color::Rgb(175, 0, 0),
+            success: Color::Rgb(0, 125, 0),
+            muted: Color::Rgb(108, 108, 108),
+            secondary: Color::Rgb(135, 0, 135),
+            info: Color::Rgb(0, 0, 175),
+            text: Color::Black,
+            background: Color::Rgb(238, 238, 238),
+            status_bar: Color::Rgb(208, 208, 208),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            accent: Color::LightCyan,
+            highlight: Color::LightYellow,
+            critical: Color::LightRed,
+            success: Color::LightGreen,
+            muted: Color::Gray,
+            secondary: Color::LightMagenta,
+            info: Color::LightBlue,
+            text: Color::White,
+            background: Color::Black,
+            status_bar: Color::Black,
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// `$XDG_CONFIG_HOME/openapi-explorer/theme.toml`, else
+    /// `~/.config/openapi-explorer/theme.toml`.
+    pub fn default_config_path() -> Option<PathBuf> {
+        crate::config::config_dir().map(|dir| dir.join("theme.toml"))
+    }
+
+    /// Resolves `--theme`: a built-in name or a TOML file. Without one, the
+    /// default config file is used when it exists, else the dark theme.
+    pub fn load(selection: Option<&str>) -> Result<Self> {
+        match selection {
+            Some(name) => match Self::builtin(name) {
+                Some(theme) => Ok(theme),
+                None => Self::from_file(Path::new(name)),
+            },
+            None => match Self::default_config_path().filter(|path| path.exists()) {
+                Some(path) => Self::from_file(&path),
+                None => Ok(Self::default()),
+            },
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Cannot read theme {}", path.display()))?;
+        Self::from_toml(&content).with_context(|| format!("Invalid theme {}", path.display()))
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let file: ThemeFile = toml::from_str(content)?;
+        let mut theme = match file.base.as_deref() {
+            Some(base) => Self::builtin(base).ok_or_else(|| {
+                anyhow!(
+                    "Unknown base theme '{}' (expected one of: {})",
+                    base,
+                    BUILTIN_THEMES.join(", ")
+                )
+            })?,
+            None => Self::default(),
+        };
+        for (role, value) in &file.colors {
+            let color: Color = value
+                .parse()
+                .map_err(|_| anyhow!("Invalid color '{}' for '{}'", value, role))?;
+            *theme.role_mut(role)? = color;
+        }
+        Ok(theme)
+    }
+
+    fn role_mut(&mut self, role: &str) -> Result<&mut Color> {
+        Ok(match role {
+            "accent" => &mut self.accent,
+            "highlight" => &mut self.highlight,
+            "critical" => &mut self.critical,
+            "success" => &mut self.success,
+            "muted" => &mut self.muted,
+            "secondary" => &mut self.secondary,
+            "info" => &mut self.info,
+            "text" => &mut self.text,
+            "background" => &mut self.background,
+            "status_bar" => &mut self.status_bar,
+            _ => return Err(anyhow!("Unknown theme color '{}'", role)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_file_overrides_base() {
+        let theme = Theme::from_toml(
+            r##"
+                base = "light"
+                [colors]
+                critical = "#ff0000"
+                muted = "gray"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(theme.critical, Color::Rgb(255, 0, 0));
+        assert_eq!(theme.muted, Color::Gray);
+        assert_eq!(theme.accent, Theme::light().accent);
+
+        assert!(Theme::from_toml("base = \"solarized\"").is_err());
+        assert!(Theme::from_toml("[colors]\nsparkle = \"red\"").is_err());
+        assert!(Theme::from_toml("[colors]\naccent = \"not-a-color\"").is_err());
+    }
+
+    #[test]
+    fn test_load_builtin_or_file() {
+        assert_eq!(
+            Theme::load(Some("high-contrast")).unwrap(),
+            Theme::high_contrast()
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.toml");
+        std::fs::write(&path, "[colors]\nhighlight = \"blue\"").unwrap();
+        let theme = Theme::load(Some(path.to_str().unwrap())).unwrap();
+        assert_eq!(theme.highlight, Color::Blue);
+        assert_eq!(theme.accent, Theme::dark().accent);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::deprecation::{Deprecation, RemovalStatus};
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use chrono::NaiveDate;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn status_color(theme: &Theme, status: &RemovalStatus) -> Color {
+    match status {
+        RemovalStatus::Overdue { .. } => theme.critical,
+        RemovalStatus::Imminent { .. } => theme.highlight,
+        RemovalStatus::Upcoming { .. } => theme.success,
+        RemovalStatus::Unscheduled => theme.muted,
+    }
+}
+
+pub fn render_timeline_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    deprecations: &[Deprecation],
+    today: NaiveDate,
+) {
+    let overdue = deprecations.iter().filter(|d| d.is_overdue(today)).count();
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("Deprecation timeline (today: {})", today),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!(
+            "{} deprecated endpoint(s) • {} scheduled • {} overdue",
+            deprecations.len(),
+            deprecations.iter().filter(|d| d.sunset.is_some()).count(),
+            overdue
+        )),
+        Line::from(""),
+    ];
+
+    if overdue > 0 {
+        lines.push(Line::from(vec![Span::styled(
+            format!("⚠ {} removal(s) past their sunset date", overdue),
+            Style::default()
+                .fg(theme.critical)
+                .add_modifier(Modifier::BOLD),
+        )]));
+        lines.push(Line::from(""));
+    }
+
+    if deprecations.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No deprecated endpoint",
+            Style::default().fg(theme.success),
+        )));
+    }
+
+    // Scheduled removals grouped by month, then unscheduled ones
+    let mut current_group: Option<String> = None;
+    for deprecation in deprecations {
+        let group = deprecation
+            .sunset
+            .map(|date| date.format("%Y-%m").to_string())
+            .unwrap_or_else(|| "Unscheduled".to_string());
+        if current_group.as_ref() != Some(&group) {
+            lines.push(Line::from(vec![Span::styled(
+                group.clone(),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::UNDERLINED),
+            )]));
+            current_group = Some(group);
+        }
+
+        let status = deprecation.status(today);
+        let mut spans = vec![
+            Span::raw("  "),
+            Span::styled(
+                deprecation
+                    .sunset
+                    .map(|date| date.to_string())
+                    .unwrap_or_else(|| "----------".to_string()),
+                Style::default().fg(theme.muted),
+            ),
+            Span::raw(" ─ "),
+            Span::styled(
+                deprecation.endpoint.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", status.describe()),
+                Style::default().fg(status_color(theme, &status)),
+            ),
+        ];
+        if let Some(since) = deprecation.since {
+            spans.push(Span::styled(
+                format!("  (deprecated since {})", since),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Deprecation Timeline "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
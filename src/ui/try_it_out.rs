@@ -0,0 +1,186 @@
+use crate::app::TryItOut;
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_try_it_out_popup(f: &mut Frame, theme: &Theme, try_it_out: &TryItOut) {
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    render_request(f, theme, try_it_out, chunks[0]);
+    render_response(f, theme, try_it_out, chunks[1]);
+}
+
+fn input_line<'a>(theme: &Theme, label: String, value: &'a str, focused: bool) -> Line<'a> {
+    let (marker, label_style) = if focused {
+        (
+            "► ",
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default().fg(theme.accent))
+    };
+    let mut spans = vec![
+        Span::raw(marker),
+        Span::styled(label, label_style),
+        Span::raw(value),
+    ];
+    if focused {
+        spans.push(Span::styled("▏", Style::default().fg(theme.highlight)));
+    }
+    Line::from(spans)
+}
+
+fn render_request(f: &mut Frame, theme: &Theme, try_it_out: &TryItOut, area: Rect) {
+    let draft = &try_it_out.draft;
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("{} {}", draft.method, draft.path),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+        input_line(
+            theme,
+            "Base URL: ".to_string(),
+            &draft.base_url,
+            try_it_out.focus == 0,
+        ),
+    ];
+
+    for (i, param) in draft.params.iter().enumerate() {
+        let label = format!(
+            "{} ({}){}: ",
+            param.name,
+            param.location,
+            if param.required { " *" } else { "" }
+        );
+        lines.push(input_line(
+            theme,
+            label,
+            &param.value,
+            try_it_out.focus == i + 1,
+        ));
+    }
+
+    if try_it_out.has_body() {
+        let focused = try_it_out.body_focused();
+        lines.push(input_line(
+            theme,
+            format!(
+                "Body ({}):",
+                draft.content_type.as_deref().unwrap_or("application/json")
+            ),
+            "",
+            focused,
+        ));
+        for body_line in draft.body.lines() {
+            lines.push(Line::from(format!("    {}", body_line)));
+        }
+    }
+
+    match draft.url() {
+        Ok(url) => lines.push(Line::from(vec![
+            Span::styled("→ ", Style::default().fg(theme.muted)),
+            Span::styled(url.to_string(), Style::default().fg(theme.muted)),
+        ])),
+        Err(e) => lines.push(Line::from(vec![Span::styled(
+            format!("✗ {}", e),
+            Style::default().fg(theme.critical),
+        )])),
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Tab/↑↓: field • type to edit • Ctrl+U: clear • Enter: send (newline in body) • F5: send • Esc: close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Try It Out "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+    f.render_widget(widget, area);
+}
+
+fn render_response(f: &mut Frame, theme: &Theme, try_it_out: &TryItOut, area: Rect) {
+    let lines = match &try_it_out.response {
+        None if try_it_out.should_send => vec![Line::from(Span::styled(
+            "⟳ Sending...",
+            Style::default().fg(theme.highlight),
+        ))],
+        None => vec![Line::from(Span::styled(
+            "No request sent yet",
+            Style::default().fg(theme.muted),
+        ))],
+        Some(Err(error)) => vec![Line::from(Span::styled(
+            format!("✗ {}", error),
+            Style::default().fg(theme.critical),
+        ))],
+        Some(Ok(response)) => {
+            let status_color = if response.is_success() {
+                theme.success
+            } else if response.status >= 400 {
+                theme.critical
+            } else {
+                theme.highlight
+            };
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} {}", response.status, response.reason),
+                        Style::default()
+                            .fg(status_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  {} ms", response.elapsed.as_millis()),
+                        Style::default().fg(theme.muted),
+                    ),
+                ]),
+                Line::from(""),
+            ];
+            for (name, value) in &response.headers {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}: ", name), Style::default().fg(theme.accent)),
+                    Span::raw(value.clone()),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.extend(response.body.lines().map(|l| Line::from(l.to_string())));
+            lines
+        }
+    };
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Response "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+    f.render_widget(widget, area);
+}
@@ -0,0 +1,65 @@
+use crate::ui::layout::popup_area;
+use crate::ui::theme::Theme;
+use crate::validate::PayloadValidation;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_validation_popup(f: &mut Frame, theme: &Theme, validation: &PayloadValidation) {
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("{} — {}", validation.endpoint, validation.target),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    if validation.is_valid() {
+        lines.push(Line::from(Span::styled(
+            "✓ Payload matches the schema",
+            Style::default().fg(theme.success),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!("{} violation(s)", validation.violations.len()),
+            Style::default()
+                .fg(theme.critical)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for violation in &validation.violations {
+            lines.push(Line::from(vec![
+                Span::styled("  ✗ ", Style::default().fg(theme.critical)),
+                Span::styled(violation.path.clone(), Style::default().fg(theme.highlight)),
+                Span::raw(format!("  {}", violation.message)),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Press 'Esc' to close",
+        Style::default()
+            .fg(theme.muted)
+            .add_modifier(Modifier::ITALIC),
+    )]));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Payload Validation "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+
+    f.render_widget(Clear, area);
+    f.render_widget(widget, area);
+}
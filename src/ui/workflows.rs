@@ -0,0 +1,121 @@
+use crate::app::{ScrollTarget, TextScrolls};
+use crate::indexer::HttpMethod;
+use crate::ui::layout::{popup_area, render_scrolled_paragraph};
+use crate::ui::theme::Theme;
+use crate::view_model::WorkflowsViewModel;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Operations chained by response links, one tree per workflow, each step
+/// with the response status, link name and parameters it fills.
+pub fn render_workflows_popup(
+    f: &mut Frame,
+    theme: &Theme,
+    scrolls: &TextScrolls,
+    model: &WorkflowsViewModel,
+) {
+    let muted = Style::default().fg(theme.muted);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("{} link(s) in {} workflow(s)", model.links, model.workflows),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    if model.rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No response declares links",
+            muted,
+        )));
+    }
+
+    for row in &model.rows {
+        let indent = "   ".repeat(row.depth.saturating_sub(1));
+        let mut spans = Vec::new();
+        if let Some(link) = &row.via {
+            spans.push(Span::styled(
+                format!("{}└─ {} {} → ", indent, link.status, link.name),
+                muted,
+            ));
+        } else if lines.last().is_none_or(|line| line.width() != 0) {
+            lines.push(Line::from(""));
+        }
+        match &row.endpoint {
+            Some(endpoint) => {
+                let (method, path) = endpoint.split_once(' ').unwrap_or(("", endpoint));
+                let color = HttpMethod::parse(&method.to_lowercase())
+                    .map_or(theme.text, |method| theme.method_color(method));
+                spans.push(Span::styled(
+                    method.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::raw(format!(" {}", path)));
+            }
+            None => spans.push(Span::styled(
+                format!(
+                    "{} (unresolved)",
+                    row.via.as_ref().map_or("", |link| link.target.as_str())
+                ),
+                Style::default().fg(theme.critical),
+            )),
+        }
+        if row.cycle {
+            spans.push(Span::styled(
+                " ↺ (cycle)",
+                Style::default().fg(theme.highlight),
+            ));
+        }
+        lines.push(Line::from(spans));
+
+        if let Some(link) = &row.via {
+            let detail_indent = format!("{}      ", indent);
+            for (name, expression) in &link.parameters {
+                lines.push(Line::from(Span::styled(
+                    format!("{}{} ← {}", detail_indent, name, expression),
+                    Style::default().fg(theme.info),
+                )));
+            }
+            if link.has_request_body {
+                lines.push(Line::from(Span::styled(
+                    format!("{}body ← (request body expression)", detail_indent),
+                    Style::default().fg(theme.info),
+                )));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "↑/↓ PgUp/PgDn: scroll  l/Esc: close",
+        muted.add_modifier(Modifier::ITALIC),
+    )));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(" Workflows "),
+        )
+        .style(Style::default().bg(theme.background).fg(theme.text))
+        .wrap(Wrap { trim: false });
+
+    let area = popup_area(f.area());
+    f.render_widget(Clear, area);
+    render_scrolled_paragraph(
+        f,
+        theme,
+        scrolls,
+        ScrollTarget::Workflows,
+        "workflows",
+        widget,
+        area,
+    );
+}
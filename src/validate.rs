@@ -0,0 +1,445 @@
+use crate::codegen::json_schema::resolve_schema;
+use crate::export::find_operation;
+use crate::parser::{MediaType, OpenApiSpec};
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Side of an exchange a payload is checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadKind {
+    Request,
+    /// Response for a status code, or the first documented 2xx response
+    Response(Option<String>),
+}
+
+/// A place where the payload does not match its schema.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    /// JSONPath-like location, `$` being the payload itself
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PayloadValidation {
+    pub endpoint: String,
+    /// `request body` or `<status> response`
+    pub target: String,
+    pub violations: Vec<Violation>,
+}
+
+impl PayloadValidation {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{} — {}\n", self.endpoint, self.target);
+        if self.is_valid() {
+            out.push_str("Payload matches the schema\n");
+        }
+        for violation in &self.violations {
+            out.push_str(&format!("  ✗ {}: {}\n", violation.path, violation.message));
+        }
+        out
+    }
+}
+
+/// Checks `payload` against the request body or a response schema of
+/// `endpoint` (`"METHOD /path"`): types, required properties, enums and
+/// formats, plus read-only properties sent in requests and write-only ones
+/// returned in responses.
+pub fn validate_payload(
+    spec: &OpenApiSpec,
+    endpoint: &str,
+    kind: &PayloadKind,
+    payload: &Value,
+) -> Result<PayloadValidation> {
+    let operation =
+        find_operation(spec, endpoint).ok_or_else(|| anyhow!("Unknown endpoint '{}'", endpoint))?;
+
+    let (target, content) = match kind {
+        PayloadKind::Request => (
+            "request body".to_string(),
+            operation.request_body.as_ref().map(|body| &body.content),
+        ),
+        PayloadKind::Response(status) => {
+            let status = match status {
+                Some(status) => status.clone(),
+                None => {
+                    let mut statuses: Vec<&String> = operation
+                        .responses
+                        .keys()
+                        .filter(|status| status.starts_with('2'))
+                        .collect();
+                    statuses.sort_unstable();
+                    statuses
+                        .first()
+                        .map(|status| status.to_string())
+                        .ok_or_else(|| anyhow!("{} has no 2xx response", endpoint))?
+                }
+            };
+            let response = operation
+                .responses
+                .get(&status)
+                .or_else(|| operation.responses.get("default"))
+                .ok_or_else(|| anyhow!("{} has no {} response", endpoint, status))?;
+            (format!("{} response", status), response.content.as_ref())
+        }
+    };
+    let schema = content
+        .and_then(json_media_type)
+        .and_then(|media| media.schema.as_ref())
+        .ok_or_else(|| anyhow!("{} of {} has no JSON schema", target, endpoint))?;
+
+    let empty = HashMap::new();
+    let schemas = spec
+        .components
+        .as_ref()
+        .and_then(|c| c.schemas.as_ref())
+        .unwrap_or(&empty);
+    let resolved = resolve_schema(schema, schemas);
+    let mut validator = Validator {
+        root: &resolved,
+        kind,
+        violations: Vec::new(),
+    };
+    validator.check(&resolved, payload, "$");
+
+    Ok(PayloadValidation {
+        endpoint: endpoint.to_string(),
+        target,
+        violations: validator.violations,
+    })
+}
+
+/// `application/json`, else the first `+json` media type.
+fn json_media_type(content: &HashMap<String, MediaType>) -> Option<&MediaType> {
+    content.get("application/json").or_else(|| {
+        let mut json: Vec<(&String, &MediaType)> = content
+            .iter()
+            .filter(|(media_type, _)| media_type.ends_with("json"))
+            .collect();
+        json.sort_unstable_by_key(|(media_type, _)| media_type.as_str());
+        json.first().map(|(_, media)| *media)
+    })
+}
+
+struct Validator<'a> {
+    /// Resolved schema, the target of `#` and `#/$defs/...` references
+    root: &'a Value,
+    kind: &'a PayloadKind,
+    violations: Vec<Violation>,
+}
+
+impl Validator<'_> {
+    fn violation(&mut self, path: &str, message: String) {
+        self.violations.push(Violation {
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn matches(&self, schema: &Value, value: &Value) -> bool {
+        let mut probe = Validator {
+            root: self.root,
+            kind: self.kind,
+            violations: Vec::new(),
+        };
+        probe.check(schema, value, "$");
+        probe.violations.is_empty()
+    }
+
+    fn check(&mut self, schema: &Value, value: &Value, path: &str) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let target = match reference {
+                "#" => Some(self.root),
+                _ => reference
+                    .strip_prefix("#/$defs/")
+                    .and_then(|name| self.root.get("$defs")?.get(name)),
+            };
+            if let Some(target) = target {
+                self.check(target, value, path);
+            }
+            return;
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let types: Vec<&str> = match expected {
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            if !types.is_empty() && !types.iter().any(|t| has_type(value, t)) {
+                let message = format!("expected {}, got {}", types.join(" or "), type_of(value));
+                self.violation(path, message);
+                return;
+            }
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            if !values.contains(value) {
+                let allowed: Vec<String> = values.iter().map(Value::to_string).collect();
+                let message = format!("{} is not one of {}", value, allowed.join(", "));
+                self.violation(path, message);
+            }
+        }
+        if let (Some(format), Some(text)) =
+            (schema.get("format").and_then(Value::as_str), value.as_str())
+        {
+            if !has_format(text, format) {
+                self.violation(path, format!("\"{}\" is not a valid {}", text, format));
+            }
+        }
+
+        match value {
+            Value::Object(object) => self.check_object(schema, object, path),
+            Value::Array(items) => {
+                if let Some(item_schema) = schema.get("items") {
+                    for (i, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &format!("{}[{}]", path, i));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        for part in schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            self.check(part, value, path);
+        }
+        if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = variants.iter().filter(|v| self.matches(v, value)).count();
+            if matching != 1 {
+                let message = format!(
+                    "matches {} of the {} oneOf variants, expected exactly one",
+                    matching,
+                    variants.len()
+                );
+                self.violation(path, message);
+            }
+        }
+        if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+            if !variants.iter().any(|v| self.matches(v, value)) {
+                let message = format!("matches none of the {} anyOf variants", variants.len());
+                self.violation(path, message);
+            }
+        }
+        if let Some(not) = schema.get("not") {
+            if self.matches(not, value) {
+                self.violation(path, "matches a schema it must not match".to_string());
+            }
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        schema: &Value,
+        object: &serde_json::Map<String, Value>,
+        path: &str,
+    ) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for name in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            let read_only = properties
+                .and_then(|properties| properties.get(name))
+                .is_some_and(|property| is_flagged(property, "readOnly"));
+            let skipped = read_only && *self.kind == PayloadKind::Request;
+            if !object.contains_key(name) && !skipped {
+                self.violation(
+                    &child_path(path, name),
+                    "required property missing".to_string(),
+                );
+            }
+        }
+
+        for (name, value) in object {
+            let property = properties.and_then(|properties| properties.get(name));
+            let child = child_path(path, name);
+            match property {
+                Some(property) => {
+                    if *self.kind == PayloadKind::Request && is_flagged(property, "readOnly") {
+                        self.violation(&child, "read-only property sent in a request".to_string());
+                    } else if *self.kind != PayloadKind::Request
+                        && is_flagged(property, "writeOnly")
+                    {
+                        self.violation(
+                            &child,
+                            "write-only property returned in a response".to_string(),
+                        );
+                    }
+                    self.check(property, value, &child);
+                }
+                None => {
+                    if let Some(additional) = schema.get("additionalProperties") {
+                        self.check(additional, value, &child);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_flagged(schema: &Value, keyword: &str) -> bool {
+    schema
+        .get(keyword)
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        format!("{}.{}", path, name)
+    } else {
+        format!("{}[{}]", path, Value::from(name))
+    }
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "number" => value.is_number(),
+        other => type_of(value) == other,
+    }
+}
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Checks the formats validators commonly assert; unknown formats pass.
+fn has_format(text: &str, format: &str) -> bool {
+    match format {
+        "date-time" => chrono::DateTime::parse_from_rfc3339(text).is_ok(),
+        "date" => chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok(),
+        "email" => text.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty() && domain.contains('.') && !domain.contains('@')
+        }),
+        "uuid" => {
+            text.len() == 36
+                && text.chars().enumerate().all(|(i, c)| match i {
+                    8 | 13 | 18 | 23 => c == '-',
+                    _ => c.is_ascii_hexdigit(),
+                })
+        }
+        "uri" | "url" => reqwest::Url::parse(text).is_ok(),
+        "ipv4" => text.parse::<std::net::Ipv4Addr>().is_ok(),
+        "ipv6" => text.parse::<std::net::Ipv6Addr>().is_ok(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_openapi_content;
+    use serde_json::json;
+
+    const SHOP: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Shop", "version": "1"},
+        "paths": {"/orders": {"post": {
+            "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+            "responses": {"201": {"description": "Created", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}}}
+        }}},
+        "components": {"schemas": {
+            "Order": {"type": "object", "required": ["id", "email", "lines"], "properties": {
+                "id": {"type": "integer", "readOnly": true},
+                "email": {"type": "string", "format": "email"},
+                "status": {"type": "string", "enum": ["open", "paid"]},
+                "placedAt": {"type": "string", "format": "date-time"},
+                "lines": {"type": "array", "items": {"$ref": "#/components/schemas/Line"}}
+            }},
+            "Line": {"type": "object", "required": ["sku"], "properties": {
+                "sku": {"type": "string"},
+                "quantity": {"type": "integer"}
+            }}
+        }}
+    }"##;
+
+    #[test]
+    fn test_request_violations() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let payload = json!({
+            "id": 7,
+            "email": "not-an-email",
+            "status": "shipped",
+            "placedAt": "2024-02-30T10:00:00Z",
+            "lines": [{"sku": "A1", "quantity": 2}, {"quantity": 1.5}]
+        });
+
+        let report =
+            validate_payload(&spec, "POST /orders", &PayloadKind::Request, &payload).unwrap();
+        assert_eq!(report.target, "request body");
+        let found: Vec<(&str, &str)> = report
+            .violations
+            .iter()
+            .map(|v| (v.path.as_str(), v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("$.email", "\"not-an-email\" is not a valid email"),
+                ("$.id", "read-only property sent in a request"),
+                ("$.lines[1].sku", "required property missing"),
+                ("$.lines[1].quantity", "expected integer, got number"),
+                (
+                    "$.placedAt",
+                    "\"2024-02-30T10:00:00Z\" is not a valid date-time"
+                ),
+                ("$.status", "\"shipped\" is not one of \"open\", \"paid\""),
+            ]
+        );
+        assert!(report
+            .to_text()
+            .starts_with("POST /orders — request body\n  ✗ $.email"));
+    }
+
+    #[test]
+    fn test_response_defaults_to_success_status() {
+        let spec = parse_openapi_content(SHOP).unwrap();
+        let payload = json!({"id": 1, "email": "a@b.io", "lines": []});
+
+        let report = validate_payload(
+            &spec,
+            "post /orders",
+            &PayloadKind::Response(None),
+            &payload,
+        )
+        .unwrap();
+        assert_eq!(report.target, "201 response");
+        assert!(report.is_valid());
+
+        let missing =
+            validate_payload(&spec, "POST /orders", &PayloadKind::Request, &json!({})).unwrap();
+        assert_eq!(missing.violations.len(), 2, "id is read-only in requests");
+
+        assert!(validate_payload(&spec, "GET /orders", &PayloadKind::Request, &payload).is_err());
+        assert!(validate_payload(
+            &spec,
+            "POST /orders",
+            &PayloadKind::Response(Some("404".to_string())),
+            &payload
+        )
+        .is_err());
+    }
+}
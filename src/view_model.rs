@@ -0,0 +1,1573 @@
+//! What the views show, derived from [`App`] without any terminal types:
+//! renderers only lay these models out, so another frontend can reuse them
+//! and view logic is testable without a terminal.
+
+use crate::app::{App, FieldInfo, Panel};
+use crate::clusters::FieldCluster;
+use crate::criticality::CriticalityRules;
+use crate::deprecation::{Deprecation, RemovalStatus};
+use crate::direction::SchemaDirection;
+use crate::error_taxonomy::ErrorTaxonomy;
+use crate::indexer::{FieldFlag, FieldIndex, FieldOrigin, FormField, HeaderData, HttpMethod};
+use crate::lint::Diagnostic;
+use crate::multi_spec::{CrossSpecIndex, SpecSummary};
+use crate::parser::{is_event_target, ExternalDocs, OpenApiSpec, CALLBACK_PREFIX, WEBHOOK_PREFIX};
+use crate::payload::PayloadEstimate;
+use crate::report::{compute_stats, Stats};
+use crate::responses::ResponseMatrix;
+use crate::schema_graph::SchemaEdge;
+use crate::schema_tree::{Composition, NodeKind, TreeRow};
+use crate::stats::{Bucket, DocumentationCoverage, SchemaSize, StatsSection, StatsTrend, TagStats};
+use crate::workflows::{WorkflowLink, WorkflowRow};
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Row of a list panel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListRow {
+    pub label: String,
+    /// Under the list cursor
+    pub cursor: bool,
+    /// Shown in the details panel
+    pub selected: bool,
+}
+
+/// Column of the Fields table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FieldColumn {
+    #[default]
+    Name,
+    Type,
+    Schemas,
+    Endpoints,
+    Required,
+    Critical,
+}
+
+impl FieldColumn {
+    pub const ALL: [FieldColumn; 6] = [
+        FieldColumn::Name,
+        FieldColumn::Type,
+        FieldColumn::Schemas,
+        FieldColumn::Endpoints,
+        FieldColumn::Required,
+        FieldColumn::Critical,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            FieldColumn::Name => "Name",
+            FieldColumn::Type => "Type",
+            FieldColumn::Schemas => "#Schemas",
+            FieldColumn::Endpoints => "#Endpoints",
+            FieldColumn::Required => "Required",
+            FieldColumn::Critical => "Critical",
+        }
+    }
+
+    /// Next column, or the previous one with `step` -1, wrapping around.
+    pub fn cycle(self, step: isize) -> Self {
+        let position = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        let len = Self::ALL.len() as isize;
+        Self::ALL[(position as isize + step).rem_euclid(len) as usize]
+    }
+}
+
+/// What the Fields table shows besides the name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldColumns {
+    pub field_type: String,
+    pub schemas: usize,
+    pub endpoints: usize,
+    /// Declaring schemas that list the field as required
+    pub required: usize,
+    pub critical: bool,
+}
+
+impl FieldColumns {
+    /// Ordering by `column`, ascending; names compare equal so the listing
+    /// order breaks ties.
+    pub fn compare(&self, other: &Self, column: FieldColumn) -> Ordering {
+        match column {
+            FieldColumn::Name => Ordering::Equal,
+            FieldColumn::Type => self.field_type.cmp(&other.field_type),
+            FieldColumn::Schemas => self.schemas.cmp(&other.schemas),
+            FieldColumn::Endpoints => self.endpoints.cmp(&other.endpoints),
+            FieldColumn::Required => self.required.cmp(&other.required),
+            FieldColumn::Critical => self.critical.cmp(&other.critical),
+        }
+    }
+
+    pub fn cell(&self, column: FieldColumn) -> String {
+        match column {
+            FieldColumn::Name => String::new(),
+            FieldColumn::Type => self.field_type.clone(),
+            FieldColumn::Schemas => self.schemas.to_string(),
+            FieldColumn::Endpoints => self.endpoints.to_string(),
+            FieldColumn::Required => format!("{}/{}", self.required, self.schemas),
+            FieldColumn::Critical => if self.critical { "yes" } else { "" }.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldRow {
+    pub row: ListRow,
+    pub sensitive: bool,
+    /// Attributes set in any schema declaring the field
+    pub flags: Vec<FieldFlag>,
+    pub columns: FieldColumns,
+    /// Marked for a bulk action
+    pub marked: bool,
+    pub bookmarked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldsViewModel {
+    pub rows: Vec<FieldRow>,
+    /// Name, then the other columns past the horizontal scroll
+    pub columns: Vec<FieldColumn>,
+    pub sort: FieldColumn,
+    pub descending: bool,
+    /// Field shown in the center and right panels
+    pub selected: Option<String>,
+    /// Details of the selected field, `None` when it is not indexed
+    pub details: Option<FieldInfo>,
+    /// Cursor of the declaring schemas list
+    pub detail_cursor: usize,
+    pub panel: Panel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaRow {
+    pub row: ListRow,
+    /// First schema picked for a side-by-side comparison
+    pub compare_base: bool,
+    pub direction: Option<SchemaDirection>,
+}
+
+/// Row of the selected schema's property tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaTreeRow {
+    pub row: TreeRow,
+    /// Sensitive data category of a property of the schema itself
+    pub sensitive: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaDetails {
+    pub name: String,
+    pub schema_type: String,
+    /// Fields, those common to the alternatives for a polymorphic schema
+    pub fields: usize,
+    pub composition: Composition,
+    pub direction: Option<SchemaDirection>,
+    pub description: Option<String>,
+    pub docs: Option<ExternalDocs>,
+    /// Discriminator property name
+    pub discriminator: Option<String>,
+    /// Visible rows of the property tree
+    pub tree: Vec<SchemaTreeRow>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemasViewModel {
+    pub rows: Vec<SchemaRow>,
+    /// Schema shown in the center and right panels
+    pub selected: Option<String>,
+    /// Details of the selected schema, `None` when it is not indexed
+    pub details: Option<SchemaDetails>,
+    pub tree_cursor: usize,
+    /// Endpoints using the fields of the selected schema
+    pub related_endpoints: Vec<String>,
+    /// Cursor of the related endpoints list
+    pub detail_cursor: usize,
+    pub panel: Panel,
+}
+
+/// Path, webhook or callback of the Endpoints list, or a prefix grouping
+/// paths in the tree listing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointRow {
+    /// Path shown for the row, its last segments in the tree listing
+    pub row: ListRow,
+    /// Listed path, `None` for a prefix grouping paths without being one
+    pub target: Option<String>,
+    pub depth: usize,
+    /// Whether the tree row is folded, `None` without paths below
+    pub folded: Option<bool>,
+    /// Paths below a folded row
+    pub hidden: usize,
+    pub is_event: bool,
+    /// First webhook or callback, listed after the paths
+    pub starts_events: bool,
+    pub bookmarked: bool,
+    /// Marked for a bulk action
+    pub marked: bool,
+    /// Most destructive write operation of the path
+    pub write_method: Option<HttpMethod>,
+    /// Sorted by method
+    pub operation_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterRow {
+    pub name: String,
+    /// `path`, `query`, `header` or `cookie`
+    pub location: String,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+/// Details of a selected `METHOD /path` operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationDetails {
+    /// `METHOD /path`
+    pub endpoint: String,
+    pub method: String,
+    pub path: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub docs: Option<ExternalDocs>,
+    pub tags: Vec<String>,
+    pub parameters: Option<Vec<ParameterRow>>,
+    pub form_fields: Option<Vec<FormField>>,
+    /// Response links of the operation
+    pub links_out: Vec<WorkflowLink>,
+    /// Response links of other operations pointing to it
+    pub links_in: Vec<WorkflowLink>,
+}
+
+/// Center panel of the Endpoints view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EndpointDetails {
+    Operation(Box<OperationDetails>),
+    /// A path, webhook or callback, with its methods and their summaries
+    Target {
+        kind: &'static str,
+        target: String,
+        operations: Vec<(String, Option<String>)>,
+    },
+    /// The selection is not in the spec, e.g. `Path not found`
+    Missing(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointsViewModel {
+    pub title: &'static str,
+    pub rows: Vec<EndpointRow>,
+    pub operation_ids_first: bool,
+    /// The cursor is drawn in the tree listing only
+    pub show_cursor: bool,
+    /// Details of the selected endpoint
+    pub details: Option<EndpointDetails>,
+    /// Fields the selected endpoint uses
+    pub fields: Option<EndpointFieldsViewModel>,
+    pub panel: Panel,
+}
+
+/// Field in the tree of the fields an endpoint uses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointFieldRow {
+    pub name: String,
+    pub critical: bool,
+    pub sensitive: bool,
+}
+
+/// Fields an operation declares in one place, e.g. its 404 response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldOriginGroup {
+    pub origin: FieldOrigin,
+    pub fields: Vec<EndpointFieldRow>,
+}
+
+/// Field of an operation listed once, with where it is declared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldUsageRow {
+    pub field: EndpointFieldRow,
+    pub occurrences: usize,
+    /// e.g. `request + 2 responses`
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationFields {
+    /// `METHOD /path`
+    pub operation: String,
+    pub groups: Vec<FieldOriginGroup>,
+    /// The same fields, each once, in declaration order
+    pub usages: Vec<FieldUsageRow>,
+}
+
+/// Fields of the selected endpoint, by operation then by origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointFieldsViewModel {
+    pub operations: Vec<OperationFields>,
+    /// Distinct field names across the operations
+    pub distinct: usize,
+    /// One line per field instead of the tree of origins
+    pub by_field: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderDetails {
+    pub name: String,
+    pub header: HeaderData,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadersViewModel {
+    pub title: String,
+    pub rows: Vec<ListRow>,
+    /// Selected header, `None` as well when it is not indexed
+    pub details: Option<HeaderDetails>,
+    pub panel: Panel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphRow {
+    pub row: ListRow,
+    pub fan_in: usize,
+    pub fan_out: usize,
+    pub in_cycle: bool,
+}
+
+/// References from and to a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaDependencies {
+    pub schema: String,
+    /// Reference cycle the schema is part of
+    pub cycle: Option<Vec<String>>,
+    pub fan_out: usize,
+    /// Sorted by target, then location
+    pub references: Vec<SchemaEdge>,
+    pub fan_in: usize,
+    /// Referencing schema and the location of the reference, sorted
+    pub referenced_by: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStats {
+    pub schemas: usize,
+    pub references: usize,
+    /// Schemas neither referencing nor referenced
+    pub isolated: usize,
+    /// Top schemas by fan-in, then name
+    pub most_referenced: Vec<(String, usize)>,
+    /// Top schemas by fan-out, then name
+    pub most_dependencies: Vec<(String, usize)>,
+    pub cycles: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphViewModel {
+    pub rows: Vec<GraphRow>,
+    /// Of the selected schema, else the one under the cursor
+    pub dependencies: Option<SchemaDependencies>,
+    /// Value-object candidates
+    pub clusters: Vec<FieldCluster>,
+    pub stats: GraphStats,
+    pub panel: Panel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponsesViewModel {
+    pub matrix: ResponseMatrix,
+    /// Matrix row under the cursor
+    pub cursor: usize,
+    /// Response descriptions of the row under the cursor, by status
+    pub descriptions: Vec<String>,
+}
+
+/// Whole-spec analyses behind the Stats view and the popups drawing from
+/// them, computed when the active spec changes rather than on every frame.
+#[derive(Debug, Clone, Default)]
+pub struct StatsAnalysis {
+    /// Critical fields counted with the configured rules
+    pub counts: Stats,
+    /// Fields used by an endpoint and their endpoint count, most used first
+    pub top_fields: Vec<(String, usize)>,
+    /// Largest first
+    pub schema_sizes: Vec<SchemaSize>,
+    /// Heaviest first
+    pub payloads: Vec<PayloadEstimate>,
+    /// Soonest sunset first
+    pub deprecations: Vec<Deprecation>,
+    pub error_taxonomy: ErrorTaxonomy,
+    pub field_types: Vec<Bucket>,
+    pub methods: Vec<Bucket>,
+    pub schema_size_buckets: Vec<Bucket>,
+    pub documentation: DocumentationCoverage,
+    pub field_usage: Vec<Bucket>,
+    pub tags: Vec<TagStats>,
+}
+
+/// Totals of every loaded spec, computed when the cross-spec index is
+/// rebuilt.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrossSpecStats {
+    /// In session order
+    pub summaries: Vec<SpecSummary>,
+    pub distinct_fields: usize,
+    pub shared_fields: usize,
+    /// Shared fields declared with differing types
+    pub type_conflicts: usize,
+}
+
+/// Cross-Service section of the Stats view, with more than one spec loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossServiceStats<'a> {
+    pub stats: &'a CrossSpecStats,
+    pub active: &'a str,
+    /// Files of the watched directories failing to load
+    pub failures: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatsViewModel<'a> {
+    pub analysis: &'a StatsAnalysis,
+    pub trend: Option<&'a StatsTrend>,
+    pub cross_service: Option<CrossServiceStats<'a>>,
+    /// Unsuppressed validation warnings, most severe first
+    pub diagnostics: Vec<&'a Diagnostic>,
+    pub suppressed: usize,
+    pub today: NaiveDate,
+    /// Documentation coverage target, in percent
+    pub min_doc_coverage: Option<f64>,
+    pub section: StatsSection,
+    pub tag_cursor: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowsViewModel {
+    pub links: usize,
+    pub workflows: usize,
+    pub rows: Vec<WorkflowRow>,
+}
+
+/// Identity of the active spec and what it contains.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecInfoViewModel {
+    pub title: String,
+    pub version: String,
+    pub openapi: String,
+    pub description: Option<String>,
+    /// File the spec was loaded from
+    pub source: Option<String>,
+    /// Position of the active spec and the number loaded, with several
+    pub position: Option<(usize, usize)>,
+    pub contact: Option<String>,
+    /// Name, then identifier and URL when declared
+    pub license: Option<String>,
+    pub terms: Option<String>,
+    pub docs: Option<String>,
+    pub servers: Vec<String>,
+    pub paths: usize,
+    pub operations: usize,
+    pub write_operations: usize,
+    pub schemas: usize,
+    pub fields: usize,
+    /// Declared tags, the untagged group left out
+    pub tags: usize,
+    pub webhooks: usize,
+    /// Elements dropped by lenient parsing
+    pub dropped: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecRow {
+    pub name: String,
+    pub file_path: Option<String>,
+    pub paths: usize,
+    pub fields: usize,
+}
+
+/// Loaded specs, for the switcher popup and the spec rail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecListViewModel {
+    pub specs: Vec<SpecRow>,
+    pub active: usize,
+    /// Switcher cursor while it is open
+    pub cursor: Option<usize>,
+    /// File names of the watched directories failing to load
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderRow {
+    pub name: String,
+    /// `unknown` without a schema type
+    pub header_type: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseRow {
+    pub status: String,
+    pub description: String,
+    /// Sorted by name
+    pub headers: Vec<HeaderRow>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestBodyDetails {
+    pub description: Option<String>,
+    pub content_types: Vec<String>,
+    pub form_fields: Option<Vec<FormField>>,
+}
+
+/// Everything the endpoint details popup shows about an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointPopupViewModel {
+    /// `METHOD /path`
+    pub endpoint: String,
+    /// The operation or its path is bookmarked
+    pub bookmarked: bool,
+    pub owner: Option<String>,
+    /// Notes on the operation, then on its path
+    pub notes: Vec<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub docs: Option<ExternalDocs>,
+    pub tags: Vec<String>,
+    pub deprecation: Option<(Deprecation, RemovalStatus)>,
+    pub parameters: Vec<ParameterRow>,
+    pub request_body: Option<RequestBodyDetails>,
+    /// Sorted by status
+    pub responses: Vec<ResponseRow>,
+}
+
+/// Schemas shown in the Graph statistics per ranking
+const GRAPH_TOP: usize = 5;
+
+fn list_rows(items: &[Arc<str>], cursor: usize, selected: Option<&str>) -> Vec<ListRow> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| ListRow {
+            label: item.to_string(),
+            cursor: i == cursor,
+            selected: selected == Some(&**item),
+        })
+        .collect()
+}
+
+impl StatsAnalysis {
+    pub fn build(spec: &OpenApiSpec, index: &FieldIndex, criticality: &CriticalityRules) -> Self {
+        let mut counts = compute_stats(spec, index);
+        counts.critical_fields = index
+            .fields
+            .keys()
+            .filter(|name| criticality.is_critical(index, name))
+            .count();
+        let mut top_fields: Vec<(String, usize)> = index
+            .fields
+            .iter()
+            .filter(|(_, data)| !data.endpoints.is_empty())
+            .map(|(name, data)| (name.clone(), data.endpoints.len()))
+            .collect();
+        top_fields.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        Self {
+            counts,
+            top_fields,
+            schema_sizes: crate::stats::schema_sizes(index),
+            payloads: crate::payload::rank_response_payloads(spec),
+            deprecations: crate::deprecation::collect_deprecations(spec),
+            error_taxonomy: crate::error_taxonomy::analyze_error_taxonomy(spec),
+            field_types: crate::stats::field_type_buckets(index),
+            methods: crate::stats::method_buckets(spec),
+            schema_size_buckets: crate::stats::schema_size_buckets(index),
+            documentation: crate::stats::documentation_coverage(spec, index),
+            field_usage: crate::stats::field_usage_buckets(index),
+            tags: crate::stats::tag_stats(spec, index),
+        }
+    }
+
+    /// Items behind a section's chart, grouped as it charts them.
+    pub fn buckets(&self, section: StatsSection) -> Vec<Bucket> {
+        match section {
+            StatsSection::FieldTypes => self.field_types.clone(),
+            StatsSection::Methods => self.methods.clone(),
+            StatsSection::SchemaSizes => self.schema_size_buckets.clone(),
+            StatsSection::Documentation => self.documentation.buckets(),
+            StatsSection::FieldUsage => self.field_usage.clone(),
+            StatsSection::Tags => self
+                .tags
+                .iter()
+                .map(|stats| Bucket {
+                    label: stats.tag.clone(),
+                    items: stats.endpoints.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CrossSpecStats {
+    pub fn build(summaries: Vec<SpecSummary>, index: &CrossSpecIndex) -> Self {
+        Self {
+            summaries,
+            distinct_fields: index.fields.len(),
+            shared_fields: index.shared_fields().len(),
+            type_conflicts: index.type_conflicts().len(),
+        }
+    }
+}
+
+impl App {
+    pub fn fields_view_model(&self) -> FieldsViewModel {
+        let rows = list_rows(
+            &self.filtered_fields,
+            self.field_list_state,
+            self.selected_field.as_deref(),
+        )
+        .into_iter()
+        .map(|row| {
+            let flags = self
+                .field_index
+                .fields
+                .get(&row.label)
+                .map(|data| {
+                    FieldFlag::ALL
+                        .into_iter()
+                        .filter(|flag| data.has_flag(*flag))
+                        .collect()
+                })
+                .unwrap_or_default();
+            FieldRow {
+                sensitive: self.sensitive_label(&row.label).is_some(),
+                flags,
+                columns: self.field_columns(&row.label),
+                marked: self.marked_fields.contains(&row.label),
+                bookmarked: self.is_bookmarked(&row.label),
+                row,
+            }
+        })
+        .collect();
+        let mut columns = vec![FieldColumn::Name];
+        columns.extend(FieldColumn::ALL.iter().skip(1 + self.field_column_offset));
+        FieldsViewModel {
+            rows,
+            columns,
+            sort: self.field_sort,
+            descending: self.field_sort_descending,
+            selected: self.selected_field.clone(),
+            details: self
+                .selected_field
+                .as_ref()
+                .and_then(|field| self.get_field_info(field)),
+            detail_cursor: self.detail_list_state,
+            panel: self.current_panel.clone(),
+        }
+    }
+
+    pub fn field_columns(&self, name: &str) -> FieldColumns {
+        let index = &self.field_index;
+        let Some(data) = index.fields.get(name) else {
+            return FieldColumns {
+                field_type: String::new(),
+                schemas: 0,
+                endpoints: 0,
+                required: 0,
+                critical: false,
+            };
+        };
+        FieldColumns {
+            field_type: data.field_type.clone(),
+            schemas: data.schemas.len(),
+            endpoints: data.endpoints.len(),
+            required: data
+                .schemas
+                .iter()
+                .filter(|schema| {
+                    index
+                        .schemas
+                        .get(&***schema)
+                        .is_some_and(|schema| schema.is_required_at(name))
+                })
+                .count(),
+            critical: self.criticality.is_critical(index, name),
+        }
+    }
+
+    pub fn schemas_view_model(&self) -> SchemasViewModel {
+        let rows = list_rows(
+            &self.filtered_schemas,
+            self.schema_list_state,
+            self.selected_schema.as_deref(),
+        )
+        .into_iter()
+        .map(|row| SchemaRow {
+            compare_base: self.schema_compare_base.as_deref() == Some(row.label.as_str()),
+            direction: self.field_index.schema_directions.get(&row.label).copied(),
+            row,
+        })
+        .collect();
+        SchemasViewModel {
+            rows,
+            selected: self.selected_schema.clone(),
+            details: self.schema_details(),
+            tree_cursor: self.schema_tree_cursor,
+            related_endpoints: self
+                .selected_schema
+                .as_ref()
+                .map(|schema| self.related_endpoints(schema))
+                .unwrap_or_default(),
+            detail_cursor: self.detail_list_state,
+            panel: self.current_panel.clone(),
+        }
+    }
+
+    fn schema_details(&self) -> Option<SchemaDetails> {
+        let name = self.selected_schema.as_ref()?;
+        let schema = self.field_index.schemas.get(name)?;
+        let fields = if schema.is_polymorphic() {
+            schema.common_field_names()
+        } else {
+            schema.get_field_names()
+        };
+        let tree = self
+            .schema_tree()
+            .into_iter()
+            .map(|row| SchemaTreeRow {
+                sensitive: (row.depth == 0 && row.kind == NodeKind::Property)
+                    .then(|| self.sensitive_label(&row.label))
+                    .flatten()
+                    .map(str::to_string),
+                row,
+            })
+            .collect();
+        Some(SchemaDetails {
+            name: name.clone(),
+            schema_type: schema
+                .schema_type
+                .clone()
+                .unwrap_or_else(|| "object".to_string()),
+            fields: fields.len(),
+            composition: crate::schema_tree::composition(schema, &self.field_index.schemas),
+            direction: self.field_index.schema_directions.get(name).copied(),
+            description: schema.description.clone(),
+            docs: schema.external_docs.clone(),
+            discriminator: schema
+                .discriminator
+                .as_ref()
+                .map(|d| d.property_name.clone()),
+            tree,
+        })
+    }
+
+    pub fn endpoints_view_model(&self) -> EndpointsViewModel {
+        let mut rows: Vec<EndpointRow> = if self.endpoint_tree {
+            self.endpoint_rows()
+                .into_iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    let mut endpoint = self.endpoint_row(row.target.as_deref(), &row.label);
+                    endpoint.row.cursor = i == self.endpoint_list_state;
+                    endpoint.depth = row.depth;
+                    endpoint.folded = row.has_children().then_some(row.collapsed);
+                    endpoint.hidden = if row.collapsed { row.descendants } else { 0 };
+                    endpoint
+                })
+                .collect()
+        } else {
+            self.filtered_endpoints
+                .iter()
+                .enumerate()
+                .map(|(i, endpoint)| {
+                    let mut row = self.endpoint_row(Some(endpoint), endpoint);
+                    row.row.cursor = i == self.endpoint_list_state;
+                    row
+                })
+                .collect()
+        };
+        if let Some(first) = rows.iter_mut().find(|row| row.is_event) {
+            first.starts_events = true;
+        }
+        EndpointsViewModel {
+            title: match (self.endpoint_tree, self.operation_ids_first) {
+                (true, _) => "Endpoints (by path prefix)",
+                (false, true) => "Endpoints (by operationId)",
+                (false, false) => "Endpoints",
+            },
+            rows,
+            operation_ids_first: self.operation_ids_first,
+            show_cursor: self.endpoint_tree,
+            details: self.endpoint_details(),
+            fields: self.endpoint_fields_view_model(),
+            panel: self.current_panel.clone(),
+        }
+    }
+
+    /// Center panel for the selected operation key, or path, webhook or
+    /// callback.
+    fn endpoint_details(&self) -> Option<EndpointDetails> {
+        let selected = self.selected_endpoint.as_ref()?;
+        let Some((method, path)) = selected.split_once(' ') else {
+            let Some(path_item) = self.openapi_spec.path_item(selected) else {
+                return Some(EndpointDetails::Missing("Invalid endpoint format"));
+            };
+            let kind = if selected.starts_with(WEBHOOK_PREFIX) {
+                "Webhook: "
+            } else if selected.starts_with(CALLBACK_PREFIX) {
+                "Callback: "
+            } else {
+                "Path: "
+            };
+            let mut operations: Vec<(String, Option<String>)> = path_item
+                .operations
+                .iter()
+                .map(|(method, operation)| (method.to_uppercase(), operation.summary.clone()))
+                .collect();
+            operations.sort_unstable();
+            return Some(EndpointDetails::Target {
+                kind,
+                target: selected.clone(),
+                operations,
+            });
+        };
+        let Some(path_item) = self.openapi_spec.paths.get(path) else {
+            return Some(EndpointDetails::Missing("Path not found"));
+        };
+        let Some(operation) = path_item.operations.get(method.to_lowercase().as_str()) else {
+            return Some(EndpointDetails::Missing("Operation not found"));
+        };
+        let links = &self.workflow_links;
+        let (links_out, links_in) = (
+            links
+                .iter()
+                .filter(|link| &link.from == selected)
+                .cloned()
+                .collect(),
+            links
+                .iter()
+                .filter(|link| link.to.as_ref() == Some(selected))
+                .cloned()
+                .collect(),
+        );
+        Some(EndpointDetails::Operation(Box::new(OperationDetails {
+            endpoint: selected.clone(),
+            method: method.to_uppercase(),
+            path: path.to_string(),
+            summary: operation.summary.clone(),
+            description: operation.description.clone(),
+            docs: self.openapi_spec.operation_docs(selected).cloned(),
+            tags: operation.tags.clone().unwrap_or_default(),
+            parameters: operation.parameters.as_ref().map(|parameters| {
+                parameters
+                    .iter()
+                    .map(|parameter| ParameterRow {
+                        name: parameter.name.clone(),
+                        location: parameter.in_.clone(),
+                        required: parameter.required.unwrap_or(false),
+                        description: parameter.description.clone(),
+                    })
+                    .collect()
+            }),
+            form_fields: self.field_index.form_fields.get(selected).cloned(),
+            links_out,
+            links_in,
+        })))
+    }
+
+    /// Row of a listed path shown as `label`, or of a tree prefix without
+    /// `target`.
+    fn endpoint_row(&self, target: Option<&str>, label: &str) -> EndpointRow {
+        let write_method = target
+            .and_then(|target| self.openapi_spec.paths.get(target))
+            .and_then(|item| {
+                item.operations
+                    .keys()
+                    .filter_map(|method| HttpMethod::parse(method))
+                    .max()
+            })
+            .filter(|method| method.is_write());
+        EndpointRow {
+            row: ListRow {
+                label: label.to_string(),
+                cursor: false,
+                selected: target.is_some() && self.selected_endpoint.as_deref() == target,
+            },
+            target: target.map(str::to_string),
+            depth: 0,
+            folded: None,
+            hidden: 0,
+            is_event: target.is_some_and(is_event_target),
+            starts_events: false,
+            bookmarked: target.is_some_and(|target| self.is_bookmarked(target)),
+            marked: target.is_some_and(|target| self.marked_endpoints.contains(target)),
+            write_method,
+            operation_ids: target
+                .map(|target| self.operation_ids(target))
+                .unwrap_or_default()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Field tree of the selected endpoint: an operation key, or a path,
+    /// webhook or callback standing for each of its operations.
+    pub fn endpoint_fields_view_model(&self) -> Option<EndpointFieldsViewModel> {
+        let selected = self.selected_endpoint.as_deref()?;
+        let index = &self.field_index;
+        let keys = if index.endpoint_fields.contains_key(selected) {
+            vec![selected.to_string()]
+        } else {
+            self.operation_keys(selected)
+        };
+        let mut names: Vec<&str> = Vec::new();
+        let mut operations = Vec::new();
+        for key in &keys {
+            let mut groups: Vec<FieldOriginGroup> = Vec::new();
+            let mut fields: Vec<_> = index
+                .endpoint_fields
+                .get(key)
+                .into_iter()
+                .flatten()
+                .collect();
+            fields.sort_by(|a, b| a.origin.cmp(&b.origin));
+            for field in fields {
+                if !names.contains(&field.name.as_str()) {
+                    names.push(&field.name);
+                }
+                let row = self.endpoint_field_row(&field.name);
+                match groups.last_mut() {
+                    Some(group) if group.origin == field.origin => group.fields.push(row),
+                    _ => groups.push(FieldOriginGroup {
+                        origin: field.origin.clone(),
+                        fields: vec![row],
+                    }),
+                }
+            }
+            if !groups.is_empty() {
+                let usages = index
+                    .endpoint_field_usages(key)
+                    .into_iter()
+                    .map(|usage| FieldUsageRow {
+                        field: self.endpoint_field_row(&usage.name),
+                        occurrences: usage.occurrences(),
+                        summary: usage.summary(),
+                    })
+                    .collect();
+                operations.push(OperationFields {
+                    operation: key.clone(),
+                    groups,
+                    usages,
+                });
+            }
+        }
+        Some(EndpointFieldsViewModel {
+            operations,
+            distinct: names.len(),
+            by_field: self.endpoint_fields_by_field,
+        })
+    }
+
+    fn endpoint_field_row(&self, name: &str) -> EndpointFieldRow {
+        EndpointFieldRow {
+            name: name.to_string(),
+            critical: self.criticality.is_critical(&self.field_index, name),
+            sensitive: self.sensitive_label(name).is_some(),
+        }
+    }
+
+    pub fn headers_view_model(&self) -> HeadersViewModel {
+        HeadersViewModel {
+            title: format!("Headers ({})", self.filtered_headers.len()),
+            rows: list_rows(
+                &self.filtered_headers,
+                self.header_list_state,
+                self.selected_header.as_deref(),
+            ),
+            details: self.selected_header.as_ref().and_then(|name| {
+                Some(HeaderDetails {
+                    name: name.clone(),
+                    header: self.field_index.headers.get(name)?.clone(),
+                })
+            }),
+            panel: self.current_panel.clone(),
+        }
+    }
+
+    pub fn graph_view_model(&self) -> GraphViewModel {
+        let graph = &self.field_index.schema_graph;
+        let rows = list_rows(
+            &self.filtered_schemas,
+            self.schema_list_state,
+            self.selected_schema.as_deref(),
+        )
+        .into_iter()
+        .map(|row| GraphRow {
+            fan_in: graph.fan_in(&row.label),
+            fan_out: graph.fan_out(&row.label),
+            in_cycle: graph.is_in_cycle(&row.label),
+            row,
+        })
+        .collect();
+        let focused_schema = self.selected_schema.clone().or_else(|| {
+            self.filtered_schemas
+                .get(self.schema_list_state)
+                .map(|s| s.to_string())
+        });
+        GraphViewModel {
+            rows,
+            dependencies: focused_schema.map(|schema| self.schema_dependencies(schema)),
+            clusters: self.field_clusters(),
+            stats: self.graph_stats(),
+            panel: self.current_panel.clone(),
+        }
+    }
+
+    pub fn responses_view_model(&self) -> ResponsesViewModel {
+        let matrix = self.response_matrix();
+        let descriptions = match matrix.rows.get(self.response_row_state) {
+            Some(row) => {
+                let operation = crate::export::find_operation(&self.openapi_spec, &row.endpoint);
+                row.statuses
+                    .iter()
+                    .map(|status| {
+                        operation
+                            .and_then(|op| op.responses.get(status))
+                            .map(|response| response.description.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        ResponsesViewModel {
+            matrix,
+            cursor: self.response_row_state,
+            descriptions,
+        }
+    }
+
+    pub fn stats_view_model(&self) -> StatsViewModel<'_> {
+        let mut diagnostics: Vec<&Diagnostic> = self
+            .validation_warnings
+            .iter()
+            .filter(|diagnostic| !diagnostic.suppressed)
+            .collect();
+        diagnostics.sort_by_key(|diagnostic| diagnostic.severity);
+        StatsViewModel {
+            analysis: &self.stats_analysis,
+            trend: self.stats_trend.as_ref(),
+            cross_service: (self.specs.len() > 1).then(|| CrossServiceStats {
+                stats: &self.cross_spec_stats,
+                active: self.active_spec_name(),
+                failures: self.spec_dir_failures.len(),
+            }),
+            suppressed: self.validation_warnings.len() - diagnostics.len(),
+            diagnostics,
+            today: chrono::Utc::now().date_naive(),
+            min_doc_coverage: self.min_doc_coverage,
+            section: self.stats_section,
+            tag_cursor: self.stats_tag_cursor,
+        }
+    }
+
+    pub fn workflows_view_model(&self) -> WorkflowsViewModel {
+        let rows = crate::workflows::workflow_rows(&self.workflow_links);
+        WorkflowsViewModel {
+            links: self.workflow_links.len(),
+            workflows: rows.iter().filter(|row| row.via.is_none()).count(),
+            rows,
+        }
+    }
+
+    pub fn spec_info_view_model(&self) -> SpecInfoViewModel {
+        let spec = &self.openapi_spec;
+        let info = &spec.info;
+        let counts = &self.stats_analysis.counts;
+        SpecInfoViewModel {
+            title: info.title.clone(),
+            version: info.version.clone(),
+            openapi: spec.openapi.clone(),
+            description: info
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+            source: self
+                .file_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            position: (self.specs.len() > 1).then_some((self.active_spec + 1, self.specs.len())),
+            contact: info.contact.as_ref().map(|contact| contact.describe()),
+            license: info.license.as_ref().map(|license| {
+                let mut value = license.name.clone();
+                if let Some(identifier) = &license.identifier {
+                    value.push_str(&format!(" ({})", identifier));
+                }
+                if let Some(url) = &license.url {
+                    value.push_str(&format!(" {}", url));
+                }
+                value
+            }),
+            terms: info.terms_of_service.clone(),
+            docs: spec
+                .external_docs
+                .as_ref()
+                .map(|docs| match &docs.description {
+                    Some(description) => format!("{} {}", description, docs.url),
+                    None => docs.url.clone(),
+                }),
+            servers: spec
+                .servers
+                .iter()
+                .flatten()
+                .map(|server| server.url.clone())
+                .collect(),
+            paths: counts.paths,
+            operations: counts.operations,
+            write_operations: counts.write_operations,
+            schemas: counts.schemas,
+            fields: counts.fields,
+            tags: self
+                .stats_analysis
+                .tags
+                .iter()
+                .filter(|tag| tag.tag != crate::stats::UNTAGGED)
+                .count(),
+            webhooks: spec.webhooks.as_ref().map_or(0, |webhooks| webhooks.len()),
+            dropped: spec.parse_warnings.len(),
+        }
+    }
+
+    pub fn spec_list_view_model(&self) -> SpecListViewModel {
+        SpecListViewModel {
+            specs: self
+                .specs
+                .iter()
+                .map(|entry| {
+                    let (paths, fields) = match &entry.parked {
+                        Some((spec, index)) => (spec.paths.len(), index.fields.len()),
+                        None => (self.openapi_spec.paths.len(), self.field_index.fields.len()),
+                    };
+                    SpecRow {
+                        name: entry.name.clone(),
+                        file_path: entry
+                            .file_path
+                            .as_ref()
+                            .map(|path| path.display().to_string()),
+                        paths,
+                        fields,
+                    }
+                })
+                .collect(),
+            active: self.active_spec,
+            cursor: self.spec_switcher,
+            failures: self
+                .spec_dir_failures
+                .keys()
+                .map(|path| {
+                    path.file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default()
+                })
+                .collect(),
+        }
+    }
+
+    /// The operation opened in the endpoint details popup, `None` when it
+    /// is not in the spec.
+    pub fn endpoint_popup_view_model(&self) -> Option<EndpointPopupViewModel> {
+        let endpoint = self.selected_endpoint_for_details.as_ref()?;
+        let (method, path) = endpoint.split_once(' ')?;
+        let operation = self
+            .openapi_spec
+            .path_item(path)?
+            .operations
+            .get(&method.to_lowercase())?;
+        let workspace = &self.workspace;
+        let mut responses: Vec<ResponseRow> = operation
+            .responses
+            .iter()
+            .map(|(status, response)| {
+                let mut headers: Vec<HeaderRow> = response
+                    .headers
+                    .iter()
+                    .flatten()
+                    .map(|(name, header)| HeaderRow {
+                        name: name.clone(),
+                        header_type: header
+                            .schema
+                            .as_ref()
+                            .and_then(|schema| schema.schema_type.clone())
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        description: header.description.clone(),
+                    })
+                    .collect();
+                headers.sort_by(|a, b| a.name.cmp(&b.name));
+                ResponseRow {
+                    status: status.clone(),
+                    description: response.description.clone(),
+                    headers,
+                }
+            })
+            .collect();
+        responses.sort_by(|a, b| a.status.cmp(&b.status));
+        Some(EndpointPopupViewModel {
+            endpoint: endpoint.clone(),
+            bookmarked: workspace.bookmarks.contains(endpoint)
+                || workspace.bookmarks.contains(path),
+            owner: workspace.owner_of(endpoint).map(str::to_string),
+            notes: [endpoint.as_str(), path]
+                .iter()
+                .filter_map(|key| workspace.annotations.get(*key).cloned())
+                .collect(),
+            summary: operation.summary.clone(),
+            description: operation.description.clone(),
+            docs: self.openapi_spec.operation_docs(endpoint).cloned(),
+            tags: operation.tags.clone().unwrap_or_default(),
+            deprecation: crate::deprecation::operation_deprecation(endpoint, operation).map(
+                |deprecation| {
+                    let status = deprecation.status(chrono::Utc::now().date_naive());
+                    (deprecation, status)
+                },
+            ),
+            parameters: operation
+                .parameters
+                .iter()
+                .flatten()
+                .map(|parameter| ParameterRow {
+                    name: parameter.name.clone(),
+                    location: parameter.in_.clone(),
+                    required: parameter.required.unwrap_or(false),
+                    description: parameter.description.clone(),
+                })
+                .collect(),
+            request_body: operation
+                .request_body
+                .as_ref()
+                .map(|request_body| RequestBodyDetails {
+                    description: request_body.description.clone(),
+                    content_types: request_body.content.keys().cloned().collect(),
+                    form_fields: self.field_index.form_fields.get(endpoint).cloned(),
+                }),
+            responses,
+        })
+    }
+
+    fn schema_dependencies(&self, schema: String) -> SchemaDependencies {
+        let graph = &self.field_index.schema_graph;
+        let mut references = graph.edges.get(&schema).cloned().unwrap_or_default();
+        references.sort_by(|a, b| a.target.cmp(&b.target).then(a.via.cmp(&b.via)));
+        let mut referenced_by = graph
+            .reverse_edges
+            .get(&schema)
+            .cloned()
+            .unwrap_or_default();
+        referenced_by.sort();
+        SchemaDependencies {
+            cycle: graph
+                .cycles
+                .iter()
+                .find(|cycle| cycle.contains(&schema))
+                .cloned(),
+            fan_out: graph.fan_out(&schema),
+            references,
+            fan_in: graph.fan_in(&schema),
+            referenced_by,
+            schema,
+        }
+    }
+
+    fn graph_stats(&self) -> GraphStats {
+        let graph = &self.field_index.schema_graph;
+        let names: Vec<&String> = self.field_index.schemas.keys().collect();
+        let top = |count: &dyn Fn(&str) -> usize| {
+            let mut ranked: Vec<(String, usize)> = names
+                .iter()
+                .map(|name| (name.to_string(), count(name)))
+                .filter(|(_, count)| *count > 0)
+                .collect();
+            ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            ranked.truncate(GRAPH_TOP);
+            ranked
+        };
+        GraphStats {
+            schemas: names.len(),
+            references: graph.edge_count(),
+            isolated: names
+                .iter()
+                .filter(|name| graph.fan_in(name) == 0 && graph.fan_out(name) == 0)
+                .count(),
+            most_referenced: top(&|name| graph.fan_in(name)),
+            most_dependencies: top(&|name| graph.fan_out(name)),
+            cycles: graph.cycles.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EndpointDetails, FieldColumn};
+    use crate::app::{App, Panel, View};
+    use crate::indexer::{build_field_index, FieldFlag, HttpMethod};
+    use crate::parser::parse_openapi_content;
+    use crate::stats::StatsSection;
+
+    fn app() -> App {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "Shop", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"operationId": "listUsers", "responses": {"200": {"description": "ok"}}},
+                        "delete": {"operationId": "purgeUsers", "responses": {"204": {"description": "gone"}}}
+                    },
+                    "/users/{id}": {"get": {
+                        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}],
+                        "responses": {
+                            "200": {"description": "ok", "content": {"application/json": {"schema": {
+                                "type": "object", "properties": {"id": {"type": "integer"}, "password": {"type": "string"}}}}}},
+                            "404": {"description": "missing", "content": {"application/json": {"schema": {
+                                "type": "object", "properties": {"id": {"type": "integer"}}}}}}
+                        }
+                    }}
+                },
+                "webhooks": {"userCreated": {"post": {"responses": {"200": {"description": "ok"}}}}},
+                "components": {"schemas": {
+                    "User": {"type": "object", "required": ["id"], "properties": {
+                        "id": {"type": "integer", "readOnly": true},
+                        "password": {"type": "string"}
+                    }}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        App::new(spec, index, None)
+    }
+
+    #[test]
+    fn test_fields_rows_follow_cursor_and_selection() {
+        let mut app = app();
+        app.navigate_down();
+        app.select_current_item();
+        let model = app.fields_view_model();
+        let rows: Vec<(&str, bool, bool)> = model
+            .rows
+            .iter()
+            .map(|r| (r.row.label.as_str(), r.row.cursor, r.row.selected))
+            .collect();
+        assert_eq!(rows, vec![("id", false, false), ("password", true, true)]);
+        assert_eq!(model.rows[0].flags, vec![FieldFlag::ReadOnly]);
+        assert!(model.rows[1].sensitive);
+        assert_eq!(model.panel, Panel::Left);
+        let details = model.details.unwrap();
+        assert_eq!(details.schemas, vec!["User"]);
+        assert_eq!(details.sensitive.as_deref(), Some("password"));
+    }
+
+    #[test]
+    fn test_fields_table_sorts_and_scrolls_columns() {
+        let mut app = app();
+        let model = app.fields_view_model();
+        assert_eq!(model.columns, FieldColumn::ALL);
+        assert_eq!(model.rows[0].columns.cell(FieldColumn::Required), "1/1");
+        assert_eq!(model.rows[1].columns.cell(FieldColumn::Required), "0/1");
+        assert_eq!(model.rows[1].columns.cell(FieldColumn::Type), "string");
+
+        // Cursor on `id`, then sorted by type descending: string first
+        app.cycle_field_sort(1);
+        app.reverse_field_sort();
+        let model = app.fields_view_model();
+        assert_eq!((model.sort, model.descending), (FieldColumn::Type, true));
+        let rows: Vec<(&str, bool)> = model
+            .rows
+            .iter()
+            .map(|r| (r.row.label.as_str(), r.row.cursor))
+            .collect();
+        assert_eq!(rows, vec![("password", false), ("id", true)]);
+
+        app.scroll_field_columns(1);
+        assert_eq!(app.fields_view_model().columns[1], FieldColumn::Schemas);
+        app.scroll_field_columns(10);
+        assert_eq!(
+            app.fields_view_model().columns,
+            vec![FieldColumn::Name, FieldColumn::Critical]
+        );
+        app.scroll_field_columns(-10);
+        assert_eq!(app.fields_view_model().columns.len(), 6);
+    }
+
+    #[test]
+    fn test_endpoint_field_tree_groups_by_origin() {
+        let mut app = app();
+        app.set_view(View::Endpoints);
+        assert!(app.endpoint_fields_view_model().is_none());
+        app.navigate_down();
+        app.select_current_item();
+        let model = app.endpoint_fields_view_model().unwrap();
+        assert_eq!(model.distinct, 2);
+        assert_eq!(model.operations.len(), 1);
+        let operation = &model.operations[0];
+        assert_eq!(operation.operation, "GET /users/{id}");
+        let groups: Vec<(String, Vec<&str>)> = operation
+            .groups
+            .iter()
+            .map(|g| {
+                let names = g.fields.iter().map(|f| f.name.as_str()).collect();
+                (g.origin.label(), names)
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("Path parameters".to_string(), vec!["id"]),
+                ("Response 200".to_string(), vec!["id", "password"]),
+                ("Response 404".to_string(), vec!["id"]),
+            ]
+        );
+        assert!(operation.groups[1].fields[1].sensitive);
+
+        app.toggle_endpoint_fields_by_field();
+        let model = app.endpoint_fields_view_model().unwrap();
+        assert!(model.by_field);
+        let usages: Vec<(&str, usize, &str)> = model.operations[0]
+            .usages
+            .iter()
+            .map(|u| (u.field.name.as_str(), u.occurrences, u.summary.as_str()))
+            .collect();
+        assert_eq!(
+            usages,
+            vec![
+                ("id", 3, "path parameter + 2 responses"),
+                ("password", 1, "response 200"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detail_panels_from_the_selection() {
+        let mut app = app();
+        app.set_view(View::Endpoints);
+        assert_eq!(app.endpoints_view_model().details, None);
+        app.selected_endpoint = Some("GET /users/{id}".to_string());
+        let Some(EndpointDetails::Operation(operation)) = app.endpoints_view_model().details else {
+            panic!("operation details expected");
+        };
+        assert_eq!(
+            (operation.method.as_str(), operation.path.as_str()),
+            ("GET", "/users/{id}")
+        );
+        let parameters = operation.parameters.unwrap();
+        assert_eq!(
+            (
+                parameters[0].name.as_str(),
+                parameters[0].location.as_str(),
+                parameters[0].required
+            ),
+            ("id", "path", true)
+        );
+
+        app.jump_to_endpoint("/users");
+        assert_eq!(
+            app.endpoints_view_model().details,
+            Some(EndpointDetails::Target {
+                kind: "Path: ",
+                target: "/users".to_string(),
+                operations: vec![("DELETE".to_string(), None), ("GET".to_string(), None)],
+            })
+        );
+        app.selected_endpoint = Some("GET /gone".to_string());
+        assert_eq!(
+            app.endpoints_view_model().details,
+            Some(EndpointDetails::Missing("Path not found"))
+        );
+
+        app.jump_to_schema("User");
+        let model = app.schemas_view_model();
+        let details = model.details.unwrap();
+        assert_eq!(
+            (details.schema_type.as_str(), details.fields),
+            ("object", 2)
+        );
+        let tree: Vec<(&str, Option<&str>)> = details
+            .tree
+            .iter()
+            .map(|r| (r.row.label.as_str(), r.sensitive.as_deref()))
+            .collect();
+        assert_eq!(tree, vec![("id", None), ("password", Some("password"))]);
+
+        app.set_view(View::Graph);
+        let model = app.graph_view_model();
+        assert_eq!(model.dependencies.unwrap().schema, "User");
+        assert_eq!((model.stats.schemas, model.stats.isolated), (1, 1));
+    }
+
+    #[test]
+    fn test_endpoint_rows_in_both_listings() {
+        let mut app = app();
+        app.set_view(View::Endpoints);
+        let model = app.endpoints_view_model();
+        assert_eq!(model.title, "Endpoints");
+        assert!(!model.show_cursor);
+        let users = &model.rows[0];
+        assert_eq!(users.write_method, Some(HttpMethod::Delete));
+        assert_eq!(users.operation_ids, vec!["purgeUsers", "listUsers"]);
+        let events: Vec<bool> = model.rows.iter().map(|r| r.starts_events).collect();
+        assert_eq!(events, vec![false, false, true]);
+
+        app.toggle_endpoint_tree();
+        app.collapse_path_node();
+        let model = app.endpoints_view_model();
+        assert_eq!(model.title, "Endpoints (by path prefix)");
+        let rows: Vec<(&str, Option<bool>, usize, bool)> = model
+            .rows
+            .iter()
+            .map(|r| (r.row.label.as_str(), r.folded, r.hidden, r.row.cursor))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("/users", Some(true), 1, true),
+                ("webhook:userCreated", None, 0, false)
+            ]
+        );
+        assert!(model.rows[1].starts_events);
+    }
+
+    #[test]
+    fn test_stats_and_popup_models_from_the_cached_analyses() {
+        let mut app = app();
+        let model = app.stats_view_model();
+        assert_eq!(
+            (
+                model.analysis.counts.paths,
+                model.analysis.counts.write_operations
+            ),
+            (2, 1)
+        );
+        assert_eq!(
+            model.analysis.top_fields,
+            vec![("id".to_string(), 1), ("password".to_string(), 1)]
+        );
+        assert!(model.cross_service.is_none());
+        let tags: Vec<&str> = model.analysis.tags.iter().map(|t| t.tag.as_str()).collect();
+        assert_eq!(tags, vec![crate::stats::UNTAGGED]);
+        assert_eq!(app.spec_info_view_model().tags, 0);
+        let methods = app.stats_analysis.buckets(StatsSection::Methods);
+        assert_eq!(methods[0].items, vec!["GET /users", "GET /users/{id}"]);
+
+        app.workspace.bookmarks.insert("/users/{id}".to_string());
+        app.selected_endpoint_for_details = Some("GET /users/{id}".to_string());
+        let popup = app.endpoint_popup_view_model().unwrap();
+        assert!(popup.bookmarked);
+        assert_eq!(popup.parameters[0].name, "id");
+        let statuses: Vec<&str> = popup.responses.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, vec!["200", "404"]);
+        app.selected_endpoint_for_details = Some("GET /gone".to_string());
+        assert_eq!(app.endpoint_popup_view_model(), None);
+
+        let specs = app.spec_list_view_model();
+        assert_eq!((specs.specs[0].paths, specs.specs[0].fields), (2, 2));
+        assert_eq!((specs.active, specs.cursor), (0, None));
+    }
+}
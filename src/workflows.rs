@@ -0,0 +1,203 @@
+//! Workflows traced through response `links`: which operation a response
+//! feeds, chained from operations nothing links to (create → get → update).
+
+use crate::indexer::FieldIndex;
+use crate::parser::OpenApiSpec;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One response link between two operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowLink {
+    pub from: String,
+    pub status: String,
+    pub name: String,
+    /// Linked endpoint, `None` when its operationId or operationRef does
+    /// not resolve
+    pub to: Option<String>,
+    /// `operationId` or `operationRef` as written
+    pub target: String,
+    /// Parameter name and the expression filling it, sorted by name
+    pub parameters: Vec<(String, String)>,
+    pub has_request_body: bool,
+    pub description: Option<String>,
+}
+
+/// Every response link of the path operations, by source endpoint, status
+/// and link name.
+pub fn collect_links(spec: &OpenApiSpec, index: &FieldIndex) -> Vec<WorkflowLink> {
+    let mut links = Vec::new();
+    for (path, path_item) in &spec.paths {
+        for (method, operation) in &path_item.operations {
+            let from = format!("{} {}", method.to_uppercase(), path);
+            for (status, response) in &operation.responses {
+                for (name, link) in response.links.iter().flatten() {
+                    let to = match &link.operation_id {
+                        Some(id) => index.endpoint_for_operation(id).cloned(),
+                        None => link
+                            .operation_ref_endpoint()
+                            .filter(|endpoint| spec.operation(endpoint).is_some()),
+                    };
+                    let mut parameters: Vec<(String, String)> = link
+                        .parameters
+                        .iter()
+                        .flatten()
+                        .map(|(name, value)| {
+                            let expression = match value {
+                                serde_json::Value::String(text) => text.clone(),
+                                other => other.to_string(),
+                            };
+                            (name.clone(), expression)
+                        })
+                        .collect();
+                    parameters.sort();
+                    links.push(WorkflowLink {
+                        from: from.clone(),
+                        status: status.clone(),
+                        name: name.clone(),
+                        to,
+                        target: link
+                            .operation_id
+                            .clone()
+                            .or_else(|| link.operation_ref.clone())
+                            .unwrap_or_default(),
+                        parameters,
+                        has_request_body: link.request_body.is_some(),
+                        description: link.description.clone(),
+                    });
+                }
+            }
+        }
+    }
+    links.sort_by(|a, b| (&a.from, &a.status, &a.name).cmp(&(&b.from, &b.status, &b.name)));
+    links
+}
+
+/// Line of the workflow tree: an operation, reached through `via` unless
+/// it starts a workflow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowRow {
+    pub depth: usize,
+    pub via: Option<WorkflowLink>,
+    /// `None` when the link does not resolve
+    pub endpoint: Option<String>,
+    /// Already on the chain above, so not followed again
+    pub cycle: bool,
+}
+
+/// Linked operations as trees, one per workflow. Workflows start at the
+/// operations no link leads to; operations only reached through a cycle
+/// start one each, in key order.
+pub fn workflow_rows(links: &[WorkflowLink]) -> Vec<WorkflowRow> {
+    let mut outgoing: BTreeMap<&str, Vec<&WorkflowLink>> = BTreeMap::new();
+    for link in links {
+        outgoing.entry(link.from.as_str()).or_default().push(link);
+    }
+    let targets: BTreeSet<&str> = links.iter().filter_map(|l| l.to.as_deref()).collect();
+
+    let mut rows = Vec::new();
+    let mut visited: BTreeSet<&str> = BTreeSet::new();
+    let starts: Vec<&str> = outgoing
+        .keys()
+        .copied()
+        .filter(|from| !targets.contains(from))
+        .chain(outgoing.keys().copied())
+        .collect();
+    for start in starts {
+        if visited.contains(start) {
+            continue;
+        }
+        rows.push(WorkflowRow {
+            depth: 0,
+            via: None,
+            endpoint: Some(start.to_string()),
+            cycle: false,
+        });
+        let mut chain = vec![start];
+        push_steps(&outgoing, start, &mut chain, &mut visited, &mut rows);
+    }
+    rows
+}
+
+fn push_steps<'a>(
+    outgoing: &BTreeMap<&'a str, Vec<&'a WorkflowLink>>,
+    from: &'a str,
+    chain: &mut Vec<&'a str>,
+    visited: &mut BTreeSet<&'a str>,
+    rows: &mut Vec<WorkflowRow>,
+) {
+    visited.insert(from);
+    for link in outgoing.get(from).into_iter().flatten() {
+        let cycle = link.to.as_deref().is_some_and(|to| chain.contains(&to));
+        rows.push(WorkflowRow {
+            depth: chain.len(),
+            via: Some((*link).clone()),
+            endpoint: link.to.clone(),
+            cycle,
+        });
+        if let (Some(to), false) = (link.to.as_deref(), cycle) {
+            chain.push(to);
+            push_steps(outgoing, to, chain, visited, rows);
+            chain.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::build_field_index;
+    use crate::parser::parse_openapi_content;
+
+    #[test]
+    fn test_links_chain_into_workflows() {
+        let spec = parse_openapi_content(
+            r##"{
+                "openapi": "3.0.0",
+                "info": {"title": "T", "version": "1"},
+                "paths": {
+                    "/users": {"post": {"operationId": "createUser", "responses": {"201": {
+                        "description": "created",
+                        "links": {"GetUser": {"operationId": "getUser",
+                            "parameters": {"id": "$response.body#/id"}}}}}}},
+                    "/users/{id}": {
+                        "get": {"operationId": "getUser", "responses": {"200": {
+                            "description": "ok",
+                            "links": {"UpdateUser": {"$ref": "#/components/links/UpdateUser"},
+                                      "Gone": {"operationId": "missing"}}}}},
+                        "patch": {"operationId": "updateUser", "responses": {"200": {
+                            "description": "ok",
+                            "links": {"Reload": {"operationRef": "#/paths/~1users~1{id}/get"}}}}}
+                    }
+                },
+                "components": {"links": {"UpdateUser": {"operationId": "updateUser"}}}
+            }"##,
+        )
+        .unwrap();
+        let index = build_field_index(&spec);
+        let links = collect_links(&spec, &index);
+        assert_eq!(links.len(), 4);
+        let get_user = links.iter().find(|link| link.name == "GetUser").unwrap();
+        assert_eq!(get_user.to.as_deref(), Some("GET /users/{id}"));
+        assert_eq!(
+            get_user.parameters,
+            [("id".to_string(), "$response.body#/id".to_string())]
+        );
+
+        let rows = workflow_rows(&links);
+        let summary: Vec<(usize, Option<&str>, bool)> = rows
+            .iter()
+            .map(|row| (row.depth, row.endpoint.as_deref(), row.cycle))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, Some("POST /users"), false),
+                (1, Some("GET /users/{id}"), false),
+                (2, None, false),
+                (2, Some("PATCH /users/{id}"), false),
+                (3, Some("GET /users/{id}"), true),
+            ]
+        );
+        assert_eq!(rows[2].via.as_ref().unwrap().target, "missing");
+    }
+}
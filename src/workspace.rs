@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Format version written to workspace files
+pub const WORKSPACE_VERSION: u32 = 1;
+
+/// Default location of the shared workspace file
+pub const DEFAULT_WORKSPACE_FILE: &str = "openapi_explorer.workspace.json";
+
+/// A search query, or a named view when `view` is set: the query plus the
+/// view, sort order and method filter it was saved from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedFilter {
+    pub name: String,
+    pub query: String,
+    /// View name as in `:view`, e.g. `endpoints`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<String>,
+    /// `natural` or `lexical`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// HTTP method the Endpoints view is limited to, all when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+impl SavedFilter {
+    pub fn is_view(&self) -> bool {
+        self.view.is_some()
+    }
+}
+
+/// Review context shared by a team: everything here is keyed by endpoint
+/// (`"METHOD /path"`), path or field name so it survives spec reloads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Workspace {
+    pub version: u32,
+    /// Spec files reviewed together, relative to the workspace file
+    pub specs: Vec<PathBuf>,
+    pub bookmarks: BTreeSet<String>,
+    /// Free-text notes by endpoint, path or field
+    pub annotations: BTreeMap<String, String>,
+    pub saved_filters: Vec<SavedFilter>,
+    /// Endpoints, paths or field names hidden from findings reports
+    pub suppressions: BTreeSet<String>,
+    /// Path prefix (`/billing`) or endpoint key (`DELETE /users/{id}`) to owner
+    pub owners: BTreeMap<String, String>,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self {
+            version: WORKSPACE_VERSION,
+            specs: Vec::new(),
+            bookmarks: BTreeSet::new(),
+            annotations: BTreeMap::new(),
+            saved_filters: Vec::new(),
+            suppressions: BTreeSet::new(),
+            owners: BTreeMap::new(),
+        }
+    }
+}
+
+impl Workspace {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Cannot read workspace {}", path.display()))?;
+        let workspace: Workspace = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid workspace file {}", path.display()))?;
+        if workspace.version > WORKSPACE_VERSION {
+            return Err(anyhow!(
+                "Workspace version {} is newer than supported version {}",
+                workspace.version,
+                WORKSPACE_VERSION
+            ));
+        }
+        Ok(workspace)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Merges an imported workspace: sets are unioned, and imported notes,
+    /// owners and same-name filters or views replace local ones.
+    pub fn merge(&mut self, other: Workspace) {
+        for spec in other.specs {
+            if !self.specs.contains(&spec) {
+                self.specs.push(spec);
+            }
+        }
+        self.bookmarks.extend(other.bookmarks);
+        self.annotations.extend(other.annotations);
+        self.suppressions.extend(other.suppressions);
+        self.owners.extend(other.owners);
+        for filter in other.saved_filters {
+            match self
+                .saved_filters
+                .iter_mut()
+                .find(|f| f.name == filter.name)
+            {
+                Some(existing) => *existing = filter,
+                None => self.saved_filters.push(filter),
+            }
+        }
+    }
+
+    /// Spec paths resolved against the directory of the workspace file.
+    pub fn spec_paths(&self, workspace_file: &Path) -> Vec<PathBuf> {
+        let base = workspace_file.parent().unwrap_or(Path::new(""));
+        self.specs.iter().map(|spec| base.join(spec)).collect()
+    }
+
+    /// Returns whether the item is now bookmarked.
+    pub fn toggle_bookmark(&mut self, item: &str) -> bool {
+        if self.bookmarks.remove(item) {
+            false
+        } else {
+            self.bookmarks.insert(item.to_string());
+            true
+        }
+    }
+
+    /// Saves `query` under its own text, replacing a filter with that name.
+    pub fn save_filter(&mut self, query: &str) {
+        self.merge(Workspace {
+            saved_filters: vec![SavedFilter {
+                name: query.to_string(),
+                query: query.to_string(),
+                ..SavedFilter::default()
+            }],
+            ..Workspace::default()
+        });
+    }
+
+    /// Saves a named view, replacing a filter or view with that name.
+    pub fn save_view(&mut self, view: SavedFilter) {
+        self.merge(Workspace {
+            saved_filters: vec![view],
+            ..Workspace::default()
+        });
+    }
+
+    /// Owner of an endpoint (`"METHOD /path"`) or path: an exact endpoint
+    /// mapping wins, then the longest matching path prefix.
+    pub fn owner_of(&self, endpoint: &str) -> Option<&str> {
+        if let Some(owner) = self.owners.get(endpoint) {
+            return Some(owner);
+        }
+        let path = endpoint
+            .split_once(' ')
+            .map(|(_, path)| path)
+            .unwrap_or(endpoint);
+        self.owners
+            .iter()
+            .filter(|(prefix, _)| {
+                prefix.starts_with('/')
+                    && path.starts_with(prefix.as_str())
+                    && (path.len() == prefix.len()
+                        || prefix.ends_with('/')
+                        || path[prefix.len()..].starts_with('/'))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, owner)| owner.as_str())
+    }
+
+    /// Suppressed when the endpoint itself or its path is listed.
+    pub fn is_suppressed(&self, item: &str) -> bool {
+        self.suppressions.contains(item)
+            || item
+                .split_once(' ')
+                .is_some_and(|(_, path)| self.suppressions.contains(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_lookup() {
+        let mut workspace = Workspace::default();
+        workspace
+            .owners
+            .insert("/billing".to_string(), "payments".to_string());
+        workspace
+            .owners
+            .insert("/billing/refunds".to_string(), "support".to_string());
+        workspace
+            .owners
+            .insert("DELETE /billing/{id}".to_string(), "security".to_string());
+
+        assert_eq!(workspace.owner_of("GET /billing/{id}"), Some("payments"));
+        assert_eq!(workspace.owner_of("POST /billing/refunds"), Some("support"));
+        assert_eq!(workspace.owner_of("DELETE /billing/{id}"), Some("security"));
+        assert_eq!(workspace.owner_of("/billing"), Some("payments"));
+        assert_eq!(workspace.owner_of("GET /billingplans"), None);
+    }
+
+    #[test]
+    fn test_roundtrip_and_merge() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("team.json");
+
+        let mut shared = Workspace::default();
+        shared.toggle_bookmark("GET /users");
+        shared
+            .annotations
+            .insert("GET /users".to_string(), "paginate!".to_string());
+        shared.suppressions.insert("/health".to_string());
+        shared.save_filter("users");
+        shared.save(&path).unwrap();
+
+        let mut local = Workspace::default();
+        local.toggle_bookmark("POST /orders");
+        local.saved_filters.push(SavedFilter {
+            name: "users".to_string(),
+            query: "old".to_string(),
+            view: Some("schemas".to_string()),
+            ..SavedFilter::default()
+        });
+        local.merge(Workspace::load(&path).unwrap());
+
+        assert_eq!(local.bookmarks.len(), 2);
+        assert_eq!(local.saved_filters.len(), 1);
+        assert_eq!(local.saved_filters[0].query, "users");
+        assert!(!local.saved_filters[0].is_view());
+        assert!(local.is_suppressed("GET /health"));
+        assert!(!local.is_suppressed("GET /users"));
+
+        std::fs::write(&path, r#"{"specs": ["users.json"]}"#).unwrap();
+        assert_eq!(
+            Workspace::load(&path).unwrap().spec_paths(&path),
+            vec![dir.path().join("users.json")]
+        );
+
+        std::fs::write(&path, r#"{"version": 99}"#).unwrap();
+        assert!(Workspace::load(&path).is_err());
+    }
+}
@@ -0,0 +1,400 @@
+//! Edits written back to the source document. Only the bytes of the
+//! changed value are replaced, so the rest of the file keeps its
+//! formatting, key order and whitespace.
+
+use crate::indexer::{FieldIndex, HttpMethod};
+use crate::parser::{CALLBACK_PREFIX, WEBHOOK_PREFIX};
+use anyhow::{anyhow, Context, Result};
+use std::ops::Range;
+
+/// What a description edit applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DescriptionTarget {
+    Field(String),
+    /// `"METHOD /path"` or `"METHOD webhook:name"`
+    Operation(String),
+}
+
+impl DescriptionTarget {
+    pub fn label(&self) -> &str {
+        match self {
+            DescriptionTarget::Field(name) | DescriptionTarget::Operation(name) => name,
+        }
+    }
+
+    /// Path from the document root to the object holding the description:
+    /// the first schema declaring a field, or the operation object.
+    pub fn pointer(&self, index: &FieldIndex) -> Result<Vec<String>> {
+        match self {
+            DescriptionTarget::Field(name) => {
+                let schema = index
+                    .fields
+                    .get(name)
+                    .and_then(|data| data.schemas.first())
+                    .ok_or_else(|| anyhow!("'{}' is not declared by a component schema", name))?;
+                let mut pointer = vec![
+                    "components".to_string(),
+                    "schemas".to_string(),
+                    schema.to_string(),
+                ];
+                for segment in name.split('.') {
+                    pointer.push("properties".to_string());
+                    match segment.strip_suffix("[]") {
+                        Some(array) => {
+                            pointer.push(array.to_string());
+                            pointer.push("items".to_string());
+                        }
+                        None => pointer.push(segment.to_string()),
+                    }
+                }
+                Ok(pointer)
+            }
+            DescriptionTarget::Operation(endpoint) => {
+                let (method, target) = endpoint
+                    .split_once(' ')
+                    .filter(|(method, _)| HttpMethod::parse(method).is_some())
+                    .ok_or_else(|| anyhow!("'{}' is not an endpoint", endpoint))?;
+                let method = method.to_lowercase();
+                if let Some(name) = target.strip_prefix(WEBHOOK_PREFIX) {
+                    Ok(vec!["webhooks".to_string(), name.to_string(), method])
+                } else if target.starts_with(CALLBACK_PREFIX) {
+                    Err(anyhow!("Callback operations cannot be edited in place"))
+                } else {
+                    Ok(vec!["paths".to_string(), target.to_string(), method])
+                }
+            }
+        }
+    }
+}
+
+/// Sets the `description` of the object at `pointer` in a JSON document,
+/// replacing the existing one or adding it as the object's first member.
+pub fn set_description(source: &str, pointer: &[String], description: &str) -> Result<String> {
+    let literal = serde_json::to_string(description)?;
+    let object = locate(source, pointer)?;
+    if source.as_bytes()[object.start] != b'{' {
+        return Err(anyhow!("{} is not an object", display_pointer(pointer)));
+    }
+
+    let mut description_pointer = pointer.to_vec();
+    description_pointer.push("description".to_string());
+    let updated = match locate(source, &description_pointer) {
+        Ok(existing) => format!(
+            "{}{}{}",
+            &source[..existing.start],
+            literal,
+            &source[existing.end..]
+        ),
+        Err(_) => insert_first_member(source, object.start, &literal)?,
+    };
+
+    // The edit must leave a document that still parses
+    serde_json::from_str::<serde_json::Value>(&updated)
+        .context("The edited document is not valid JSON")?;
+    Ok(updated)
+}
+
+/// Adds `"description": <literal>` right after the `{` at `open`, laid
+/// out like the object's current first member.
+fn insert_first_member(source: &str, open: usize, literal: &str) -> Result<String> {
+    let mut scanner = Scanner::new(source, open + 1);
+    scanner.skip_whitespace();
+    let first = scanner.pos;
+    if scanner.peek() == Some(b'}') {
+        return Ok(format!(
+            "{}\"description\": {}{}",
+            &source[..=open],
+            literal,
+            &source[first..]
+        ));
+    }
+    let indent = &source[open + 1..first];
+    let separator = if indent.contains('\n') { indent } else { " " };
+    // Reuse the spacing around the first member's colon
+    scanner.string()?;
+    let key_end = scanner.pos;
+    scanner.skip_whitespace();
+    scanner.expect(b':')?;
+    scanner.skip_whitespace();
+    let colon = &source[key_end..scanner.pos];
+    Ok(format!(
+        "{}{}\"description\"{}{},{}{}",
+        &source[..=open],
+        indent,
+        colon,
+        literal,
+        separator,
+        &source[first..]
+    ))
+}
+
+fn display_pointer(pointer: &[String]) -> String {
+    pointer
+        .iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Byte range of the value at `pointer`.
+fn locate(source: &str, pointer: &[String]) -> Result<Range<usize>> {
+    let not_found = || {
+        anyhow!(
+            "{} not found in the source document",
+            display_pointer(pointer)
+        )
+    };
+    let mut scanner = Scanner::new(source, 0);
+    scanner.skip_whitespace();
+    'segments: for segment in pointer {
+        match scanner.peek() {
+            Some(b'{') => {
+                scanner.pos += 1;
+                scanner.skip_whitespace();
+                if scanner.peek() == Some(b'}') {
+                    return Err(not_found());
+                }
+                loop {
+                    let key = scanner.string()?;
+                    scanner.skip_whitespace();
+                    scanner.expect(b':')?;
+                    scanner.skip_whitespace();
+                    if key == *segment {
+                        continue 'segments;
+                    }
+                    scanner.skip_value()?;
+                    if !scanner.next_element(b'}')? {
+                        return Err(not_found());
+                    }
+                }
+            }
+            Some(b'[') => {
+                let target: usize = segment.parse().map_err(|_| not_found())?;
+                scanner.pos += 1;
+                scanner.skip_whitespace();
+                if scanner.peek() == Some(b']') {
+                    return Err(not_found());
+                }
+                for _ in 0..target {
+                    scanner.skip_value()?;
+                    if !scanner.next_element(b']')? {
+                        return Err(not_found());
+                    }
+                }
+            }
+            _ => return Err(not_found()),
+        }
+    }
+    let start = scanner.pos;
+    scanner.skip_value()?;
+    Ok(start..scanner.pos)
+}
+
+/// Just enough of a JSON tokenizer to find where values start and end.
+struct Scanner<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(source: &'a str, pos: usize) -> Self {
+        Self { source, pos }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<()> {
+        if self.peek() != Some(byte) {
+            return Err(anyhow!("Expected '{}' at byte {}", byte as char, self.pos));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// After a member or element: moves past a `,` and returns true, or
+    /// past the closing byte and returns false.
+    fn next_element(&mut self, close: u8) -> Result<bool> {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+            self.skip_whitespace();
+            return Ok(true);
+        }
+        self.expect(close)?;
+        Ok(false)
+    }
+
+    /// Decoded string literal at the cursor.
+    fn string(&mut self) -> Result<String> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        loop {
+            match self.peek() {
+                Some(b'\\') => self.pos += 2,
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(_) => self.pos += 1,
+                None => return Err(anyhow!("Unterminated string at byte {}", start)),
+            }
+        }
+        Ok(serde_json::from_str(&self.source[start..self.pos])?)
+    }
+
+    fn skip_value(&mut self) -> Result<()> {
+        match self.peek() {
+            Some(b'"') => {
+                self.string()?;
+            }
+            Some(open @ (b'{' | b'[')) => {
+                let close = if open == b'{' { b'}' } else { b']' };
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(close) {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                loop {
+                    if open == b'{' {
+                        self.string()?;
+                        self.skip_whitespace();
+                        self.expect(b':')?;
+                        self.skip_whitespace();
+                    }
+                    self.skip_value()?;
+                    if !self.next_element(close)? {
+                        break;
+                    }
+                }
+            }
+            Some(_) => {
+                // Numbers, booleans and null run until a delimiter
+                while self
+                    .peek()
+                    .is_some_and(|b| !matches!(b, b',' | b'}' | b']') && !b.is_ascii_whitespace())
+                {
+                    self.pos += 1;
+                }
+            }
+            None => return Err(anyhow!("Unexpected end of document")),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pointer(segments: &[&str]) -> Vec<String> {
+        segments.iter().map(|s| s.to_string()).collect()
+    }
+
+    const SOURCE: &str = r#"{
+  "paths": {
+    "/users": {
+      "get": {
+        "summary": "List users",
+        "description": "Old \"text\"",
+        "responses": {}
+      },
+      "post": {"responses": {}}
+    }
+  },
+  "components": {"schemas": {"User": {"properties": {"email": {}, "id": {"type": "integer"}}}}}
+}
+"#;
+
+    #[test]
+    fn test_replaces_only_the_description() {
+        let updated = set_description(
+            SOURCE,
+            &pointer(&["paths", "/users", "get"]),
+            "Lists users, newest first",
+        )
+        .unwrap();
+        assert_eq!(
+            updated,
+            SOURCE.replace(r#""Old \"text\"""#, r#""Lists users, newest first""#)
+        );
+    }
+
+    #[test]
+    fn test_adds_a_missing_description() {
+        let updated =
+            set_description(SOURCE, &pointer(&["paths", "/users", "post"]), "Creates").unwrap();
+        assert!(updated.contains(r#""post": {"description": "Creates", "responses": {}}"#));
+
+        let updated = set_description(
+            SOURCE,
+            &pointer(&["components", "schemas", "User", "properties", "email"]),
+            "Login",
+        )
+        .unwrap();
+        assert!(updated.contains(r#""email": {"description": "Login"}"#));
+
+        let multiline = "{\n  \"openapi\": \"3.0.0\"\n}";
+        assert_eq!(
+            set_description(multiline, &[], "API").unwrap(),
+            "{\n  \"description\": \"API\",\n  \"openapi\": \"3.0.0\"\n}"
+        );
+
+        assert!(set_description(SOURCE, &pointer(&["paths", "/orders"]), "x").is_err());
+        assert!(set_description(
+            SOURCE,
+            &pointer(&["paths", "/users", "get", "summary"]),
+            "x"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_target_pointers() {
+        let spec = crate::parser::parse_openapi_content(
+            r#"{"openapi": "3.0.0", "info": {"title": "T", "version": "1"}, "paths": {},
+                "components": {"schemas": {"Order": {"type": "object", "properties": {
+                    "lines": {"type": "array", "items": {"type": "object", "properties": {"sku": {"type": "string"}}}}
+                }}}}}"#,
+        )
+        .unwrap();
+        let index = crate::indexer::build_field_index(&spec);
+        assert_eq!(
+            DescriptionTarget::Field("lines[].sku".to_string())
+                .pointer(&index)
+                .unwrap(),
+            pointer(&[
+                "components",
+                "schemas",
+                "Order",
+                "properties",
+                "lines",
+                "items",
+                "properties",
+                "sku"
+            ])
+        );
+        assert_eq!(
+            DescriptionTarget::Operation("PATCH /orders/{id}".to_string())
+                .pointer(&index)
+                .unwrap(),
+            pointer(&["paths", "/orders/{id}", "patch"])
+        );
+        assert_eq!(
+            DescriptionTarget::Operation("POST webhook:orderShipped".to_string())
+                .pointer(&index)
+                .unwrap(),
+            pointer(&["webhooks", "orderShipped", "post"])
+        );
+        assert!(DescriptionTarget::Field("missing".to_string())
+            .pointer(&index)
+            .is_err());
+    }
+}
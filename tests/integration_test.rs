@@ -1,6 +1,14 @@
 use openapi_explorer::indexer;
+use openapi_explorer::notifications::Level;
 use openapi_explorer::parser;
+use openapi_explorer::App;
 use std::path::Path;
+use std::sync::Arc;
+
+/// A filtered list as plain names, to compare with literals.
+fn names(list: &[Arc<str>]) -> Vec<&str> {
+    list.iter().map(|name| &**name).collect()
+}
 
 #[tokio::test]
 async fn test_parse_and_index_petstore() {
@@ -50,12 +58,24 @@ fn test_field_relationships() {
 
     let spec = OpenApiSpec {
         openapi: "3.0.0".to_string(),
+        webhooks: None,
         info: Info {
             title: "Test".to_string(),
             version: "1.0.0".to_string(),
             description: None,
+            terms_of_service: None,
+            contact: None,
+            license: None,
         },
+        servers: None,
         paths: HashMap::new(),
+        tags: None,
+        external_docs: None,
+        parse_warnings: Vec::new(),
+        referenced_components: Default::default(),
+        unresolved_refs: Vec::new(),
+        callback_targets: Default::default(),
+        explorer_ignore: None,
         components: Some(Components {
             schemas: Some(HashMap::from([(
                 "User".to_string(),
@@ -80,6 +100,7 @@ fn test_field_relationships() {
                     ..Default::default()
                 },
             )])),
+            ..Default::default()
         }),
     };
 
@@ -97,3 +118,1691 @@ fn test_field_relationships() {
     let name_related = &relationships["name"];
     assert!(name_related.contains(&"id".to_string()));
 }
+
+#[test]
+fn test_switch_between_specs() {
+    let users = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Users", "version": "1"},
+            "paths": {"/users": {"get": {"responses": {}}}},
+            "components": {"schemas": {"User": {"type": "object", "properties": {"user_id": {"type": "string"}}}}}
+        }"#,
+    )
+    .unwrap();
+    let orders = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Orders", "version": "1"},
+            "paths": {"/orders": {"get": {"responses": {}}}},
+            "components": {"schemas": {"Order": {"type": "object", "properties": {"user_id": {"type": "string"}}}}}
+        }"#,
+    )
+    .unwrap();
+
+    let users_index = indexer::build_field_index(&users);
+    let orders_index = indexer::build_field_index(&orders);
+    let mut app = App::new(users, users_index, None);
+    app.add_spec(orders, orders_index, None);
+
+    assert_eq!(app.cross_index.shared_fields(), vec!["user_id"]);
+
+    app.switch_spec(1);
+    assert_eq!(app.active_spec_name(), "Orders");
+    assert_eq!(names(&app.filtered_endpoints), vec!["/orders".to_string()]);
+
+    app.switch_spec(0);
+    assert_eq!(app.active_spec_name(), "Users");
+    assert!(app.openapi_spec.paths.contains_key("/users"));
+    assert_eq!(app.cross_index.usages("user_id").len(), 2);
+}
+
+#[test]
+fn test_command_palette() {
+    use openapi_explorer::app::View;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Users", "version": "1"},
+            "paths": {"/users": {"get": {"operationId": "listUsers", "responses": {}}}},
+            "components": {"schemas": {"User": {"type": "object", "properties": {
+                "user_id": {"type": "string"}, "age": {"type": "integer"}, "username": {"type": "string"}
+            }}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    let run = |app: &mut App, input: &str| {
+        app.open_command_palette();
+        app.command_palette.as_mut().unwrap().input = input.to_string();
+        app.submit_command();
+    };
+
+    run(&mut app, "view schemas");
+    assert_eq!(app.current_view, View::Schemas);
+    run(&mut app, "view headers");
+    assert_eq!(app.current_view, View::Headers);
+    run(&mut app, "view 7");
+    assert_eq!(app.current_view, View::Responses);
+
+    run(&mut app, "filter type:string");
+    assert_eq!(names(&app.filtered_fields), vec!["user_id", "username"]);
+    run(&mut app, "filter");
+    assert_eq!(app.filtered_fields.len(), 3);
+
+    // Invalid commands stay in the palette and out of the history
+    run(&mut app, "view nowhere");
+    assert!(app.command_palette.as_ref().unwrap().error.is_some());
+    assert_eq!(
+        app.command_history,
+        vec![
+            "view schemas",
+            "view headers",
+            "view 7",
+            "filter type:string",
+            "filter"
+        ]
+    );
+
+    app.recall_command(true);
+    app.recall_command(true);
+    assert_eq!(
+        app.command_palette.as_ref().unwrap().input,
+        "filter type:string"
+    );
+
+    run(&mut app, "op listUsers");
+    assert_eq!(app.current_view, View::Endpoints);
+    assert!(app.show_endpoint_details);
+    assert_eq!(
+        app.selected_endpoint_for_details.as_deref(),
+        Some("GET /users")
+    );
+}
+
+#[test]
+fn test_method_filter_combines_with_search() {
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/users": {"get": {"responses": {}}, "post": {"responses": {}}},
+                "/users/{id}": {"get": {"responses": {}}, "delete": {"responses": {}}},
+                "/orders": {"post": {"responses": {}}}
+            }
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.cycle_method_filter();
+    assert_eq!(app.method_filter, Some("GET"));
+    assert_eq!(
+        names(&app.filtered_endpoints),
+        vec!["/users", "/users/{id}"]
+    );
+    assert_eq!(
+        app.filtered_endpoint_keys(),
+        vec!["GET /users", "GET /users/{id}"]
+    );
+
+    app.cycle_method_filter();
+    app.search_query = "orders".to_string();
+    app.update_filters();
+    assert_eq!(app.method_filter, Some("POST"));
+    assert_eq!(names(&app.filtered_endpoints), vec!["/orders"]);
+
+    // PUT, PATCH, DELETE, then back to every method
+    for _ in 0..4 {
+        app.cycle_method_filter();
+    }
+    assert_eq!(app.method_filter, None);
+    app.search_query.clear();
+    app.update_filters();
+    assert_eq!(app.filtered_endpoints.len(), 3);
+}
+
+#[test]
+fn test_search_input_edits_at_the_cursor() {
+    use openapi_explorer::line_editor;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {"/users": {"get": {"responses": {}}}, "/orders": {"get": {"responses": {}}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    // Edits are ignored until `/` focuses the search bar
+    app.edit_search(|query, cursor| line_editor::insert(query, cursor, "x"));
+    assert!(app.search_query.is_empty());
+
+    app.start_search_input();
+    app.edit_search(|query, cursor| line_editor::insert(query, cursor, "usr"));
+    app.edit_search(|_, cursor| line_editor::move_left(cursor));
+    app.edit_search(|query, cursor| line_editor::insert(query, cursor, "e"));
+    assert_eq!(app.search_query, "user");
+    assert_eq!(app.search_cursor, Some(3));
+    app.update_filters();
+    assert_eq!(names(&app.filtered_endpoints), vec!["/users"]);
+
+    app.stop_search_input();
+    assert_eq!(app.search_cursor, None);
+    app.start_search_input();
+    assert_eq!(app.search_cursor, Some(4));
+    app.edit_search(line_editor::delete_word_before);
+    assert!(app.search_query.is_empty());
+    assert_eq!(app.filtered_endpoints.len(), 2);
+}
+
+#[test]
+fn test_key_events_drive_the_app() {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use openapi_explorer::app::View;
+    use openapi_explorer::codegen::Language;
+    use openapi_explorer::events::{handle_key_event, AppEvent, EditAction, InputMode};
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {"/users": {"get": {"responses": {}}}, "/orders": {"get": {"responses": {}}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let press = |app: &mut App, code| app.handle_event(AppEvent::Input(Event::Key(key(code))));
+
+    // The same key maps to the action of the current view
+    app.set_view(View::Schemas);
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('e')), &app),
+        Some(AppEvent::ExportModels(Language::TypeScript))
+    ));
+    press(&mut app, KeyCode::Char('3'));
+    assert_eq!(app.current_view, View::Endpoints);
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('e')), &app),
+        Some(AppEvent::ExportParameterMatrix)
+    ));
+
+    // `o` opens another spec, so documentation links take `O`
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('O')), &app),
+        Some(AppEvent::OpenDocumentation)
+    ));
+    assert!(handle_key_event(key(KeyCode::Char('w')), &app).is_none());
+
+    // Spec info is on `i` in every view, the Endpoints listing on `k`
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('i')), &app),
+        Some(AppEvent::ToggleSpecInfo)
+    ));
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('k')), &app),
+        Some(AppEvent::ToggleEndpointListing)
+    ));
+
+    // Ctrl+I goes forward when the terminal tells it apart from Tab
+    let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+    for code in [KeyCode::Char('i'), KeyCode::Tab] {
+        assert!(matches!(
+            handle_key_event(ctrl(code), &app),
+            Some(AppEvent::GoForward)
+        ));
+    }
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Tab), &app),
+        Some(AppEvent::NextPanel)
+    ));
+
+    // Keys go to the topmost popup: typed text lands in the palette
+    press(&mut app, KeyCode::Char(':'));
+    assert_eq!(InputMode::of(&app), InputMode::CommandPalette);
+    assert!(matches!(
+        handle_key_event(key(KeyCode::Char('e')), &app),
+        Some(AppEvent::Edit(EditAction::Insert(text))) if text == "e"
+    ));
+    press(&mut app, KeyCode::Char('q'));
+    assert!(!app.should_quit);
+    press(&mut app, KeyCode::Backspace);
+    press(&mut app, KeyCode::Backspace);
+    assert!(app.command_palette.is_none());
+
+    // Search input edits at the cursor and pastes are inserted
+    press(&mut app, KeyCode::Char('/'));
+    app.handle_event(AppEvent::Input(Event::Paste("usr".to_string())));
+    press(&mut app, KeyCode::Left);
+    press(&mut app, KeyCode::Char('e'));
+    assert_eq!(app.search_query, "user");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(InputMode::of(&app), InputMode::Normal);
+
+    app.handle_event(AppEvent::Input(Event::Key(KeyEvent::new(
+        KeyCode::Char('c'),
+        KeyModifiers::CONTROL,
+    ))));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_saved_view_restores_the_slice() {
+    use openapi_explorer::app::View;
+    use openapi_explorer::command::parse_command;
+    use openapi_explorer::sorting::SortMode;
+    use openapi_explorer::workspace::Workspace;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Billing", "version": "1"},
+            "paths": {
+                "/invoices": {"get": {"responses": {}}, "post": {"responses": {}}},
+                "/users": {"post": {"responses": {}}}
+            }
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let dir = tempfile::tempdir().unwrap();
+    app.workspace_file = dir.path().join("team.workspace.json");
+
+    app.set_view(View::Endpoints);
+    app.cycle_method_filter();
+    app.cycle_method_filter();
+    app.search_query = "invoices".to_string();
+    app.toggle_sort_mode();
+    app.run_command(parse_command("save billing-write-endpoints").unwrap());
+    let saved = Workspace::load(&app.workspace_file).unwrap();
+    assert_eq!(saved.saved_filters[0].view.as_deref(), Some("endpoints"));
+    assert_eq!(saved.saved_filters[0].method.as_deref(), Some("POST"));
+
+    app.set_view(View::Fields);
+    app.cycle_method_filter();
+    app.toggle_sort_mode();
+    app.search_query.clear();
+    app.update_filters();
+
+    app.open_saved_view_picker();
+    assert_eq!(app.saved_view_picker, Some(0));
+    app.saved_view_picker = None;
+    app.apply_saved_filter(0);
+    assert_eq!(app.current_view, View::Endpoints);
+    assert_eq!(app.method_filter, Some("POST"));
+    assert_eq!(app.sort_mode, SortMode::Lexical);
+    assert_eq!(app.search_query, "invoices");
+    assert_eq!(app.filtered_endpoint_keys(), vec!["POST /invoices"]);
+}
+
+#[test]
+fn test_navigation_history() {
+    use openapi_explorer::app::{Location, View};
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {"/users": {"get": {"responses": {}}}},
+            "components": {"schemas": {
+                "Order": {"type": "object", "properties": {"total": {"type": "number"}}},
+                "User": {"type": "object", "properties": {"id": {"type": "integer"}}}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.set_view(View::Schemas);
+    app.navigate_down();
+    app.select_current_item();
+    assert_eq!(app.selected_schema.as_deref(), Some("User"));
+    app.set_view(View::Endpoints);
+    app.select_current_item();
+    assert_eq!(app.selected_endpoint.as_deref(), Some("/users"));
+
+    app.go_back();
+    assert_eq!(app.current_view, View::Endpoints);
+    assert!(app.selected_endpoint.is_none());
+    app.go_back();
+    assert_eq!(app.current_view, View::Schemas);
+    assert_eq!(app.selected_schema.as_deref(), Some("User"));
+    assert_eq!(app.schema_list_state, 1);
+
+    app.go_forward();
+    app.go_forward();
+    assert_eq!(
+        app.location(),
+        Location {
+            view: View::Endpoints,
+            field: None,
+            schema: None,
+            endpoint: Some("/users".to_string()),
+            header: None,
+        }
+    );
+    app.go_forward();
+    assert_eq!(
+        app.notifications.current().map(|n| n.message.as_str()),
+        Some("No later location")
+    );
+}
+
+#[test]
+fn test_cross_view_links() {
+    use openapi_explorer::app::{Panel, View};
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/orders": {"get": {"responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "object", "properties": {"total": {"type": "number"}}}}}}}}},
+                "/users": {"get": {"responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "object", "properties": {"email": {"type": "string"}}}}}}}}}
+            },
+            "components": {"schemas": {
+                "Order": {"type": "object", "properties": {"id": {"type": "integer"}, "total": {"type": "number"}}},
+                "User": {"type": "object", "properties": {"id": {"type": "integer"}, "email": {"type": "string"}}}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.field_list_state = app
+        .filtered_fields
+        .iter()
+        .position(|f| &**f == "id")
+        .unwrap();
+    app.select_current_item();
+    assert_eq!(app.selected_field.as_deref(), Some("id"));
+
+    // Field details → Schemas view
+    app.current_panel = Panel::Center;
+    assert_eq!(app.detail_items(), vec!["Order", "User"]);
+    app.navigate_down();
+    app.select_current_item();
+    assert_eq!(app.current_view, View::Schemas);
+    assert_eq!(app.selected_schema.as_deref(), Some("User"));
+
+    // Related endpoints → Endpoints view
+    app.current_panel = Panel::Right;
+    assert_eq!(app.detail_items(), vec!["GET /users"]);
+    app.select_current_item();
+    assert_eq!(app.current_view, View::Endpoints);
+    assert_eq!(app.selected_endpoint.as_deref(), Some("/users"));
+
+    app.go_back();
+    assert_eq!(app.current_view, View::Schemas);
+    assert_eq!(app.selected_schema.as_deref(), Some("User"));
+}
+
+#[test]
+fn test_schema_tree_navigation() {
+    use openapi_explorer::app::{Panel, View};
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {
+                "Address": {"type": "object", "properties": {"city": {"type": "string"}, "zip": {"type": "string"}}},
+                "User": {"type": "object", "properties": {
+                    "address": {"$ref": "#/components/schemas/Address"},
+                    "id": {"type": "integer"}
+                }}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let paths = |app: &App| {
+        app.schema_tree()
+            .into_iter()
+            .map(|row| row.path)
+            .collect::<Vec<_>>()
+    };
+
+    app.set_view(View::Schemas);
+    app.navigate_down();
+    app.select_current_item();
+    assert_eq!(paths(&app), vec!["address", "id"]);
+
+    // Arrows only drive the tree from the center panel
+    app.expand_tree_node();
+    assert_eq!(paths(&app).len(), 2);
+    app.current_panel = Panel::Center;
+    app.expand_tree_node();
+    assert_eq!(
+        paths(&app),
+        vec!["address", "address/city", "address/zip", "id"]
+    );
+
+    app.expand_tree_node();
+    app.navigate_down();
+    assert_eq!(app.schema_tree_cursor, 2);
+    app.collapse_tree_node();
+    assert_eq!(app.schema_tree_cursor, 0);
+    app.collapse_tree_node();
+    assert_eq!(paths(&app), vec!["address", "id"]);
+
+    app.select_current_item();
+    assert_eq!(paths(&app).len(), 4);
+    app.navigate_down();
+    app.navigate_down();
+    app.navigate_down();
+    app.navigate_down();
+    assert_eq!(app.schema_tree_cursor, 3);
+
+    // Choosing another schema starts from a collapsed tree
+    app.current_panel = Panel::Left;
+    app.navigate_up();
+    app.select_current_item();
+    assert_eq!(app.selected_schema.as_deref(), Some("Address"));
+    assert!(app.schema_tree_expanded.is_empty());
+    assert_eq!(app.schema_tree_cursor, 0);
+}
+
+#[test]
+fn test_endpoint_tree_navigation() {
+    use openapi_explorer::app::View;
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/users": {"get": {"responses": {"200": {"description": "ok"}}}},
+                "/users/{id}": {"get": {"responses": {"200": {"description": "ok"}}}},
+                "/users/{id}/orders": {"get": {"responses": {"200": {"description": "ok"}}}},
+                "/zones/{zone}/health": {"get": {"responses": {"200": {"description": "ok"}}}},
+                "/zones/{zone}/status": {"get": {"responses": {"200": {"description": "ok"}}}}
+            }
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let labels = |app: &App| {
+        app.endpoint_rows()
+            .into_iter()
+            .map(|row| row.label)
+            .collect::<Vec<_>>()
+    };
+
+    app.set_view(View::Endpoints);
+    app.navigate_down();
+    app.toggle_endpoint_tree();
+    // The cursor stays on the same path
+    assert_eq!(app.endpoint_list_state, 1);
+    assert_eq!(
+        labels(&app),
+        vec![
+            "/users",
+            "/{id}",
+            "/orders",
+            "/zones/{zone}",
+            "/health",
+            "/status"
+        ]
+    );
+
+    // Left collapses the prefix under the cursor, then moves to its parent
+    app.collapse_path_node();
+    assert_eq!(
+        labels(&app),
+        vec!["/users", "/{id}", "/zones/{zone}", "/health", "/status"]
+    );
+    app.collapse_path_node();
+    assert_eq!(app.endpoint_list_state, 0);
+    // Right moves into an unfolded prefix, then unfolds
+    app.expand_path_node();
+    assert_eq!(app.endpoint_list_state, 1);
+    app.expand_path_node();
+    assert_eq!(labels(&app).len(), 6);
+
+    // Enter folds a row grouping paths and selects a path
+    app.navigate_down();
+    app.navigate_down();
+    assert_eq!(app.endpoint_at_cursor(), None);
+    app.select_current_item();
+    assert_eq!(
+        labels(&app),
+        vec!["/users", "/{id}", "/orders", "/zones/{zone}"]
+    );
+    app.navigate_down();
+    assert_eq!(app.endpoint_list_state, 3);
+    app.navigate_up();
+    app.select_current_item();
+    assert_eq!(app.selected_endpoint.as_deref(), Some("/users/{id}/orders"));
+
+    // Jumping to a folded path unfolds its prefixes
+    app.jump_to_endpoint("GET /zones/{zone}/status");
+    assert_eq!(labels(&app).len(), 6);
+    assert_eq!(app.endpoint_list_state, 5);
+
+    app.toggle_endpoint_tree();
+    assert_eq!(app.endpoint_list_state, 4);
+}
+
+#[test]
+fn test_schema_composition_links_parents() {
+    use openapi_explorer::app::{Panel, View};
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Zoo", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {
+                "Entity": {"type": "object", "properties": {"id": {"type": "string"}}},
+                "Pet": {"allOf": [
+                    {"$ref": "#/components/schemas/Entity"},
+                    {"type": "object", "properties": {"name": {"type": "string"}}}
+                ]}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.set_view(View::Schemas);
+    app.navigate_down();
+    app.select_current_item();
+    assert_eq!(app.selected_schema.as_deref(), Some("Pet"));
+
+    // Parent sections start expanded, their fields marked inherited
+    let rows = app.schema_tree();
+    assert_eq!(
+        rows.iter()
+            .map(|row| (row.path.as_str(), row.inherited))
+            .collect::<Vec<_>>(),
+        vec![
+            ("allOf[0]", false),
+            ("allOf[0]/id", true),
+            ("allOf[1]", false)
+        ]
+    );
+
+    app.current_panel = Panel::Center;
+    app.open_tree_reference();
+    assert_eq!(app.selected_schema.as_deref(), Some("Entity"));
+    assert_eq!(app.current_panel, Panel::Center);
+    app.open_tree_reference();
+    assert_eq!(
+        app.notifications.current().map(|n| n.message.as_str()),
+        Some("No schema referenced here")
+    );
+
+    app.go_back();
+    assert_eq!(app.selected_schema.as_deref(), Some("Pet"));
+}
+
+#[test]
+fn test_lint_findings_jump_to_endpoints() {
+    use openapi_explorer::app::View;
+    use openapi_explorer::lint::{LintConfig, LintRule, Severity};
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/orders": {"get": {"operationId": "list_orders", "responses": {}}},
+                "/users": {"get": {"operationId": "listUsers", "responses": {}}},
+                "/users/{id}": {"get": {"operationId": "listUsers", "responses": {}}}
+            }
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let rules = |app: &App| {
+        app.lint_findings
+            .iter()
+            .map(|finding| finding.rule)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        rules(&app),
+        vec![
+            LintRule::UndeclaredPathParameter,
+            LintRule::DuplicateOperationId,
+            LintRule::DuplicateOperationId,
+            LintRule::OperationIdNaming
+        ]
+    );
+
+    // The pattern is configurable
+    app.lint_config = LintConfig::with_operation_id_pattern(Some("^[a-zA-Z_]+$")).unwrap();
+    app.validate_spec();
+    assert!(!rules(&app).contains(&LintRule::OperationIdNaming));
+    assert!(app
+        .validation_warnings
+        .iter()
+        .any(
+            |diagnostic| diagnostic.message == "GET /users/{id}: {id} has no path parameter"
+                && diagnostic.severity == Severity::Error
+        ));
+
+    app.open_lint_picker();
+    assert_eq!(app.lint_picker, Some(0));
+    app.lint_picker = None;
+    app.jump_to_finding(1);
+    assert_eq!(app.current_view, View::Endpoints);
+    assert_eq!(app.selected_endpoint.as_deref(), Some("/users"));
+}
+
+#[test]
+fn test_strict_meta_schema_findings() {
+    use openapi_explorer::app::View;
+    use openapi_explorer::lint::{LintConfig, LintRule};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("api.json");
+    std::fs::write(
+        &path,
+        r#"{
+            "openapi": "3.0.3",
+            "info": {"title": "T", "version": "1"},
+            "paths": {
+                "/users": {"get": {"operationId": "listUsers", "responses": {"200": {"description": "ok"}}}},
+                "/orders": {"get": {"operationId": "listOrders", "responses": {"200": {"description": "ok", "headres": {}}}}}
+            }
+        }"#,
+    )
+    .unwrap();
+    let spec = parser::parse_openapi_content(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, Some(path));
+    let meta_schema = |app: &App| {
+        app.lint_findings
+            .iter()
+            .filter(|finding| finding.rule == LintRule::MetaSchema)
+            .count()
+    };
+    assert_eq!(meta_schema(&app), 0);
+
+    app.lint_config = LintConfig::default().with_strict(true);
+    app.validate_spec();
+    assert_eq!(meta_schema(&app), 1);
+    let position = app
+        .lint_findings
+        .iter()
+        .position(|finding| finding.location == "/paths/~1orders/get/responses/200")
+        .unwrap();
+    app.jump_to_finding(position);
+    assert_eq!(app.current_view, View::Endpoints);
+    assert_eq!(app.selected_endpoint.as_deref(), Some("/orders"));
+}
+
+#[test]
+fn test_export_typescript_from_palette() {
+    use openapi_explorer::command::parse_command;
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {
+                "User": {"type": "object", "required": ["id"], "properties": {
+                    "id": {"type": "integer"},
+                    "address": {"$ref": "#/components/schemas/Address"}
+                }},
+                "Address": {"type": "object", "properties": {"city": {"type": "string"}}}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("api");
+    app.run_command(parse_command(&format!("export ts {}", out.display())).unwrap());
+    assert_eq!(latest_level(&app), Some(Level::Success));
+
+    let user = std::fs::read_to_string(out.join("User.ts")).unwrap();
+    assert!(user.contains("import type { Address } from \"./Address\";"));
+    assert!(user.contains("  address?: Address;\n  id: number;\n"));
+    assert!(out.join("Address.ts").exists());
+    assert!(out.join("index.ts").exists());
+}
+
+#[test]
+fn test_export_stats_with_snapshot_trend() {
+    use openapi_explorer::command::parse_command;
+    use openapi_explorer::stats::{record_stats_snapshot, StatsSnapshot};
+
+    let dir = tempfile::tempdir().unwrap();
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {"/users": {"get": {"responses": {}}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let now = chrono::Utc::now();
+    record_stats_snapshot(dir.path(), &StatsSnapshot::capture(&spec, &index, now), now).unwrap();
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "2"},
+            "paths": {
+                "/users": {"get": {"responses": {}}},
+                "/orders": {"get": {"responses": {}}, "post": {"responses": {}}}
+            }
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let later = now + chrono::Duration::seconds(1);
+    let (_, trend) = record_stats_snapshot(
+        dir.path(),
+        &StatsSnapshot::capture(&spec, &index, later),
+        later,
+    )
+    .unwrap();
+
+    let mut app = App::new(spec, index, None);
+    app.stats_trend = trend;
+    let path = dir.path().join("stats.md");
+    app.run_command(parse_command(&format!("export stats {}", path.display())).unwrap());
+    assert_eq!(latest_level(&app), Some(Level::Success));
+
+    let markdown = std::fs::read_to_string(&path).unwrap();
+    assert!(markdown.contains("+1 endpoints, +2 operations since last snapshot"));
+}
+
+#[tokio::test]
+async fn test_open_spec_from_file_browser() {
+    let dir = tempfile::tempdir().unwrap();
+    let spec = |title: &str, path: &str| {
+        format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "{}", "version": "1"}}, "paths": {{"{}": {{"get": {{"responses": {{}}}}}}}}}}"#,
+            title, path
+        )
+    };
+    let users_path = dir.path().join("users.json");
+    std::fs::write(&users_path, spec("Users", "/users")).unwrap();
+    std::fs::write(dir.path().join("orders.json"), spec("Orders", "/orders")).unwrap();
+
+    let users = parser::parse_openapi(&users_path).await.unwrap();
+    let index = indexer::build_field_index(&users);
+    let mut app = App::new(users, index, Some(users_path));
+    app.selected_endpoint = Some("/users".to_string());
+
+    app.open_file_browser();
+    let browser = app.file_browser.as_mut().unwrap();
+    browser.cursor = browser
+        .entries
+        .iter()
+        .position(|entry| entry.name == "orders.json")
+        .unwrap();
+    app.select_browser_entry();
+    assert!(app.file_browser.is_none());
+
+    app.run_pending_loads().await;
+    assert_eq!(app.active_spec_name(), "Orders");
+    assert_eq!(names(&app.filtered_endpoints), vec!["/orders".to_string()]);
+    assert!(app.selected_endpoint.is_none());
+    assert!(!app.is_loading);
+
+    // Opening an already loaded spec switches back instead of adding it twice
+    app.request_open(dir.path().join("users.json"));
+    app.run_pending_loads().await;
+    assert_eq!(app.active_spec_name(), "Users");
+    assert_eq!(app.specs.len(), 2);
+}
+
+#[test]
+fn test_file_browser_url_prompt() {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use openapi_explorer::events::AppEvent;
+
+    let spec = parser::parse_openapi_content(
+        r#"{"openapi": "3.0.0", "info": {"title": "Shop", "version": "1"}, "paths": {}}"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let press = |app: &mut App, code| app.handle_event(AppEvent::Input(Event::Key(key(code))));
+
+    press(&mut app, KeyCode::Char('o'));
+    assert!(app.file_browser.is_some());
+
+    // Esc leaves the prompt first, then closes the browser
+    press(&mut app, KeyCode::Char('u'));
+    press(&mut app, KeyCode::Esc);
+    assert!(app.file_browser.as_ref().unwrap().url.is_none());
+    press(&mut app, KeyCode::Esc);
+    assert!(app.file_browser.is_none());
+
+    press(&mut app, KeyCode::Char('o'));
+    press(&mut app, KeyCode::Char('u'));
+    app.handle_event(AppEvent::Input(Event::Paste("ftp://x".to_string())));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(
+        app.file_browser.as_ref().unwrap().url.as_deref(),
+        Some("ftp://x")
+    );
+    assert!(app.pending_open_url.is_none());
+
+    // The prompt stays open with the rejected URL
+    for _ in 0.."ftp://x".len() {
+        press(&mut app, KeyCode::Backspace);
+    }
+    app.handle_event(AppEvent::Input(Event::Paste(
+        "https://example.com/api.json".to_string(),
+    )));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.file_browser.is_none());
+    assert_eq!(
+        app.pending_open_url.as_deref(),
+        Some("https://example.com/api.json")
+    );
+    assert!(app.is_loading);
+}
+
+#[tokio::test]
+async fn test_background_reload_reports_progress() {
+    use openapi_explorer::app::LoadKind;
+    use openapi_explorer::progress::LoadStage;
+    use std::sync::Mutex;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("api.json");
+    let spec = |title: &str| {
+        format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "{}", "version": "1"}}, "paths": {{}}}}"#,
+            title
+        )
+    };
+    std::fs::write(&path, spec("Before")).unwrap();
+    let before = parser::parse_openapi(&path).await.unwrap();
+    let index = indexer::build_field_index(&before);
+    let mut app = App::new(before, index, Some(path.clone()));
+
+    std::fs::write(&path, spec("After")).unwrap();
+    app.request_reload();
+    let request = app.take_load_request().expect("reload pending");
+    assert_eq!(request.kind, LoadKind::Reload);
+    assert!(app.take_load_request().is_none());
+
+    let stages = Mutex::new(Vec::new());
+    let load = request
+        .run(&|message| stages.lock().unwrap().push(message))
+        .await;
+    assert_eq!(
+        stages.into_inner().unwrap(),
+        vec![
+            LoadStage::Reading,
+            LoadStage::Parsing,
+            LoadStage::ResolvingRefs,
+            // No components, so no schema stage
+            LoadStage::IndexingEndpoints { done: 0, total: 0 },
+        ]
+    );
+    // The app keeps showing the old spec until the load is applied
+    assert_eq!(app.active_spec_name(), "Before");
+    assert!(app.is_loading);
+
+    app.apply_spec_load(load);
+    assert_eq!(app.active_spec_name(), "After");
+    assert!(!app.is_loading);
+    assert_eq!(latest_level(&app), Some(Level::Success));
+}
+
+#[test]
+fn test_spec_without_file_is_not_reloaded() {
+    // Specs piped on stdin are loaded without a file path
+    let spec = parser::parse_openapi_content(
+        r#"{"openapi": "3.0.0", "info": {"title": "Piped", "version": "1"}, "paths": {}}"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.request_reload();
+    assert!(!app.is_loading);
+    assert!(app.take_load_request().is_none());
+    assert_eq!(latest_level(&app), Some(Level::Info));
+    assert_eq!(app.active_spec_name(), "Piped");
+}
+
+#[tokio::test]
+async fn test_unchanged_remote_spec_is_not_reparsed() {
+    use axum::http::{header, HeaderMap, StatusCode};
+    use axum::response::IntoResponse;
+    use axum::{routing::get, Router};
+
+    const ETAG: &str = "\"v1\"";
+    let app = Router::new().route(
+        "/openapi.json",
+        get(|headers: HeaderMap| async move {
+            if headers.get(header::IF_NONE_MATCH).is_some_and(|v| v == ETAG) {
+                return StatusCode::NOT_MODIFIED.into_response();
+            }
+            (
+                [(header::ETAG, ETAG)],
+                r#"{"openapi": "3.0.0", "info": {"title": "Staging", "version": "1"}, "paths": {}}"#,
+            )
+                .into_response()
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/openapi.json", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    let dir = tempfile::tempdir().unwrap();
+    let path = openapi_explorer::remote::download(&reqwest::Client::new(), &url, dir.path())
+        .await
+        .unwrap();
+
+    let spec = parser::parse_openapi(&path).await.unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, Some(path));
+    app.request_reload();
+    app.run_pending_loads().await;
+    let latest = app.notifications.latest().unwrap();
+    assert_eq!(latest.level, Level::Info);
+    assert_eq!(latest.message, "Staging not modified");
+}
+
+#[tokio::test]
+async fn test_watched_directory_refresh() {
+    use openapi_explorer::{multi_spec, spec_dir};
+
+    let dir = tempfile::tempdir().unwrap();
+    let document = |title: &str, field: &str| {
+        format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "{}", "version": "1"}}, "paths": {{}},
+                "components": {{"schemas": {{"Item": {{"type": "object", "properties": {{"{}": {{"type": "string"}}}}}}}}}}}}"#,
+            title, field
+        )
+    };
+    std::fs::write(
+        dir.path().join("orders.json"),
+        document("Orders", "order_id"),
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("users.json"), document("Users", "user_id")).unwrap();
+    let files = spec_dir::spec_files(dir.path()).unwrap();
+    let (mut loaded, failed) =
+        multi_spec::load_specs_skipping_failures(&files, None, 2, false).await;
+    assert!(failed.is_empty());
+    let (spec, index, path) = loaded.remove(0);
+    let mut app = App::new(spec, index, Some(path));
+    for (spec, index, path) in loaded {
+        app.add_spec(spec, index, Some(path));
+    }
+    app.spec_dirs = vec![dir.path().canonicalize().unwrap()];
+
+    // Edited: the parked spec is re-read once the changes settle
+    std::fs::write(&files[1], document("Users", "account_id")).unwrap();
+    app.queue_spec_refresh(vec![files[1].clone()]);
+    assert!(app.take_load_request().is_none());
+    app.refresh_due = None;
+    app.run_pending_loads().await;
+    assert!(app.cross_index.fields.contains_key("account_id"));
+    assert!(!app.cross_index.fields.contains_key("user_id"));
+    assert_eq!(app.active_spec_name(), "Orders");
+
+    // Added, broken, then deleted files
+    let billing = files[0].with_file_name("billing.json");
+    let broken = files[0].with_file_name("broken.json");
+    std::fs::write(&billing, document("Billing", "invoice_id")).unwrap();
+    std::fs::write(&broken, "{").unwrap();
+    app.queue_spec_refresh(vec![billing.clone(), broken.clone()]);
+    app.refresh_due = None;
+    app.run_pending_loads().await;
+    assert_eq!(app.specs.len(), 3);
+    assert!(app.spec_dir_failures.contains_key(&broken));
+
+    std::fs::remove_file(&files[0]).unwrap();
+    app.queue_spec_refresh(vec![files[0].clone()]);
+    app.refresh_due = None;
+    app.run_pending_loads().await;
+    let names: Vec<&str> = app.specs.iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, ["Users", "Billing"]);
+    assert!(!app.cross_index.fields.contains_key("order_id"));
+    let totals: usize = app
+        .spec_summaries()
+        .iter()
+        .map(|summary| summary.schemas)
+        .sum();
+    assert_eq!(totals, 2);
+}
+
+/// Synthetic spec with `schemas` schemas of 20 fields and one GET/POST path
+/// per schema.
+fn large_spec(schemas: usize) -> parser::OpenApiSpec {
+    let mut components = serde_json::Map::new();
+    let mut paths = serde_json::Map::new();
+    for i in 0..schemas {
+        let properties: serde_json::Map<String, serde_json::Value> = (0..20)
+            .map(|j| {
+                (
+                    format!("field_{}", (i * 7 + j) % 500),
+                    serde_json::json!({"type": "string"}),
+                )
+            })
+            .collect();
+        components.insert(
+            format!("Model{}", i),
+            serde_json::json!({"type": "object", "properties": properties}),
+        );
+        let body = serde_json::json!({"content": {"application/json": {
+            "schema": {"$ref": format!("#/components/schemas/Model{}", i)}
+        }}});
+        paths.insert(
+            format!("/models{}/{{id}}", i),
+            serde_json::json!({
+                "get": {
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                    "responses": {"200": {"description": "ok", "content": body["content"]}}
+                },
+                "post": {"requestBody": body, "responses": {}}
+            }),
+        );
+    }
+    let spec = serde_json::json!({
+        "openapi": "3.0.0",
+        "info": {"title": "Large", "version": "1"},
+        "paths": paths,
+        "components": {"schemas": components}
+    });
+    parser::parse_openapi_content(&spec.to_string()).unwrap()
+}
+
+#[test]
+fn test_parallel_index_matches_single_threaded() {
+    let spec = large_spec(300);
+
+    let parallel = indexer::build_field_index(&spec);
+    let single = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap()
+        .install(|| indexer::build_field_index(&spec));
+
+    assert_eq!(parallel.schemas.len(), 300);
+    assert_eq!(parallel.endpoint_fields.len(), 600);
+    assert_eq!(parallel.fields.len(), single.fields.len());
+    for (name, data) in &parallel.fields {
+        let other = &single.fields[name];
+        assert_eq!(data.schemas, other.schemas, "schemas of {}", name);
+        assert_eq!(data.endpoints, other.endpoints, "endpoints of {}", name);
+    }
+}
+
+#[test]
+fn test_validate_payload_from_palette() {
+    use openapi_explorer::command::parse_command;
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {"/users": {"post": {
+                "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+                "responses": {"201": {"description": "Created"}}
+            }}},
+            "components": {"schemas": {
+                "User": {"type": "object", "required": ["email"], "properties": {
+                    "email": {"type": "string", "format": "email"},
+                    "role": {"type": "string", "enum": ["admin", "member"]}
+                }}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    let dir = tempfile::tempdir().unwrap();
+    let payload = dir.path().join("body.json");
+    std::fs::write(&payload, r#"{"role": "owner"}"#).unwrap();
+    let command = parse_command(&format!("validate {}", payload.display())).unwrap();
+
+    app.run_command(command.clone());
+    assert!(app.payload_validation.is_none(), "needs an open endpoint");
+
+    app.selected_endpoint_for_details = Some("POST /users".to_string());
+    app.show_endpoint_details = true;
+    app.run_command(command);
+    let validation = app.payload_validation.as_ref().unwrap();
+    assert_eq!(validation.target, "request body");
+    let paths: Vec<&str> = validation
+        .violations
+        .iter()
+        .map(|v| v.path.as_str())
+        .collect();
+    assert_eq!(paths, vec!["$.email", "$.role"]);
+}
+
+#[test]
+fn test_compare_schemas_flow() {
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Users", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {
+                "UserDto": {"type": "object", "required": ["id"], "properties": {"id": {"type": "integer"}}},
+                "UserResponse": {"type": "object", "properties": {"id": {"type": "integer"}, "name": {"type": "string"}}}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    app.set_view(openapi_explorer::app::View::Schemas);
+    let position = |app: &App, name: &str| app.filtered_schemas.iter().position(|s| &**s == name);
+
+    app.schema_list_state = position(&app, "UserDto").unwrap();
+    app.compare_schema();
+    assert_eq!(app.schema_compare_base.as_deref(), Some("UserDto"));
+
+    app.schema_list_state = position(&app, "UserResponse").unwrap();
+    app.select_current_item();
+    assert!(app.schema_compare_base.is_none());
+    let comparison = app.schema_comparison.as_ref().unwrap();
+    assert_eq!(
+        (comparison.left.as_str(), comparison.right.as_str()),
+        ("UserDto", "UserResponse")
+    );
+    assert_eq!(comparison.shared_count(), 1);
+    assert_eq!(comparison.mismatch_count(), 2);
+}
+
+#[test]
+fn test_delete_and_patch_count_as_writes() {
+    use openapi_explorer::indexer::HttpMethod;
+    use openapi_explorer::report::compute_stats;
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Users", "version": "1"},
+            "paths": {
+                "/users": {"get": {"responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "object", "properties": {"name": {"type": "string"}}}}}}}}},
+                "/users/{id}": {
+                    "patch": {"requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {"email": {"type": "string"}}}}}}, "responses": {}},
+                    "delete": {"responses": {}}
+                }
+            },
+            "components": {"schemas": {
+                "User": {"type": "object", "properties": {"name": {"type": "string"}, "email": {"type": "string"}}}
+            }}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+
+    let stats = compute_stats(&spec, &index);
+    assert_eq!(stats.operations, 3);
+    assert_eq!(stats.write_operations, 2);
+    assert_eq!(stats.methods["PATCH"], 1);
+    assert_eq!(stats.methods["DELETE"], 1);
+    assert_eq!(stats.critical_fields, 1);
+
+    assert!(index.is_critical_field("email"));
+    assert!(!index.is_critical_field("name"));
+    assert_eq!(
+        index.fields["email"]
+            .methods
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![HttpMethod::Patch]
+    );
+}
+
+#[test]
+fn test_field_rename_plan() {
+    use openapi_explorer::command::parse_command;
+
+    let content = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Users", "version": "1"},
+        "paths": {"/users": {"get": {
+            "parameters": [{"name": "email", "in": "query", "schema": {"type": "string"}}],
+            "responses": {"200": {"description": "ok", "content": {"application/json": {
+                "schema": {"type": "object", "properties": {"email": {"type": "string"}}},
+                "example": {"email": "a@example.com"}
+            }}}}
+        }}},
+        "components": {"schemas": {
+            "User": {"type": "object", "required": ["email"], "properties": {"email": {"type": "string"}}}
+        }}
+    }"##;
+    let dir = tempfile::tempdir().unwrap();
+    let spec_path = dir.path().join("users.json");
+    std::fs::write(&spec_path, content).unwrap();
+    let spec = parser::parse_openapi_content(content).unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, Some(spec_path.clone()));
+
+    app.field_list_state = app
+        .filtered_fields
+        .iter()
+        .position(|f| &**f == "email")
+        .unwrap();
+    let plan_path = dir.path().join("plan.json");
+    app.run_command(
+        parse_command(&format!("rename email_address {}", plan_path.display())).unwrap(),
+    );
+
+    let plan = app.rename_plan.as_ref().unwrap();
+    assert_eq!(plan.schemas(), vec!["User"]);
+    assert_eq!(plan.endpoints(), vec!["GET /users"]);
+    assert_eq!(plan.sites.len(), 5);
+
+    // The plan applies to the source document, which is left untouched
+    let patch: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&plan_path).unwrap()).unwrap();
+    assert_eq!(patch.as_array().unwrap().len(), 5);
+    assert_eq!(
+        patch[0]["path"],
+        "/components/schemas/User/properties/email_address"
+    );
+    assert_eq!(std::fs::read_to_string(&spec_path).unwrap(), content);
+}
+
+#[test]
+fn test_description_write_back() {
+    let content = r##"{
+  "openapi": "3.0.0",
+  "info": {"title": "Users", "version": "1"},
+  "paths": {
+    "/users": {
+      "get": {"summary": "List", "responses": {}}
+    }
+  },
+  "components": {"schemas": {
+    "User": {"type": "object", "properties": {"email": {"type": "string", "description": "TODO"}}}
+  }}
+}
+"##;
+    let dir = tempfile::tempdir().unwrap();
+    let spec_path = dir.path().join("users.json");
+    std::fs::write(&spec_path, content).unwrap();
+    let spec = parser::parse_openapi_content(content).unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, Some(spec_path.clone()));
+
+    app.field_list_state = app
+        .filtered_fields
+        .iter()
+        .position(|f| &**f == "email")
+        .unwrap();
+    app.open_description_editor();
+    assert_eq!(app.description_editor.as_ref().unwrap().input, "TODO");
+    app.edit_description(|input, cursor| {
+        input.clear();
+        *cursor = 0;
+    });
+    app.edit_description(|input, cursor| {
+        openapi_explorer::line_editor::insert(input, cursor, "Login address")
+    });
+    app.save_description();
+    assert!(app.description_editor.is_none());
+    assert!(app.should_reload);
+
+    app.selected_endpoint_for_details = Some("GET /users".to_string());
+    app.show_endpoint_details = true;
+    app.open_description_editor();
+    app.edit_description(|input, cursor| {
+        openapi_explorer::line_editor::insert(input, cursor, "Lists users")
+    });
+    app.save_description();
+
+    // Everything but the two descriptions is left as written
+    let expected = content.replace("\"TODO\"", "\"Login address\"").replace(
+        "\"get\": {\"summary\"",
+        "\"get\": {\"description\": \"Lists users\", \"summary\"",
+    );
+    assert_eq!(std::fs::read_to_string(&spec_path).unwrap(), expected);
+}
+
+#[test]
+fn test_error_skeleton_export() {
+    use openapi_explorer::command::parse_command;
+
+    let spec = parser::parse_openapi_content(
+        r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/orders": {"post": {"responses": {
+                    "201": {"description": "created"},
+                    "422": {"description": "invalid", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/ApiError"}}}},
+                    "500": {"description": "error", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/ApiError"}}}}
+                }}},
+                "/orders/{id}": {"delete": {"responses": {"204": {"description": "deleted"}}}}
+            },
+            "components": {"schemas": {"ApiError": {"type": "object", "properties": {"message": {"type": "string"}}}}}
+        }"##,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    let dir = tempfile::tempdir().unwrap();
+    let patch_path = dir.path().join("errors.json");
+    app.run_command(parse_command(&format!("export errors {}", patch_path.display())).unwrap());
+    let patch: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&patch_path).unwrap()).unwrap();
+    // The spec's own error schema is reused, so only responses are added
+    let paths: Vec<&str> = patch
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|op| op["path"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/paths/~1orders~1{id}/delete/responses/404",
+            "/paths/~1orders~1{id}/delete/responses/500",
+        ]
+    );
+    assert_eq!(
+        patch[0]["value"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ApiError"
+    );
+
+    let yaml_path = dir.path().join("errors.yaml");
+    app.run_command(parse_command(&format!("export errors {}", yaml_path.display())).unwrap());
+    let yaml = std::fs::read_to_string(&yaml_path).unwrap();
+    assert!(yaml.starts_with("\"paths\":\n  \"/orders/{id}\":\n    \"delete\":\n"));
+    assert!(yaml.contains("\"$ref\": \"#/components/schemas/ApiError\""));
+}
+
+#[tokio::test]
+async fn test_lenient_reload_keeps_valid_parts() {
+    use openapi_explorer::cache::SpecCache;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("api.json");
+    let valid = r#"{"openapi": "3.0.0", "info": {"title": "Draft", "version": "1"}, "paths": {
+        "/users": {"get": {"responses": {"200": {"description": "ok", "content": {"application/json": {
+            "schema": {"type": "object", "properties": {"user_id": {"type": "string"}}}
+        }}}}}}
+    }}"#;
+    std::fs::write(&path, valid).unwrap();
+    let spec = parser::parse_openapi(&path).await.unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, Some(path.clone()));
+    let cache = SpecCache::new(dir.path().join("cache"));
+    app.cache = Some(cache.clone());
+
+    // An operation being written breaks a strict reload
+    let broken = valid.replace(
+        r#""/users": {"#,
+        r#""/users": {"post": {"responses": {"201": {}}}, "#,
+    );
+    std::fs::write(&path, &broken).unwrap();
+    app.request_reload();
+    app.run_pending_loads().await;
+    assert_eq!(latest_level(&app), Some(Level::Error));
+
+    app.lenient = true;
+    app.request_reload();
+    app.run_pending_loads().await;
+    assert_eq!(latest_level(&app), Some(Level::Success));
+    assert!(app.field_index.endpoint_fields.contains_key("GET /users"));
+    assert_eq!(
+        app.validation_warnings[0].message,
+        "Skipped invalid /paths/~1users/post: missing field `description`"
+    );
+    // Partial specs are not cached, so the warnings show on every load
+    assert!(cache.load(&path, &broken).is_none());
+}
+
+#[tokio::test]
+async fn test_failed_reload_shows_parse_error_and_retries() {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use openapi_explorer::events::{AppEvent, InputMode};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("api.json");
+    let spec = |title: &str| {
+        format!(
+            "{{\n  \"openapi\": \"3.0.0\",\n  \"info\": {{\"title\": \"{}\", \"version\": \"1\"}},\n  \"paths\": {{}}\n}}",
+            title
+        )
+    };
+    std::fs::write(&path, spec("Before")).unwrap();
+    let before = parser::parse_openapi(&path).await.unwrap();
+    let index = indexer::build_field_index(&before);
+    let mut app = App::new(before, index, Some(path.clone()));
+    let press = |app: &mut App, code| {
+        app.handle_event(AppEvent::Input(Event::Key(KeyEvent::new(
+            code,
+            KeyModifiers::NONE,
+        ))))
+    };
+
+    std::fs::write(&path, spec("After").replace("\"1\"}", "1}")).unwrap();
+    app.request_reload();
+    app.run_pending_loads().await;
+    let failed = app.failed_load.as_ref().expect("parse error shown");
+    let error = failed.parse_error().unwrap();
+    assert_eq!((error.line, error.column), (3, 11));
+    assert_eq!(error.pointer.as_deref(), Some("/info"));
+    assert!(error.message.contains("invalid type: integer"));
+    assert_eq!(InputMode::of(&app), InputMode::FailedLoad);
+    assert_eq!(app.active_spec_name(), "Before");
+
+    // Once fixed, `r` loads the document again
+    std::fs::write(&path, spec("After")).unwrap();
+    press(&mut app, KeyCode::Char('r'));
+    assert!(app.failed_load.is_none());
+    app.run_pending_loads().await;
+    assert_eq!(app.active_spec_name(), "After");
+    assert_eq!(InputMode::of(&app), InputMode::Normal);
+}
+
+fn latest_level(app: &App) -> Option<Level> {
+    app.notifications
+        .latest()
+        .map(|notification| notification.level)
+}
+
+#[test]
+fn test_notifications_toast_and_history() {
+    use openapi_explorer::events::AppEvent;
+
+    let spec = parser::parse_openapi_content(
+        r#"{"openapi": "3.0.0", "info": {"title": "Empty", "version": "1"}, "paths": {}}"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    app.notify(Level::Error, "Export failed: disk full");
+    app.notify(Level::Success, "Exported 3 endpoint(s)");
+    assert_eq!(
+        app.notifications.current().map(|n| n.message.as_str()),
+        Some("Exported 3 endpoint(s)")
+    );
+
+    app.handle_event(AppEvent::ToggleNotifications);
+    assert!(app.show_notifications);
+    let history: Vec<Level> = app.notifications.history().map(|n| n.level).collect();
+    assert_eq!(history, [Level::Success, Level::Error]);
+
+    // Esc closes the history and dismisses the toast, keeping the history
+    app.handle_event(AppEvent::Close);
+    assert!(!app.show_notifications);
+    assert!(app.notifications.current().is_none());
+    assert_eq!(app.notifications.len(), 2);
+}
+
+#[test]
+fn test_stats_tag_selects_endpoints() {
+    use openapi_explorer::app::View;
+    use openapi_explorer::command::parse_command;
+    use openapi_explorer::stats::StatsSection;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {
+                "/users": {"get": {"tags": ["users"], "responses": {}},
+                           "post": {"tags": ["admin"], "responses": {}}},
+                "/orders": {"get": {"tags": ["orders"], "responses": {}}},
+                "/health": {"get": {"responses": {}}}
+            }
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    app.set_view(View::Stats);
+    app.stats_section = StatsSection::Tags;
+
+    // One endpoint each, so by name: (untagged), admin, orders, users
+    for _ in 0..3 {
+        app.navigate_down();
+    }
+    assert_eq!(app.stats_tag_cursor, 3);
+    app.select_current_item();
+    assert_eq!(app.current_view, View::Endpoints);
+    assert_eq!(app.tag_filter.as_deref(), Some("users"));
+    assert_eq!(names(&app.filtered_endpoints), vec!["/users"]);
+    assert_eq!(app.filtered_endpoint_keys(), vec!["GET /users"]);
+
+    app.run_command(parse_command("tag (untagged)").unwrap());
+    assert_eq!(names(&app.filtered_endpoints), vec!["/health"]);
+    app.run_command(parse_command("tag").unwrap());
+    assert_eq!(app.tag_filter, None);
+    assert_eq!(app.filtered_endpoints.len(), 3);
+}
+
+#[test]
+fn test_documentation_link_follows_the_selection() {
+    use openapi_explorer::app::View;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Pets", "version": "1"},
+            "externalDocs": {"url": "https://docs.example.com"},
+            "paths": {"/pets": {"get": {"responses": {},
+                "externalDocs": {"url": "https://docs.example.com/list-pets"}}}},
+            "components": {"schemas": {"Pet": {"type": "object",
+                "externalDocs": {"url": "https://docs.example.com/pet"},
+                "properties": {"name": {"type": "string"}}}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+    let link = |app: &App| app.documentation_link().map(|docs| docs.url.clone());
+
+    assert_eq!(link(&app).as_deref(), Some("https://docs.example.com"));
+
+    app.set_view(View::Endpoints);
+    app.selected_endpoint = Some("GET /pets".to_string());
+    assert_eq!(
+        link(&app).as_deref(),
+        Some("https://docs.example.com/list-pets")
+    );
+
+    app.set_view(View::Schemas);
+    app.selected_schema = Some("Pet".to_string());
+    assert_eq!(link(&app).as_deref(), Some("https://docs.example.com/pet"));
+}
+
+#[test]
+fn test_filter_by_field_flags() {
+    use openapi_explorer::command::parse_command;
+
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {"User": {"type": "object", "properties": {
+                "id": {"type": "string", "readOnly": true},
+                "createdAt": {"type": "string", "readOnly": true},
+                "password": {"type": "string", "writeOnly": true},
+                "name": {"type": "string"}
+            }}}}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let mut app = App::new(spec, index, None);
+
+    app.run_command(parse_command("filter readonly:true").unwrap());
+    assert_eq!(names(&app.filtered_fields), vec!["createdAt", "id"]);
+    app.run_command(parse_command("filter readonly:false writeonly:false").unwrap());
+    assert_eq!(names(&app.filtered_fields), vec!["name"]);
+    app.run_command(parse_command("filter").unwrap());
+    assert!(app.field_flag_filters.is_empty());
+    assert_eq!(app.filtered_fields.len(), 4);
+
+    let info = app.get_field_info("password").unwrap();
+    assert_eq!(info.flags.len(), 1);
+    assert!(info.flags[0].1.write_only);
+}
+
+#[test]
+fn test_field_info_lists_defaults_and_examples() {
+    let spec = parser::parse_openapi_content(
+        r#"{
+            "openapi": "3.0.0",
+            "info": {"title": "Shop", "version": "1"},
+            "paths": {},
+            "components": {"schemas": {
+                "Order": {"type": "object", "properties": {
+                    "status": {"type": "string", "default": "pending", "example": "shipped"},
+                    "note": {"type": "string", "maxLength": 200}}},
+                "Draft": {"type": "object", "properties": {
+                    "status": {"type": "string", "default": "draft"}}}
+            }}
+        }"#,
+    )
+    .unwrap();
+    let index = indexer::build_field_index(&spec);
+    let app = App::new(spec, index, None);
+
+    let mut values = app.get_field_info("status").unwrap().values;
+    values.sort_by(|a, b| a.schema.cmp(&b.schema));
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].schema, "Draft");
+    assert_eq!(values[0].default.as_deref(), Some("\"draft\""));
+    assert_eq!(values[0].example, None);
+    assert_eq!(values[1].example.as_deref(), Some("\"shipped\""));
+
+    let note = app.get_field_info("note").unwrap();
+    assert!(note.values.is_empty());
+    assert_eq!(
+        note.constraints,
+        vec![("Order".to_string(), vec!["maxLength 200".to_string()])]
+    );
+}